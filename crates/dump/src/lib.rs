@@ -6,17 +6,19 @@ use std::collections::BTreeMap;
 use meilisearch_types::batches::BatchId;
 use meilisearch_types::byte_unit::Byte;
 use meilisearch_types::error::ResponseError;
-use meilisearch_types::keys::Key;
+use meilisearch_types::keys::{Key, SigningKey};
 use meilisearch_types::milli::update::IndexDocumentsMethod;
 use meilisearch_types::settings::Unchecked;
 use meilisearch_types::tasks::network::{DbTaskNetwork, NetworkTopologyChange};
 use meilisearch_types::tasks::{
-    Details, DsrUpdate, ExportIndexSettings, IndexSwap, KindWithContent, Status, Task, TaskId,
+    Details, DsrUpdate, DumpSelection, ExportIndexSettings, IndexSwap, KindWithContent, Status,
+    Task, TaskId, TaskPriority,
 };
 use meilisearch_types::InstanceUid;
 use roaring::RoaringBitmap;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
+use uuid::Uuid;
 
 mod error;
 mod reader;
@@ -38,6 +40,9 @@ pub struct Metadata {
     pub db_version: String,
     #[serde(with = "time::serde::rfc3339")]
     pub dump_date: OffsetDateTime,
+    /// The parts of the instance left out of the dump, if any.
+    #[serde(default, skip_serializing_if = "DumpSelection::is_full")]
+    pub selection: DumpSelection,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -100,6 +105,10 @@ pub struct TaskDump {
     pub network: Option<DbTaskNetwork>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_metadata: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_uid: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "TaskPriority::is_normal")]
+    pub priority: TaskPriority,
 }
 
 // A `Kind` specific version made for the dump. If modified you may break the dump.
@@ -150,7 +159,11 @@ pub enum KindDump {
     },
     DumpCreation {
         keys: Vec<Key>,
+        #[serde(default)]
+        signing_keys: Vec<SigningKey>,
         instance_uid: Option<InstanceUid>,
+        #[serde(default)]
+        selection: DumpSelection,
     },
     SnapshotCreation,
     Export {
@@ -168,6 +181,13 @@ pub enum KindDump {
     NetworkTopologyChange(NetworkTopologyChange),
     DsrUpdate(DsrUpdate),
     DsrClear,
+    DumpImport {
+        dump_uid: String,
+        #[serde(default)]
+        index_renames: BTreeMap<String, String>,
+        #[serde(default)]
+        skip_existing_indexes: bool,
+    },
 }
 
 impl From<Task> for TaskDump {
@@ -186,6 +206,8 @@ impl From<Task> for TaskDump {
             finished_at: task.finished_at,
             network: task.network,
             custom_metadata: task.custom_metadata,
+            schedule_uid: task.schedule_uid,
+            priority: task.priority,
         }
     }
 }
@@ -235,8 +257,8 @@ impl From<KindWithContent> for KindDump {
             KindWithContent::TaskDeletion { query, tasks } => {
                 KindDump::TasksDeletion { query, tasks }
             }
-            KindWithContent::DumpCreation { keys, instance_uid } => {
-                KindDump::DumpCreation { keys, instance_uid }
+            KindWithContent::DumpCreation { keys, signing_keys, instance_uid, selection } => {
+                KindDump::DumpCreation { keys, signing_keys, instance_uid, selection }
             }
             KindWithContent::SnapshotCreation => KindDump::SnapshotCreation,
             KindWithContent::Export { url, api_key, payload_size, indexes } => KindDump::Export {
@@ -259,6 +281,9 @@ impl From<KindWithContent> for KindDump {
             }
             KindWithContent::DsrUpdate(update) => KindDump::DsrUpdate(update),
             KindWithContent::DsrClear => KindDump::DsrClear,
+            KindWithContent::DumpImport { dump_uid, index_renames, skip_existing_indexes } => {
+                KindDump::DumpImport { dump_uid, index_renames, skip_existing_indexes }
+            }
         }
     }
 }
@@ -281,7 +306,7 @@ pub(crate) mod test {
     use meilisearch_types::network::{Network, Remote, Shard};
     use meilisearch_types::settings::{Checked, FacetingSettings, Settings};
     use meilisearch_types::task_view::DetailsView;
-    use meilisearch_types::tasks::{BatchStopReason, Details, Kind, Status};
+    use meilisearch_types::tasks::{BatchStopReason, Details, DumpSelection, Kind, Status};
     use serde_json::{json, Map, Value};
     use time::macros::datetime;
     use uuid::Uuid;
@@ -352,6 +377,7 @@ pub(crate) mod test {
             facet_search: Setting::NotSet,
             prefix_search: Setting::NotSet,
             chat: Setting::NotSet,
+            reranker: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         settings.check()
@@ -411,6 +437,8 @@ pub(crate) mod test {
                     finished_at: Some(datetime!(2022-11-21 0:00 UTC)),
                     network: None,
                     custom_metadata: None,
+                    schedule_uid: None,
+                    priority: TaskPriority::Normal,
                 },
                 None,
             ),
@@ -437,6 +465,8 @@ pub(crate) mod test {
                     finished_at: None,
                     network: None,
                     custom_metadata: None,
+                    schedule_uid: None,
+                    priority: TaskPriority::Normal,
                 },
                 Some(vec![
                     json!({ "id": 4, "race": "leonberg" }).as_object().unwrap().clone(),
@@ -458,6 +488,8 @@ pub(crate) mod test {
                     finished_at: None,
                     network: None,
                     custom_metadata: None,
+                    schedule_uid: None,
+                    priority: TaskPriority::Normal,
                 },
                 None,
             ),
@@ -473,6 +505,7 @@ pub(crate) mod test {
                 actions: vec![Action::DocumentsAll],
                 indexes: vec![IndexUidPattern::from_str("doggos").unwrap()],
                 expires_at: Some(datetime!(4130-03-14 12:21 UTC)),
+                rate_limits: None,
                 created_at: datetime!(1960-11-15 0:00 UTC),
                 updated_at: datetime!(2022-11-10 0:00 UTC),
             },
@@ -483,6 +516,7 @@ pub(crate) mod test {
                 actions: vec![Action::All],
                 indexes: vec![IndexUidPattern::all()],
                 expires_at: None,
+                rate_limits: None,
                 created_at: datetime!(0000-01-01 00:01 UTC),
                 updated_at: datetime!(1964-05-04 17:25 UTC),
             },
@@ -493,6 +527,7 @@ pub(crate) mod test {
                 actions: vec![],
                 indexes: vec![],
                 expires_at: None,
+                rate_limits: None,
                 created_at: datetime!(400-02-29 0:00 UTC),
                 updated_at: datetime!(1024-02-29 0:00 UTC),
             },
@@ -592,6 +627,7 @@ pub(crate) mod test {
         assert_eq!(dump.version(), Version::V6);
         assert!(dump.date().is_some());
         assert_eq!(dump.instance_uid().unwrap().unwrap(), create_test_instance_uid());
+        assert!(dump.selection().is_full());
 
         // ==== checking the index
         let mut indexes = dump.indexes().unwrap();
@@ -639,4 +675,29 @@ pub(crate) mod test {
         expected.local = None;
         assert_eq!(&expected, dump.network().unwrap().unwrap());
     }
+
+    #[test]
+    fn test_read_dump_selection() {
+        let selection = DumpSelection {
+            indexes: Some(vec![IndexUidPattern::new_unchecked("dog*")]),
+            skip_tasks: true,
+            skip_batches: false,
+            skip_keys: true,
+        };
+        let dump = DumpWriter::with_selection(None, selection.clone()).unwrap();
+        dump.create_tasks_queue().unwrap().flush().unwrap();
+        dump.create_keys().unwrap().flush().unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        dump.persist_to(&mut file).unwrap();
+        file.rewind().unwrap();
+
+        let mut dump = DumpReader::open(&mut file).unwrap();
+        assert_eq!(dump.selection(), selection);
+        assert!(dump.selection().contains_index("doggos"));
+        assert!(!dump.selection().contains_index("catto"));
+        assert!(dump.indexes().unwrap().next().is_none());
+        assert!(dump.tasks().unwrap().next().is_none());
+        assert!(dump.keys().unwrap().next().is_none());
+    }
 }
@@ -126,7 +126,12 @@ impl CompatV5ToV6 {
                         },
                         v5::tasks::TaskContent::Dump { uid: _ } => {
                             // in v6 we compute the dump_uid from the started_at processing time
-                            v6::Kind::DumpCreation { keys: keys.clone(), instance_uid }
+                            v6::Kind::DumpCreation {
+                                keys: keys.clone(),
+                                signing_keys: Vec::new(),
+                                instance_uid,
+                                selection: Default::default(),
+                            }
                         }
                     },
                     canceled_by: None,
@@ -165,6 +170,8 @@ impl CompatV5ToV6 {
                     finished_at: task_view.finished_at,
                     network: None,
                     custom_metadata: None,
+                    schedule_uid: None,
+                    priority: v6::TaskPriority::Normal,
                 };
 
                 (task, content_file)
@@ -193,6 +200,7 @@ impl CompatV5ToV6 {
                     })
                     .collect(),
                 expires_at: key.expires_at,
+                rate_limits: None,
                 created_at: key.created_at,
                 updated_at: key.updated_at,
             })
@@ -429,6 +437,7 @@ impl<T> From<v5::Settings<T>> for v6::Settings<v6::Unchecked> {
             facet_search: v6::Setting::NotSet,
             prefix_search: v6::Setting::NotSet,
             chat: v6::Setting::NotSet,
+            reranker: v6::Setting::NotSet,
             _kind: std::marker::PhantomData,
         }
     }
@@ -78,6 +78,15 @@ impl DumpReader {
         }
     }
 
+    /// The parts of the instance left out of the dump when it was created.
+    pub fn selection(&self) -> v6::DumpSelection {
+        match self {
+            DumpReader::Current(current) => current.selection().clone(),
+            // selective dumps were introduced with the v6 dumps
+            DumpReader::Compat(_compat) => v6::DumpSelection::default(),
+        }
+    }
+
     pub fn indexes(&self) -> Result<Box<dyn Iterator<Item = Result<DumpIndexReader>> + '_>> {
         match self {
             DumpReader::Current(current) => {
@@ -116,6 +125,15 @@ impl DumpReader {
         }
     }
 
+    pub fn signing_keys(
+        &mut self,
+    ) -> Result<Box<dyn Iterator<Item = Result<v6::SigningKey>> + '_>> {
+        match self {
+            DumpReader::Current(current) => Ok(current.signing_keys()),
+            DumpReader::Compat(_compat) => Ok(Box::new(std::iter::empty())),
+        }
+    }
+
     pub fn chat_completions_settings(
         &mut self,
     ) -> Result<Box<dyn Iterator<Item = Result<(String, v6::ChatCompletionSettings)>> + '_>> {
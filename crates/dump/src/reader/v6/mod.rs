@@ -23,15 +23,18 @@ pub type Unchecked = meilisearch_types::settings::Unchecked;
 pub type Task = crate::TaskDump;
 pub type Batch = meilisearch_types::batches::Batch;
 pub type Key = meilisearch_types::keys::Key;
+pub type SigningKey = meilisearch_types::keys::SigningKey;
 pub type ChatCompletionSettings = meilisearch_types::features::ChatCompletionSettings;
 pub type RuntimeTogglableFeatures = meilisearch_types::features::RuntimeTogglableFeatures;
 pub type Network = meilisearch_types::network::Network;
+pub type DumpSelection = meilisearch_types::tasks::DumpSelection;
 pub type Webhooks = meilisearch_types::webhooks::WebhooksDumpView;
 pub type DynamicSearchRule = meilisearch_types::dynamic_search_rules::DynamicSearchRule;
 
 // ===== Other types to clarify the code of the compat module
 // everything related to the tasks
 pub type Status = meilisearch_types::tasks::Status;
+pub type TaskPriority = meilisearch_types::tasks::TaskPriority;
 pub type Kind = crate::KindDump;
 pub type Details = meilisearch_types::tasks::Details;
 
@@ -63,6 +66,7 @@ pub struct V6Reader {
     tasks2: BufReader<File>,
     batches: Option<BufReader<File>>,
     keys: BufReader<File>,
+    signing_keys: Option<BufReader<File>>,
     features: Option<RuntimeTogglableFeatures>,
     network: Option<Network>,
     webhooks: Option<Webhooks>,
@@ -100,6 +104,13 @@ impl V6Reader {
             Err(e) => return Err(e.into()),
         };
 
+        let signing_keys = match File::open(dump.path().join("signing-keys.jsonl")) {
+            Ok(file) => Some(BufReader::new(file)),
+            // The signing keys were introduced after the api keys, older dumps don't have them
+            Err(err) if err.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(e.into()),
+        };
+
         let mut network: Option<meilisearch_types::network::Network> =
             match fs::read(dump.path().join("network.json")) {
                 Ok(network_file) => Some(serde_json::from_reader(&*network_file)?),
@@ -141,6 +152,7 @@ impl V6Reader {
             tasks2: BufReader::new(File::open(dump.path().join("tasks").join("queue.jsonl"))?),
             batches,
             keys: BufReader::new(File::open(dump.path().join("keys.jsonl"))?),
+            signing_keys,
             features,
             network,
             dump,
@@ -160,6 +172,10 @@ impl V6Reader {
         Ok(self.instance_uid)
     }
 
+    pub fn selection(&self) -> &DumpSelection {
+        &self.metadata.selection
+    }
+
     pub fn indexes(&self) -> Result<Box<dyn Iterator<Item = Result<V6IndexReader>> + '_>> {
         let entries = fs::read_dir(self.dump.path().join("indexes"))?;
         Ok(Box::new(
@@ -256,6 +272,15 @@ impl V6Reader {
         )
     }
 
+    pub fn signing_keys(&mut self) -> Box<dyn Iterator<Item = Result<SigningKey>> + '_> {
+        match self.signing_keys.as_mut() {
+            Some(signing_keys) => Box::new(
+                signing_keys.lines().map(|line| -> Result<_> { Ok(serde_json::from_str(&line?)?) }),
+            ),
+            None => Box::new(std::iter::empty()),
+        }
+    }
+
     pub fn chat_completions_settings(
         &mut self,
     ) -> Result<Box<dyn Iterator<Item = Result<(String, ChatCompletionSettings)>> + '_>> {
@@ -6,9 +6,10 @@ use flate2::write::GzEncoder;
 use flate2::Compression;
 use meilisearch_types::batches::Batch;
 use meilisearch_types::features::{ChatCompletionSettings, RuntimeTogglableFeatures};
-use meilisearch_types::keys::Key;
+use meilisearch_types::keys::{Key, SigningKey};
 use meilisearch_types::network::Network;
 use meilisearch_types::settings::{Checked, Settings};
+use meilisearch_types::tasks::DumpSelection;
 use meilisearch_types::webhooks::WebhooksDumpView;
 use serde_json::{Map, Value};
 use tempfile::TempDir;
@@ -24,6 +25,14 @@ pub struct DumpWriter {
 
 impl DumpWriter {
     pub fn new(instance_uuid: Option<Uuid>) -> Result<DumpWriter> {
+        Self::with_selection(instance_uuid, DumpSelection::default())
+    }
+
+    /// Creates a dump recording in its metadata the parts of the instance left out of it.
+    pub fn with_selection(
+        instance_uuid: Option<Uuid>,
+        selection: DumpSelection,
+    ) -> Result<DumpWriter> {
         let dir = TempDir::new()?;
 
         if let Some(instance_uuid) = instance_uuid {
@@ -37,6 +46,7 @@ impl DumpWriter {
             dump_version: CURRENT_DUMP_VERSION,
             db_version: env!("CARGO_PKG_VERSION").to_string(),
             dump_date: OffsetDateTime::now_utc(),
+            selection,
         };
         fs::write(dir.path().join("metadata.json"), serde_json::to_string(&metadata)?)?;
 
@@ -53,6 +63,10 @@ impl DumpWriter {
         KeyWriter::new(self.dir.path().to_path_buf())
     }
 
+    pub fn create_signing_keys(&self) -> Result<SigningKeyWriter> {
+        SigningKeyWriter::new(self.dir.path().to_path_buf())
+    }
+
     pub fn create_chat_completions_settings(&self) -> Result<ChatCompletionsSettingsWriter> {
         ChatCompletionsSettingsWriter::new(self.dir.path().join("chat-completions-settings"))
     }
@@ -117,6 +131,28 @@ impl KeyWriter {
     }
 }
 
+pub struct SigningKeyWriter {
+    signing_keys: BufWriter<File>,
+}
+
+impl SigningKeyWriter {
+    pub(crate) fn new(path: PathBuf) -> Result<Self> {
+        let signing_keys = File::create(path.join("signing-keys.jsonl"))?;
+        Ok(SigningKeyWriter { signing_keys: BufWriter::new(signing_keys) })
+    }
+
+    pub fn push_signing_key(&mut self, signing_key: &SigningKey) -> Result<()> {
+        serde_json::to_writer(&mut self.signing_keys, &signing_key)?;
+        self.signing_keys.write_all(b"\n")?;
+        Ok(())
+    }
+
+    pub fn flush(mut self) -> Result<()> {
+        self.signing_keys.flush()?;
+        Ok(())
+    }
+}
+
 pub struct ChatCompletionsSettingsWriter {
     path: PathBuf,
 }
@@ -32,6 +32,8 @@ pub enum Condition {
     Between { from: Token, to: Token },
     Contains { keyword: Token, word: Token },
     StartsWith { keyword: Token, word: Token },
+    EndsWith { keyword: Token, word: Token },
+    Matches { keyword: Token, pattern: Token },
 }
 
 impl Condition {
@@ -49,6 +51,8 @@ impl Condition {
             Condition::Between { .. } => "TO",
             Condition::Contains { .. } => "CONTAINS",
             Condition::StartsWith { .. } => "STARTS WITH",
+            Condition::EndsWith { .. } => "ENDS WITH",
+            Condition::Matches { .. } => "MATCHES",
         }
     }
 }
@@ -224,7 +228,7 @@ pub fn parse_not_contains(input: Span) -> IResult<FilterCondition> {
     ))
 }
 
-/// starts with        = value "CONTAINS" value
+/// starts with        = value "STARTS WITH" value
 pub fn parse_starts_with(input: Span) -> IResult<FilterCondition> {
     let (input, (fid, starts_with, value)) =
         tuple((parse_value, tag("STARTS WITH"), cut(parse_value)))(input)?;
@@ -237,7 +241,7 @@ pub fn parse_starts_with(input: Span) -> IResult<FilterCondition> {
     ))
 }
 
-/// starts with        = value "NOT" WS+ "CONTAINS" value
+/// starts with        = value "NOT" WS+ "STARTS WITH" value
 pub fn parse_not_starts_with(input: Span) -> IResult<FilterCondition> {
     let keyword = tuple((tag("NOT"), multispace1, tag("STARTS WITH")));
     let (input, (fid, (_not, _spaces, starts_with), value)) =
@@ -252,6 +256,79 @@ pub fn parse_not_starts_with(input: Span) -> IResult<FilterCondition> {
     ))
 }
 
+/// ends with        = value "ENDS WITH" value
+pub fn parse_ends_with(input: Span) -> IResult<FilterCondition> {
+    let (input, (fid, ends_with, value)) =
+        tuple((parse_value, tag("ENDS WITH"), cut(parse_value)))(input)?;
+    Ok((
+        input,
+        FilterCondition::Condition { fid, op: EndsWith { keyword: ends_with.into(), word: value } },
+    ))
+}
+
+/// ends with        = value "NOT" WS+ "ENDS WITH" value
+pub fn parse_not_ends_with(input: Span) -> IResult<FilterCondition> {
+    let keyword = tuple((tag("NOT"), multispace1, tag("ENDS WITH")));
+    let (input, (fid, (_not, _spaces, ends_with), value)) =
+        tuple((parse_value, keyword, cut(parse_value)))(input)?;
+
+    Ok((
+        input,
+        FilterCondition::Not(Box::new(FilterCondition::Condition {
+            fid,
+            op: EndsWith { keyword: ends_with.into(), word: value },
+        })),
+    ))
+}
+
+/// The pattern of a `MATCHES` must be a valid regular expression, the error points to the pattern.
+fn parse_pattern(input: Span) -> IResult<Token> {
+    let (input, pattern) = cut(parse_value)(input)?;
+    if let Err(error) = crate::build_regex(pattern.fragment()) {
+        return Err(Error::failure_from_kind(
+            pattern.span(),
+            ErrorKind::InvalidRegex(regex_error_message(error)),
+        ));
+    }
+    Ok((input, pattern))
+}
+
+fn regex_error_message(error: regex::Error) -> String {
+    match error {
+        regex::Error::Syntax(syntax) => {
+            // the last line of a syntax error holds the description of the problem
+            syntax.lines().last().unwrap_or_default().trim_start_matches("error: ").to_string()
+        }
+        regex::Error::CompiledTooBig(_) => "the compiled regular expression is too big".to_string(),
+        error => error.to_string(),
+    }
+}
+
+/// matches        = value "MATCHES" value
+pub fn parse_matches(input: Span) -> IResult<FilterCondition> {
+    let (input, (fid, matches, pattern)) =
+        tuple((parse_value, tag("MATCHES"), parse_pattern))(input)?;
+    Ok((
+        input,
+        FilterCondition::Condition { fid, op: Matches { keyword: matches.into(), pattern } },
+    ))
+}
+
+/// matches        = value "NOT" WS+ "MATCHES" value
+pub fn parse_not_matches(input: Span) -> IResult<FilterCondition> {
+    let keyword = tuple((tag("NOT"), multispace1, tag("MATCHES")));
+    let (input, (fid, (_not, _spaces, matches), pattern)) =
+        tuple((parse_value, keyword, parse_pattern))(input)?;
+
+    Ok((
+        input,
+        FilterCondition::Not(Box::new(FilterCondition::Condition {
+            fid,
+            op: Matches { keyword: matches.into(), pattern },
+        })),
+    ))
+}
+
 /// to             = value value "TO" WS+ value
 pub fn parse_to(input: Span) -> IResult<FilterCondition> {
     let (input, (key, from, _, _, to)) =
@@ -106,6 +106,7 @@ pub enum ErrorKind {
     Char(char),
     InternalError(error::ErrorKind),
     DepthLimitReached,
+    InvalidRegex(String),
     External(String),
 }
 
@@ -195,7 +196,7 @@ impl Display for Error {
             }
             ErrorKind::InvalidPrimary => {
                 let text = if input.trim().is_empty() { "but instead got nothing.".to_string() } else { format!("at `{}`.", escaped_input) };
-                writeln!(f, "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, `_geoBoundingBox` or `_geoPolygon` {text}")?
+                writeln!(f, "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, `_geoBoundingBox` or `_geoPolygon` {text}")?
             }
             ErrorKind::InvalidEscapedNumber => {
                 writeln!(f, "Found an invalid escaped sequence number: `{}`.", escaped_input)?
@@ -300,6 +301,7 @@ impl Display for Error {
                 f,
                 "Encountered an internal `{:?}` error while parsing your filter. Please fill an issue", kind
             )?,
+            ErrorKind::InvalidRegex(ref error) => writeln!(f, "The pattern `{escaped_input}` is not a valid regular expression: {error}.")?,
             ErrorKind::External(ref error) => writeln!(f, "{}", error)?,
             ErrorKind::Foreign => writeln!(f, "Was expecting a field name and an condition inside `_foreign(..)` filter but instead found `{escaped_input}`.")?,
         }
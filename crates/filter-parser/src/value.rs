@@ -272,7 +272,9 @@ fn is_keyword(s: &str) -> bool {
             | "EMPTY"
             | "CONTAINS"
             | "STARTS"
+            | "ENDS"
             | "WITH"
+            | "MATCHES"
             | "_geoRadius"
             | "_geoBoundingBox"
     )
@@ -0,0 +1,137 @@
+//! Persistence of the conversations of the chat workspaces.
+//!
+//! The conversations are keyed by `{workspace_uid}\0{conversation_id}` so that
+//! all the conversations of a workspace can be iterated or deleted with a prefix.
+//! As LMDB keys are limited to 511 bytes, the conversation ids are checked against it.
+
+use meilisearch_types::features::{ChatConversation, ChatConversationsSettings};
+use meilisearch_types::heed::types::{DecodeIgnore, SerdeJson, Str};
+use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn, WithoutTls};
+use time::OffsetDateTime;
+
+use crate::{Error, Result};
+
+mod db_name {
+    pub const CHAT_CONVERSATIONS: &str = "chat-conversations";
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ChatConversations {
+    /// All the conversations, by workspace and conversation id.
+    conversations: Database<Str, SerdeJson<ChatConversation>>,
+}
+
+/// The maximum length of a key of the LMDB databases, in bytes.
+pub(crate) const MAX_KEY_LENGTH: usize = 511;
+
+fn key(workspace_uid: &str, conversation_id: &str) -> Result<String> {
+    let key = format!("{workspace_uid}\0{conversation_id}");
+    if key.len() > MAX_KEY_LENGTH {
+        return Err(Error::ChatConversationIdTooLong {
+            workspace_uid: workspace_uid.to_string(),
+            conversation_id: conversation_id.to_string(),
+        });
+    }
+    Ok(key)
+}
+
+fn prefix(workspace_uid: &str) -> String {
+    format!("{workspace_uid}\0")
+}
+
+impl ChatConversations {
+    pub(crate) const fn nb_db() -> u32 {
+        1
+    }
+
+    pub(crate) fn new(env: &Env<WithoutTls>, wtxn: &mut RwTxn) -> Result<Self> {
+        Ok(Self { conversations: env.create_database(wtxn, Some(db_name::CHAT_CONVERSATIONS))? })
+    }
+
+    pub(crate) fn get(
+        &self,
+        rtxn: &RoTxn,
+        workspace_uid: &str,
+        conversation_id: &str,
+    ) -> Result<Option<ChatConversation>> {
+        Ok(self.conversations.get(rtxn, &key(workspace_uid, conversation_id)?)?)
+    }
+
+    pub(crate) fn put(
+        &self,
+        wtxn: &mut RwTxn,
+        workspace_uid: &str,
+        conversation: &ChatConversation,
+    ) -> Result<()> {
+        Ok(self.conversations.put(wtxn, &key(workspace_uid, &conversation.id)?, conversation)?)
+    }
+
+    pub(crate) fn delete(
+        &self,
+        wtxn: &mut RwTxn,
+        workspace_uid: &str,
+        conversation_id: &str,
+    ) -> Result<bool> {
+        Ok(self.conversations.delete(wtxn, &key(workspace_uid, conversation_id)?)?)
+    }
+
+    /// Returns the total number of conversations of the workspace accessible by the requester
+    /// and the requested page of them.
+    pub(crate) fn paginated(
+        &self,
+        rtxn: &RoTxn,
+        workspace_uid: &str,
+        requester: Option<&str>,
+        from: usize,
+        limit: usize,
+    ) -> Result<(usize, Vec<ChatConversation>)> {
+        let mut total = 0;
+        let mut conversations = Vec::new();
+        for ret in self.conversations.prefix_iter(rtxn, &prefix(workspace_uid))? {
+            let (_, conversation) = ret?;
+            if !conversation.is_accessible_by(requester) {
+                continue;
+            }
+            if total >= from && conversations.len() < limit {
+                conversations.push(conversation);
+            }
+            total += 1;
+        }
+        Ok((total, conversations))
+    }
+
+    /// Deletes all the conversations of the workspace and returns how many were deleted.
+    pub(crate) fn delete_workspace(&self, wtxn: &mut RwTxn, workspace_uid: &str) -> Result<usize> {
+        let mut deleted = 0;
+        let mut iter = self
+            .conversations
+            .remap_data_type::<DecodeIgnore>()
+            .prefix_iter_mut(wtxn, &prefix(workspace_uid))?;
+        while iter.next().transpose()?.is_some() {
+            // safety: we don't keep any reference to the deleted entry
+            unsafe { iter.del_current()? };
+            deleted += 1;
+        }
+        Ok(deleted)
+    }
+
+    /// Deletes the conversations of the workspace that outlived their TTL.
+    pub(crate) fn delete_expired(
+        &self,
+        wtxn: &mut RwTxn,
+        workspace_uid: &str,
+        settings: &ChatConversationsSettings,
+        now: OffsetDateTime,
+    ) -> Result<usize> {
+        let mut deleted = 0;
+        let mut iter = self.conversations.prefix_iter_mut(wtxn, &prefix(workspace_uid))?;
+        while let Some((_, conversation)) = iter.next().transpose()? {
+            if conversation.is_expired(settings, now) {
+                // safety: we don't keep any reference to the deleted entry
+                unsafe { iter.del_current()? };
+                deleted += 1;
+            }
+        }
+        Ok(deleted)
+    }
+}
@@ -9,7 +9,7 @@ use meilisearch_types::heed::RwTxn;
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::milli;
 use meilisearch_types::milli::update::MissingDocumentPolicy;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task, TaskPriority};
 use roaring::RoaringBitmap;
 use uuid::Uuid;
 
@@ -25,6 +25,7 @@ pub struct Dump<'a> {
     indexes: HashMap<String, RoaringBitmap>,
     statuses: HashMap<Status, RoaringBitmap>,
     kinds: HashMap<Kind, RoaringBitmap>,
+    priorities: HashMap<TaskPriority, RoaringBitmap>,
 
     batch_indexes: HashMap<String, RoaringBitmap>,
     batch_statuses: HashMap<Status, RoaringBitmap>,
@@ -43,6 +44,7 @@ impl<'a> Dump<'a> {
             indexes: HashMap::new(),
             statuses: HashMap::new(),
             kinds: HashMap::new(),
+            priorities: HashMap::new(),
             batch_indexes: HashMap::new(),
             batch_statuses: HashMap::new(),
             batch_kinds: HashMap::new(),
@@ -153,6 +155,8 @@ impl<'a> Dump<'a> {
             status: task.status,
             network: task.network,
             custom_metadata: task.custom_metadata,
+            schedule_uid: task.schedule_uid,
+            priority: task.priority,
             kind: match task.kind {
                 KindDump::DocumentImport {
                     primary_key,
@@ -216,8 +220,8 @@ impl<'a> Dump<'a> {
                 KindDump::TasksDeletion { query, tasks } => {
                     KindWithContent::TaskDeletion { query, tasks }
                 }
-                KindDump::DumpCreation { keys, instance_uid } => {
-                    KindWithContent::DumpCreation { keys, instance_uid }
+                KindDump::DumpCreation { keys, signing_keys, instance_uid, selection } => {
+                    KindWithContent::DumpCreation { keys, signing_keys, instance_uid, selection }
                 }
                 KindDump::SnapshotCreation => KindWithContent::SnapshotCreation,
                 KindDump::Export { url, api_key, payload_size, indexes } => {
@@ -246,6 +250,9 @@ impl<'a> Dump<'a> {
                 }
                 KindDump::DsrUpdate(update) => KindWithContent::DsrUpdate(update),
                 KindDump::DsrClear => KindWithContent::DsrClear,
+                KindDump::DumpImport { dump_uid, index_renames, skip_existing_indexes } => {
+                    KindWithContent::DumpImport { dump_uid, index_renames, skip_existing_indexes }
+                }
             },
         };
 
@@ -296,6 +303,7 @@ impl<'a> Dump<'a> {
 
         self.statuses.entry(task.status).or_default().insert(task.uid);
         self.kinds.entry(task.kind.as_kind()).or_default().insert(task.uid);
+        self.priorities.entry(task.priority).or_default().insert(task.uid);
 
         Ok(task)
     }
@@ -319,6 +327,9 @@ impl<'a> Dump<'a> {
         for (kind, bitmap) in self.kinds {
             self.index_scheduler.queue.tasks.put_kind(&mut self.wtxn, kind, &bitmap)?;
         }
+        for (priority, bitmap) in self.priorities {
+            self.index_scheduler.queue.tasks.put_priority(&mut self.wtxn, priority, &bitmap)?;
+        }
 
         for (index, bitmap) in self.batch_indexes {
             self.index_scheduler.queue.batches.index_tasks.put(&mut self.wtxn, &index, &bitmap)?;
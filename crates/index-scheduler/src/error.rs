@@ -7,6 +7,7 @@ use meilisearch_types::milli::index::RollbackOutcome;
 use meilisearch_types::milli::DocumentId;
 use meilisearch_types::tasks::network::{ReceiveImportFinishedError, ReceiveTaskError};
 use meilisearch_types::tasks::{Kind, Status};
+use meilisearch_types::webhooks::DeliveryId;
 use meilisearch_types::{heed, milli};
 use thiserror::Error;
 use uuid::Uuid;
@@ -84,6 +85,8 @@ pub enum Error {
     SwapIndexesFoundDuringRename(Vec<String>),
     #[error("Corrupted dump.")]
     CorruptedDump,
+    #[error("Dump `{0}` not found.")]
+    DumpNotFound(String),
     #[error(
         "Task `{field}` `{date}` is invalid. It should follow the YYYY-MM-DD or RFC 3339 date-time format."
     )]
@@ -127,6 +130,12 @@ pub enum Error {
     TaskFileNotFound(TaskId),
     #[error("Batch `{0}` not found.")]
     BatchNotFound(BatchId),
+    #[error("Delivery `{delivery}` of the webhook `{webhook}` not found.")]
+    WebhookDeliveryNotFound { webhook: Uuid, delivery: DeliveryId },
+    #[error("Schedule `{0}` not found.")]
+    ScheduleNotFound(Uuid),
+    #[error("Conversation id `{conversation_id}` is too long for the chat `{workspace_uid}`. The chat uid and the conversation id can not be more than {max} bytes together.", max = crate::chat_conversations::MAX_KEY_LENGTH - 1)]
+    ChatConversationIdTooLong { workspace_uid: String, conversation_id: String },
     #[error("Query parameters to filter the tasks to delete are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
     TaskDeletionWithEmptyQuery,
     #[error("Query parameters to filter the tasks to cancel are missing. Available query parameters are: `uids`, `indexUids`, `statuses`, `types`, `canceledBy`, `beforeEnqueuedAt`, `afterEnqueuedAt`, `beforeStartedAt`, `afterStartedAt`, `beforeFinishedAt`, `afterFinishedAt`.")]
@@ -241,6 +250,7 @@ impl Error {
             | Error::SwapIndexFoundDuringRename(_, _)
             | Error::SwapIndexesFoundDuringRename(_)
             | Error::CorruptedDump
+            | Error::DumpNotFound(_)
             | Error::InvalidTaskDate { .. }
             | Error::InvalidTaskUid { .. }
             | Error::InvalidBatchUid { .. }
@@ -252,6 +262,9 @@ impl Error {
             | Error::TaskNotFound(_)
             | Error::TaskFileNotFound(_)
             | Error::BatchNotFound(_)
+            | Error::WebhookDeliveryNotFound { .. }
+            | Error::ScheduleNotFound(_)
+            | Error::ChatConversationIdTooLong { .. }
             | Error::TaskDeletionWithEmptyQuery
             | Error::TaskCancelationWithEmptyQuery
             | Error::FromRemoteWhenExporting { .. }
@@ -331,6 +344,9 @@ impl ErrorCode for Error {
             Error::TaskNotFound(_) => Code::TaskNotFound,
             Error::TaskFileNotFound(_) => Code::TaskFileNotFound,
             Error::BatchNotFound(_) => Code::BatchNotFound,
+            Error::WebhookDeliveryNotFound { .. } => Code::WebhookDeliveryNotFound,
+            Error::ScheduleNotFound(_) => Code::ScheduleNotFound,
+            Error::ChatConversationIdTooLong { .. } => Code::InvalidChatConversationId,
             Error::TaskDeletionWithEmptyQuery => Code::MissingTaskFilters,
             Error::TaskCancelationWithEmptyQuery => Code::MissingTaskFilters,
             Error::NoSpaceLeftInTaskQueue => Code::NoSpaceLeftOnDevice,
@@ -365,6 +381,7 @@ impl ErrorCode for Error {
             Error::Anyhow(_) => Code::Internal,
             Error::CorruptedTaskQueue => Code::Internal,
             Error::CorruptedDump => Code::Internal,
+            Error::DumpNotFound(_) => Code::DumpNotFound,
             Error::DatabaseUpgrade(_) => Code::Internal,
             Error::Export(_) => Code::Internal,
             Error::RollbackFailed { .. } => Code::Internal,
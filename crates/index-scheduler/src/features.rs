@@ -108,12 +108,12 @@ impl RoFeatures {
         }
     }
 
-    pub fn check_contains_filter(&self) -> Result<()> {
+    pub fn check_contains_filter(&self, disabled_action: &'static str) -> Result<()> {
         if self.runtime.contains_filter {
             Ok(())
         } else {
             Err(FeatureNotEnabledError {
-                disabled_action: "Using `CONTAINS` in a filter",
+                disabled_action,
                 feature: "contains filter",
                 issue_link: "https://github.com/orgs/meilisearch/discussions/763",
             }
@@ -1,6 +1,8 @@
 use either::Either;
 use meilisearch_types::error::Code;
-use meilisearch_types::milli::{self, Filter, FilterCondition, IndexFilter, IndexFilterCondition};
+use meilisearch_types::milli::{
+    self, Filter, FilterCondition, IndexFilter, IndexFilterCondition, TokenLike as _,
+};
 use serde_json::Value;
 
 use crate::{Error, Result, RoFeatures};
@@ -82,10 +84,15 @@ fn check_filter_experimental_features(
     features: RoFeatures,
 ) -> Result<Option<Filter>> {
     if let Some(ref filter) = filter {
-        // If the contains operator is used while the contains filter feature is not enabled, errors out
-        if let Some((token, error)) =
-            filter.use_contains_operator().zip(features.check_contains_filter().err())
-        {
+        // If the contains, ends with or matches operators are used while the contains filter feature is not enabled, errors out
+        if let Some((token, error)) = filter.use_contains_operator().and_then(|token| {
+            let disabled_action = match token.fragment() {
+                "ENDS WITH" => "Using `ENDS WITH` in a filter",
+                "MATCHES" => "Using `MATCHES` in a filter",
+                _ => "Using `CONTAINS` in a filter",
+            };
+            Some(token).zip(features.check_contains_filter(disabled_action).err())
+        }) {
             return Err(Error::Milli {
                 error: token.to_external_error(error).into(),
                 index_uid: None,
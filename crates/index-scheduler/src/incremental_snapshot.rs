@@ -0,0 +1,132 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use meilisearch_types::batches::BatchId;
+use meilisearch_types::tasks::TaskId;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::{Result, SnapshotRetention};
+
+/// The name of the file describing the content of an incremental snapshot.
+pub const SNAPSHOT_MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Describes the content of an incremental snapshot.
+///
+/// Incremental snapshots are directories with the same layout as the content of
+/// a full snapshot tarball, the index files of the indexes that did not change since
+/// the previous snapshot are hard links to the ones of the previous snapshot.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    /// The version of Meilisearch that created the snapshot.
+    pub version: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    /// The state of every index of the snapshot, by index uuid.
+    pub indexes: BTreeMap<Uuid, IndexManifest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexManifest {
+    pub uid: String,
+    /// The last succeeded task of the index when the snapshot was created.
+    pub last_task_uid: Option<TaskId>,
+    /// The batch of the last succeeded task of the index.
+    pub last_batch_uid: Option<BatchId>,
+    #[serde(with = "time::serde::rfc3339::option")]
+    pub updated_at: Option<OffsetDateTime>,
+    /// Whether the index file was reused from the previous snapshot.
+    pub reused: bool,
+}
+
+impl IndexManifest {
+    /// Whether the index did not change since the snapshot of the `previous` manifest was created.
+    pub fn is_unchanged_since(&self, previous: &IndexManifest) -> bool {
+        // the task uids can be reused once all the tasks are deleted,
+        // the date of the last update of the index prevents this case
+        self.updated_at.is_some()
+            && self.updated_at == previous.updated_at
+            && self.last_task_uid == previous.last_task_uid
+    }
+}
+
+impl SnapshotManifest {
+    pub fn new(version: String, created_at: OffsetDateTime) -> Self {
+        SnapshotManifest { version, created_at, indexes: BTreeMap::new() }
+    }
+
+    /// Reads the manifest of the incremental snapshot stored in `dir`, if any.
+    pub fn read_from(dir: &Path) -> Result<Option<Self>> {
+        match File::open(dir.join(SNAPSHOT_MANIFEST_FILE_NAME)) {
+            Ok(file) => {
+                let manifest =
+                    serde_json::from_reader(BufReader::new(file)).map_err(io::Error::from)?;
+                Ok(Some(manifest))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn write_to(&self, dir: &Path) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(dir.join(SNAPSHOT_MANIFEST_FILE_NAME))?);
+        serde_json::to_writer_pretty(&mut writer, self).map_err(io::Error::from)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Returns the most recent incremental snapshot of the `db_name` database in the snapshots directory.
+pub(crate) fn latest_incremental_snapshot(
+    snapshots_path: &Path,
+    db_name: &str,
+) -> Result<Option<(PathBuf, SnapshotManifest)>> {
+    let mut latest: Option<(PathBuf, SnapshotManifest)> = None;
+    for entry in fs::read_dir(snapshots_path)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else { continue };
+        let is_snapshot = name == format!("{db_name}.snapshot")
+            || SnapshotRetention::snapshot_date(db_name, name).is_some();
+        if !is_snapshot || !path.is_dir() {
+            continue;
+        }
+        // a snapshot with an invalid manifest is not worth failing the new snapshot
+        let Ok(Some(manifest)) = SnapshotManifest::read_from(&path) else { continue };
+        if latest.as_ref().is_none_or(|(_, latest)| latest.created_at < manifest.created_at) {
+            latest = Some((path, manifest));
+        }
+    }
+    Ok(latest)
+}
+
+/// Whether the snapshot at the given path is an incremental snapshot.
+pub fn is_incremental_snapshot(path: &Path) -> bool {
+    path.join(SNAPSHOT_MANIFEST_FILE_NAME).is_file()
+}
+
+/// Restores the incremental snapshot at `src` into the `dst` database directory.
+///
+/// The files are copied and not hard linked as the database modifies them in place.
+pub fn restore_incremental_snapshot(src: &Path, dst: &Path) -> io::Result<()> {
+    fn copy_dir(src: &Path, dst: &Path) -> io::Result<()> {
+        fs::create_dir_all(dst)?;
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            let dst = dst.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                copy_dir(&entry.path(), &dst)?;
+            } else {
+                fs::copy(entry.path(), dst)?;
+            }
+        }
+        Ok(())
+    }
+
+    copy_dir(src, dst)?;
+    fs::remove_file(dst.join(SNAPSHOT_MANIFEST_FILE_NAME))
+}
@@ -5,7 +5,7 @@ use meilisearch_types::batches::{Batch, BatchEnqueuedAt, BatchStats};
 use meilisearch_types::heed::types::{SerdeBincode, SerdeJson, Str};
 use meilisearch_types::heed::{Database, RoTxn};
 use meilisearch_types::milli::{CboRoaringBitmapCodec, RoaringBitmapCodec, BEU32};
-use meilisearch_types::tasks::{Details, Kind, Status, Task};
+use meilisearch_types::tasks::{Details, Kind, Status, Task, TaskPriority};
 use meilisearch_types::versioning::{self, VERSION_MAJOR, VERSION_MINOR, VERSION_PATCH};
 use roaring::RoaringBitmap;
 
@@ -30,11 +30,15 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
         index_mapper,
         features: _,
         webhooks: _,
+        webhook_deliveries: _,
+        webhook_retries_running: _,
+        schedules: _,
         test_breakpoint_sdr: _,
         planned_failures: _,
         run_loop_iteration: _,
         embedders: _,
         chat_settings: _,
+        chat_conversations: _,
         runtime: _,
         web_client: _,
         dsr_fuel: _,
@@ -87,6 +91,14 @@ pub fn snapshot_index_scheduler(scheduler: &IndexScheduler) -> String {
     snap.push_str(&snapshot_index_tasks(&rtxn, queue.tasks.index_tasks));
     snap.push_str("----------------------------------------------------------------------\n");
 
+    // the tasks with a normal priority are not stored, most snapshots don't have any
+    let priority_db_snapshot = snapshot_priority(&rtxn, queue.tasks.priority);
+    if !priority_db_snapshot.is_empty() {
+        snap.push_str("### Priority:\n");
+        snap.push_str(&priority_db_snapshot);
+        snap.push_str("----------------------------------------------------------------------\n");
+    }
+
     snap.push_str("### Index Mapper:\n");
     snap.push_str(&snapshot_index_mapper(&rtxn, index_mapper));
     snap.push_str("\n----------------------------------------------------------------------\n");
@@ -234,6 +246,8 @@ pub fn snapshot_task(task: &Task) -> String {
         kind,
         network,
         custom_metadata,
+        schedule_uid,
+        priority,
     } = task;
     snap.push('{');
     snap.push_str(&format!("uid: {uid}, "));
@@ -257,6 +271,12 @@ pub fn snapshot_task(task: &Task) -> String {
     if let Some(custom_metadata) = custom_metadata {
         snap.push_str(&format!("custom_metadata: {custom_metadata:?}"))
     }
+    if let Some(schedule_uid) = schedule_uid {
+        snap.push_str(&format!("schedule_uid: {schedule_uid}"))
+    }
+    if !priority.is_normal() {
+        snap.push_str(&format!("priority: {priority}"))
+    }
 
     snap.push('}');
     snap
@@ -336,6 +356,9 @@ fn snapshot_details(d: &Details) -> String {
         Details::DsrUpdate(update) => {
             format!("{{ update: {update:?} }}")
         }
+        Details::DumpImport { dump_uid, imported_indexes, skipped_indexes } => {
+            format!("{{ dump_uid: {dump_uid:?}, imported_indexes: {imported_indexes:?}, skipped_indexes: {skipped_indexes:?} }}")
+        }
     }
 }
 
@@ -363,6 +386,19 @@ pub fn snapshot_kind(rtxn: &RoTxn, db: Database<SerdeBincode<Kind>, RoaringBitma
     snap
 }
 
+pub fn snapshot_priority(
+    rtxn: &RoTxn,
+    db: Database<SerdeBincode<TaskPriority>, RoaringBitmapCodec>,
+) -> String {
+    let mut snap = String::new();
+    let iter = db.iter(rtxn).unwrap();
+    for next in iter {
+        let (priority, task_ids) = next.unwrap();
+        writeln!(snap, "{priority} {}", snapshot_bitmap(&task_ids)).unwrap();
+    }
+    snap
+}
+
 pub fn snapshot_index_tasks(rtxn: &RoTxn, db: Database<Str, RoaringBitmapCodec>) -> String {
     let mut snap = String::new();
     let iter = db.iter(rtxn).unwrap();
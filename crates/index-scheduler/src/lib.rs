@@ -21,11 +21,13 @@ called asynchronously from any thread. These methods can either query the
 content of the scheduler or enqueue new tasks.
 */
 
+mod chat_conversations;
 mod dump;
 mod dynamic_search_rules;
 pub mod error;
 mod features;
 pub mod filter;
+mod incremental_snapshot;
 mod index_mapper;
 #[cfg(test)]
 mod insta_snapshot;
@@ -33,12 +35,15 @@ mod lru;
 mod processing;
 mod queue;
 mod scheduler;
+mod schedules;
+mod snapshot_retention;
 #[cfg(test)]
 mod test_utils;
 pub mod upgrade;
 mod utils;
 pub mod uuid_codec;
 pub mod versioning;
+mod webhook_deliveries;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 pub type TaskId = u32;
@@ -47,18 +52,23 @@ use std::collections::{BTreeMap, HashMap};
 use std::io::{self, BufReader, Read};
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use byte_unit::Byte;
+use chat_conversations::ChatConversations;
 use dump::Dump;
 pub use error::Error;
 pub use features::RoFeatures;
 use flate2::bufread::GzEncoder;
 use flate2::Compression;
+use hmac::{Hmac, Mac};
+pub use incremental_snapshot::{is_incremental_snapshot, restore_incremental_snapshot};
 use meilisearch_types::batches::Batch;
 use meilisearch_types::features::{
-    ChatCompletionSettings, InstanceTogglableFeatures, RuntimeTogglableFeatures,
+    ChatCompletionSettings, ChatConversation, ChatConversationsSettings, InstanceTogglableFeatures,
+    RuntimeTogglableFeatures,
 };
 use meilisearch_types::heed::byteorder::BE;
 use meilisearch_types::heed::types::{DecodeIgnore, SerdeJson, Str, I128};
@@ -74,23 +84,31 @@ use meilisearch_types::milli::vector::{
 use meilisearch_types::milli::{self, Index};
 use meilisearch_types::network::route::Status;
 use meilisearch_types::network::{Network, RemoteAvailability};
+use meilisearch_types::schedules::Schedule;
 use meilisearch_types::task_view::TaskView;
 use meilisearch_types::tasks::network::{
     DbTaskNetwork, NetworkTopologyChange, Origin, TaskNetwork,
 };
-use meilisearch_types::tasks::{KindWithContent, Task};
-use meilisearch_types::webhooks::{Webhook, WebhooksDumpView, WebhooksView};
+use meilisearch_types::tasks::{KindWithContent, Task, TaskPriority};
+use meilisearch_types::webhooks::{
+    DeliveryAttempt, DeliveryId, DeliveryStatus, Webhook, WebhookDelivery, WebhooksDumpView,
+    WebhooksView,
+};
 use milli::vector::db::IndexEmbeddingConfig;
 pub use queue::Query;
 use queue::Queue;
 use roaring::RoaringBitmap;
 use scheduler::Scheduler;
+use schedules::Schedules;
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+pub use snapshot_retention::SnapshotRetention;
 use time::OffsetDateTime;
 use tokio::sync::broadcast::error::RecvError;
 pub use utils::{ReqwestRequestWrapper, UreqRequestWrapper};
 use uuid::Uuid;
 use versioning::Versioning;
+use webhook_deliveries::WebhookDeliveries;
 
 use crate::dynamic_search_rules::DynamicSearchRules;
 use crate::index_mapper::IndexMapper;
@@ -167,6 +185,11 @@ pub struct IndexSchedulerOptions {
     pub ip_policy: http_client::policy::IpPolicy,
     /// Fuel for Dynamic Search Rules
     pub dsr_fuel: DsrFuel,
+    /// The snapshots to keep after each snapshot creation.
+    pub snapshot_retention: SnapshotRetention,
+    /// Whether the snapshots on disk are incremental directories reusing the
+    /// unchanged index files of the previous snapshot instead of tarballs.
+    pub incremental_snapshots: bool,
 }
 
 /// Structure which holds meilisearch's indexes and schedules the tasks
@@ -189,6 +212,8 @@ pub struct IndexScheduler {
 
     /// Stores the custom chat prompts and other settings of the indexes.
     pub(crate) chat_settings: Database<Str, SerdeJson<ChatCompletionSettings>>,
+    /// The conversations of the chat workspaces stored on the server.
+    chat_conversations: ChatConversations,
 
     /// Everything related to the processing of the tasks
     pub scheduler: scheduler::Scheduler,
@@ -198,6 +223,13 @@ pub struct IndexScheduler {
 
     /// Webhook, loaded and stored in the `persisted` database
     webhooks: Arc<Webhooks>,
+    /// The webhook notifications that failed at least once, and their retries.
+    webhook_deliveries: WebhookDeliveries,
+    /// Whether the thread retrying the webhook deliveries is running.
+    webhook_retries_running: Arc<AtomicBool>,
+
+    /// The tasks enqueued automatically following a cron expression.
+    schedules: Schedules,
 
     /// A map to retrieve the runtime representation of an embedder depending on its configuration.
     ///
@@ -206,6 +238,12 @@ pub struct IndexScheduler {
     /// to the same embeddings for the same input text.
     embedders: Arc<RwLock<HashMap<EmbedderOptions, Arc<Embedder>>>>,
 
+    /// A map to retrieve the HTTP client of the rerankers depending on their timeout in milliseconds.
+    ///
+    /// The client only depends on the timeout of the reranker configuration, so the rerankers
+    /// with the same timeout share the same client instead of building one for each search.
+    reranker_clients: Arc<RwLock<HashMap<u64, http_client::reqwest::Client>>>,
+
     /// The maximum size of the default payload for exporting documents, in bytes
     pub export_default_payload_size_bytes: Byte,
 
@@ -250,7 +288,11 @@ impl IndexScheduler {
             export_default_payload_size_bytes: self.export_default_payload_size_bytes,
 
             webhooks: self.webhooks.clone(),
+            webhook_deliveries: self.webhook_deliveries,
+            webhook_retries_running: self.webhook_retries_running.clone(),
+            schedules: self.schedules,
             embedders: self.embedders.clone(),
+            reranker_clients: self.reranker_clients.clone(),
             #[cfg(test)]
             test_breakpoint_sdr: self.test_breakpoint_sdr.clone(),
             #[cfg(test)]
@@ -259,6 +301,7 @@ impl IndexScheduler {
             run_loop_iteration: self.run_loop_iteration.clone(),
             features: self.features.clone(),
             chat_settings: self.chat_settings,
+            chat_conversations: self.chat_conversations,
             runtime: self.runtime.clone(),
             web_client: self.web_client.clone(),
             dsr_fuel: self.dsr_fuel,
@@ -270,7 +313,10 @@ impl IndexScheduler {
             + Queue::nb_db()
             + IndexMapper::nb_db()
             + features::FeatureData::nb_db()
+            + WebhookDeliveries::nb_db()
+            + Schedules::nb_db()
             + 1 // chat-prompts
+            + ChatConversations::nb_db()
             + 1 // persisted
             + 1 // legacy dynamic search rules
     }
@@ -284,6 +330,8 @@ impl IndexScheduler {
     ) -> Result<Self> {
         let mut this = Self::new_without_run(options, auth_env, from_db_version, runtime)?;
         this.run();
+        // resume the retries of the deliveries that were pending before the restart
+        this.retry_webhook_deliveries();
         Ok(this)
     }
 
@@ -337,12 +385,15 @@ impl IndexScheduler {
         let queue = Queue::new(&env, &mut wtxn, &options)?;
         let index_mapper = IndexMapper::new(&env, &mut wtxn, &options, budget)?;
         let chat_settings = env.create_database(&mut wtxn, Some(db_name::CHAT_SETTINGS))?;
+        let chat_conversations = ChatConversations::new(&env, &mut wtxn)?;
 
         let persisted = env.create_database(&mut wtxn, Some(db_name::PERSISTED))?;
         let webhooks_db = persisted.remap_data_type::<SerdeJson<Webhooks>>();
         let mut webhooks = webhooks_db.get(&wtxn, db_keys::WEBHOOKS)?.unwrap_or_default();
         webhooks
             .with_cli(options.cli_webhook_url.clone(), options.cli_webhook_authorization.clone());
+        let webhook_deliveries = WebhookDeliveries::new(&env, &mut wtxn)?;
+        let schedules = Schedules::new(&env, &mut wtxn)?;
 
         wtxn.commit()?;
 
@@ -361,7 +412,11 @@ impl IndexScheduler {
             env,
             persisted,
             webhooks: Arc::new(webhooks),
+            webhook_deliveries,
+            webhook_retries_running: Arc::new(AtomicBool::new(false)),
+            schedules,
             embedders: Default::default(),
+            reranker_clients: Default::default(),
             export_default_payload_size_bytes: options.export_default_payload_size_bytes,
 
             #[cfg(test)] // Will be replaced in `new_tests` in test environments
@@ -372,6 +427,7 @@ impl IndexScheduler {
             run_loop_iteration: Arc::new(RwLock::new(0)),
             features,
             chat_settings,
+            chat_conversations,
             runtime,
             web_client,
             dsr_fuel: options.dsr_fuel,
@@ -787,7 +843,19 @@ impl IndexScheduler {
         custom_metadata: Option<String>,
         task_network: Option<TaskNetwork>,
     ) -> Result<Task> {
-        self.register_with_custom_metadata_and_network(kind, custom_metadata, task_network, None)
+        self.register_with_priority(kind, custom_metadata, TaskPriority::default(), task_network)
+    }
+
+    /// Register a new task that the scheduler processes before the tasks with a lower priority,
+    /// as long as the order of the tasks of each index is respected.
+    pub fn register_with_priority(
+        &self,
+        kind: KindWithContent,
+        custom_metadata: Option<String>,
+        priority: TaskPriority,
+        task_network: Option<TaskNetwork>,
+    ) -> Result<Task> {
+        self.register_task(kind, custom_metadata, priority, task_network, None)
     }
 
     /// Register a new task in the scheduler, with metadata.
@@ -811,13 +879,24 @@ impl IndexScheduler {
         task_network: Option<TaskNetwork>,
         new_network: Option<Network>,
     ) -> Result<Task> {
-        // if the task doesn't delete or cancel anything and 40% of the task queue is full, we must refuse to enqueue the incoming task
-        if !matches!(&kind, KindWithContent::TaskDeletion { tasks, .. } | KindWithContent::TaskCancelation { tasks, .. } if !tasks.is_empty())
-            && (self.env.non_free_pages_size()? * 100) / self.env.info().map_size as u64
-                > TASK_SCHEDULER_SIZE_THRESHOLD_PERCENT_INT
-        {
-            return Err(Error::NoSpaceLeftInTaskQueue);
-        }
+        self.register_task(
+            kind,
+            custom_metadata,
+            TaskPriority::default(),
+            task_network,
+            new_network,
+        )
+    }
+
+    fn register_task(
+        &self,
+        kind: KindWithContent,
+        custom_metadata: Option<String>,
+        priority: TaskPriority,
+        task_network: Option<TaskNetwork>,
+        new_network: Option<Network>,
+    ) -> Result<Task> {
+        self.check_task_queue_space(&kind)?;
 
         let mut wtxn = self.env.write_txn()?;
 
@@ -838,7 +917,9 @@ impl IndexScheduler {
             &mut wtxn,
             &kind,
             custom_metadata,
+            priority,
             task_network.map(DbTaskNetwork::from),
+            None,
         )?;
 
         // If the registered task is a task cancelation
@@ -868,6 +949,64 @@ impl IndexScheduler {
         Ok(task)
     }
 
+    /// Refuses the task if 40% of the task queue is full, unless it deletes or cancels some tasks.
+    fn check_task_queue_space(&self, kind: &KindWithContent) -> Result<()> {
+        if !matches!(kind, KindWithContent::TaskDeletion { tasks, .. } | KindWithContent::TaskCancelation { tasks, .. } if !tasks.is_empty())
+            && (self.env.non_free_pages_size()? * 100) / self.env.info().map_size as u64
+                > TASK_SCHEDULER_SIZE_THRESHOLD_PERCENT_INT
+        {
+            return Err(Error::NoSpaceLeftInTaskQueue);
+        }
+        Ok(())
+    }
+
+    /// Register the task of a schedule and plan the next run of the schedule.
+    ///
+    /// The schedule is updated in the same transaction as the task is registered,
+    /// so that a run of a schedule never enqueues its task twice.
+    pub fn register_scheduled_task(
+        &self,
+        schedule_uid: Uuid,
+        kind: KindWithContent,
+        now: OffsetDateTime,
+    ) -> Result<Task> {
+        self.check_task_queue_space(&kind)?;
+
+        let mut wtxn = self.env.write_txn()?;
+        let mut schedule = self
+            .schedules
+            .get(&wtxn, schedule_uid)?
+            .ok_or(Error::ScheduleNotFound(schedule_uid))?;
+
+        let task = self.queue.register(
+            &mut wtxn,
+            &kind,
+            None,
+            TaskPriority::default(),
+            None,
+            Some(schedule_uid),
+        )?;
+
+        schedule.last_run_at = Some(now);
+        schedule.last_task_uid = Some(task.uid);
+        // the runs missed while the instance was down are skipped
+        schedule.plan_next_run(now);
+        self.schedules.put(&mut wtxn, &schedule)?;
+
+        if let Err(e) = wtxn.commit() {
+            self.queue.delete_persisted_task_data(&task)?;
+            return Err(e.into());
+        }
+
+        // notify the scheduler loop to execute a new tick
+        self.scheduler
+            .waker
+            .send(ModifiedTasks::Some { ids: RoaringBitmap::from([task.uid]) })
+            .unwrap();
+
+        Ok(task)
+    }
+
     pub fn network_no_index_for_remote(
         &self,
         remote_name: String,
@@ -1027,48 +1166,6 @@ impl IndexScheduler {
 
     /// Once the tasks changes have been committed we must send all the tasks that were updated to our webhooks
     fn notify_webhooks(&self, updated: RoaringBitmap) {
-        struct TaskReader<'a, 'b> {
-            rtxn: &'a RoTxn<'a>,
-            index_scheduler: &'a IndexScheduler,
-            tasks: &'b mut roaring::bitmap::Iter<'b>,
-            buffer: Vec<u8>,
-            written: usize,
-        }
-
-        impl Read for TaskReader<'_, '_> {
-            fn read(&mut self, mut buf: &mut [u8]) -> std::io::Result<usize> {
-                if self.buffer.is_empty() {
-                    match self.tasks.next() {
-                        None => return Ok(0),
-                        Some(task_id) => {
-                            let task = self
-                                .index_scheduler
-                                .queue
-                                .tasks
-                                .get_task(self.rtxn, task_id)
-                                .map_err(io::Error::other)?
-                                .ok_or_else(|| io::Error::other(Error::CorruptedTaskQueue))?;
-
-                            serde_json::to_writer(&mut self.buffer, &TaskView::from_task(&task))?;
-                            self.buffer.push(b'\n');
-                        }
-                    }
-                }
-
-                let mut to_write = &self.buffer[self.written..];
-                let wrote = io::copy(&mut to_write, &mut buf)?;
-                self.written += wrote as usize;
-
-                // we wrote everything and must refresh our buffer on the next call
-                if self.written == self.buffer.len() {
-                    self.written = 0;
-                    self.buffer.clear();
-                }
-
-                Ok(wrote as usize)
-            }
-        }
-
         let webhooks = self.webhooks.get_all();
         if webhooks.is_empty() {
             return;
@@ -1083,38 +1180,252 @@ impl IndexScheduler {
                 return;
             }
         };
+        let client = self.webhook_client();
+
+        std::thread::spawn(move || {
+            let mut failures = Vec::new();
+            for (uuid, webhook) in webhooks.iter() {
+                let tasks = if webhook.has_filters() {
+                    match this.webhook_tasks(&rtxn, webhook, &updated) {
+                        Ok(tasks) => tasks,
+                        Err(e) => {
+                            tracing::error!("While filtering the tasks of the webhook {uuid}: {e}");
+                            continue;
+                        }
+                    }
+                } else {
+                    updated.clone()
+                };
+                // the webhook is not interested in any of the updated tasks
+                if tasks.is_empty() {
+                    continue;
+                }
+
+                let attempt = this.send_webhook_notification(&client, &rtxn, webhook, &tasks);
+                if let Some(error) = &attempt.error {
+                    tracing::error!("While sending data to the webhook {uuid}: {error}");
+                    failures.push((*uuid, tasks, attempt));
+                }
+            }
+            drop(rtxn);
+
+            if failures.is_empty() {
+                return;
+            }
+            if let Err(e) = this.register_failed_webhook_deliveries(failures) {
+                tracing::error!("Couldn't register the failed webhook deliveries: {e}");
+            }
+            this.retry_webhook_deliveries();
+        });
+    }
+
+    fn webhook_client(&self) -> http_client::ureq::Agent {
         let config = http_client::ureq::config::Config::builder()
             .prepare(|config| config.timeout_global(Some(Duration::from_secs(30))))
             .build();
-        let client =
-            http_client::ureq::Agent::new_with_config(config, self.scheduler.ip_policy.clone());
+        http_client::ureq::Agent::new_with_config(config, self.scheduler.ip_policy.clone())
+    }
 
-        std::thread::spawn(move || {
-            for (uuid, Webhook { url, headers }) in webhooks.iter() {
-                let task_reader = TaskReader {
-                    rtxn: &rtxn,
-                    index_scheduler: &this,
-                    tasks: &mut updated.iter(),
-                    buffer: Vec::with_capacity(page_size::get()),
-                    written: 0,
-                };
+    /// Sends the given tasks to the webhook and reports how it went.
+    fn send_webhook_notification(
+        &self,
+        client: &http_client::ureq::Agent,
+        rtxn: &RoTxn,
+        webhook: &Webhook,
+        tasks: &RoaringBitmap,
+    ) -> DeliveryAttempt {
+        let attempted_at = OffsetDateTime::now_utc();
+        let task_reader = TaskReader {
+            rtxn,
+            index_scheduler: self,
+            tasks: &mut tasks.iter(),
+            buffer: Vec::with_capacity(page_size::get()),
+            written: 0,
+        };
 
+        let mut request = client
+            .post(&webhook.url)
+            .header("Content-Encoding", "gzip")
+            .header("Content-Type", "application/x-ndjson");
+        for (header_name, header_value) in webhook.headers.iter() {
+            request = request.header(header_name, header_value);
+        }
+
+        let result = match &webhook.secret {
+            // The signature is sent in the headers, so the payload must be known before sending the body.
+            Some(secret) => {
+                let mut payload = Vec::new();
+                if let Err(e) = BufReader::new(task_reader).read_to_end(&mut payload) {
+                    return DeliveryAttempt {
+                        attempted_at,
+                        status_code: None,
+                        error: Some(format!("Couldn't prepare the payload: {e}")),
+                    };
+                }
+                let timestamp = attempted_at.unix_timestamp();
+                let signature = sign_webhook_payload(secret, timestamp, &payload);
+                request = request
+                    .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string())
+                    .header(WEBHOOK_SIGNATURE_HEADER, signature);
+
+                let mut reader = GzEncoder::new(payload.as_slice(), Compression::default());
+                request.send(http_client::ureq::SendBody::from_reader(&mut reader))
+            }
+            None => {
                 let mut reader =
                     GzEncoder::new(BufReader::new(task_reader), Compression::default());
+                request.send(http_client::ureq::SendBody::from_reader(&mut reader))
+            }
+        };
+
+        match result {
+            Ok(response) => DeliveryAttempt {
+                attempted_at,
+                status_code: Some(response.status().as_u16()),
+                error: None,
+            },
+            Err(http_client::ureq::Error::StatusCode(code)) => DeliveryAttempt {
+                attempted_at,
+                status_code: Some(code),
+                error: Some(format!("The webhook answered with the status code {code}.")),
+            },
+            Err(e) => {
+                DeliveryAttempt { attempted_at, status_code: None, error: Some(e.to_string()) }
+            }
+        }
+    }
+
+    fn register_failed_webhook_deliveries(
+        &self,
+        failures: Vec<(Uuid, RoaringBitmap, DeliveryAttempt)>,
+    ) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        for (uuid, tasks, attempt) in failures {
+            self.webhook_deliveries.register_failure(
+                &mut wtxn,
+                uuid,
+                tasks.into_iter().collect(),
+                attempt,
+            )?;
+        }
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Spawns the thread in charge of retrying the pending webhook deliveries, unless it's already running.
+    ///
+    /// The thread stops by itself once there is no pending delivery anymore.
+    fn retry_webhook_deliveries(&self) {
+        if self.webhook_retries_running.swap(true, Ordering::AcqRel) {
+            return;
+        }
 
-                let mut request = client
-                    .post(url)
-                    .header("Content-Encoding", "gzip")
-                    .header("Content-Type", "application/x-ndjson");
-                for (header_name, header_value) in headers.iter() {
-                    request = request.header(header_name, header_value);
+        let this = self.private_clone();
+        let spawned =
+            std::thread::Builder::new().name(String::from("webhook-retries")).spawn(move || {
+                let client = this.webhook_client();
+                loop {
+                    if let Err(e) = this.retry_due_webhook_deliveries(&client) {
+                        tracing::error!("While retrying the webhook deliveries: {e}");
+                    }
+
+                    match this
+                        .read_txn()
+                        .and_then(|rtxn| this.webhook_deliveries.has_pending(&rtxn))
+                    {
+                        Ok(true) => (),
+                        Ok(false) => {
+                            this.webhook_retries_running.store(false, Ordering::Release);
+                            // A delivery may have been registered right before we gave up the flag,
+                            // in which case its registerer couldn't start a new thread.
+                            let pending = this
+                                .read_txn()
+                                .and_then(|rtxn| this.webhook_deliveries.has_pending(&rtxn));
+                            if !matches!(pending, Ok(true))
+                                || this.webhook_retries_running.swap(true, Ordering::AcqRel)
+                            {
+                                return;
+                            }
+                        }
+                        Err(e) => {
+                            tracing::error!("While looking for pending webhook deliveries: {e}")
+                        }
+                    }
+
+                    std::thread::sleep(Duration::from_secs(1));
+                }
+            });
+
+        if let Err(e) = spawned {
+            tracing::error!("Couldn't spawn the thread retrying the webhook deliveries: {e}");
+            self.webhook_retries_running.store(false, Ordering::Release);
+        }
+    }
+
+    /// Attempts once again all the pending deliveries whose retry delay has expired.
+    fn retry_due_webhook_deliveries(&self, client: &http_client::ureq::Agent) -> Result<()> {
+        let rtxn = self.read_txn()?;
+        let due = self.webhook_deliveries.due(&rtxn, OffsetDateTime::now_utc())?;
+        if due.is_empty() {
+            return Ok(());
+        }
+        let webhooks = self.webhooks.get_all();
+
+        let mut updated = Vec::with_capacity(due.len());
+        for mut delivery in due {
+            let mut tasks = RoaringBitmap::new();
+            for &task_id in &delivery.task_uids {
+                if self.queue.tasks.get_task(&rtxn, task_id)?.is_some() {
+                    tasks.insert(task_id);
                 }
-                if let Err(e) = request.send(http_client::ureq::SendBody::from_reader(&mut reader))
-                {
-                    tracing::error!("While sending data to the webhook {uuid}: {e}");
+            }
+
+            let webhook = webhooks.get(&delivery.webhook_uuid);
+            if let (Some(webhook), false) = (webhook, tasks.is_empty()) {
+                let attempt = self.send_webhook_notification(client, &rtxn, webhook, &tasks);
+                if let Some(error) = &attempt.error {
+                    tracing::error!(
+                        "While redelivering data to the webhook {}: {error}",
+                        delivery.webhook_uuid
+                    );
                 }
+                webhook_deliveries::record_attempt(&mut delivery, attempt);
+            } else {
+                // Either the webhook or all the tasks have been deleted, there is nothing left to deliver.
+                delivery.status = DeliveryStatus::Failed;
+                delivery.next_attempt_at = None;
             }
-        });
+            updated.push(delivery);
+        }
+        drop(rtxn);
+
+        let mut wtxn = self.env.write_txn()?;
+        for delivery in updated {
+            // The delivery may have been removed with its webhook while we were sending it.
+            if self.webhook_deliveries.get(&wtxn, delivery.uid)?.is_some() {
+                self.webhook_deliveries.put(&mut wtxn, &delivery)?;
+            }
+        }
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Returns the tasks among `tasks` that the webhook wants to be notified of.
+    fn webhook_tasks(
+        &self,
+        rtxn: &RoTxn,
+        webhook: &Webhook,
+        tasks: &RoaringBitmap,
+    ) -> Result<RoaringBitmap> {
+        let mut accepted = RoaringBitmap::new();
+        for task_id in tasks {
+            let task =
+                self.queue.tasks.get_task(rtxn, task_id)?.ok_or(Error::CorruptedTaskQueue)?;
+            if webhook.accepts(task.kind.as_kind(), task.status, &task.indexes()) {
+                accepted.insert(task_id);
+            }
+        }
+        Ok(accepted)
     }
 
     pub fn user_index_stats(&self, index_uid: &str) -> Result<IndexStats> {
@@ -1135,6 +1446,23 @@ impl IndexScheduler {
         &self.web_client
     }
 
+    /// Returns the HTTP client of the rerankers timing out after `timeout_ms`, building it on first use.
+    pub fn reranker_client(
+        &self,
+        timeout_ms: u64,
+    ) -> http_client::reqwest::Result<http_client::reqwest::Client> {
+        // optimistically return the existing client
+        if let Some(client) = self.reranker_clients.read().unwrap().get(&timeout_ms) {
+            return Ok(client.clone());
+        }
+
+        let client = http_client::reqwest::Client::builder()
+            .prepare(|inner| inner.timeout(Duration::from_millis(timeout_ms)))
+            .build_with_policies(self.ip_policy().clone(), Default::default())?;
+        self.reranker_clients.write().unwrap().insert(timeout_ms, client.clone());
+        Ok(client)
+    }
+
     pub fn features(&self) -> RoFeatures {
         self.features.features()
     }
@@ -1176,6 +1504,11 @@ impl IndexScheduler {
         let mut wtxn = self.env.write_txn()?;
         let webhooks_db = self.persisted.remap_data_type::<SerdeJson<Webhooks>>();
         webhooks_db.put(&mut wtxn, db_keys::WEBHOOKS, &webhooks)?;
+        // The deliveries of the cli webhook are kept since it can't be deleted through the API
+        let runtime = webhooks.runtime.read().unwrap();
+        self.webhook_deliveries
+            .retain_webhooks(&mut wtxn, |uuid| uuid.is_nil() || runtime.contains_key(uuid))?;
+        drop(runtime);
         wtxn.commit()?;
         self.webhooks.update_runtime(webhooks.into_runtime());
         Ok(())
@@ -1194,6 +1527,111 @@ impl IndexScheduler {
         self.webhooks.get_runtime()
     }
 
+    /// Returns the deliveries of the webhook that failed at least once, most recent first.
+    pub fn webhook_deliveries(&self, webhook_uuid: Uuid) -> Result<Vec<WebhookDelivery>> {
+        let rtxn = self.read_txn()?;
+        self.webhook_deliveries.list(&rtxn, webhook_uuid)
+    }
+
+    /// Schedules the given deliveries of the webhook to be sent again right away.
+    ///
+    /// When no delivery is specified, all the deliveries that exhausted their retries are sent again.
+    pub fn redeliver_webhook_deliveries(
+        &self,
+        webhook_uuid: Uuid,
+        delivery_uids: Option<Vec<DeliveryId>>,
+    ) -> Result<Vec<WebhookDelivery>> {
+        let mut wtxn = self.env.write_txn()?;
+        let deliveries = match delivery_uids {
+            Some(uids) => {
+                let mut deliveries = Vec::with_capacity(uids.len());
+                for uid in uids {
+                    match self.webhook_deliveries.get(&wtxn, uid)? {
+                        Some(delivery) if delivery.webhook_uuid == webhook_uuid => {
+                            deliveries.push(delivery)
+                        }
+                        _ => {
+                            return Err(Error::WebhookDeliveryNotFound {
+                                webhook: webhook_uuid,
+                                delivery: uid,
+                            })
+                        }
+                    }
+                }
+                deliveries
+            }
+            None => self
+                .webhook_deliveries
+                .list(&wtxn, webhook_uuid)?
+                .into_iter()
+                .filter(|delivery| delivery.status == DeliveryStatus::Failed)
+                .collect(),
+        };
+
+        let now = OffsetDateTime::now_utc();
+        let mut redelivered = Vec::with_capacity(deliveries.len());
+        for mut delivery in deliveries {
+            delivery.status = DeliveryStatus::Pending;
+            delivery.next_attempt_at = Some(now);
+            self.webhook_deliveries.put(&mut wtxn, &delivery)?;
+            redelivered.push(delivery);
+        }
+        wtxn.commit()?;
+
+        if !redelivered.is_empty() {
+            self.retry_webhook_deliveries();
+        }
+        Ok(redelivered)
+    }
+
+    /// Returns all the schedules, sorted by creation date.
+    pub fn schedules(&self) -> Result<Vec<Schedule>> {
+        let rtxn = self.read_txn()?;
+        self.schedules.list(&rtxn)
+    }
+
+    pub fn schedule(&self, uid: Uuid) -> Result<Schedule> {
+        let rtxn = self.read_txn()?;
+        self.schedules.get(&rtxn, uid)?.ok_or(Error::ScheduleNotFound(uid))
+    }
+
+    /// Creates or replaces a schedule.
+    pub fn put_schedule(&self, schedule: &Schedule) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.schedules.put(&mut wtxn, schedule)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    pub fn delete_schedule(&self, uid: Uuid) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        if !self.schedules.delete(&mut wtxn, uid)? {
+            return Err(Error::ScheduleNotFound(uid));
+        }
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    /// Returns the schedules that must enqueue their task at `now`.
+    pub fn due_schedules(&self, now: OffsetDateTime) -> Result<Vec<Schedule>> {
+        let rtxn = self.read_txn()?;
+        self.schedules.due(&rtxn, now)
+    }
+
+    /// Plans the next run of a schedule whose task could not be registered at `now`,
+    /// so that a broken schedule doesn't try to enqueue its task again every minute.
+    pub fn skip_scheduled_run(&self, schedule_uid: Uuid, now: OffsetDateTime) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        let Some(mut schedule) = self.schedules.get(&wtxn, schedule_uid)? else {
+            // the schedule was deleted in the meantime
+            return Ok(());
+        };
+        schedule.plan_next_run(now);
+        self.schedules.put(&mut wtxn, &schedule)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
     pub fn embedders(
         &self,
         index_uid: String,
@@ -1284,9 +1722,70 @@ impl IndexScheduler {
         Ok(())
     }
 
+    /// Deletes the settings of the chat workspace along with all its conversations.
     pub fn delete_chat_settings(&self, uid: &str) -> Result<bool> {
         let mut wtxn = self.env.write_txn()?;
         let deleted = self.chat_settings.delete(&mut wtxn, uid)?;
+        self.chat_conversations.delete_workspace(&mut wtxn, uid)?;
+        wtxn.commit()?;
+        Ok(deleted)
+    }
+
+    /// Returns the total number of conversations of the chat workspace that the requester can access
+    /// and the requested page of them, see [`ChatConversation::is_accessible_by`].
+    pub fn paginated_chat_conversations(
+        &self,
+        workspace_uid: &str,
+        requester: Option<&str>,
+        from: usize,
+        limit: usize,
+    ) -> Result<(usize, Vec<ChatConversation>)> {
+        let rtxn = self.env.read_txn()?;
+        self.chat_conversations.paginated(&rtxn, workspace_uid, requester, from, limit)
+    }
+
+    pub fn chat_conversation(
+        &self,
+        workspace_uid: &str,
+        conversation_id: &str,
+    ) -> Result<Option<ChatConversation>> {
+        let rtxn = self.env.read_txn()?;
+        self.chat_conversations.get(&rtxn, workspace_uid, conversation_id)
+    }
+
+    pub fn put_chat_conversation(
+        &self,
+        workspace_uid: &str,
+        conversation: &ChatConversation,
+    ) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.chat_conversations.put(&mut wtxn, workspace_uid, conversation)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    pub fn delete_chat_conversation(
+        &self,
+        workspace_uid: &str,
+        conversation_id: &str,
+    ) -> Result<bool> {
+        let mut wtxn = self.env.write_txn()?;
+        let deleted = self.chat_conversations.delete(&mut wtxn, workspace_uid, conversation_id)?;
+        wtxn.commit()?;
+        Ok(deleted)
+    }
+
+    /// Deletes the conversations of the chat workspace that did not receive any message
+    /// for longer than their TTL, and returns how many were deleted.
+    pub fn delete_expired_chat_conversations(
+        &self,
+        workspace_uid: &str,
+        settings: &ChatConversationsSettings,
+        now: OffsetDateTime,
+    ) -> Result<usize> {
+        let mut wtxn = self.env.write_txn()?;
+        let deleted =
+            self.chat_conversations.delete_expired(&mut wtxn, workspace_uid, settings, now)?;
         wtxn.commit()?;
         Ok(deleted)
     }
@@ -1392,6 +1891,67 @@ impl From<&CliWebhook> for Webhook {
         if let Some(ref auth) = webhook.auth {
             headers.insert("Authorization".to_string(), auth.to_string());
         }
-        Self { url: webhook.url.to_string(), headers }
+        Self::new(webhook.url.to_string(), headers)
+    }
+}
+
+/// Header containing the unix timestamp at which a signed webhook payload was sent.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "Meili-Webhook-Timestamp";
+/// Header containing the signature of a webhook payload.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "Meili-Webhook-Signature";
+
+/// Signs the uncompressed payload of a webhook along with the timestamp it was sent at.
+///
+/// Including the timestamp in the signature lets receivers reject replayed requests.
+/// Returns `sha256=` followed by the hex-encoded HMAC-SHA256 of `{timestamp}.{payload}`.
+pub fn sign_webhook_payload(secret: &str, timestamp: i64, payload: &[u8]) -> String {
+    // new_from_slice never fails for HMAC, whatever the length of the key
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(payload);
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// Streams the given tasks as NDJSON, as they are sent to the webhooks.
+struct TaskReader<'a, 'b> {
+    rtxn: &'a RoTxn<'a>,
+    index_scheduler: &'a IndexScheduler,
+    tasks: &'b mut roaring::bitmap::Iter<'b>,
+    buffer: Vec<u8>,
+    written: usize,
+}
+
+impl Read for TaskReader<'_, '_> {
+    fn read(&mut self, mut buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.buffer.is_empty() {
+            match self.tasks.next() {
+                None => return Ok(0),
+                Some(task_id) => {
+                    let task = self
+                        .index_scheduler
+                        .queue
+                        .tasks
+                        .get_task(self.rtxn, task_id)
+                        .map_err(io::Error::other)?
+                        .ok_or_else(|| io::Error::other(Error::CorruptedTaskQueue))?;
+
+                    serde_json::to_writer(&mut self.buffer, &TaskView::from_task(&task))?;
+                    self.buffer.push(b'\n');
+                }
+            }
+        }
+
+        let mut to_write = &self.buffer[self.written..];
+        let wrote = io::copy(&mut to_write, &mut buf)?;
+        self.written += wrote as usize;
+
+        // we wrote everything and must refresh our buffer on the next call
+        if self.written == self.buffer.len() {
+            self.written = 0;
+            self.buffer.clear();
+        }
+
+        Ok(wrote as usize)
     }
 }
@@ -106,6 +106,9 @@ make_enum_progress! {
         SnapshotTheIndexes,
         SnapshotTheApiKeys,
         CreateTheTarball,
+        UploadTheSnapshot,
+        WriteTheManifest,
+        ApplyTheRetentionPolicy,
     }
 }
 
@@ -124,6 +127,21 @@ make_enum_progress! {
     }
 }
 
+make_enum_progress! {
+    pub enum DumpImportProgress {
+        StartTheDumpImport,
+        CheckTheIndexes,
+        ImportTheIndexes,
+    }
+}
+
+make_enum_progress! {
+    pub enum DumpImportIndexProgress {
+        ImportTheSettings,
+        ImportTheDocuments,
+    }
+}
+
 make_enum_progress! {
     pub enum CreateIndexProgress {
         CreatingTheIndex,
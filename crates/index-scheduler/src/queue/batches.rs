@@ -324,6 +324,7 @@ impl Queue {
             statuses,
             types,
             index_uids,
+            priorities,
             canceled_by,
             before_enqueued_at,
             after_enqueued_at,
@@ -447,6 +448,28 @@ impl Queue {
             batches &= &index_batches;
         }
 
+        // There is no database for this query, we must look for the batches containing a task with the priority
+        if let Some(priorities) = &priorities {
+            let mut priority_tasks = RoaringBitmap::new();
+            for priority in priorities {
+                priority_tasks |= self.tasks.get_priority(rtxn, *priority)?;
+            }
+
+            let mut priority_batches = RoaringBitmap::new();
+            for batch_uid in &batches {
+                let contains_priority = match &processing.batch {
+                    Some(batch) if batch.uid == batch_uid => {
+                        !processing.processing.is_disjoint(&priority_tasks)
+                    }
+                    _ => !self.tasks_in_batch(rtxn, batch_uid)?.is_disjoint(&priority_tasks),
+                };
+                if contains_priority {
+                    priority_batches.insert(batch_uid);
+                }
+            }
+            batches &= priority_batches;
+        }
+
         // For the started_at filter, we need to treat the part of the batches that are processing from the part of the
         // batches that are not processing. The non-processing ones are filtered normally while the processing ones
         // are entirely removed unless the in-memory startedAt variable falls within the date filter.
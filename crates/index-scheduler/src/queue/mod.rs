@@ -16,7 +16,7 @@ use meilisearch_types::batches::BatchId;
 use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn, WithoutTls};
 use meilisearch_types::milli::{CboRoaringBitmapCodec, BEU32};
 use meilisearch_types::tasks::network::DbTaskNetwork;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task, TaskPriority};
 use roaring::RoaringBitmap;
 use time::format_description::well_known::Rfc3339;
 use time::OffsetDateTime;
@@ -67,6 +67,8 @@ pub struct Query {
     pub types: Option<Vec<Kind>>,
     /// The allowed [index ids](meilisearch_types::tasks::Task::index_uid) of the matched tasks
     pub index_uids: Option<Vec<String>>,
+    /// The allowed [priorities](`meilisearch_types::tasks::Task::priority`) of the matched tasks
+    pub priorities: Option<Vec<TaskPriority>>,
     /// The [task ids](`meilisearch_types::tasks::Task::uid`) of the [`TaskCancelation`](meilisearch_types::tasks::Task::Kind::TaskCancelation) tasks
     /// that canceled the matched tasks.
     pub canceled_by: Option<Vec<TaskId>>,
@@ -99,6 +101,7 @@ impl Query {
                 statuses: None,
                 types: None,
                 index_uids: None,
+                priorities: None,
                 canceled_by: None,
                 before_enqueued_at: None,
                 after_enqueued_at: None,
@@ -254,7 +257,9 @@ impl Queue {
         wtxn: &mut RwTxn,
         kind: &KindWithContent,
         custom_metadata: Option<String>,
+        priority: TaskPriority,
         network: Option<DbTaskNetwork>,
+        schedule_uid: Option<Uuid>,
     ) -> Result<Task> {
         let next_task_id = self.tasks.next_task_id(wtxn)?;
 
@@ -272,6 +277,8 @@ impl Queue {
             kind: kind.clone(),
             network,
             custom_metadata,
+            schedule_uid,
+            priority,
         };
         // For deletion and cancelation tasks, we want to make extra sure that they
         // don't attempt to delete/cancel tasks that are newer than themselves.
@@ -331,6 +338,8 @@ impl Queue {
                 tasks: to_delete,
             },
             None,
+            TaskPriority::default(),
+            None,
             None,
         )?;
 
@@ -4,7 +4,7 @@ use meilisearch_types::heed::types::{DecodeIgnore, SerdeBincode, SerdeJson, Str}
 use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn, WithoutTls};
 use meilisearch_types::milli::{CboRoaringBitmapCodec, RoaringBitmapCodec, BEU32};
 use meilisearch_types::tasks::network::DbTaskNetwork;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{Kind, KindWithContent, Status, Task, TaskPriority};
 use roaring::{MultiOps, RoaringBitmap};
 use time::OffsetDateTime;
 
@@ -16,7 +16,7 @@ use crate::utils::{
 use crate::{Error, Result, TaskId, BEI128};
 
 /// The number of database used by the task queue
-const NUMBER_OF_DATABASES: u32 = 8;
+const NUMBER_OF_DATABASES: u32 = 9;
 /// Database const names for the `IndexScheduler`.
 mod db_name {
     pub const ALL_TASKS: &str = "all-tasks";
@@ -28,6 +28,7 @@ mod db_name {
     pub const ENQUEUED_AT: &str = "enqueued-at";
     pub const STARTED_AT: &str = "started-at";
     pub const FINISHED_AT: &str = "finished-at";
+    pub const PRIORITY: &str = "priority";
 }
 
 pub struct TaskQueue {
@@ -49,6 +50,11 @@ pub struct TaskQueue {
     pub(crate) started_at: Database<BEI128, CboRoaringBitmapCodec>,
     /// Store the task ids of tasks which finished at a specific date
     pub(crate) finished_at: Database<BEI128, CboRoaringBitmapCodec>,
+    /// All the tasks ids grouped by their priority.
+    ///
+    /// The tasks with the normal priority are not stored, so that the databases created
+    /// before the introduction of the priorities don't need to be migrated.
+    pub(crate) priority: Database<SerdeBincode<TaskPriority>, RoaringBitmapCodec>,
 }
 
 impl TaskQueue {
@@ -62,6 +68,7 @@ impl TaskQueue {
             enqueued_at: self.enqueued_at,
             started_at: self.started_at,
             finished_at: self.finished_at,
+            priority: self.priority,
         }
     }
 
@@ -79,6 +86,7 @@ impl TaskQueue {
             enqueued_at: env.create_database(wtxn, Some(db_name::ENQUEUED_AT))?,
             started_at: env.create_database(wtxn, Some(db_name::STARTED_AT))?,
             finished_at: env.create_database(wtxn, Some(db_name::FINISHED_AT))?,
+            priority: env.create_database(wtxn, Some(db_name::PRIORITY))?,
         })
     }
 
@@ -278,6 +286,57 @@ impl TaskQueue {
         Ok(())
     }
 
+    /// Returns the tasks with the given priority.
+    pub(crate) fn get_priority(
+        &self,
+        rtxn: &RoTxn,
+        priority: TaskPriority,
+    ) -> Result<RoaringBitmap> {
+        if priority.is_normal() {
+            let mut tasks = self.all_task_ids(rtxn)?;
+            for priority in enum_iterator::all::<TaskPriority>().filter(|p| !p.is_normal()) {
+                tasks -= self.get_priority(rtxn, priority)?;
+            }
+            Ok(tasks)
+        } else {
+            Ok(self.priority.get(rtxn, &priority)?.unwrap_or_default())
+        }
+    }
+
+    /// Stores the tasks with the given priority, the normal priority is never stored.
+    pub(crate) fn put_priority(
+        &self,
+        wtxn: &mut RwTxn,
+        priority: TaskPriority,
+        bitmap: &RoaringBitmap,
+    ) -> Result<()> {
+        if !priority.is_normal() {
+            self.priority.put(wtxn, &priority, bitmap)?;
+        }
+        Ok(())
+    }
+
+    /// Updates the tasks with the given priority, the normal priority is never stored.
+    pub(crate) fn update_priority(
+        &self,
+        wtxn: &mut RwTxn,
+        priority: TaskPriority,
+        f: impl Fn(&mut RoaringBitmap),
+    ) -> Result<()> {
+        if priority.is_normal() {
+            return Ok(());
+        }
+        let mut tasks = self.get_priority(wtxn, priority)?;
+        f(&mut tasks);
+        if tasks.is_empty() {
+            self.priority.delete(wtxn, &priority)?;
+        } else {
+            self.priority.put(wtxn, &priority, &tasks)?;
+        }
+
+        Ok(())
+    }
+
     /// Convert an iterator to a `Vec` of tasks. The tasks MUST exist or a
     /// `CorruptedTaskQueue` error will be thrown.
     pub(crate) fn get_existing_tasks(
@@ -310,6 +369,10 @@ impl TaskQueue {
             bitmap.insert(task.uid);
         })?;
 
+        self.update_priority(wtxn, task.priority, |bitmap| {
+            bitmap.insert(task.uid);
+        })?;
+
         utils::insert_task_datetime(wtxn, self.enqueued_at, task.enqueued_at, task.uid)?;
 
         Ok(())
@@ -335,6 +398,7 @@ impl Queue {
             statuses,
             types,
             index_uids,
+            priorities,
             canceled_by,
             before_enqueued_at,
             after_enqueued_at,
@@ -422,6 +486,14 @@ impl Queue {
             tasks &= &index_tasks;
         }
 
+        if let Some(priorities) = priorities {
+            let mut priority_tasks = RoaringBitmap::new();
+            for priority in priorities {
+                priority_tasks |= self.tasks.get_priority(rtxn, *priority)?;
+            }
+            tasks &= &priority_tasks;
+        }
+
         // For the started_at filter, we need to treat the part of the tasks that are processing from the part of the
         // tasks that are not processing. The non-processing ones are filtered normally while the processing ones
         // are entirely removed unless the in-memory startedAt variable falls within the date filter.
@@ -66,4 +66,14 @@ impl IndexScheduler {
     ) -> Result<Vec<Task>> {
         Err(Error::RequiresEnterpriseEdition { action: "processing an S3-streaming snapshot task" })
     }
+
+    #[cfg(unix)]
+    pub async fn download_snapshot_from_s3(
+        _opts: meilisearch_types::milli::update::S3SnapshotOptions,
+        _snapshot: &str,
+        _dst_dir: &std::path::Path,
+        _ip_policy: http_client::policy::IpPolicy,
+    ) -> Result<Option<std::path::PathBuf>> {
+        Err(Error::RequiresEnterpriseEdition { action: "importing a snapshot from S3" })
+    }
 }
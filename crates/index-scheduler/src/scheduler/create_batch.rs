@@ -6,7 +6,9 @@ use meilisearch_types::index_uid::{AnyIndex, DsrIndex};
 use meilisearch_types::milli::update::{IndexDocumentsMethod, MissingDocumentPolicy};
 use meilisearch_types::settings::{Settings, Unchecked};
 use meilisearch_types::tasks::network::{DbTaskNetwork, NetworkTopologyState, Origin};
-use meilisearch_types::tasks::{BatchStopReason, Kind, KindWithContent, Status, Task};
+use meilisearch_types::tasks::{
+    BatchStopReason, Kind, KindWithContent, Status, Task, TaskPriority,
+};
 use roaring::RoaringBitmap;
 use serde_json::Value;
 use uuid::Uuid;
@@ -78,6 +80,7 @@ pub(crate) enum Batch {
     DsrClear {
         tasks: Vec<Task>,
     },
+    DumpImport(Task),
 }
 
 #[derive(Debug)]
@@ -132,6 +135,7 @@ impl Batch {
         match self {
             Batch::TaskCancelation { task, .. }
             | Batch::Dump(task)
+            | Batch::DumpImport(task)
             | Batch::IndexCreation { task, .. }
             | Batch::Export { task }
             | Batch::IndexUpdate { task, .. }
@@ -181,6 +185,7 @@ impl Batch {
             | TaskDeletions(_)
             | SnapshotCreation(_)
             | Dump(_)
+            | DumpImport(_)
             | Export { .. }
             | UpgradeDatabase { .. }
             | NetworkReady { .. }
@@ -206,6 +211,7 @@ impl fmt::Display for Batch {
             Batch::TaskDeletions(_) => f.write_str("TaskDeletion")?,
             Batch::SnapshotCreation(_) => f.write_str("SnapshotCreation")?,
             Batch::Dump(_) => f.write_str("Dump")?,
+            Batch::DumpImport(_) => f.write_str("DumpImport")?,
             Batch::IndexOperation { op, .. } => write!(f, "{op}")?,
             Batch::IndexCreation { .. } => f.write_str("IndexCreation")?,
             Batch::IndexUpdate { .. } => f.write_str("IndexUpdate")?,
@@ -519,7 +525,10 @@ impl IndexScheduler {
     /// 3. We get the *next* export to process.
     /// 4. We get the *next* snapshot to process.
     /// 5. We get the *next* dump to process.
-    /// 6. We get the *next* tasks to process for a specific index.
+    /// 6. We get the *next* dump import to process.
+    /// 7. We get the *next* tasks to process for a specific index.
+    ///    The index is the one of the enqueued task with the highest priority,
+    ///    the tasks of this index are still batched in their enqueue order.
     #[tracing::instrument(level = "trace", skip(self, rtxn), target = "indexing::scheduler")]
     pub(crate) fn create_next_batch(
         &self,
@@ -648,9 +657,22 @@ impl IndexScheduler {
             return Ok(Some((Batch::Dump(task), current_batch)));
         }
 
+        // 8. we batch the dump imports.
+        let to_import = self.queue.tasks.get_kind(rtxn, Kind::DumpImport)? & enqueued;
+        if let Some(to_import) = to_import.min() {
+            let mut task =
+                self.queue.tasks.get_task(rtxn, to_import)?.ok_or(Error::CorruptedTaskQueue)?;
+            current_batch.processing(Some(&mut task));
+            current_batch.reason(BatchStopReason::TaskCannotBeBatched {
+                kind: Kind::DumpImport,
+                id: task.uid,
+            });
+            return Ok(Some((Batch::DumpImport(task), current_batch)));
+        }
+
         let network = self.network();
 
-        // 8. We make a batch from the unprioritised tasks.
+        // 9. We make a batch from the unprioritised tasks.
         let (batch, current_batch) =
             self.create_next_batch_unprioritized(rtxn, enqueued, current_batch, |task| {
                 // We want to execute all tasks, except those that have a version strictly higher than the network version
@@ -680,7 +702,12 @@ impl IndexScheduler {
     {
         let count_total_enqueued = enqueued.len();
 
-        let mut enqueued_it = enqueued.iter();
+        // The task with the highest priority decides which index is batched,
+        // between tasks of the same priority the oldest one is picked.
+        let high = self.queue.tasks.get_priority(rtxn, TaskPriority::High)? & enqueued;
+        let low = self.queue.tasks.get_priority(rtxn, TaskPriority::Low)? & enqueued;
+        let normal = enqueued - &high - &low;
+        let mut enqueued_it = [high, normal, low].into_iter().flat_map(|tasks| tasks.into_iter());
         let mut task;
         let index_name = loop {
             let Some(task_id) = enqueued_it.next() else {
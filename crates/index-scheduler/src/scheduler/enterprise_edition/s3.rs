@@ -104,6 +104,13 @@ impl IndexScheduler {
         use std::ffi::OsStr;
 
         use meilisearch_types::milli::update::S3SnapshotOptions;
+        use time::OffsetDateTime;
+
+        use crate::processing::SnapshotCreationProgress;
+
+        if self.scheduler.incremental_snapshots {
+            return self.process_incremental_snapshot_to_s3(progress, opts, tasks).await;
+        }
 
         let S3SnapshotOptions {
             s3_bucket_url,
@@ -128,8 +135,13 @@ impl IndexScheduler {
             base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms").to_string()
         };
 
+        let now = OffsetDateTime::now_utc();
+        let retention = self.scheduler.snapshot_retention;
+        let snapshot_name = retention.snapshot_name(&db_name, now);
+
         let (reader, writer) = std::io::pipe()?;
         let ip_policy = self.scheduler.ip_policy.clone();
+        let uploader_progress = progress.clone();
 
         let uploader_task = tokio::spawn(async move {
             let (s3_access_key, s3_secret_key, s3_token) = Self::extract_credentials_from_options(
@@ -140,25 +152,44 @@ impl IndexScheduler {
                 ip_policy.clone(),
             )
             .await?;
+            let bucket = s3_bucket(s3_bucket_url, s3_bucket_name, s3_bucket_region)?;
+            let credential = s3_credentials(s3_access_key, s3_secret_key, s3_token);
+            let client = http_client::reqwest::Client::builder()
+                .build_with_policies(ip_policy, Default::default())
+                .unwrap();
 
             multipart_stream_to_s3(
-                s3_bucket_url,
-                s3_bucket_region,
-                s3_bucket_name,
-                s3_snapshot_prefix,
-                s3_access_key,
-                s3_secret_key,
-                s3_token,
+                &client,
+                &bucket,
+                &credential,
+                &s3_object_key(&s3_snapshot_prefix, &snapshot_name),
                 s3_max_in_flight_parts,
                 s3_signature_duration,
                 s3_multipart_part_size,
                 must_stop_processing,
-                retry_backoff,
-                db_name,
+                retry_backoff.clone(),
                 reader,
-                ip_policy,
             )
-            .await
+            .await?;
+
+            if retention.is_enabled() {
+                uploader_progress
+                    .update_progress(SnapshotCreationProgress::ApplyTheRetentionPolicy);
+                apply_s3_retention_policy(
+                    &client,
+                    &bucket,
+                    &credential,
+                    &s3_snapshot_prefix,
+                    &db_name,
+                    now,
+                    &retention,
+                    s3_signature_duration,
+                    retry_backoff.clone(),
+                )
+                .await?;
+            }
+
+            Result::<_, Error>::Ok(())
         });
 
         let index_scheduler = IndexScheduler::private_clone(self);
@@ -179,6 +210,395 @@ impl IndexScheduler {
 
         Ok(tasks)
     }
+
+    /// Creates an incremental snapshot in the S3 bucket.
+    ///
+    /// The files of the snapshot are stored as separate objects under the prefix of the snapshot,
+    /// with the same layout as an incremental snapshot on disk. The index files that did not change
+    /// since the previous incremental snapshot are copied server-side from it, and the manifest is
+    /// uploaded last to mark the snapshot as complete.
+    async fn process_incremental_snapshot_to_s3(
+        &self,
+        progress: Progress,
+        opts: meilisearch_types::milli::update::S3SnapshotOptions,
+        tasks: Vec<Task>,
+    ) -> Result<Vec<Task>> {
+        use std::collections::{BTreeMap, BTreeSet};
+        use std::ffi::OsStr;
+
+        use meilisearch_types::milli::update::S3SnapshotOptions;
+        use meilisearch_types::versioning::{VERSION_MAJOR, VERSION_MINOR, VERSION_PATCH};
+        use rusty_s3::S3Action as _;
+        use time::OffsetDateTime;
+
+        use crate::incremental_snapshot::{SnapshotManifest, SNAPSHOT_MANIFEST_FILE_NAME};
+        use crate::processing::SnapshotCreationProgress;
+
+        let S3SnapshotOptions {
+            s3_bucket_url,
+            s3_bucket_region,
+            s3_bucket_name,
+            s3_snapshot_prefix,
+            s3_access_key,
+            s3_secret_key,
+            s3_role_arn,
+            s3_web_identity_token_file,
+            s3_max_in_flight_parts,
+            s3_compression_level: _,
+            s3_signature_duration,
+            s3_multipart_part_size,
+        } = opts;
+
+        let must_stop_processing = self.scheduler.must_stop_processing.clone();
+        let retry_backoff = backoff::ExponentialBackoff::default();
+        let db_name = {
+            let mut base_path = self.env.path().to_owned();
+            base_path.pop();
+            base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms").to_string()
+        };
+
+        let now = OffsetDateTime::now_utc();
+        let retention = self.scheduler.snapshot_retention;
+        let snapshot_name = retention.snapshot_name(&db_name, now);
+        let version = format!("{VERSION_MAJOR}.{VERSION_MINOR}.{VERSION_PATCH}");
+
+        let ip_policy = self.scheduler.ip_policy.clone();
+        let (s3_access_key, s3_secret_key, s3_token) = Self::extract_credentials_from_options(
+            s3_access_key,
+            s3_secret_key,
+            s3_role_arn,
+            s3_web_identity_token_file,
+            ip_policy.clone(),
+        )
+        .await?;
+        let bucket = s3_bucket(s3_bucket_url, s3_bucket_name, s3_bucket_region)?;
+        let credential = s3_credentials(s3_access_key, s3_secret_key, s3_token);
+        let client = http_client::reqwest::Client::builder()
+            .build_with_policies(ip_policy, Default::default())
+            .unwrap();
+
+        // 1. Find the previous incremental snapshot and only reuse
+        //    the files of a snapshot made by the same version.
+        let snapshots = list_s3_snapshots(
+            &client,
+            &bucket,
+            &credential,
+            &s3_snapshot_prefix,
+            s3_signature_duration,
+            retry_backoff.clone(),
+        )
+        .await?;
+        let mut previous = None;
+        if let Some(latest) = latest_s3_snapshot(snapshots.iter().filter(|s| s.incremental)) {
+            let dir = s3_object_key(&s3_snapshot_prefix, &latest.name);
+            let object = s3_object_key(&dir, SNAPSHOT_MANIFEST_FILE_NAME);
+            let action = bucket.get_object(Some(&credential), &object);
+            let request = client.get(action.sign(s3_signature_duration));
+            let body = send_s3_request(request, retry_backoff.clone()).await?;
+            // a snapshot with an invalid manifest is not worth failing the new snapshot
+            let manifest = serde_json::from_str::<SnapshotManifest>(&body)
+                .ok()
+                .filter(|manifest| manifest.version == version);
+            if let Some(manifest) = manifest {
+                let objects = list_s3_objects(
+                    &client,
+                    &bucket,
+                    &credential,
+                    &s3_object_key(&dir, ""),
+                    s3_signature_duration,
+                    retry_backoff.clone(),
+                )
+                .await?;
+                let sizes: BTreeMap<_, _> =
+                    objects.into_iter().map(|object| (object.key, object.size)).collect();
+                previous = Some((dir, manifest, sizes));
+            }
+        }
+
+        // 2. Copy the database in a temporary directory, except for the unchanged indexes
+        let temp_snapshot_dir = tempfile::tempdir()?;
+        let index_scheduler = IndexScheduler::private_clone(self);
+        let builder_progress = progress.clone();
+        let builder_task = tokio::task::spawn_blocking(move || {
+            let mut manifest = SnapshotManifest::new(version, now);
+            let mut reused = Vec::new();
+            index_scheduler.copy_database_into(
+                &builder_progress,
+                &tasks,
+                temp_snapshot_dir.path(),
+                Some(&mut manifest),
+                previous.as_ref().map(|(_, manifest, _)| manifest),
+                |uuid, _dst| {
+                    let Some((dir, _, sizes)) = previous.as_ref() else { return Ok(false) };
+                    let file = format!("indexes/{uuid}/data.mdb");
+                    let object = s3_object_key(dir, &file);
+                    // the larger files can't be copied server-side and are uploaded again
+                    if sizes.get(&object).is_some_and(|size| *size <= MAX_COPY_OBJECT_SIZE) {
+                        reused.push((object, file));
+                        Ok(true)
+                    } else {
+                        Ok(false)
+                    }
+                },
+            )?;
+            Result::<_, Error>::Ok((temp_snapshot_dir, manifest, reused, tasks))
+        });
+        // safety: JoinHandle can return an error if the task was aborted, cancelled, or panicked.
+        let (temp_snapshot_dir, manifest, reused, mut tasks) = builder_task.await.unwrap()?;
+
+        // 3. Copy the unchanged index files and upload the other files
+        progress.update_progress(SnapshotCreationProgress::UploadTheSnapshot);
+        let snapshot_dir = s3_object_key(&s3_snapshot_prefix, &snapshot_name);
+        let mut uploaded = BTreeSet::new();
+        for (src, file) in reused {
+            if must_stop_processing.get() {
+                return Err(Error::AbortedTask);
+            }
+            let dst = s3_object_key(&snapshot_dir, &file);
+            // the previous snapshot is overwritten when the retention policy is disabled
+            if src != dst {
+                copy_s3_object(
+                    &client,
+                    &bucket,
+                    &credential,
+                    &src,
+                    &dst,
+                    s3_signature_duration,
+                    retry_backoff.clone(),
+                )
+                .await?;
+            }
+            uploaded.insert(dst);
+        }
+
+        for path in files_of_dir(temp_snapshot_dir.path())? {
+            if must_stop_processing.get() {
+                return Err(Error::AbortedTask);
+            }
+            // safety: the files are listed from this directory
+            let file = path.strip_prefix(temp_snapshot_dir.path()).unwrap();
+            let object = s3_object_key(&snapshot_dir, &file.display().to_string());
+            upload_file_to_s3(
+                &client,
+                &bucket,
+                &credential,
+                &object,
+                &path,
+                s3_max_in_flight_parts,
+                s3_signature_duration,
+                s3_multipart_part_size,
+                must_stop_processing.clone(),
+                retry_backoff.clone(),
+            )
+            .await?;
+            uploaded.insert(object);
+        }
+
+        // 4. Remove the files of the overwritten snapshot that are not part of the new one
+        let manifest_object = s3_object_key(&snapshot_dir, SNAPSHOT_MANIFEST_FILE_NAME);
+        let objects = list_s3_objects(
+            &client,
+            &bucket,
+            &credential,
+            &s3_object_key(&snapshot_dir, ""),
+            s3_signature_duration,
+            retry_backoff.clone(),
+        )
+        .await?;
+        for object in objects {
+            if object.key != manifest_object && !uploaded.contains(&object.key) {
+                delete_s3_object(
+                    &client,
+                    &bucket,
+                    &credential,
+                    &object.key,
+                    s3_signature_duration,
+                    retry_backoff.clone(),
+                )
+                .await?;
+            }
+        }
+
+        // 5. Upload the manifest to mark the snapshot as complete
+        progress.update_progress(SnapshotCreationProgress::WriteTheManifest);
+        let manifest = serde_json::to_vec_pretty(&manifest).map_err(std::io::Error::from)?;
+        put_s3_object(
+            &client,
+            &bucket,
+            &credential,
+            &manifest_object,
+            manifest,
+            s3_signature_duration,
+            retry_backoff.clone(),
+        )
+        .await?;
+
+        // a full snapshot with the same name is replaced by the incremental one
+        if snapshots.iter().any(|s| s.name == snapshot_name && !s.incremental) {
+            delete_s3_object(
+                &client,
+                &bucket,
+                &credential,
+                &snapshot_dir,
+                s3_signature_duration,
+                retry_backoff.clone(),
+            )
+            .await?;
+        }
+
+        // 6. Remove the snapshots that are not retained anymore
+        if retention.is_enabled() {
+            progress.update_progress(SnapshotCreationProgress::ApplyTheRetentionPolicy);
+            apply_s3_retention_policy(
+                &client,
+                &bucket,
+                &credential,
+                &s3_snapshot_prefix,
+                &db_name,
+                now,
+                &retention,
+                s3_signature_duration,
+                retry_backoff,
+            )
+            .await?;
+        }
+
+        for task in &mut tasks {
+            task.status = Status::Succeeded;
+        }
+
+        Ok(tasks)
+    }
+
+    /// Downloads a snapshot of the S3 bucket into the `dst_dir` directory.
+    ///
+    /// `snapshot` is either the name of a snapshot stored under the snapshot prefix or `latest`
+    /// to download the most recently uploaded one. Returns the path of the downloaded snapshot,
+    /// a file or the directory of an incremental snapshot, or `None` if there is no such snapshot.
+    pub async fn download_snapshot_from_s3(
+        opts: meilisearch_types::milli::update::S3SnapshotOptions,
+        snapshot: &str,
+        dst_dir: &std::path::Path,
+        ip_policy: http_client::policy::IpPolicy,
+    ) -> Result<Option<std::path::PathBuf>> {
+        use meilisearch_types::milli::update::S3SnapshotOptions;
+
+        use crate::incremental_snapshot::SNAPSHOT_MANIFEST_FILE_NAME;
+
+        let S3SnapshotOptions {
+            s3_bucket_url,
+            s3_bucket_region,
+            s3_bucket_name,
+            s3_snapshot_prefix,
+            s3_access_key,
+            s3_secret_key,
+            s3_role_arn,
+            s3_web_identity_token_file,
+            s3_max_in_flight_parts: _,
+            s3_compression_level: _,
+            s3_signature_duration,
+            s3_multipart_part_size: _,
+        } = opts;
+
+        let retry_backoff = backoff::ExponentialBackoff::default();
+        let (s3_access_key, s3_secret_key, s3_token) = Self::extract_credentials_from_options(
+            s3_access_key,
+            s3_secret_key,
+            s3_role_arn,
+            s3_web_identity_token_file,
+            ip_policy.clone(),
+        )
+        .await?;
+        let bucket = s3_bucket(s3_bucket_url, s3_bucket_name, s3_bucket_region)?;
+        let credential = s3_credentials(s3_access_key, s3_secret_key, s3_token);
+        let client = http_client::reqwest::Client::builder()
+            .build_with_policies(ip_policy, Default::default())
+            .unwrap();
+
+        let (name, incremental) = if snapshot == "latest" {
+            let snapshots = list_s3_snapshots(
+                &client,
+                &bucket,
+                &credential,
+                &s3_snapshot_prefix,
+                s3_signature_duration,
+                retry_backoff.clone(),
+            )
+            .await?;
+            match latest_s3_snapshot(&snapshots) {
+                Some(snapshot) => (snapshot.name.clone(), Some(snapshot.incremental)),
+                None => return Ok(None),
+            }
+        } else if snapshot.ends_with(".snapshot") {
+            (snapshot.to_string(), None)
+        } else {
+            (format!("{snapshot}.snapshot"), None)
+        };
+
+        let object = s3_object_key(&s3_snapshot_prefix, &name);
+        let dst = dst_dir.join(&name);
+
+        // a snapshot given by name is first looked up as a file
+        if incremental != Some(true) {
+            tracing::info!("Downloading the snapshot {object} from S3");
+            let found = download_s3_object(
+                &client,
+                &bucket,
+                &credential,
+                &object,
+                &dst,
+                s3_signature_duration,
+                retry_backoff.clone(),
+            )
+            .await?;
+            if found || incremental == Some(false) {
+                return Ok(found.then_some(dst));
+            }
+        }
+
+        // the incremental snapshots are only complete once their manifest is uploaded
+        let dir = s3_object_key(&object, "");
+        let objects = list_s3_objects(
+            &client,
+            &bucket,
+            &credential,
+            &dir,
+            s3_signature_duration,
+            retry_backoff.clone(),
+        )
+        .await?;
+        let manifest_object = s3_object_key(&object, SNAPSHOT_MANIFEST_FILE_NAME);
+        if !objects.iter().any(|object| object.key == manifest_object) {
+            return Ok(None);
+        }
+
+        tracing::info!("Downloading the incremental snapshot {object} from S3");
+        for object in objects {
+            // safety: the objects are listed with this prefix
+            let path = dst.join(object.key.strip_prefix(&dir).unwrap());
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            let found = download_s3_object(
+                &client,
+                &bucket,
+                &credential,
+                &object.key,
+                &path,
+                s3_signature_duration,
+                retry_backoff.clone(),
+            )
+            .await?;
+            if !found {
+                return Err(Error::S3Error {
+                    status: http_client::reqwest::StatusCode::NOT_FOUND,
+                    body: format!("the object {} of the snapshot disappeared", object.key),
+                });
+            }
+        }
+
+        Ok(Some(dst))
+    }
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -377,58 +797,476 @@ where
     Ok(())
 }
 
-/// Streams the content read from the given reader to S3.
-#[allow(clippy::too_many_arguments)]
-async fn multipart_stream_to_s3(
+/// Returns the key of the `name` object under the snapshot prefix.
+fn s3_object_key(s3_snapshot_prefix: &str, name: &str) -> String {
+    // Note for the future (rust 1.91+): use with_added_extension, it's prettier
+    let object_path = std::path::PathBuf::from(s3_snapshot_prefix).join(name);
+    // Note: It doesn't work on Windows and if a port to this platform is needed,
+    //       use the slash-path crate or similar to get the correct path separator.
+    object_path.display().to_string()
+}
+
+fn s3_bucket(
     s3_bucket_url: String,
-    s3_bucket_region: String,
     s3_bucket_name: String,
-    s3_snapshot_prefix: String,
+    s3_bucket_region: String,
+) -> Result<rusty_s3::Bucket> {
+    use rusty_s3::{Bucket, BucketError, UrlStyle};
+
+    let url =
+        s3_bucket_url.parse().map_err(BucketError::ParseError).map_err(Error::S3BucketError)?;
+    Bucket::new(url, UrlStyle::Path, s3_bucket_name, s3_bucket_region).map_err(Error::S3BucketError)
+}
+
+fn s3_credentials(
     s3_access_key: String,
     s3_secret_key: String,
     s3_token: Option<String>,
+) -> rusty_s3::Credentials {
+    use rusty_s3::Credentials;
+
+    match s3_token {
+        Some(token) => Credentials::new_with_token(s3_access_key, s3_secret_key, token),
+        None => Credentials::new(s3_access_key, s3_secret_key),
+    }
+}
+
+/// Sends a bodyless request to S3, retrying on server errors, and returns the response body.
+async fn send_s3_request(
+    request: http_client::reqwest::RequestBuilder,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<String> {
+    use http_client::reqwest::StatusCode;
+
+    backoff::future::retry(retry_backoff, move || {
+        // safety: it fails only with stream body. We send a request without a body.
+        let request = request.try_clone().unwrap();
+        let request = request.send();
+        async {
+            let resp =
+                request.await.map_err(Error::S3HttpError).map_err(backoff::Error::transient)?;
+            let status = resp.status();
+            let result = resp.text().await;
+            match status {
+                status if status.is_success() => result
+                    .map_err(http_client::reqwest::Error::from)
+                    .map_err(Error::S3HttpError)
+                    .map_err(backoff::Error::transient),
+                status if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS => {
+                    Err(backoff::Error::Permanent(Error::S3Error {
+                        status,
+                        body: result.unwrap_or_default(),
+                    }))
+                }
+                _ => Err(backoff::Error::transient(Error::S3Error {
+                    status,
+                    body: result.unwrap_or_default(),
+                })),
+            }
+        }
+    })
+    .await
+}
+
+/// The maximum size of an object that can be copied server-side in a single request.
+const MAX_COPY_OBJECT_SIZE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// An object stored in the bucket.
+struct S3Object {
+    key: String,
+    size: u64,
+    /// The RFC 3339 date at which the object was last modified.
+    last_modified: String,
+}
+
+/// Lists the objects whose key starts with the given prefix.
+async fn list_s3_objects(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    prefix: &str,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<Vec<S3Object>> {
+    use rusty_s3::actions::ListObjectsV2;
+    use rusty_s3::S3Action as _;
+
+    let mut objects = Vec::new();
+    let mut continuation_token = None;
+    loop {
+        let mut action = bucket.list_objects_v2(Some(credential));
+        action.with_prefix(prefix);
+        if let Some(token) = continuation_token.take() {
+            action.with_continuation_token(token);
+        }
+        let request = client.get(action.sign(s3_signature_duration));
+        let body = send_s3_request(request, retry_backoff.clone()).await?;
+        let response =
+            ListObjectsV2::parse_response(&body).map_err(|e| Error::S3XmlError(Box::new(e)))?;
+
+        objects.extend(response.contents.into_iter().map(|object| S3Object {
+            key: object.key,
+            size: object.size,
+            last_modified: object.last_modified,
+        }));
+
+        match response.next_continuation_token {
+            Some(token) => continuation_token = Some(token),
+            None => break,
+        }
+    }
+
+    Ok(objects)
+}
+
+/// A snapshot stored directly under the snapshot prefix of the bucket.
+struct S3Snapshot {
+    /// The name of the object without the prefix.
+    name: String,
+    /// The RFC 3339 date at which the object, or the manifest
+    /// of an incremental snapshot, was last modified.
+    last_modified: String,
+    /// Whether the snapshot is stored as one object per file under its name.
+    incremental: bool,
+}
+
+/// Lists the snapshots stored directly under the snapshot prefix of the bucket.
+///
+/// The incremental snapshots are only listed once their manifest is uploaded.
+async fn list_s3_snapshots(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    s3_snapshot_prefix: &str,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<Vec<S3Snapshot>> {
+    use crate::incremental_snapshot::SNAPSHOT_MANIFEST_FILE_NAME;
+
+    let prefix = s3_object_key(s3_snapshot_prefix, "");
+    let objects =
+        list_s3_objects(client, bucket, credential, &prefix, s3_signature_duration, retry_backoff)
+            .await?;
+
+    let snapshots = objects.into_iter().filter_map(|object| {
+        let name = object.key.strip_prefix(&prefix)?;
+        let (name, incremental) = match name.split_once('/') {
+            None => (name, false),
+            Some((name, SNAPSHOT_MANIFEST_FILE_NAME)) => (name, true),
+            Some(_) => return None,
+        };
+        name.ends_with(".snapshot").then(|| S3Snapshot {
+            name: name.to_string(),
+            last_modified: object.last_modified,
+            incremental,
+        })
+    });
+
+    Ok(snapshots.collect())
+}
+
+/// Returns the most recently uploaded snapshot.
+fn latest_s3_snapshot<'a>(
+    snapshots: impl IntoIterator<Item = &'a S3Snapshot>,
+) -> Option<&'a S3Snapshot> {
+    // RFC 3339 dates of the same format can be compared lexicographically
+    snapshots
+        .into_iter()
+        .max_by(|a, b| (&a.last_modified, &a.name).cmp(&(&b.last_modified, &b.name)))
+}
+
+/// Removes the snapshots that are not retained anymore from the bucket.
+#[allow(clippy::too_many_arguments)]
+async fn apply_s3_retention_policy(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    s3_snapshot_prefix: &str,
+    db_name: &str,
+    now: time::OffsetDateTime,
+    retention: &crate::SnapshotRetention,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<()> {
+    use crate::incremental_snapshot::SNAPSHOT_MANIFEST_FILE_NAME;
+
+    let snapshots = list_s3_snapshots(
+        client,
+        bucket,
+        credential,
+        s3_snapshot_prefix,
+        s3_signature_duration,
+        retry_backoff.clone(),
+    )
+    .await?;
+    let names = snapshots.iter().map(|snapshot| snapshot.name.as_str());
+    let expired = retention.expired_snapshots(db_name, names, now);
+
+    for snapshot in snapshots.iter().filter(|snapshot| expired.contains(&snapshot.name.as_str())) {
+        tracing::info!("Removing the expired snapshot {} from S3", snapshot.name);
+        let object = s3_object_key(s3_snapshot_prefix, &snapshot.name);
+        if !snapshot.incremental {
+            delete_s3_object(
+                client,
+                bucket,
+                credential,
+                &object,
+                s3_signature_duration,
+                retry_backoff.clone(),
+            )
+            .await?;
+            continue;
+        }
+
+        // the manifest is removed first for the snapshot to never be listed while incomplete
+        let manifest_object = s3_object_key(&object, SNAPSHOT_MANIFEST_FILE_NAME);
+        delete_s3_object(
+            client,
+            bucket,
+            credential,
+            &manifest_object,
+            s3_signature_duration,
+            retry_backoff.clone(),
+        )
+        .await?;
+        let objects = list_s3_objects(
+            client,
+            bucket,
+            credential,
+            &s3_object_key(&object, ""),
+            s3_signature_duration,
+            retry_backoff.clone(),
+        )
+        .await?;
+        for object in objects {
+            delete_s3_object(
+                client,
+                bucket,
+                credential,
+                &object.key,
+                s3_signature_duration,
+                retry_backoff.clone(),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn delete_s3_object(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    object: &str,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<()> {
+    use rusty_s3::S3Action as _;
+
+    let action = bucket.delete_object(Some(credential), object);
+    let request = client.delete(action.sign(s3_signature_duration));
+    send_s3_request(request, retry_backoff).await.map(drop)
+}
+
+async fn put_s3_object(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    object: &str,
+    content: Vec<u8>,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<()> {
+    use rusty_s3::S3Action as _;
+
+    let action = bucket.put_object(Some(credential), object);
+    let request =
+        client.put(action.sign(s3_signature_duration)).prepare(|inner| inner.body(content));
+    send_s3_request(request, retry_backoff).await.map(drop)
+}
+
+/// Copies the `src` object of the bucket to the `dst` object without downloading it.
+async fn copy_s3_object(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    src: &str,
+    dst: &str,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<()> {
+    use http_client::reqwest::StatusCode;
+    use rusty_s3::S3Action as _;
+
+    const COPY_SOURCE_HEADER: &str = "x-amz-copy-source";
+
+    let copy_source = format!("/{}/{src}", bucket.name());
+    let mut action = bucket.put_object(Some(credential), dst);
+    action.headers_mut().insert(COPY_SOURCE_HEADER, copy_source.clone());
+    let request = client
+        .put(action.sign(s3_signature_duration))
+        .prepare(|inner| inner.header(COPY_SOURCE_HEADER, copy_source));
+    let body = send_s3_request(request, retry_backoff).await?;
+
+    // S3 can report a failed copy with a successful status and an error in the body
+    if body.contains("<Error>") {
+        return Err(Error::S3Error { status: StatusCode::INTERNAL_SERVER_ERROR, body });
+    }
+
+    Ok(())
+}
+
+/// Uploads the file at `path` to the `object` of the bucket, with a multipart upload if
+/// the file is larger than a part.
+#[allow(clippy::too_many_arguments)]
+async fn upload_file_to_s3(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    object: &str,
+    path: &std::path::Path,
+    s3_max_in_flight_parts: std::num::NonZero<usize>,
+    s3_signature_duration: std::time::Duration,
+    s3_multipart_part_size: u64,
+    must_stop_processing: milli::MustStopProcessing,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<()> {
+    let mut file = std::fs::File::open(path)?;
+    if file.metadata()?.len() < s3_multipart_part_size {
+        let content = tokio::fs::read(path).await?;
+        return put_s3_object(
+            client,
+            bucket,
+            credential,
+            object,
+            content,
+            s3_signature_duration,
+            retry_backoff,
+        )
+        .await;
+    }
+
+    let (reader, mut writer) = std::io::pipe()?;
+    let writer_task = tokio::task::spawn_blocking(move || std::io::copy(&mut file, &mut writer));
+    let upload_result = multipart_stream_to_s3(
+        client,
+        bucket,
+        credential,
+        object,
+        s3_max_in_flight_parts,
+        s3_signature_duration,
+        s3_multipart_part_size,
+        must_stop_processing,
+        retry_backoff,
+        reader,
+    )
+    .await;
+
+    // Check the upload result first as the writer fails when the upload is aborted.
+    upload_result?;
+    // safety: JoinHandle can return an error if the task was aborted, cancelled, or panicked.
+    writer_task.await.unwrap()?;
+
+    Ok(())
+}
+
+/// Downloads the `object` of the bucket into the `dst` file.
+///
+/// Returns `false` if there is no such object.
+async fn download_s3_object(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    object: &str,
+    dst: &std::path::Path,
+    s3_signature_duration: std::time::Duration,
+    retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
+) -> Result<bool> {
+    use http_client::reqwest::StatusCode;
+    use rusty_s3::S3Action as _;
+    use tokio::io::AsyncWriteExt as _;
+
+    let url = bucket.get_object(Some(credential), object).sign(s3_signature_duration);
+    let mut resp = backoff::future::retry(retry_backoff, || {
+        let request = client.get(url.clone()).send();
+        async {
+            match request.await {
+                Ok(resp) if resp.status().is_server_error() => {
+                    Err(backoff::Error::transient(Error::S3Error {
+                        status: resp.status(),
+                        body: resp.text().await.unwrap_or_default(),
+                    }))
+                }
+                Ok(resp) => Ok(resp),
+                Err(e) => Err(backoff::Error::transient(Error::S3HttpError(e))),
+            }
+        }
+    })
+    .await?;
+
+    let status = resp.status();
+    if status == StatusCode::NOT_FOUND {
+        return Ok(false);
+    } else if !status.is_success() {
+        return Err(Error::S3Error { status, body: resp.text().await.unwrap_or_default() });
+    }
+
+    let mut file = tokio::fs::File::create(dst).await?;
+    while let Some(chunk) =
+        resp.chunk().await.map_err(http_client::reqwest::Error::from).map_err(Error::S3HttpError)?
+    {
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+
+    Ok(true)
+}
+
+/// Returns the paths of the files of the directory and its subdirectories.
+fn files_of_dir(dir: &std::path::Path) -> std::io::Result<Vec<std::path::PathBuf>> {
+    let mut files = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            files.extend(files_of_dir(&entry.path())?);
+        } else {
+            files.push(entry.path());
+        }
+    }
+    Ok(files)
+}
+
+/// Streams the content read from the given reader to S3.
+#[allow(clippy::too_many_arguments)]
+async fn multipart_stream_to_s3(
+    client: &http_client::reqwest::Client,
+    bucket: &rusty_s3::Bucket,
+    credential: &rusty_s3::Credentials,
+    object: &str,
     s3_max_in_flight_parts: std::num::NonZero<usize>,
     s3_signature_duration: std::time::Duration,
     s3_multipart_part_size: u64,
     must_stop_processing: milli::MustStopProcessing,
     retry_backoff: backoff::exponential::ExponentialBackoff<backoff::SystemClock>,
-    db_name: String,
     reader: std::io::PipeReader,
-    ip_policy: http_client::policy::IpPolicy,
 ) -> Result<(), Error> {
     use std::collections::VecDeque;
     use std::io;
     use std::os::fd::OwnedFd;
-    use std::path::PathBuf;
 
     use bytes::{BufMut as _, Bytes, BytesMut};
-    use http_client::reqwest::{Client, Response, StatusCode};
+    use http_client::reqwest::{Response, StatusCode};
     use rusty_s3::actions::CreateMultipartUpload;
-    use rusty_s3::{Bucket, BucketError, Credentials, S3Action as _, UrlStyle};
+    use rusty_s3::S3Action as _;
     use tokio::task::JoinHandle;
 
     let reader = OwnedFd::from(reader);
     let reader = tokio::net::unix::pipe::Receiver::from_owned_fd(reader)?;
-    let s3_snapshot_prefix = PathBuf::from(s3_snapshot_prefix);
-    let url =
-        s3_bucket_url.parse().map_err(BucketError::ParseError).map_err(Error::S3BucketError)?;
-    let bucket = Bucket::new(url, UrlStyle::Path, s3_bucket_name, s3_bucket_region)
-        .map_err(Error::S3BucketError)?;
-    let credential = match s3_token {
-        Some(token) => Credentials::new_with_token(s3_access_key, s3_secret_key, token),
-        None => Credentials::new(s3_access_key, s3_secret_key),
-    };
-
-    // Note for the future (rust 1.91+): use with_added_extension, it's prettier
-    let object_path = s3_snapshot_prefix.join(format!("{db_name}.snapshot"));
-    // Note: It doesn't work on Windows and if a port to this platform is needed,
-    //       use the slash-path crate or similar to get the correct path separator.
-    let object = object_path.display().to_string();
 
-    let action = bucket.create_multipart_upload(Some(&credential), &object);
+    let action = bucket.create_multipart_upload(Some(credential), object);
     let url = action.sign(s3_signature_duration);
 
-    let client = Client::builder().build_with_policies(ip_policy, Default::default()).unwrap();
     let request = client.post(url);
     let body = backoff::future::retry(retry_backoff.clone(), move || {
         // safety: it fails only with stream body. We send a request without a body.
@@ -478,7 +1316,7 @@ async fn multipart_stream_to_s3(
         }
 
         let part_upload =
-            bucket.upload_part(Some(&credential), &object, part_number, multipart.upload_id());
+            bucket.upload_part(Some(credential), object, part_number, multipart.upload_id());
         let url = part_upload.sign(s3_signature_duration);
 
         // Wait for a buffer to be ready if there are in-flight parts that landed
@@ -565,8 +1403,8 @@ async fn multipart_stream_to_s3(
     tracing::debug!("Finalizing the multipart upload");
 
     let action = bucket.complete_multipart_upload(
-        Some(&credential),
-        &object,
+        Some(credential),
+        object,
         multipart.upload_id(),
         etags.iter().map(AsRef::as_ref),
     );
@@ -9,6 +9,7 @@ pub mod enterprise_edition;
 
 mod process_batch;
 mod process_dump_creation;
+mod process_dump_import;
 mod process_export;
 mod process_index_operation;
 mod process_snapshot_creation;
@@ -37,7 +38,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use roaring::RoaringBitmap;
 
 use crate::processing::{AtomicTaskStep, BatchProgress};
-use crate::{Error, IndexScheduler, IndexSchedulerOptions, Result, TickOutcome};
+use crate::{Error, IndexScheduler, IndexSchedulerOptions, Result, SnapshotRetention, TickOutcome};
 
 pub struct Scheduler {
     /// A boolean that can be set to true to stop the currently processing tasks.
@@ -80,6 +81,12 @@ pub struct Scheduler {
 
     /// S3 Snapshot options.
     pub(crate) s3_snapshot_options: Option<S3SnapshotOptions>,
+
+    /// The snapshots to keep after each snapshot creation.
+    pub(crate) snapshot_retention: SnapshotRetention,
+
+    /// Whether the snapshots on disk are incremental.
+    pub(crate) incremental_snapshots: bool,
 }
 
 impl Scheduler {
@@ -97,6 +104,8 @@ impl Scheduler {
             version_file_path: self.version_file_path.clone(),
             embedding_cache_cap: self.embedding_cache_cap,
             s3_snapshot_options: self.s3_snapshot_options.clone(),
+            snapshot_retention: self.snapshot_retention,
+            incremental_snapshots: self.incremental_snapshots,
             ip_policy: self.ip_policy.clone(),
         }
     }
@@ -127,6 +136,8 @@ impl Scheduler {
             embedding_cache_cap,
             ip_policy,
             dsr_fuel: _,
+            snapshot_retention,
+            incremental_snapshots,
         } = options;
 
         let (waker, wake_up) = tokio::sync::broadcast::channel(32);
@@ -147,6 +158,8 @@ impl Scheduler {
             embedding_cache_cap: *embedding_cache_cap,
             ip_policy: ip_policy.clone(),
             s3_snapshot_options: indexer_config.s3_snapshot_options.clone(),
+            snapshot_retention: *snapshot_retention,
+            incremental_snapshots: *incremental_snapshots,
         }
     }
 }
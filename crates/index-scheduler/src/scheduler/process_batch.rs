@@ -149,6 +149,9 @@ impl IndexScheduler {
             Batch::Dump(task) => self
                 .process_dump_creation(progress, task)
                 .map(|tasks| (tasks, ProcessBatchInfo::default())),
+            Batch::DumpImport(task) => self
+                .process_dump_import(progress, task, network, current_batch.embedder_stats.clone())
+                .map(|tasks| (tasks, ProcessBatchInfo::default())),
             Batch::IndexOperation { op, must_create_index } => {
                 let index_name = op.index_uid().to_string();
                 let index_uid = UserIndex::try_from_uid(&index_name)?;
@@ -966,6 +969,7 @@ impl IndexScheduler {
         let mut affected_indexes = HashSet::<Rc<str>>::new();
         let mut affected_statuses = HashSet::new();
         let mut affected_kinds = HashSet::new();
+        let mut affected_priorities = HashSet::new();
         let mut affected_canceled_by = RoaringBitmap::new();
         // The tasks that have been removed *per batches*.
         let mut affected_batches: HashMap<BatchId, RoaringBitmap> = HashMap::new();
@@ -982,6 +986,7 @@ impl IndexScheduler {
                 affected_indexes.extend(task.indexes().into_iter().map(Rc::from));
                 affected_statuses.insert(task.status);
                 affected_kinds.insert(task.kind.as_kind());
+                affected_priorities.insert(task.priority);
 
                 let enqueued_at = task.enqueued_at.unix_timestamp_nanos();
                 tasks_enqueued_to_remove.entry(enqueued_at).or_default().insert(task_id);
@@ -1126,10 +1131,13 @@ impl IndexScheduler {
             self.queue.batches.update_kind(wtxn, kind, |b| *b -= &batches)?;
         }
 
-        // 10. Remove tasks from indexes, statuses, and kinds
+        // 10. Remove tasks from indexes, statuses, kinds, and priorities
         progress.update_progress(TaskDeletionProgress::DeletingTasksMetadata);
         let (atomic_progress, task_progress) = AtomicTaskStep::new(
-            (affected_indexes.len() + affected_statuses.len() + affected_kinds.len()) as u32,
+            (affected_indexes.len()
+                + affected_statuses.len()
+                + affected_kinds.len()
+                + affected_priorities.len()) as u32,
         );
         progress.update_progress(task_progress);
 
@@ -1148,6 +1156,11 @@ impl IndexScheduler {
             atomic_progress.fetch_add(1, Ordering::Relaxed);
         }
 
+        for priority in affected_priorities {
+            self.queue.tasks.update_priority(wtxn, priority, |tasks| *tasks -= &to_delete_tasks)?;
+            atomic_progress.fetch_add(1, Ordering::Relaxed);
+        }
+
         // 11. Delete tasks
         progress.update_progress(TaskDeletionProgress::DeletingTasks);
         let (atomic_progress, task_progress) =
@@ -27,21 +27,31 @@ impl IndexScheduler {
     ) -> Result<Vec<Task>> {
         progress.update_progress(DumpCreationProgress::StartTheDumpCreation);
         let started_at = OffsetDateTime::now_utc();
-        let (keys, instance_uid) =
-            if let KindWithContent::DumpCreation { keys, instance_uid } = &task.kind {
-                (keys, instance_uid)
+        let (keys, signing_keys, instance_uid, selection) =
+            if let KindWithContent::DumpCreation { keys, signing_keys, instance_uid, selection } =
+                &task.kind
+            {
+                (keys, signing_keys, instance_uid, selection)
             } else {
                 unreachable!();
             };
-        let dump = dump::DumpWriter::new(*instance_uid)?;
+        let dump = dump::DumpWriter::with_selection(*instance_uid, selection.clone())?;
 
         // 1. dump the keys
         progress.update_progress(DumpCreationProgress::DumpTheApiKeys);
+        // the keys file is always written as the dump reader expects it, even when empty
         let mut dump_keys = dump.create_keys()?;
-        for key in keys {
-            dump_keys.push_key(key)?;
+        let mut dump_signing_keys = dump.create_signing_keys()?;
+        if !selection.skip_keys {
+            for key in keys {
+                dump_keys.push_key(key)?;
+            }
+            for signing_key in signing_keys {
+                dump_signing_keys.push_signing_key(signing_key)?;
+            }
         }
         dump_keys.flush()?;
+        dump_signing_keys.flush()?;
 
         let rtxn = self.env.read_txn()?;
 
@@ -56,57 +66,65 @@ impl IndexScheduler {
 
         // 3. dump the tasks
         progress.update_progress(DumpCreationProgress::DumpTheTasks);
+        // the task queue is always created as the dump reader expects it, even when empty
         let mut dump_tasks = dump.create_tasks_queue()?;
 
-        let (atomic, update_task_progress) =
-            AtomicTaskStep::new(self.queue.tasks.all_tasks.len(&rtxn)? as u32);
-        progress.update_progress(update_task_progress);
-
-        for ret in self.queue.tasks.all_tasks.iter(&rtxn)? {
-            if self.scheduler.must_stop_processing.get() {
-                return Err(Error::AbortedTask);
-            }
-
-            let (_, mut t) = ret?;
-            let status = t.status;
-            let content_file = t.content_uuid();
-
-            // In the case we're dumping ourselves we want to be marked as finished
-            // to not loop over ourselves indefinitely.
-            if t.uid == task.uid {
-                let finished_at = OffsetDateTime::now_utc();
-
-                // We're going to fake the date because we don't know if everything is going to go well.
-                // But we need to dump the task as finished and successful.
-                // If something fail everything will be set appropriately in the end.
-                t.status = Status::Succeeded;
-                t.started_at = Some(started_at);
-                t.finished_at = Some(finished_at);
-            }
-
-            let mut dump_content_file = dump_tasks.push_task(&t.into())?;
+        if !selection.skip_tasks {
+            let (atomic, update_task_progress) =
+                AtomicTaskStep::new(self.queue.tasks.all_tasks.len(&rtxn)? as u32);
+            progress.update_progress(update_task_progress);
 
-            // 3.1. Dump the `content_file` associated with the task if there is one and the task is not finished yet.
-            if let Some(content_file) = content_file {
+            for ret in self.queue.tasks.all_tasks.iter(&rtxn)? {
                 if self.scheduler.must_stop_processing.get() {
                     return Err(Error::AbortedTask);
                 }
-                if status == Status::Enqueued {
-                    let content_file = self.queue.file_store.get_update(content_file)?;
-
-                    for document in
-                        serde_json::de::Deserializer::from_reader(content_file).into_iter()
-                    {
-                        let document = document.map_err(|e| {
-                            Error::from_milli(milli::InternalError::SerdeJson(e).into(), None)
-                        })?;
-                        dump_content_file.push_document(&document)?;
+
+                let (_, mut t) = ret?;
+                // the tasks of the indexes left out of the dump would recreate them once imported
+                if !t.indexes().iter().all(|index| selection.contains_index(index)) {
+                    atomic.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+                let status = t.status;
+                let content_file = t.content_uuid();
+
+                // In the case we're dumping ourselves we want to be marked as finished
+                // to not loop over ourselves indefinitely.
+                if t.uid == task.uid {
+                    let finished_at = OffsetDateTime::now_utc();
+
+                    // We're going to fake the date because we don't know if everything is going to go well.
+                    // But we need to dump the task as finished and successful.
+                    // If something fail everything will be set appropriately in the end.
+                    t.status = Status::Succeeded;
+                    t.started_at = Some(started_at);
+                    t.finished_at = Some(finished_at);
+                }
+
+                let mut dump_content_file = dump_tasks.push_task(&t.into())?;
+
+                // 3.1. Dump the `content_file` associated with the task if there is one and the task is not finished yet.
+                if let Some(content_file) = content_file {
+                    if self.scheduler.must_stop_processing.get() {
+                        return Err(Error::AbortedTask);
                     }
+                    if status == Status::Enqueued {
+                        let content_file = self.queue.file_store.get_update(content_file)?;
+
+                        for document in
+                            serde_json::de::Deserializer::from_reader(content_file).into_iter()
+                        {
+                            let document = document.map_err(|e| {
+                                Error::from_milli(milli::InternalError::SerdeJson(e).into(), None)
+                            })?;
+                            dump_content_file.push_document(&document)?;
+                        }
 
-                    dump_content_file.flush()?;
+                        dump_content_file.flush()?;
+                    }
                 }
+                atomic.fetch_add(1, Ordering::Relaxed);
             }
-            atomic.fetch_add(1, Ordering::Relaxed);
         }
         dump_tasks.flush()?;
 
@@ -114,32 +132,38 @@ impl IndexScheduler {
         progress.update_progress(DumpCreationProgress::DumpTheBatches);
         let mut dump_batches = dump.create_batches_queue()?;
 
-        let (atomic_batch_progress, update_batch_progress) =
-            AtomicBatchStep::new(self.queue.batches.all_batches.len(&rtxn)? as u32);
-        progress.update_progress(update_batch_progress);
+        if !selection.skip_batches {
+            let (atomic_batch_progress, update_batch_progress) =
+                AtomicBatchStep::new(self.queue.batches.all_batches.len(&rtxn)? as u32);
+            progress.update_progress(update_batch_progress);
 
-        for ret in self.queue.batches.all_batches.iter(&rtxn)? {
-            if self.scheduler.must_stop_processing.get() {
-                return Err(Error::AbortedTask);
-            }
+            for ret in self.queue.batches.all_batches.iter(&rtxn)? {
+                if self.scheduler.must_stop_processing.get() {
+                    return Err(Error::AbortedTask);
+                }
 
-            let (_, mut b) = ret?;
-            // In the case we're dumping ourselves we want to be marked as finished
-            // to not loop over ourselves indefinitely.
-            if b.uid == task.uid {
-                let finished_at = OffsetDateTime::now_utc();
-
-                // We're going to fake the date because we don't know if everything is going to go well.
-                // But we need to dump the task as finished and successful.
-                // If something fail everything will be set appropriately in the end.
-                let mut statuses = BTreeMap::new();
-                statuses.insert(Status::Succeeded, b.stats.total_nb_tasks);
-                b.stats.status = statuses;
-                b.finished_at = Some(finished_at);
-            }
+                let (_, mut b) = ret?;
+                if !b.stats.index_uids.keys().all(|index| selection.contains_index(index)) {
+                    atomic_batch_progress.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+                // In the case we're dumping ourselves we want to be marked as finished
+                // to not loop over ourselves indefinitely.
+                if b.uid == task.uid {
+                    let finished_at = OffsetDateTime::now_utc();
+
+                    // We're going to fake the date because we don't know if everything is going to go well.
+                    // But we need to dump the task as finished and successful.
+                    // If something fail everything will be set appropriately in the end.
+                    let mut statuses = BTreeMap::new();
+                    statuses.insert(Status::Succeeded, b.stats.total_nb_tasks);
+                    b.stats.status = statuses;
+                    b.finished_at = Some(finished_at);
+                }
 
-            dump_batches.push_batch(&b)?;
-            atomic_batch_progress.fetch_add(1, Ordering::Relaxed);
+                dump_batches.push_batch(&b)?;
+                atomic_batch_progress.fetch_add(1, Ordering::Relaxed);
+            }
         }
         dump_batches.flush()?;
 
@@ -150,6 +174,9 @@ impl IndexScheduler {
         let () = self.index_mapper.try_for_each_index::<_, _, AnyIndex>(
             &rtxn,
             |uid, index| -> Result<()> {
+                if !selection.contains_index(uid.uid()) {
+                    return Ok(());
+                }
                 progress.update_progress(VariableNameStep::<DumpCreationProgress>::new(
                     uid.uid().to_string(),
                     count,
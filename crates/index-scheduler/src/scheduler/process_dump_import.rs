@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use meilisearch_types::index_uid::AnyIndex;
+use meilisearch_types::milli::progress::{EmbedderStats, Progress, VariableNameStep};
+use meilisearch_types::milli::update::new::indexer;
+use meilisearch_types::milli::update::MissingDocumentPolicy;
+use meilisearch_types::milli::{self};
+use meilisearch_types::network::Network;
+use meilisearch_types::settings::apply_settings_to_builder;
+use meilisearch_types::tasks::{Details, KindWithContent, Status, Task};
+
+use crate::processing::{DumpImportIndexProgress, DumpImportProgress};
+use crate::{Error, IndexScheduler, Result};
+
+impl IndexScheduler {
+    /// Import the indexes of a dump of the dumps directory into the running instance.
+    ///
+    /// Only the dumps already present in the dumps directory can be imported, uploading a dump
+    /// is not supported. Only the indexes are imported, the tasks, batches, keys and other
+    /// instance-wide settings of the dump are ignored. The indexes imported before a failure
+    /// are kept, the index being imported when the failure happens is deleted.
+    pub(super) fn process_dump_import(
+        &self,
+        progress: Progress,
+        mut task: Task,
+        network: &Network,
+        embedder_stats: Arc<EmbedderStats>,
+    ) -> Result<Vec<Task>> {
+        progress.update_progress(DumpImportProgress::StartTheDumpImport);
+        let (dump_uid, index_renames, skip_existing_indexes) =
+            if let KindWithContent::DumpImport { dump_uid, index_renames, skip_existing_indexes } =
+                &task.kind
+            {
+                (dump_uid, index_renames, *skip_existing_indexes)
+            } else {
+                unreachable!();
+            };
+
+        let path = self.scheduler.dumps_path.join(format!("{dump_uid}.dump"));
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Err(Error::DumpNotFound(dump_uid.clone()))
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let dump_reader = dump::DumpReader::open(file)?;
+
+        // 1. Ensure that we can import every index of the dump before touching the instance
+        progress.update_progress(DumpImportProgress::CheckTheIndexes);
+        let mut imported_indexes = Vec::new();
+        let mut skipped_indexes = Vec::new();
+        let mut targets = BTreeMap::new();
+        {
+            let rtxn = self.env.read_txn()?;
+            for index_reader in dump_reader.indexes()? {
+                let uid = index_reader?.metadata().uid.clone();
+                let target = index_renames.get(&uid).cloned().unwrap_or_else(|| uid.clone());
+                if self.index_mapper.exists(&rtxn, AnyIndex::new(&target))? {
+                    if skip_existing_indexes {
+                        skipped_indexes.push(target);
+                        continue;
+                    }
+                    return Err(Error::IndexAlreadyExists(target));
+                }
+                // two indexes of the dump cannot be renamed to the same uid
+                if targets.values().any(|other| other == &target) {
+                    return Err(Error::IndexAlreadyExists(target));
+                }
+                targets.insert(uid, target);
+            }
+        }
+
+        // 2. Import the indexes one by one
+        progress.update_progress(DumpImportProgress::ImportTheIndexes);
+        let must_stop_processing = self.scheduler.must_stop_processing.clone();
+        let indexer_config = self.index_mapper.indexer_config();
+        let nb_indexes = targets.len() as u32;
+        let mut count = 0;
+        for index_reader in dump_reader.indexes()? {
+            let mut index_reader = index_reader?;
+            let metadata = index_reader.metadata();
+            let Some(target) = targets.get(&metadata.uid) else { continue };
+            progress.update_progress(VariableNameStep::<DumpImportProgress>::new(
+                target.clone(),
+                count,
+                nb_indexes,
+            ));
+            count += 1;
+
+            if must_stop_processing.get() {
+                return Err(Error::AbortedTask);
+            }
+
+            let uid = AnyIndex::new(target);
+            let from_milli = |err| Error::from_milli(err, Some(target.clone()));
+            let date = Some((metadata.created_at, metadata.updated_at));
+            let primary_key = metadata.primary_key.clone();
+
+            let wtxn = self.env.write_txn()?;
+            let index = self.index_mapper.create_index(wtxn, uid, date, network.shards())?;
+
+            // The index is deleted if its import fails so that no half-imported index is kept.
+            let result = || -> Result<()> {
+                // 2.1 Import the primary key and the settings
+                progress.update_progress(DumpImportIndexProgress::ImportTheSettings);
+                let mut index_wtxn = index.write_txn()?;
+                let mut builder =
+                    milli::update::Settings::new(&mut index_wtxn, &index, indexer_config);
+                if let Some(primary_key) = primary_key {
+                    builder.set_primary_key(primary_key);
+                }
+                let settings = index_reader.settings()?;
+                apply_settings_to_builder(&settings, &mut builder);
+                builder
+                    .execute(
+                        &must_stop_processing,
+                        &progress,
+                        self.ip_policy(),
+                        embedder_stats.clone(),
+                    )
+                    .map_err(from_milli)?;
+                index_wtxn.commit()?;
+
+                #[cfg(test)]
+                self.maybe_fail(crate::test_utils::FailureLocation::ProcessDumpImport)?;
+
+                // 2.2 Import the documents
+                progress.update_progress(DumpImportIndexProgress::ImportTheDocuments);
+                let mut index_wtxn = index.write_txn()?;
+                let rtxn = index.read_txn()?;
+                let db_fields_ids_map = index.fields_ids_map(&rtxn)?;
+                let primary_key = index.primary_key(&rtxn)?;
+                let mut new_fields_ids_map = db_fields_ids_map.clone();
+
+                let embedders = index
+                    .embedding_configs()
+                    .embedding_configs(&rtxn)
+                    .map_err(|e| from_milli(e.into()))?;
+                let embedders = self.embedders(target.clone(), embedders)?;
+
+                let mmap = unsafe { memmap2::Mmap::map(index_reader.documents_file())? };
+                let mut operations = indexer::IndexOperations::new();
+                operations
+                    .replace_documents(&mmap, MissingDocumentPolicy::default())
+                    .map_err(from_milli)?;
+
+                let indexer_alloc = Bump::new();
+                let shards = network.shards();
+                let (document_changes, mut operation_stats, primary_key) = operations
+                    .into_changes(
+                        &indexer_alloc,
+                        &index,
+                        &rtxn,
+                        primary_key,
+                        &mut new_fields_ids_map,
+                        &must_stop_processing,
+                        progress.clone(),
+                        shards.as_ref(),
+                    )
+                    .map_err(from_milli)?;
+
+                if let Some(error) = operation_stats.pop().and_then(|stats| stats.error) {
+                    return Err(from_milli(milli::Error::UserError(error)));
+                }
+
+                indexer::index(
+                    &mut index_wtxn,
+                    &index,
+                    &indexer_config.thread_pool,
+                    indexer_config.grenad_parameters(),
+                    &db_fields_ids_map,
+                    new_fields_ids_map,
+                    primary_key,
+                    &document_changes,
+                    embedders,
+                    &must_stop_processing,
+                    &progress,
+                    self.ip_policy(),
+                    &embedder_stats,
+                )
+                .map_err(from_milli)?;
+
+                index_wtxn.commit()?;
+                drop(rtxn);
+                self.refresh_index_stats(uid)
+            }();
+            if let Err(error) = result {
+                drop(index);
+                let wtxn = self.env.write_txn()?;
+                if let Err(e) = self.index_mapper.delete_index(wtxn, uid) {
+                    tracing::error!(
+                        "Error while deleting the partially imported index `{target}`: {e}"
+                    );
+                }
+                return Err(error);
+            }
+            imported_indexes.push(target.clone());
+        }
+
+        task.status = Status::Succeeded;
+        task.details = Some(Details::DumpImport {
+            dump_uid: dump_uid.clone(),
+            imported_indexes: Some(imported_indexes),
+            skipped_indexes: Some(skipped_indexes),
+        });
+        Ok(vec![task])
+    }
+}
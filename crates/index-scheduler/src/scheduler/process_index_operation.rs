@@ -612,6 +612,14 @@ impl IndexScheduler {
                 cmp_attr_pattern(dsr_fields::CONDITIONS_TIME_END.into()),
                 // used to find query isEmpty constraints
                 eq_attr_pattern(dsr_fields::CONDITIONS_QUERY_IS_EMPTY.into()),
+                // used to find query prefix constraints
+                eq_attr_pattern(dsr_fields::CONDITIONS_QUERY_PREFIX.into()),
+                // used to find rules with a query regex constraint
+                eq_attr_pattern(dsr_fields::CONDITIONS_QUERY_REGEX.into()),
+                // used to find locale constraints
+                eq_attr_pattern(dsr_fields::CONDITIONS_LOCALES.into()),
+                // used to find tenant token claim constraints
+                eq_attr_pattern(format!("{}.*", dsr_fields::CONDITIONS_TENANT_TOKEN_CLAIMS)),
                 // used to find filter constraints
                 cmp_attr_pattern(format!("{}.*", dsr_fields::CONDITIONS_FILTER_VALUES)),
                 // use to count filter constraints
@@ -653,6 +661,7 @@ impl IndexScheduler {
                 meilisearch_types::settings::PrefixSearchSettings::Disabled,
             ),
             chat: Setting::NotSet,
+            reranker: Setting::NotSet,
             _kind: std::marker::PhantomData,
         };
         apply_settings_to_builder(&checked_settings, &mut builder);
@@ -844,7 +853,7 @@ fn eq_attr_pattern(pattern: String) -> FilterableAttributesRule {
         attribute_patterns: vec![pattern].into(),
         features: FilterableAttributesFeatures {
             facet_search: false,
-            filter: FilterFeatures { equality: true, comparison: false },
+            filter: FilterFeatures { equality: true, comparison: false, pattern_matching: false },
         },
     })
 }
@@ -854,7 +863,7 @@ fn cmp_attr_pattern(pattern: String) -> FilterableAttributesRule {
         attribute_patterns: vec![pattern].into(),
         features: FilterableAttributesFeatures {
             facet_search: false,
-            filter: FilterFeatures { equality: true, comparison: true },
+            filter: FilterFeatures { equality: true, comparison: true, pattern_matching: false },
         },
     })
 }
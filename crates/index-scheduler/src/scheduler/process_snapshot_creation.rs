@@ -1,14 +1,19 @@
 use std::ffi::OsStr;
 use std::fs;
+use std::path::Path;
 use std::sync::atomic::Ordering;
 
 use meilisearch_types::heed::CompactionOption;
 use meilisearch_types::index_uid::AnyIndex;
 use meilisearch_types::milli::progress::{Progress, VariableNameStep};
 use meilisearch_types::tasks::{Status, Task};
+use meilisearch_types::versioning::{VERSION_MAJOR, VERSION_MINOR, VERSION_PATCH};
 use meilisearch_types::{compression, VERSION_FILE_NAME};
+use time::OffsetDateTime;
+use uuid::Uuid;
 
 use crate::heed::EnvOpenOptions;
+use crate::incremental_snapshot::{latest_incremental_snapshot, IndexManifest, SnapshotManifest};
 use crate::processing::{AtomicUpdateFileStep, SnapshotCreationProgress};
 use crate::queue::TaskQueue;
 use crate::{Error, IndexScheduler, Result};
@@ -39,6 +44,7 @@ unsafe fn remove_tasks(
         enqueued_at,
         started_at,
         finished_at,
+        priority,
     } = task_queue;
 
     for task in tasks {
@@ -54,6 +60,11 @@ unsafe fn remove_tasks(
 
         canceled_by.delete(&mut wtxn, &task.uid)?;
 
+        if let Some(mut tasks) = priority.get(&wtxn, &task.priority)? {
+            tasks.remove(task.uid);
+            priority.put(&mut wtxn, &task.priority, &tasks)?;
+        }
+
         let timestamp = task.enqueued_at.unix_timestamp_nanos();
         let mut tasks = enqueued_at.get(&wtxn, &timestamp)?.unwrap_or_default();
         tasks.remove(task.uid);
@@ -108,10 +119,114 @@ impl IndexScheduler {
         mut tasks: Vec<Task>,
     ) -> Result<Vec<Task>, Error> {
         fs::create_dir_all(&self.scheduler.snapshots_path)?;
-        let temp_snapshot_dir = tempfile::tempdir()?;
 
+        // Find the original name of the database
+        // TODO find a better way to get this path
+        let mut base_path = self.env.path().to_owned();
+        base_path.pop();
+        let db_name = base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms");
+        let now = OffsetDateTime::now_utc();
+
+        // Incremental snapshots are created next to the previous ones to be able to hard link
+        // their files, and only reuse the files of a snapshot made by the same version.
+        let incremental = self.scheduler.incremental_snapshots;
+        let temp_snapshot_dir = if incremental {
+            tempfile::Builder::new().prefix(".tmp").tempdir_in(&self.scheduler.snapshots_path)?
+        } else {
+            tempfile::tempdir()?
+        };
+        let version = format!("{VERSION_MAJOR}.{VERSION_MINOR}.{VERSION_PATCH}");
+        let mut manifest = incremental.then(|| SnapshotManifest::new(version.clone(), now));
+        let previous = if incremental {
+            latest_incremental_snapshot(&self.scheduler.snapshots_path, db_name)?
+                .filter(|(_, previous)| previous.version == version)
+        } else {
+            None
+        };
+
+        self.copy_database_into(
+            &progress,
+            &tasks,
+            temp_snapshot_dir.path(),
+            manifest.as_mut(),
+            previous.as_ref().map(|(_, previous)| previous),
+            |uuid, dst| {
+                // Reuse the index file of the previous snapshot if it still exists
+                let Some((path, _)) = previous.as_ref() else { return Ok(false) };
+                let file = path.join("indexes").join(uuid.to_string()).join("data.mdb");
+                if !file.is_file() {
+                    return Ok(false);
+                }
+                fs::hard_link(file, dst)?;
+                Ok(true)
+            },
+        )?;
+
+        let snapshot_name = self.scheduler.snapshot_retention.snapshot_name(db_name, now);
+        let snapshot_path = self.scheduler.snapshots_path.join(snapshot_name);
+        if let Some(manifest) = manifest {
+            // 5. Write the manifest and move the incremental snapshot in place
+            progress.update_progress(SnapshotCreationProgress::WriteTheManifest);
+            manifest.write_to(temp_snapshot_dir.path())?;
+
+            // The previous snapshot with the same name is moved away before being
+            // deleted to never leave the snapshot path empty while a snapshot exists.
+            let mut trash = None;
+            if snapshot_path.exists() {
+                let dir = tempfile::Builder::new()
+                    .prefix(".tmp")
+                    .tempdir_in(&self.scheduler.snapshots_path)?;
+                fs::rename(&snapshot_path, dir.path().join("previous"))?;
+                trash = Some(dir);
+            }
+            fs::rename(temp_snapshot_dir.keep(), &snapshot_path)?;
+            drop(trash);
+        } else {
+            self.persist_snapshot_tarball(&progress, temp_snapshot_dir.path(), &snapshot_path)?;
+        }
+
+        // 6. Remove the snapshots that are not retained anymore
+        progress.update_progress(SnapshotCreationProgress::ApplyTheRetentionPolicy);
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.scheduler.snapshots_path)? {
+            if let Some(name) = entry?.file_name().to_str() {
+                names.push(name.to_string());
+            }
+        }
+        let retention = &self.scheduler.snapshot_retention;
+        for name in retention.expired_snapshots(db_name, names.iter().map(String::as_str), now) {
+            tracing::info!("Removing the expired snapshot {name}");
+            let path = self.scheduler.snapshots_path.join(name);
+            if path.is_dir() {
+                fs::remove_dir_all(path)?;
+            } else {
+                fs::remove_file(path)?;
+            }
+        }
+
+        for task in &mut tasks {
+            task.status = Status::Succeeded;
+        }
+
+        Ok(tasks)
+    }
+
+    /// Copies the content of the database into the `dst` directory with the layout of a snapshot.
+    ///
+    /// When a manifest is given, the state of every index is recorded in it, and the indexes
+    /// that did not change since the `previous` manifest are handed to `reuse_index` with the
+    /// destination of their file. The index is only copied if it returns `false`.
+    pub(super) fn copy_database_into(
+        &self,
+        progress: &Progress,
+        tasks: &[Task],
+        dst_dir: &Path,
+        mut manifest: Option<&mut SnapshotManifest>,
+        previous: Option<&SnapshotManifest>,
+        mut reuse_index: impl FnMut(Uuid, &Path) -> Result<bool>,
+    ) -> Result<()> {
         // 1. Snapshot the version file.
-        let dst = temp_snapshot_dir.path().join(VERSION_FILE_NAME);
+        let dst = dst_dir.join(VERSION_FILE_NAME);
         fs::copy(&self.scheduler.version_file_path, dst)?;
 
         // 2. Snapshot the index-scheduler LMDB env
@@ -128,7 +243,7 @@ impl IndexScheduler {
 
         // 2.1 First copy the LMDB env of the index-scheduler
         progress.update_progress(SnapshotCreationProgress::SnapshotTheIndexScheduler);
-        let dst = temp_snapshot_dir.path().join("tasks");
+        let dst = dst_dir.join("tasks");
         fs::create_dir_all(&dst)?;
         self.env.copy_to_path(dst.join("data.mdb"), CompactionOption::Enabled)?;
 
@@ -141,14 +256,14 @@ impl IndexScheduler {
         // This is safe because we open the env file we just created in a temporary directory.
         // We are sure it's not being used by any other process nor thread.
         unsafe {
-            remove_tasks(&tasks, &dst, self.index_mapper.index_base_map_size())?;
+            remove_tasks(tasks, &dst, self.index_mapper.index_base_map_size())?;
         }
 
         // 2.3 Create a read transaction on the index-scheduler
         let rtxn = self.env.read_txn()?;
 
         // 2.4 Create the update files directory
-        let update_files_dir = temp_snapshot_dir.path().join(UPDATE_FILES_DIR_NAME);
+        let update_files_dir = dst_dir.join(UPDATE_FILES_DIR_NAME);
         fs::create_dir_all(&update_files_dir)?;
 
         // 2.5 Only copy the update files of the enqueued tasks
@@ -170,6 +285,7 @@ impl IndexScheduler {
         // 3. Snapshot every indexes
         progress.update_progress(SnapshotCreationProgress::SnapshotTheIndexes);
         let nb_indexes = self.index_mapper.index_count::<AnyIndex>(&rtxn)? as u32;
+        let succeeded = self.queue.tasks.get_status(&rtxn, Status::Succeeded)?;
 
         for (i, result) in self.index_mapper.index_names::<AnyIndex>(&rtxn)?.enumerate() {
             let name = result?;
@@ -178,8 +294,6 @@ impl IndexScheduler {
                 i as u32,
                 nb_indexes,
             ));
-            let index = self.index_mapper.index(&rtxn, name)?;
-
             let uuid = self.index_mapper.index_uuid(&rtxn, name)?.ok_or_else(|| {
                 Error::from_milli(
                     meilisearch_types::milli::InternalError::DatabaseMissingEntry {
@@ -191,10 +305,43 @@ impl IndexScheduler {
                 )
             })?;
 
-            let dst = temp_snapshot_dir.path().join("indexes").join(uuid.to_string());
+            let dst = dst_dir.join("indexes").join(uuid.to_string());
             fs::create_dir_all(&dst)?;
+            let dst = dst.join("data.mdb");
+
+            // 3.1 Reuse the index file of the previous snapshot if the index didn't change since
+            if let Some(manifest) = manifest.as_deref_mut() {
+                let last_task_uid =
+                    (&succeeded & self.queue.tasks.index_tasks(&rtxn, name.uid())?).max();
+                let last_batch_uid = match last_task_uid {
+                    Some(task_id) => {
+                        self.queue.tasks.get_task(&rtxn, task_id)?.and_then(|task| task.batch_uid)
+                    }
+                    None => None,
+                };
+                let updated_at = self.index_mapper.stats_of(&rtxn, name).ok().map(|s| s.updated_at);
+                let mut index_manifest = IndexManifest {
+                    uid: name.uid().to_string(),
+                    last_task_uid,
+                    last_batch_uid,
+                    updated_at,
+                    reused: false,
+                };
+
+                let unchanged = previous
+                    .and_then(|previous| previous.indexes.get(&uuid))
+                    .is_some_and(|previous| index_manifest.is_unchanged_since(previous));
+                if unchanged && reuse_index(uuid, &dst)? {
+                    index_manifest.reused = true;
+                    manifest.indexes.insert(uuid, index_manifest);
+                    continue;
+                }
+                manifest.indexes.insert(uuid, index_manifest);
+            }
+
+            let index = self.index_mapper.index(&rtxn, name)?;
             index
-                .copy_to_path(dst.join("data.mdb"), CompactionOption::Enabled)
+                .copy_to_path(&dst, CompactionOption::Enabled)
                 .map_err(|e| Error::from_milli(e, Some(name.uid().to_string())))?;
         }
 
@@ -202,25 +349,33 @@ impl IndexScheduler {
 
         // 4. Snapshot the auth LMDB env
         progress.update_progress(SnapshotCreationProgress::SnapshotTheApiKeys);
-        let dst = temp_snapshot_dir.path().join("auth");
+        let dst = dst_dir.join("auth");
         fs::create_dir_all(&dst)?;
         self.scheduler.auth_env.copy_to_path(dst.join("data.mdb"), CompactionOption::Enabled)?;
 
+        Ok(())
+    }
+
+    /// Tarballs the content of the flat snapshot and persists it as a readonly file.
+    fn persist_snapshot_tarball(
+        &self,
+        progress: &Progress,
+        snapshot_dir: &Path,
+        snapshot_path: &Path,
+    ) -> Result<()> {
         // 5. Copy and tarball the flat snapshot
         progress.update_progress(SnapshotCreationProgress::CreateTheTarball);
-        // 5.1 Find the original name of the database
-        // TODO find a better way to get this path
-        let mut base_path = self.env.path().to_owned();
-        base_path.pop();
-        let db_name = base_path.file_name().and_then(OsStr::to_str).unwrap_or("data.ms");
 
-        // 5.2 Tarball the content of the snapshot in a tempfile with a .snapshot extension
-        let snapshot_path = self.scheduler.snapshots_path.join(format!("{}.snapshot", db_name));
+        // 5.1 Tarball the content of the snapshot in a tempfile with a .snapshot extension
         let temp_snapshot_file = tempfile::NamedTempFile::new_in(&self.scheduler.snapshots_path)?;
-        compression::to_tar_gz(temp_snapshot_dir.path(), temp_snapshot_file.path())?;
+        compression::to_tar_gz(snapshot_dir, temp_snapshot_file.path())?;
+        // a previous incremental snapshot can't be replaced by a file
+        if snapshot_path.is_dir() {
+            fs::remove_dir_all(snapshot_path)?;
+        }
         let file = temp_snapshot_file.persist(snapshot_path)?;
 
-        // 5.3 Change the permission to make the snapshot readonly
+        // 5.2 Change the permission to make the snapshot readonly
         let mut permissions = file.metadata()?.permissions();
         permissions.set_readonly(true);
         #[cfg(unix)]
@@ -233,10 +388,6 @@ impl IndexScheduler {
 
         file.set_permissions(permissions)?;
 
-        for task in &mut tasks {
-            task.status = Status::Succeeded;
-        }
-
-        Ok(tasks)
+        Ok(())
     }
 }
@@ -3,21 +3,25 @@ use std::collections::BTreeMap;
 use big_s::S;
 use meili_snap::{json_string, snapshot};
 use meilisearch_auth::AuthFilter;
+use meilisearch_types::features::ChatConversation;
 use meilisearch_types::index_uid::{AnyIndex, DsrIndex, UserIndex};
 use meilisearch_types::milli::update::IndexDocumentsMethod::*;
 use meilisearch_types::milli::update::MissingDocumentPolicy;
 use meilisearch_types::milli::{self};
+use meilisearch_types::schedules::{Schedule, ScheduledTask};
 use meilisearch_types::settings::SettingEmbeddingSettings;
-use meilisearch_types::tasks::{IndexSwap, KindWithContent};
+use meilisearch_types::tasks::{IndexSwap, KindWithContent, Status, TaskPriority};
 use milli::vector::db::IndexEmbeddingConfig;
 use roaring::RoaringBitmap;
+use time::macros::datetime;
+use uuid::Uuid;
 
-use crate::insta_snapshot::snapshot_index_scheduler;
+use crate::insta_snapshot::{snapshot_bitmap, snapshot_index_scheduler};
 use crate::test_utils::Breakpoint::*;
 use crate::test_utils::{
     index_creation_task, read_json, replace_document_import_task, sample_documents,
 };
-use crate::{IndexScheduler, Query};
+use crate::{Error, IndexScheduler, Query};
 
 #[test]
 fn insert_task_while_another_task_is_processing() {
@@ -301,6 +305,52 @@ fn do_not_batch_task_of_different_indexes() {
     snapshot!(snapshot_index_scheduler(&index_scheduler), name: "all_tasks_processed");
 }
 
+#[test]
+fn process_tasks_by_priority() {
+    let (index_scheduler, mut handle) = IndexScheduler::test(false, vec![]);
+
+    for name in ["doggos", "cattos"] {
+        index_scheduler
+            .register(KindWithContent::IndexCreation { index_uid: S(name), primary_key: None })
+            .unwrap();
+    }
+    let clear = |name: &str| KindWithContent::DocumentClear { index_uid: S(name) };
+    index_scheduler.register(clear("doggos")).unwrap();
+    index_scheduler
+        .register_with_priority(clear("cattos"), None, TaskPriority::High, None)
+        .unwrap();
+    index_scheduler.register_with_priority(clear("doggos"), None, TaskPriority::Low, None).unwrap();
+    index_scheduler.assert_internally_consistent();
+
+    let task_ids = |query: Query| {
+        let (tasks, _) = index_scheduler
+            .get_task_ids_from_authorized_indexes(&query, &AuthFilter::default())
+            .unwrap();
+        snapshot_bitmap(&tasks)
+    };
+
+    // the task with the high priority is processed first, but only after the older task of its index
+    handle.advance_n_successful_batches(2);
+    let query = Query { statuses: Some(vec![Status::Succeeded]), ..Default::default() };
+    snapshot!(task_ids(query), @"[1,3,]");
+
+    handle.advance_n_successful_batches(3);
+    index_scheduler.assert_internally_consistent();
+    let rtxn = index_scheduler.read_txn().unwrap();
+    let batch_uids: Vec<_> = (0..5)
+        .map(|uid| index_scheduler.queue.tasks.get_task(&rtxn, uid).unwrap().unwrap().batch_uid)
+        .collect();
+    snapshot!(format!("{batch_uids:?}"), @"[Some(2), Some(0), Some(3), Some(1), Some(4)]");
+
+    let query = Query {
+        priorities: Some(vec![TaskPriority::High, TaskPriority::Low]),
+        ..Default::default()
+    };
+    snapshot!(task_ids(query), @"[3,4,]");
+    let query = Query { priorities: Some(vec![TaskPriority::Normal]), ..Default::default() };
+    snapshot!(task_ids(query), @"[0,1,2,]");
+}
+
 #[test]
 fn swap_indexes() {
     let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
@@ -899,7 +949,12 @@ fn basic_get_stats() {
 fn cancel_processing_dump() {
     let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
 
-    let dump_creation = KindWithContent::DumpCreation { keys: Vec::new(), instance_uid: None };
+    let dump_creation = KindWithContent::DumpCreation {
+        keys: Vec::new(),
+        signing_keys: Vec::new(),
+        instance_uid: None,
+        selection: Default::default(),
+    };
     let dump_cancellation = KindWithContent::TaskCancelation {
         query: "cancel dump".to_owned(),
         tasks: RoaringBitmap::from_iter([0]),
@@ -1042,3 +1097,103 @@ fn test_index_uid_count() {
     snapshot!(index_scheduler.index_mapper.index_count::<DsrIndex>(&rtxn).unwrap(), @"1");
     snapshot!(index_scheduler.index_mapper.index_count::<AnyIndex>(&rtxn).unwrap(), @"4");
 }
+
+#[test]
+fn register_scheduled_task() {
+    let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+
+    let created_at = datetime!(2024-08-08 16:37:09 UTC);
+    let mut schedule = Schedule {
+        uid: Uuid::from_u128(1),
+        cron: "0 3 * * *".parse().unwrap(),
+        task: ScheduledTask::SnapshotCreation,
+        enabled: true,
+        created_at,
+        next_run_at: None,
+        last_run_at: None,
+        last_task_uid: None,
+    };
+    schedule.plan_next_run(created_at);
+    index_scheduler.put_schedule(&schedule).unwrap();
+    assert!(index_scheduler.due_schedules(created_at).unwrap().is_empty());
+
+    // the instance was down at 3am, the run is enqueued late
+    let now = datetime!(2024-08-09 07:12:00 UTC);
+    let due = index_scheduler.due_schedules(now).unwrap();
+    assert_eq!(due, vec![schedule.clone()]);
+
+    let task = index_scheduler
+        .register_scheduled_task(schedule.uid, KindWithContent::SnapshotCreation, now)
+        .unwrap();
+    snapshot!(task.schedule_uid.unwrap(), @"00000000-0000-0000-0000-000000000001");
+
+    let schedule = index_scheduler.schedule(schedule.uid).unwrap();
+    assert_eq!(schedule.last_run_at, Some(now));
+    assert_eq!(schedule.last_task_uid, Some(task.uid));
+    assert_eq!(schedule.next_run_at, Some(datetime!(2024-08-10 03:00 UTC)));
+    assert!(index_scheduler.due_schedules(now).unwrap().is_empty());
+
+    index_scheduler.delete_schedule(schedule.uid).unwrap();
+    let error = index_scheduler
+        .register_scheduled_task(schedule.uid, KindWithContent::SnapshotCreation, now)
+        .unwrap_err();
+    snapshot!(error, @"Schedule `00000000-0000-0000-0000-000000000001` not found.");
+}
+
+#[test]
+fn chat_conversations_are_only_accessible_by_their_owner() {
+    let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+    let now = datetime!(2025-01-01 00:00 UTC);
+
+    for (workspace, id, owner) in [
+        ("my-workspace", "by-key", Some("key:1234")),
+        ("my-workspace", "by-token", Some("key:1234:token:abcd")),
+        ("my-workspace", "by-other-key", Some("key:5678")),
+        ("my-workspace", "by-master-key", None),
+        ("other-workspace", "by-key-elsewhere", Some("key:1234")),
+    ] {
+        let conversation = ChatConversation::new(S(id), owner.map(S), now);
+        index_scheduler.put_chat_conversation(workspace, &conversation).unwrap();
+    }
+
+    let ids = |requester: Option<&str>| {
+        let (total, conversations) =
+            index_scheduler.paginated_chat_conversations("my-workspace", requester, 0, 20).unwrap();
+        let ids: Vec<_> = conversations.into_iter().map(|conversation| conversation.id).collect();
+        assert_eq!(total, ids.len());
+        ids
+    };
+    snapshot!(format!("{:?}", ids(None)), @r###"["by-key", "by-master-key", "by-other-key", "by-token"]"###);
+    snapshot!(format!("{:?}", ids(Some("key:1234"))), @r###"["by-key", "by-token"]"###);
+    snapshot!(format!("{:?}", ids(Some("key:1234:token:abcd"))), @r###"["by-token"]"###);
+    snapshot!(format!("{:?}", ids(Some("key:1234:token:ef01"))), @"[]");
+    snapshot!(format!("{:?}", ids(Some("key:5678"))), @r###"["by-other-key"]"###);
+    snapshot!(format!("{:?}", ids(Some("key:12"))), @"[]");
+
+    let conversation =
+        index_scheduler.chat_conversation("my-workspace", "by-token").unwrap().unwrap();
+    assert!(conversation.is_accessible_by(Some("key:1234")));
+    assert!(!conversation.is_accessible_by(Some("key:5678")));
+    assert!(!conversation.is_accessible_by(Some("key:1234:token:ef01")));
+}
+
+#[test]
+fn chat_conversation_ids_must_fit_in_a_key() {
+    let (index_scheduler, _handle) = IndexScheduler::test(true, vec![]);
+    let now = datetime!(2025-01-01 00:00 UTC);
+
+    // `my-workspace\0` takes 13 of the 511 bytes of the key
+    let longest = "a".repeat(498);
+    let conversation = ChatConversation::new(longest.clone(), None, now);
+    index_scheduler.put_chat_conversation("my-workspace", &conversation).unwrap();
+    assert!(index_scheduler.chat_conversation("my-workspace", &longest).unwrap().is_some());
+
+    let too_long = "a".repeat(499);
+    let conversation = ChatConversation::new(too_long.clone(), None, now);
+    let err = index_scheduler.put_chat_conversation("my-workspace", &conversation).unwrap_err();
+    assert!(matches!(err, Error::ChatConversationIdTooLong { .. }), "{err}");
+    let err = index_scheduler.chat_conversation("my-workspace", &too_long).unwrap_err();
+    assert!(matches!(err, Error::ChatConversationIdTooLong { .. }), "{err}");
+    let err = index_scheduler.delete_chat_conversation("my-workspace", &too_long).unwrap_err();
+    assert!(matches!(err, Error::ChatConversationIdTooLong { .. }), "{err}");
+}
@@ -456,6 +456,7 @@ fn import_vectors_first_and_embedder_later() {
                         ),
                         distribution: None,
                         pooling: UseModel,
+                        model_path: None,
                     },
                 ),
                 prompt: PromptData {
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::time::Instant;
 
 use big_s::S;
@@ -5,7 +6,7 @@ use meili_snap::snapshot;
 use meilisearch_types::milli::update::IndexDocumentsMethod::*;
 use meilisearch_types::milli::update::{MissingDocumentPolicy, Setting};
 use meilisearch_types::milli::{obkv_to_json, FilterableAttributesRule};
-use meilisearch_types::tasks::{Kind, KindWithContent};
+use meilisearch_types::tasks::{Details, Kind, KindWithContent, Status};
 
 use crate::insta_snapshot::snapshot_index_scheduler;
 use crate::test_utils::Breakpoint::*;
@@ -114,6 +115,71 @@ fn fail_in_update_task_after_process_batch_success_for_document_addition() {
     snapshot!(snapshot_index_scheduler(&index_scheduler), name: "task_successfully_processed");
 }
 
+#[test]
+fn fail_in_process_batch_for_dump_import() {
+    let (index_scheduler, mut handle) =
+        IndexScheduler::test(true, vec![(3, FailureLocation::ProcessDumpImport)]);
+
+    let content = r#"
+        {
+            "id": 1,
+            "doggo": "bob"
+        }"#;
+
+    let (uuid, mut file) = index_scheduler.queue.create_update_file_with_uuid(0).unwrap();
+    let documents_count = read_json(content.as_bytes(), &mut file).unwrap();
+    file.persist().unwrap();
+    index_scheduler
+        .register(KindWithContent::DocumentAdditionOrUpdate {
+            index_uid: S("doggos"),
+            primary_key: Some(S("id")),
+            method: ReplaceDocuments,
+            content_file: uuid,
+            documents_count,
+            allow_index_creation: true,
+            on_missing_document: MissingDocumentPolicy::default(),
+        })
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    index_scheduler
+        .register(KindWithContent::DumpCreation {
+            keys: Vec::new(),
+            signing_keys: Vec::new(),
+            instance_uid: None,
+            selection: Default::default(),
+        })
+        .unwrap();
+    handle.advance_one_successful_batch();
+
+    let rtxn = index_scheduler.read_txn().unwrap();
+    let dump = index_scheduler.queue.tasks.get_task(&rtxn, 1).unwrap().unwrap();
+    let Some(Details::Dump { dump_uid: Some(dump_uid) }) = dump.details else {
+        panic!("unexpected details: {:?}", dump.details)
+    };
+    drop(rtxn);
+
+    // The settings of `puppies` are imported, then the import fails before its documents
+    index_scheduler
+        .register(KindWithContent::DumpImport {
+            dump_uid,
+            index_renames: BTreeMap::from([(S("doggos"), S("puppies"))]),
+            skip_existing_indexes: false,
+        })
+        .unwrap();
+    handle.advance_one_failed_batch();
+
+    let rtxn = index_scheduler.read_txn().unwrap();
+    let task = index_scheduler.queue.tasks.get_task(&rtxn, 2).unwrap().unwrap();
+    assert_eq!(task.status, Status::Failed);
+    snapshot!(task.error.unwrap().message, @"Planned failure for tests.");
+    drop(rtxn);
+
+    // The partially imported index is deleted, the other indexes are untouched
+    assert!(!index_scheduler.user_index_exists("puppies").unwrap());
+    assert!(index_scheduler.user_index_exists("doggos").unwrap());
+}
+
 #[test]
 fn fail_in_process_batch_for_document_deletion() {
     let (index_scheduler, mut handle) = IndexScheduler::test(true, vec![]);
@@ -0,0 +1,69 @@
+//! Persistence of the schedules, the tasks that are enqueued automatically following a cron expression.
+
+use meilisearch_types::heed::types::SerdeJson;
+use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn, WithoutTls};
+use meilisearch_types::schedules::Schedule;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::uuid_codec::UuidCodec;
+use crate::Result;
+
+mod db_name {
+    pub const SCHEDULES: &str = "schedules";
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Schedules {
+    /// All the schedules, by uid.
+    schedules: Database<UuidCodec, SerdeJson<Schedule>>,
+}
+
+impl Schedules {
+    pub(crate) const fn nb_db() -> u32 {
+        1
+    }
+
+    pub(crate) fn new(env: &Env<WithoutTls>, wtxn: &mut RwTxn) -> Result<Self> {
+        Ok(Self { schedules: env.create_database(wtxn, Some(db_name::SCHEDULES))? })
+    }
+
+    pub(crate) fn get(&self, rtxn: &RoTxn, uid: Uuid) -> Result<Option<Schedule>> {
+        Ok(self.schedules.get(rtxn, &uid)?)
+    }
+
+    pub(crate) fn put(&self, wtxn: &mut RwTxn, schedule: &Schedule) -> Result<()> {
+        Ok(self.schedules.put(wtxn, &schedule.uid, schedule)?)
+    }
+
+    pub(crate) fn delete(&self, wtxn: &mut RwTxn, uid: Uuid) -> Result<bool> {
+        Ok(self.schedules.delete(wtxn, &uid)?)
+    }
+
+    /// Returns all the schedules, sorted by creation date.
+    pub(crate) fn list(&self, rtxn: &RoTxn) -> Result<Vec<Schedule>> {
+        let mut schedules = Vec::new();
+        for ret in self.schedules.iter(rtxn)? {
+            let (_, schedule) = ret?;
+            schedules.push(schedule);
+        }
+        schedules.sort_by_key(|schedule| schedule.created_at);
+        Ok(schedules)
+    }
+
+    pub(crate) fn len(&self, rtxn: &RoTxn) -> Result<u64> {
+        Ok(self.schedules.len(rtxn)?)
+    }
+
+    /// Returns the schedules that must enqueue their task now.
+    pub(crate) fn due(&self, rtxn: &RoTxn, now: OffsetDateTime) -> Result<Vec<Schedule>> {
+        let mut schedules = Vec::new();
+        for ret in self.schedules.iter(rtxn)? {
+            let (_, schedule) = ret?;
+            if schedule.is_due(now) {
+                schedules.push(schedule);
+            }
+        }
+        Ok(schedules)
+    }
+}
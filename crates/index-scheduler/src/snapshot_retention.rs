@@ -0,0 +1,141 @@
+use std::num::NonZeroU64;
+use std::time::Duration;
+
+use time::format_description::FormatItem;
+use time::macros::format_description;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+/// The format of the timestamp appended to the name of the snapshots when a
+/// retention policy is enabled, it is the same one as the dump uids.
+const SNAPSHOT_TIMESTAMP_FORMAT: &[FormatItem<'static>] = format_description!(
+    "[year repr:full][month repr:numerical][day padding:zero]-[hour padding:zero][minute padding:zero][second padding:zero][subsecond digits:3]"
+);
+
+/// Defines which snapshots must be kept after a new snapshot is created,
+/// both in the snapshots directory and in the S3 bucket.
+///
+/// When no limit is defined, snapshots are not timestamped and every new
+/// snapshot overwrites the previous one, as before.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotRetention {
+    /// The maximum number of snapshots to keep, the most recent ones are kept.
+    pub max_count: Option<NonZeroU64>,
+    /// The maximum age of a snapshot before it gets deleted.
+    pub max_age: Option<Duration>,
+}
+
+impl SnapshotRetention {
+    pub fn is_enabled(&self) -> bool {
+        self.max_count.is_some() || self.max_age.is_some()
+    }
+
+    /// Returns the file name of a snapshot of the `db_name` database created at `now`.
+    pub fn snapshot_name(&self, db_name: &str, now: OffsetDateTime) -> String {
+        if self.is_enabled() {
+            // safety: the format only contains numerical components
+            let timestamp = now.format(SNAPSHOT_TIMESTAMP_FORMAT).unwrap();
+            format!("{db_name}-{timestamp}.snapshot")
+        } else {
+            format!("{db_name}.snapshot")
+        }
+    }
+
+    /// Returns the creation date of a snapshot of the `db_name` database from its file name,
+    /// or `None` if the file is not a timestamped snapshot of this database.
+    pub fn snapshot_date(db_name: &str, name: &str) -> Option<OffsetDateTime> {
+        let timestamp = name.strip_prefix(db_name)?.strip_prefix('-')?.strip_suffix(".snapshot")?;
+        PrimitiveDateTime::parse(timestamp, SNAPSHOT_TIMESTAMP_FORMAT).ok().map(|d| d.assume_utc())
+    }
+
+    /// Returns the names of the snapshots of the `db_name` database that must be deleted.
+    ///
+    /// Files that are not timestamped snapshots of this database are never returned.
+    pub fn expired_snapshots<'a>(
+        &self,
+        db_name: &str,
+        names: impl IntoIterator<Item = &'a str>,
+        now: OffsetDateTime,
+    ) -> Vec<&'a str> {
+        if !self.is_enabled() {
+            return Vec::new();
+        }
+
+        let mut snapshots: Vec<_> = names
+            .into_iter()
+            .filter_map(|name| Self::snapshot_date(db_name, name).map(|date| (date, name)))
+            .collect();
+        // most recent snapshots first
+        snapshots.sort_unstable_by(|a, b| b.cmp(a));
+
+        snapshots
+            .into_iter()
+            .enumerate()
+            .filter(|(i, (date, _))| {
+                let too_many = self.max_count.is_some_and(|max| *i as u64 >= max.get());
+                let too_old = self.max_age.is_some_and(|max| now - *date > max);
+                too_many || too_old
+            })
+            .map(|(_, (_, name))| name)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn snapshot_names() {
+        let now = datetime!(2025-03-04 10:20:30.456 UTC);
+
+        let retention = SnapshotRetention::default();
+        assert_eq!(retention.snapshot_name("data.ms", now), "data.ms.snapshot");
+        assert_eq!(SnapshotRetention::snapshot_date("data.ms", "data.ms.snapshot"), None);
+
+        let retention = SnapshotRetention { max_count: NonZeroU64::new(2), max_age: None };
+        let name = retention.snapshot_name("data.ms", now);
+        assert_eq!(name, "data.ms-20250304-102030456.snapshot");
+        assert_eq!(SnapshotRetention::snapshot_date("data.ms", &name), Some(now));
+        assert_eq!(SnapshotRetention::snapshot_date("other.ms", &name), None);
+    }
+
+    #[test]
+    fn expired_snapshots() {
+        let now = datetime!(2025-03-04 12:00:00 UTC);
+        let names = [
+            "data.ms-20250304-110000000.snapshot",
+            "data.ms-20250301-110000000.snapshot",
+            "data.ms-20250304-090000000.snapshot",
+            "data.ms.snapshot",
+            "other.ms-20250101-000000000.snapshot",
+            "data.ms-20250304-090000000.snapshot.tmp",
+        ];
+
+        let retention = SnapshotRetention::default();
+        assert!(retention.expired_snapshots("data.ms", names, now).is_empty());
+
+        let retention = SnapshotRetention { max_count: NonZeroU64::new(2), max_age: None };
+        assert_eq!(
+            retention.expired_snapshots("data.ms", names, now),
+            ["data.ms-20250301-110000000.snapshot"]
+        );
+
+        let retention =
+            SnapshotRetention { max_count: None, max_age: Some(Duration::from_secs(4 * 3600)) };
+        assert_eq!(
+            retention.expired_snapshots("data.ms", names, now),
+            ["data.ms-20250301-110000000.snapshot"]
+        );
+
+        let retention = SnapshotRetention {
+            max_count: NonZeroU64::new(2),
+            max_age: Some(Duration::from_secs(90 * 60)),
+        };
+        assert_eq!(
+            retention.expired_snapshots("data.ms", names, now),
+            ["data.ms-20250304-090000000.snapshot", "data.ms-20250301-110000000.snapshot"]
+        );
+    }
+}
@@ -41,6 +41,7 @@ pub(crate) enum FailureLocation {
     InsideProcessBatch,
     PanicInsideProcessBatch,
     ProcessExport,
+    ProcessDumpImport,
     ProcessUpgrade,
     AcquiringWtxn,
     UpdatingTaskAfterProcessBatchSuccess { task_uid: u32 },
@@ -119,6 +120,8 @@ impl IndexScheduler {
             // NO DANGER: test code
             ip_policy: IpPolicy::danger_always_allow(),
             dsr_fuel: DsrFuel::new(3, 10, 3, 128, 128, FilterConstraintFuel::new(100, 100, 25)),
+            snapshot_retention: Default::default(),
+            incremental_snapshots: false,
         };
         let version = configuration(&mut options).unwrap_or({
             (versioning::VERSION_MAJOR, versioning::VERSION_MINOR, versioning::VERSION_PATCH)
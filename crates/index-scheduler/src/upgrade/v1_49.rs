@@ -66,7 +66,12 @@ impl LegacyDynamicSearchRule {
         for condition in conditions {
             match condition {
                 Condition::Query { is_empty, contains } => match &mut query {
-                    Some(QueryCondition { is_empty: existing_is_empty, words: existing_words }) => {
+                    Some(QueryCondition {
+                        is_empty: existing_is_empty,
+                        words: existing_words,
+                        prefix: _,
+                        regex: _,
+                    }) => {
                         if let (Some(existing_is_empty), Some(is_empty)) =
                             (existing_is_empty, is_empty)
                         {
@@ -84,7 +89,14 @@ impl LegacyDynamicSearchRule {
                             }
                         }
                     }
-                    None => query = Some(QueryCondition { is_empty, words: contains }),
+                    None => {
+                        query = Some(QueryCondition {
+                            is_empty,
+                            words: contains,
+                            prefix: None,
+                            regex: None,
+                        })
+                    }
                 },
                 Condition::Time { start, end } => match &mut time {
                     Some(TimeCondition { start: existing_start, end: existing_end }) => {
@@ -113,7 +125,8 @@ impl LegacyDynamicSearchRule {
             }
         }
 
-        let conditions = NewConditions { time, query, filter: None };
+        let conditions =
+            NewConditions { time, query, filter: None, locales: None, tenant_token: None };
 
         Some(NewDynamicSearchRule {
             uid,
@@ -177,7 +190,7 @@ impl RuleAction {
         let Selector { index_uid, id } = selector;
         let id = id?;
 
-        let selector = NewSelector { index_uid, id };
+        let selector = NewSelector { index_uid, id: Some(id), filter: None };
         let action = match action {
             DynamicSearchRuleAction::Pin { position } => {
                 NewDynamicSearchRuleAction::Pin { position }
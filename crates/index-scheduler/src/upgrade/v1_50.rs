@@ -1,5 +1,5 @@
 use meilisearch_types::heed::{Env, RwTxn, WithoutTls};
-use meilisearch_types::tasks::{KindWithContent, Status, Task};
+use meilisearch_types::tasks::{KindWithContent, Status, Task, TaskPriority};
 use time::OffsetDateTime;
 
 use super::UpgradeIndexScheduler;
@@ -65,6 +65,8 @@ impl UpgradeIndexScheduler for MigrateDynamicSearchRules {
                     kind,
                     network: None,
                     custom_metadata: None,
+                    schedule_uid: None,
+                    priority: TaskPriority::Normal,
                 },
             )?;
         }
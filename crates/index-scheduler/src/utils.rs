@@ -358,6 +358,7 @@ pub fn swap_index_uid_in_task(task: &mut Task, swap: (&str, &str)) {
         K::TaskCancelation { .. }
         | K::TaskDeletion { .. }
         | K::DumpCreation { .. }
+        | K::DumpImport { .. }
         | K::Export { .. }
         | K::UpgradeDatabase { .. }
         | K::NetworkTopologyChange(_)
@@ -457,6 +458,8 @@ impl crate::IndexScheduler {
                 kind,
                 network: _,
                 custom_metadata: _,
+                schedule_uid: _,
+                priority,
             } = task;
             assert_eq!(uid, task.uid);
             if task.status != Status::Enqueued {
@@ -491,6 +494,7 @@ impl crate::IndexScheduler {
                     .unwrap()
                     .contains(task.uid));
             }
+            assert!(self.queue.tasks.get_priority(&rtxn, priority).unwrap().contains(uid));
             let db_enqueued_at = self
                 .queue
                 .tasks
@@ -710,6 +714,13 @@ impl crate::IndexScheduler {
                     Details::DsrUpdate { .. } => {
                         assert_eq!(kind.as_kind(), Kind::DsrUpdate);
                     }
+                    Details::DumpImport {
+                        dump_uid: _,
+                        imported_indexes: _,
+                        skipped_indexes: _,
+                    } => {
+                        assert_eq!(kind.as_kind(), Kind::DumpImport);
+                    }
                 }
             }
 
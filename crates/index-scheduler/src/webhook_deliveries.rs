@@ -0,0 +1,214 @@
+//! Persistence of the webhook notifications that could not be delivered.
+//!
+//! A notification is only stored once its first delivery attempt failed. It is then retried with an
+//! exponential backoff until it succeeds or [`MAX_ATTEMPTS`] attempts were made. Past that point it stays
+//! in the database as a dead letter until it is manually redelivered or its webhook is deleted.
+
+use std::time::Duration;
+
+use meilisearch_types::heed::types::SerdeJson;
+use meilisearch_types::heed::{Database, Env, RoTxn, RwTxn, WithoutTls};
+use meilisearch_types::milli::BEU32;
+use meilisearch_types::tasks::TaskId;
+use meilisearch_types::webhooks::{DeliveryAttempt, DeliveryId, DeliveryStatus, WebhookDelivery};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::Result;
+
+/// The number of attempts, including the first one, after which a delivery is not retried automatically anymore.
+pub const MAX_ATTEMPTS: usize = 10;
+/// The delay before the first retry. It is doubled after each failed attempt.
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(10);
+/// The number of deliveries kept per webhook, the oldest finished deliveries are removed first.
+const MAX_DELIVERIES_PER_WEBHOOK: usize = 100;
+
+mod db_name {
+    pub const WEBHOOK_DELIVERIES: &str = "webhook-deliveries";
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookDeliveries {
+    /// All the deliveries that failed at least once, by delivery id.
+    deliveries: Database<BEU32, SerdeJson<WebhookDelivery>>,
+}
+
+impl WebhookDeliveries {
+    pub(crate) const fn nb_db() -> u32 {
+        1
+    }
+
+    pub(crate) fn new(env: &Env<WithoutTls>, wtxn: &mut RwTxn) -> Result<Self> {
+        Ok(Self { deliveries: env.create_database(wtxn, Some(db_name::WEBHOOK_DELIVERIES))? })
+    }
+
+    pub(crate) fn get(&self, rtxn: &RoTxn, uid: DeliveryId) -> Result<Option<WebhookDelivery>> {
+        Ok(self.deliveries.get(rtxn, &uid)?)
+    }
+
+    pub(crate) fn put(&self, wtxn: &mut RwTxn, delivery: &WebhookDelivery) -> Result<()> {
+        Ok(self.deliveries.put(wtxn, &delivery.uid, delivery)?)
+    }
+
+    /// Returns the deliveries of a webhook, most recent first.
+    pub(crate) fn list(&self, rtxn: &RoTxn, webhook_uuid: Uuid) -> Result<Vec<WebhookDelivery>> {
+        let mut deliveries = Vec::new();
+        for ret in self.deliveries.rev_iter(rtxn)? {
+            let (_, delivery) = ret?;
+            if delivery.webhook_uuid == webhook_uuid {
+                deliveries.push(delivery);
+            }
+        }
+        Ok(deliveries)
+    }
+
+    /// Returns the pending deliveries that must be attempted again now.
+    pub(crate) fn due(&self, rtxn: &RoTxn, now: OffsetDateTime) -> Result<Vec<WebhookDelivery>> {
+        let mut deliveries = Vec::new();
+        for ret in self.deliveries.iter(rtxn)? {
+            let (_, delivery) = ret?;
+            if delivery.status == DeliveryStatus::Pending
+                && delivery.next_attempt_at.is_some_and(|next| next <= now)
+            {
+                deliveries.push(delivery);
+            }
+        }
+        Ok(deliveries)
+    }
+
+    pub(crate) fn has_pending(&self, rtxn: &RoTxn) -> Result<bool> {
+        for ret in self.deliveries.iter(rtxn)? {
+            let (_, delivery) = ret?;
+            if delivery.status == DeliveryStatus::Pending {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Stores a delivery whose first attempt failed, and plans its first retry.
+    pub(crate) fn register_failure(
+        &self,
+        wtxn: &mut RwTxn,
+        webhook_uuid: Uuid,
+        task_uids: Vec<TaskId>,
+        attempt: DeliveryAttempt,
+    ) -> Result<WebhookDelivery> {
+        let uid = match self.deliveries.last(wtxn)? {
+            Some((uid, _)) => uid + 1,
+            None => 0,
+        };
+        let mut delivery = WebhookDelivery {
+            uid,
+            webhook_uuid,
+            task_uids,
+            status: DeliveryStatus::Pending,
+            attempts: Vec::new(),
+            next_attempt_at: None,
+        };
+        record_attempt(&mut delivery, attempt);
+        self.put(wtxn, &delivery)?;
+        self.prune(wtxn, webhook_uuid)?;
+        Ok(delivery)
+    }
+
+    /// Removes the oldest finished deliveries of a webhook when it has too many of them.
+    fn prune(&self, wtxn: &mut RwTxn, webhook_uuid: Uuid) -> Result<()> {
+        let mut count = 0;
+        let mut to_delete = Vec::new();
+        for ret in self.deliveries.rev_iter(wtxn)? {
+            let (uid, delivery) = ret?;
+            if delivery.webhook_uuid != webhook_uuid {
+                continue;
+            }
+            count += 1;
+            if count > MAX_DELIVERIES_PER_WEBHOOK && delivery.status != DeliveryStatus::Pending {
+                to_delete.push(uid);
+            }
+        }
+
+        for uid in to_delete {
+            self.deliveries.delete(wtxn, &uid)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the deliveries of the webhooks that must not be kept.
+    pub(crate) fn retain_webhooks(
+        &self,
+        wtxn: &mut RwTxn,
+        keep: impl Fn(&Uuid) -> bool,
+    ) -> Result<()> {
+        let mut iter = self.deliveries.iter_mut(wtxn)?;
+        while let Some(ret) = iter.next() {
+            let (_, delivery) = ret?;
+            if !keep(&delivery.webhook_uuid) {
+                // safety: we don't keep references to the database while iterating
+                unsafe { iter.del_current()? };
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Records an attempt to deliver the notification and plans the next one if it failed.
+pub(crate) fn record_attempt(delivery: &mut WebhookDelivery, attempt: DeliveryAttempt) {
+    let succeeded = attempt.error.is_none();
+    let attempted_at = attempt.attempted_at;
+    delivery.attempts.push(attempt);
+
+    if succeeded {
+        delivery.status = DeliveryStatus::Succeeded;
+        delivery.next_attempt_at = None;
+    } else if delivery.attempts.len() >= MAX_ATTEMPTS {
+        delivery.status = DeliveryStatus::Failed;
+        delivery.next_attempt_at = None;
+    } else {
+        // 10s, 20s, 40s... between two attempts
+        let delay = RETRY_BASE_DELAY * 2u32.pow(delivery.attempts.len() as u32 - 1);
+        delivery.status = DeliveryStatus::Pending;
+        delivery.next_attempt_at = Some(attempted_at + delay);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed_attempt(attempted_at: OffsetDateTime) -> DeliveryAttempt {
+        DeliveryAttempt { attempted_at, status_code: Some(500), error: Some(String::from("boom")) }
+    }
+
+    #[test]
+    fn retries_with_exponential_backoff_then_gives_up() {
+        let start = OffsetDateTime::UNIX_EPOCH;
+        let mut delivery = WebhookDelivery {
+            uid: 0,
+            webhook_uuid: Uuid::nil(),
+            task_uids: vec![0],
+            status: DeliveryStatus::Pending,
+            attempts: Vec::new(),
+            next_attempt_at: None,
+        };
+
+        record_attempt(&mut delivery, failed_attempt(start));
+        assert_eq!(delivery.status, DeliveryStatus::Pending);
+        assert_eq!(delivery.next_attempt_at, Some(start + Duration::from_secs(10)));
+
+        record_attempt(&mut delivery, failed_attempt(start));
+        assert_eq!(delivery.next_attempt_at, Some(start + Duration::from_secs(20)));
+
+        for _ in 2..MAX_ATTEMPTS {
+            record_attempt(&mut delivery, failed_attempt(start));
+        }
+        assert_eq!(delivery.attempts.len(), MAX_ATTEMPTS);
+        assert_eq!(delivery.status, DeliveryStatus::Failed);
+        assert_eq!(delivery.next_attempt_at, None);
+
+        let succeeded =
+            DeliveryAttempt { attempted_at: start, status_code: Some(200), error: None };
+        record_attempt(&mut delivery, succeeded);
+        assert_eq!(delivery.status, DeliveryStatus::Succeeded);
+        assert_eq!(delivery.next_attempt_at, None);
+    }
+}
@@ -8,6 +8,7 @@ use serde_json::Deserializer;
 use crate::{AuthController, HeedAuthStore, Result};
 
 const KEYS_PATH: &str = "keys";
+const SIGNING_KEYS_PATH: &str = "signing-keys";
 
 impl AuthController {
     pub fn dump(auth_env: Env<WithoutTls>, dst: impl AsRef<Path>) -> Result<()> {
@@ -22,6 +23,15 @@ impl AuthController {
             keys_file.write_all(b"\n")?;
         }
 
+        let signing_keys_file_path = dst.as_ref().join(SIGNING_KEYS_PATH);
+
+        let signing_keys = store.list_signing_keys()?;
+        let mut signing_keys_file = File::create(signing_keys_file_path)?;
+        for signing_key in signing_keys {
+            serde_json::to_writer(&mut signing_keys_file, &signing_key)?;
+            signing_keys_file.write_all(b"\n")?;
+        }
+
         Ok(())
     }
 
@@ -30,13 +40,20 @@ impl AuthController {
 
         let keys_file_path = src.as_ref().join(KEYS_PATH);
 
-        if !keys_file_path.exists() {
-            return Ok(());
+        if keys_file_path.exists() {
+            let reader = BufReader::new(File::open(&keys_file_path)?);
+            for key in Deserializer::from_reader(reader).into_iter() {
+                store.put_api_key(key?)?;
+            }
         }
 
-        let reader = BufReader::new(File::open(&keys_file_path)?);
-        for key in Deserializer::from_reader(reader).into_iter() {
-            store.put_api_key(key?)?;
+        let signing_keys_file_path = src.as_ref().join(SIGNING_KEYS_PATH);
+
+        if signing_keys_file_path.exists() {
+            let reader = BufReader::new(File::open(&signing_keys_file_path)?);
+            for signing_key in Deserializer::from_reader(reader).into_iter() {
+                store.put_signing_key(&signing_key?)?;
+            }
         }
 
         Ok(())
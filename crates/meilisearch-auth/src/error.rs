@@ -3,6 +3,8 @@ use std::error::Error;
 use meilisearch_types::error::{Code, ErrorCode};
 use meilisearch_types::internal_error;
 
+use crate::RateLimitError;
+
 pub type Result<T> = std::result::Result<T, AuthControllerError>;
 
 #[derive(Debug, thiserror::Error)]
@@ -13,6 +15,14 @@ pub enum AuthControllerError {
     ApiKeyAlreadyExists(String),
     #[error("cannot create index-scoped API key with global action `{0}`\n  - Hint: remove action `{0}` from the action list, or remove `indexes` to make the key global")]
     IndexScopedApiKeyWithGlobalAction(String),
+    #[error("Signing key `{0}` not found.")]
+    SigningKeyNotFound(String),
+    #[error("`kid` field value `{0}` is already an existing signing key.")]
+    SigningKeyAlreadyExists(String),
+    #[error("a signing key cannot delegate the action `{0}`, only tenant tokens actions can be delegated\n  - Hint: use one of `search`, `chatCompletions` or `*`")]
+    SigningKeyWithUndelegableAction(String),
+    #[error(transparent)]
+    RateLimited(#[from] RateLimitError),
     #[error("Internal error: {0}")]
     Internal(Box<dyn Error + Send + Sync + 'static>),
 }
@@ -30,6 +40,10 @@ impl ErrorCode for AuthControllerError {
             Self::ApiKeyNotFound(_) => Code::ApiKeyNotFound,
             Self::ApiKeyAlreadyExists(_) => Code::ApiKeyAlreadyExists,
             Self::IndexScopedApiKeyWithGlobalAction(_) => Code::IndexScopedApiKeyWithGlobalAction,
+            Self::SigningKeyNotFound(_) => Code::SigningKeyNotFound,
+            Self::SigningKeyAlreadyExists(_) => Code::SigningKeyAlreadyExists,
+            Self::SigningKeyWithUndelegableAction(_) => Code::InvalidSigningKeyActions,
+            Self::RateLimited(e) => e.error_code(),
             Self::Internal(_) => Code::Internal,
         }
     }
@@ -1,16 +1,24 @@
 mod dump;
 pub mod error;
+mod rate_limit;
 mod store;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::Arc;
 
 use error::{AuthControllerError, Result};
 use maplit::hashset;
 use meilisearch_types::heed::{Env, WithoutTls};
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
-use meilisearch_types::keys::{Action, CreateApiKey, Key, PatchApiKey};
+use meilisearch_types::keys::{
+    Action, CreateApiKey, CreateSigningKey, Key, PatchApiKey, SigningKey,
+};
 use meilisearch_types::milli::update::Setting;
+use meilisearch_types::milli::{is_faceted_by, AttributePatterns, PatternMatch};
+use rate_limit::RateLimiter;
+pub use rate_limit::{ConcurrentSearchPermit, KeyUsage, RateLimitError};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 pub use store::open_auth_store_env;
 use store::{generate_key_as_hexa, HeedAuthStore};
 use time::OffsetDateTime;
@@ -20,6 +28,7 @@ use uuid::Uuid;
 pub struct AuthController {
     store: HeedAuthStore,
     master_key: Option<String>,
+    rate_limiter: Arc<RateLimiter>,
 }
 
 impl AuthController {
@@ -30,7 +39,7 @@ impl AuthController {
             generate_default_keys(&store)?;
         }
 
-        Ok(Self { store, master_key: master_key.clone() })
+        Ok(Self { store, master_key: master_key.clone(), rate_limiter: Default::default() })
     }
 
     /// Return `Ok(())` if the auth controller is able to access one of its database.
@@ -79,6 +88,10 @@ impl AuthController {
             Setting::NotSet => (),
             name => key.name = name.set(),
         };
+        match patch.rate_limits {
+            Setting::NotSet => (),
+            rate_limits => key.rate_limits = rate_limits.set(),
+        };
         key.updated_at = OffsetDateTime::now_utc();
         self.store.put_api_key(key)
     }
@@ -113,8 +126,19 @@ impl AuthController {
         let key_authorized_indexes = SearchRules::Set(key.indexes.into_iter().collect());
 
         let allow_index_creation = self.is_key_authorized(uid, Action::IndexesAdd, None)?;
-
-        Ok(AuthFilter { search_rules, key_authorized_indexes, allow_index_creation })
+        let allow_task_prioritization =
+            self.is_key_authorized(uid, Action::TasksPrioritize, None)?;
+
+        Ok(AuthFilter {
+            key_uid: Some(uid),
+            signing_key_kid: None,
+            search_rules,
+            key_authorized_indexes,
+            allow_index_creation,
+            allow_task_prioritization,
+            tenant_token_claims: None,
+            search_permit: None,
+        })
     }
 
     pub fn list_keys(&self) -> Result<Vec<Key>> {
@@ -123,12 +147,86 @@ impl AuthController {
 
     pub fn delete_key(&self, uid: Uuid) -> Result<()> {
         if self.store.delete_api_key(uid)? {
+            self.rate_limiter.remove(uid);
             Ok(())
         } else {
             Err(AuthControllerError::ApiKeyNotFound(uid.to_string()))
         }
     }
 
+    pub fn create_signing_key(&self, create_key: CreateSigningKey) -> Result<SigningKey> {
+        if let Some(action) =
+            create_key.actions.iter().find(|action| !SigningKey::is_delegable(**action))
+        {
+            return Err(AuthControllerError::SigningKeyWithUndelegableAction(
+                // unwrap: action is serializable
+                serde_json::to_string(action).unwrap(),
+            ));
+        }
+        match self.store.get_signing_key(&create_key.kid)? {
+            Some(_) => Err(AuthControllerError::SigningKeyAlreadyExists(create_key.kid)),
+            None => {
+                let key = create_key.to_signing_key();
+                self.store.put_signing_key(&key)?;
+                Ok(key)
+            }
+        }
+    }
+
+    pub fn get_signing_key(&self, kid: &str) -> Result<SigningKey> {
+        self.store
+            .get_signing_key(kid)?
+            .ok_or_else(|| AuthControllerError::SigningKeyNotFound(kid.to_string()))
+    }
+
+    pub fn list_signing_keys(&self) -> Result<Vec<SigningKey>> {
+        self.store.list_signing_keys()
+    }
+
+    pub fn delete_signing_key(&self, kid: &str) -> Result<()> {
+        if self.store.delete_signing_key(kid)? {
+            Ok(())
+        } else {
+            Err(AuthControllerError::SigningKeyNotFound(kid.to_string()))
+        }
+    }
+
+    /// Returns the filters of a tenant token signed with the signing key.
+    /// The indexes of the signing key play the role of the indexes of the API key of a regular tenant token.
+    pub fn get_signing_key_filters(
+        &self,
+        signing_key: &SigningKey,
+        search_rules: SearchRules,
+    ) -> AuthFilter {
+        AuthFilter {
+            key_uid: None,
+            signing_key_kid: Some(signing_key.kid.clone()),
+            search_rules: Some(search_rules),
+            key_authorized_indexes: SearchRules::Set(signing_key.indexes.iter().cloned().collect()),
+            allow_index_creation: false,
+            allow_task_prioritization: false,
+            tenant_token_claims: None,
+            search_permit: None,
+        }
+    }
+
+    /// Counts a request made with the key and enforces the rate limits of the key.
+    ///
+    /// When `search` is `true`, the returned permit counts the search as running until it is dropped.
+    pub fn register_request(
+        &self,
+        uid: Uuid,
+        search: bool,
+    ) -> Result<Option<ConcurrentSearchPermit>> {
+        let key = self.get_key(uid)?;
+        Ok(self.rate_limiter.register_request(uid, key.rate_limits.as_ref(), search)?)
+    }
+
+    /// Returns the usage counters of the key since Meilisearch started.
+    pub fn key_usage(&self, uid: Uuid) -> KeyUsage {
+        self.rate_limiter.usage(uid)
+    }
+
     pub fn get_master_key(&self) -> Option<&String> {
         self.master_key.as_ref()
     }
@@ -182,6 +280,25 @@ impl AuthController {
         })
     }
 
+    /// Check if the request authenticated with the filter is also authorized to make the action.
+    ///
+    /// The master key is authorized to make every action, while tenant tokens are restricted
+    /// to the action of the route they are used on.
+    pub fn is_filter_authorized(
+        &self,
+        filter: &AuthFilter,
+        action: Action,
+        index: Option<&str>,
+    ) -> Result<bool> {
+        if filter.is_tenant_token() {
+            return Ok(false);
+        }
+        match filter.key_uid {
+            Some(uid) => self.is_key_authorized(uid, action, index),
+            None => Ok(true),
+        }
+    }
+
     /// Delete all the keys in the DB.
     pub fn raw_delete_all_keys(&mut self) -> Result<()> {
         self.store.delete_all_keys()
@@ -192,21 +309,44 @@ impl AuthController {
         self.store.put_api_key(key)?;
         Ok(())
     }
+
+    /// Delete all the signing keys in the DB.
+    pub fn raw_delete_all_signing_keys(&mut self) -> Result<()> {
+        self.store.delete_all_signing_keys()
+    }
+
+    /// Insert a signing key directly into the store.
+    pub fn raw_insert_signing_key(&mut self, signing_key: SigningKey) -> Result<()> {
+        self.store.put_signing_key(&signing_key)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct AuthFilter {
+    /// The API key used to authenticate, `None` when the master key was used.
+    key_uid: Option<Uuid>,
+    /// The signing key of the tenant token, when it was signed by an identity provider.
+    signing_key_kid: Option<String>,
     search_rules: Option<SearchRules>,
     key_authorized_indexes: SearchRules,
     allow_index_creation: bool,
+    allow_task_prioritization: bool,
+    tenant_token_claims: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Counts the search made with the API key as running as long as the filter is alive.
+    search_permit: Option<Arc<ConcurrentSearchPermit>>,
 }
 
 impl Default for AuthFilter {
     fn default() -> Self {
         Self {
+            key_uid: None,
+            signing_key_kid: None,
             search_rules: None,
             key_authorized_indexes: SearchRules::default(),
             allow_index_creation: true,
+            allow_task_prioritization: true,
+            tenant_token_claims: None,
+            search_permit: None,
         }
     }
 }
@@ -217,6 +357,12 @@ impl AuthFilter {
         self.allow_index_creation && self.is_index_authorized(index)
     }
 
+    /// Return true if the tasks can be enqueued with a priority other than the default one.
+    #[inline]
+    pub fn allow_task_prioritization(&self) -> bool {
+        self.allow_task_prioritization
+    }
+
     #[inline]
     /// Return true if a tenant token was used to generate the search rules.
     pub fn is_tenant_token(&self) -> bool {
@@ -225,12 +371,57 @@ impl AuthFilter {
 
     pub fn with_allowed_indexes(allowed_indexes: HashSet<IndexUidPattern>) -> Self {
         Self {
+            key_uid: None,
+            signing_key_kid: None,
             search_rules: None,
             key_authorized_indexes: SearchRules::Set(allowed_indexes),
             allow_index_creation: false,
+            allow_task_prioritization: false,
+            tenant_token_claims: None,
+            search_permit: None,
         }
     }
 
+    /// Attach the permit counting the running search of the API key.
+    pub fn with_search_permit(mut self, permit: Option<ConcurrentSearchPermit>) -> Self {
+        self.search_permit = permit.map(Arc::new);
+        self
+    }
+
+    /// Attach the custom claims of the tenant token that generated the search rules.
+    pub fn with_tenant_token_claims(
+        mut self,
+        claims: serde_json::Map<String, serde_json::Value>,
+    ) -> Self {
+        self.tenant_token_claims = Some(claims);
+        self
+    }
+
+    /// Return the custom claims of the tenant token, if a tenant token was used.
+    pub fn tenant_token_claims(&self) -> Option<&serde_json::Map<String, serde_json::Value>> {
+        self.tenant_token_claims.as_ref()
+    }
+
+    /// Identifies the API key or tenant token that made the request, `None` for the master key.
+    ///
+    /// A tenant token is identified by its issuer, followed by a hash of its search rules and custom claims.
+    /// Two tokens generated for the same tenant are then the same requester, even with a different expiration date.
+    pub fn requester(&self) -> Option<String> {
+        let issuer = match (&self.key_uid, &self.signing_key_kid) {
+            (Some(uid), _) => format!("key:{uid}"),
+            (None, Some(kid)) => format!("signingKey:{kid}"),
+            (None, None) => return None,
+        };
+        let Some(search_rules) = &self.search_rules else { return Some(issuer) };
+
+        let token = serde_json::json!({
+            "searchRules": search_rules,
+            "customClaims": self.tenant_token_claims,
+        });
+        let digest = Sha256::digest(canonical_json(token).to_string().as_bytes());
+        Some(format!("{issuer}:token:{}", hex::encode(digest)))
+    }
+
     pub fn all_indexes_authorized(&self) -> bool {
         self.key_authorized_indexes.all_indexes_authorized()
             && self
@@ -297,6 +488,26 @@ impl AuthFilter {
     }
 }
 
+/// Sorts the keys of the objects and the items of the arrays, so that the hash
+/// of the claims of a tenant token doesn't depend on the iteration order of the search rules.
+fn canonical_json(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<_> = object.into_iter().collect();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+            Value::Object(entries.into_iter().map(|(k, v)| (k, canonical_json(v))).collect())
+        }
+        Value::Array(array) => {
+            let mut array: Vec<_> = array.into_iter().map(canonical_json).collect();
+            array.sort_unstable_by_key(|value| value.to_string());
+            Value::Array(array)
+        }
+        value => value,
+    }
+}
+
 /// Transparent wrapper around a list of allowed indexes with the search rules to apply for each.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
@@ -373,9 +584,155 @@ impl IntoIterator for SearchRules {
 /// Contains the rules to apply on the top of the search query for a specific index.
 ///
 /// filter: search filter to apply in addition to query filters.
+/// attributesToRetrieve: attributes that can be retrieved, highlighted or cropped.
+/// attributesToHighlight: attributes that can be highlighted.
+/// facets: attribute patterns whose facet values can be requested.
+/// limit: maximum number of hits that can be requested per page.
 #[derive(Debug, Serialize, Deserialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct IndexSearchRules {
     pub filter: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes_to_retrieve: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes_to_highlight: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub facets: Option<AttributePatterns>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl IndexSearchRules {
+    /// Return true if the rules prevent some of the displayed attributes from being returned.
+    pub fn restricts_attributes(&self) -> bool {
+        self.attributes_to_retrieve.is_some()
+    }
+
+    /// Restrict the requested attributes to retrieve to the ones allowed by the rules.
+    ///
+    /// Requesting no attributes means requesting all of them.
+    pub fn restrict_attributes_to_retrieve(
+        &self,
+        requested: Option<BTreeSet<String>>,
+    ) -> Option<BTreeSet<String>> {
+        match &self.attributes_to_retrieve {
+            Some(allowed) => Some(restrict_attributes(
+                allowed,
+                requested.unwrap_or_else(|| BTreeSet::from(["*".to_string()])),
+            )),
+            None => requested,
+        }
+    }
+
+    /// Restrict the requested attributes to highlight to the ones allowed
+    /// to be both retrieved and highlighted by the rules.
+    pub fn restrict_attributes_to_highlight(
+        &self,
+        requested: Option<HashSet<String>>,
+    ) -> Option<HashSet<String>> {
+        let mut requested = requested?;
+        for allowed in
+            [&self.attributes_to_retrieve, &self.attributes_to_highlight].into_iter().flatten()
+        {
+            requested = restrict_attributes(allowed, requested);
+        }
+        Some(requested)
+    }
+
+    /// Restrict the requested attributes to crop to the ones allowed to be retrieved by the rules,
+    /// keeping the `attribute:length` syntax.
+    pub fn restrict_attributes_to_crop(
+        &self,
+        requested: Option<Vec<String>>,
+    ) -> Option<Vec<String>> {
+        let requested = requested?;
+        let Some(allowed) = &self.attributes_to_retrieve else { return Some(requested) };
+        let restricted = requested
+            .into_iter()
+            .flat_map(|attribute| {
+                let (name, length) = match attribute.rsplit_once(':') {
+                    Some((name, length)) => (name.to_string(), Some(length.to_string())),
+                    None => (attribute, None),
+                };
+                restrict_attributes::<Vec<_>>(allowed, [name]).into_iter().map(move |name| {
+                    match &length {
+                        Some(length) => format!("{name}:{length}"),
+                        None => name,
+                    }
+                })
+            })
+            .collect();
+        Some(restricted)
+    }
+
+    /// Restrict the requested facet patterns to the ones allowed by the rules.
+    pub fn restrict_facets(
+        &self,
+        requested: Option<AttributePatterns>,
+    ) -> Option<AttributePatterns> {
+        let requested = requested?;
+        let Some(allowed) = &self.facets else { return Some(requested) };
+        let patterns = requested
+            .patterns
+            .into_iter()
+            .flat_map(|pattern| {
+                if pattern == "*" {
+                    allowed.patterns.clone()
+                } else if self.is_facet_authorized(&pattern) {
+                    vec![pattern]
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect();
+        Some(AttributePatterns { patterns })
+    }
+
+    /// Return true if the attribute can be retrieved, and thus used to filter, sort or search.
+    pub fn is_attribute_authorized(&self, attribute: &str) -> bool {
+        match &self.attributes_to_retrieve {
+            Some(allowed) => is_attribute_allowed(allowed, attribute),
+            None => true,
+        }
+    }
+
+    /// Return true if the values of the facet can be requested.
+    pub fn is_facet_authorized(&self, facet: &str) -> bool {
+        match &self.facets {
+            Some(allowed) => allowed.match_str(facet) == PatternMatch::Match,
+            None => true,
+        }
+    }
+
+    /// Cap the requested number of hits to the limit of the rules.
+    pub fn restrict_limit(&self, limit: usize) -> usize {
+        self.limit.map_or(limit, |max| limit.min(max))
+    }
+}
+
+/// Keep the requested attributes that are allowed or nested in an allowed attribute,
+/// the `*` wildcard being replaced by the allowed attributes.
+fn restrict_attributes<C: FromIterator<String>>(
+    allowed: &[String],
+    requested: impl IntoIterator<Item = String>,
+) -> C {
+    requested
+        .into_iter()
+        .flat_map(|attribute| {
+            if attribute == "*" {
+                allowed.to_vec()
+            } else if is_attribute_allowed(allowed, &attribute) {
+                vec![attribute]
+            } else {
+                Vec::new()
+            }
+        })
+        .collect()
+}
+
+/// Return true if the attribute is allowed or nested in an allowed attribute.
+pub fn is_attribute_allowed(allowed: &[String], attribute: &str) -> bool {
+    allowed.iter().any(|allowed| allowed == "*" || is_faceted_by(attribute, allowed))
 }
 
 fn generate_default_keys(store: &HeedAuthStore) -> Result<()> {
@@ -0,0 +1,164 @@
+//! Enforcement of the rate limits of the API keys and tracking of their usage.
+//!
+//! The state is kept in memory only: the counters are reset when Meilisearch restarts.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Instant;
+
+use meilisearch_types::error::{Code, ErrorCode};
+use meilisearch_types::keys::KeyRateLimits;
+use uuid::Uuid;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RateLimitError {
+    #[error("The API key exceeded its limit of {limit} requests per second. Retry after {retry_after} seconds.")]
+    TooManyRequests { limit: usize, retry_after: u64 },
+    #[error("The API key exceeded its limit of {limit} concurrent searches. Retry once one of its ongoing searches is done.")]
+    TooManyConcurrentSearches { limit: usize },
+}
+
+impl RateLimitError {
+    /// The number of seconds the client should wait before retrying.
+    pub fn retry_after(&self) -> u64 {
+        match self {
+            RateLimitError::TooManyRequests { retry_after, .. } => *retry_after,
+            RateLimitError::TooManyConcurrentSearches { .. } => 1,
+        }
+    }
+}
+
+impl ErrorCode for RateLimitError {
+    fn error_code(&self) -> Code {
+        Code::ApiKeyRateLimitExceeded
+    }
+}
+
+/// The usage counters of an API key since Meilisearch started.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyUsage {
+    /// The number of requests authenticated with the key, including the rate limited ones.
+    pub requests: u64,
+    /// The number of requests rejected because they exceeded the rate limits of the key.
+    pub rate_limited_requests: u64,
+    /// The number of searches currently running with the key.
+    pub concurrent_searches: usize,
+}
+
+#[derive(Default)]
+struct KeyState {
+    /// The tokens available to make requests and when they were last refilled.
+    bucket: Mutex<Option<(f64, Instant)>>,
+    concurrent_searches: AtomicUsize,
+    requests: AtomicU64,
+    rate_limited_requests: AtomicU64,
+}
+
+/// Tracks the usage of every API key and enforces their rate limits.
+#[derive(Default)]
+pub struct RateLimiter {
+    keys: RwLock<HashMap<Uuid, Arc<KeyState>>>,
+}
+
+impl RateLimiter {
+    fn state(&self, uid: Uuid) -> Arc<KeyState> {
+        if let Some(state) = self.keys.read().unwrap().get(&uid) {
+            return state.clone();
+        }
+        self.keys.write().unwrap().entry(uid).or_default().clone()
+    }
+
+    /// Counts a request made with the key and checks it against the rate limits of the key.
+    ///
+    /// When `search` is `true`, the returned permit must be kept until the search is done.
+    pub fn register_request(
+        &self,
+        uid: Uuid,
+        limits: Option<&KeyRateLimits>,
+        search: bool,
+    ) -> Result<Option<ConcurrentSearchPermit>, RateLimitError> {
+        let state = self.state(uid);
+        state.requests.fetch_add(1, Ordering::Relaxed);
+
+        let result = Self::check_limits(&state, limits, search);
+        if result.is_err() {
+            state.rate_limited_requests.fetch_add(1, Ordering::Relaxed);
+        }
+        result
+    }
+
+    fn check_limits(
+        state: &Arc<KeyState>,
+        limits: Option<&KeyRateLimits>,
+        search: bool,
+    ) -> Result<Option<ConcurrentSearchPermit>, RateLimitError> {
+        let KeyRateLimits { requests_per_second, concurrent_searches } =
+            limits.copied().unwrap_or_default();
+
+        if let Some(limit) = requests_per_second {
+            let limit = limit.get();
+            let rate = limit as f64;
+            let now = Instant::now();
+            let mut bucket = state.bucket.lock().unwrap();
+            // the bucket starts full and refills at the rate of the limit
+            let (tokens, last_refill) = bucket.get_or_insert((rate, now));
+            *tokens = (*tokens + now.duration_since(*last_refill).as_secs_f64() * rate).min(rate);
+            *last_refill = now;
+            if *tokens < 1.0 {
+                let retry_after = ((1.0 - *tokens) / rate).ceil().max(1.0) as u64;
+                return Err(RateLimitError::TooManyRequests { limit, retry_after });
+            }
+            *tokens -= 1.0;
+        }
+
+        if !search {
+            return Ok(None);
+        }
+
+        let previous = state.concurrent_searches.fetch_add(1, Ordering::AcqRel);
+        // the permit decrements the counter back when dropped, including on error
+        let permit = ConcurrentSearchPermit { state: state.clone() };
+        match concurrent_searches {
+            Some(limit) if previous >= limit.get() => {
+                Err(RateLimitError::TooManyConcurrentSearches { limit: limit.get() })
+            }
+            _ => Ok(Some(permit)),
+        }
+    }
+
+    /// Returns the usage counters of the key.
+    pub fn usage(&self, uid: Uuid) -> KeyUsage {
+        match self.keys.read().unwrap().get(&uid) {
+            Some(state) => KeyUsage {
+                requests: state.requests.load(Ordering::Relaxed),
+                rate_limited_requests: state.rate_limited_requests.load(Ordering::Relaxed),
+                concurrent_searches: state.concurrent_searches.load(Ordering::Relaxed),
+            },
+            None => KeyUsage::default(),
+        }
+    }
+
+    /// Forgets the usage of a deleted key.
+    pub fn remove(&self, uid: Uuid) {
+        self.keys.write().unwrap().remove(&uid);
+    }
+}
+
+/// Counts a running search of an API key until it is dropped.
+pub struct ConcurrentSearchPermit {
+    state: Arc<KeyState>,
+}
+
+impl Drop for ConcurrentSearchPermit {
+    fn drop(&mut self) {
+        self.state.concurrent_searches.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+impl fmt::Debug for ConcurrentSearchPermit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcurrentSearchPermit").finish_non_exhaustive()
+    }
+}
@@ -9,9 +9,9 @@ use std::str::FromStr;
 use hmac::{Hmac, Mac};
 use meilisearch_types::heed::{BoxedError, WithoutTls};
 use meilisearch_types::index_uid_pattern::IndexUidPattern;
-use meilisearch_types::keys::KeyId;
+use meilisearch_types::keys::{KeyId, SigningKey};
 use meilisearch_types::milli::heed;
-use meilisearch_types::milli::heed::types::{Bytes, DecodeIgnore, SerdeJson};
+use meilisearch_types::milli::heed::types::{Bytes, DecodeIgnore, SerdeJson, Str};
 use meilisearch_types::milli::heed::{Database, Env, EnvOpenOptions, RwTxn};
 use sha2::Sha256;
 use thiserror::Error;
@@ -25,19 +25,21 @@ use super::{Action, Key};
 const AUTH_STORE_SIZE: usize = 1_073_741_824; //1GiB
 const KEY_DB_NAME: &str = "api-keys";
 const KEY_ID_ACTION_INDEX_EXPIRATION_DB_NAME: &str = "keyid-action-index-expiration";
+const SIGNING_KEY_DB_NAME: &str = "signing-keys";
 
 #[derive(Clone)]
 pub struct HeedAuthStore {
     env: Env<WithoutTls>,
     keys: Database<Bytes, SerdeJson<Key>>,
     action_keyid_index_expiration: Database<KeyIdActionCodec, SerdeJson<Option<OffsetDateTime>>>,
+    signing_keys: Database<Str, SerdeJson<SigningKey>>,
 }
 
 pub fn open_auth_store_env(path: &Path) -> heed::Result<Env<WithoutTls>> {
     let options = EnvOpenOptions::new();
     let mut options = options.read_txn_without_tls();
     options.map_size(AUTH_STORE_SIZE); // 1GB
-    options.max_dbs(2);
+    options.max_dbs(3);
     unsafe { options.open(path) }
 }
 
@@ -47,8 +49,9 @@ impl HeedAuthStore {
         let keys = env.create_database(&mut wtxn, Some(KEY_DB_NAME))?;
         let action_keyid_index_expiration =
             env.create_database(&mut wtxn, Some(KEY_ID_ACTION_INDEX_EXPIRATION_DB_NAME))?;
+        let signing_keys = env.create_database(&mut wtxn, Some(SIGNING_KEY_DB_NAME))?;
         wtxn.commit()?;
-        Ok(Self { env, keys, action_keyid_index_expiration })
+        Ok(Self { env, keys, action_keyid_index_expiration, signing_keys })
     }
 
     /// Return `Ok(())` if the auth store is able to access one of its database.
@@ -118,7 +121,7 @@ impl HeedAuthStore {
                     actions.extend([Action::SettingsGet, Action::SettingsUpdate].iter());
                 }
                 Action::DumpsAll => {
-                    actions.insert(Action::DumpsCreate);
+                    actions.extend([Action::DumpsCreate, Action::DumpsImport]);
                 }
                 Action::SnapshotsAll => {
                     actions.insert(Action::SnapshotsCreate);
@@ -129,6 +132,7 @@ impl HeedAuthStore {
                         Action::TasksDelete,
                         Action::TasksCancel,
                         Action::TasksCompact,
+                        Action::TasksPrioritize,
                     ]);
                 }
                 Action::StatsAll => {
@@ -159,6 +163,14 @@ impl HeedAuthStore {
                         Action::DynamicSearchRulesDelete,
                     ]);
                 }
+                Action::SchedulesAll => {
+                    actions.extend([
+                        Action::SchedulesGet,
+                        Action::SchedulesCreate,
+                        Action::SchedulesUpdate,
+                        Action::SchedulesDelete,
+                    ]);
+                }
                 other => {
                     actions.insert(*other);
                 }
@@ -235,6 +247,13 @@ impl HeedAuthStore {
         Ok(())
     }
 
+    pub fn delete_all_signing_keys(&self) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.signing_keys.clear(&mut wtxn)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
     pub fn list_api_keys(&self) -> Result<Vec<Key>> {
         let mut list = Vec::new();
         let rtxn = self.env.read_txn()?;
@@ -291,6 +310,36 @@ impl HeedAuthStore {
         Ok(exp)
     }
 
+    pub fn put_signing_key(&self, key: &SigningKey) -> Result<()> {
+        let mut wtxn = self.env.write_txn()?;
+        self.signing_keys.put(&mut wtxn, &key.kid, key)?;
+        wtxn.commit()?;
+        Ok(())
+    }
+
+    pub fn get_signing_key(&self, kid: &str) -> Result<Option<SigningKey>> {
+        let rtxn = self.env.read_txn()?;
+        self.signing_keys.get(&rtxn, kid).map_err(|e| e.into())
+    }
+
+    pub fn delete_signing_key(&self, kid: &str) -> Result<bool> {
+        let mut wtxn = self.env.write_txn()?;
+        let existing = self.signing_keys.delete(&mut wtxn, kid)?;
+        wtxn.commit()?;
+        Ok(existing)
+    }
+
+    pub fn list_signing_keys(&self) -> Result<Vec<SigningKey>> {
+        let rtxn = self.env.read_txn()?;
+        let mut list = Vec::new();
+        for result in self.signing_keys.iter(&rtxn)? {
+            let (_, key) = result?;
+            list.push(key);
+        }
+        list.sort_unstable_by_key(|k| Reverse(k.created_at));
+        Ok(list)
+    }
+
     fn delete_key_from_inverted_db(&self, wtxn: &mut RwTxn, key: &KeyId) -> Result<()> {
         let mut iter = self
             .action_keyid_index_expiration
@@ -12,10 +12,10 @@ use milli::update::ChatSettings;
 use crate::error::deserr_codes::{self, *};
 use crate::error::{
     Code, DeserrParseBoolError, DeserrParseIntError, ErrorCode, InvalidTaskDateError,
-    ParseOffsetDateTimeError,
+    ParseOffsetDateTimeError, ParseSigningKeyKidError,
 };
 use crate::index_uid::IndexUidFormatError;
-use crate::tasks::{ParseTaskKindError, ParseTaskStatusError};
+use crate::tasks::{ParseTaskKindError, ParseTaskPriorityError, ParseTaskStatusError};
 
 pub mod query_params;
 
@@ -173,6 +173,7 @@ make_missing_field_convenience_builder!(
     MissingDocumentEditionFunction,
     missing_document_edition_function
 );
+make_missing_field_convenience_builder!(MissingDumpImportDumpUid, missing_dump_import_dump_uid);
 
 // Integrate a sub-error into a [`DeserrError`] by taking its error message but using
 // the default error code (C) from `Self`
@@ -203,8 +204,10 @@ merge_with_error_impl_take_error_message!(DeserrParseBoolError);
 merge_with_error_impl_take_error_message!(uuid::Error);
 merge_with_error_impl_take_error_message!(InvalidTaskDateError);
 merge_with_error_impl_take_error_message!(ParseOffsetDateTimeError);
+merge_with_error_impl_take_error_message!(ParseSigningKeyKidError);
 merge_with_error_impl_take_error_message!(ParseTaskKindError);
 merge_with_error_impl_take_error_message!(ParseTaskStatusError);
+merge_with_error_impl_take_error_message!(ParseTaskPriorityError);
 merge_with_error_impl_take_error_message!(IndexUidFormatError);
 merge_with_error_impl_take_error_message!(InvalidMultiSearchWeight);
 merge_with_error_impl_take_error_message!(InvalidNetworkUrl);
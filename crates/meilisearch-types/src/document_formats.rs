@@ -1,13 +1,27 @@
 use std::fmt::{self, Debug, Display};
 use std::fs::File;
-use std::io::{self, BufWriter};
+use std::io::{self, BufReader, BufWriter};
 use std::marker::PhantomData;
 
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Float16Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+    UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, RecordBatch};
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_ipc::reader::StreamReader;
+use arrow_schema::{ArrowError, DataType};
 use bumpalo::Bump;
 use bumparaw_collections::RawMap;
 use memmap2::Mmap;
+use milli::constants::{
+    RESERVED_GEOJSON_FIELD_NAME, RESERVED_GEO_FIELD_NAME, RESERVED_VECTORS_FIELD_NAME,
+};
 use milli::documents::Error;
 use milli::Object;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::errors::ParquetError;
 use rustc_hash::FxBuildHasher;
 use serde::de::{SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer};
@@ -24,6 +38,8 @@ pub enum PayloadType {
     Ndjson,
     Json,
     Csv { delimiter: u8 },
+    Parquet,
+    ArrowStream,
 }
 
 impl fmt::Display for PayloadType {
@@ -32,6 +48,8 @@ impl fmt::Display for PayloadType {
             PayloadType::Ndjson => f.write_str("ndjson"),
             PayloadType::Json => f.write_str("json"),
             PayloadType::Csv { .. } => f.write_str("csv"),
+            PayloadType::Parquet => f.write_str("parquet"),
+            PayloadType::ArrowStream => f.write_str("arrow"),
         }
     }
 }
@@ -40,6 +58,18 @@ impl fmt::Display for PayloadType {
 pub enum DocumentFormatError {
     Io(io::Error),
     MalformedPayload(Error, PayloadType),
+    MalformedColumnarPayload(ColumnarError, PayloadType),
+}
+
+/// The errors that can be encountered while reading a Parquet or an Arrow IPC payload.
+#[derive(Debug, thiserror::Error)]
+pub enum ColumnarError {
+    #[error(transparent)]
+    Arrow(#[from] ArrowError),
+    #[error(transparent)]
+    Parquet(#[from] ParquetError),
+    #[error("Invalid value at row {row} of column `{column}`: {message}")]
+    InvalidValue { row: u64, column: String, message: String },
 }
 
 impl Display for DocumentFormatError {
@@ -78,6 +108,9 @@ impl Display for DocumentFormatError {
                 }
                 _ => write!(f, "The `{}` payload provided is malformed: `{}`.", b, me),
             },
+            Self::MalformedColumnarPayload(ce, b) => {
+                write!(f, "The `{}` payload provided is malformed: `{}`.", b, ce)
+            }
         }
     }
 }
@@ -113,6 +146,19 @@ impl From<(PayloadType, csv::Error)> for DocumentFormatError {
     }
 }
 
+impl From<(PayloadType, ColumnarError)> for DocumentFormatError {
+    fn from((ty, error): (PayloadType, ColumnarError)) -> Self {
+        match error {
+            ColumnarError::Arrow(ArrowError::IoError(_, e)) => Self::Io(e),
+            ColumnarError::Parquet(ParquetError::External(e)) if e.is::<io::Error>() => {
+                // cannot fail, we just checked the type of the error
+                Self::Io(*e.downcast::<io::Error>().unwrap())
+            }
+            e => Self::MalformedColumnarPayload(e, ty),
+        }
+    }
+}
+
 impl From<io::Error> for DocumentFormatError {
     fn from(error: io::Error) -> Self {
         Self::Io(error)
@@ -124,6 +170,7 @@ impl ErrorCode for DocumentFormatError {
         match self {
             DocumentFormatError::Io(e) => e.error_code(),
             DocumentFormatError::MalformedPayload(_, _) => Code::MalformedPayload,
+            DocumentFormatError::MalformedColumnarPayload(_, _) => Code::MalformedPayload,
         }
     }
 }
@@ -275,6 +322,174 @@ pub fn read_ndjson(input: &File) -> Result<u64> {
     Ok(count)
 }
 
+/// Reads Parquet from file and write it in NDJSON in a file checking it along the way.
+pub fn read_parquet(input: &File, output: impl io::Write) -> Result<u64> {
+    let ptype = PayloadType::Parquet;
+    let reader = ParquetRecordBatchReaderBuilder::try_new(input.try_clone()?)
+        .and_then(|builder| builder.build())
+        .map_err(|e| DocumentFormatError::from((ptype, ColumnarError::from(e))))?;
+
+    write_record_batches(reader, output, ptype)
+}
+
+/// Reads an Arrow IPC stream from file and write it in NDJSON in a file checking it along the way.
+pub fn read_arrow_stream(input: &File, output: impl io::Write) -> Result<u64> {
+    let ptype = PayloadType::ArrowStream;
+    let reader = StreamReader::try_new(BufReader::new(input), None)
+        .map_err(|e| DocumentFormatError::from((ptype, ColumnarError::from(e))))?;
+
+    write_record_batches(reader, output, ptype)
+}
+
+/// Writes every row of the record batches as a document, with its nested fields flattened.
+fn write_record_batches(
+    batches: impl Iterator<Item = std::result::Result<RecordBatch, ArrowError>>,
+    output: impl io::Write,
+    ptype: PayloadType,
+) -> Result<u64> {
+    let mut output = BufWriter::new(output);
+
+    let mut count = 0;
+    for batch in batches {
+        let batch =
+            batch.map_err(|e| DocumentFormatError::from((ptype, ColumnarError::from(e))))?;
+        let schema = batch.schema();
+        for row in 0..batch.num_rows() {
+            // We increment here and not at the end of the loop
+            // to report the rows starting at 1 like the CSV lines.
+            count += 1;
+
+            let mut object = Map::new();
+            for (field, column) in schema.fields().iter().zip(batch.columns()) {
+                let value = arrow_value(column.as_ref(), row).map_err(|message| {
+                    let column = field.name().clone();
+                    let error = ColumnarError::InvalidValue { row: count, column, message };
+                    DocumentFormatError::MalformedColumnarPayload(error, ptype)
+                })?;
+                object.insert(field.name().clone(), value);
+            }
+
+            to_writer(&mut output, &flatten_object(&object))
+                .map_err(|e| DocumentFormatError::from((ptype, e)))?;
+        }
+    }
+
+    match output.into_inner() {
+        Ok(_) => Ok(count),
+        Err(ie) => Err(DocumentFormatError::Io(ie.into_error())),
+    }
+}
+
+/// Flattens the nested objects like `flatten-serde-json` does, but only keeps the leaves,
+/// `{ "a": { "b": 1 } }` becomes `{ "a.b": 1 }`.
+///
+/// The reserved `_geo`, `_geojson` and `_vectors` fields are kept as is since they must be objects.
+fn flatten_object(object: &Map<String, Value>) -> Map<String, Value> {
+    let is_reserved = |field: &str| {
+        [RESERVED_GEO_FIELD_NAME, RESERVED_GEOJSON_FIELD_NAME, RESERVED_VECTORS_FIELD_NAME]
+            .contains(&field)
+    };
+    let (reserved, to_flatten): (Map<_, _>, Map<_, _>) =
+        object.iter().map(|(k, v)| (k.clone(), v.clone())).partition(|(k, _)| is_reserved(k));
+
+    let mut flattened = flatten_serde_json::flatten(&to_flatten);
+    flattened.retain(|_, value| match value {
+        Value::Object(_) => false,
+        Value::Array(values) => !values.iter().any(|v| v.is_object() || v.is_array()),
+        _ => true,
+    });
+    flattened.extend(reserved);
+    flattened
+}
+
+/// Converts the value at the given row of an Arrow array into a JSON value.
+fn arrow_value(array: &dyn Array, row: usize) -> std::result::Result<Value, String> {
+    if array.is_null(row) {
+        return Ok(Value::Null);
+    }
+
+    let value = match array.data_type() {
+        DataType::Null => Value::Null,
+        DataType::Boolean => Value::Bool(array.as_boolean().value(row)),
+        DataType::Int8 => Value::from(array.as_primitive::<Int8Type>().value(row)),
+        DataType::Int16 => Value::from(array.as_primitive::<Int16Type>().value(row)),
+        DataType::Int32 => Value::from(array.as_primitive::<Int32Type>().value(row)),
+        DataType::Int64 => Value::from(array.as_primitive::<Int64Type>().value(row)),
+        DataType::UInt8 => Value::from(array.as_primitive::<UInt8Type>().value(row)),
+        DataType::UInt16 => Value::from(array.as_primitive::<UInt16Type>().value(row)),
+        DataType::UInt32 => Value::from(array.as_primitive::<UInt32Type>().value(row)),
+        DataType::UInt64 => Value::from(array.as_primitive::<UInt64Type>().value(row)),
+        DataType::Float16 => float_value(array.as_primitive::<Float16Type>().value(row).to_f64())?,
+        DataType::Float32 => float_value(array.as_primitive::<Float32Type>().value(row) as f64)?,
+        DataType::Float64 => float_value(array.as_primitive::<Float64Type>().value(row))?,
+        DataType::Utf8 => Value::from(array.as_string::<i32>().value(row)),
+        DataType::LargeUtf8 => Value::from(array.as_string::<i64>().value(row)),
+        DataType::Utf8View => Value::from(array.as_string_view().value(row)),
+        // There is no JSON equivalent to these types, we use their textual representation.
+        DataType::Date32
+        | DataType::Date64
+        | DataType::Time32(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(_)
+        | DataType::Decimal128(_, _)
+        | DataType::Decimal256(_, _) => {
+            let formatter = ArrayFormatter::try_new(array, &FormatOptions::default())
+                .map_err(|e| e.to_string())?;
+            Value::from(formatter.value(row).to_string())
+        }
+        DataType::List(_) => list_value(array.as_list::<i32>().value(row).as_ref())?,
+        DataType::LargeList(_) => list_value(array.as_list::<i64>().value(row).as_ref())?,
+        DataType::FixedSizeList(_, _) => {
+            list_value(array.as_fixed_size_list().value(row).as_ref())?
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            let mut object = Map::new();
+            for (field, column) in fields.iter().zip(array.columns()) {
+                object.insert(field.name().clone(), arrow_value(column.as_ref(), row)?);
+            }
+            Value::Object(object)
+        }
+        DataType::Map(_, _) => {
+            let entries = array.as_map().value(row);
+            let (keys, values) = (entries.column(0), entries.column(1));
+            let mut object = Map::new();
+            for entry in 0..entries.len() {
+                let key = match arrow_value(keys.as_ref(), entry)? {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                };
+                object.insert(key, arrow_value(values.as_ref(), entry)?);
+            }
+            Value::Object(object)
+        }
+        DataType::Dictionary(_, _) => {
+            let dictionary = array.as_any_dictionary();
+            let key = arrow_value(dictionary.keys(), row)?;
+            let key = key.as_u64().ok_or_else(|| format!("invalid dictionary key `{key}`"))?;
+            arrow_value(dictionary.values().as_ref(), key as usize)?
+        }
+        data_type => return Err(format!("unsupported type `{data_type}`")),
+    };
+
+    Ok(value)
+}
+
+fn list_value(array: &dyn Array) -> std::result::Result<Value, String> {
+    (0..array.len())
+        .map(|row| arrow_value(array, row))
+        .collect::<std::result::Result<_, _>>()
+        .map(Value::Array)
+}
+
+fn float_value(float: f64) -> std::result::Result<Value, String> {
+    serde_json::Number::from_f64(float)
+        .map(Value::Number)
+        .ok_or_else(|| format!("`{float}` cannot be represented in JSON"))
+}
+
 /// The actual handling of the deserialization process in serde
 /// avoids storing the deserialized object in memory.
 ///
@@ -18,6 +18,7 @@ use crate::error::deserr_codes::{
 };
 use crate::error::ParseOffsetDateTimeError;
 use crate::index_uid::IndexUid;
+use crate::locales::Locale;
 
 pub type RuleUid = IndexUid;
 
@@ -189,11 +190,19 @@ impl DynamicSearchRule {
         *last_updated_at = Some(updated_at);
 
         match new_conditions {
-            Setting::Set(Conditions { time: new_time, query: new_query, filter: new_filter }) => {
-                let Conditions { time, query, filter } = conditions;
+            Setting::Set(Conditions {
+                time: new_time,
+                query: new_query,
+                filter: new_filter,
+                locales: new_locales,
+                tenant_token: new_tenant_token,
+            }) => {
+                let Conditions { time, query, filter, locales, tenant_token } = conditions;
                 *time = new_time;
                 *query = new_query;
                 *filter = new_filter;
+                *locales = new_locales;
+                *tenant_token = new_tenant_token;
             }
             Setting::Reset => *conditions = Conditions::default(),
             Setting::NotSet => (),
@@ -280,6 +289,13 @@ pub struct Conditions {
     /// Conditions on the values matching the filter of the search query that determines whether the rule is active
     #[request(default, skip_serializing_if = "Option::is_none")]
     pub filter: Option<FilterCondition>,
+    /// If present and non-null, specifies that the rule can only be active if the search uses at least one
+    /// of these locales in its `locales` parameter.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub locales: Option<Vec<Locale>>,
+    /// Conditions on the claims of the tenant token used to perform the search that determines whether the rule is active
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_token: Option<TenantTokenCondition>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema, Deserr)]
@@ -325,6 +341,20 @@ pub struct QueryCondition {
     /// present in the search query.
     #[request(default, skip_serializing_if = "Option::is_none")]
     pub words: Option<String>,
+
+    /// If present and non-null, specifies that the rule can only be active if the search query starts with
+    /// this prefix.
+    ///
+    /// The comparison ignores case and leading whitespace.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+
+    /// If present and non-null, specifies that the rule can only be active if the search query matches
+    /// this regular expression.
+    ///
+    /// The regular expression is not anchored: use `^` and `$` to match the whole query.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
 }
 
 #[routes::request(db, override_error = DeserrJsonError<InvalidDynamicSearchRuleConditions>)]
@@ -334,10 +364,22 @@ pub struct FilterCondition {
     pub values: BTreeMap<String, serde_json::Value>,
 }
 
+#[routes::request(db, override_error = DeserrJsonError<InvalidDynamicSearchRuleConditions>)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TenantTokenCondition {
+    /// Values that the claims of the tenant token must have for the rule to be active.
+    ///
+    /// Each value is either a string, a number, a boolean, or an array of them. When the value is an array,
+    /// the claim must be equal to one of its elements. Claims missing from the tenant token never match,
+    /// and requests that don't use a tenant token never match rules with this condition.
+    #[request(default)]
+    pub claims: BTreeMap<String, serde_json::Value>,
+}
+
 // We manually check the exclusivity of `is_empty` and `contains` because Deserr does not support
 // untagged enums
 fn validate_condition<E: DeserializeError>(
-    conditions: Conditions,
+    mut conditions: Conditions,
     location: ValuePointerRef,
 ) -> Result<Conditions, E> {
     // 1. check is_empty and words
@@ -353,7 +395,43 @@ fn validate_condition<E: DeserializeError>(
         }
     }
 
-    // 2. check that start is before end
+    // 2. check that the other query constraints are not used with an empty query
+    if let Some(query) = &mut conditions.query {
+        if query.is_empty == Some(true) && (query.prefix.is_some() || query.regex.is_some()) {
+            return Err(deserr::take_cf_content(E::error::<Infallible>(
+                None,
+                ErrorKind::Unexpected {
+                    msg: "`isEmpty: true` cannot be used with `prefix` or `regex`".to_string(),
+                },
+                location.push_key("query"),
+            )));
+        }
+
+        if let Some(prefix) = &query.prefix {
+            let normalized = milli::normalize_facet(prefix);
+            if normalized.is_empty() {
+                return Err(deserr::take_cf_content(E::error::<Infallible>(
+                    None,
+                    ErrorKind::Unexpected { msg: "`prefix` cannot be empty".to_string() },
+                    location.push_key("query").push_key("prefix"),
+                )));
+            }
+            // store the normalized prefix so that it can be looked up as a facet value
+            query.prefix = Some(normalized);
+        }
+
+        if let Some(regex) = &query.regex {
+            if let Err(error) = milli::dynamic_search_rules::compile_query_regex(regex) {
+                return Err(deserr::take_cf_content(E::error::<Infallible>(
+                    None,
+                    ErrorKind::Unexpected { msg: format!("invalid regular expression: {error}") },
+                    location.push_key("query").push_key("regex"),
+                )));
+            }
+        }
+    }
+
+    // 3. check that start is before end
     if let Some(time) = &conditions.time {
         if let Some((start, end)) = time.start.as_ref().zip(time.end.as_ref()) {
             if start > end {
@@ -368,6 +446,54 @@ fn validate_condition<E: DeserializeError>(
         }
     }
 
+    // 4. check that at least one locale is specified
+    if let Some(locales) = &mut conditions.locales {
+        if locales.is_empty() {
+            return Err(deserr::take_cf_content(E::error::<Infallible>(
+                None,
+                ErrorKind::Unexpected {
+                    msg: "`locales` must contain at least one locale".to_string(),
+                },
+                location.push_key("locales"),
+            )));
+        }
+        // store the ISO-639-3 form of the locales so that they can be looked up as facet values
+        for locale in locales.iter_mut() {
+            *locale = Locale::from(milli::tokenizer::Language::from(*locale));
+        }
+        locales.sort_unstable();
+        locales.dedup();
+    }
+
+    // 5. check that the claims values are scalars or arrays of scalars
+    if let Some(tenant_token) = &conditions.tenant_token {
+        for (claim, value) in &tenant_token.claims {
+            let is_scalar = |value: &serde_json::Value| {
+                matches!(
+                    value,
+                    serde_json::Value::String(_)
+                        | serde_json::Value::Number(_)
+                        | serde_json::Value::Bool(_)
+                )
+            };
+            let is_valid = match value {
+                serde_json::Value::Array(values) => {
+                    !values.is_empty() && values.iter().all(is_scalar)
+                }
+                value => is_scalar(value),
+            };
+            if !is_valid {
+                return Err(deserr::take_cf_content(E::error::<Infallible>(
+                    None,
+                    ErrorKind::Unexpected {
+                        msg: format!("the value of the `{claim}` claim must be a string, a number, a boolean, or a non-empty array of them"),
+                    },
+                    location.push_key("tenantToken").push_key("claims").push_key(claim),
+                )));
+            }
+        }
+    }
+
     Ok(conditions)
 }
 
@@ -375,6 +501,7 @@ fn validate_condition<E: DeserializeError>(
 #[deserr(
     rename_all = camelCase,
     deny_unknown_fields,
+    validate = validate_rule_action -> __Deserr_E,
     where_predicate = __Deserr_E: deserr::MergeWithError<crate::index_uid::IndexUidFormatError>
 )]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -397,8 +524,16 @@ impl routes::RequestBody for RuleAction {}
 pub struct Selector {
     #[request(default, skip_serializing_if = "Option::is_none")]
     pub index_uid: Option<IndexUid>,
-    #[request(required)]
-    pub id: String,
+    /// Primary key of the selected document.
+    ///
+    /// Exactly one of `id` or `filter` must be specified, depending on the action.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Filter expression selecting a set of documents, with the same syntax as the `filter` search parameter.
+    ///
+    /// Exactly one of `id` or `filter` must be specified, depending on the action.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
 }
 
 #[derive(Serialize, Deserialize, Deserr, Debug, Clone, PartialEq, Eq, ToSchema)]
@@ -406,7 +541,75 @@ pub struct Selector {
 #[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
 #[schema(rename_all = "camelCase")]
 pub enum DynamicSearchRuleAction {
+    /// Places the document selected by `id` at `position` in the results.
     Pin { position: u32 },
+    /// Removes the selected documents from the results.
+    Hide,
+    /// Places the selected documents after all the other results.
+    Bury,
+    /// Places the selected documents before all the other results.
+    Boost,
+    /// Restricts the results to the documents selected by `filter`.
+    InjectFilter,
+}
+
+// We manually check which selectors an action accepts because Deserr does not support untagged enums
+fn validate_rule_action<E: DeserializeError>(
+    rule_action: RuleAction,
+    location: ValuePointerRef,
+) -> Result<RuleAction, E> {
+    let selector_error = |msg: String| {
+        deserr::take_cf_content(E::error::<Infallible>(
+            None,
+            ErrorKind::Unexpected { msg },
+            location.push_key("selector"),
+        ))
+    };
+
+    let RuleAction { selector, action } = &rule_action;
+    let action_name = match action {
+        DynamicSearchRuleAction::Pin { .. } => "pin",
+        DynamicSearchRuleAction::Hide => "hide",
+        DynamicSearchRuleAction::Bury => "bury",
+        DynamicSearchRuleAction::Boost => "boost",
+        DynamicSearchRuleAction::InjectFilter => "injectFilter",
+    };
+
+    match (action, &selector.id, &selector.filter) {
+        (_, Some(_), Some(_)) => {
+            return Err(selector_error(
+                "either `id` or `filter` can be used, not both at once".to_string(),
+            ))
+        }
+        (DynamicSearchRuleAction::Pin { .. }, None, _) => {
+            return Err(selector_error(format!(
+                "the `{action_name}` action requires a selector with an `id`"
+            )))
+        }
+        (DynamicSearchRuleAction::InjectFilter, _, None) => {
+            return Err(selector_error(format!(
+                "the `{action_name}` action requires a selector with a `filter`"
+            )))
+        }
+        (_, None, None) => {
+            return Err(selector_error(format!(
+                "the `{action_name}` action requires a selector with either an `id` or a `filter`"
+            )))
+        }
+        _ => (),
+    }
+
+    if let Some(filter) = &selector.filter {
+        if let Err(error) = milli::Filter::from_json(filter) {
+            return Err(deserr::take_cf_content(E::error::<Infallible>(
+                None,
+                ErrorKind::Unexpected { msg: error.to_string() },
+                location.push_key("selector").push_key("filter"),
+            )));
+        }
+    }
+
+    Ok(rule_action)
 }
 
 fn parse_optional_rfc3339_datetime(
@@ -29,6 +29,9 @@ pub struct ResponseError {
     /// A link to the documentation about this specific error.
     #[serde(rename = "link")]
     error_link: String,
+    /// The number of seconds to wait before retrying, sent in the `Retry-After` header.
+    #[serde(skip)]
+    retry_after: Option<u64>,
 }
 
 impl ResponseError {
@@ -42,8 +45,15 @@ impl ResponseError {
             error_code: code.name(),
             error_type: code.type_(),
             error_link: code.url(),
+            retry_after: None,
         }
     }
+
+    /// Sends a `Retry-After` header with the error.
+    pub fn with_retry_after(mut self, seconds: u64) -> Self {
+        self.retry_after = Some(seconds);
+        self
+    }
 }
 
 impl fmt::Display for ResponseError {
@@ -69,7 +79,9 @@ impl aweb::error::ResponseError for ResponseError {
         let mut builder = HttpResponseBuilder::new(self.status_code());
         builder.content_type("application/json");
 
-        if self.code == StatusCode::SERVICE_UNAVAILABLE {
+        if let Some(retry_after) = self.retry_after {
+            builder.insert_header((header::RETRY_AFTER, retry_after.to_string()));
+        } else if self.code == StatusCode::SERVICE_UNAVAILABLE {
             builder.insert_header((header::RETRY_AFTER, "10"));
         }
 
@@ -197,7 +209,10 @@ macro_rules! make_error_codes {
 make_error_codes! {
 ApiKeyAlreadyExists                            , InvalidRequest       , CONFLICT ;
 ApiKeyNotFound                                 , InvalidRequest       , NOT_FOUND ;
+ApiKeyRateLimitExceeded                        , Auth                 , TOO_MANY_REQUESTS ;
 IndexScopedApiKeyWithGlobalAction              , InvalidRequest       , BAD_REQUEST ;
+SigningKeyAlreadyExists                        , InvalidRequest       , CONFLICT ;
+SigningKeyNotFound                             , InvalidRequest       , NOT_FOUND ;
 BadParameter                                   , InvalidRequest       , BAD_REQUEST;
 BadRequest                                     , InvalidRequest       , BAD_REQUEST;
 DatabaseSizeLimitReached                       , Internal             , INTERNAL_SERVER_ERROR;
@@ -234,7 +249,14 @@ InvalidApiKeyIndexes                           , InvalidRequest       , BAD_REQU
 InvalidApiKeyLimit                             , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyName                              , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyOffset                            , InvalidRequest       , BAD_REQUEST ;
+InvalidApiKeyRateLimits                        , InvalidRequest       , BAD_REQUEST ;
 InvalidApiKeyUid                               , InvalidRequest       , BAD_REQUEST ;
+InvalidSigningKeyKid                           , InvalidRequest       , BAD_REQUEST ;
+InvalidSigningKeyAlgorithm                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSigningKeyPublicKey                     , InvalidRequest       , BAD_REQUEST ;
+InvalidSigningKeyActions                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSigningKeyIndexes                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSigningKeyExpiresAt                     , InvalidRequest       , BAD_REQUEST ;
 InvalidContentType                             , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
 InvalidDocumentCsvDelimiter                    , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentFields                          , InvalidRequest       , BAD_REQUEST ;
@@ -255,6 +277,13 @@ InvalidDocumentId                              , InvalidRequest       , BAD_REQU
 InvalidDocumentIds                             , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentLimit                           , InvalidRequest       , BAD_REQUEST ;
 InvalidDocumentOffset                          , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpIndexes                             , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpSkipTasks                           , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpSkipBatches                         , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpSkipKeys                            , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpImportDumpUid                       , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpImportIndexRenames                  , InvalidRequest       , BAD_REQUEST ;
+InvalidDumpImportSkipExistingIndexes           , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchEmbedder                          , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarEmbedder                         , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchHybridQuery                       , InvalidRequest       , BAD_REQUEST ;
@@ -269,6 +298,7 @@ InvalidMultiSearchFacetsByIndex                , InvalidRequest       , BAD_REQU
 InvalidMultiSearchFacetOrder                   , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchQueryPersonalization         , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchQueryShowPerformanceDetails  , InvalidRequest       , BAD_REQUEST ;
+InvalidMultiSearchQuerySuggestions             , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchFederated                    , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchFederationOptions            , InvalidRequest       , BAD_REQUEST ;
 InvalidMultiSearchMaxValuesPerFacet            , InvalidRequest       , BAD_REQUEST ;
@@ -299,6 +329,7 @@ InvalidSearchRetrieveVectors                   , InvalidRequest       , BAD_REQU
 InvalidSearchCropLength                        , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchCropMarker                        , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchFacets                            , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchFacetAggregations                 , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchSemanticRatio                     , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchLocales                           , InvalidRequest       , BAD_REQUEST ;
 InvalidFacetSearchExhaustiveFacetCount         , InvalidRequest       , BAD_REQUEST ;
@@ -312,6 +343,8 @@ InvalidSearchHitsPerPage                       , InvalidRequest       , BAD_REQU
 InvalidSimilarLimit                            , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchLimit                             , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchMatchingStrategy                  , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchAdvancedQuerySyntax               , InvalidRequest       , BAD_REQUEST ;
+InvalidSearchSuggestions                       , InvalidRequest       , BAD_REQUEST ;
 InvalidSimilarOffset                           , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchOffset                            , InvalidRequest       , BAD_REQUEST ;
 InvalidSearchPage                              , InvalidRequest       , BAD_REQUEST ;
@@ -371,6 +404,8 @@ InvalidTaskBeforeStartedAt                     , InvalidRequest       , BAD_REQU
 InvalidTaskCanceledBy                          , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskFrom                                , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskLimit                               , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskPriorities                          , InvalidRequest       , BAD_REQUEST ;
+InvalidTaskPriority                            , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskReverse                             , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskStatuses                            , InvalidRequest       , BAD_REQUEST ;
 InvalidTaskTypes                               , InvalidRequest       , BAD_REQUEST ;
@@ -386,6 +421,7 @@ MissingApiKeyIndexes                           , InvalidRequest       , BAD_REQU
 MissingAuthorizationHeader                     , Auth                 , UNAUTHORIZED ;
 MissingContentType                             , InvalidRequest       , UNSUPPORTED_MEDIA_TYPE ;
 MissingDocumentId                              , InvalidRequest       , BAD_REQUEST ;
+MissingDumpImportDumpUid                       , InvalidRequest       , BAD_REQUEST ;
 MissingFacetSearchFacetName                    , InvalidRequest       , BAD_REQUEST ;
 MissingIndexUid                                , InvalidRequest       , BAD_REQUEST ;
 MissingMasterKey                               , Auth                 , UNAUTHORIZED ;
@@ -407,6 +443,7 @@ RemoteRemoteError                              , System               , BAD_GATE
 RemoteTimeout                                  , System               , BAD_GATEWAY ;
 TooManySearchRequests                          , System               , SERVICE_UNAVAILABLE ;
 TaskNotFound                                   , InvalidRequest       , NOT_FOUND ;
+TaskPriorityNotAllowed                         , Auth                 , FORBIDDEN ;
 TaskFileNotFound                               , InvalidRequest       , NOT_FOUND ;
 BatchNotFound                                  , InvalidRequest       , NOT_FOUND ;
 TooManyOpenFiles                               , System               , UNPROCESSABLE_ENTITY ;
@@ -428,6 +465,7 @@ InvalidDocumentEditionContext                  , InvalidRequest       , BAD_REQU
 InvalidDocumentEditionFunctionFilter           , InvalidRequest       , BAD_REQUEST ;
 EditDocumentsByFunctionError                   , InvalidRequest       , BAD_REQUEST ;
 InvalidSettingsIndexChat                       , InvalidRequest       , BAD_REQUEST ;
+InvalidSettingsReranker                        , InvalidRequest       , BAD_REQUEST ;
 // Export
 InvalidExportUrl                               , InvalidRequest       , BAD_REQUEST ;
 InvalidExportApiKey                            , InvalidRequest       , BAD_REQUEST ;
@@ -455,6 +493,10 @@ InvalidChatCompletionSearchQueryParamPrompt    , InvalidRequest       , BAD_REQU
 InvalidChatCompletionSearchFilterParamPrompt   , InvalidRequest       , BAD_REQUEST ;
 InvalidChatCompletionSearchIndexUidParamPrompt , InvalidRequest       , BAD_REQUEST ;
 InvalidChatCompletionPreQueryPrompt            , InvalidRequest       , BAD_REQUEST ;
+InvalidChatCompletionConversationsTtlSeconds   , InvalidRequest       , BAD_REQUEST ;
+InvalidChatCompletionConversationsMaxMessages  , InvalidRequest       , BAD_REQUEST ;
+InvalidChatConversationId                      , InvalidRequest       , BAD_REQUEST ;
+ChatConversationNotFound                       , InvalidRequest       , NOT_FOUND   ;
 InvalidIndexFieldsFilter                       , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexFieldsFilterAttributePatterns      , InvalidRequest       , BAD_REQUEST ;
 InvalidIndexFieldsFilterDisplayed              , InvalidRequest       , BAD_REQUEST ;
@@ -474,11 +516,25 @@ TemplateRenderingError                         , InvalidRequest       , BAD_REQU
 InvalidWebhooks                                , InvalidRequest       , BAD_REQUEST ;
 InvalidWebhookUrl                              , InvalidRequest       , BAD_REQUEST ;
 InvalidWebhookHeaders                          , InvalidRequest       , BAD_REQUEST ;
+InvalidWebhookTypes                            , InvalidRequest       , BAD_REQUEST ;
+InvalidWebhookStatuses                         , InvalidRequest       , BAD_REQUEST ;
+InvalidWebhookIndexUids                        , InvalidRequest       , BAD_REQUEST ;
+InvalidWebhookSecret                           , InvalidRequest       , BAD_REQUEST ;
 ImmutableWebhook                               , InvalidRequest       , BAD_REQUEST ;
 InvalidWebhookUuid                             , InvalidRequest       , BAD_REQUEST ;
 WebhookNotFound                                , InvalidRequest       , NOT_FOUND ;
+WebhookDeliveryNotFound                        , InvalidRequest       , NOT_FOUND ;
+InvalidWebhookDeliveryUids                     , InvalidRequest       , BAD_REQUEST ;
 ImmutableWebhookUuid                           , InvalidRequest       , BAD_REQUEST ;
 ImmutableWebhookIsEditable                     , InvalidRequest       , BAD_REQUEST ;
+// Schedules
+InvalidSchedules                               , InvalidRequest       , BAD_REQUEST ;
+InvalidScheduleCron                            , InvalidRequest       , BAD_REQUEST ;
+InvalidScheduleTask                            , InvalidRequest       , BAD_REQUEST ;
+InvalidScheduleEnabled                         , InvalidRequest       , BAD_REQUEST ;
+InvalidScheduleUid                             , InvalidRequest       , BAD_REQUEST ;
+ImmutableScheduleUid                           , InvalidRequest       , BAD_REQUEST ;
+ScheduleNotFound                               , InvalidRequest       , NOT_FOUND ;
 InvalidDynamicSearchRuleOffset                 , InvalidRequest       , BAD_REQUEST ;
 InvalidDynamicSearchRuleLimit                  , InvalidRequest       , BAD_REQUEST ;
 InvalidDynamicSearchRuleFilter                 , InvalidRequest       , BAD_REQUEST ;
@@ -544,6 +600,7 @@ impl ErrorCode for milli::Error {
                 UserError::InvalidChatSettingsDocumentTemplate(_) => {
                     Code::InvalidChatSettingDocumentTemplate
                 }
+                UserError::InvalidSettingsReranker(_) => Code::InvalidSettingsReranker,
                 UserError::NoPrimaryKeyCandidateFound => Code::IndexPrimaryKeyNoCandidateFound,
                 UserError::MultiplePrimaryKeyCandidatesFound { .. } => {
                     Code::IndexPrimaryKeyMultipleCandidatesFound
@@ -552,6 +609,7 @@ impl ErrorCode for milli::Error {
                 UserError::InvalidDistinctAttribute { .. } => Code::InvalidSearchDistinct,
                 UserError::SortRankingRuleMissing => Code::InvalidSearchSort,
                 UserError::InvalidFacetsDistribution { .. } => Code::InvalidSearchFacets,
+                UserError::InvalidFacetAggregation { .. } => Code::InvalidSearchFacetAggregations,
                 UserError::InvalidSearchSortableAttribute { .. } => Code::InvalidSearchSort,
                 UserError::InvalidDocumentSortableAttribute { .. } => Code::InvalidDocumentSort,
                 UserError::InvalidSearchableAttribute { .. } => {
@@ -651,6 +709,15 @@ impl fmt::Display for ParseOffsetDateTimeError {
     }
 }
 
+/// Deserialization when `deserr` cannot parse a signing key id.
+#[derive(Debug)]
+pub struct ParseSigningKeyKidError(pub String);
+impl fmt::Display for ParseSigningKeyKidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a valid signing key id. It must be a non-empty string of at most 128 characters containing only alphanumeric characters, hyphens (-), underscores (_) and dots (.).", self.0)
+    }
+}
+
 /// Deserialization when `deserr` cannot parse a task date.
 #[derive(Debug)]
 pub struct InvalidTaskDateError(pub String);
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
 
 use crate::error::{Code, ResponseError};
 
@@ -56,6 +58,8 @@ pub struct ChatCompletionSettings {
     pub api_key: Option<String>,
     #[serde(default)]
     pub prompts: ChatCompletionPrompts,
+    #[serde(default)]
+    pub conversations: ChatConversationsSettings,
 }
 
 impl ChatCompletionSettings {
@@ -93,11 +97,101 @@ impl ChatCompletionSettings {
                 "vLlm requires setting a valid `baseUrl`".to_string(),
                 Code::BadRequest,
             )),
+            Self { conversations: ChatConversationsSettings { max_messages: 0, .. }, .. } => Err(ResponseError::from_msg(
+                "`conversations.maxMessages` must be greater than 0".to_string(),
+                Code::InvalidChatCompletionConversationsMaxMessages,
+            )),
             _otherwise => Ok(()),
         }
     }
 }
 
+/// Seven days.
+pub const DEFAULT_CHAT_CONVERSATION_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+pub const DEFAULT_CHAT_CONVERSATION_MAX_MESSAGES: usize = 100;
+
+/// How the conversations stored on the server are kept.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatConversationsSettings {
+    /// Number of seconds after the last message after which a conversation is deleted.
+    #[serde(default = "default_conversation_ttl_seconds")]
+    pub ttl_seconds: u64,
+    /// Maximum number of messages kept in a conversation, the oldest ones are dropped first.
+    #[serde(default = "default_conversation_max_messages")]
+    pub max_messages: usize,
+}
+
+fn default_conversation_ttl_seconds() -> u64 {
+    DEFAULT_CHAT_CONVERSATION_TTL_SECONDS
+}
+
+fn default_conversation_max_messages() -> usize {
+    DEFAULT_CHAT_CONVERSATION_MAX_MESSAGES
+}
+
+impl Default for ChatConversationsSettings {
+    fn default() -> Self {
+        Self {
+            ttl_seconds: DEFAULT_CHAT_CONVERSATION_TTL_SECONDS,
+            max_messages: DEFAULT_CHAT_CONVERSATION_MAX_MESSAGES,
+        }
+    }
+}
+
+/// A conversation stored on the server, so that the clients only send the new messages.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatConversation {
+    pub id: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    pub updated_at: OffsetDateTime,
+    /// The messages of the conversation in the OpenAI format, without the system prompt.
+    pub messages: Vec<Value>,
+    /// The API key or tenant token that started the conversation, `None` for the master key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+}
+
+impl ChatConversation {
+    pub fn new(id: String, owner: Option<String>, now: OffsetDateTime) -> Self {
+        Self { id, created_at: now, updated_at: now, messages: Vec::new(), owner }
+    }
+
+    /// Returns `true` if the requester can read, continue or delete the conversation.
+    ///
+    /// The master key (`None`) can access all the conversations, an API key can access
+    /// the conversations it started and the ones started by its tenant tokens, and a
+    /// tenant token can only access the conversations it started.
+    pub fn is_accessible_by(&self, requester: Option<&str>) -> bool {
+        let Some(requester) = requester else { return true };
+        self.owner.as_deref().is_some_and(|owner| {
+            owner == requester
+                || owner.strip_prefix(requester).is_some_and(|rest| rest.starts_with(':'))
+        })
+    }
+
+    /// Returns `true` if the conversation did not receive any message for longer than its TTL.
+    pub fn is_expired(&self, settings: &ChatConversationsSettings, now: OffsetDateTime) -> bool {
+        let ttl = time::Duration::seconds(settings.ttl_seconds.try_into().unwrap_or(i64::MAX));
+        self.updated_at.checked_add(ttl).is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Drops the oldest messages until there are at most `max_messages` left.
+    ///
+    /// The conversation never starts in the middle of an exchange: the messages
+    /// are dropped until the first one sent by the user.
+    pub fn truncate(&mut self, max_messages: usize) {
+        let mut start = self.messages.len().saturating_sub(max_messages);
+        while self.messages.get(start).is_some_and(|message| message["role"] != "user") {
+            start += 1;
+        }
+        self.messages.drain(..start);
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
 pub enum ChatCompletionSource {
@@ -261,4 +355,64 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn conversation_truncation_starts_with_a_user_message() {
+        let now = time::macros::datetime!(2025-01-01 00:00 UTC);
+        let mut conversation = ChatConversation::new(String::from("conversation"), None, now);
+        conversation.messages = vec![
+            serde_json::json!({ "role": "user", "content": "first question" }),
+            serde_json::json!({ "role": "assistant", "content": "first answer" }),
+            serde_json::json!({ "role": "user", "content": "second question" }),
+            serde_json::json!({ "role": "assistant", "tool_calls": [] }),
+            serde_json::json!({ "role": "tool", "content": "search results" }),
+            serde_json::json!({ "role": "assistant", "content": "second answer" }),
+        ];
+
+        let mut truncated = conversation.clone();
+        truncated.truncate(10);
+        assert_eq!(truncated.messages, conversation.messages);
+
+        // keeping the last 5 messages would start with an answer
+        let mut truncated = conversation.clone();
+        truncated.truncate(5);
+        assert_eq!(truncated.messages, conversation.messages[2..]);
+
+        // an exchange that doesn't fit is dropped entirely
+        let mut truncated = conversation.clone();
+        truncated.truncate(2);
+        assert!(truncated.messages.is_empty());
+    }
+
+    #[test]
+    fn conversation_expiration() {
+        let now = time::macros::datetime!(2025-01-01 00:00 UTC);
+        let conversation = ChatConversation::new(String::from("conversation"), None, now);
+        let settings = ChatConversationsSettings { ttl_seconds: 60, max_messages: 100 };
+
+        assert!(!conversation.is_expired(&settings, now + time::Duration::seconds(59)));
+        assert!(conversation.is_expired(&settings, now + time::Duration::seconds(60)));
+
+        let settings = ChatConversationsSettings { ttl_seconds: u64::MAX, max_messages: 100 };
+        assert!(!conversation.is_expired(&settings, now + time::Duration::days(365)));
+    }
+
+    #[test]
+    fn conversation_access() {
+        let now = time::macros::datetime!(2025-01-01 00:00 UTC);
+        let owner = Some(String::from("key:1234:token:abcd"));
+        let conversation = ChatConversation::new(String::from("conversation"), owner, now);
+
+        assert!(conversation.is_accessible_by(None));
+        assert!(conversation.is_accessible_by(Some("key:1234:token:abcd")));
+        assert!(conversation.is_accessible_by(Some("key:1234")));
+        assert!(!conversation.is_accessible_by(Some("key:12")));
+        assert!(!conversation.is_accessible_by(Some("key:1234:token:ef01")));
+        assert!(!conversation.is_accessible_by(Some("key:5678")));
+
+        // the conversations started with the master key are only accessible with the master key
+        let conversation = ChatConversation::new(String::from("conversation"), None, now);
+        assert!(conversation.is_accessible_by(None));
+        assert!(!conversation.is_accessible_by(Some("key:1234")));
+    }
 }
@@ -1,5 +1,6 @@
 use std::convert::Infallible;
 use std::hash::Hash;
+use std::num::NonZeroUsize;
 use std::str::FromStr;
 
 use deserr::{DeserializeError, MergeWithError, ValuePointerRef};
@@ -9,11 +10,12 @@ use serde::{Deserialize, Serialize};
 use time::format_description::well_known::Rfc3339;
 use time::macros::{format_description, time};
 use time::{Date, OffsetDateTime, PrimitiveDateTime};
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use crate::deserr::{immutable_field_error, DeserrError, DeserrJsonError};
 use crate::error::deserr_codes::*;
-use crate::error::{Code, ErrorCode, ParseOffsetDateTimeError};
+use crate::error::{Code, ErrorCode, ParseOffsetDateTimeError, ParseSigningKeyKidError};
 use crate::index_uid_pattern::{IndexUidPattern, IndexUidPatternFormatError};
 
 pub type KeyId = Uuid;
@@ -89,11 +91,15 @@ pub struct CreateApiKey {
         missing_field_error = DeserrJsonError::missing_api_key_expires_at
     )]
     pub expires_at: Option<OffsetDateTime>,
+    /// Limits on the usage of the key. `null` or omitted for no limits.
+    #[request(default, error = DeserrJsonError<InvalidApiKeyRateLimits>, example = json!({ "requestsPerSecond": 10, "concurrentSearches": 4 }))]
+    pub rate_limits: Option<KeyRateLimits>,
 }
 
 impl CreateApiKey {
     pub fn to_key(self) -> Key {
-        let CreateApiKey { description, name, uid, actions, indexes, expires_at } = self;
+        let CreateApiKey { description, name, uid, actions, indexes, expires_at, rate_limits } =
+            self;
         let now = OffsetDateTime::now_utc();
         Key {
             description,
@@ -102,12 +108,27 @@ impl CreateApiKey {
             actions,
             indexes,
             expires_at,
+            rate_limits,
             created_at: now,
             updated_at: now,
         }
     }
 }
 
+/// Limits on the usage of an API key, enforced on every request authenticated with the key
+/// or with a tenant token generated from it. Requests exceeding them are answered with a
+/// `429 Too Many Requests` and a `Retry-After` header.
+#[routes::request(db, override_error = DeserrJsonError<InvalidApiKeyRateLimits>)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct KeyRateLimits {
+    /// The maximum number of requests per second. `null` or omitted for no limit.
+    #[request(default, example = 10, skip_serializing_if = "Option::is_none")]
+    pub requests_per_second: Option<NonZeroUsize>,
+    /// The maximum number of searches running at the same time. `null` or omitted for no limit.
+    #[request(default, example = 4, skip_serializing_if = "Option::is_none")]
+    pub concurrent_searches: Option<NonZeroUsize>,
+}
+
 fn deny_immutable_fields_api_key(
     field: &str,
     accepted: &[&str],
@@ -128,8 +149,8 @@ fn deny_immutable_fields_api_key(
     }
 }
 
-/// Request body for updating an existing API key. Only the name and
-/// description can be modified - other properties like actions, indexes,
+/// Request body for updating an existing API key. Only the name, description
+/// and rate limits can be modified - other properties like actions, indexes,
 /// and expiration are immutable after creation.
 #[routes::request(deny_unknown_fields = deny_immutable_fields_api_key)]
 #[derive(Debug)]
@@ -143,6 +164,9 @@ pub struct PatchApiKey {
     /// "Production Search Key" or "CI/CD Indexing Key".
     #[request(default, error = DeserrJsonError<InvalidApiKeyName>, schema_type = Option<String>, example = "Indexing Products API key")]
     pub name: Setting<String>,
+    /// New limits on the usage of the API key. Pass `null` to remove the existing limits.
+    #[request(default, error = DeserrJsonError<InvalidApiKeyRateLimits>, schema_type = Option<KeyRateLimits>, example = json!({ "requestsPerSecond": 10 }))]
+    pub rate_limits: Setting<KeyRateLimits>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -156,6 +180,8 @@ pub struct Key {
     pub indexes: Vec<IndexUidPattern>,
     #[serde(with = "time::serde::rfc3339::option")]
     pub expires_at: Option<OffsetDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limits: Option<KeyRateLimits>,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
     #[serde(with = "time::serde::rfc3339")]
@@ -173,6 +199,7 @@ impl Key {
             actions: vec![Action::All],
             indexes: vec![IndexUidPattern::all()],
             expires_at: None,
+            rate_limits: None,
             created_at: now,
             updated_at: now,
         }
@@ -188,6 +215,7 @@ impl Key {
             actions: vec![Action::AllGet, Action::KeysGet],
             indexes: vec![IndexUidPattern::all()],
             expires_at: None,
+            rate_limits: None,
             created_at: now,
             updated_at: now,
         }
@@ -203,6 +231,7 @@ impl Key {
             actions: vec![Action::Search],
             indexes: vec![IndexUidPattern::all()],
             expires_at: None,
+            rate_limits: None,
             created_at: now,
             updated_at: now,
         }
@@ -218,12 +247,167 @@ impl Key {
             actions: vec![Action::ChatCompletions, Action::Search],
             indexes: vec![IndexUidPattern::all()],
             expires_at: None,
+            rate_limits: None,
             created_at: now,
             updated_at: now,
         }
     }
 }
 
+/// The algorithm of a signing key, i.e. the one the identity provider uses to sign its tenant tokens.
+#[routes::request(db, no_error)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SigningKeyAlgorithm {
+    /// RSASSA-PKCS1-v1_5 using SHA-256
+    #[request(rename = "RS256")]
+    Rs256,
+    /// ECDSA using the P-256 curve and SHA-256
+    #[request(rename = "ES256")]
+    Es256,
+    /// EdDSA using the Ed25519 curve
+    #[request(rename = "EdDSA")]
+    EdDsa,
+}
+
+/// Request body for registering the public key of an identity provider. The tenant tokens
+/// signed with the matching private key and carrying the `kid` in their header are then
+/// verified with this public key instead of an API key.
+#[routes::request(validate = validate_signing_key_material -> DeserrJsonError<InvalidSigningKeyPublicKey>)]
+#[derive(Debug)]
+pub struct CreateSigningKey {
+    /// The key id, matched against the `kid` header of the tenant tokens. It can only contain
+    /// alphanumeric characters, hyphens (-), underscores (_) and dots (.).
+    #[request(
+        required,
+        example = "my-idp-2024",
+        error = DeserrJsonError<InvalidSigningKeyKid>,
+        try_from(String) = parse_signing_key_kid -> ParseSigningKeyKidError
+    )]
+    pub kid: String,
+    /// The algorithm the identity provider signs its tenant tokens with.
+    #[request(required, example = "EdDSA", error = DeserrJsonError<InvalidSigningKeyAlgorithm>)]
+    pub algorithm: SigningKeyAlgorithm,
+    /// The PEM-encoded public key. Exactly one of `publicKey` and `jwk` must be specified.
+    #[request(
+        default,
+        example = "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAdilE7LnzschuKNTNvSvCXGqcunMo88p7EKPeV1AwlIw=\n-----END PUBLIC KEY-----",
+        error = DeserrJsonError<InvalidSigningKeyPublicKey>
+    )]
+    pub public_key: Option<String>,
+    /// The public key as a JSON Web Key. Exactly one of `publicKey` and `jwk` must be specified.
+    #[request(default, schema_type = Option<Object>, error = DeserrJsonError<InvalidSigningKeyPublicKey>)]
+    pub jwk: Option<serde_json::Value>,
+    /// The actions the tenant tokens signed with this key may perform.
+    /// Only `search`, `chatCompletions` and `*` can be delegated.
+    #[request(required, example = json!(["search"]), error = DeserrJsonError<InvalidSigningKeyActions>)]
+    pub actions: Vec<Action>,
+    /// The indexes the tenant tokens signed with this key may access. `["*"]` for all indexes.
+    #[request(
+        required,
+        error = DeserrJsonError<InvalidSigningKeyIndexes>,
+        schema_type = Vec<String>,
+        example = json!(["products"])
+    )]
+    pub indexes: Vec<IndexUidPattern>,
+    /// Represent the expiration date and time as RFC 3339 format. `null` or omitted
+    /// equals to no expiration time. Once past this date, the tokens signed with the key are rejected.
+    #[request(default,
+        error = DeserrJsonError<InvalidSigningKeyExpiresAt>,
+        try_from(Option<String>) = parse_expiration_date -> ParseOffsetDateTimeError
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+}
+
+fn parse_signing_key_kid(kid: String) -> Result<String, ParseSigningKeyKidError> {
+    if !kid.is_empty()
+        && kid.len() <= 128
+        && kid.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        Ok(kid)
+    } else {
+        Err(ParseSigningKeyKidError(kid))
+    }
+}
+
+fn validate_signing_key_material<E: DeserializeError>(
+    create: CreateSigningKey,
+    location: ValuePointerRef,
+) -> Result<CreateSigningKey, E> {
+    match (&create.public_key, &create.jwk) {
+        (Some(_), None) | (None, Some(_)) => Ok(create),
+        _ => Err(deserr::take_cf_content(E::error::<Infallible>(
+            None,
+            deserr::ErrorKind::Unexpected {
+                msg: "Exactly one of `publicKey` and `jwk` must be specified.".to_string(),
+            },
+            location,
+        ))),
+    }
+}
+
+impl CreateSigningKey {
+    pub fn to_signing_key(self) -> SigningKey {
+        let CreateSigningKey { kid, algorithm, public_key, jwk, actions, indexes, expires_at } =
+            self;
+        SigningKey {
+            kid,
+            algorithm,
+            public_key,
+            jwk,
+            actions,
+            indexes,
+            expires_at,
+            created_at: OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+/// A public key registered to verify the tenant tokens signed by an identity provider.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningKey {
+    /// The key id, matched against the `kid` header of the tenant tokens.
+    pub kid: String,
+    /// The algorithm the identity provider signs its tenant tokens with.
+    pub algorithm: SigningKeyAlgorithm,
+    /// The PEM-encoded public key, if it was registered in this format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    /// The public key as a JSON Web Key, if it was registered in this format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<Object>)]
+    pub jwk: Option<serde_json::Value>,
+    /// The actions the tenant tokens signed with this key may perform.
+    pub actions: Vec<Action>,
+    /// The indexes the tenant tokens signed with this key may access.
+    #[schema(value_type = Vec<String>)]
+    pub indexes: Vec<IndexUidPattern>,
+    /// The date after which the tokens signed with this key are rejected, `null` if it never expires.
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[schema(value_type = Option<String>)]
+    pub expires_at: Option<OffsetDateTime>,
+    /// The date this key was registered.
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String)]
+    pub created_at: OffsetDateTime,
+}
+
+impl SigningKey {
+    /// The actions that a tenant token can be used for, and thus that a signing key can delegate.
+    pub fn is_delegable(action: Action) -> bool {
+        matches!(action, Action::All | Action::Search | Action::ChatCompletions)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|exp| exp <= OffsetDateTime::now_utc())
+    }
+
+    /// Whether the tokens signed with this key may perform the action, without checking the index.
+    pub fn is_action_authorized(&self, action: Action) -> bool {
+        self.actions.iter().any(|a| *a == Action::All || *a == action)
+    }
+}
+
 fn parse_expiration_date(
     string: Option<String>,
 ) -> std::result::Result<Option<OffsetDateTime>, ParseOffsetDateTimeError> {
@@ -379,6 +563,20 @@ pub enum Action {
     DynamicSearchRulesDelete,
     #[request(rename = "dynamicSearchRules.*")]
     DynamicSearchRulesAll,
+    #[request(rename = "schedules.get")]
+    SchedulesGet,
+    #[request(rename = "schedules.create")]
+    SchedulesCreate,
+    #[request(rename = "schedules.update")]
+    SchedulesUpdate,
+    #[request(rename = "schedules.delete")]
+    SchedulesDelete,
+    #[request(rename = "schedules.*")]
+    SchedulesAll,
+    #[request(rename = "dumps.import")]
+    DumpsImport,
+    #[request(rename = "tasks.prioritize")]
+    TasksPrioritize,
 }
 
 impl Action {
@@ -443,6 +641,13 @@ impl Action {
             DYNAMIC_SEARCH_RULES_UPDATE => Some(Self::DynamicSearchRulesUpdate),
             DYNAMIC_SEARCH_RULES_DELETE => Some(Self::DynamicSearchRulesDelete),
             DYNAMIC_SEARCH_RULES_ALL => Some(Self::DynamicSearchRulesAll),
+            SCHEDULES_GET => Some(Self::SchedulesGet),
+            SCHEDULES_CREATE => Some(Self::SchedulesCreate),
+            SCHEDULES_UPDATE => Some(Self::SchedulesUpdate),
+            SCHEDULES_DELETE => Some(Self::SchedulesDelete),
+            SCHEDULES_ALL => Some(Self::SchedulesAll),
+            DUMPS_IMPORT => Some(Self::DumpsImport),
+            TASKS_PRIORITIZE => Some(Self::TasksPrioritize),
             _otherwise => None,
         }
     }
@@ -467,7 +672,8 @@ impl Action {
             | SnapshotsAll
             | ChatsSettingsAll
             | WebhooksAll
-            | DynamicSearchRulesAll => false,
+            | DynamicSearchRulesAll
+            | SchedulesAll => false,
 
             Search => true,
             DocumentsAdd => false,
@@ -513,6 +719,12 @@ impl Action {
             DynamicSearchRulesCreate => false,
             DynamicSearchRulesUpdate => false,
             DynamicSearchRulesDelete => false,
+            SchedulesGet => true,
+            SchedulesCreate => false,
+            SchedulesUpdate => false,
+            SchedulesDelete => false,
+            DumpsImport => false,
+            TasksPrioritize => false,
         }
     }
 
@@ -549,6 +761,8 @@ impl Action {
             Action::TasksDelete => IndexScope::RouteHandlerChecksIndex,
             Action::TasksGet => IndexScope::RouteHandlerChecksIndex,
             Action::TasksCompact => IndexScope::DenyIndexScope,
+            // checked by the routes enqueuing the tasks
+            Action::TasksPrioritize => IndexScope::RouteHandlerChecksIndex,
 
             // settings.get is handler-checked, as required by render-template
             Action::SettingsGet => IndexScope::RouteHandlerChecksIndex,
@@ -562,7 +776,7 @@ impl Action {
 
             Action::ChatCompletions => IndexScope::RouteHandlerChecksIndex,
 
-            // metrics, dumps, snapshots, version, keys, experimental features, export, network, chats, webhooks and DSR are global
+            // metrics, dumps, snapshots, version, keys, experimental features, export, network, chats, webhooks, DSR and schedules are global
             Action::MetricsAll
             | Action::MetricsGet
             | Action::DumpsAll
@@ -594,7 +808,13 @@ impl Action {
             | Action::DynamicSearchRulesCreate
             | Action::DynamicSearchRulesUpdate
             | Action::DynamicSearchRulesDelete
-            | Action::DynamicSearchRulesAll => IndexScope::DenyIndexScope,
+            | Action::DynamicSearchRulesAll
+            | Action::SchedulesGet
+            | Action::SchedulesCreate
+            | Action::SchedulesUpdate
+            | Action::SchedulesDelete
+            | Action::SchedulesAll
+            | Action::DumpsImport => IndexScope::DenyIndexScope,
         }
     }
 
@@ -700,6 +920,14 @@ pub mod actions {
     pub const DYNAMIC_SEARCH_RULES_UPDATE: u8 = DynamicSearchRulesUpdate.repr();
     pub const DYNAMIC_SEARCH_RULES_DELETE: u8 = DynamicSearchRulesDelete.repr();
     pub const DYNAMIC_SEARCH_RULES_ALL: u8 = DynamicSearchRulesAll.repr();
+
+    pub const SCHEDULES_GET: u8 = SchedulesGet.repr();
+    pub const SCHEDULES_CREATE: u8 = SchedulesCreate.repr();
+    pub const SCHEDULES_UPDATE: u8 = SchedulesUpdate.repr();
+    pub const SCHEDULES_DELETE: u8 = SchedulesDelete.repr();
+    pub const SCHEDULES_ALL: u8 = SchedulesAll.repr();
+    pub const DUMPS_IMPORT: u8 = DumpsImport.repr();
+    pub const TASKS_PRIORITIZE: u8 = TasksPrioritize.repr();
 }
 
 #[cfg(test)]
@@ -768,6 +996,13 @@ pub(crate) mod test {
         assert!(DynamicSearchRulesUpdate.repr() == 55 && DYNAMIC_SEARCH_RULES_UPDATE == 55);
         assert!(DynamicSearchRulesDelete.repr() == 56 && DYNAMIC_SEARCH_RULES_DELETE == 56);
         assert!(DynamicSearchRulesAll.repr() == 57 && DYNAMIC_SEARCH_RULES_ALL == 57);
+        assert!(SchedulesGet.repr() == 58 && SCHEDULES_GET == 58);
+        assert!(SchedulesCreate.repr() == 59 && SCHEDULES_CREATE == 59);
+        assert!(SchedulesUpdate.repr() == 60 && SCHEDULES_UPDATE == 60);
+        assert!(SchedulesDelete.repr() == 61 && SCHEDULES_DELETE == 61);
+        assert!(SchedulesAll.repr() == 62 && SCHEDULES_ALL == 62);
+        assert!(DumpsImport.repr() == 63 && DUMPS_IMPORT == 63);
+        assert!(TasksPrioritize.repr() == 64 && TASKS_PRIORITIZE == 64);
     }
 
     #[test]
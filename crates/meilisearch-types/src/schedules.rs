@@ -0,0 +1,379 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use byte_unit::Byte;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use time::{Date, Duration, OffsetDateTime, Time};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::index_uid_pattern::IndexUidPattern;
+use crate::tasks::{ExportIndexSettings, Kind, TaskId};
+
+/// A task that is enqueued automatically each time its cron expression matches.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+pub struct Schedule {
+    /// Unique identifier of the schedule.
+    pub uid: Uuid,
+    /// Cron expression, evaluated in UTC, telling when the task must be enqueued.
+    #[schema(value_type = String, example = json!("0 3 * * *"))]
+    pub cron: CronSchedule,
+    /// The task enqueued by the schedule.
+    pub task: ScheduledTask,
+    /// Whether the schedule enqueues tasks. A disabled schedule is kept but never enqueues anything.
+    pub enabled: bool,
+    /// Date of the creation of the schedule.
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String, example = json!("2024-08-08T16:37:09.971Z"))]
+    pub created_at: OffsetDateTime,
+    /// Date at which the next task will be enqueued. `null` when the schedule is disabled.
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[schema(value_type = Option<String>, example = json!("2024-08-09T03:00:00Z"))]
+    pub next_run_at: Option<OffsetDateTime>,
+    /// Date at which the schedule last enqueued a task. `null` if it never did.
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[schema(value_type = Option<String>, example = json!("2024-08-08T03:00:00Z"))]
+    pub last_run_at: Option<OffsetDateTime>,
+    /// Uid of the last task enqueued by the schedule. `null` if it never enqueued any.
+    pub last_task_uid: Option<TaskId>,
+}
+
+impl Schedule {
+    /// Returns `true` if the schedule must enqueue its task at `now`.
+    pub fn is_due(&self, now: OffsetDateTime) -> bool {
+        self.enabled && self.next_run_at.is_some_and(|next_run_at| next_run_at <= now)
+    }
+
+    /// Computes the date of the next run, strictly after `after`.
+    pub fn plan_next_run(&mut self, after: OffsetDateTime) {
+        self.next_run_at = if self.enabled { self.cron.next_after(after) } else { None };
+    }
+}
+
+/// The template of the task enqueued by a schedule.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ScheduledTask {
+    /// Creates a dump of the instance.
+    DumpCreation,
+    /// Creates a snapshot of the instance.
+    SnapshotCreation,
+    /// Exports documents and settings to a remote Meilisearch.
+    #[serde(rename_all = "camelCase")]
+    Export {
+        url: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        api_key: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[schema(value_type = Option<String>)]
+        payload_size: Option<Byte>,
+        #[schema(value_type = BTreeMap<String, ExportIndexSettings>)]
+        indexes: BTreeMap<IndexUidPattern, ExportIndexSettings>,
+    },
+    /// Compacts an index.
+    #[serde(rename_all = "camelCase")]
+    IndexCompaction { index_uid: String },
+    /// Deletes the finished tasks that finished more than `olderThanDays` days before the task is enqueued.
+    #[serde(rename_all = "camelCase")]
+    TaskDeletion { older_than_days: u32 },
+}
+
+impl ScheduledTask {
+    pub fn kind(&self) -> Kind {
+        match self {
+            ScheduledTask::DumpCreation => Kind::DumpCreation,
+            ScheduledTask::SnapshotCreation => Kind::SnapshotCreation,
+            ScheduledTask::Export { .. } => Kind::Export,
+            ScheduledTask::IndexCompaction { .. } => Kind::IndexCompaction,
+            ScheduledTask::TaskDeletion { .. } => Kind::TaskDeletion,
+        }
+    }
+
+    pub fn redact_api_key(&mut self) {
+        if let ScheduledTask::Export { api_key: Some(api_key), .. } = self {
+            crate::settings::hide_secret(api_key, 0);
+        }
+    }
+}
+
+/// A parsed cron expression.
+///
+/// The five usual fields are supported: minute, hour, day of month, month and day of week.
+/// Each field accepts `*`, values, ranges (`1-5`), lists (`1,15`) and steps (`*/10`, `0-30/5`).
+/// Months and days of week can also be written with their three-letter English names,
+/// and the `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly` shorthands are accepted.
+///
+/// Like in most cron implementations, when both the day of month and the day of week are restricted,
+/// the expression matches the days matching either of them.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    restricted_days_of_month: bool,
+    restricted_days_of_week: bool,
+}
+
+/// How far in the future a cron expression is looked for a match before giving up.
+const MAX_LOOKAHEAD: Duration = Duration::days(366 * 5);
+
+impl CronSchedule {
+    /// Returns the first date strictly after `after` that matches the expression, at the minute precision.
+    ///
+    /// Returns `None` if the expression never matches, e.g. `0 0 30 2 *`.
+    pub fn next_after(&self, after: OffsetDateTime) -> Option<OffsetDateTime> {
+        let after = after.to_offset(time::UtcOffset::UTC);
+        let mut current = after.replace_time(Time::from_hms(after.hour(), after.minute(), 0).ok()?)
+            + Duration::minutes(1);
+        let limit = after + MAX_LOOKAHEAD;
+
+        while current <= limit {
+            if !contains(self.months, u8::from(current.month())) {
+                let (year, month) = match current.month() {
+                    time::Month::December => (current.year() + 1, time::Month::January),
+                    month => (current.year(), month.next()),
+                };
+                let date = Date::from_calendar_date(year, month, 1).ok()?;
+                current = current.replace_date(date).replace_time(Time::MIDNIGHT);
+                continue;
+            }
+            if !self.matches_day(current.date()) {
+                current = current.replace_time(Time::MIDNIGHT) + Duration::days(1);
+                continue;
+            }
+            if !contains(self.hours, current.hour()) {
+                current = current.replace_time(Time::from_hms(current.hour(), 0, 0).ok()?)
+                    + Duration::hours(1);
+                continue;
+            }
+            if !contains(self.minutes, current.minute()) {
+                current += Duration::minutes(1);
+                continue;
+            }
+            return Some(current);
+        }
+
+        None
+    }
+
+    fn matches_day(&self, date: Date) -> bool {
+        let day_of_month = contains(self.days_of_month, date.day());
+        let day_of_week = contains(self.days_of_week, date.weekday().number_days_from_sunday());
+        if self.restricted_days_of_month && self.restricted_days_of_week {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+}
+
+fn contains(set: u64, value: u8) -> bool {
+    set & (1 << value) != 0
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Invalid cron expression `{expression}`: {reason}")]
+pub struct ParseCronError {
+    expression: String,
+    reason: String,
+}
+
+struct Field {
+    name: &'static str,
+    min: u8,
+    max: u8,
+    names: &'static [&'static str],
+}
+
+const MINUTE: Field = Field { name: "minute", min: 0, max: 59, names: &[] };
+const HOUR: Field = Field { name: "hour", min: 0, max: 23, names: &[] };
+const DAY_OF_MONTH: Field = Field { name: "day of month", min: 1, max: 31, names: &[] };
+const MONTH: Field = Field {
+    name: "month",
+    min: 1,
+    max: 12,
+    names: &["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"],
+};
+// 7 is accepted as an alias of sunday
+const DAY_OF_WEEK: Field = Field {
+    name: "day of week",
+    min: 0,
+    max: 7,
+    names: &["sun", "mon", "tue", "wed", "thu", "fri", "sat"],
+};
+
+impl Field {
+    /// Parses the field into a bitset of the matching values, and tells whether the field is restricted.
+    fn parse(&self, field: &str) -> Result<(u64, bool), String> {
+        let mut set = 0;
+        for item in field.split(',') {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => {
+                    let step: u8 = step.parse().ok().filter(|&step| step > 0).ok_or_else(|| {
+                        format!("`{step}` is not a valid step for the {} field", self.name)
+                    })?;
+                    (range, step)
+                }
+                None => (item, 1),
+            };
+            let (start, end) = match range {
+                "*" => (self.min, self.max),
+                range => match range.split_once('-') {
+                    Some((start, end)) => (self.value(start)?, self.value(end)?),
+                    // `5/10` means every 10 starting from 5
+                    None if step > 1 => (self.value(range)?, self.max),
+                    None => {
+                        let value = self.value(range)?;
+                        (value, value)
+                    }
+                },
+            };
+            if start > end {
+                return Err(format!("the range `{range}` of the {} field is reversed", self.name));
+            }
+            for value in (start..=end).step_by(step as usize) {
+                set |= 1 << value;
+            }
+        }
+        Ok((set, field != "*"))
+    }
+
+    fn value(&self, value: &str) -> Result<u8, String> {
+        let lowercase = value.to_ascii_lowercase();
+        if let Some(position) = self.names.iter().position(|name| *name == lowercase) {
+            return Ok(self.min + position as u8);
+        }
+        value.parse().ok().filter(|value| (self.min..=self.max).contains(value)).ok_or_else(|| {
+            format!(
+                "`{value}` is not a valid {}, expected a value between {} and {}",
+                self.name, self.min, self.max
+            )
+        })
+    }
+}
+
+impl FromStr for CronSchedule {
+    type Err = ParseCronError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason: String| ParseCronError { expression: s.to_string(), reason };
+
+        let expression = match s.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+
+        let fields: Vec<_> = expression.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            return Err(error(format!(
+                "expected 5 fields (minute, hour, day of month, month and day of week), but found {}",
+                fields.len()
+            )));
+        };
+
+        let (minutes, _) = MINUTE.parse(minutes).map_err(error)?;
+        let (hours, _) = HOUR.parse(hours).map_err(error)?;
+        let (days_of_month, restricted_days_of_month) =
+            DAY_OF_MONTH.parse(days_of_month).map_err(error)?;
+        let (months, _) = MONTH.parse(months).map_err(error)?;
+        let (mut days_of_week, restricted_days_of_week) =
+            DAY_OF_WEEK.parse(days_of_week).map_err(error)?;
+        // sunday can be written both 0 and 7
+        if contains(days_of_week, 7) {
+            days_of_week |= 1;
+        }
+
+        Ok(CronSchedule {
+            expression: s.trim().to_string(),
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            restricted_days_of_month,
+            restricted_days_of_week,
+        })
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+impl fmt::Debug for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CronSchedule").field(&self.expression).finish()
+    }
+}
+
+impl Serialize for CronSchedule {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.expression)
+    }
+}
+
+impl<'de> Deserialize<'de> for CronSchedule {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expression = String::deserialize(deserializer)?;
+        expression.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn next(cron: &str, after: OffsetDateTime) -> Option<OffsetDateTime> {
+        cron.parse::<CronSchedule>().unwrap().next_after(after)
+    }
+
+    #[test]
+    fn next_run() {
+        let now = datetime!(2024-08-08 16:37:09 UTC);
+
+        assert_eq!(next("* * * * *", now), Some(datetime!(2024-08-08 16:38 UTC)));
+        assert_eq!(next("*/15 * * * *", now), Some(datetime!(2024-08-08 16:45 UTC)));
+        assert_eq!(next("0 3 * * *", now), Some(datetime!(2024-08-09 03:00 UTC)));
+        assert_eq!(next("@daily", now), Some(datetime!(2024-08-09 00:00 UTC)));
+        assert_eq!(next("30 16 * * *", now), Some(datetime!(2024-08-09 16:30 UTC)));
+        // 2024-08-08 is a thursday
+        assert_eq!(next("0 0 * * mon-fri", now), Some(datetime!(2024-08-09 00:00 UTC)));
+        assert_eq!(next("0 0 * * 7", now), Some(datetime!(2024-08-11 00:00 UTC)));
+        assert_eq!(next("0 0 1 jan *", now), Some(datetime!(2025-01-01 00:00 UTC)));
+        assert_eq!(next("0 0 29 2 *", now), Some(datetime!(2028-02-29 00:00 UTC)));
+        // both the day of month and the day of week are restricted: either of them matches
+        assert_eq!(next("0 0 13 * fri", now), Some(datetime!(2024-08-09 00:00 UTC)));
+        // a match is always strictly after the given date
+        assert_eq!(
+            next("0 3 * * *", datetime!(2024-08-09 03:00 UTC)),
+            Some(datetime!(2024-08-10 03:00 UTC))
+        );
+
+        assert_eq!(next("0 0 30 2 *", now), None);
+    }
+
+    #[test]
+    fn invalid_expressions() {
+        let error = |cron: &str| cron.parse::<CronSchedule>().unwrap_err().to_string();
+
+        meili_snap::snapshot!(error("* * *"), @"Invalid cron expression `* * *`: expected 5 fields (minute, hour, day of month, month and day of week), but found 3");
+        meili_snap::snapshot!(error("60 * * * *"), @"Invalid cron expression `60 * * * *`: `60` is not a valid minute, expected a value between 0 and 59");
+        meili_snap::snapshot!(error("* * * foo *"), @"Invalid cron expression `* * * foo *`: `foo` is not a valid month, expected a value between 1 and 12");
+        meili_snap::snapshot!(error("*/0 * * * *"), @"Invalid cron expression `*/0 * * * *`: `0` is not a valid step for the minute field");
+        meili_snap::snapshot!(error("* 5-2 * * *"), @"Invalid cron expression `* 5-2 * * *`: the range `5-2` of the hour field is reversed");
+    }
+}
@@ -12,6 +12,7 @@ use milli::disabled_typos_terms::DisabledTyposTerms;
 use milli::index::PrefixSearch;
 use milli::proximity::ProximityPrecision;
 pub use milli::update::ChatSettings;
+pub use milli::update::RerankerSettings;
 use milli::update::Setting;
 use milli::vector::db::IndexEmbeddingConfig;
 use milli::{
@@ -431,12 +432,27 @@ pub struct Settings<T> {
     )]
     pub chat: Setting<ChatSettings>,
 
+    /// Remote reranker used to personalize the search results: URL, request and response templates, timeout, and fields of the documents sent to it.
+    #[request(
+        error = DeserrJsonError<InvalidSettingsReranker>,
+        schema_type = Option<RerankerSettings>,
+        example = json!({ "url": "http://localhost:8080/rerank", "request": { "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] }, "response": [{ "index": "{{index}}" }, "{{..}}"], "timeoutMs": 5000, "documentFields": ["title", "overview"] }),
+        skip_serializing_if = "Setting::is_not_set",
+    )]
+    pub reranker: Setting<RerankerSettings>,
+
     #[request(skip)]
     pub _kind: PhantomData<T>,
 }
 
 impl<T> Settings<T> {
     pub fn hide_secrets(&mut self) {
+        if let Setting::Set(RerankerSettings { api_key: Setting::Set(api_key), .. }) =
+            &mut self.reranker
+        {
+            hide_secret(api_key, 0);
+        }
+
         let Setting::Set(embedders) = &mut self.embedders else {
             return;
         };
@@ -503,6 +519,7 @@ impl Settings<Checked> {
             facet_search: Setting::Reset,
             prefix_search: Setting::Reset,
             chat: Setting::Reset,
+            reranker: Setting::Reset,
             _kind: PhantomData,
         }
     }
@@ -531,6 +548,7 @@ impl Settings<Checked> {
             facet_search,
             prefix_search,
             chat,
+            reranker,
             _kind,
         } = self;
 
@@ -557,6 +575,7 @@ impl Settings<Checked> {
             facet_search,
             prefix_search,
             chat,
+            reranker,
             _kind: PhantomData,
         }
     }
@@ -609,6 +628,7 @@ impl Settings<Unchecked> {
             facet_search: self.facet_search,
             prefix_search: self.prefix_search,
             chat: self.chat,
+            reranker: self.reranker,
             _kind: PhantomData,
         }
     }
@@ -709,6 +729,7 @@ impl Settings<Unchecked> {
             facet_search: other.facet_search.or(self.facet_search),
             prefix_search: other.prefix_search.or(self.prefix_search),
             chat: other.chat.clone().or(self.chat.clone()),
+            reranker: other.reranker.clone().or(self.reranker.clone()),
             _kind: PhantomData,
         }
     }
@@ -749,6 +770,7 @@ pub fn apply_settings_to_builder(
         facet_search,
         prefix_search,
         chat,
+        reranker,
         _kind,
     } = settings;
 
@@ -972,6 +994,12 @@ pub fn apply_settings_to_builder(
         Setting::Reset => builder.reset_chat(),
         Setting::NotSet => (),
     }
+
+    match reranker {
+        Setting::Set(reranker) => builder.set_reranker(reranker.clone()),
+        Setting::Reset => builder.reset_reranker(),
+        Setting::NotSet => (),
+    }
 }
 
 pub enum SecretPolicy {
@@ -1078,6 +1106,7 @@ pub fn settings(
     let prefix_search = index.prefix_search(rtxn)?.map(PrefixSearchSettings::from);
     let facet_search = index.facet_search(rtxn)?;
     let chat = index.chat_config(rtxn).map(ChatSettings::from)?;
+    let reranker = index.reranker_config(rtxn)?.map(RerankerSettings::from);
 
     let mut settings = Settings {
         displayed_attributes: match displayed_attributes {
@@ -1119,6 +1148,10 @@ pub fn settings(
         facet_search: Setting::Set(facet_search),
         prefix_search: Setting::Set(prefix_search.unwrap_or_default()),
         chat: Setting::Set(chat),
+        reranker: match reranker {
+            Some(reranker) => Setting::Set(reranker),
+            None => Setting::Reset,
+        },
         _kind: PhantomData,
     };
 
@@ -1363,6 +1396,7 @@ pub(crate) mod test {
             facet_search: Setting::NotSet,
             prefix_search: Setting::NotSet,
             chat: Setting::NotSet,
+            reranker: Setting::NotSet,
             _kind: PhantomData::<Unchecked>,
         };
 
@@ -1396,6 +1430,7 @@ pub(crate) mod test {
             facet_search: Setting::NotSet,
             prefix_search: Setting::NotSet,
             chat: Setting::NotSet,
+            reranker: Setting::NotSet,
 
             _kind: PhantomData::<Unchecked>,
         };
@@ -5,6 +5,7 @@ use milli::Object;
 use serde::{Deserialize, Serialize};
 use time::{Duration, OffsetDateTime};
 use utoipa::ToSchema;
+use uuid::Uuid;
 
 use crate::batches::BatchId;
 use crate::error::ResponseError;
@@ -12,6 +13,7 @@ use crate::settings::{Settings, Unchecked};
 use crate::tasks::network::DbTaskNetwork;
 use crate::tasks::{
     serialize_duration, Details, DetailsExportIndexSettings, IndexSwap, Kind, Status, Task, TaskId,
+    TaskPriority,
 };
 
 /// Represents the current state and details of an asynchronous task.
@@ -103,6 +105,15 @@ pub struct TaskView {
     /// track task origins, or add any application-specific information.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_metadata: Option<String>,
+    /// Unique identifier of the schedule that automatically enqueued this
+    /// task. Absent for the tasks that were not enqueued by a schedule.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_uid: Option<Uuid>,
+    /// The priority of the task. Tasks with a higher priority are processed
+    /// first when it doesn't change the order of the tasks of an index.
+    /// Absent for the tasks enqueued with the `normal` priority.
+    #[serde(default, skip_serializing_if = "TaskPriority::is_normal")]
+    pub priority: TaskPriority,
 }
 
 impl TaskView {
@@ -122,6 +133,8 @@ impl TaskView {
             finished_at: task.finished_at,
             network: task.network.clone(),
             custom_metadata: task.custom_metadata.clone(),
+            schedule_uid: task.schedule_uid,
+            priority: task.priority,
         }
     }
 }
@@ -194,6 +207,16 @@ pub struct DetailsView {
     /// processing or failed before generating a UID.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dump_uid: Option<Option<String>>,
+    /// The uids of the indexes created by a `dumpImport` task, after the
+    /// requested renames were applied. The inner `null` indicates the task
+    /// is still processing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub imported_indexes: Option<Option<Vec<String>>>,
+    /// The uids of the indexes of the dump that a `dumpImport` task left
+    /// untouched because they already existed in the instance. The inner
+    /// `null` indicates the task is still processing.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped_indexes: Option<Option<Vec<String>>>,
     /// The context object that was provided to the RHAI function for a
     /// `documentEdition` task. This object contains data that the function
     /// can access during document processing.
@@ -377,6 +400,27 @@ impl DetailsView {
                 // issue anyway.
                 (Some(Some(left)), Some(Some(_right))) => Some(Some(left.to_string())),
             },
+            imported_indexes: match (self.imported_indexes.clone(), other.imported_indexes.clone())
+            {
+                (None, None) => None,
+                (None, Some(None)) | (Some(None), None) | (Some(None), Some(None)) => Some(None),
+                (None | Some(None), Some(Some(indexes)))
+                | (Some(Some(indexes)), None | Some(None)) => Some(Some(indexes)),
+                (Some(Some(mut left)), Some(Some(mut right))) => {
+                    left.append(&mut right);
+                    Some(Some(left))
+                }
+            },
+            skipped_indexes: match (self.skipped_indexes.clone(), other.skipped_indexes.clone()) {
+                (None, None) => None,
+                (None, Some(None)) | (Some(None), None) | (Some(None), Some(None)) => Some(None),
+                (None | Some(None), Some(Some(indexes)))
+                | (Some(Some(indexes)), None | Some(None)) => Some(Some(indexes)),
+                (Some(Some(mut left)), Some(Some(mut right))) => {
+                    left.append(&mut right);
+                    Some(Some(left))
+                }
+            },
             context: match (&self.context, &other.context) {
                 (None, None) => None,
                 (None, Some(None)) | (Some(None), None) | (Some(None), Some(None)) => Some(None),
@@ -616,6 +660,12 @@ impl From<Details> for DetailsView {
                 message: Some(message),
                 ..Default::default()
             },
+            Details::DumpImport { dump_uid, imported_indexes, skipped_indexes } => DetailsView {
+                dump_uid: Some(Some(dump_uid)),
+                imported_indexes: Some(imported_indexes),
+                skipped_indexes: Some(skipped_indexes),
+                ..Default::default()
+            },
             Details::DsrUpdate(update) => match update {
                 crate::tasks::DsrUpdate::CreateOrUpdate { rule_id: _, update: rule } => {
                     DetailsView { rule: Some(rule), updated_rules: Some(1), ..Default::default() }
@@ -4,6 +4,7 @@ use std::fmt::{Display, Write};
 use std::str::FromStr;
 
 use byte_unit::Byte;
+use deserr::Deserr;
 use enum_iterator::Sequence;
 use milli::update::{IndexDocumentsMethod, MissingDocumentPolicy};
 use milli::Object;
@@ -19,7 +20,7 @@ use crate::dynamic_search_rules::{DynamicSearchRuleUpdateRequest, RuleUid};
 use crate::error::ResponseError;
 use crate::index_uid::DsrIndex;
 use crate::index_uid_pattern::IndexUidPattern;
-use crate::keys::Key;
+use crate::keys::{Key, SigningKey};
 use crate::settings::{Settings, Unchecked};
 use crate::{versioning, InstanceUid};
 
@@ -52,6 +53,13 @@ pub struct Task {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_metadata: Option<String>,
+
+    /// The schedule that enqueued this task, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule_uid: Option<Uuid>,
+
+    #[serde(default, skip_serializing_if = "TaskPriority::is_normal")]
+    pub priority: TaskPriority,
 }
 
 impl Task {
@@ -60,6 +68,7 @@ impl Task {
 
         match &self.kind {
             DumpCreation { .. }
+            | DumpImport { .. }
             | SnapshotCreation
             | TaskCancelation { .. }
             | TaskDeletion { .. }
@@ -102,6 +111,7 @@ impl Task {
             | KindWithContent::TaskCancelation { .. }
             | KindWithContent::TaskDeletion { .. }
             | KindWithContent::DumpCreation { .. }
+            | KindWithContent::DumpImport { .. }
             | KindWithContent::SnapshotCreation
             | KindWithContent::Export { .. }
             | KindWithContent::UpgradeDatabase { .. }
@@ -174,7 +184,11 @@ pub enum KindWithContent {
     },
     DumpCreation {
         keys: Vec<Key>,
+        #[serde(default)]
+        signing_keys: Vec<SigningKey>,
         instance_uid: Option<InstanceUid>,
+        #[serde(default)]
+        selection: DumpSelection,
     },
     SnapshotCreation,
     Export {
@@ -192,6 +206,13 @@ pub enum KindWithContent {
     NetworkTopologyChange(network::NetworkTopologyChange),
     DsrUpdate(DsrUpdate),
     DsrClear,
+    DumpImport {
+        dump_uid: String,
+        #[serde(default)]
+        index_renames: BTreeMap<String, String>,
+        #[serde(default)]
+        skip_existing_indexes: bool,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -223,6 +244,39 @@ pub struct ExportIndexSettings {
     pub override_settings: bool,
 }
 
+/// The parts of the instance written in a dump
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpSelection {
+    /// Patterns of the indexes to dump, every index is dumped when `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub indexes: Option<Vec<IndexUidPattern>>,
+    /// Whether the task queue is left out of the dump
+    #[serde(default)]
+    pub skip_tasks: bool,
+    /// Whether the batch queue is left out of the dump
+    #[serde(default)]
+    pub skip_batches: bool,
+    /// Whether the API keys are left out of the dump
+    #[serde(default)]
+    pub skip_keys: bool,
+}
+
+impl DumpSelection {
+    /// Returns `true` if nothing is left out of the dump.
+    pub fn is_full(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Returns `true` if the index must be written in the dump.
+    pub fn contains_index(&self, index_uid: &str) -> bool {
+        match &self.indexes {
+            Some(patterns) => patterns.iter().any(|pattern| pattern.matches_str(index_uid)),
+            None => true,
+        }
+    }
+}
+
 impl KindWithContent {
     pub fn as_kind(&self) -> Kind {
         match self {
@@ -246,6 +300,7 @@ impl KindWithContent {
             KindWithContent::NetworkTopologyChange { .. } => Kind::NetworkTopologyChange,
             KindWithContent::DsrUpdate { .. } => Kind::DsrUpdate,
             KindWithContent::DsrClear => Kind::DsrClear,
+            KindWithContent::DumpImport { .. } => Kind::DumpImport,
         }
     }
 
@@ -254,6 +309,7 @@ impl KindWithContent {
 
         match self {
             DumpCreation { .. }
+            | DumpImport { .. }
             | SnapshotCreation
             | TaskCancelation { .. }
             | TaskDeletion { .. }
@@ -378,6 +434,11 @@ impl KindWithContent {
                 message: "processing tasks for previous network versions".into(),
             }),
             KindWithContent::DsrUpdate(update) => Some(Details::DsrUpdate(update.clone())),
+            KindWithContent::DumpImport { dump_uid, .. } => Some(Details::DumpImport {
+                dump_uid: dump_uid.clone(),
+                imported_indexes: None,
+                skipped_indexes: None,
+            }),
         }
     }
 
@@ -469,6 +530,11 @@ impl KindWithContent {
                 Some(network_topology_change.to_details())
             }
             KindWithContent::DsrUpdate(update) => Some(Details::DsrUpdate(update.clone())),
+            KindWithContent::DumpImport { dump_uid, .. } => Some(Details::DumpImport {
+                dump_uid: dump_uid.clone(),
+                imported_indexes: None,
+                skipped_indexes: None,
+            }),
         }
     }
 }
@@ -540,6 +606,11 @@ impl From<&KindWithContent> for Option<Details> {
                 Some(network_topology_change.to_details())
             }
             KindWithContent::DsrUpdate(update) => Some(Details::DsrUpdate(update.clone())),
+            KindWithContent::DumpImport { dump_uid, .. } => Some(Details::DumpImport {
+                dump_uid: dump_uid.clone(),
+                imported_indexes: None,
+                skipped_indexes: None,
+            }),
         }
     }
 }
@@ -554,12 +625,14 @@ impl From<&KindWithContent> for Option<Details> {
     Hash,
     Serialize,
     Deserialize,
+    Deserr,
     Sequence,
     PartialOrd,
     Ord,
     ToSchema,
 )]
 #[schema(example = json!(Status::Processing))]
+#[deserr(rename_all = camelCase)]
 #[serde(rename_all = "camelCase")]
 pub enum Status {
     Enqueued,
@@ -618,6 +691,85 @@ impl fmt::Display for ParseTaskStatusError {
 }
 impl std::error::Error for ParseTaskStatusError {}
 
+/// The priority of a task.
+///
+/// The scheduler processes the tasks with the highest priority first, as long as it
+/// doesn't change the order in which the tasks of a same index are applied.
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Deserr,
+    Sequence,
+    PartialOrd,
+    Ord,
+    ToSchema,
+)]
+#[schema(example = json!(TaskPriority::High))]
+#[deserr(rename_all = camelCase)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl TaskPriority {
+    pub fn is_normal(&self) -> bool {
+        *self == TaskPriority::Normal
+    }
+}
+
+impl Display for TaskPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TaskPriority::Low => write!(f, "low"),
+            TaskPriority::Normal => write!(f, "normal"),
+            TaskPriority::High => write!(f, "high"),
+        }
+    }
+}
+
+impl FromStr for TaskPriority {
+    type Err = ParseTaskPriorityError;
+
+    fn from_str(priority: &str) -> Result<Self, Self::Err> {
+        if priority.eq_ignore_ascii_case("low") {
+            Ok(TaskPriority::Low)
+        } else if priority.eq_ignore_ascii_case("normal") {
+            Ok(TaskPriority::Normal)
+        } else if priority.eq_ignore_ascii_case("high") {
+            Ok(TaskPriority::High)
+        } else {
+            Err(ParseTaskPriorityError(priority.to_owned()))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseTaskPriorityError(pub String);
+impl fmt::Display for ParseTaskPriorityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is not a valid task priority. Available priorities are {}.",
+            self.0,
+            enum_iterator::all::<TaskPriority>()
+                .map(|p| format!("`{p}`"))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+impl std::error::Error for ParseTaskPriorityError {}
+
 /// The type of the task.
 #[derive(
     Debug,
@@ -628,11 +780,13 @@ impl std::error::Error for ParseTaskStatusError {}
     Hash,
     Serialize,
     Deserialize,
+    Deserr,
     Sequence,
     PartialOrd,
     Ord,
     ToSchema,
 )]
+#[deserr(rename_all = camelCase)]
 #[serde(rename_all = "camelCase")]
 #[schema(rename_all = "camelCase", example = "documentAdditionOrUpdate")]
 pub enum Kind {
@@ -654,6 +808,7 @@ pub enum Kind {
     NetworkTopologyChange,
     DsrUpdate,
     DsrClear,
+    DumpImport,
 }
 
 impl Kind {
@@ -673,6 +828,7 @@ impl Kind {
             | Kind::TaskCancelation
             | Kind::TaskDeletion
             | Kind::DumpCreation
+            | Kind::DumpImport
             | Kind::Export
             | Kind::UpgradeDatabase
             | Kind::NetworkTopologyChange
@@ -701,6 +857,7 @@ impl Display for Kind {
             Kind::NetworkTopologyChange => write!(f, "networkTopologyChange"),
             Kind::DsrUpdate => write!(f, "dsrUpdate"),
             Kind::DsrClear => write!(f, "dsrClear"),
+            Kind::DumpImport => write!(f, "dumpImport"),
         }
     }
 }
@@ -744,6 +901,8 @@ impl FromStr for Kind {
             Ok(Kind::DsrUpdate)
         } else if kind.eq_ignore_ascii_case("dsrClear") {
             Ok(Kind::DsrClear)
+        } else if kind.eq_ignore_ascii_case("dumpImport") {
+            Ok(Kind::DumpImport)
         } else {
             Err(ParseTaskKindError(kind.to_owned()))
         }
@@ -839,6 +998,11 @@ pub enum Details {
         message: String,
     },
     DsrUpdate(DsrUpdate),
+    DumpImport {
+        dump_uid: String,
+        imported_indexes: Option<Vec<String>>,
+        skipped_indexes: Option<Vec<String>>,
+    },
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, ToSchema)]
@@ -871,6 +1035,10 @@ impl Details {
             Self::ClearAll { deleted_documents } => *deleted_documents = Some(0),
             Self::TaskCancelation { canceled_tasks, .. } => *canceled_tasks = Some(0),
             Self::TaskDeletion { deleted_tasks, .. } => *deleted_tasks = Some(0),
+            Self::DumpImport { imported_indexes, skipped_indexes, .. } => {
+                *imported_indexes = Some(Vec::new());
+                *skipped_indexes = Some(Vec::new());
+            }
             Self::IndexCompaction { pre_compaction_size, post_compaction_size, .. } => {
                 *pre_compaction_size = None;
                 *post_compaction_size = None;
@@ -1,17 +1,62 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::index_uid_pattern::IndexUidPattern;
+use crate::tasks::{Kind, Status, TaskId};
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Webhook {
     pub url: String,
     #[serde(default)]
     pub headers: BTreeMap<String, String>,
+    /// Only the tasks of these types are sent to the webhook. All types are sent when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub types: Option<BTreeSet<Kind>>,
+    /// Only the tasks with these statuses are sent to the webhook. All statuses are sent when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<BTreeSet<Status>>,
+    /// Only the tasks about an index matching one of these patterns are sent to the webhook.
+    /// All tasks are sent when `None`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index_uids: Option<BTreeSet<IndexUidPattern>>,
+    /// Shared secret used to sign the payloads sent to the webhook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
 }
 
 impl Webhook {
+    /// Creates a webhook that receives all the tasks, without signature.
+    pub fn new(url: String, headers: BTreeMap<String, String>) -> Self {
+        Self { url, headers, types: None, statuses: None, index_uids: None, secret: None }
+    }
+
+    /// Returns `true` if the webhook only wants to receive some of the tasks.
+    pub fn has_filters(&self) -> bool {
+        self.types.is_some() || self.statuses.is_some() || self.index_uids.is_some()
+    }
+
+    /// Returns `true` if a task of this type and status, on these indexes, must be sent to the webhook.
+    pub fn accepts(&self, kind: Kind, status: Status, indexes: &[&str]) -> bool {
+        self.types.as_ref().is_none_or(|types| types.contains(&kind))
+            && self.statuses.as_ref().is_none_or(|statuses| statuses.contains(&status))
+            && self.index_uids.as_ref().is_none_or(|patterns| {
+                indexes
+                    .iter()
+                    .any(|index| patterns.iter().any(|pattern| pattern.matches_str(index)))
+            })
+    }
+
+    pub fn redact_secret(&mut self) {
+        if let Some(secret) = &mut self.secret {
+            crate::settings::hide_secret(secret, 0);
+        }
+    }
+
     pub fn redact_authorization_header(&mut self) {
         // headers are case insensitive, so to make the redaction robust we iterate over qualifying headers
         // rather than getting one canonical `Authorization` header.
@@ -44,3 +89,54 @@ pub struct WebhooksDumpView {
     #[serde(default)]
     pub webhooks: BTreeMap<Uuid, Webhook>,
 }
+
+/// Identifier of a webhook delivery.
+pub type DeliveryId = u32;
+
+/// A webhook notification that could not be delivered on the first attempt.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+pub struct WebhookDelivery {
+    /// Unique identifier of the delivery.
+    pub uid: DeliveryId,
+    /// Uuid of the webhook the notification is sent to.
+    pub webhook_uuid: Uuid,
+    /// Uids of the tasks contained in the notification.
+    pub task_uids: Vec<TaskId>,
+    /// Whether the notification is still being retried, was eventually delivered, or was given up on.
+    pub status: DeliveryStatus,
+    /// All the attempts made to deliver the notification, oldest first.
+    pub attempts: Vec<DeliveryAttempt>,
+    /// Date of the next automatic attempt. `null` when no attempt is planned.
+    #[serde(with = "time::serde::rfc3339::option")]
+    #[schema(value_type = Option<String>, example = json!("2024-08-08T16:37:09.971Z"))]
+    pub next_attempt_at: Option<OffsetDateTime>,
+}
+
+/// The status of a webhook delivery.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DeliveryStatus {
+    /// The notification will be sent again at `nextAttemptAt`.
+    Pending,
+    /// The notification was eventually delivered.
+    Succeeded,
+    /// All the automatic attempts failed. The notification is only sent again on manual redelivery.
+    Failed,
+}
+
+/// An attempt to deliver a webhook notification.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+pub struct DeliveryAttempt {
+    /// Date of the attempt.
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String, example = json!("2024-08-08T16:37:09.971Z"))]
+    pub attempted_at: OffsetDateTime,
+    /// HTTP status code answered by the webhook. `null` when the webhook could not be reached.
+    pub status_code: Option<u16>,
+    /// Why the attempt failed. `null` when it succeeded.
+    pub error: Option<String>,
+}
@@ -58,7 +58,7 @@ fn config_user_id_path(db_path: &Path) -> Option<PathBuf> {
 }
 
 /// Look for the instance-uid in the `data.ms` or in `~/.config/Meilisearch/path-to-db-instance-uid`
-fn find_user_id(db_path: &Path) -> Option<InstanceUid> {
+pub(crate) fn find_user_id(db_path: &Path) -> Option<InstanceUid> {
     fs::read_to_string(db_path.join("instance-uid"))
         .ok()
         .or_else(|| fs::read_to_string(config_user_id_path(db_path)?).ok())
@@ -214,6 +214,7 @@ struct Infos {
     experimental_queue_documents_fetch: bool,
     experimental_legacy_search: bool,
     experimental_personalization: bool,
+    experimental_hugging_face_models_dir: bool,
     experimental_allowed_ip_networks: bool,
     experimental_render_route: bool,
     experimental_tasks_streaming_route: bool,
@@ -224,7 +225,10 @@ struct Infos {
     ignore_missing_dump: bool,
     ignore_dump_if_db_exists: bool,
     import_snapshot: bool,
+    import_snapshot_from_s3: bool,
     schedule_snapshot: Option<u64>,
+    experimental_snapshot_retention: bool,
+    experimental_incremental_snapshots: bool,
     snapshot_dir: bool,
     uses_s3_snapshots: bool,
     ignore_missing_snapshot: bool,
@@ -284,10 +288,14 @@ impl Infos {
             ssl_resumption,
             ssl_tickets,
             import_snapshot,
+            import_snapshot_from_s3,
             ignore_missing_snapshot,
             ignore_snapshot_if_db_exists,
             snapshot_dir,
             schedule_snapshot,
+            experimental_snapshot_retention_count,
+            experimental_snapshot_retention_duration_seconds,
+            experimental_incremental_snapshots,
             import_dump,
             ignore_missing_dump,
             ignore_dump_if_db_exists,
@@ -297,6 +305,7 @@ impl Infos {
             config_file_path,
             no_analytics: _,
             experimental_personalization_api_key,
+            experimental_hugging_face_models_dir,
             s3_snapshot_options,
         } = options;
 
@@ -367,7 +376,11 @@ impl Infos {
             ignore_missing_dump,
             ignore_dump_if_db_exists,
             import_snapshot: import_snapshot.is_some(),
+            import_snapshot_from_s3: import_snapshot_from_s3.is_some(),
             schedule_snapshot,
+            experimental_snapshot_retention: experimental_snapshot_retention_count.is_some()
+                || experimental_snapshot_retention_duration_seconds.is_some(),
+            experimental_incremental_snapshots,
             snapshot_dir: snapshot_dir != Path::new("snapshots/"),
             uses_s3_snapshots: s3_snapshot_options.is_some(),
             ignore_missing_snapshot,
@@ -392,6 +405,7 @@ impl Infos {
             ssl_tickets,
             experimental_no_edition_2024_for_settings,
             experimental_personalization: experimental_personalization_api_key.is_some(),
+            experimental_hugging_face_models_dir: experimental_hugging_face_models_dir.is_some(),
         }
     }
 }
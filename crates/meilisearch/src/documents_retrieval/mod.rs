@@ -16,8 +16,8 @@ use crate::search::federated::types::PreprocessedQuery;
 use crate::search::federated::NetworkPartitioner;
 use crate::search::proxy::{json_proxy, ProxySearchError, ProxySearchParams};
 use crate::search::{
-    add_search_rules, perform_federated_search, FederatedSearchResult, Federation,
-    SearchQueryWithIndex, SearchResultWithIndex, ShowFederationInfo,
+    perform_federated_search, FederatedSearchResult, Federation, SearchQueryWithIndex,
+    SearchResultWithIndex, ShowFederationInfo,
 };
 
 pub use preprocessing::{preprocess_filters, retrieve_foreign_keys_settings};
@@ -56,8 +56,16 @@ impl DocumentSearch {
                 }
                 // Apply search rules from tenant token
                 if let Some(search_rules) = auth_filter.get_index_search_rules(index_uid) {
-                    add_search_rules(&mut federated_query.filter, search_rules);
+                    if let Some(federation) = &mut self.federation {
+                        federation.add_search_rules(index_uid, &search_rules);
+                    }
+                    if let Err(error) =
+                        federated_query.add_search_rules(search_rules, self.federation.is_some())
+                    {
+                        break 'check_authorization Err(error).with_index(query_index);
+                    }
                 }
+                federated_query.tenant_token_claims = auth_filter.tenant_token_claims().cloned();
             }
             Ok(())
         }?;
@@ -114,7 +122,8 @@ impl DocumentSearch {
                     let PreprocessedQuery { query, filter } = query;
                     let q = query.q.clone();
                     let index_uid = query.index_uid.to_string();
-                    let (fixed_query, federation) = fixup_query_federation(&query);
+                    let (fixed_query, federation) =
+                        fixup_query_federation(&query).with_index(query_index)?;
 
                     (q, index_uid, PreprocessedQuery { query: fixed_query, filter }, federation)
                 };
@@ -153,7 +162,19 @@ impl DocumentSearch {
     }
 }
 
-pub fn fixup_query_federation(query: &SearchQueryWithIndex) -> (SearchQueryWithIndex, Federation) {
+/// Turns a single query into a federated search of this query only.
+///
+/// The facet aggregations can't be merged in a federated search, so a query
+/// requesting them is rejected.
+pub fn fixup_query_federation(
+    query: &SearchQueryWithIndex,
+) -> Result<(SearchQueryWithIndex, Federation), MeilisearchHttpError> {
+    if query.has_facet_aggregations() {
+        return Err(MeilisearchHttpError::FacetAggregationsInNetworkQuery(
+            query.index_uid.to_string(),
+        ));
+    }
+
     let mut query = query.clone();
     // Move query parameters that make sense at the federation level
     // from the `SearchQueryWithIndex` to the `Federation`
@@ -181,14 +202,19 @@ pub fn fixup_query_federation(query: &SearchQueryWithIndex) -> (SearchQueryWithI
         sort: _,
         distinct,
         facets,
+        facet_aggregations: _,
         highlight_pre_tag: _,
         highlight_post_tag: _,
         crop_marker: _,
         matching_strategy: _,
+        advanced_query_syntax: _,
+        suggestions: _,
         attributes_to_search_on: _,
         ranking_score_threshold: _,
         locales: _,
         personalize,
+        tenant_token_claims: _,
+        tenant_token_attributes: _,
         federation_options: _,
     } = &mut query;
 
@@ -239,7 +265,7 @@ pub fn fixup_query_federation(query: &SearchQueryWithIndex) -> (SearchQueryWithI
         }
     }
 
-    (query, federation)
+    Ok((query, federation))
 }
 
 /// Local `Result` extension trait to avoid `map_err` boilerplate.
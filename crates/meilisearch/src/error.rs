@@ -41,10 +41,16 @@ pub enum MeilisearchHttpError {
     PaginationInFederatedQuery(&'static str),
     #[error("Using facet options is not allowed in federated queries.\n - Hint: remove `facets` from the query or remove `federation` from the request\n - Hint: pass `federation.facetsByIndex.{0}: {1:?}` for facets in federated search")]
     FacetsInFederatedQuery(String, AttributePatterns),
+    #[error("Using `.facetAggregations` is not allowed in federated queries.\n - Hint: remove `facetAggregations` from the query or remove `federation` from the request")]
+    FacetAggregationsInFederatedQuery,
+    #[error("Using `.facetAggregations` is only allowed when searching a single index without the network.\n - Hint: remove `facetAggregations` from the query\n - Hint: search the index with the `/indexes/{0}/search` route and `useNetwork: false`")]
+    FacetAggregationsInNetworkQuery(String),
     #[error("Using `.personalize` is not allowed in federated queries.\n - Hint: remove `personalize` from the query or remove `federation` from the request\n - Hint: pass `federation.personalize` for personalization in federated search")]
     PersonalizationInFederatedQuery,
     #[error("Using `.showPerformanceDetails` is not allowed in federated queries.\n - Hint: remove `showPerformanceDetails` from the query or remove `federation` from the request")]
     ShowPerformanceDetailsInFederatedQuery,
+    #[error("Using `.suggestions` is not allowed in federated queries.\n - Hint: remove `suggestions` from the query or remove `federation` from the request")]
+    SuggestionsInFederatedQuery,
     #[error("Using `.useNetwork` is not allowed as the same time as `.federationOptions.remote`.\n  - Hint: to perform an explicit query against a remote, remove `.useNetwork`.\n  - Hint: to automatically perform queries against the entire network, remove `.federationOptions.remote`.")]
     RemoteAndUseNetwork,
     #[error("Inconsistent order for values in facet `{facet}`: index `{previous_uid}` orders {previous_facet_order}, but index `{current_uid}` orders {index_facet_order}.\n - Hint: Remove `federation.mergeFacets` or change `faceting.sortFacetValuesBy` to be consistent in settings.")]
@@ -88,6 +94,8 @@ pub enum MeilisearchHttpError {
     DocumentFormat(#[from] DocumentFormatError),
     #[error(transparent)]
     Join(#[from] JoinError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
     #[error("Invalid request: missing `hybrid` parameter when `vector` or `media` are present.")]
     MissingSearchHybrid,
     #[error("Invalid request: both `media` and `vector` parameters are present.")]
@@ -189,6 +197,7 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::FileStore(_) => Code::Internal,
             MeilisearchHttpError::DocumentFormat(e) => e.error_code(),
             MeilisearchHttpError::Join(_) => Code::Internal,
+            MeilisearchHttpError::Io(_) => Code::Internal,
             MeilisearchHttpError::MissingSearchHybrid => Code::MissingSearchHybrid,
             MeilisearchHttpError::MediaAndVector => Code::InvalidSearchMediaAndVector,
             MeilisearchHttpError::FederationOptionsInNonFederatedRequest
@@ -201,7 +210,13 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::DistinctInFederatedQueryAndFederation => {
                 Code::InvalidMultiSearchDistinct
             }
-            MeilisearchHttpError::FacetsInFederatedQuery(..) => Code::InvalidMultiSearchQueryFacets,
+            MeilisearchHttpError::FacetsInFederatedQuery(..)
+            | MeilisearchHttpError::FacetAggregationsInFederatedQuery => {
+                Code::InvalidMultiSearchQueryFacets
+            }
+            MeilisearchHttpError::FacetAggregationsInNetworkQuery(_) => {
+                Code::InvalidSearchFacetAggregations
+            }
             MeilisearchHttpError::InconsistentFacetOrder { .. } => {
                 Code::InvalidMultiSearchFacetOrder
             }
@@ -211,6 +226,9 @@ impl ErrorCode for MeilisearchHttpError {
             MeilisearchHttpError::ShowPerformanceDetailsInFederatedQuery => {
                 Code::InvalidMultiSearchQueryShowPerformanceDetails
             }
+            MeilisearchHttpError::SuggestionsInFederatedQuery => {
+                Code::InvalidMultiSearchQuerySuggestions
+            }
             MeilisearchHttpError::InconsistentOriginHeaders { .. }
             | MeilisearchHttpError::InconsistentImportHeaders { .. }
             | MeilisearchHttpError::InconsistentImportMetadataHeaders { .. }
@@ -43,6 +43,10 @@ impl<P, D> GuardedData<P, D> {
                 None => Err(AuthenticationError::IrretrievableState.into()),
             },
             Err(_) if missing_master_key => Err(AuthenticationError::MissingMasterKey.into()),
+            Err(AuthError::RateLimited(e)) => {
+                let retry_after = e.retry_after();
+                Err(ResponseError::from(e).with_retry_after(retry_after))
+            }
             Err(e) => Err(ResponseError::from_msg(e.to_string(), Code::InvalidApiKey)),
         }
     }
@@ -143,20 +147,27 @@ pub trait Policy: Sized {
 
 pub mod policies {
     use actix_web::web::Data;
-    use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
-    use meilisearch_auth::{AuthController, AuthFilter, SearchRules};
+    use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+    use meilisearch_auth::error::AuthControllerError;
+    use meilisearch_auth::{
+        AuthController, AuthFilter, ConcurrentSearchPermit, RateLimitError, SearchRules,
+    };
     use meilisearch_types::error::{Code, ErrorCode};
     // reexport actions in policies in order to be used in routes configuration.
     pub use meilisearch_types::keys::{actions, Action};
+    use meilisearch_types::keys::{SigningKey, SigningKeyAlgorithm};
     use serde::{Deserialize, Serialize};
     use time::OffsetDateTime;
     use uuid::Uuid;
 
     use crate::extractors::authentication::Policy;
+    use crate::metrics::MEILISEARCH_API_KEY_REQUESTS_TOTAL;
 
     enum TenantTokenOutcome {
         NotATenantToken,
-        Valid(Uuid, SearchRules),
+        Valid(Uuid, SearchRules, serde_json::Map<String, serde_json::Value>),
+        /// The token is signed by an identity provider with one of the registered signing keys.
+        SignedBy(SigningKey, SearchRules, serde_json::Map<String, serde_json::Value>),
     }
 
     #[derive(thiserror::Error, Debug)]
@@ -179,6 +190,14 @@ pub mod policies {
         InvalidTenantToken,
         #[error("Could not decode tenant token, {0}.")]
         CouldNotDecodeTenantToken(jsonwebtoken::errors::Error),
+        #[error("The tenant token is signed with the unknown signing key `{0}`.")]
+        UnknownSigningKey(String),
+        #[error("The signing key `{0}` used to sign this tenant token is expired.")]
+        ExpiredSigningKey(String),
+        #[error("The signing key `{kid}` used to sign this tenant token cannot be used for this action.")]
+        SigningKeyUnauthorizedAction { kid: String },
+        #[error(transparent)]
+        RateLimited(RateLimitError),
         #[error("Invalid action `{0}`.")]
         InternalInvalidAction(u8),
     }
@@ -198,6 +217,7 @@ pub mod policies {
         fn error_code(&self) -> Code {
             match self {
                 AuthError::InternalInvalidAction(_) => Code::Internal,
+                AuthError::RateLimited(e) => e.error_code(),
                 _ => Code::InvalidApiKey,
             }
         }
@@ -220,6 +240,107 @@ pub mod policies {
         Ok(api_key_uid)
     }
 
+    /// Builds the key verifying the tenant tokens signed with a signing key
+    /// from its PEM or JWK representation, checking that it matches the algorithm.
+    pub fn signing_key_decoding_key(
+        algorithm: SigningKeyAlgorithm,
+        public_key: Option<&str>,
+        jwk: Option<&serde_json::Value>,
+    ) -> Result<DecodingKey, jsonwebtoken::errors::Error> {
+        use jsonwebtoken::errors::ErrorKind;
+        use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk};
+
+        match (public_key, jwk) {
+            (Some(pem), _) => match algorithm {
+                SigningKeyAlgorithm::Rs256 => DecodingKey::from_rsa_pem(pem.as_bytes()),
+                SigningKeyAlgorithm::Es256 => DecodingKey::from_ec_pem(pem.as_bytes()),
+                SigningKeyAlgorithm::EdDsa => DecodingKey::from_ed_pem(pem.as_bytes()),
+            },
+            (None, Some(jwk)) => {
+                let jwk: Jwk = serde_json::from_value(jwk.clone())?;
+                let matches_algorithm = match (&jwk.algorithm, algorithm) {
+                    (AlgorithmParameters::RSA(_), SigningKeyAlgorithm::Rs256) => true,
+                    (AlgorithmParameters::EllipticCurve(params), SigningKeyAlgorithm::Es256) => {
+                        params.curve == EllipticCurve::P256
+                    }
+                    (AlgorithmParameters::OctetKeyPair(params), SigningKeyAlgorithm::EdDsa) => {
+                        params.curve == EllipticCurve::Ed25519
+                    }
+                    _ => false,
+                };
+                if !matches_algorithm {
+                    return Err(ErrorKind::InvalidKeyFormat.into());
+                }
+                DecodingKey::from_jwk(&jwk)
+            }
+            (None, None) => Err(ErrorKind::InvalidKeyFormat.into()),
+        }
+    }
+
+    fn jwt_algorithm(algorithm: SigningKeyAlgorithm) -> Algorithm {
+        match algorithm {
+            SigningKeyAlgorithm::Rs256 => Algorithm::RS256,
+            SigningKeyAlgorithm::Es256 => Algorithm::ES256,
+            SigningKeyAlgorithm::EdDsa => Algorithm::EdDSA,
+        }
+    }
+
+    /// Checks that the index is authorized by both the tenant token and the key that signed it.
+    fn check_index_authorized(
+        auth_filter: &AuthFilter,
+        index: Option<&str>,
+    ) -> Result<(), AuthError> {
+        let Some(index) = index else { return Ok(()) };
+
+        // First check if the index is authorized in the tenant token, this is a public
+        // information, we can return a nice error message.
+        if !auth_filter.tenant_token_is_index_authorized(index) {
+            return Err(AuthError::TenantTokenAccessingnUnauthorizedIndex {
+                index: index.to_string(),
+                allowed: auth_filter.tenant_token_list_index_authorized(),
+            });
+        }
+        if !auth_filter.api_key_is_index_authorized(index) {
+            if auth_filter.is_tenant_token() {
+                // If the error comes from a tenant token we cannot share the list
+                // of authorized indexes in the API key. This is not public information.
+                return Err(AuthError::TenantTokenApiKeyAccessingnUnauthorizedIndex {
+                    index: index.to_string(),
+                });
+            } else {
+                // Otherwise we can share the list
+                // of authorized indexes in the API key.
+                return Err(AuthError::ApiKeyAccessingnUnauthorizedIndex {
+                    index: index.to_string(),
+                    allowed: auth_filter.api_key_list_index_authorized(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts the request in the usage of the API key and enforces its rate limits.
+    fn register_request(
+        auth: &AuthController,
+        key_uuid: Uuid,
+        search: bool,
+    ) -> Result<Option<ConcurrentSearchPermit>, AuthError> {
+        let key_uid = key_uuid.to_string();
+        match auth.register_request(key_uuid, search) {
+            Ok(permit) => {
+                MEILISEARCH_API_KEY_REQUESTS_TOTAL.with_label_values(&[&key_uid, "accepted"]).inc();
+                Ok(permit)
+            }
+            Err(AuthControllerError::RateLimited(e)) => {
+                MEILISEARCH_API_KEY_REQUESTS_TOTAL
+                    .with_label_values(&[&key_uid, "rate_limited"])
+                    .inc();
+                Err(AuthError::RateLimited(e))
+            }
+            Err(_) => Err(AuthError::InvalidApiKey),
+        }
+    }
+
     fn is_keys_action(action: u8) -> bool {
         use actions::*;
         matches!(action, KEYS_GET | KEYS_CREATE | KEYS_UPDATE | KEYS_DELETE)
@@ -238,6 +359,19 @@ pub mod policies {
             auth: Data<AuthController>,
             token: &str,
             index: Option<&str>,
+        ) -> Result<AuthFilter, AuthError> {
+            Self::authenticate_action(&auth, token, index, true)
+        }
+    }
+
+    impl<const A: u8> ActionPolicy<A> {
+        /// Authenticates the token for the action `A`, only counting the request in the usage
+        /// and the rate limits of the API key when `register` is `true`.
+        fn authenticate_action(
+            auth: &AuthController,
+            token: &str,
+            index: Option<&str>,
+            register: bool,
         ) -> Result<AuthFilter, AuthError> {
             // authenticate if token is the master key.
             // Without a master key, all routes are accessible except the key-related routes.
@@ -245,10 +379,24 @@ pub mod policies {
                 return Ok(AuthFilter::default());
             }
 
-            let (key_uuid, search_rules) =
-                match ActionPolicy::<A>::authenticate_tenant_token(&auth, token) {
-                    Ok(TenantTokenOutcome::Valid(key_uuid, search_rules)) => {
-                        (key_uuid, Some(search_rules))
+            let (key_uuid, search_rules, custom_claims) =
+                match ActionPolicy::<A>::authenticate_tenant_token(auth, token) {
+                    Ok(TenantTokenOutcome::Valid(key_uuid, search_rules, custom_claims)) => {
+                        (key_uuid, Some(search_rules), Some(custom_claims))
+                    }
+                    Ok(TenantTokenOutcome::SignedBy(signing_key, search_rules, custom_claims)) => {
+                        let action =
+                            Action::from_repr(A).ok_or(AuthError::InternalInvalidAction(A))?;
+                        if !signing_key.is_action_authorized(action) {
+                            return Err(AuthError::SigningKeyUnauthorizedAction {
+                                kid: signing_key.kid,
+                            });
+                        }
+                        let auth_filter = auth
+                            .get_signing_key_filters(&signing_key, search_rules)
+                            .with_tenant_token_claims(custom_claims);
+                        check_index_authorized(&auth_filter, index)?;
+                        return Ok(auth_filter);
                     }
                     Ok(TenantTokenOutcome::NotATenantToken)
                     | Err(AuthError::InvalidTenantToken) => (
@@ -256,51 +404,32 @@ pub mod policies {
                             .map_err(|_e| AuthError::InvalidApiKey)?
                             .ok_or(AuthError::InvalidApiKey)?,
                         None,
+                        None,
                     ),
                     Err(e) => return Err(e),
                 };
 
             // check that the indexes are allowed
             let action = Action::from_repr(A).ok_or(AuthError::InternalInvalidAction(A))?;
-            let auth_filter = auth
+            let mut auth_filter = auth
                 .get_key_filters(key_uuid, search_rules)
                 .map_err(|_e| AuthError::InvalidApiKey)?;
-
-            // First check if the index is authorized in the tenant token, this is a public
-            // information, we can return a nice error message.
-            if let Some(index) = index {
-                if !auth_filter.tenant_token_is_index_authorized(index) {
-                    return Err(AuthError::TenantTokenAccessingnUnauthorizedIndex {
-                        index: index.to_string(),
-                        allowed: auth_filter.tenant_token_list_index_authorized(),
-                    });
-                }
-                if !auth_filter.api_key_is_index_authorized(index) {
-                    if auth_filter.is_tenant_token() {
-                        // If the error comes from a tenant token we cannot share the list
-                        // of authorized indexes in the API key. This is not public information.
-                        return Err(AuthError::TenantTokenApiKeyAccessingnUnauthorizedIndex {
-                            index: index.to_string(),
-                        });
-                    } else {
-                        // Otherwise we can share the list
-                        // of authorized indexes in the API key.
-                        return Err(AuthError::ApiKeyAccessingnUnauthorizedIndex {
-                            index: index.to_string(),
-                            allowed: auth_filter.api_key_list_index_authorized(),
-                        });
-                    }
-                }
+            if let Some(custom_claims) = custom_claims {
+                auth_filter = auth_filter.with_tenant_token_claims(custom_claims);
             }
+
+            check_index_authorized(&auth_filter, index)?;
             if auth.is_key_authorized(key_uuid, action, index).unwrap_or(false) {
-                return Ok(auth_filter);
+                if !register {
+                    return Ok(auth_filter);
+                }
+                let permit = register_request(auth, key_uuid, A == actions::SEARCH)?;
+                return Ok(auth_filter.with_search_permit(permit));
             }
 
             Err(AuthError::InvalidApiKey)
         }
-    }
 
-    impl<const A: u8> ActionPolicy<A> {
         fn authenticate_tenant_token(
             auth: &AuthController,
             token: &str,
@@ -310,6 +439,13 @@ pub mod policies {
                 return Ok(TenantTokenOutcome::NotATenantToken);
             }
 
+            let header = decode_header(token)?;
+            if let Some(kid) = header.kid.filter(|_| {
+                matches!(header.alg, Algorithm::RS256 | Algorithm::ES256 | Algorithm::EdDSA)
+            }) {
+                return Self::authenticate_signed_tenant_token(auth, token, kid);
+            }
+
             let uid = extract_key_id(token)?;
 
             // Check if tenant token is valid.
@@ -333,7 +469,47 @@ pub mod policies {
                 }
             }
 
-            Ok(TenantTokenOutcome::Valid(uid, data.claims.search_rules))
+            let Claims { search_rules, exp: _, api_key_uid: _, custom_claims } = data.claims;
+            Ok(TenantTokenOutcome::Valid(uid, search_rules, custom_claims))
+        }
+
+        /// Verifies a tenant token signed by an identity provider with the signing key `kid`.
+        fn authenticate_signed_tenant_token(
+            auth: &AuthController,
+            token: &str,
+            kid: String,
+        ) -> Result<TenantTokenOutcome, AuthError> {
+            let signing_key = match auth.get_signing_key(&kid) {
+                Ok(signing_key) => signing_key,
+                Err(AuthControllerError::SigningKeyNotFound(_)) => {
+                    return Err(AuthError::UnknownSigningKey(kid))
+                }
+                Err(_) => return Err(AuthError::InvalidTenantToken),
+            };
+            if signing_key.is_expired() {
+                return Err(AuthError::ExpiredSigningKey(kid));
+            }
+
+            let decoding_key = signing_key_decoding_key(
+                signing_key.algorithm,
+                signing_key.public_key.as_deref(),
+                signing_key.jwk.as_ref(),
+            )?;
+            let mut validation = Validation::new(jwt_algorithm(signing_key.algorithm));
+            validation.validate_exp = false;
+            validation.required_spec_claims.remove("exp");
+            let data = decode::<SignedClaims>(token, &decoding_key, &validation)?;
+
+            // Check if token is expired.
+            if let Some(exp) = data.claims.exp {
+                let now = OffsetDateTime::now_utc().unix_timestamp();
+                if now > exp {
+                    return Err(AuthError::ExpiredTenantToken { exp, now });
+                }
+            }
+
+            let SignedClaims { search_rules, exp: _, custom_claims } = data.claims;
+            Ok(TenantTokenOutcome::SignedBy(signing_key, search_rules, custom_claims))
         }
     }
 
@@ -346,7 +522,9 @@ pub mod policies {
             index: Option<&str>,
         ) -> Result<AuthFilter, AuthError> {
             let filter_a = ActionPolicy::<A>::authenticate(auth.clone(), token, index)?;
-            let _filter_b = ActionPolicy::<B>::authenticate(auth, token, index)?;
+            // The request was already registered while authenticating the first action,
+            // registering it again would count it twice in the usage and rate limits of the key.
+            let _filter_b = ActionPolicy::<B>::authenticate_action(&auth, token, index, false)?;
             // There is no point merging the filters here.
             // Since they originate from the same API key, they will hold the same information.
             Ok(filter_a)
@@ -359,5 +537,19 @@ pub mod policies {
         search_rules: SearchRules,
         exp: Option<i64>,
         api_key_uid: Uuid,
+        /// Any other claim of the token, matched by the conditions of dynamic search rules.
+        #[serde(flatten)]
+        custom_claims: serde_json::Map<String, serde_json::Value>,
+    }
+
+    /// The claims of a tenant token signed by an identity provider, that is not tied to an API key.
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct SignedClaims {
+        search_rules: SearchRules,
+        exp: Option<i64>,
+        /// Any other claim of the token, matched by the conditions of dynamic search rules.
+        #[serde(flatten)]
+        custom_claims: serde_json::Map<String, serde_json::Value>,
     }
 }
@@ -15,11 +15,12 @@ mod option_test;
 pub mod personalization;
 pub mod proxy;
 pub mod routes;
+pub mod schedules;
 pub mod search;
 pub mod search_queue;
 
 use std::fs::File;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::thread;
@@ -40,7 +41,7 @@ use error::PayloadError;
 use extractors::payload::PayloadConfig;
 use http_client::policy::IpPolicy;
 use index_scheduler::versioning::Versioning;
-use index_scheduler::{IndexScheduler, IndexSchedulerOptions};
+use index_scheduler::{IndexScheduler, IndexSchedulerOptions, SnapshotRetention};
 use meilisearch_auth::{open_auth_store_env, AuthController};
 use meilisearch_types::milli::constants::VERSION_MAJOR;
 use meilisearch_types::milli::dynamic_search_rules::DsrFuel;
@@ -336,8 +337,15 @@ pub fn setup_meilisearch(
         index_count: DEFAULT_INDEX_COUNT,
         instance_features: opt.to_instance_features(),
         embedding_cache_cap: opt.experimental_embedding_cache_entries,
-        ip_policy,
+        ip_policy: ip_policy.clone(),
         dsr_fuel,
+        snapshot_retention: SnapshotRetention {
+            max_count: opt.experimental_snapshot_retention_count,
+            max_age: opt
+                .experimental_snapshot_retention_duration_seconds
+                .map(|seconds| Duration::from_secs(seconds.get())),
+        },
+        incremental_snapshots: opt.experimental_incremental_snapshots,
     };
     let binary_version = (VERSION_MAJOR, VERSION_MINOR, VERSION_PATCH);
 
@@ -346,19 +354,7 @@ pub fn setup_meilisearch(
         let snapshot_path_exists = snapshot_path.exists();
         // the db is empty and the snapshot exists, import it
         if empty_db && snapshot_path_exists {
-            match compression::from_tar_gz(snapshot_path, &opt.db_path) {
-                Ok(()) => open_or_create_database_unchecked(
-                    opt,
-                    index_scheduler_opt,
-                    OnFailure::RemoveDb,
-                    binary_version, // the db is empty
-                    handle,
-                )?,
-                Err(e) => {
-                    std::fs::remove_dir_all(&opt.db_path)?;
-                    return Err(e);
-                }
-            }
+            import_snapshot(opt, snapshot_path, index_scheduler_opt, binary_version, handle)?
         // the db already exists and we should not ignore the snapshot => throw an error
         } else if !empty_db && !opt.ignore_snapshot_if_db_exists {
             bail!(
@@ -372,6 +368,30 @@ pub fn setup_meilisearch(
         } else {
             open_or_create_database(opt, index_scheduler_opt, empty_db, binary_version, handle)?
         }
+    } else if let Some(ref snapshot_name) = opt.import_snapshot_from_s3 {
+        // the snapshot is only downloaded if the db is empty
+        let snapshot = if empty_db {
+            download_snapshot_from_s3(opt, snapshot_name, ip_policy, &handle)?
+        } else {
+            None
+        };
+        // the db is empty and the snapshot exists, import it
+        if let Some((_snapshot_dir, snapshot_path)) = snapshot {
+            import_snapshot(opt, &snapshot_path, index_scheduler_opt, binary_version, handle)?
+        // the db already exists and we should not ignore the snapshot => throw an error
+        } else if !empty_db && !opt.ignore_snapshot_if_db_exists {
+            bail!(
+                "database already exists at {:?}, try to delete it or rename it",
+                opt.db_path.canonicalize().unwrap_or_else(|_| opt.db_path.to_owned())
+            )
+        // the snapshot doesn't exist and we can't ignore it => throw an error
+        } else if empty_db && !opt.ignore_missing_snapshot {
+            bail!("snapshot `{snapshot_name}` doesn't exist in the S3 bucket")
+        // the db exists and we can ignore the snapshot because of the ignore_snapshot_if_db_exists flag
+        // or, the snapshot is missing but we can ignore that because of the ignore_missing_snapshot flag
+        } else {
+            open_or_create_database(opt, index_scheduler_opt, empty_db, binary_version, handle)?
+        }
     } else if let Some(ref path) = opt.import_dump {
         let src_path_exists = path.exists();
         // the db is empty and the dump exists, import it
@@ -425,6 +445,13 @@ pub fn setup_meilisearch(
             .unwrap();
     }
 
+    // We create a loop in a thread that registers the tasks of the schedules
+    schedules::spawn_schedules_thread(
+        index_scheduler.clone(),
+        auth_controller.clone(),
+        opt.db_path.clone(),
+    );
+
     Ok((index_scheduler, auth_controller))
 }
 
@@ -435,6 +462,75 @@ fn almost_as_big_as(input: byte_unit::Byte) -> byte_unit::Byte {
     with_margin.unwrap_or(at_least).max(at_least)
 }
 
+/// Extracts the snapshot into the empty database directory and opens it.
+fn import_snapshot(
+    opt: &Opt,
+    snapshot_path: &Path,
+    index_scheduler_opt: IndexSchedulerOptions,
+    version: (u32, u32, u32),
+    handle: tokio::runtime::Handle,
+) -> anyhow::Result<(IndexScheduler, AuthController)> {
+    let result = if index_scheduler::is_incremental_snapshot(snapshot_path) {
+        index_scheduler::restore_incremental_snapshot(snapshot_path, &opt.db_path)
+            .map_err(anyhow::Error::from)
+    } else {
+        compression::from_tar_gz(snapshot_path, &opt.db_path)
+    };
+    match result {
+        Ok(()) => open_or_create_database_unchecked(
+            opt,
+            index_scheduler_opt,
+            OnFailure::RemoveDb,
+            version, // the db is empty
+            handle,
+        ),
+        Err(e) => {
+            std::fs::remove_dir_all(&opt.db_path)?;
+            Err(e)
+        }
+    }
+}
+
+/// Downloads a snapshot of the S3 bucket into a temporary directory of the snapshots directory.
+///
+/// Returns the directory with the path of the snapshot, or `None` if there is no such snapshot.
+#[cfg(unix)]
+fn download_snapshot_from_s3(
+    opt: &Opt,
+    snapshot_name: &str,
+    ip_policy: IpPolicy,
+    handle: &tokio::runtime::Handle,
+) -> anyhow::Result<Option<(tempfile::TempDir, PathBuf)>> {
+    let Some(s3_snapshot_options) = opt.s3_snapshot_options.clone() else {
+        bail!("`--import-snapshot-from-s3` requires the S3 snapshot options to be defined")
+    };
+    let s3_snapshot_options = s3_snapshot_options.try_into()?;
+
+    std::fs::create_dir_all(&opt.snapshot_dir)?;
+    let snapshot_dir = tempfile::tempdir_in(&opt.snapshot_dir)?;
+    let download = IndexScheduler::download_snapshot_from_s3(
+        s3_snapshot_options,
+        snapshot_name,
+        snapshot_dir.path(),
+        ip_policy,
+    );
+    // We are called from the async main and cannot block the current thread on the runtime.
+    let snapshot_path = thread::scope(|s| s.spawn(|| handle.block_on(download)).join())
+        .expect("the snapshot download panicked")?;
+
+    Ok(snapshot_path.map(|path| (snapshot_dir, path)))
+}
+
+#[cfg(not(unix))]
+fn download_snapshot_from_s3(
+    _opt: &Opt,
+    _snapshot_name: &str,
+    _ip_policy: IpPolicy,
+    _handle: &tokio::runtime::Handle,
+) -> anyhow::Result<Option<(tempfile::TempDir, PathBuf)>> {
+    bail!("Importing a snapshot from S3 is not supported on this platform")
+}
+
 /// Try to start the IndexScheduler and AuthController without checking the VERSION file or anything.
 fn open_or_create_database_unchecked(
     opt: &Opt,
@@ -611,6 +707,10 @@ fn import_dump(
         auth.raw_insert_key(key.clone())?;
         keys.push(key);
     }
+    auth.raw_delete_all_signing_keys()?;
+    for signing_key in dump_reader.signing_keys()? {
+        auth.raw_insert_signing_key(signing_key?)?;
+    }
 
     // 4. Import the `ChatCompletionSettings`s.
     for result in dump_reader.chat_completions_settings()? {
@@ -11,6 +11,14 @@ lazy_static! {
         &["method", "path", "status"]
     )
     .expect("Can't create a metric");
+    pub static ref MEILISEARCH_API_KEY_REQUESTS_TOTAL: IntCounterVec = register_int_counter_vec!(
+        opts!(
+            "meilisearch_api_key_requests_total",
+            "Meilisearch HTTP requests authenticated with an API key"
+        ),
+        &["key_uid", "outcome"]
+    )
+    .expect("Can't create a metric");
     pub static ref MEILISEARCH_DEGRADED_SEARCH_REQUESTS: IntGauge = register_int_gauge!(opts!(
         "meilisearch_degraded_search_requests",
         "Meilisearch number of degraded search requests"
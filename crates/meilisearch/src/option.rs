@@ -2,7 +2,7 @@ use std::env::VarError;
 use std::ffi::OsStr;
 use std::fmt::Display;
 use std::io::{BufReader, Read};
-use std::num::{NonZeroUsize, ParseIntError};
+use std::num::{NonZeroU64, NonZeroUsize, ParseIntError};
 use std::ops::Deref;
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -41,10 +41,16 @@ const MEILI_SSL_REQUIRE_AUTH: &str = "MEILI_SSL_REQUIRE_AUTH";
 const MEILI_SSL_RESUMPTION: &str = "MEILI_SSL_RESUMPTION";
 const MEILI_SSL_TICKETS: &str = "MEILI_SSL_TICKETS";
 const MEILI_IMPORT_SNAPSHOT: &str = "MEILI_IMPORT_SNAPSHOT";
+const MEILI_IMPORT_SNAPSHOT_FROM_S3: &str = "MEILI_IMPORT_SNAPSHOT_FROM_S3";
 const MEILI_IGNORE_MISSING_SNAPSHOT: &str = "MEILI_IGNORE_MISSING_SNAPSHOT";
 const MEILI_IGNORE_SNAPSHOT_IF_DB_EXISTS: &str = "MEILI_IGNORE_SNAPSHOT_IF_DB_EXISTS";
 const MEILI_SNAPSHOT_DIR: &str = "MEILI_SNAPSHOT_DIR";
 const MEILI_SCHEDULE_SNAPSHOT: &str = "MEILI_SCHEDULE_SNAPSHOT";
+const MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_COUNT: &str =
+    "MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_COUNT";
+const MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_DURATION_SECONDS: &str =
+    "MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_DURATION_SECONDS";
+const MEILI_EXPERIMENTAL_INCREMENTAL_SNAPSHOTS: &str = "MEILI_EXPERIMENTAL_INCREMENTAL_SNAPSHOTS";
 const MEILI_IMPORT_DUMP: &str = "MEILI_IMPORT_DUMP";
 const MEILI_IGNORE_MISSING_DUMP: &str = "MEILI_IGNORE_MISSING_DUMP";
 const MEILI_IGNORE_DUMP_IF_DB_EXISTS: &str = "MEILI_IGNORE_DUMP_IF_DB_EXISTS";
@@ -73,6 +79,8 @@ const MEILI_EXPERIMENTAL_EMBEDDING_CACHE_ENTRIES: &str =
     "MEILI_EXPERIMENTAL_EMBEDDING_CACHE_ENTRIES";
 const MEILI_EXPERIMENTAL_PERSONALIZATION_API_KEY: &str =
     "MEILI_EXPERIMENTAL_PERSONALIZATION_API_KEY";
+const MEILI_EXPERIMENTAL_HUGGING_FACE_MODELS_DIR: &str =
+    "MEILI_EXPERIMENTAL_HUGGING_FACE_MODELS_DIR";
 
 const MEILI_EXPERIMENTAL_ALLOWED_IP_NETWORKS: &str = "MEILI_EXPERIMENTAL_ALLOWED_IP_NETWORKS";
 
@@ -304,30 +312,48 @@ pub struct Opt {
     pub ssl_tickets: bool,
 
     /// Launches Meilisearch after importing a previously-generated snapshot at the given filepath.
-    #[clap(long, env = MEILI_IMPORT_SNAPSHOT)]
+    #[clap(long, env = MEILI_IMPORT_SNAPSHOT, group = "snapshot_import")]
     pub import_snapshot: Option<PathBuf>,
 
+    /// Launches Meilisearch after downloading and importing a snapshot from the S3 bucket
+    /// defined by the S3 snapshot options.
+    ///
+    /// When provided with a value, imports the snapshot with this name under the snapshot
+    /// prefix, otherwise imports the latest snapshot uploaded to the bucket.
+    #[clap(
+        long,
+        env = MEILI_IMPORT_SNAPSHOT_FROM_S3,
+        num_args(0..=1),
+        default_missing_value = "latest",
+        value_name = "SNAPSHOT_NAME",
+        requires = "s3_bucket_url",
+        group = "snapshot_import"
+    )]
+    pub import_snapshot_from_s3: Option<String>,
+
     /// Prevents a Meilisearch instance from throwing an error when `--import-snapshot`
-    /// does not point to a valid snapshot file.
+    /// or `--import-snapshot-from-s3` does not point to a valid snapshot file.
     ///
-    /// This command will throw an error if `--import-snapshot` is not defined.
+    /// This command will throw an error if neither `--import-snapshot` nor
+    /// `--import-snapshot-from-s3` is defined.
     #[clap(
         long,
         env = MEILI_IGNORE_MISSING_SNAPSHOT,
-        requires = "import_snapshot"
+        requires = "snapshot_import"
     )]
     #[serde(default)]
     pub ignore_missing_snapshot: bool,
 
     /// Prevents a Meilisearch instance with an existing database from throwing an
-    /// error when using `--import-snapshot`. Instead, the snapshot will be ignored
-    /// and Meilisearch will launch using the existing database.
+    /// error when using `--import-snapshot` or `--import-snapshot-from-s3`. Instead,
+    /// the snapshot will be ignored and Meilisearch will launch using the existing database.
     ///
-    /// This command will throw an error if `--import-snapshot` is not defined.
+    /// This command will throw an error if neither `--import-snapshot` nor
+    /// `--import-snapshot-from-s3` is defined.
     #[clap(
         long,
         env = MEILI_IGNORE_SNAPSHOT_IF_DB_EXISTS,
-        requires = "import_snapshot"
+        requires = "snapshot_import"
     )]
     #[serde(default)]
     pub ignore_snapshot_if_db_exists: bool,
@@ -344,9 +370,36 @@ pub struct Opt {
     #[serde(default, deserialize_with = "schedule_snapshot_deserialize")]
     pub schedule_snapshot: ScheduleSnapshot,
 
+    /// Experimental snapshot retention feature.
+    ///
+    /// Sets the number of snapshots to keep, locally or in the S3 bucket. Older snapshots are
+    /// deleted after each snapshot creation. When a retention policy is defined, the name of
+    /// the snapshots contains their creation date instead of being overwritten.
+    #[clap(long, env = MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_COUNT)]
+    pub experimental_snapshot_retention_count: Option<NonZeroU64>,
+
+    /// Experimental snapshot retention feature.
+    ///
+    /// Sets the duration, in seconds, during which a snapshot is kept, locally or in the S3
+    /// bucket. Expired snapshots are deleted after each snapshot creation.
+    #[clap(long, env = MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_DURATION_SECONDS)]
+    pub experimental_snapshot_retention_duration_seconds: Option<NonZeroU64>,
+
+    /// Experimental incremental snapshots feature.
+    ///
+    /// Snapshots are created as directories in the snapshot directory instead of tarballs,
+    /// and the indexes that did not change since the previous snapshot are hard linked to
+    /// it instead of being copied. Such a snapshot is imported with `--import-snapshot` too.
+    ///
+    /// The snapshots streamed to S3 are stored as one object per file under the name of the
+    /// snapshot, and the unchanged index files are copied server-side from the previous one.
+    #[clap(long, env = MEILI_EXPERIMENTAL_INCREMENTAL_SNAPSHOTS)]
+    #[serde(default)]
+    pub experimental_incremental_snapshots: bool,
+
     /// Imports the dump file located at the specified path. Path must point to a `.dump` file.
     /// If a database already exists, Meilisearch will throw an error and abort launch.
-    #[clap(long, env = MEILI_IMPORT_DUMP, conflicts_with = "import_snapshot")]
+    #[clap(long, env = MEILI_IMPORT_DUMP, conflicts_with = "snapshot_import")]
     pub import_dump: Option<PathBuf>,
 
     /// Prevents Meilisearch from throwing an error when `--import-dump` does not point to
@@ -382,7 +435,7 @@ pub struct Opt {
     /// Experimental contains filter feature. For more information,
     /// see: <https://github.com/orgs/meilisearch/discussions/763>
     ///
-    /// Enables the experimental contains filter operator.
+    /// Enables the experimental contains filter operator, along with the `ENDS WITH` and `MATCHES` operators.
     #[clap(long, env = MEILI_EXPERIMENTAL_CONTAINS_FILTER)]
     #[serde(default)]
     pub experimental_contains_filter: bool,
@@ -504,6 +557,13 @@ pub struct Opt {
     #[clap(long, env = MEILI_EXPERIMENTAL_PERSONALIZATION_API_KEY)]
     pub experimental_personalization_api_key: Option<String>,
 
+    /// Experimental local Hugging Face models.
+    ///
+    /// Sets the directory the `modelPath` of the Hugging Face embedders must be in.
+    /// The `modelPath` setting is rejected when this directory is not set.
+    #[clap(long, env = MEILI_EXPERIMENTAL_HUGGING_FACE_MODELS_DIR)]
+    pub experimental_hugging_face_models_dir: Option<PathBuf>,
+
     /// Experimental control over IP policy.
     ///
     /// Sets this to override the default IP policy of blocking all internal IPs and allow some internal IPs.
@@ -595,10 +655,14 @@ impl Opt {
             ssl_tickets,
             snapshot_dir,
             schedule_snapshot,
+            experimental_snapshot_retention_count,
+            experimental_snapshot_retention_duration_seconds,
+            experimental_incremental_snapshots,
             dump_dir,
             log_level,
             indexer_options,
             import_snapshot: _,
+            import_snapshot_from_s3: _,
             ignore_missing_snapshot: _,
             ignore_snapshot_if_db_exists: _,
             import_dump: _,
@@ -621,6 +685,7 @@ impl Opt {
             experimental_limit_batched_tasks_total_size,
             experimental_embedding_cache_entries,
             experimental_personalization_api_key,
+            experimental_hugging_face_models_dir,
             experimental_allowed_ip_networks,
             s3_snapshot_options,
         } = self;
@@ -664,6 +729,22 @@ impl Opt {
         if let Some(snapshot_interval) = schedule_snapshot_to_env(schedule_snapshot) {
             export_to_env_if_not_present(MEILI_SCHEDULE_SNAPSHOT, snapshot_interval)
         }
+        if let Some(count) = experimental_snapshot_retention_count {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_COUNT,
+                count.to_string(),
+            );
+        }
+        if let Some(seconds) = experimental_snapshot_retention_duration_seconds {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_SNAPSHOT_RETENTION_DURATION_SECONDS,
+                seconds.to_string(),
+            );
+        }
+        export_to_env_if_not_present(
+            MEILI_EXPERIMENTAL_INCREMENTAL_SNAPSHOTS,
+            experimental_incremental_snapshots.to_string(),
+        );
 
         export_to_env_if_not_present(MEILI_DUMP_DIR, dump_dir);
         export_to_env_if_not_present(MEILI_LOG_LEVEL, log_level.to_string());
@@ -728,6 +809,12 @@ impl Opt {
                 experimental_personalization_api_key,
             );
         }
+        if let Some(experimental_hugging_face_models_dir) = experimental_hugging_face_models_dir {
+            export_to_env_if_not_present(
+                MEILI_EXPERIMENTAL_HUGGING_FACE_MODELS_DIR,
+                experimental_hugging_face_models_dir,
+            );
+        }
 
         if !experimental_allowed_ip_networks.is_empty() {
             let experimental_allowed_ip_networks: String = experimental_allowed_ip_networks
@@ -7,6 +7,43 @@ fn test_valid_opt() {
     assert!(Opt::try_parse_from(Some("")).is_ok());
 }
 
+#[test]
+fn test_import_snapshot_from_s3_opt() {
+    let s3_options = [
+        "--s3-bucket-url",
+        "http://localhost:9000",
+        "--s3-bucket-region",
+        "us-east-1",
+        "--s3-bucket-name",
+        "snapshots",
+        "--s3-snapshot-prefix",
+        "meilisearch",
+        "--s3-access-key",
+        "access",
+        "--s3-secret-key",
+        "secret",
+    ];
+
+    // the S3 options are required to import a snapshot from S3
+    assert!(Opt::try_parse_from(["meilisearch", "--import-snapshot-from-s3"]).is_err());
+
+    let args = ["meilisearch", "--import-snapshot-from-s3"].into_iter().chain(s3_options);
+    let opt = Opt::try_parse_from(args).unwrap();
+    assert_eq!(opt.import_snapshot_from_s3.as_deref(), Some("latest"));
+
+    let args = ["meilisearch", "--import-snapshot-from-s3", "data.ms-20250304-102030456"]
+        .into_iter()
+        .chain(s3_options);
+    let opt = Opt::try_parse_from(args).unwrap();
+    assert_eq!(opt.import_snapshot_from_s3.as_deref(), Some("data.ms-20250304-102030456"));
+
+    // a snapshot can only be imported from a single source
+    let args = ["meilisearch", "--import-snapshot-from-s3", "--import-snapshot", "db.snapshot"]
+        .into_iter()
+        .chain(s3_options);
+    assert!(Opt::try_parse_from(args).is_err());
+}
+
 #[test]
 #[ignore]
 fn test_meilli_config_file_path_valid() {
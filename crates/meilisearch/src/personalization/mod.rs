@@ -1,11 +1,13 @@
 use std::time::Duration;
 
 use http_client::reqwest::Client;
+use index_scheduler::IndexScheduler;
 use meilisearch_types::error::{Code, ErrorCode, ResponseError};
 use meilisearch_types::milli::progress::Progress;
-use meilisearch_types::milli::{Deadline, SearchStep};
+use meilisearch_types::milli::reranker::{RerankerConfig, RerankerTemplates};
+use meilisearch_types::milli::{Deadline, Index, SearchStep};
 use rand::Rng;
-use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use tracing::{debug, info, warn};
 
 use crate::search::{Personalize, SearchHit};
@@ -19,7 +21,7 @@ enum PersonalizationError {
     Request(#[from] http_client::reqwest::Error),
     #[error("Personalization service: Failed to parse response: {0}")]
     Parse(String),
-    #[error("Personalization service: Cohere API error: {0}")]
+    #[error("Personalization service: Reranker API error: {0}")]
     Api(String),
     #[error("Personalization service: Unauthorized: invalid API key")]
     Unauthorized,
@@ -33,6 +35,8 @@ enum PersonalizationError {
     Network(String),
     #[error("Personalization service: Deadline exceeded")]
     DeadlineExceeded,
+    #[error("Personalization service: Invalid reranker configuration: {0}")]
+    InvalidConfiguration(String),
     #[error(transparent)]
     FeatureNotEnabled(#[from] index_scheduler::error::FeatureNotEnabledError),
 }
@@ -52,23 +56,82 @@ impl ErrorCode for PersonalizationError {
                 Code::RemoteBadResponse
             }
             PersonalizationError::DeadlineExceeded => Code::Internal, // should not be returned to the client
+            PersonalizationError::InvalidConfiguration(_) => Code::InvalidSettingsReranker,
         }
     }
 }
 
-pub struct CohereService {
+/// An HTTP reranker, either configured in the settings of an index or the default Cohere one.
+pub struct RerankerService {
     client: Client,
-    api_key: String,
+    config: RerankerConfig,
+    templates: RerankerTemplates,
 }
 
-impl CohereService {
-    pub fn new(api_key: String, ip_policy: http_client::policy::IpPolicy) -> Self {
+impl RerankerService {
+    /// Builds a reranker with the HTTP client shared by the rerankers with the same timeout.
+    pub fn new(
+        config: RerankerConfig,
+        index_scheduler: &IndexScheduler,
+    ) -> Result<Self, ResponseError> {
+        let templates =
+            RerankerTemplates::new(&config).map_err(PersonalizationError::InvalidConfiguration)?;
+        let client = index_scheduler
+            .reranker_client(config.timeout_ms())
+            .map_err(PersonalizationError::Request)?;
+        Ok(Self { client, config, templates })
+    }
+
+    /// The reranker used when the index doesn't configure one, calling Cohere's rerank API.
+    pub fn cohere(
+        api_key: String,
+        index_scheduler: &IndexScheduler,
+    ) -> Result<Self, ResponseError> {
         info!("Personalization service initialized with Cohere API");
-        let client = Client::builder()
-            .prepare(|inner| inner.timeout(Duration::from_secs(30)))
-            .build_with_policies(ip_policy, Default::default())
-            .expect("Failed to create HTTP client");
-        Self { client, api_key }
+        let config = RerankerConfig {
+            url: COHERE_API_URL.to_string(),
+            api_key: Some(api_key),
+            request: json!({
+                "query": "{{query}}",
+                "documents": ["{{document}}", "{{..}}"],
+                "model": "rerank-english-v3.0",
+            }),
+            response: json!({ "results": [{ "index": "{{index}}" }, "{{..}}"] }),
+            ..Default::default()
+        };
+        Self::new(config, index_scheduler)
+    }
+
+    /// Returns the reranker configured in the settings of the index, if any.
+    pub fn from_index(
+        index: &Index,
+        index_scheduler: &IndexScheduler,
+    ) -> Result<Option<Self>, ResponseError> {
+        let rtxn = index.read_txn()?;
+        match index.reranker_config(&rtxn)? {
+            Some(config) => Self::new(config, index_scheduler).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the reranker configured in the settings of the first of the local indexes that configures one.
+    ///
+    /// Indexes that don't exist on this instance are ignored, as they may only exist on remotes.
+    pub fn from_indexes<'a>(
+        index_scheduler: &IndexScheduler,
+        index_uids: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Option<Self>, ResponseError> {
+        for index_uid in index_uids {
+            let index = match index_scheduler.user_index(index_uid) {
+                Ok(index) => index,
+                Err(index_scheduler::Error::IndexNotFound(_)) => continue,
+                Err(error) => return Err(error.into()),
+            };
+            if let Some(reranker) = Self::from_index(&index, index_scheduler)? {
+                return Ok(Some(reranker));
+            }
+        }
+        Ok(None)
     }
 
     pub async fn rerank_search_results<H: RerankableSearchHit>(
@@ -97,19 +160,27 @@ impl CohereService {
         let documents: Vec<String> = hits
             .iter()
             .map(|hit| {
+                let document = &hit.search_hit().document;
+                let document = match &self.config.document_fields {
+                    Some(fields) => permissive_json_pointer::select_values(
+                        document.clone(),
+                        fields.iter().map(String::as_str),
+                    ),
+                    None => document.clone(),
+                };
                 // Convert the document to a string representation for reranking
-                serde_json::to_string(&hit.search_hit().document)
-                    .unwrap_or_else(|_| "{}".to_string())
+                serde_json::to_string(&document).unwrap_or_else(|_| "{}".to_string())
             })
             .collect();
 
-        if documents.is_empty() {
+        let document_count = documents.len();
+        let Some(request_body) = self.templates.render_request(&prompt, documents) else {
             return Ok(hits);
-        }
+        };
 
-        // Call Cohere's rerank API with retry logic
+        // Call the rerank API with retry logic
         let reranked_indices =
-            match self.call_rerank_with_retry(&prompt, &documents, deadline).await {
+            match self.call_rerank_with_retry(&request_body, document_count, deadline).await {
                 Ok(indices) => indices,
                 Err(PersonalizationError::DeadlineExceeded) => {
                     // If the deadline is exceeded, return the original search result instead of an error
@@ -118,9 +189,9 @@ impl CohereService {
                 Err(e) => return Err(e.into()),
             };
 
-        debug!("Cohere rerank successful, reordering {} results", hits.len());
+        debug!("Rerank successful, reordering {} results", hits.len());
 
-        // Reorder the hits based on Cohere's reranking
+        // Reorder the hits based on the reranking
         let mut reranked_hits = Vec::new();
         for index in reranked_indices.iter() {
             if let Some(hit) = hits.get(*index) {
@@ -133,24 +204,18 @@ impl CohereService {
 
     async fn call_rerank_with_retry(
         &self,
-        query: &str,
-        documents: &[String],
+        request_body: &Value,
+        document_count: usize,
         deadline: &Deadline,
     ) -> Result<Vec<usize>, PersonalizationError> {
-        let request_body = CohereRerankRequest {
-            query: query.to_string(),
-            documents: documents.to_vec(),
-            model: "rerank-english-v3.0".to_string(),
-        };
-
         // Retry loop similar to vector extraction
         for attempt in 0..MAX_RETRIES {
-            let response_result = self.send_rerank_request(&request_body).await;
+            let response_result = self.send_rerank_request(request_body).await;
 
-            let retry_duration = match self.handle_response(response_result).await {
+            let retry_duration = match self.handle_response(response_result, document_count).await {
                 Ok(indices) => return Ok(indices),
                 Err(retry) => {
-                    warn!("Cohere rerank attempt #{} failed: {}", attempt, retry.error);
+                    warn!("Rerank attempt #{} failed: {}", attempt, retry.error);
 
                     if deadline.exceeded() {
                         warn!("Could not rerank due to deadline");
@@ -173,9 +238,9 @@ impl CohereService {
         }
 
         // Final attempt without retry
-        let response_result = self.send_rerank_request(&request_body).await;
+        let response_result = self.send_rerank_request(request_body).await;
 
-        match self.handle_response(response_result).await {
+        match self.handle_response(response_result, document_count).await {
             Ok(indices) => Ok(indices),
             Err(retry) => Err(retry.into_error()),
         }
@@ -183,15 +248,18 @@ impl CohereService {
 
     async fn send_rerank_request(
         &self,
-        request_body: &CohereRerankRequest,
+        request_body: &Value,
     ) -> Result<http_client::reqwest::Response, http_client::reqwest::Error> {
         self.client
-            .post(COHERE_API_URL)
-            .prepare(|inner| {
-                inner
-                    .header("Authorization", format!("Bearer {}", self.api_key))
-                    .header("Content-Type", "application/json")
-                    .json(request_body)
+            .post(&self.config.url)
+            .prepare(|mut inner| {
+                if let Some(api_key) = &self.config.api_key {
+                    inner = inner.header("Authorization", format!("Bearer {api_key}"));
+                }
+                for (name, value) in &self.config.headers {
+                    inner = inner.header(name, value);
+                }
+                inner.header("Content-Type", "application/json").json(request_body)
             })
             .send()
             .await
@@ -200,6 +268,7 @@ impl CohereService {
     async fn handle_response(
         &self,
         response_result: Result<http_client::reqwest::Response, http_client::reqwest::Error>,
+        document_count: usize,
     ) -> Result<Vec<usize>, Retry> {
         let response = match response_result {
             Ok(r) => r,
@@ -221,7 +290,7 @@ impl CohereService {
         let status_code = status.as_u16();
 
         if status.is_success() {
-            let rerank_response: CohereRerankResponse = match response.json().await {
+            let rerank_response: Value = match response.json().await {
                 Ok(r) => r,
                 Err(e) => {
                     return Err(Retry::retry_later(PersonalizationError::Parse(format!(
@@ -231,11 +300,11 @@ impl CohereService {
                 }
             };
 
-            // Extract indices from rerank results
-            let indices: Vec<usize> =
-                rerank_response.results.iter().map(|result| result.index as usize).collect();
-
-            return Ok(indices);
+            // Extract indices from rerank results, a response that doesn't match the template is not retried
+            return self
+                .templates
+                .extract_ranking(rerank_response, document_count)
+                .map_err(|error| Retry::give_up(PersonalizationError::Parse(error)));
         }
 
         // Handle error status codes
@@ -263,23 +332,6 @@ impl CohereService {
     }
 }
 
-#[derive(Serialize)]
-struct CohereRerankRequest {
-    query: String,
-    documents: Vec<String>,
-    model: String,
-}
-
-#[derive(Deserialize)]
-struct CohereRerankResponse {
-    results: Vec<CohereRerankResult>,
-}
-
-#[derive(Deserialize)]
-struct CohereRerankResult {
-    index: u32,
-}
-
 // Retry strategy similar to vector extraction
 struct Retry {
     error: PersonalizationError,
@@ -325,17 +377,20 @@ impl Retry {
 }
 
 pub enum PersonalizationService {
-    Cohere(CohereService),
+    Cohere(RerankerService),
     Disabled,
 }
 
 impl PersonalizationService {
-    pub fn cohere(api_key: String, ip_policy: http_client::policy::IpPolicy) -> Self {
+    pub fn cohere(
+        api_key: String,
+        index_scheduler: &IndexScheduler,
+    ) -> Result<Self, ResponseError> {
         // If the API key is empty, consider the personalization service as disabled
         if api_key.trim().is_empty() {
-            Self::disabled()
+            Ok(Self::disabled())
         } else {
-            Self::Cohere(CohereService::new(api_key, ip_policy))
+            RerankerService::cohere(api_key, index_scheduler).map(Self::Cohere)
         }
     }
 
@@ -344,28 +399,32 @@ impl PersonalizationService {
         Self::Disabled
     }
 
+    /// Reranks the hits with the reranker of the index if it configures one, or with the default reranker otherwise.
     pub async fn rerank_search_results<H: RerankableSearchHit>(
         &self,
         hits: Vec<H>,
         personalize: &Personalize,
         query: Option<&str>,
+        index_reranker: Option<&RerankerService>,
         deadline: &Deadline,
         progress: &Progress,
     ) -> Result<Vec<H>, ResponseError> {
-        match self {
-            Self::Cohere(cohere_service) => {
-                let _step = progress.update_progress_scoped(SearchStep::Personalization);
-                cohere_service.rerank_search_results(hits, personalize, query, deadline).await
+        let reranker = match (index_reranker, self) {
+            (Some(reranker), _) | (None, Self::Cohere(reranker)) => reranker,
+            (None, Self::Disabled) => {
+                return Err(PersonalizationError::FeatureNotEnabled(
+                    index_scheduler::error::FeatureNotEnabledError {
+                        disabled_action: "reranking search results",
+                        feature: "personalization",
+                        issue_link: "https://github.com/orgs/meilisearch/discussions/866",
+                    },
+                )
+                .into())
             }
-            Self::Disabled => Err(PersonalizationError::FeatureNotEnabled(
-                index_scheduler::error::FeatureNotEnabledError {
-                    disabled_action: "reranking search results",
-                    feature: "personalization",
-                    issue_link: "https://github.com/orgs/meilisearch/discussions/866",
-                },
-            )
-            .into()),
-        }
+        };
+
+        let _step = progress.update_progress_scoped(SearchStep::Personalization);
+        reranker.rerank_search_results(hits, personalize, query, deadline).await
     }
 }
 
@@ -5,12 +5,12 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::{AwebJson, AwebQueryParameter};
 use deserr::Deserr;
 use meilisearch_auth::error::AuthControllerError;
-use meilisearch_auth::AuthController;
+use meilisearch_auth::{AuthController, KeyUsage};
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
-use meilisearch_types::keys::{CreateApiKey, Key, PatchApiKey};
+use meilisearch_types::keys::{CreateApiKey, Key, KeyRateLimits, PatchApiKey};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use utoipa::{IntoParams, ToSchema};
@@ -252,7 +252,9 @@ pub async fn get_api_key(
             Uuid::parse_str(&key).or_else(|_| auth_controller.get_uid_from_encoded_key(&key))?;
         let key = auth_controller.get_key(uid)?;
 
-        Ok(KeyView::from_key(key, &auth_controller))
+        let mut view = KeyView::from_key(key, &auth_controller);
+        view.usage = Some(auth_controller.key_usage(uid).into());
+        Ok(view)
     })
     .await
     .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
@@ -427,6 +429,13 @@ pub(super) struct KeyView {
     /// for keys that never expire.
     #[serde(serialize_with = "time::serde::rfc3339::option::serialize")]
     expires_at: Option<OffsetDateTime>,
+    /// The limits on the usage of the key. Omitted if the key has no limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limits: Option<KeyRateLimits>,
+    /// The usage of the key since Meilisearch started. Only returned when fetching a single key.
+    #[schema(read_only)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage: Option<KeyUsageView>,
     /// The date and time when this API key was created, formatted as an
     /// RFC 3339 date-time string. This is automatically set by Meilisearch
     /// and cannot be modified.
@@ -453,8 +462,29 @@ impl KeyView {
             actions: key.actions,
             indexes: key.indexes.into_iter().map(|x| x.to_string()).collect(),
             expires_at: key.expires_at,
+            rate_limits: key.rate_limits,
+            usage: None,
             created_at: key.created_at,
             updated_at: key.updated_at,
         }
     }
 }
+
+/// The usage counters of an API key. They are kept in memory and reset when Meilisearch restarts.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct KeyUsageView {
+    /// The number of requests authenticated with the key, including the rate limited ones.
+    requests: u64,
+    /// The number of requests rejected because they exceeded the rate limits of the key.
+    rate_limited_requests: u64,
+    /// The number of searches currently running with the key.
+    concurrent_searches: usize,
+}
+
+impl From<KeyUsage> for KeyUsageView {
+    fn from(usage: KeyUsage) -> Self {
+        let KeyUsage { requests, rate_limited_requests, concurrent_searches } = usage;
+        KeyUsageView { requests, rate_limited_requests, concurrent_searches }
+    }
+}
@@ -13,9 +13,10 @@ use async_openai::types::{
     ChatCompletionRequestDeveloperMessageContent, ChatCompletionRequestMessage,
     ChatCompletionRequestSystemMessage, ChatCompletionRequestSystemMessageContent,
     ChatCompletionRequestToolMessage, ChatCompletionRequestToolMessageContent,
-    ChatCompletionStreamOptions, ChatCompletionStreamResponseDelta, ChatCompletionToolArgs,
-    ChatCompletionToolType, CreateChatCompletionRequest, CreateChatCompletionStreamResponse,
-    FinishReason, FunctionCall, FunctionCallStream, FunctionObjectArgs,
+    ChatCompletionStreamOptions, ChatCompletionStreamResponseDelta, ChatCompletionTool,
+    ChatCompletionToolArgs, ChatCompletionToolType, CreateChatCompletionRequest,
+    CreateChatCompletionStreamResponse, FinishReason, FunctionCall, FunctionCallStream,
+    FunctionObjectArgs,
 };
 use async_openai::Client;
 use bumpalo::Bump;
@@ -26,7 +27,7 @@ use meilisearch_auth::{AuthController, IndexSearchRules};
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::features::{
     ChatCompletionPrompts as DbChatCompletionPrompts,
-    ChatCompletionSource as DbChatCompletionSource, SystemRole,
+    ChatCompletionSource as DbChatCompletionSource, ChatConversation, SystemRole,
 };
 use meilisearch_types::heed::RoTxn;
 use meilisearch_types::index_uid::IndexUid;
@@ -34,9 +35,11 @@ use meilisearch_types::keys::actions;
 use meilisearch_types::milli::index::ChatConfig;
 use meilisearch_types::milli::progress::Progress;
 use meilisearch_types::milli::{
-    all_obkv_to_json, obkv_to_json, FieldsIdsMap, OrderBy, PatternMatch, TotalProcessingTimeStep,
+    all_obkv_to_json, obkv_to_json, DocumentId, FieldsIdsMap, OrderBy, PatternMatch,
+    TotalProcessingTimeStep, DEFAULT_VALUES_PER_FACET,
 };
 use meilisearch_types::{Document, Index};
+use roaring::RoaringBitmap;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use tokio::runtime::Handle;
@@ -44,11 +47,14 @@ use tokio::sync::mpsc::error::SendError;
 
 use super::chat_completion_analytics::ChatCompletionAggregator;
 use super::config::Config;
+use super::conversations::{conversation_id_from_request, conversation_not_found};
 use super::errors::{MistralError, OpenAiOutsideError, StreamErrorEvent};
 use super::utils::format_documents;
 use super::{
-    ChatsParam, MEILI_APPEND_CONVERSATION_MESSAGE_NAME, MEILI_SEARCH_IN_INDEX_FUNCTION_NAME,
-    MEILI_SEARCH_PROGRESS_NAME, MEILI_SEARCH_SOURCES_NAME,
+    is_internal_function, ChatsParam, MEILI_APPEND_CONVERSATION_MESSAGE_NAME,
+    MEILI_FACET_DISTRIBUTION_FUNCTION_NAME, MEILI_GET_DOCUMENT_FUNCTION_NAME,
+    MEILI_SEARCH_IN_INDEX_FUNCTION_NAME, MEILI_SEARCH_PROGRESS_NAME, MEILI_SEARCH_SOURCES_NAME,
+    MEILI_SIMILAR_DOCUMENTS_FUNCTION_NAME,
 };
 use crate::analytics::Analytics;
 use crate::documents_retrieval::preprocess_filters;
@@ -64,7 +70,11 @@ use crate::routes::chats::utils::SseEventSender;
 use crate::routes::indexes::search::search_kind;
 use crate::search::federated::types::{PreprocessableQuery, PreprocessedQuery};
 use crate::search::federated::NetworkPartitioner;
-use crate::search::{add_search_rules, elapsed, prepare_search, search_from_kind, SearchQuery};
+use crate::search::{
+    add_search_rules, check_attributes_authorized, elapsed, perform_similar_with_documents_ids,
+    prepare_search, search_from_kind, SearchQuery, SimilarQuery, DEFAULT_SEARCH_LIMIT,
+    DEFAULT_SEARCH_OFFSET,
+};
 use crate::search_queue::SearchQueue;
 
 /// Request a chat completion
@@ -73,6 +83,13 @@ use crate::search_queue::SearchQueue;
 /// using the documents of the authorized indexes as context.
 /// Only streamed responses (`stream: true`) are supported.
 ///
+/// When the `Meili-Conversation-Id` header is sent, the conversation is stored on the server:
+/// the client only sends the new messages and the previous ones are retrieved from the
+/// conversation with this id. The conversations are kept according to the `conversations`
+/// settings of the chat workspace.
+/// A conversation belongs to the API key or tenant token that started it: the conversations
+/// of the other keys and tenants can't be continued.
+///
 /// This route is only available when the `chatCompletions` [experimental feature](https://www.meilisearch.com/docs/resources/help/experimental_features_overview) is enabled.
 #[routes::path(
     params(
@@ -204,9 +221,9 @@ fn setup_search_tool(
     let tools = chat_completion.tools.get_or_insert_default();
     for tool in &tools[..] {
         match tool.function.name.as_str() {
-            MEILI_SEARCH_IN_INDEX_FUNCTION_NAME => {
+            name if is_internal_function(name) => {
                 return Err(ResponseError::from_msg(
-                    format!("{MEILI_SEARCH_IN_INDEX_FUNCTION_NAME} function is already defined."),
+                    format!("{name} function is already defined."),
                     Code::BadRequest,
                 ));
             }
@@ -245,6 +262,10 @@ fn setup_search_tool(
     });
 
     let mut index_uids = Vec::new();
+    let mut similar_index_uids = Vec::new();
+    let mut embedders_description = String::from(
+        "The name of the embedder to use to find the similar documents. The embedders of each index are:\n",
+    );
     let mut function_description = prompts.search_description.clone();
     let mut filter_description = prompts.search_filter_param.clone();
     index_scheduler.try_for_each_user_index::<_, ()>(|name, index| {
@@ -264,6 +285,18 @@ fn setup_search_tool(
             name = name.uid()
         );
         index_uids.push(name.uid().to_string());
+        let embedders = index.embedding_configs().embedding_configs(&rtxn)?;
+        if !embedders.is_empty() {
+            let embedders: Vec<_> =
+                embedders.iter().map(|config| format!("`{}`", config.name)).collect();
+            let _ = writeln!(
+                &mut embedders_description,
+                " - {name}: {embedders}",
+                name = name.uid(),
+                embedders = embedders.join(", ")
+            );
+            similar_index_uids.push(name.uid().to_string());
+        }
         let facet_distributions = format_facet_distributions(
             index_scheduler,
             index,
@@ -323,6 +356,76 @@ fn setup_search_tool(
         .unwrap();
 
     tools.push(tool);
+    tools.push(internal_tool(
+        MEILI_GET_DOCUMENT_FUNCTION_NAME,
+        "Fetch a document by its unique identifier, the value of the primary key of the index.",
+        json!({
+            "type": "object",
+            "properties": {
+                "index_uid": {
+                    "type": "string",
+                    "enum": index_uids,
+                    "description": "The name of the index to fetch the document from.",
+                },
+                "document_id": {
+                    "type": "string",
+                    "description": "The unique identifier of the document to fetch.",
+                }
+            },
+            "required": ["index_uid", "document_id"],
+            "additionalProperties": false,
+        }),
+    ));
+    tools.push(internal_tool(
+        MEILI_FACET_DISTRIBUTION_FUNCTION_NAME,
+        "Count the documents for each value of a filterable attribute, e.g. to list the available brands or categories.",
+        json!({
+            "type": "object",
+            "properties": {
+                "index_uid": {
+                    "type": "string",
+                    "enum": index_uids,
+                    "description": "The name of the index to compute the facet distribution in.",
+                },
+                "facet_name": {
+                    "type": "string",
+                    "description": "The name of the filterable attribute to compute the distribution of.",
+                },
+                "filter": {
+                    "type": "string",
+                    "description": filter_description,
+                }
+            },
+            "required": ["index_uid", "facet_name", "filter"],
+            "additionalProperties": false,
+        }),
+    ));
+    if !similar_index_uids.is_empty() {
+        tools.push(internal_tool(
+            MEILI_SIMILAR_DOCUMENTS_FUNCTION_NAME,
+            "Retrieve the documents that are semantically similar to a given document.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "index_uid": {
+                        "type": "string",
+                        "enum": similar_index_uids,
+                        "description": "The name of the index to retrieve the similar documents from.",
+                    },
+                    "document_id": {
+                        "type": "string",
+                        "description": "The unique identifier of the document to find similar documents for.",
+                    },
+                    "embedder": {
+                        "type": "string",
+                        "description": embedders_description,
+                    }
+                },
+                "required": ["index_uid", "document_id", "embedder"],
+                "additionalProperties": false,
+            }),
+        ));
+    }
 
     let system_message = match system_role {
         SystemRole::System => {
@@ -343,6 +446,23 @@ fn setup_search_tool(
     Ok(FunctionSupport { report_progress, report_sources, append_to_conversation })
 }
 
+/// Builds the definition of a function that Meilisearch answers itself.
+fn internal_tool(name: &str, description: &str, parameters: Value) -> ChatCompletionTool {
+    ChatCompletionToolArgs::default()
+        .r#type(ChatCompletionToolType::Function)
+        .function(
+            FunctionObjectArgs::default()
+                .name(name)
+                .description(description)
+                .parameters(parameters)
+                .strict(true)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap()
+}
+
 #[allow(clippy::too_many_arguments)]
 /// Process search request and return formatted results
 async fn process_search_request(
@@ -371,7 +491,9 @@ async fn process_search_request(
     )?;
 
     // Tenant token search_rules.
-    if let Some(search_rules) = auth_filter.get_index_search_rules(&query.index_uid) {
+    let search_rules = auth_filter.get_index_search_rules(&query.index_uid);
+    if let Some(search_rules) = search_rules.clone() {
+        check_attributes_authorized(&search_rules, query.filter.as_ref(), None, None)?;
         add_search_rules(&mut query.filter, search_rules);
     }
 
@@ -397,7 +519,15 @@ async fn process_search_request(
     let index = index_scheduler.user_index(&index_uid)?;
     let rtxn = index.static_read_txn()?;
     let ChatConfig { description: _, prompt: _, search_parameters } = index.chat_config(&rtxn)?;
-    let query = SearchQuery { q, filter: None, ..SearchQuery::from(search_parameters) };
+    let mut query = SearchQuery {
+        q,
+        filter: None,
+        tenant_token_attributes: search_rules
+            .as_ref()
+            .and_then(|rules| rules.attributes_to_retrieve.clone()),
+        ..SearchQuery::from(search_parameters)
+    };
+    query.restrict_attributes_to_search_on(&index, &rtxn)?;
 
     let search_kind =
         search_kind(&query, index_scheduler.get_ref(), index_uid.to_string(), &index)?;
@@ -444,40 +574,254 @@ async fn process_search_request(
         Ok((_rtxn, Err(error))) => return Ok((index, Vec::new(), error.to_string())),
         Err(err) => Err(err),
     };
-    let mut documents = Vec::new();
-    if let Ok((ref rtxn, ref search_result)) = output {
-        MEILISEARCH_CHAT_SEARCHES_TOTAL.with_label_values(&["internal"]).inc();
-        if search_result.degraded {
-            MEILISEARCH_DEGRADED_SEARCH_REQUESTS.inc();
-        }
-
-        let fields_ids_map = index.fields_ids_map(rtxn)?;
-        let displayed_fields = index.displayed_fields_ids(rtxn, &fields_ids_map)?;
-        for &document_id in &search_result.documents_ids {
-            let obkv = index.document(rtxn, document_id)?;
-            let document = match displayed_fields {
-                Some(ref fields) => obkv_to_json(fields, &fields_ids_map, obkv)?,
-                None => all_obkv_to_json(obkv, &fields_ids_map)?,
-            };
-            documents.push(document);
-        }
+    let (rtxn, search_result) = output?;
+    MEILISEARCH_CHAT_SEARCHES_TOTAL.with_label_values(&["internal"]).inc();
+    if search_result.degraded {
+        MEILISEARCH_DEGRADED_SEARCH_REQUESTS.inc();
     }
 
-    let (rtxn, search_result) = output?;
-    let render_alloc = Bump::new();
-    let formatted = format_documents(
-        &rtxn,
+    let (documents, text) = documents_and_text(
         &index,
+        &rtxn,
         &fields_ids_map,
-        &render_alloc,
         search_result.documents_ids,
+        search_rules.as_ref(),
     )?;
-    let text = formatted.join("\n");
     drop(rtxn);
 
     Ok((index, documents, text))
 }
 
+/// Returns the documents with their displayed fields, to report them as sources,
+/// and their rendering with the chat prompt of the index, to give them to the LLM.
+///
+/// When the search rules of a tenant token restrict the retrievable attributes, the documents
+/// only contain these attributes and are given as is to the LLM, since the chat prompt
+/// of the index could render any attribute.
+fn documents_and_text(
+    index: &Index,
+    rtxn: &RoTxn,
+    fields_ids_map: &FieldsIdsMap,
+    documents_ids: Vec<DocumentId>,
+    search_rules: Option<&IndexSearchRules>,
+) -> Result<(Vec<Document>, String), ResponseError> {
+    let displayed_fields = index.displayed_fields_ids(rtxn, fields_ids_map)?;
+    let retrievable = search_rules
+        .and_then(|rules| rules.restrict_attributes_to_retrieve(None))
+        .filter(|attributes| !attributes.contains("*"));
+    let mut documents = Vec::with_capacity(documents_ids.len());
+    for &document_id in &documents_ids {
+        let obkv = index.document(rtxn, document_id)?;
+        let mut document = match displayed_fields {
+            Some(ref fields) => obkv_to_json(fields, fields_ids_map, obkv)?,
+            None => all_obkv_to_json(obkv, fields_ids_map)?,
+        };
+        if let Some(retrievable) = &retrievable {
+            document = permissive_json_pointer::select_values(
+                document,
+                retrievable.iter().map(String::as_str),
+            );
+        }
+        documents.push(document);
+    }
+
+    if retrievable.is_some() {
+        let text = documents.iter().map(|document| Value::Object(document.clone()).to_string());
+        return Ok((documents, text.collect::<Vec<_>>().join("\n")));
+    }
+
+    let render_alloc = Bump::new();
+    let formatted = format_documents(rtxn, index, fields_ids_map, &render_alloc, documents_ids)?;
+    Ok((documents, formatted.join("\n")))
+}
+
+/// Returns the search rules of the index if the tenant token allows to search in it.
+fn index_search_rules(
+    auth_ctrl: web::Data<AuthController>,
+    auth_token: &str,
+    index_uid: &str,
+) -> Result<Option<IndexSearchRules>, ResponseError> {
+    let auth_filter =
+        ActionPolicy::<{ actions::SEARCH }>::authenticate(auth_ctrl, auth_token, Some(index_uid))?;
+    Ok(auth_filter.get_index_search_rules(index_uid))
+}
+
+/// Fetch a document by its external id, if the tenant token gives access to it
+async fn process_get_document_request(
+    index_scheduler: &Data<IndexScheduler>,
+    features: RoFeatures,
+    auth_ctrl: web::Data<AuthController>,
+    auth_token: &str,
+    GetDocumentParameters { index_uid, document_id }: GetDocumentParameters,
+) -> Result<(Index, Vec<Document>, String), ResponseError> {
+    tracing::debug!("LLM document fetch: {index_uid} {document_id}");
+    let search_rules = index_search_rules(auth_ctrl, auth_token, &index_uid)?;
+    let index = index_scheduler.user_index(&index_uid)?;
+
+    tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let rtxn = index.read_txn()?;
+        let fields_ids_map = index.fields_ids_map(&rtxn)?;
+        let universe = authorized_universe(
+            features,
+            &index,
+            &rtxn,
+            &fields_ids_map,
+            search_rules.clone(),
+            &index_uid,
+        )?;
+        let docid = index
+            .external_documents_ids()
+            .get(&rtxn, &document_id)?
+            .filter(|docid| universe.contains(*docid));
+
+        let Some(docid) = docid else {
+            let text = format!("Document `{document_id}` not found in the `{index_uid}` index.");
+            drop(rtxn);
+            return Ok((index, Vec::new(), text));
+        };
+        let (documents, text) =
+            documents_and_text(&index, &rtxn, &fields_ids_map, vec![docid], search_rules.as_ref())?;
+        drop(rtxn);
+        Ok((index, documents, text))
+    })
+    .await?
+}
+
+/// Compute the distribution of the values of a facet among the documents the tenant token gives access to
+async fn process_facet_distribution_request(
+    index_scheduler: &Data<IndexScheduler>,
+    features: RoFeatures,
+    auth_ctrl: web::Data<AuthController>,
+    auth_token: &str,
+    FacetDistributionParameters { index_uid, facet_name, filter }: FacetDistributionParameters,
+) -> Result<(Index, Vec<Document>, String), ResponseError> {
+    tracing::debug!("LLM facet distribution: {index_uid} {facet_name} {filter:?}");
+    let search_rules = index_search_rules(auth_ctrl, auth_token, &index_uid)?;
+    let index = index_scheduler.user_index(&index_uid)?;
+    if let Some(search_rules) = &search_rules {
+        if !search_rules.is_facet_authorized(&facet_name) {
+            let text = format!(
+                "The tenant token does not allow requesting the values of the `{facet_name}` facet."
+            );
+            return Ok((index, Vec::new(), text));
+        }
+        check_attributes_authorized(search_rules, filter.as_ref(), None, None)?;
+    }
+
+    tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let from_milli = |err| MeilisearchHttpError::from_milli(err, Some(index_uid.to_string()));
+        let rtxn = index.read_txn()?;
+        let fields_ids_map = index.fields_ids_map(&rtxn)?;
+        let mut universe = authorized_universe(
+            features,
+            &index,
+            &rtxn,
+            &fields_ids_map,
+            search_rules,
+            &index_uid,
+        )?;
+        let filter = match filter {
+            Some(filter) => parse_local_index_filter(
+                &filter,
+                Some(index_uid.as_str()),
+                features,
+                Code::InvalidSearchFilter,
+            )?,
+            None => None,
+        };
+        if let Some(filter) = filter {
+            universe &= filter.evaluate(&rtxn, &index, &fields_ids_map).map_err(from_milli)?;
+        }
+
+        let max_values_per_facet = index
+            .max_values_per_facet(&rtxn)?
+            .map(|x| x as usize)
+            .unwrap_or(DEFAULT_VALUES_PER_FACET);
+        let distribution = index
+            .facets_distribution(&rtxn, &fields_ids_map)
+            .max_values_per_facet(max_values_per_facet)
+            .candidates(universe)
+            .facets([(facet_name.as_str(), OrderBy::Count)])
+            .execute();
+        let distribution = match distribution {
+            Ok(distribution) => distribution,
+            Err(meilisearch_types::milli::Error::UserError(error)) => {
+                drop(rtxn);
+                return Ok((index, Vec::new(), error.to_string()));
+            }
+            Err(error) => return Err(from_milli(error).into()),
+        };
+        drop(rtxn);
+
+        let mut text = String::new();
+        for (facet_name, values) in distribution {
+            let _ = writeln!(&mut text, "{facet_name}:");
+            for (value, count) in values {
+                let _ = writeln!(&mut text, " - {value}: {count}");
+            }
+        }
+        Ok((index, Vec::new(), text))
+    })
+    .await?
+}
+
+/// Retrieve the documents similar to a given one, among the documents the tenant token gives access to
+async fn process_similar_documents_request(
+    index_scheduler: &Data<IndexScheduler>,
+    auth_ctrl: web::Data<AuthController>,
+    auth_token: &str,
+    SimilarDocumentsParameters { index_uid, document_id, embedder }: SimilarDocumentsParameters,
+) -> Result<(Index, Vec<Document>, String), ResponseError> {
+    tracing::debug!("LLM similar documents: {index_uid} {document_id} {embedder}");
+    let search_rules = index_search_rules(auth_ctrl, auth_token, &index_uid)?;
+    let index = index_scheduler.user_index(&index_uid)?;
+
+    let mut query = SimilarQuery {
+        id: Value::String(document_id),
+        offset: DEFAULT_SEARCH_OFFSET(),
+        limit: DEFAULT_SEARCH_LIMIT(),
+        filter: None,
+        embedder,
+        attributes_to_retrieve: None,
+        retrieve_vectors: false,
+        show_ranking_score: false,
+        show_ranking_score_details: false,
+        show_performance_details: false,
+        ranking_score_threshold: None,
+    };
+    if let Some(search_rules) = search_rules.clone() {
+        query.add_search_rules(search_rules)?;
+    }
+
+    let index_scheduler = index_scheduler.clone();
+    tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
+        let progress = Progress::default();
+        // The documents are rendered with the chat prompt of the index from their internal ids,
+        // the hits only contain the displayed fields.
+        let (_, documents_ids) = perform_similar_with_documents_ids(
+            &index_scheduler,
+            index_uid,
+            query,
+            &progress,
+            search_rules.clone(),
+        )?;
+
+        let rtxn = index.read_txn()?;
+        let fields_ids_map = index.fields_ids_map(&rtxn)?;
+
+        let (documents, text) = documents_and_text(
+            &index,
+            &rtxn,
+            &fields_ids_map,
+            documents_ids,
+            search_rules.as_ref(),
+        )?;
+        drop(rtxn);
+        Ok((index, documents, text))
+    })
+    .await?
+}
+
 #[allow(unreachable_code, unused_variables)] // will be correctly implemented in the future
 async fn non_streamed_chat(
     index_scheduler: GuardedData<ActionPolicy<{ actions::CHAT_COMPLETIONS }>, Data<IndexScheduler>>,
@@ -546,7 +890,7 @@ async fn non_streamed_chat(
 
                 let (meili_calls, other_calls): (Vec<_>, Vec<_>) = tool_calls
                     .into_iter()
-                    .partition(|call| call.function.name == MEILI_SEARCH_IN_INDEX_FUNCTION_NAME);
+                    .partition(|call| is_internal_function(&call.function.name));
 
                 chat_completion.messages.push(
                     ChatCompletionRequestAssistantMessageArgs::default()
@@ -557,18 +901,18 @@ async fn non_streamed_chat(
                 );
 
                 for call in meili_calls {
-                    let result = match serde_json::from_str(&call.function.arguments) {
-                        Ok(query) => process_search_request(
-                            &index_scheduler,
-                            features,
-                            auth_ctrl.clone(),
-                            &search_queue,
-                            auth_token,
-                            start_time,
-                            query,
-                        )
-                        .await
-                        .map_err(|e| e.to_string()),
+                    let result = match process_tool_call(
+                        &index_scheduler,
+                        features,
+                        auth_ctrl.clone(),
+                        &search_queue,
+                        auth_token,
+                        start_time,
+                        &call,
+                    )
+                    .await
+                    {
+                        Ok(result) => result.map_err(|e| e.to_string()),
                         Err(err) => Err(err.to_string()),
                     };
 
@@ -650,6 +994,42 @@ async fn streamed_chat(
     );
     let start_time = time::OffsetDateTime::now_utc();
 
+    // Continue the conversation stored on the server, the client only sends the new messages
+    let conversation = match conversation_id_from_request(&req)? {
+        Some(conversation_id) => {
+            index_scheduler.delete_expired_chat_conversations(
+                workspace_uid,
+                &chat_settings.conversations,
+                start_time,
+            )?;
+            let requester = filters.requester();
+            let conversation =
+                match index_scheduler.chat_conversation(workspace_uid, &conversation_id)? {
+                    Some(conversation) if conversation.is_accessible_by(requester.as_deref()) => {
+                        conversation
+                    }
+                    // the conversations of the other API keys and tenant tokens can't be continued
+                    Some(_) => return Err(conversation_not_found(workspace_uid, &conversation_id)),
+                    None => ChatConversation::new(conversation_id, requester, start_time),
+                };
+            let history = conversation
+                .messages
+                .iter()
+                .cloned()
+                .map(serde_json::from_value)
+                .collect::<Result<Vec<ChatCompletionRequestMessage>, _>>()
+                .map_err(|e| {
+                    ResponseError::from_msg(
+                        format!("Could not read the conversation `{}`: {e}", conversation.id),
+                        Code::Internal,
+                    )
+                })?;
+            chat_completion.messages.splice(0..0, history);
+            Some(conversation)
+        }
+        None => None,
+    };
+
     let config = Config::new(&chat_settings);
     let auth_token = extract_token_from_request(&req)?.unwrap().to_string();
     let system_role = chat_settings.source.system_role(&chat_completion.model);
@@ -669,9 +1049,11 @@ async fn streamed_chat(
     let _join_handle = Handle::current().spawn(async move {
         let client = Client::with_config(index_scheduler.ip_policy().clone(), config.clone());
         let mut global_tool_calls = HashMap::<u32, Call>::new();
+        let mut answer = String::new();
 
         // Limit the number of internal calls to satisfy the search requests of the LLM
         for _ in 0..20 {
+            answer.clear();
             let output = run_conversation(
                 &index_scheduler,
                 &auth_ctrl,
@@ -683,6 +1065,7 @@ async fn streamed_chat(
                 &mut chat_completion,
                 &tx,
                 &mut global_tool_calls,
+                &mut answer,
                 function_support,
                 start_time,
             );
@@ -695,6 +1078,30 @@ async fn streamed_chat(
             }
         }
 
+        if let Some(conversation) = conversation {
+            if !answer.is_empty() {
+                chat_completion.messages.push(
+                    ChatCompletionRequestAssistantMessageArgs::default()
+                        .content(answer)
+                        .build()
+                        .unwrap()
+                        .into(),
+                );
+            }
+            let max_messages = chat_settings.conversations.max_messages;
+            if let Err(e) = save_conversation(
+                &index_scheduler,
+                &workspace_uid,
+                conversation,
+                &chat_completion.messages,
+                max_messages,
+            ) {
+                tracing::error!(
+                    "Could not save the conversation of the `{workspace_uid}` chat: {e}"
+                );
+            }
+        }
+
         let _ = tx.stop().await;
     });
 
@@ -705,8 +1112,31 @@ async fn streamed_chat(
     Ok(sse_chat_response(rx))
 }
 
+/// Stores the messages of the chat completion in the conversation, without the system prompt.
+fn save_conversation(
+    index_scheduler: &IndexScheduler,
+    workspace_uid: &str,
+    mut conversation: ChatConversation,
+    messages: &[ChatCompletionRequestMessage],
+    max_messages: usize,
+) -> Result<(), ResponseError> {
+    // The system prompt is always the first message, see `setup_search_tool`
+    conversation.messages = messages
+        .iter()
+        .skip(1)
+        .map(serde_json::to_value)
+        .collect::<Result<_, _>>()
+        .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))?;
+    conversation.updated_at = time::OffsetDateTime::now_utc();
+    conversation.truncate(max_messages);
+    index_scheduler.put_chat_conversation(workspace_uid, &conversation)?;
+    Ok(())
+}
+
 /// Updates the chat completion with the new messages, streams the LLM tokens,
 /// and report progress and errors.
+///
+/// The content generated by the LLM is appended to `answer`.
 #[allow(clippy::too_many_arguments)]
 async fn run_conversation<C: async_openai::config::Config>(
     index_scheduler: &GuardedData<
@@ -722,6 +1152,7 @@ async fn run_conversation<C: async_openai::config::Config>(
     chat_completion: &mut CreateChatCompletionRequest,
     tx: &SseEventSender,
     global_tool_calls: &mut HashMap<u32, Call>,
+    answer: &mut String,
     function_support: FunctionSupport,
     start_time: time::OffsetDateTime,
 ) -> Result<ControlFlow<Option<FinishReason>, ()>, SendError<Event>> {
@@ -755,7 +1186,11 @@ async fn run_conversation<C: async_openai::config::Config>(
                 };
                 finish_reason = choice.finish_reason;
 
-                let ChatCompletionStreamResponseDelta { ref tool_calls, .. } = &choice.delta;
+                let ChatCompletionStreamResponseDelta { ref content, ref tool_calls, .. } =
+                    &choice.delta;
+                if let Some(content) = content {
+                    answer.push_str(content);
+                }
 
                 match tool_calls {
                     Some(tool_calls) => {
@@ -776,8 +1211,7 @@ async fn run_conversation<C: async_openai::config::Config>(
                                     }
                                 })
                                 .or_insert_with(|| {
-                                    if name.as_deref() == Some(MEILI_SEARCH_IN_INDEX_FUNCTION_NAME)
-                                    {
+                                    if name.as_deref().is_some_and(is_internal_function) {
                                         Call::Internal {
                                             id: id.as_ref().unwrap().clone(),
                                             function_name: name.as_ref().unwrap().clone(),
@@ -804,9 +1238,7 @@ async fn run_conversation<C: async_openai::config::Config>(
                                         }
                                         Call::External => None,
                                     })
-                                    .partition(|call| {
-                                        call.function.name == MEILI_SEARCH_IN_INDEX_FUNCTION_NAME
-                                    });
+                                    .partition(|call| is_internal_function(&call.function.name));
 
                             chat_completion.messages.push(
                                 ChatCompletionRequestAssistantMessageArgs::default()
@@ -897,25 +1329,23 @@ async fn handle_meili_tools(
 
         let mut error = None;
 
-        let result = match serde_json::from_str(&call.function.arguments) {
-            Ok(query) => match process_search_request(
-                index_scheduler,
-                features,
-                auth_ctrl.clone(),
-                search_queue,
-                auth_token,
-                start_time,
-                query,
-            )
-            .await
-            {
-                Ok(output) => Ok(output),
-                Err(err) => {
-                    let error_text = format!("the search tool call failed with {err}");
-                    error = Some(err);
-                    Err(error_text)
-                }
-            },
+        let result = match process_tool_call(
+            index_scheduler,
+            features,
+            auth_ctrl.clone(),
+            search_queue,
+            auth_token,
+            start_time,
+            &call,
+        )
+        .await
+        {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(err)) => {
+                let error_text = format!("the {} tool call failed with {err}", call.function.name);
+                error = Some(err);
+                Err(error_text)
+            }
             Err(err) => Err(err.to_string()),
         };
 
@@ -948,11 +1378,77 @@ async fn handle_meili_tools(
     Ok(())
 }
 
+/// Answers a call to one of the internal functions.
+///
+/// Returns an error if the arguments provided by the LLM cannot be deserialized.
+#[allow(clippy::too_many_arguments)]
+async fn process_tool_call(
+    index_scheduler: &GuardedData<
+        ActionPolicy<{ actions::CHAT_COMPLETIONS }>,
+        Data<IndexScheduler>,
+    >,
+    features: RoFeatures,
+    auth_ctrl: web::Data<AuthController>,
+    search_queue: &web::Data<SearchQueue>,
+    auth_token: &str,
+    start_time: time::OffsetDateTime,
+    call: &ChatCompletionMessageToolCall,
+) -> Result<Result<(Index, Vec<Document>, String), ResponseError>, serde_json::Error> {
+    let arguments = call.function.arguments.as_str();
+    let output = match call.function.name.as_str() {
+        MEILI_SEARCH_IN_INDEX_FUNCTION_NAME => {
+            let query = serde_json::from_str(arguments)?;
+            process_search_request(
+                index_scheduler,
+                features,
+                auth_ctrl,
+                search_queue,
+                auth_token,
+                start_time,
+                query,
+            )
+            .await
+        }
+        MEILI_GET_DOCUMENT_FUNCTION_NAME => {
+            let parameters = serde_json::from_str(arguments)?;
+            process_get_document_request(
+                index_scheduler,
+                features,
+                auth_ctrl,
+                auth_token,
+                parameters,
+            )
+            .await
+        }
+        MEILI_FACET_DISTRIBUTION_FUNCTION_NAME => {
+            let parameters = serde_json::from_str(arguments)?;
+            process_facet_distribution_request(
+                index_scheduler,
+                features,
+                auth_ctrl,
+                auth_token,
+                parameters,
+            )
+            .await
+        }
+        MEILI_SIMILAR_DOCUMENTS_FUNCTION_NAME => {
+            let parameters = serde_json::from_str(arguments)?;
+            process_similar_documents_request(index_scheduler, auth_ctrl, auth_token, parameters)
+                .await
+        }
+        name => Err(ResponseError::from_msg(
+            format!("{name}: unknown internal function."),
+            Code::Internal,
+        )),
+    };
+    Ok(output)
+}
+
 /// The structure used to aggregate the function calls to make.
 #[derive(Debug)]
 enum Call {
     /// Tool calls to tools that must be managed by Meilisearch internally.
-    /// Typically the search functions.
+    /// Typically the search, document, facet distribution and similar documents functions.
     Internal { id: String, function_name: String, arguments: String },
     /// Tool calls that we track but only to know that its not our functions.
     /// We return the function calls as-is to the end-user.
@@ -995,6 +1491,34 @@ impl PreprocessableQuery for SearchInIndexParameters {
     }
 }
 
+#[derive(Deserialize, Debug)]
+struct GetDocumentParameters {
+    /// The index uid to fetch the document from.
+    index_uid: IndexUid,
+    /// The primary key value of the document.
+    document_id: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct FacetDistributionParameters {
+    /// The index uid to compute the facet distribution in.
+    index_uid: IndexUid,
+    /// The filterable attribute to compute the distribution of.
+    facet_name: String,
+    /// The filter parameter to use.
+    filter: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct SimilarDocumentsParameters {
+    /// The index uid to retrieve the similar documents from.
+    index_uid: IndexUid,
+    /// The primary key value of the target document.
+    document_id: String,
+    /// The name of the embedder to use.
+    embedder: String,
+}
+
 #[allow(clippy::too_many_arguments)]
 fn format_facet_distributions(
     index_scheduler: &IndexScheduler,
@@ -1007,29 +1531,17 @@ fn format_facet_distributions(
 ) -> index_scheduler::Result<String> {
     let features = index_scheduler.features();
     let from_milli = |err| index_scheduler::Error::from_milli(err, Some(index_uid.to_string()));
-    let universe = 'filter: {
-        let Some(search_rules) = search_rules else { break 'filter index.documents_ids(rtxn)? };
-        let Some(filter) = search_rules.filter else {
-            break 'filter index.documents_ids(rtxn)?;
-        };
-
-        // TODO: should we support foreign filters from the search rules?
-        let Some(filter) = parse_local_index_filter(
-            &filter,
-            Some(index_uid),
-            features,
-            Code::InvalidSearchFilter,
-        )?
-        else {
-            break 'filter index.documents_ids(rtxn)?;
-        };
-        filter.evaluate(rtxn, index, fields_ids_map).map_err(from_milli)?
-    };
+    let is_facet_authorized =
+        |name: &str| search_rules.as_ref().is_none_or(|rules| rules.is_facet_authorized(name));
     let rules = index.filterable_attributes_rules(rtxn)?;
-    let filterable_attributes = fields_ids_map
+    let filterable_attributes: Vec<_> = fields_ids_map
         .names()
         .filter(|name| rules.iter().any(|rule| matches!(rule.match_str(name), PatternMatch::Match)))
-        .map(|name| (name, OrderBy::Count));
+        .filter(|name| is_facet_authorized(name))
+        .map(|name| (name, OrderBy::Count))
+        .collect();
+    let universe =
+        authorized_universe(features, index, rtxn, fields_ids_map, search_rules, index_uid)?;
     let facets_distribution = index
         .facets_distribution(rtxn, fields_ids_map)
         .max_values_per_facet(max_values_per_facet)
@@ -1054,3 +1566,28 @@ fn format_facet_distributions(
 
     Ok(output)
 }
+
+/// Returns the documents of the index that the search rules of the tenant token give access to.
+fn authorized_universe(
+    features: RoFeatures,
+    index: &Index,
+    rtxn: &RoTxn,
+    fields_ids_map: &FieldsIdsMap,
+    search_rules: Option<IndexSearchRules>,
+    index_uid: &str,
+) -> index_scheduler::Result<RoaringBitmap> {
+    let Some(search_rules) = search_rules else { return Ok(index.documents_ids(rtxn)?) };
+    let Some(filter) = search_rules.filter else {
+        return Ok(index.documents_ids(rtxn)?);
+    };
+
+    // TODO: should we support foreign filters from the search rules?
+    let Some(filter) =
+        parse_local_index_filter(&filter, Some(index_uid), features, Code::InvalidSearchFilter)?
+    else {
+        return Ok(index.documents_ids(rtxn)?);
+    };
+    filter
+        .evaluate(rtxn, index, fields_ids_map)
+        .map_err(|err| index_scheduler::Error::from_milli(err, Some(index_uid.to_string())))
+}
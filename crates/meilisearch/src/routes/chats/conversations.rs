@@ -0,0 +1,337 @@
+use actix_web::web::{self, Data};
+use actix_web::{HttpRequest, HttpResponse};
+use deserr::actix_web::AwebQueryParameter;
+use deserr::Deserr;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::deserr::query_params::Param;
+use meilisearch_types::deserr::DeserrQueryParamError;
+use meilisearch_types::error::deserr_codes::{InvalidIndexLimit, InvalidIndexOffset};
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::features::{ChatCompletionSettings, ChatConversation};
+use meilisearch_types::keys::actions;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::OffsetDateTime;
+use tracing::debug;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::extractors::authentication::policies::ActionPolicy;
+use crate::extractors::authentication::GuardedData;
+use crate::routes::{Pagination, PAGINATION_DEFAULT_LIMIT};
+
+/// The header used to continue a conversation stored on the server.
+pub const CONVERSATION_ID_HEADER: &str = "Meili-Conversation-Id";
+
+/// The maximum length of a conversation id, in bytes.
+const MAX_CONVERSATION_ID_LENGTH: usize = 400;
+
+#[derive(Deserialize)]
+pub struct ConversationParam {
+    workspace_uid: String,
+    conversation_id: String,
+}
+
+/// Returns the id of the conversation to continue, as sent in the `Meili-Conversation-Id` header.
+///
+/// A conversation id can be of any length between 1 and 400 bytes and can only contain
+/// alphanumeric characters, hyphens (-) and underscores (_). The index scheduler also rejects the
+/// ids that are too long to be stored along with the uid of their chat.
+pub fn conversation_id_from_request(req: &HttpRequest) -> Result<Option<String>, ResponseError> {
+    let Some(header) = req.headers().get(CONVERSATION_ID_HEADER) else { return Ok(None) };
+    let invalid = |id: &str| {
+        ResponseError::from_msg(
+            format!(
+                "`{id}` is not a valid conversation id. Conversation ids can be of any length between 1 and {MAX_CONVERSATION_ID_LENGTH} bytes and can only contain alphanumeric characters, hyphens (-) and underscores (_)."
+            ),
+            Code::InvalidChatConversationId,
+        )
+    };
+    let id = header.to_str().map_err(|_| invalid(&String::from_utf8_lossy(header.as_bytes())))?;
+    if id.is_empty()
+        || id.len() > MAX_CONVERSATION_ID_LENGTH
+        || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(invalid(id));
+    }
+    Ok(Some(id.to_string()))
+}
+
+fn workspace_settings(
+    index_scheduler: &IndexScheduler,
+    workspace_uid: &str,
+) -> Result<ChatCompletionSettings, ResponseError> {
+    match index_scheduler.chat_settings(workspace_uid)? {
+        Some(settings) => Ok(settings),
+        None => Err(ResponseError::from_msg(
+            format!("Chat `{workspace_uid}` not found"),
+            Code::ChatNotFound,
+        )),
+    }
+}
+
+pub fn conversation_not_found(workspace_uid: &str, conversation_id: &str) -> ResponseError {
+    ResponseError::from_msg(
+        format!("Conversation `{conversation_id}` not found in chat `{workspace_uid}`"),
+        Code::ChatConversationNotFound,
+    )
+}
+
+#[derive(Deserr, Debug, Clone, Copy, IntoParams)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+#[into_params(rename_all = "camelCase", parameter_in = Query)]
+pub struct ListConversations {
+    /// The number of conversations to skip before starting to retrieve anything
+    #[param(required = false, value_type = Option<usize>, default, example = 100)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidIndexOffset>)]
+    pub offset: Param<usize>,
+    /// The number of conversations to retrieve
+    #[param(required = false, value_type = Option<usize>, default = 20, example = 1)]
+    #[deserr(default = Param(PAGINATION_DEFAULT_LIMIT), error = DeserrQueryParamError<InvalidIndexLimit>)]
+    pub limit: Param<usize>,
+}
+
+impl ListConversations {
+    fn as_pagination(self) -> Pagination {
+        Pagination { offset: self.offset.0, limit: self.limit.0 }
+    }
+}
+
+/// A conversation stored on the server, without its messages
+#[derive(Debug, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatConversationSummaryView {
+    /// Unique identifier of the conversation in the chat workspace
+    pub id: String,
+    /// Date of the first message of the conversation
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String)]
+    pub created_at: OffsetDateTime,
+    /// Date of the last message of the conversation
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String)]
+    pub updated_at: OffsetDateTime,
+    /// Number of messages stored in the conversation
+    pub message_count: usize,
+}
+
+/// A conversation stored on the server
+#[derive(Debug, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatConversationView {
+    /// Unique identifier of the conversation in the chat workspace
+    pub id: String,
+    /// Date of the first message of the conversation
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String)]
+    pub created_at: OffsetDateTime,
+    /// Date of the last message of the conversation
+    #[serde(with = "time::serde::rfc3339")]
+    #[schema(value_type = String)]
+    pub updated_at: OffsetDateTime,
+    /// The messages of the conversation in the OpenAI format, without the system prompt
+    pub messages: Vec<Value>,
+}
+
+impl From<ChatConversation> for ChatConversationSummaryView {
+    fn from(conversation: ChatConversation) -> Self {
+        let ChatConversation { id, created_at, updated_at, messages, owner: _ } = conversation;
+        Self { id, created_at, updated_at, message_count: messages.len() }
+    }
+}
+
+impl From<ChatConversation> for ChatConversationView {
+    fn from(conversation: ChatConversation) -> Self {
+        let ChatConversation { id, created_at, updated_at, messages, owner: _ } = conversation;
+        Self { id, created_at, updated_at, messages }
+    }
+}
+
+/// List the conversations of a chat workspace
+///
+/// List the conversations stored on the server for a chat workspace, with pagination.
+/// The conversations that outlived the TTL of the workspace are not returned.
+///
+/// This route is only available when the `chatCompletions` [experimental feature](https://www.meilisearch.com/docs/resources/help/experimental_features_overview) is enabled.
+#[routes::path(
+    security(("Bearer" = ["chats.get", "*"])),
+    params(
+        ("workspace_uid" = String, Path, example = "my-workspace", description = "The unique identifier of the chat workspace.", nullable = false),
+        ListConversations,
+    ),
+    responses(
+        (status = 404, description = "Chat not found.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+              "message": "Chat :workspaceUid not found.",
+              "code": "chat_not_found",
+              "type": "invalid_request",
+              "link": "https://docs.meilisearch.com/errors#chat_not_found"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+        (status = 200, description = "Conversations retrieved.", content_type = "application/json", example = json!(
+            {
+                "results": [
+                    {
+                        "id": "my-conversation",
+                        "createdAt": "2025-01-01T00:00:00Z",
+                        "updatedAt": "2025-01-01T00:05:00Z",
+                        "messageCount": 6
+                    }
+                ],
+                "offset": 0,
+                "limit": 20,
+                "total": 1
+            }
+        )),
+    ),
+)]
+pub async fn list_conversations(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::CHATS_GET }>, Data<IndexScheduler>>,
+    workspace_uid: web::Path<String>,
+    paginate: AwebQueryParameter<ListConversations, DeserrQueryParamError>,
+) -> Result<HttpResponse, ResponseError> {
+    index_scheduler.features().check_chat_completions("listing the conversations of a chat")?;
+
+    let workspace_uid = workspace_uid.into_inner();
+    debug!(parameters = ?paginate, "List chat conversations");
+    let settings = workspace_settings(&index_scheduler, &workspace_uid)?;
+    index_scheduler.delete_expired_chat_conversations(
+        &workspace_uid,
+        &settings.conversations,
+        OffsetDateTime::now_utc(),
+    )?;
+
+    let requester = index_scheduler.filters().requester();
+    let (total, conversations) = index_scheduler.paginated_chat_conversations(
+        &workspace_uid,
+        requester.as_deref(),
+        *paginate.offset,
+        *paginate.limit,
+    )?;
+    let conversations =
+        conversations.into_iter().map(ChatConversationSummaryView::from).collect::<Vec<_>>();
+    let ret = paginate.as_pagination().format_with(total, conversations);
+
+    debug!(returns = ?ret, "List chat conversations");
+    Ok(HttpResponse::Ok().json(ret))
+}
+
+/// Get a conversation of a chat workspace
+///
+/// Get a conversation stored on the server, with all its messages.
+///
+/// This route is only available when the `chatCompletions` [experimental feature](https://www.meilisearch.com/docs/resources/help/experimental_features_overview) is enabled.
+#[routes::path(
+    security(("Bearer" = ["chats.get", "*"])),
+    params(
+        ("workspace_uid" = String, Path, example = "my-workspace", description = "The unique identifier of the chat workspace.", nullable = false),
+        ("conversation_id" = String, Path, example = "my-conversation", description = "The identifier of the conversation, as sent in the `Meili-Conversation-Id` header.", nullable = false),
+    ),
+    responses(
+        (status = 404, description = "Conversation not found.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+              "message": "Conversation `my-conversation` not found in chat `my-workspace`",
+              "code": "chat_conversation_not_found",
+              "type": "invalid_request",
+              "link": "https://docs.meilisearch.com/errors#chat_conversation_not_found"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+        (status = 200, description = "Conversation retrieved.", body = ChatConversationView, content_type = "application/json", example = json!(
+            {
+                "id": "my-conversation",
+                "createdAt": "2025-01-01T00:00:00Z",
+                "updatedAt": "2025-01-01T00:05:00Z",
+                "messages": [
+                    { "role": "user", "content": "What are the best Rust books?" },
+                    { "role": "assistant", "content": "Here are some great Rust books..." }
+                ]
+            }
+        )),
+    ),
+)]
+pub async fn get_conversation(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::CHATS_GET }>, Data<IndexScheduler>>,
+    param: web::Path<ConversationParam>,
+) -> Result<HttpResponse, ResponseError> {
+    index_scheduler.features().check_chat_completions("displaying a chat conversation")?;
+
+    let ConversationParam { workspace_uid, conversation_id } = param.into_inner();
+    let settings = workspace_settings(&index_scheduler, &workspace_uid)?;
+    let requester = index_scheduler.filters().requester();
+    let conversation = index_scheduler
+        .chat_conversation(&workspace_uid, &conversation_id)?
+        .filter(|conversation| {
+            conversation.is_accessible_by(requester.as_deref())
+                && !conversation.is_expired(&settings.conversations, OffsetDateTime::now_utc())
+        })
+        .ok_or_else(|| conversation_not_found(&workspace_uid, &conversation_id))?;
+
+    Ok(HttpResponse::Ok().json(ChatConversationView::from(conversation)))
+}
+
+/// Delete a conversation of a chat workspace
+///
+/// Delete a conversation stored on the server. The next chat completion using
+/// the same conversation id starts a new conversation.
+///
+/// This route is only available when the `chatCompletions` [experimental feature](https://www.meilisearch.com/docs/resources/help/experimental_features_overview) is enabled.
+#[routes::path(
+    security(("Bearer" = ["chats.delete", "*"])),
+    params(
+        ("workspace_uid" = String, Path, example = "my-workspace", description = "The unique identifier of the chat workspace.", nullable = false),
+        ("conversation_id" = String, Path, example = "my-conversation", description = "The identifier of the conversation, as sent in the `Meili-Conversation-Id` header.", nullable = false),
+    ),
+    responses(
+        (status = 404, description = "Conversation not found.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+              "message": "Conversation `my-conversation` not found in chat `my-workspace`",
+              "code": "chat_conversation_not_found",
+              "type": "invalid_request",
+              "link": "https://docs.meilisearch.com/errors#chat_conversation_not_found"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+        (status = 204, description = "Conversation deleted."),
+    ),
+)]
+pub async fn delete_conversation(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::CHATS_DELETE }>, Data<IndexScheduler>>,
+    param: web::Path<ConversationParam>,
+) -> Result<HttpResponse, ResponseError> {
+    index_scheduler.features().check_chat_completions("deleting a chat conversation")?;
+
+    let ConversationParam { workspace_uid, conversation_id } = param.into_inner();
+    workspace_settings(&index_scheduler, &workspace_uid)?;
+    let requester = index_scheduler.filters().requester();
+    // the conversations of the other API keys and tenant tokens are not found
+    let accessible = index_scheduler
+        .chat_conversation(&workspace_uid, &conversation_id)?
+        .is_some_and(|conversation| conversation.is_accessible_by(requester.as_deref()));
+    if accessible && index_scheduler.delete_chat_conversation(&workspace_uid, &conversation_id)? {
+        Ok(HttpResponse::NoContent().finish())
+    } else {
+        Err(conversation_not_found(&workspace_uid, &conversation_id))
+    }
+}
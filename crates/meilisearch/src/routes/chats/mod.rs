@@ -22,6 +22,7 @@ use crate::routes::PAGINATION_DEFAULT_LIMIT;
 mod chat_completion_analytics;
 pub mod chat_completions;
 mod config;
+pub mod conversations;
 mod errors;
 pub mod settings;
 mod utils;
@@ -45,6 +46,26 @@ const MEILI_SEARCH_SOURCES_NAME: &str = "_meiliSearchSources";
 /// This function must not leak to the user as the LLM will call it and the
 /// main goal of Meilisearch is to provide an answer to these calls.
 const MEILI_SEARCH_IN_INDEX_FUNCTION_NAME: &str = "_meiliSearchInIndex";
+/// The *internal* function name to provide to the LLM to fetch a document
+/// by its primary key.
+const MEILI_GET_DOCUMENT_FUNCTION_NAME: &str = "_meiliGetDocument";
+/// The *internal* function name to provide to the LLM to compute the
+/// distribution of the values of a facet, e.g. "what brands do you have?".
+const MEILI_FACET_DISTRIBUTION_FUNCTION_NAME: &str = "_meiliFacetDistribution";
+/// The *internal* function name to provide to the LLM to retrieve the
+/// documents similar to a given document with one of the embedders of the index.
+const MEILI_SIMILAR_DOCUMENTS_FUNCTION_NAME: &str = "_meiliSimilarDocuments";
+/// All the *internal* functions that the LLM can call and that Meilisearch answers.
+const MEILI_INTERNAL_FUNCTION_NAMES: &[&str] = &[
+    MEILI_SEARCH_IN_INDEX_FUNCTION_NAME,
+    MEILI_GET_DOCUMENT_FUNCTION_NAME,
+    MEILI_FACET_DISTRIBUTION_FUNCTION_NAME,
+    MEILI_SIMILAR_DOCUMENTS_FUNCTION_NAME,
+];
+
+fn is_internal_function(name: &str) -> bool {
+    MEILI_INTERNAL_FUNCTION_NAMES.contains(&name)
+}
 
 #[routes::routes(
     tag = "Chats",
@@ -52,6 +73,8 @@ const MEILI_SEARCH_IN_INDEX_FUNCTION_NAME: &str = "_meiliSearchInIndex";
         "" => [get(list_workspaces)],
         "/{workspace_uid}/settings" => [get(settings::get_settings), patch(settings::patch_settings), delete(settings::reset_settings)],
         "/{workspace_uid}" => [get(get_chat), delete(delete_chat)],
+        "/{workspace_uid}/conversations" => [get(conversations::list_conversations)],
+        "/{workspace_uid}/conversations/{conversation_id}" => [get(conversations::get_conversation), delete(conversations::delete_conversation)],
         "/{workspace_uid}/chat/completions" => post(chat_completions::chat),
     ),
     tags((
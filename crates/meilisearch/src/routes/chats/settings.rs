@@ -7,9 +7,12 @@ use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::features::{
     ChatCompletionPrompts as DbChatCompletionPrompts, ChatCompletionSettings,
-    ChatCompletionSource as DbChatCompletionSource, DEFAULT_CHAT_SEARCH_DESCRIPTION_PROMPT,
-    DEFAULT_CHAT_SEARCH_FILTER_PARAM_PROMPT, DEFAULT_CHAT_SEARCH_INDEX_UID_PARAM_PROMPT,
-    DEFAULT_CHAT_SEARCH_Q_PARAM_PROMPT, DEFAULT_CHAT_SYSTEM_PROMPT,
+    ChatCompletionSource as DbChatCompletionSource,
+    ChatConversationsSettings as DbChatConversationsSettings,
+    DEFAULT_CHAT_CONVERSATION_MAX_MESSAGES, DEFAULT_CHAT_CONVERSATION_TTL_SECONDS,
+    DEFAULT_CHAT_SEARCH_DESCRIPTION_PROMPT, DEFAULT_CHAT_SEARCH_FILTER_PARAM_PROMPT,
+    DEFAULT_CHAT_SEARCH_INDEX_UID_PARAM_PROMPT, DEFAULT_CHAT_SEARCH_Q_PARAM_PROMPT,
+    DEFAULT_CHAT_SYSTEM_PROMPT,
 };
 use meilisearch_types::keys::actions;
 use meilisearch_types::milli::update::Setting;
@@ -178,6 +181,23 @@ pub async fn patch_settings(
         Setting::NotSet => old_settings.prompts,
     };
 
+    let conversations = match new.conversations {
+        Setting::Set(new_conversations) => DbChatConversationsSettings {
+            ttl_seconds: match new_conversations.ttl_seconds {
+                Setting::Set(new_ttl_seconds) => new_ttl_seconds,
+                Setting::Reset => DEFAULT_CHAT_CONVERSATION_TTL_SECONDS,
+                Setting::NotSet => old_settings.conversations.ttl_seconds,
+            },
+            max_messages: match new_conversations.max_messages {
+                Setting::Set(new_max_messages) => new_max_messages,
+                Setting::Reset => DEFAULT_CHAT_CONVERSATION_MAX_MESSAGES,
+                Setting::NotSet => old_settings.conversations.max_messages,
+            },
+        },
+        Setting::Reset => DbChatConversationsSettings::default(),
+        Setting::NotSet => old_settings.conversations,
+    };
+
     let mut settings = ChatCompletionSettings {
         source: match new.source {
             Setting::Set(new_source) => new_source.into(),
@@ -215,6 +235,7 @@ pub async fn patch_settings(
             Setting::NotSet => old_settings.api_key,
         },
         prompts,
+        conversations,
     };
 
     // TODO send analytics
@@ -326,6 +347,9 @@ pub struct ChatWorkspaceSettings {
     /// Custom prompts for chat completions
     #[request(default, inline, schema_type = Option<ChatPrompts>)]
     pub prompts: Setting<ChatPrompts>,
+    /// How the conversations stored on the server are kept
+    #[request(default, inline, schema_type = Option<ChatConversations>)]
+    pub conversations: Setting<ChatConversations>,
 }
 
 /// LLM provider for chat completions
@@ -374,3 +398,15 @@ pub struct ChatPrompts {
     #[request(default, schema_type = Option<String>, example = json!("This is index you want to search in..."), error = DeserrJsonError<InvalidChatCompletionSearchIndexUidParamPrompt>)]
     pub search_index_uid_param: Setting<String>,
 }
+
+/// How the conversations stored on the server are kept
+#[routes::request]
+#[derive(Debug, Clone)]
+pub struct ChatConversations {
+    /// Number of seconds after the last message after which a conversation is deleted
+    #[request(default, schema_type = Option<u64>, example = json!(604800), error = DeserrJsonError<InvalidChatCompletionConversationsTtlSeconds>)]
+    pub ttl_seconds: Setting<u64>,
+    /// Maximum number of messages kept in a conversation, the oldest ones are dropped first
+    #[request(default, schema_type = Option<usize>, example = json!(100), error = DeserrJsonError<InvalidChatCompletionConversationsMaxMessages>)]
+    pub max_messages: Setting<usize>,
+}
@@ -1,12 +1,20 @@
+use std::collections::BTreeMap;
+
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
 use index_scheduler::IndexScheduler;
 use meilisearch_auth::AuthController;
-use meilisearch_types::error::ResponseError;
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::deserr_codes::*;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::index_uid_pattern::IndexUidPattern;
+use meilisearch_types::tasks::{DumpSelection, KindWithContent};
 use tracing::debug;
 
 use crate::analytics::Analytics;
+use crate::error::PayloadError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::routes::SummarizedTaskView;
@@ -14,18 +22,23 @@ use crate::routes::SummarizedTaskView;
 #[routes::routes(
     routes(
         "" => post(create_dump),
+        "/import" => post(import_dump),
     ),
     tag = "Backups",
 )]
 pub struct DumpApi;
 
 crate::empty_analytics!(DumpAnalytics, "Dump Created");
+crate::empty_analytics!(DumpImportAnalytics, "Dump Imported");
 
 /// Create dump
 ///
 /// Trigger a dump creation process. When complete, a dump file is written to the [dump directory](https://www.meilisearch.com/docs/learn/self_hosted/configure_meilisearch_at_launch#dump-directory). The directory is created if it does not exist.
+///
+/// The request body is optional. It restricts the dump to some indexes and leaves the tasks, batches or API keys out of it.
+/// These choices are recorded in the `metadata.json` file of the dump.
 #[routes::path(
-    no_request_body,
+    request_body = CreateDump,
     security(("Bearer" = ["dumps.create", "dumps.*", "*"])),
     responses(
         (status = 202, description = "Dump is being created.", body = SummarizedTaskView, content_type = "application/json", example = json!(
@@ -50,14 +63,35 @@ crate::empty_analytics!(DumpAnalytics, "Dump Created");
 pub async fn create_dump(
     index_scheduler: GuardedData<ActionPolicy<{ actions::DUMPS_CREATE }>, Data<IndexScheduler>>,
     auth_controller: GuardedData<ActionPolicy<{ actions::DUMPS_CREATE }>, Data<AuthController>>,
+    body: web::Bytes,
     req: HttpRequest,
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
+    // the body is optional to keep accepting the requests sent without any payload
+    let create_dump = if body.iter().all(u8::is_ascii_whitespace) {
+        CreateDump::default()
+    } else {
+        let value: serde_json::Value =
+            serde_json::from_slice(&body).map_err(PayloadError::MalformedPayload)?;
+        deserr::deserialize::<Option<CreateDump>, _, DeserrJsonError>(value)?.unwrap_or_default()
+    };
+    debug!(parameters = ?create_dump, "Create dump");
+
     analytics.publish(DumpAnalytics::default(), &req);
 
+    let CreateDump { indexes, skip_tasks, skip_batches, skip_keys } = create_dump;
+    let selection = DumpSelection { indexes, skip_tasks, skip_batches, skip_keys };
+    let (keys, signing_keys) = if skip_keys {
+        (Vec::new(), Vec::new())
+    } else {
+        (auth_controller.list_keys()?, auth_controller.list_signing_keys()?)
+    };
+
     let task = KindWithContent::DumpCreation {
-        keys: auth_controller.list_keys()?,
+        keys,
+        signing_keys,
         instance_uid: analytics.instance_uid().cloned(),
+        selection,
     };
     let task: SummarizedTaskView =
         tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
@@ -65,3 +99,104 @@ pub async fn create_dump(
     debug!(returns = ?task, "Create dump");
     Ok(HttpResponse::Accepted().json(task))
 }
+
+/// Request body for creating a dump
+#[routes::request]
+#[derive(Debug, Default)]
+pub struct CreateDump {
+    /// Only dump the indexes matching one of these patterns. All the indexes are dumped when omitted or `null`.
+    #[request(default, error = DeserrJsonError<InvalidDumpIndexes>, schema_type = Option<Vec<String>>, example = json!(["products", "movies_*"]))]
+    pub indexes: Option<Vec<IndexUidPattern>>,
+    /// Leave the task queue out of the dump
+    #[request(default, error = DeserrJsonError<InvalidDumpSkipTasks>, example = json!(false))]
+    pub skip_tasks: bool,
+    /// Leave the batch queue out of the dump
+    #[request(default, error = DeserrJsonError<InvalidDumpSkipBatches>, example = json!(false))]
+    pub skip_batches: bool,
+    /// Leave the API keys out of the dump
+    #[request(default, error = DeserrJsonError<InvalidDumpSkipKeys>, example = json!(true))]
+    pub skip_keys: bool,
+}
+
+/// Import dump
+///
+/// Import the indexes of a dump of the [dump directory](https://www.meilisearch.com/docs/learn/self_hosted/configure_meilisearch_at_launch#dump-directory) into the running instance.
+///
+/// Only the indexes are imported: the tasks, API keys and instance settings of the dump are ignored.
+/// The import fails if one of the indexes already exists, unless it is renamed or `skipExistingIndexes` is set.
+/// The dump must already be in the dump directory, uploading a dump is not supported.
+/// If the import of an index fails, this index is deleted and the indexes imported before it are kept.
+#[routes::path(
+    request_body = ImportDump,
+    security(("Bearer" = ["dumps.import", "dumps.*", "*"])),
+    responses(
+        (status = 202, description = "Dump is being imported.", body = SummarizedTaskView, content_type = "application/json", example = json!(
+            {
+                "taskUid": 0,
+                "indexUid": null,
+                "status": "enqueued",
+                "type": "dumpImport",
+                "enqueuedAt": "2021-01-01T09:39:00.000000Z"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+pub async fn import_dump(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::DUMPS_IMPORT }>, Data<IndexScheduler>>,
+    params: AwebJson<ImportDump, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let import_dump = params.into_inner();
+    debug!(parameters = ?import_dump, "Import dump");
+    let ImportDump { dump_uid, index_renames, skip_existing_indexes } = import_dump;
+
+    // the uid is used to build the path of the dump file and must not escape the dump directory
+    if dump_uid.is_empty()
+        || !dump_uid.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(ResponseError::from_msg(
+            format!("`{dump_uid}` is not a valid dump uid. A dump uid can only be composed of alphanumeric characters, hyphens (-) and underscores (_)."),
+            Code::InvalidDumpImportDumpUid,
+        ));
+    }
+
+    analytics.publish(DumpImportAnalytics::default(), &req);
+
+    let task = KindWithContent::DumpImport {
+        dump_uid,
+        index_renames: index_renames
+            .into_iter()
+            .map(|(uid, new_uid)| (uid, new_uid.into_inner()))
+            .collect(),
+        skip_existing_indexes,
+    };
+    let task: SummarizedTaskView =
+        tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
+
+    debug!(returns = ?task, "Import dump");
+    Ok(HttpResponse::Accepted().json(task))
+}
+
+/// Request body for importing a dump
+#[routes::request]
+#[derive(Debug)]
+pub struct ImportDump {
+    /// The uid of the dump to import, as returned in the details of the `dumpCreation` task
+    #[request(required, error = DeserrJsonError<InvalidDumpImportDumpUid>, missing_field_error = DeserrJsonError::missing_dump_import_dump_uid, example = json!("20240101-120000000"))]
+    pub dump_uid: String,
+    /// The new uids of the indexes of the dump, indexed by their uid in the dump
+    #[request(default, error = DeserrJsonError<InvalidDumpImportIndexRenames>, schema_type = BTreeMap<String, String>, example = json!({ "products": "products_v2" }))]
+    pub index_renames: BTreeMap<String, IndexUid>,
+    /// Leave the indexes that already exist untouched instead of failing the import
+    #[request(default, error = DeserrJsonError<InvalidDumpImportSkipExistingIndexes>, example = json!(true))]
+    pub skip_existing_indexes: bool,
+}
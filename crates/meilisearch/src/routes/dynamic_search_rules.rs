@@ -23,7 +23,9 @@ use crate::extractors::authentication::policies::ActionPolicy;
 use crate::extractors::authentication::GuardedData;
 use crate::proxy::{proxy, task_network_and_check_leader_and_version, Body};
 use crate::routes::indexes::documents::CustomMetadataQuery;
-use crate::routes::{Pagination, PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT};
+use crate::routes::{
+    check_task_priority, Pagination, PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT,
+};
 
 #[routes::routes(
     routes(
@@ -193,6 +195,8 @@ async fn list_rules(
         degraded: _,
         used_negative_operator: _,
         query_vector: _,
+        applied_rules: _,
+        suggestions: _,
     } = dsrs.search_in_description_and_words(query, &rule_ids, limit, offset)?;
 
     let rules = dsrs
@@ -314,7 +318,8 @@ async fn update_or_create_rule(
         .check_dynamic_search_rules("Using the `/dynamic-search-rules` routes")?;
     let network = index_scheduler.network();
 
-    let CustomMetadataQuery { custom_metadata } = query.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = query.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
 
     let uid = uid.into_inner();
     let rule = body.into_inner();
@@ -325,7 +330,7 @@ async fn update_or_create_rule(
             rule_id: uid,
             update: rule.clone(),
         });
-        index_scheduler.register_with_custom_metadata(kind, custom_metadata, task_network)
+        index_scheduler.register_with_priority(kind, custom_metadata, priority, task_network)
     }?;
 
     if let Some(task_network) = task.network.take() {
@@ -381,13 +386,14 @@ async fn delete_rule(
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
 
-    let CustomMetadataQuery { custom_metadata } = query.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = query.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
 
     let uid = uid.into_inner();
 
     let mut task = {
         let kind = KindWithContent::DsrUpdate(DsrUpdate::Deletion(uid));
-        index_scheduler.register_with_custom_metadata(kind, custom_metadata, task_network)?
+        index_scheduler.register_with_priority(kind, custom_metadata, priority, task_network)?
     };
 
     if let Some(task_network) = task.network.take() {
@@ -440,11 +446,12 @@ async fn clear_rules(
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
 
-    let CustomMetadataQuery { custom_metadata } = query.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = query.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
 
     let mut task = {
         let kind = KindWithContent::DsrClear;
-        index_scheduler.register_with_custom_metadata(kind, custom_metadata, task_network)?
+        index_scheduler.register_with_priority(kind, custom_metadata, priority, task_network)?
     };
 
     if let Some(task_network) = task.network.take() {
@@ -34,6 +34,8 @@ pub struct ExportApi;
 /// Export to a remote Meilisearch
 ///
 /// Trigger an export that sends documents and settings from this instance to a remote Meilisearch server. Configure the remote URL and optional API key in the request body.
+///
+/// Exports are always enqueued with the `normal` priority: they are processed before the tasks of the indexes, whatever their priority.
 #[routes::path(
     request_body = Export,
     security(("Bearer" = ["export", "*"])),
@@ -87,6 +89,7 @@ async fn export(
         payload_size: payload_size.map(|ByteWithDeserr(bytes)| bytes),
         indexes,
     };
+    // The scheduler processes the exports before the tasks of the indexes, a priority is meaningless
     let task: SummarizedTaskView =
         tokio::task::spawn_blocking(move || index_scheduler.register(task)).await??.into();
 
@@ -90,7 +90,7 @@ pub struct RuntimeTogglableFeatures {
     /// Enable document editing via JavaScript functions
     #[request(default)]
     pub edit_documents_by_function: Option<bool>,
-    /// Enable the CONTAINS filter operator
+    /// Enable the CONTAINS, ENDS WITH and MATCHES filter operators
     #[request(default)]
     pub contains_filter: Option<bool>,
     /// Enable dynamic search rules and the `/dynamic-search-rules` routes
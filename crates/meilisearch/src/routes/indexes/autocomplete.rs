@@ -0,0 +1,231 @@
+use std::collections::BinaryHeap;
+
+use actix_web::web::{self, Data};
+use actix_web::{HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
+use index_scheduler::IndexScheduler;
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::ResponseError;
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::keys::actions;
+use meilisearch_types::milli::progress::Progress;
+use meilisearch_types::milli::TotalProcessingTimeStep;
+use tracing::debug;
+
+use super::ActionPolicy;
+use crate::analytics::{Aggregate, Analytics};
+use crate::extractors::authentication::GuardedData;
+use crate::search::{perform_autocomplete, AutocompleteQuery, AutocompleteResult};
+use crate::search_queue::SearchQueue;
+
+#[routes::routes(
+    routes(""=>post(autocomplete)),
+    tag = "Autocomplete",
+    tags(
+        (
+            name = "Autocomplete",
+            description = "The `/autocomplete` route completes the last word of a query with the words of the index, to build search-as-you-type interfaces.\n\nThe completions are read from the words databases of the index without ranking the documents, which makes them much cheaper than a full search on every keystroke.",
+        ),
+    ),
+)]
+pub struct AutocompleteApi;
+
+#[derive(Default)]
+pub struct AutocompleteAggregator {
+    // requests
+    total_received: usize,
+    total_succeeded: usize,
+    time_spent: BinaryHeap<usize>,
+
+    // limit
+    max_limit: usize,
+
+    // filter
+    filter_total_number_of_criteria: usize,
+
+    // attributes_to_search_on
+    attributes_to_search_on_total_number_of_uses: usize,
+
+    // locales
+    locales: bool,
+}
+
+impl AutocompleteAggregator {
+    pub fn from_query(query: &AutocompleteQuery) -> Self {
+        let AutocompleteQuery { q: _, limit, filter, attributes_to_search_on, locales } = query;
+
+        Self {
+            total_received: 1,
+            max_limit: *limit,
+            filter_total_number_of_criteria: filter.is_some() as usize,
+            attributes_to_search_on_total_number_of_uses: attributes_to_search_on.is_some()
+                as usize,
+            locales: locales.is_some(),
+            ..Default::default()
+        }
+    }
+
+    pub fn succeed(&mut self, result: &AutocompleteResult) {
+        let AutocompleteResult { completions: _, query: _, processing_time_ms } = result;
+        self.total_succeeded = 1;
+        self.time_spent.push(*processing_time_ms as usize);
+    }
+}
+
+impl Aggregate for AutocompleteAggregator {
+    fn event_name(&self) -> &'static str {
+        "Autocomplete POST"
+    }
+
+    fn aggregate(mut self: Box<Self>, new: Box<Self>) -> Box<Self> {
+        for time in new.time_spent {
+            self.time_spent.push(time);
+        }
+
+        Box::new(Self {
+            total_received: self.total_received.saturating_add(new.total_received),
+            total_succeeded: self.total_succeeded.saturating_add(new.total_succeeded),
+            time_spent: self.time_spent,
+            max_limit: self.max_limit.max(new.max_limit),
+            filter_total_number_of_criteria: self
+                .filter_total_number_of_criteria
+                .saturating_add(new.filter_total_number_of_criteria),
+            attributes_to_search_on_total_number_of_uses: self
+                .attributes_to_search_on_total_number_of_uses
+                .saturating_add(new.attributes_to_search_on_total_number_of_uses),
+            locales: self.locales | new.locales,
+        })
+    }
+
+    fn into_event(self: Box<Self>) -> serde_json::Value {
+        let Self {
+            total_received,
+            total_succeeded,
+            time_spent,
+            max_limit,
+            filter_total_number_of_criteria,
+            attributes_to_search_on_total_number_of_uses,
+            locales,
+        } = *self;
+        // the index of the 99th percentage of value
+        let percentile_99th = 0.99 * (total_succeeded as f64 - 1.) + 1.;
+        // we get all the values in a sorted manner
+        let time_spent = time_spent.into_sorted_vec();
+        // We are only interested by the slowest value of the 99th fastest results
+        let time_spent = time_spent.get(percentile_99th as usize);
+
+        serde_json::json!({
+            "requests": {
+                "99th_response_time":  time_spent.map(|t| format!("{:.2}", t)),
+                "total_succeeded": total_succeeded,
+                "total_failed": total_received.saturating_sub(total_succeeded), // just to be sure we never panics
+                "total_received": total_received,
+            },
+            "limit": {
+                "max_limit": max_limit,
+            },
+            "filter": {
+                "total_number_of_criteria": filter_total_number_of_criteria,
+            },
+            "attributes_to_search_on": {
+                "total_number_of_uses": attributes_to_search_on_total_number_of_uses,
+            },
+            "locales": locales,
+        })
+    }
+}
+
+/// Complete the last word of a query
+///
+/// Return the words of the index starting with the last word of `q`, sorted by the number of
+/// documents containing them. Use this to suggest completions while the user is typing,
+/// instead of sending a full search for every keystroke.
+///
+/// The previous words of `q`, the `filter` and the `attributesToSearchOn` restrict the documents
+/// considered when counting the completions. The documents are not ranked.
+///
+/// Like a search, the autocomplete stops at the `searchCutoffMs` of the index and returns the best
+/// completions found until then.
+#[routes::path(
+    security(("Bearer" = ["search", "*"])),
+    params(("index_uid" = String, Path, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+    request_body = AutocompleteQuery,
+    responses(
+        (status = 200, description = "The completions are returned.", body = AutocompleteResult, content_type = "application/json", example = json!(
+            {
+              "completions": [
+                {
+                  "value": "dragon",
+                  "count": 42
+                },
+                {
+                  "value": "dragonfly",
+                  "count": 3
+                }
+              ],
+              "query": "the drag",
+              "processingTimeMs": 0
+            }
+        )),
+        (status = 404, description = "Index not found.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "Index `movies` not found.",
+                "code": "index_not_found",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#index_not_found"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+pub async fn autocomplete(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SEARCH }>, Data<IndexScheduler>>,
+    search_queue: Data<SearchQueue>,
+    index_uid: web::Path<String>,
+    params: AwebJson<AutocompleteQuery, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: web::Data<Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let index_uid = IndexUid::try_from(index_uid.into_inner())?;
+
+    let mut query = params.into_inner();
+    debug!(parameters = ?query, "Autocomplete post");
+
+    // Tenant token search_rules.
+    if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+        query.add_search_rules(search_rules)?;
+    }
+
+    let mut aggregate = AutocompleteAggregator::from_query(&query);
+
+    let permit = search_queue.try_get_search_permit().await?;
+    let progress = Progress::default();
+    let progress_clone = progress.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        let _step = progress_clone.update_progress_scoped(TotalProcessingTimeStep::Autocomplete);
+
+        perform_autocomplete(&index_scheduler, index_uid, query, &progress_clone)
+    })
+    .await;
+    permit.drop().await;
+
+    debug!(progress = ?progress.accumulated_durations(), "Autocomplete");
+
+    let result = result?;
+    if let Ok(result) = &result {
+        aggregate.succeed(result);
+    }
+    analytics.publish(aggregate, &req);
+
+    let result = result?;
+
+    debug!(returns = ?result, "Autocomplete post");
+    Ok(HttpResponse::Ok().json(result))
+}
@@ -23,6 +23,8 @@ pub struct CompactApi;
 /// Trigger a compaction process on the specified index.
 ///
 /// Compaction reorganizes the index database to reclaim space and improve read performance.
+///
+/// Compactions are always enqueued with the `normal` priority: they are processed before the other tasks of the indexes, whatever their priority.
 #[routes::path(
     security(("Bearer" = ["indexes.compact", "*"])),
     no_request_body,
@@ -66,6 +68,7 @@ pub async fn compact(
     analytics.publish(IndexCompacted::default(), &req);
 
     let task = KindWithContent::IndexCompaction { index_uid: index_uid.to_string() };
+    // The scheduler processes the compactions before the other index tasks, a priority is meaningless
     let task = match tokio::task::spawn_blocking(move || index_scheduler.register(task)).await? {
         Ok(task) => task,
         Err(e) => return Err(e.into()),
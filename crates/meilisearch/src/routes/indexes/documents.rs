@@ -1,10 +1,11 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::io::{ErrorKind, Seek as _};
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-use actix_web::http::header::CONTENT_TYPE;
+use actix_web::http::header::{Accept, Header as _, CONTENT_TYPE};
 use actix_web::web::Data;
 use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use bstr::ByteSlice as _;
@@ -15,7 +16,9 @@ use index_scheduler::filter::parse_local_index_filter;
 use index_scheduler::IndexScheduler;
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
-use meilisearch_types::document_formats::{read_csv, read_json, read_ndjson, PayloadType};
+use meilisearch_types::document_formats::{
+    read_arrow_stream, read_csv, read_json, read_ndjson, read_parquet, PayloadType,
+};
 use meilisearch_types::error::deserr_codes::*;
 use meilisearch_types::error::{Code, ResponseError};
 use meilisearch_types::heed::RoTxn;
@@ -35,7 +38,7 @@ use meilisearch_types::milli::{
 };
 use meilisearch_types::serde_cs::vec::CS;
 use meilisearch_types::star_or::OptionStarOrList;
-use meilisearch_types::tasks::KindWithContent;
+use meilisearch_types::tasks::{KindWithContent, TaskPriority};
 use meilisearch_types::{milli, Document, Index};
 use mime::Mime;
 use once_cell::sync::Lazy;
@@ -59,7 +62,8 @@ use crate::extractors::payload::Payload;
 use crate::proxy::{proxy, task_network_and_check_leader_and_version, Body};
 use crate::routes::indexes::search::fix_sort_query_parameters;
 use crate::routes::{
-    PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT, PAGINATION_DEFAULT_LIMIT_FN,
+    check_task_priority, PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT,
+    PAGINATION_DEFAULT_LIMIT_FN,
 };
 use crate::search::federated::{weighted_scores, NetworkPartitioner};
 use crate::search::proxy::{
@@ -70,7 +74,13 @@ use crate::search::{
 };
 
 static ACCEPTED_CONTENT_TYPE: Lazy<Vec<String>> = Lazy::new(|| {
-    vec!["application/json".to_string(), "application/x-ndjson".to_string(), "text/csv".to_string()]
+    vec![
+        "application/json".to_string(),
+        "application/x-ndjson".to_string(),
+        "text/csv".to_string(),
+        "application/vnd.apache.parquet".to_string(),
+        "application/vnd.apache.arrow.stream".to_string(),
+    ]
 });
 use crate::search::federated::types::{
     PreprocessableQuery, PreprocessedQuery, FEDERATION_EXTERNAL_DOCUMENT_ID, FEDERATION_HIT,
@@ -196,6 +206,9 @@ pub struct DocumentsFetchAggregator<Method: AggregateMethod> {
     #[serde(rename = "pagination.max_offset")]
     max_offset: usize,
 
+    // if the documents were streamed in NDJSON or CSV
+    streamed: bool,
+
     marker: std::marker::PhantomData<Method>,
 }
 
@@ -214,6 +227,7 @@ impl<Method: AggregateMethod> Aggregate for DocumentsFetchAggregator<Method> {
             max_limit: self.max_limit.max(new.max_limit),
             max_offset: self.max_offset.max(new.max_offset),
             max_document_ids: self.max_document_ids.max(new.max_document_ids),
+            streamed: self.streamed | new.streamed,
             marker: PhantomData,
         })
     }
@@ -293,6 +307,7 @@ pub async fn get_document(
         DocumentsFetchAggregator::<DocumentsGET> {
             retrieve_vectors: retrieve_vectors == RetrieveVectors::Retrieve,
             per_document_id: true,
+            streamed: false,
             per_filter: false,
             with_vector_filter: false,
             sort: false,
@@ -423,7 +438,8 @@ pub async fn delete_document(
     req: HttpRequest,
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
-    let CustomMetadataQuery { custom_metadata } = params.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = params.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
     let DocumentParam { index_uid, document_id } = path.into_inner();
     let index_uid = IndexUid::try_from(index_uid)?;
     let network = index_scheduler.network();
@@ -446,7 +462,7 @@ pub async fn delete_document(
     let mut task = {
         let index_scheduler = index_scheduler.clone();
         tokio::task::spawn_blocking(move || {
-            index_scheduler.register_with_custom_metadata(task, custom_metadata, task_network)
+            index_scheduler.register_with_priority(task, custom_metadata, priority, task_network)
         })
         .await??
     };
@@ -730,6 +746,9 @@ impl TryFrom<Document> for MergedDocument {
 /// **Note:** Sending an empty payload (`{}`) returns all documents in the index.
 ///
 /// **Note:** Documents are not returned following the order of their primary keys.
+///
+/// **Note:** When the `Accept` header prefers `application/x-ndjson` or `text/csv` over `application/json`,
+/// all the matching documents are streamed in that format instead, ignoring `offset` and `limit`.
 #[routes::path(
     security(("Bearer" = ["documents.get", "documents.*", "*"])),
     params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
@@ -798,6 +817,7 @@ pub async fn documents_by_query_post(
 
     let body = body.into_inner();
     debug!(parameters = ?body, "Get documents POST");
+    let stream_format = DocumentsStreamFormat::from_accept_header(&req);
 
     // check remote header
     let is_proxy = req
@@ -818,13 +838,21 @@ pub async fn documents_by_query_post(
             max_offset: body.offset,
             max_document_ids: body.ids.as_ref().map(Vec::len).unwrap_or_default(),
             per_document_id: false,
+            streamed: stream_format.is_some(),
             marker: PhantomData,
         },
         &req,
     );
 
-    let ret =
-        documents_by_query(index_scheduler.clone(), index_uid, body, is_proxy, &progress).await;
+    let ret = documents_by_query(
+        index_scheduler.clone(),
+        index_uid,
+        body,
+        is_proxy,
+        stream_format,
+        &progress,
+    )
+    .await;
     if let Some(permit) = permit {
         permit.drop().await;
     }
@@ -838,6 +866,9 @@ pub async fn documents_by_query_post(
 /// **Deprecated:** This endpoint will be deprecated in a future release. Use `POST /indexes/{index_uid}/documents/fetch` instead, which supports more parameters and array-based filter expressions.
 ///
 /// **Note:** Documents are not returned following the order of their primary keys.
+///
+/// **Note:** When the `Accept` header prefers `application/x-ndjson` or `text/csv` over `application/json`,
+/// all the matching documents are streamed in that format instead, ignoring `offset` and `limit`.
 #[routes::path(
     security(("Bearer" = ["documents.get", "documents.*", "*"])),
     params(
@@ -904,6 +935,7 @@ pub async fn get_documents(
 
     let BrowseQueryGet { limit, offset, fields, retrieve_vectors, filter, ids, sort, use_network } =
         params.into_inner();
+    let stream_format = DocumentsStreamFormat::from_accept_header(&req);
 
     let filter = match filter {
         Some(f) => match serde_json::from_str(&f) {
@@ -937,12 +969,21 @@ pub async fn get_documents(
             max_offset: query.offset,
             max_document_ids: query.ids.as_ref().map(Vec::len).unwrap_or_default(),
             per_document_id: false,
+            streamed: stream_format.is_some(),
             marker: PhantomData,
         },
         &req,
     );
 
-    let ret = documents_by_query(index_scheduler.clone(), index_uid, query, false, &progress).await;
+    let ret = documents_by_query(
+        index_scheduler.clone(),
+        index_uid,
+        query,
+        false,
+        stream_format,
+        &progress,
+    )
+    .await;
 
     if let Some(permit) = permit {
         permit.drop().await;
@@ -956,6 +997,7 @@ async fn documents_by_query(
     index_uid: web::Path<String>,
     query: BrowseQuery,
     is_proxy: bool,
+    stream_format: Option<DocumentsStreamFormat>,
     progress: &Progress,
 ) -> Result<HttpResponse, ResponseError> {
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
@@ -978,6 +1020,16 @@ async fn documents_by_query(
     // we only have one query, so we can pop it
     let mut query = queries.pop().unwrap();
 
+    if let Some(format) = stream_format {
+        if query.query.must_use_network(&network_partitioner, &features)? {
+            return Err(ResponseError::from_msg(
+                "Streaming the documents is not supported on the whole network. Hint: Set `useNetwork` to `false` to stream the documents of this instance.".to_string(),
+                Code::InvalidDocumentUseNetwork,
+            ));
+        }
+        return stream_documents_local(index_scheduler, query, format).await;
+    }
+
     let ret = if query.query.must_use_network(&network_partitioner, &features)? {
         retrieve_documents_federated(index_scheduler, query, remote_errors, &network_partitioner)
             .await
@@ -988,6 +1040,243 @@ async fn documents_by_query(
     Ok(HttpResponse::Ok().json(ret?))
 }
 
+/// The formats in which the documents can be streamed instead of being paginated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocumentsStreamFormat {
+    Ndjson,
+    Csv,
+}
+
+impl DocumentsStreamFormat {
+    /// Returns the streaming format preferred by the `Accept` header, if any.
+    ///
+    /// JSON stays the default format, it's preferred over the streaming formats when accepted with the same quality.
+    fn from_accept_header(req: &HttpRequest) -> Option<Self> {
+        let accept = req.get_header::<Accept>()?;
+        for mime in accept.ranked() {
+            match (mime.type_().as_str(), mime.subtype().as_str()) {
+                ("application", "x-ndjson") => return Some(Self::Ndjson),
+                ("text", "csv") => return Some(Self::Csv),
+                ("application", "json" | "*") | ("*", "*") => return None,
+                _ => (),
+            }
+        }
+        None
+    }
+
+    fn content_type(&self) -> &'static str {
+        match self {
+            Self::Ndjson => "application/x-ndjson",
+            Self::Csv => "text/csv",
+        }
+    }
+}
+
+/// The size of the chunks sent to the client when streaming the documents.
+const DOCUMENTS_STREAM_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Buffers the streamed documents and sends them to the client chunk by chunk.
+struct DocumentsStreamWriter {
+    sender: tokio::sync::mpsc::Sender<Result<bytes::Bytes, std::io::Error>>,
+    buffer: Vec<u8>,
+}
+
+impl std::io::Write for DocumentsStreamWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= DOCUMENTS_STREAM_CHUNK_SIZE {
+            self.flush()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = bytes::Bytes::from(std::mem::take(&mut self.buffer));
+        self.sender.blocking_send(Ok(chunk)).map_err(|_| {
+            std::io::Error::new(ErrorKind::BrokenPipe, "the client stopped receiving the documents")
+        })
+    }
+}
+
+/// Streams all the documents matching the query from a single read transaction, ignoring the `offset` and `limit`.
+async fn stream_documents_local(
+    index_scheduler: Data<IndexScheduler>,
+    query: PreprocessedQuery<BrowseQueryWithIndex>,
+    format: DocumentsStreamFormat,
+) -> Result<HttpResponse, ResponseError> {
+    let PreprocessedQuery {
+        query:
+            BrowseQueryWithIndex {
+                index_uid,
+                remote: _,
+                query:
+                    BrowseQuery {
+                        offset: _,
+                        limit: _,
+                        fields,
+                        retrieve_vectors,
+                        filter: _,
+                        ids,
+                        sort,
+                        use_network: _,
+                    },
+            },
+        filter,
+    } = query;
+
+    let (ready_sender, ready_receiver) = tokio::sync::oneshot::channel();
+    let (sender, mut receiver) = tokio::sync::mpsc::channel(8);
+
+    tokio::task::spawn_blocking(move || {
+        let mut ready_sender = Some(ready_sender);
+        let error_sender = sender.clone();
+        let result = (|| -> Result<(), ResponseError> {
+            let retrieve_vectors = RetrieveVectors::new(retrieve_vectors);
+            let ids = parse_document_ids(ids)?;
+            let sort_criteria = parse_sort_criteria(sort.as_deref())?;
+            // `*` means all the fields, like when no field is specified
+            let fields = fields.filter(|fields| !fields.iter().any(|field| field == "*"));
+
+            let index = index_scheduler.user_index(&index_uid)?;
+            let rtxn = index.read_txn()?;
+            let fields_ids_map = index.fields_ids_map(&rtxn)?;
+
+            let candidates = documents_candidates(&index, &rtxn, &fields_ids_map, ids, filter)?;
+            let documents_ids = match sort_criteria.as_ref() {
+                Some(sort) => {
+                    let facet_sort =
+                        recursive_sort(&index, &rtxn, &fields_ids_map, sort, &candidates)?;
+                    let documents_ids: Result<Vec<_>, _> = facet_sort.iter()?.collect();
+                    itertools::Either::Left(documents_ids?.into_iter())
+                }
+                None => itertools::Either::Right(candidates.iter()),
+            };
+
+            let headers = match &fields {
+                Some(fields) => fields.clone(),
+                None => top_level_fields(&fields_ids_map),
+            };
+            let documents = some_documents(
+                &index,
+                &rtxn,
+                &fields_ids_map,
+                documents_ids,
+                retrieve_vectors,
+                fields,
+                None::<Vec<String>>,
+            )?
+            .map(|ret| ret.map(|(document, _)| document));
+
+            // From now on the response is being sent, the errors can only abort it.
+            if let Some(ready_sender) = ready_sender.take() {
+                let _ = ready_sender.send(Ok(()));
+            }
+
+            let writer = DocumentsStreamWriter { sender, buffer: Vec::new() };
+            match format {
+                DocumentsStreamFormat::Ndjson => write_ndjson_documents(writer, documents),
+                DocumentsStreamFormat::Csv => {
+                    let mut headers = headers;
+                    if retrieve_vectors == RetrieveVectors::Retrieve
+                        && !headers.iter().any(|header| header == "_vectors")
+                    {
+                        headers.push("_vectors".to_string());
+                    }
+                    write_csv_documents(writer, &headers, documents)
+                }
+            }
+        })();
+
+        match (ready_sender, result) {
+            (Some(ready_sender), result) => {
+                let _ = ready_sender.send(result);
+            }
+            (None, Err(e)) => {
+                tracing::error!("While streaming the documents of `{index_uid}`: {e}");
+                // aborts the response so the client doesn't mistake it for a complete one
+                let _ = error_sender.blocking_send(Err(std::io::Error::other(e.to_string())));
+            }
+            (None, Ok(())) => (),
+        }
+    });
+
+    // the sender is only dropped without being used if the blocking task panicked
+    ready_receiver.await.map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    let body = futures::stream::poll_fn(move |cx| receiver.poll_recv(cx));
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(body))
+}
+
+/// Returns the fields that are not nested in another field, they are the columns of the CSV export.
+fn top_level_fields(fields_ids_map: &FieldsIdsMap) -> Vec<String> {
+    let names: BTreeSet<_> = fields_ids_map.names().collect();
+    fields_ids_map
+        .names()
+        .filter(|name| !name.match_indices('.').any(|(dot, _)| names.contains(&name[..dot])))
+        .map(String::from)
+        .collect()
+}
+
+fn write_ndjson_documents(
+    mut writer: DocumentsStreamWriter,
+    documents: impl Iterator<Item = Result<Document, ResponseError>>,
+) -> Result<(), ResponseError> {
+    use std::io::Write as _;
+
+    for document in documents {
+        serde_json::to_writer(&mut writer, &document?).map_err(MeilisearchHttpError::from)?;
+        writer.write_all(b"\n").map_err(MeilisearchHttpError::from)?;
+    }
+    writer.flush().map_err(MeilisearchHttpError::from)?;
+    Ok(())
+}
+
+fn write_csv_documents(
+    writer: DocumentsStreamWriter,
+    headers: &[String],
+    documents: impl Iterator<Item = Result<Document, ResponseError>>,
+) -> Result<(), ResponseError> {
+    let mut writer = csv::Writer::from_writer(writer);
+    writer
+        .write_record(headers)
+        .map_err(std::io::Error::from)
+        .map_err(MeilisearchHttpError::from)?;
+    for document in documents {
+        let document = document?;
+        let record: Vec<_> = headers.iter().map(|field| csv_field(&document, field)).collect();
+        writer
+            .write_record(record.iter().map(|cell| cell.as_bytes()))
+            .map_err(std::io::Error::from)
+            .map_err(MeilisearchHttpError::from)?;
+    }
+    writer.flush().map_err(MeilisearchHttpError::from)?;
+    Ok(())
+}
+
+/// Formats a field of the document as a CSV cell, the arrays and objects are written in JSON.
+fn csv_field<'a>(document: &'a Document, field: &str) -> Cow<'a, str> {
+    let value = document.get(field).or_else(|| {
+        let mut path = field.split('.');
+        let mut value = document.get(path.next()?)?;
+        for key in path {
+            value = value.as_object()?.get(key)?;
+        }
+        Some(value)
+    });
+
+    match value {
+        None | Some(Value::Null) => Cow::Borrowed(""),
+        Some(Value::String(string)) => Cow::Borrowed(string),
+        Some(value) => Cow::Owned(value.to_string()),
+    }
+}
+
 async fn retrieve_documents_federated(
     index_scheduler: Data<IndexScheduler>,
     query: PreprocessedQuery<BrowseQueryWithIndex>,
@@ -1108,31 +1397,8 @@ async fn retrieve_documents_local(
 
     tokio::task::spawn_blocking(move || -> Result<_, ResponseError> {
         let retrieve_vectors = RetrieveVectors::new(retrieve_vectors);
-        let ids = if let Some(ids) = ids {
-            let mut parsed_ids = Vec::with_capacity(ids.len());
-            for (index, id) in ids.into_iter().enumerate() {
-                let id = id.try_into().map_err(|error| {
-                    let msg = format!("In `.ids[{index}]`: {error}");
-                    ResponseError::from_msg(msg, Code::InvalidDocumentIds)
-                })?;
-                parsed_ids.push(id)
-            }
-            Some(parsed_ids)
-        } else {
-            None
-        };
-
-        let sort_criteria = if let Some(sort) = &sort {
-            let sorts: Vec<_> = match sort.iter().map(|s| milli::AscDesc::from_str(s)).collect() {
-                Ok(sorts) => sorts,
-                Err(asc_desc_error) => {
-                    return Err(milli::SortError::from(asc_desc_error).into_document_error().into())
-                }
-            };
-            Some(sorts)
-        } else {
-            None
-        };
+        let ids = parse_document_ids(ids)?;
+        let sort_criteria = parse_sort_criteria(sort.as_deref())?;
 
         let index = index_scheduler.user_index(&index_uid)?;
         let rtxn = index.read_txn()?;
@@ -1164,6 +1430,33 @@ async fn retrieve_documents_local(
     .unwrap()
 }
 
+fn parse_document_ids(
+    ids: Option<Vec<Value>>,
+) -> Result<Option<Vec<ExternalDocumentId>>, ResponseError> {
+    let Some(ids) = ids else { return Ok(None) };
+
+    let mut parsed_ids = Vec::with_capacity(ids.len());
+    for (index, id) in ids.into_iter().enumerate() {
+        let id = id.try_into().map_err(|error| {
+            let msg = format!("In `.ids[{index}]`: {error}");
+            ResponseError::from_msg(msg, Code::InvalidDocumentIds)
+        })?;
+        parsed_ids.push(id)
+    }
+    Ok(Some(parsed_ids))
+}
+
+fn parse_sort_criteria(sort: Option<&[String]>) -> Result<Option<Vec<AscDesc>>, ResponseError> {
+    let Some(sort) = sort else { return Ok(None) };
+
+    match sort.iter().map(|s| milli::AscDesc::from_str(s)).collect() {
+        Ok(sorts) => Ok(Some(sorts)),
+        Err(asc_desc_error) => {
+            Err(milli::SortError::from(asc_desc_error).into_document_error().into())
+        }
+    }
+}
+
 #[derive(Deserialize, Debug, Deserr, IntoParams)]
 #[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
 #[into_params(parameter_in = Query, rename_all = "camelCase")]
@@ -1189,6 +1482,14 @@ pub struct UpdateDocumentsQuery {
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexCustomMetadata>)]
     pub custom_metadata: Option<String>,
 
+    /// Priority of the enqueued task: `low`, `normal` or `high`. The index of the task with the
+    /// highest priority is processed first, but the tasks of a same index are always processed
+    /// in their enqueue order. Any priority other than `normal` requires the `tasks.prioritize`
+    /// action.
+    #[param(required = false, value_type = TaskPriority, example = "high")]
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskPriority>)]
+    pub priority: Option<Param<TaskPriority>>,
+
     /// When set to `true`, only updates existing documents and skips creating
     /// new ones. Documents that don't already exist in the index will be
     /// ignored. This is useful for partial updates where you only want to
@@ -1209,6 +1510,14 @@ pub struct CustomMetadataQuery {
     #[param(required = false, example = "custom")]
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexCustomMetadata>)]
     pub custom_metadata: Option<String>,
+
+    /// Priority of the enqueued task: `low`, `normal` or `high`. The index of the task with the
+    /// highest priority is processed first, but the tasks of a same index are always processed
+    /// in their enqueue order. Any priority other than `normal` requires the `tasks.prioritize`
+    /// action.
+    #[param(required = false, value_type = TaskPriority, example = "high")]
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskPriority>)]
+    pub priority: Option<Param<TaskPriority>>,
 }
 
 fn from_char_csv_delimiter(
@@ -1284,7 +1593,9 @@ impl<Method: AggregateMethod> Aggregate for DocumentsAggregator<Method> {
 ///
 /// If the provided index does not exist, it will be created.
 ///
-/// **Accepted content types:** `application/json`, `application/x-ndjson`, `text/csv`.
+/// **Accepted content types:** `application/json`, `application/x-ndjson`, `text/csv`,
+/// `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`.
+/// The nested structs and lists of Parquet and Arrow payloads are flattened, `{ "a": { "b": 1 } }` becomes `{ "a.b": 1 }`.
 ///
 /// **Note:** Use the reserved `_geo` object to add geo coordinates: `{"lat": 48.8566, "lng": 2.3522}`.
 ///
@@ -1362,6 +1673,7 @@ pub async fn replace_documents(
     );
 
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
+    let priority = check_task_priority(index_scheduler.filters(), params.priority)?;
     let task = document_addition(
         index_scheduler,
         index_uid,
@@ -1370,6 +1682,7 @@ pub async fn replace_documents(
         body,
         IndexDocumentsMethod::ReplaceDocuments,
         params.custom_metadata,
+        priority,
         allow_index_creation,
         params.skip_creation,
         &req,
@@ -1396,7 +1709,9 @@ pub async fn replace_documents(
 ///
 /// If the provided index does not exist, it will be created.
 ///
-/// **Accepted content types:** `application/json`, `application/x-ndjson`, `text/csv`.
+/// **Accepted content types:** `application/json`, `application/x-ndjson`, `text/csv`,
+/// `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`.
+/// The nested structs and lists of Parquet and Arrow payloads are flattened, `{ "a": { "b": 1 } }` becomes `{ "a.b": 1 }`.
 ///
 /// **Note:** Use the reserved `_geo` object to add geo coordinates: `{"lat": 48.8566, "lng": 2.3522}`.
 ///
@@ -1474,6 +1789,7 @@ pub async fn update_documents(
     );
 
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
+    let priority = check_task_priority(index_scheduler.filters(), params.priority)?;
     let task = document_addition(
         index_scheduler,
         index_uid,
@@ -1482,6 +1798,7 @@ pub async fn update_documents(
         body,
         IndexDocumentsMethod::UpdateDocuments,
         params.custom_metadata,
+        priority,
         allow_index_creation,
         params.skip_creation,
         &req,
@@ -1501,6 +1818,7 @@ async fn document_addition(
     body: Payload,
     method: IndexDocumentsMethod,
     custom_metadata: Option<String>,
+    priority: TaskPriority,
     allow_index_creation: bool,
     skip_creation: Option<bool>,
     req: &HttpRequest,
@@ -1517,6 +1835,8 @@ async fn document_addition(
         (Some(("application", "x-ndjson")), None) => PayloadType::Ndjson,
         (Some(("text", "csv")), None) => PayloadType::Csv { delimiter: b',' },
         (Some(("text", "csv")), Some(delimiter)) => PayloadType::Csv { delimiter },
+        (Some(("application", "vnd.apache.parquet")), None) => PayloadType::Parquet,
+        (Some(("application", "vnd.apache.arrow.stream")), None) => PayloadType::ArrowStream,
 
         (Some(("application", "json")), Some(_)) => {
             return Err(MeilisearchHttpError::CsvDelimiterWithWrongContentType(String::from(
@@ -1528,6 +1848,14 @@ async fn document_addition(
                 "application/x-ndjson",
             )))
         }
+        (
+            Some(("application", subtype @ ("vnd.apache.parquet" | "vnd.apache.arrow.stream"))),
+            Some(_),
+        ) => {
+            return Err(MeilisearchHttpError::CsvDelimiterWithWrongContentType(format!(
+                "application/{subtype}"
+            )))
+        }
         (Some((type_, subtype)), _) => {
             return Err(MeilisearchHttpError::InvalidContentType(
                 format!("{}/{}", type_, subtype),
@@ -1565,7 +1893,10 @@ async fn document_addition(
 
             Ok(res)
         }
-        PayloadType::Json | PayloadType::Csv { delimiter: _ } => {
+        PayloadType::Json
+        | PayloadType::Csv { delimiter: _ }
+        | PayloadType::Parquet
+        | PayloadType::ArrowStream => {
             let temp_file = match tempfile() {
                 Ok(file) => file,
                 Err(e) => return Err(MeilisearchHttpError::Payload(ReceivePayload(Box::new(e)))),
@@ -1578,6 +1909,8 @@ async fn document_addition(
                     PayloadType::Csv { delimiter } => {
                         read_csv(&read_file, &mut update_file, delimiter)?
                     }
+                    PayloadType::Parquet => read_parquet(&read_file, &mut update_file)?,
+                    PayloadType::ArrowStream => read_arrow_stream(&read_file, &mut update_file)?,
                     PayloadType::Ndjson => {
                         unreachable!("We already wrote the user content into the update file")
                     }
@@ -1630,7 +1963,7 @@ async fn document_addition(
     // FIXME: not new to #6000, but _any_ error here will cause the payload to unduly persist
     let scheduler = index_scheduler.clone();
     let mut task = match tokio::task::spawn_blocking(move || {
-        scheduler.register_with_custom_metadata(task, custom_metadata, task_network)
+        scheduler.register_with_priority(task, custom_metadata, priority, task_network)
     })
     .await?
     {
@@ -1737,7 +2070,8 @@ pub async fn delete_documents_batch(
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Delete documents by batch");
-    let CustomMetadataQuery { custom_metadata } = params.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = params.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
 
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
     let network = index_scheduler.network();
@@ -1763,7 +2097,7 @@ pub async fn delete_documents_batch(
     let mut task = {
         let index_scheduler = index_scheduler.clone();
         tokio::task::spawn_blocking(move || {
-            index_scheduler.register_with_custom_metadata(task, custom_metadata, task_network)
+            index_scheduler.register_with_priority(task, custom_metadata, priority, task_network)
         })
         .await??
     };
@@ -1845,7 +2179,8 @@ pub async fn delete_documents_by_filter(
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Delete documents by filter");
-    let CustomMetadataQuery { custom_metadata } = params.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = params.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
 
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
     let index_uid = index_uid.into_inner();
@@ -1880,7 +2215,7 @@ pub async fn delete_documents_by_filter(
     let mut task = {
         let index_scheduler = index_scheduler.clone();
         tokio::task::spawn_blocking(move || {
-            index_scheduler.register_with_custom_metadata(task, custom_metadata, task_network)
+            index_scheduler.register_with_priority(task, custom_metadata, priority, task_network)
         })
         .await??
     };
@@ -2001,7 +2336,8 @@ pub async fn edit_documents_by_function(
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Edit documents by function");
-    let CustomMetadataQuery { custom_metadata } = params.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = params.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
 
     index_scheduler
         .features()
@@ -2057,7 +2393,7 @@ pub async fn edit_documents_by_function(
     let mut task = {
         let index_scheduler = index_scheduler.clone();
         tokio::task::spawn_blocking(move || {
-            index_scheduler.register_with_custom_metadata(task, custom_metadata, task_network)
+            index_scheduler.register_with_priority(task, custom_metadata, priority, task_network)
         })
         .await??
     };
@@ -2127,7 +2463,8 @@ pub async fn clear_all_documents(
 ) -> Result<HttpResponse, ResponseError> {
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
     let network = index_scheduler.network();
-    let CustomMetadataQuery { custom_metadata } = params.into_inner();
+    let CustomMetadataQuery { custom_metadata, priority } = params.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
 
     analytics.publish(
@@ -2146,7 +2483,7 @@ pub async fn clear_all_documents(
         let index_scheduler = index_scheduler.clone();
 
         tokio::task::spawn_blocking(move || {
-            index_scheduler.register_with_custom_metadata(task, custom_metadata, task_network)
+            index_scheduler.register_with_priority(task, custom_metadata, priority, task_network)
         })
         .await??
     };
@@ -2241,20 +2578,14 @@ where
     }))
 }
 
-#[allow(clippy::too_many_arguments)]
-fn retrieve_documents<S: AsRef<str>>(
+/// Returns the documents matching the ids and the filter.
+fn documents_candidates(
     index: &Index,
     rtxn: &RoTxn,
     fields_ids_map: &FieldsIdsMap,
-    offset: usize,
-    limit: usize,
     ids: Option<Vec<ExternalDocumentId>>,
     filter: Option<IndexFilter>,
-    attributes_to_retrieve: Option<Vec<S>>,
-    retrieve_vectors: RetrieveVectors,
-    sort_criteria: Option<Vec<AscDesc>>,
-    is_proxy: bool,
-) -> Result<(u64, Vec<Document>), ResponseError> {
+) -> Result<RoaringBitmap, ResponseError> {
     let mut candidates = if let Some(ids) = ids {
         let external_document_ids = index.external_documents_ids();
         let mut candidates = RoaringBitmap::new();
@@ -2278,6 +2609,25 @@ fn retrieve_documents<S: AsRef<str>>(
         })?
     }
 
+    Ok(candidates)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn retrieve_documents<S: AsRef<str>>(
+    index: &Index,
+    rtxn: &RoTxn,
+    fields_ids_map: &FieldsIdsMap,
+    offset: usize,
+    limit: usize,
+    ids: Option<Vec<ExternalDocumentId>>,
+    filter: Option<IndexFilter>,
+    attributes_to_retrieve: Option<Vec<S>>,
+    retrieve_vectors: RetrieveVectors,
+    sort_criteria: Option<Vec<AscDesc>>,
+    is_proxy: bool,
+) -> Result<(u64, Vec<Document>), ResponseError> {
+    let candidates = documents_candidates(index, rtxn, fields_ids_map, ids, filter)?;
+
     let primary_key = index.primary_key(rtxn)?;
 
     let (it, number_of_documents) = if let Some(sort) = sort_criteria.as_ref() {
@@ -26,9 +26,9 @@ use crate::search::federated::types::{PreprocessableQuery, PreprocessedQuery};
 use crate::search::federated::NetworkPartitioner;
 use crate::search::proxy::{json_proxy, ProxySearchError, ProxySearchParams};
 use crate::search::{
-    add_search_rules, intersect_index_filters, perform_facet_search, prepare_search,
-    union_index_filters, FacetSearchResult, HybridQuery, MatchingStrategy, NetworkableQuery,
-    RankingScoreThreshold, SearchQuery, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER,
+    add_search_rules, check_attributes_authorized, intersect_index_filters, perform_facet_search,
+    prepare_search, union_index_filters, FacetSearchResult, HybridQuery, MatchingStrategy,
+    NetworkableQuery, RankingScoreThreshold, SearchQuery, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER,
     DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG, DEFAULT_SEARCH_LIMIT,
     DEFAULT_SEARCH_OFFSET,
 };
@@ -111,6 +111,9 @@ pub struct FacetSearchQuery {
     /// When using the network, the index must exist with compatible settings on all remotes.
     #[request(default, error = DeserrJsonError<InvalidSearchUseNetwork>)]
     pub use_network: Option<bool>,
+    /// Attributes that the tenant token used for this search allows to retrieve, if restricted
+    #[request(skip)]
+    pub tenant_token_attributes: Option<Vec<String>>,
 }
 
 impl NetworkableQuery for (IndexUid, FacetSearchQuery) {
@@ -164,6 +167,7 @@ impl FacetSearchAggregator {
             locales,
             exhaustive_facet_count,
             use_network,
+            tenant_token_attributes: _,
         } = query;
 
         Self {
@@ -319,6 +323,22 @@ pub async fn search(
     // Tenant token search_rules.
     // NOTE: must be applied **BEFORE** proxying the query so that the tenant token sent to the original machine is taken into account
     if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
+        if !search_rules.is_facet_authorized(&query.facet_name) {
+            return Err(ResponseError::from_msg(
+                format!(
+                    "The tenant token does not allow searching in the values of the `{}` facet.",
+                    query.facet_name
+                ),
+                Code::InvalidFacetSearchFacetName,
+            ));
+        }
+        check_attributes_authorized(
+            &search_rules,
+            query.filter.as_ref(),
+            None,
+            query.attributes_to_search_on.as_deref(),
+        )?;
+        query.tenant_token_attributes = search_rules.attributes_to_retrieve.clone();
         add_search_rules(&mut query.filter, search_rules);
     }
 
@@ -553,7 +573,7 @@ async fn search_local(
     let facet_name = query.facet_name.clone();
     let facet_query = query.facet_query.clone();
     let locales = query.locales.clone().map(|l| l.into_iter().map(Into::into).collect());
-    let search_query = SearchQuery::from(query);
+    let mut search_query = SearchQuery::from(query);
 
     let progress_clone = progress.clone();
     let search_result = tokio::task::spawn_blocking(move || {
@@ -561,6 +581,7 @@ async fn search_local(
         let rtxn = index.read_txn()?;
         let deadline = index.search_deadline(&rtxn)?;
         let fields_ids_map = index.fields_ids_map(&rtxn)?;
+        search_query.restrict_attributes_to_search_on(&index, &rtxn)?;
         let search_kind =
             search_kind(&search_query, &index_scheduler, index_uid.to_string(), &index)?;
 
@@ -609,6 +630,7 @@ impl From<FacetSearchQuery> for SearchQuery {
             locales,
             exhaustive_facet_count,
             use_network,
+            tenant_token_attributes,
         } = value;
 
         // If exhaustive_facet_count is true, we need to set the page to 0
@@ -637,14 +659,19 @@ impl From<FacetSearchQuery> for SearchQuery {
             show_ranking_score: false,
             show_ranking_score_details: false,
             show_performance_details: false,
+            tenant_token_claims: None,
+            tenant_token_attributes,
             filter,
             sort: None,
             distinct: None,
             facets: None,
+            facet_aggregations: None,
             highlight_pre_tag: DEFAULT_HIGHLIGHT_PRE_TAG(),
             highlight_post_tag: DEFAULT_HIGHLIGHT_POST_TAG(),
             crop_marker: DEFAULT_CROP_MARKER(),
             matching_strategy,
+            advanced_query_syntax: false,
+            suggestions: 0,
             vector,
             attributes_to_search_on,
             hybrid,
@@ -47,8 +47,8 @@ impl<'a> Field<'a> {
     ) -> Self {
         let FilterableAttributesFeatures { facet_search, filter } =
             metadata.filterable_attributes_features(filterable_attributes);
-        let FilterFeatures { equality, comparison } = filter;
-        let is_filterable = equality || comparison || facet_search;
+        let FilterFeatures { equality, comparison, pattern_matching } = filter;
+        let is_filterable = equality || comparison || pattern_matching || facet_search;
 
         let locales = localized_attributes_rules
             .and_then(|rules| metadata.locales(rules))
@@ -18,12 +18,16 @@ use time::OffsetDateTime;
 use tracing::debug;
 use utoipa::{IntoParams, ToSchema};
 
-use super::{Pagination, PaginationView, SummarizedTaskView, PAGINATION_DEFAULT_LIMIT};
+use super::{
+    check_task_priority, Pagination, PaginationView, SummarizedTaskView, TaskPriorityQuery,
+    PAGINATION_DEFAULT_LIMIT,
+};
 use crate::analytics::{Aggregate, Analytics};
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::{AuthenticationError, GuardedData};
 use crate::proxy::{proxy, task_network_and_check_leader_and_version, Body};
 
+pub mod autocomplete;
 pub mod compact;
 pub mod documents;
 
@@ -45,6 +49,7 @@ mod similar_analytics;
         "/{index_uid}" => [get(get_index), patch(update_index), delete(delete_index)],
         "/{index_uid}/documents" => sub(documents::DocumentsApi),
         "/{index_uid}/facet-search" => sub(facet_search::FacetSearchApi),
+        "/{index_uid}/autocomplete" => sub(autocomplete::AutocompleteApi),
         "/{index_uid}/similar" => sub(similar::SimilarApi),
         "/{index_uid}/settings" => sub(settings::SettingsApi),
         "/{index_uid}/compact" => sub(compact::CompactApi),
@@ -207,6 +212,7 @@ impl Aggregate for IndexCreatedAggregate {
 /// If no primary key is provided, Meilisearch will [infer one](https://www.meilisearch.com/docs/learn/getting_started/primary_key#meilisearch-guesses-your-primary-key) from the first batch of documents.
 #[routes::path(
     security(("Bearer" = ["indexes.create", "indexes.*", "*"])),
+    params(TaskPriorityQuery),
     request_body = IndexCreateRequest,
     responses(
         (status = 202, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
@@ -230,11 +236,13 @@ impl Aggregate for IndexCreatedAggregate {
 )]
 pub async fn create_index(
     index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_CREATE }>, Data<IndexScheduler>>,
+    params: AwebQueryParameter<TaskPriorityQuery, DeserrQueryParamError>,
     body: AwebJson<IndexCreateRequest, DeserrJsonError>,
     req: HttpRequest,
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Create index");
+    let priority = check_task_priority(index_scheduler.filters(), params.into_inner().priority)?;
 
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
@@ -254,7 +262,7 @@ pub async fn create_index(
         };
         let scheduler = index_scheduler.clone();
         let mut task = tokio::task::spawn_blocking(move || {
-            scheduler.register_with_custom_metadata(task, None, task_network)
+            scheduler.register_with_priority(task, None, priority, task_network)
         })
         .await??;
 
@@ -381,7 +389,10 @@ pub struct UpdateIndexRequest {
 /// Returns an error if the index does not exist or if it already contains documents ([primary key](https://www.meilisearch.com/docs/learn/getting_started/primary_key) cannot be changed in that case).
 #[routes::path(
     security(("Bearer" = ["indexes.update", "indexes.*", "*"])),
-    params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+    params(
+        ("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false),
+        TaskPriorityQuery,
+    ),
     request_body = UpdateIndexRequest,
     responses(
         (status = ACCEPTED, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
@@ -414,11 +425,13 @@ pub struct UpdateIndexRequest {
 pub async fn update_index(
     index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_UPDATE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
+    params: AwebQueryParameter<TaskPriorityQuery, DeserrQueryParamError>,
     body: AwebJson<UpdateIndexRequest, DeserrJsonError>,
     req: HttpRequest,
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     debug!(parameters = ?body, "Update index");
+    let priority = check_task_priority(index_scheduler.filters(), params.into_inner().priority)?;
 
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
@@ -444,7 +457,7 @@ pub async fn update_index(
 
     let scheduler = index_scheduler.clone();
     let mut task = tokio::task::spawn_blocking(move || {
-        scheduler.register_with_custom_metadata(task, None, task_network)
+        scheduler.register_with_priority(task, None, priority, task_network)
     })
     .await??;
 
@@ -472,7 +485,10 @@ pub async fn update_index(
 /// Permanently delete an index and all its documents, settings, and task history.
 #[routes::path(
     security(("Bearer" = ["indexes.delete", "indexes.*", "*"])),
-    params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+    params(
+        ("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false),
+        TaskPriorityQuery,
+    ),
     responses(
         (status = ACCEPTED, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
             {
@@ -504,8 +520,10 @@ pub async fn update_index(
 pub async fn delete_index(
     index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_DELETE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
+    params: AwebQueryParameter<TaskPriorityQuery, DeserrQueryParamError>,
     req: HttpRequest,
 ) -> Result<HttpResponse, ResponseError> {
+    let priority = check_task_priority(index_scheduler.filters(), params.into_inner().priority)?;
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
 
@@ -514,7 +532,7 @@ pub async fn delete_index(
     let scheduler = index_scheduler.clone();
 
     let mut task = tokio::task::spawn_blocking(move || {
-        scheduler.register_with_custom_metadata(task, None, task_network)
+        scheduler.register_with_priority(task, None, priority, task_network)
     })
     .await??;
 
@@ -24,17 +24,17 @@ use crate::documents_retrieval::{
 use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
-use crate::personalization::PersonalizationService;
+use crate::personalization::{PersonalizationService, RerankerService};
 use crate::routes::indexes::search_analytics::{SearchAggregator, SearchGET, SearchPOST};
 use crate::routes::parse_include_metadata_header;
 use crate::search::federated::types::PreprocessedQuery;
 use crate::search::federated::NetworkPartitioner;
 use crate::search::{
-    add_search_rules, perform_federated_search, perform_search, HybridQuery, MatchingStrategy,
-    NetworkableQuery as _, Personalize, RankingScoreThreshold, RetrieveVectors, SearchKind,
-    SearchParams, SearchQuery, SearchQueryWithIndex, SearchResult, SemanticRatio,
-    ShowFederationInfo, DEFAULT_CROP_LENGTH, DEFAULT_CROP_MARKER, DEFAULT_HIGHLIGHT_POST_TAG,
-    DEFAULT_HIGHLIGHT_PRE_TAG, DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET, DEFAULT_SEMANTIC_RATIO,
+    perform_federated_search, perform_search, HybridQuery, MatchingStrategy, NetworkableQuery as _,
+    Personalize, RankingScoreThreshold, RetrieveVectors, SearchKind, SearchParams, SearchQuery,
+    SearchQueryWithIndex, SearchResult, SemanticRatio, ShowFederationInfo, DEFAULT_CROP_LENGTH,
+    DEFAULT_CROP_MARKER, DEFAULT_HIGHLIGHT_POST_TAG, DEFAULT_HIGHLIGHT_PRE_TAG,
+    DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET, DEFAULT_SEMANTIC_RATIO,
 };
 use crate::search_queue::SearchQueue;
 
@@ -247,6 +247,16 @@ pub struct SearchQueryGet {
     #[param(required = false)]
     #[deserr(default, error = DeserrQueryParamError<InvalidSearchMatchingStrategy>)]
     matching_strategy: MatchingStrategy,
+    /// Parse the `attribute:term` and `(term OR term)` operators of `q`.
+    #[param(required = false, value_type = bool)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchAdvancedQuerySyntax>)]
+    advanced_query_syntax: Param<bool>,
+    /// Return up to this number of alternative queries where the typos of `q` are corrected.
+    ///
+    /// At most 10 suggestions are returned. Default: `0`, no suggestions are computed.
+    #[param(required = false, value_type = usize)]
+    #[deserr(default, error = DeserrQueryParamError<InvalidSearchSuggestions>)]
+    suggestions: Param<usize>,
     /// Restrict the search to the listed attributes only.
     ///
     /// Each attribute must be in the index [searchable attributes](https://www.meilisearch.com/docs/learn/relevancy/displayed_searchable_attributes) list.
@@ -439,9 +449,13 @@ impl TryFrom<SearchQueryGet> for SearchQuery {
             sort: other.sort.map(|attr| fix_sort_query_parameters(&attr)),
             distinct: other.distinct,
             facets: other.facets.map(|o| o.into_iter().collect::<Vec<_>>().into()),
+            // `facetAggregations` not supported for `GET`
+            facet_aggregations: None,
             highlight_pre_tag: other.highlight_pre_tag,
             highlight_post_tag: other.highlight_post_tag,
             matching_strategy: other.matching_strategy,
+            advanced_query_syntax: other.advanced_query_syntax.0,
+            suggestions: other.suggestions.0,
             attributes_to_search_on: other.attributes_to_search_on.map(|o| o.into_iter().collect()),
             ranking_score_threshold: other.ranking_score_threshold.map(|o| o.0),
             locales: other.locales.map(|o| o.into_iter().collect()),
@@ -455,6 +469,8 @@ impl TryFrom<SearchQueryGet> for SearchQuery {
             show_ranking_score: other.show_ranking_score.0,
             show_ranking_score_details: other.show_ranking_score_details.0,
             show_performance_details: other.show_performance_details.0,
+            tenant_token_claims: None,
+            tenant_token_attributes: None,
         })
     }
 }
@@ -647,8 +663,9 @@ pub async fn legacy_search_with_url_query(
 
     // Tenant token search_rules.
     if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
-        add_search_rules(&mut query.filter, search_rules);
+        query.add_search_rules(search_rules)?;
     }
+    query.tenant_token_claims = index_scheduler.filters().tenant_token_claims().cloned();
 
     let mut aggregate = SearchAggregator::<SearchGET>::from_query(&query);
 
@@ -721,7 +738,7 @@ pub(crate) async fn legacy_search(
         let (q, fixed_query, federation) = {
             let PreprocessedQuery { query, filter } = query;
             let q = query.q.clone();
-            let (fixed_query, federation) = fixup_query_federation(&query);
+            let (fixed_query, federation) = fixup_query_federation(&query)?;
 
             (q, PreprocessedQuery { query: fixed_query, filter }, federation)
         };
@@ -763,6 +780,12 @@ pub(crate) async fn legacy_search(
         let search_kind = search_kind(&query, &index_scheduler, index_uid.to_string(), &index)?;
         let retrieve_vector = RetrieveVectors::new(query.retrieve_vectors);
 
+        // read the reranker of the index before the index is moved into the search task
+        let index_reranker = match &personalize {
+            Some(_) => RerankerService::from_index(&index, &index_scheduler)?,
+            None => None,
+        };
+
         let progress_clone = progress.clone();
         let show_performance_details = query.show_performance_details;
         let search_result = tokio::task::spawn_blocking(move || {
@@ -794,6 +817,7 @@ pub(crate) async fn legacy_search(
                     std::mem::take(&mut search_result.hits),
                     &personalize,
                     personalize_query.as_deref(),
+                    index_reranker.as_ref(),
                     &deadline,
                     progress,
                 )
@@ -972,8 +996,9 @@ pub async fn legacy_search_with_post(
 
     // Tenant token search_rules.
     if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(&index_uid) {
-        add_search_rules(&mut query.filter, search_rules);
+        query.add_search_rules(search_rules)?;
     }
+    query.tenant_token_claims = index_scheduler.filters().tenant_token_claims().cloned();
 
     let mut aggregate = SearchAggregator::<SearchPOST>::from_query(&query);
 
@@ -55,6 +55,9 @@ pub struct SearchAggregator<Method: AggregateMethod> {
     // q
     // The maximum number of terms in a q request
     max_terms_number: usize,
+    advanced_query_syntax: bool,
+    // The maximum number of suggestions requested
+    max_suggestions: usize,
 
     // vector
     // The maximum number of floats in a vector request
@@ -90,6 +93,7 @@ pub struct SearchAggregator<Method: AggregateMethod> {
     // facets
     facets_sum_of_terms: usize,
     facets_total_number_of_facets: usize,
+    facets_total_number_of_aggregations: usize,
 
     // scoring
     show_ranking_score: bool,
@@ -126,7 +130,10 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
             sort,
             distinct,
             facets: _,
+            facet_aggregations,
             matching_strategy,
+            advanced_query_syntax,
+            suggestions,
             attributes_to_search_on,
             ranking_score_threshold,
             locales,
@@ -139,6 +146,8 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
             show_ranking_score,
             show_ranking_score_details,
             show_performance_details: _,
+            tenant_token_claims: _,
+            tenant_token_attributes: _,
         } = query;
 
         let mut ret = Self::default();
@@ -185,6 +194,8 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
 
         if let Some(ref q) = q {
             ret.max_terms_number = q.split_whitespace().count();
+            ret.advanced_query_syntax = *advanced_query_syntax;
+            ret.max_suggestions = *suggestions;
         }
 
         if let Some(ref vector) = vector {
@@ -195,6 +206,10 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
             ret.total_media = 1;
         }
 
+        if let Some(facet_aggregations) = facet_aggregations {
+            ret.facets_total_number_of_aggregations = facet_aggregations.len();
+        }
+
         ret.retrieve_vectors |= retrieve_vectors;
 
         if query.is_finite_pagination() {
@@ -251,10 +266,13 @@ impl<Method: AggregateMethod> SearchAggregator<Method> {
             hits_info: _,
             facet_distribution: _,
             facet_stats: _,
+            facet_aggregations: _,
             request_uid: _,
             metadata: _,
             remote_errors: _,
             semantic_hit_count: _,
+            applied_dynamic_search_rules: _,
+            suggestions: _,
             degraded,
             used_negative_operator,
             performance_details: _,
@@ -293,6 +311,8 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             used_syntax,
             attributes_to_search_on_total_number_of_uses,
             max_terms_number,
+            advanced_query_syntax,
+            max_suggestions,
             max_vector_size,
             retrieve_vectors,
             matching_strategy,
@@ -309,6 +329,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             crop_length,
             facets_sum_of_terms,
             facets_total_number_of_facets,
+            facets_total_number_of_aggregations,
             show_ranking_score,
             show_ranking_score_details,
             semantic_ratio,
@@ -361,6 +382,8 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
 
         // q
         self.max_terms_number = self.max_terms_number.max(max_terms_number);
+        self.advanced_query_syntax |= advanced_query_syntax;
+        self.max_suggestions = self.max_suggestions.max(max_suggestions);
 
         // vector
         self.max_vector_size = self.max_vector_size.max(max_vector_size);
@@ -390,6 +413,9 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
         self.facets_sum_of_terms = self.facets_sum_of_terms.saturating_add(facets_sum_of_terms);
         self.facets_total_number_of_facets =
             self.facets_total_number_of_facets.saturating_add(facets_total_number_of_facets);
+        self.facets_total_number_of_aggregations = self
+            .facets_total_number_of_aggregations
+            .saturating_add(facets_total_number_of_aggregations);
 
         // matching strategy
         for (key, value) in matching_strategy.into_iter() {
@@ -432,6 +458,8 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             used_syntax,
             attributes_to_search_on_total_number_of_uses,
             max_terms_number,
+            advanced_query_syntax,
+            max_suggestions,
             max_vector_size,
             retrieve_vectors,
             matching_strategy,
@@ -448,6 +476,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             crop_length,
             facets_sum_of_terms,
             facets_total_number_of_facets,
+            facets_total_number_of_aggregations,
             show_ranking_score,
             show_ranking_score_details,
             semantic_ratio,
@@ -495,6 +524,8 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             },
             "q": {
                "max_terms_number": max_terms_number,
+               "advanced_query_syntax": advanced_query_syntax,
+               "max_suggestions": max_suggestions,
             },
             "vector": {
                 "max_vector_size": max_vector_size,
@@ -522,6 +553,7 @@ impl<Method: AggregateMethod> Aggregate for SearchAggregator<Method> {
             },
             "facets": {
                 "avg_facets_number": format!("{:.2}", facets_sum_of_terms as f64 / facets_total_number_of_facets as f64),
+                "total_aggregations": facets_total_number_of_aggregations,
             },
             "matching_strategy": {
                 "most_used_strategy": matching_strategy.iter().max_by_key(|(_, v)| *v).map(|(k, _)| json!(k)).unwrap_or_else(|| json!(null)),
@@ -1,14 +1,15 @@
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
-use deserr::actix_web::AwebJson;
+use deserr::actix_web::{AwebJson, AwebQueryParameter};
 use index_scheduler::IndexScheduler;
-use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::milli::update::Setting;
 use meilisearch_types::network::route;
 use meilisearch_types::settings::{
-    settings, ChatSettings, SecretPolicy, SettingEmbeddingSettings, Settings, Unchecked,
+    settings, ChatSettings, RerankerSettings, SecretPolicy, SettingEmbeddingSettings, Settings,
+    Unchecked,
 };
 use meilisearch_types::tasks::KindWithContent;
 use tracing::debug;
@@ -18,7 +19,8 @@ use crate::analytics::Analytics;
 use crate::extractors::authentication::policies::*;
 use crate::extractors::authentication::GuardedData;
 use crate::proxy::{proxy, task_network_and_check_leader_and_version, Body, OverrideEndpoint};
-use crate::routes::SummarizedTaskView;
+use crate::routes::indexes::documents::CustomMetadataQuery;
+use crate::routes::{check_task_priority, SummarizedTaskView};
 
 /// This macro generates the routes for the settings.
 ///
@@ -82,7 +84,10 @@ macro_rules! make_setting_route {
                 operation_id = concat!("delete", $camelcase_attr),
                 summary = concat!("Reset ", $camelcase_attr),
                 description = concat!("Resets the `", $camelcase_attr, "` setting to its default value."),
-                params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+                params(
+                    ("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false),
+                    CustomMetadataQuery,
+                ),
                 responses(
                     (status = 202, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
                         {
@@ -117,13 +122,14 @@ macro_rules! make_setting_route {
                     Data<IndexScheduler>,
                 >,
                 index_uid: web::Path<String>,
+                params: AwebQueryParameter<CustomMetadataQuery, DeserrQueryParamError>,
                 req: HttpRequest,
             ) -> Result<HttpResponse, ResponseError> {
                 let index_uid = IndexUid::try_from(index_uid.into_inner())?;
 
                 let new_settings = Settings { $attr: Setting::Reset.into(), ..Default::default() };
 
-                let task = register_new_settings(new_settings, true, index_scheduler, &req, index_uid).await?;
+                let task = register_new_settings(new_settings, true, index_scheduler, params.into_inner(), &req, index_uid).await?;
 
                 debug!(returns = ?task, "Delete settings");
                 Ok(HttpResponse::Accepted().json(task))
@@ -135,7 +141,10 @@ macro_rules! make_setting_route {
                 operation_id = concat!(stringify!($update_verb), $camelcase_attr),
                 summary = concat!("Update ", $camelcase_attr),
                 description = concat!("Updates the `", $camelcase_attr, "` setting for the index. Send the new value in the request body; send null to reset to default."),
-                params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+                params(
+                    ("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false),
+                    CustomMetadataQuery,
+                ),
                 request_body(content = $type),
                 responses(
                     (status = 202, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
@@ -172,6 +181,7 @@ macro_rules! make_setting_route {
                 >,
                 index_uid: actix_web::web::Path<String>,
                 body: deserr::actix_web::AwebJson<Option<$type>, $err_type>,
+                params: AwebQueryParameter<CustomMetadataQuery, DeserrQueryParamError>,
                 req: HttpRequest,
                 analytics: web::Data<Analytics>,
             ) -> std::result::Result<HttpResponse, ResponseError> {
@@ -194,7 +204,7 @@ macro_rules! make_setting_route {
                     ..Default::default()
                 };
 
-                let task = register_new_settings(new_settings, false, index_scheduler, &req, index_uid).await?;
+                let task = register_new_settings(new_settings, false, index_scheduler, params.into_inner(), &req, index_uid).await?;
 
                 debug!(returns = ?task, "Update settings");
                 Ok(HttpResponse::Accepted().json(task))
@@ -489,6 +499,17 @@ make_setting_routes!(
         camelcase_attr: "chat",
         analytics: ChatAnalytics
     },
+    {
+        route: "/reranker",
+        update_verb: patch,
+        value_type: RerankerSettings,
+        err_type: meilisearch_types::deserr::DeserrJsonError<
+            meilisearch_types::error::deserr_codes::InvalidSettingsReranker,
+        >,
+        attr: reranker,
+        camelcase_attr: "reranker",
+        analytics: RerankerAnalytics
+    },
     {
         route: "/foreign-keys",
         update_verb: put,
@@ -506,7 +527,10 @@ make_setting_routes!(
     summary = "Update all settings",
     description = "Updates one or more settings for the index. Only the fields sent in the body are changed. Pass null for a setting to reset it to its default. If the index does not exist, it is created.\n\nSee also: [Configuring index settings on the Cloud](https://www.meilisearch.com/docs/learn/configuration/configuring_index_settings).",
     security(("Bearer" = ["settings.update", "settings.*", "*"])),
-    params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+    params(
+        ("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false),
+        CustomMetadataQuery,
+    ),
     request_body(content = Settings<Unchecked>),
     responses(
         (status = 202, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
@@ -549,6 +573,7 @@ pub async fn update_all(
     index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
     body: AwebJson<Settings<Unchecked>, DeserrJsonError>,
+    params: AwebQueryParameter<CustomMetadataQuery, DeserrQueryParamError>,
     req: HttpRequest,
     analytics: Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
@@ -599,11 +624,20 @@ pub async fn update_all(
             facet_search: FacetSearchAnalytics::new(new_settings.facet_search.as_ref().set()),
             prefix_search: PrefixSearchAnalytics::new(new_settings.prefix_search.as_ref().set()),
             chat: ChatAnalytics::new(new_settings.chat.as_ref().set()),
+            reranker: RerankerAnalytics::new(new_settings.reranker.as_ref().set()),
         },
         &req,
     );
 
-    let task = register_new_settings(new_settings, false, index_scheduler, &req, index_uid).await?;
+    let task = register_new_settings(
+        new_settings,
+        false,
+        index_scheduler,
+        params.into_inner(),
+        &req,
+        index_uid,
+    )
+    .await?;
 
     debug!(returns = ?task, "Update all settings");
     Ok(HttpResponse::Accepted().json(task))
@@ -613,9 +647,12 @@ async fn register_new_settings(
     new_settings: Settings<Unchecked>,
     is_deletion: bool,
     index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
+    params: CustomMetadataQuery,
     req: &HttpRequest,
     index_uid: IndexUid,
 ) -> Result<SummarizedTaskView, ResponseError> {
+    let CustomMetadataQuery { custom_metadata, priority } = params;
+    let priority = check_task_priority(index_scheduler.filters(), priority)?;
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(req, &network)?;
 
@@ -627,7 +664,11 @@ async fn register_new_settings(
     };
 
     let allow_index_creation = index_scheduler.filters().allow_index_creation(&index_uid);
-    let settings_path = route::settings_path(&index_uid).unwrap();
+    let mut settings_path = route::settings_path(&index_uid).unwrap();
+    if !req.query_string().is_empty() {
+        // forward the query parameters, such as the priority, to the remotes
+        settings_path = format!("{settings_path}?{}", req.query_string()).parse().unwrap();
+    }
 
     let index_uid = IndexUid::try_from(index_uid.into_inner())?.into_inner();
     let task = KindWithContent::SettingsUpdate {
@@ -638,7 +679,7 @@ async fn register_new_settings(
     };
     let scheduler = index_scheduler.clone();
     let mut task = tokio::task::spawn_blocking(move || {
-        scheduler.register_with_custom_metadata(task, None, task_network)
+        scheduler.register_with_priority(task, custom_metadata, priority, task_network)
     })
     .await??;
 
@@ -697,7 +738,8 @@ async fn register_new_settings(
             "localizedAttributes": [{ "locales": ["jpn"], "attributePatterns": ["*_ja"] }],
             "facetSearch": true,
             "prefixSearch": "indexingTime",
-            "chat": { "description": "A comprehensive movie database", "documentTemplateMaxBytes": 400, "searchParameters": { "limit": 20 } }
+            "chat": { "description": "A comprehensive movie database", "documentTemplateMaxBytes": 400, "searchParameters": { "limit": 20 } },
+            "reranker": null
         })),
         (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
             {
@@ -748,7 +790,10 @@ pub async fn get_all(
     summary = "Reset all settings",
     description = "Resets all settings of the index to their default values.",
     security(("Bearer" = ["settings.update", "settings.*", "*"])),
-    params(("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false)),
+    params(
+        ("index_uid" = String, example = "movies", description = "Unique identifier of the index.", nullable = false),
+        CustomMetadataQuery,
+    ),
     responses(
         (status = 202, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
             {
@@ -783,13 +828,22 @@ pub async fn get_all(
 pub async fn delete_all(
     index_scheduler: GuardedData<ActionPolicy<{ actions::SETTINGS_UPDATE }>, Data<IndexScheduler>>,
     index_uid: web::Path<String>,
+    params: AwebQueryParameter<CustomMetadataQuery, DeserrQueryParamError>,
     req: HttpRequest,
 ) -> Result<HttpResponse, ResponseError> {
     let index_uid = IndexUid::try_from(index_uid.into_inner())?;
 
     let new_settings = Settings::cleared().into_unchecked();
 
-    let task = register_new_settings(new_settings, true, index_scheduler, &req, index_uid).await?;
+    let task = register_new_settings(
+        new_settings,
+        true,
+        index_scheduler,
+        params.into_inner(),
+        &req,
+        index_uid,
+    )
+    .await?;
 
     debug!(returns = ?task, "Delete all settings");
     Ok(HttpResponse::Accepted().json(task))
@@ -11,7 +11,8 @@ use meilisearch_types::milli::update::Setting;
 use meilisearch_types::milli::{FilterableAttributesRule, ForeignKey};
 use meilisearch_types::settings::{
     ChatSettings, FacetingSettings, PaginationSettings, PrefixSearchSettings,
-    ProximityPrecisionView, RankingRuleView, SettingEmbeddingSettings, TypoSettings,
+    ProximityPrecisionView, RankingRuleView, RerankerSettings, SettingEmbeddingSettings,
+    TypoSettings,
 };
 use serde::Serialize;
 
@@ -41,6 +42,7 @@ pub struct SettingsAnalytics {
     pub facet_search: FacetSearchAnalytics,
     pub prefix_search: PrefixSearchAnalytics,
     pub chat: ChatAnalytics,
+    pub reranker: RerankerAnalytics,
 }
 
 impl Aggregate for SettingsAnalytics {
@@ -213,6 +215,10 @@ impl Aggregate for SettingsAnalytics {
                 value: new.prefix_search.value.or(self.prefix_search.value),
             },
             chat: ChatAnalytics { set: new.chat.set | self.chat.set },
+            reranker: RerankerAnalytics {
+                set: new.reranker.set | self.reranker.set,
+                document_fields: new.reranker.document_fields.or(self.reranker.document_fields),
+            },
         })
     }
 
@@ -734,3 +740,23 @@ impl ChatAnalytics {
         SettingsAnalytics { chat: self, ..Default::default() }
     }
 }
+
+#[derive(Serialize, Default)]
+pub struct RerankerAnalytics {
+    pub set: bool,
+    pub document_fields: Option<bool>,
+}
+
+impl RerankerAnalytics {
+    pub fn new(settings: Option<&RerankerSettings>) -> Self {
+        Self {
+            set: settings.is_some(),
+            document_fields: settings
+                .map(|settings| settings.document_fields.as_ref().set().is_some()),
+        }
+    }
+
+    pub fn into_settings(self) -> SettingsAnalytics {
+        SettingsAnalytics { reranker: self, ..Default::default() }
+    }
+}
@@ -20,7 +20,7 @@ use crate::analytics::Analytics;
 use crate::extractors::authentication::GuardedData;
 use crate::routes::indexes::similar_analytics::{SimilarAggregator, SimilarGET, SimilarPOST};
 use crate::search::{
-    add_search_rules, perform_similar, RankingScoreThresholdSimilar, SimilarQuery, SimilarResult,
+    perform_similar, RankingScoreThresholdSimilar, SimilarQuery, SimilarResult,
     DEFAULT_SEARCH_LIMIT, DEFAULT_SEARCH_OFFSET,
 };
 
@@ -212,7 +212,7 @@ async fn similar(
 
     // Tenant token search_rules.
     if let Some(search_rules) = search_rules.clone() {
-        add_search_rules(&mut query.filter, search_rules);
+        query.add_search_rules(search_rules)?;
     }
 
     let progress_clone = progress.clone();
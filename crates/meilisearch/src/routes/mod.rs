@@ -4,13 +4,15 @@ use std::sync::atomic::Ordering;
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
 use deserr::actix_web::AwebQueryParameter;
+use deserr::Deserr;
 use export::Export;
 use index_scheduler::IndexScheduler;
-use meilisearch_auth::AuthController;
+use meilisearch_auth::{AuthController, AuthFilter};
 use meilisearch_types::batch_view::BatchView;
 use meilisearch_types::batches::BatchStats;
 use meilisearch_types::deserr::query_params::Param;
 use meilisearch_types::deserr::DeserrQueryParamError;
+use meilisearch_types::error::deserr_codes::InvalidTaskPriority;
 use meilisearch_types::error::{Code, ErrorType, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::keys::CreateApiKey;
@@ -18,18 +20,20 @@ use meilisearch_types::milli::{
     AttributePatterns, FilterFeatures, FilterableAttributesFeatures, FilterableAttributesPatterns,
     FilterableAttributesRule,
 };
+use meilisearch_types::schedules::Schedule;
 use meilisearch_types::settings::{
     Checked, FacetingSettings, MinWordSizeTyposSetting, PaginationSettings, Settings, TypoSettings,
     Unchecked,
 };
 use meilisearch_types::task_view::{DetailsView, TaskView};
-use meilisearch_types::tasks::{Kind, Status, Task, TaskId};
+use meilisearch_types::tasks::{Kind, Status, Task, TaskId, TaskPriority};
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tracing::debug;
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 use self::api_key::KeyView;
+use self::dump::{CreateDump, ImportDump};
 use self::indexes::documents::BrowseQuery;
 use self::indexes::{IndexCreateRequest, IndexStats, UpdateIndexRequest};
 use self::logs::{GetLogs, LogMode, UpdateStderrLogs};
@@ -46,6 +50,7 @@ use crate::routes::indexes::{
 };
 use crate::routes::multi_search::SearchResults;
 use crate::routes::network::{Network, Remote, Shard};
+use crate::routes::schedules::{ScheduleResults, ScheduleSettings, ScheduledTaskSettings};
 use crate::routes::swap_indexes::SwapIndexesPayload;
 use crate::routes::webhooks::{
     WebhookResults, WebhookSettings, WebhookWithMetadataRedactedAuthorization,
@@ -77,6 +82,8 @@ pub mod network;
 mod open_api_utils;
 pub mod render;
 mod render_analytics;
+mod schedules;
+mod signing_keys;
 mod snapshot;
 mod swap_indexes;
 pub mod tasks;
@@ -97,6 +104,7 @@ mod webhooks;
         "/snapshots"=> sub(snapshot::SnapshotApi),
         "/dumps"=> sub(dump::DumpApi),
         "/keys"=> sub(api_key::ApiKeyApi),
+        "/signing-keys"=> sub(signing_keys::SigningKeysApi),
         "/metrics"=> sub(metrics::MetricApi),
         "/logs"=> sub(logs::LogsApi),
         "/multi-search"=> sub(multi_search::MultiSearchApi),
@@ -105,6 +113,7 @@ mod webhooks;
         "/export"=> sub(export::ExportApi),
         "/network"=> sub(network::NetworkApi),
         "/webhooks"=> sub(webhooks::WebhooksApi),
+        "/schedules"=> sub(schedules::SchedulesApi),
         "/dynamic-search-rules"=> sub(dynamic_search_rules::DynamicSearchRulesApi),
         "/render-template" => sub(render::RenderApi),
     ),
@@ -122,7 +131,7 @@ mod webhooks;
         url = "http://localhost:7700",
         description = "Local server.",
     )),
-    components(schemas(PaginationView<KeyView>, PaginationView<IndexView>, IndexView, DocumentDeletionByFilter, AllBatches, BatchStats, ProgressStepView, ProgressView, BatchView, RuntimeTogglableFeatures, SwapIndexesPayload, DocumentEditionByFunction, MergeFacets, FederationOptions, SearchQueryWithIndex, Federation, FederatedSearch, FederatedSearchResult, SearchResults, SearchResultWithIndex, SimilarQuery, SimilarResult, PaginationView<serde_json::Value>, BrowseQuery, UpdateIndexRequest, IndexUid, IndexCreateRequest, KeyView, Action, CreateApiKey, UpdateStderrLogs, LogMode, GetLogs, IndexStats, Stats, HealthStatus, HealthResponse, VersionResponse, Code, ErrorType, AllTasks, TaskView, Status, DetailsView, ResponseError, Settings<Unchecked>, Settings<Checked>, TypoSettings, MinWordSizeTyposSetting, FacetingSettings, PaginationSettings, SummarizedTaskView, Kind, Network, Remote, Shard, FilterableAttributesRule, FilterableAttributesPatterns, AttributePatterns, FilterableAttributesFeatures, FilterFeatures, Export, WebhookSettings, WebhookResults, WebhookWithMetadataRedactedAuthorization, ListFields, ListFieldsFilter, SizeFormat))
+    components(schemas(PaginationView<KeyView>, PaginationView<IndexView>, IndexView, DocumentDeletionByFilter, AllBatches, BatchStats, ProgressStepView, ProgressView, BatchView, RuntimeTogglableFeatures, SwapIndexesPayload, DocumentEditionByFunction, MergeFacets, FederationOptions, SearchQueryWithIndex, Federation, FederatedSearch, FederatedSearchResult, SearchResults, SearchResultWithIndex, SimilarQuery, SimilarResult, PaginationView<serde_json::Value>, BrowseQuery, UpdateIndexRequest, IndexUid, IndexCreateRequest, KeyView, Action, CreateApiKey, UpdateStderrLogs, LogMode, GetLogs, IndexStats, Stats, HealthStatus, HealthResponse, VersionResponse, Code, ErrorType, AllTasks, TaskView, Status, DetailsView, ResponseError, Settings<Unchecked>, Settings<Checked>, TypoSettings, MinWordSizeTyposSetting, FacetingSettings, PaginationSettings, SummarizedTaskView, Kind, TaskPriority, Network, Remote, Shard, FilterableAttributesRule, FilterableAttributesPatterns, AttributePatterns, FilterableAttributesFeatures, FilterFeatures, Export, CreateDump, ImportDump, WebhookSettings, WebhookResults, WebhookWithMetadataRedactedAuthorization, ScheduleSettings, ScheduledTaskSettings, ScheduleResults, Schedule, ListFields, ListFieldsFilter, SizeFormat))
 )]
 pub struct MeilisearchApi;
 
@@ -139,6 +148,37 @@ pub fn parse_include_metadata_header(req: &HttpRequest) -> bool {
         .unwrap_or(false)
 }
 
+/// The query parameters of the routes enqueuing a task that can be prioritized.
+#[derive(Debug, Deserr, IntoParams)]
+#[deserr(error = DeserrQueryParamError, rename_all = camelCase, deny_unknown_fields)]
+#[into_params(parameter_in = Query, rename_all = "camelCase")]
+pub struct TaskPriorityQuery {
+    /// Priority of the enqueued task: `low`, `normal` or `high`. Any priority other than
+    /// `normal` requires the `tasks.prioritize` action.
+    #[param(required = false, value_type = TaskPriority, example = "high")]
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskPriority>)]
+    pub priority: Option<Param<TaskPriority>>,
+}
+
+/// Check that the API key is allowed to enqueue a task with the requested priority.
+///
+/// Returns the priority to register the task with, `normal` when none was requested.
+pub fn check_task_priority(
+    filters: &AuthFilter,
+    priority: Option<Param<TaskPriority>>,
+) -> Result<TaskPriority, ResponseError> {
+    let priority = priority.map(|p| p.0).unwrap_or_default();
+    if !priority.is_normal() && !filters.allow_task_prioritization() {
+        return Err(ResponseError::from_msg(
+            format!(
+                "The API key is not allowed to enqueue tasks with the `{priority}` priority.\n  - Hint: the key requires the `tasks.prioritize` action."
+            ),
+            Code::TaskPriorityNotAllowed,
+        ));
+    }
+    Ok(priority)
+}
+
 /// A summarized view of a task, returned when a task is enqueued
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -164,6 +204,9 @@ pub struct SummarizedTaskView {
     /// tasks with external systems or add application-specific information.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom_metadata: Option<String>,
+    /// Priority of the task. Omitted when the task has the `normal` priority.
+    #[serde(default, skip_serializing_if = "TaskPriority::is_normal")]
+    pub priority: TaskPriority,
 }
 
 impl From<Task> for SummarizedTaskView {
@@ -175,6 +218,7 @@ impl From<Task> for SummarizedTaskView {
             kind: task.kind.as_kind(),
             enqueued_at: task.enqueued_at,
             custom_metadata: task.custom_metadata,
+            priority: task.priority,
         }
     }
 }
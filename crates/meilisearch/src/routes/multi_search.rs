@@ -25,8 +25,8 @@ use crate::search::federated::types::PreprocessedQuery;
 use crate::search::federated::NetworkPartitioner;
 use crate::search::proxy::{PROXY_SEARCH_HEADER, PROXY_SEARCH_HEADER_VALUE};
 use crate::search::{
-    add_search_rules, perform_federated_search, FederatedSearch, FederatedSearchResult,
-    SearchQueryWithIndex, SearchResultWithIndex, ShowFederationInfo,
+    perform_federated_search, FederatedSearch, FederatedSearchResult, SearchQueryWithIndex,
+    SearchResultWithIndex, ShowFederationInfo,
 };
 use crate::search_queue::SearchQueue;
 
@@ -259,7 +259,7 @@ pub async fn legacy_multi_search_with_post(
 
     let mut multi_aggregate = MultiSearchAggregator::from_federated_search(&federated_search);
 
-    let FederatedSearch { mut queries, federation } = federated_search;
+    let FederatedSearch { mut queries, mut federation } = federated_search;
 
     debug!(
         request_uid = ?request_uid,
@@ -283,8 +283,17 @@ pub async fn legacy_multi_search_with_post(
             // Apply search rules from tenant token
             if let Some(search_rules) = index_scheduler.filters().get_index_search_rules(index_uid)
             {
-                add_search_rules(&mut federated_query.filter, search_rules);
+                if let Some(federation) = &mut federation {
+                    federation.add_search_rules(index_uid, &search_rules);
+                }
+                if let Err(error) =
+                    federated_query.add_search_rules(search_rules, federation.is_some())
+                {
+                    break 'check_authorization Err(error).with_index(query_index);
+                }
             }
+            federated_query.tenant_token_claims =
+                index_scheduler.filters().tenant_token_claims().cloned();
         }
         Ok(())
     };
@@ -55,15 +55,20 @@ impl MultiSearchAggregator {
             show_ranking_score: _,
             show_ranking_score_details: _,
             show_performance_details: _,
+            tenant_token_claims: _,
+            tenant_token_attributes: _,
             show_matches_position: _,
             filter: _,
             sort: _,
             distinct: _,
             facets: _,
+            facet_aggregations: _,
             highlight_pre_tag: _,
             highlight_post_tag: _,
             crop_marker: _,
             matching_strategy: _,
+            advanced_query_syntax: _,
+            suggestions: _,
             attributes_to_search_on: _,
             hybrid: _,
             ranking_score_threshold: _,
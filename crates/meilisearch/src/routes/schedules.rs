@@ -0,0 +1,627 @@
+use core::convert::Infallible;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use actix_web::web::{self, Data, Path};
+use actix_web::{HttpRequest, HttpResponse};
+use deserr::actix_web::AwebJson;
+use deserr::{DeserializeError, ValuePointerRef};
+use index_scheduler::IndexScheduler;
+use meilisearch_auth::{AuthController, AuthFilter};
+use meilisearch_types::deserr::{immutable_field_error, DeserrJsonError};
+use meilisearch_types::error::deserr_codes::{
+    BadRequest, InvalidScheduleCron, InvalidScheduleEnabled, InvalidScheduleTask,
+};
+use meilisearch_types::error::{Code, ErrorCode, ResponseError};
+use meilisearch_types::index_uid::IndexUid;
+use meilisearch_types::index_uid_pattern::IndexUidPattern;
+use meilisearch_types::keys::{actions, Action};
+use meilisearch_types::milli::update::Setting;
+use meilisearch_types::schedules::{CronSchedule, ParseCronError, Schedule, ScheduledTask};
+use meilisearch_types::tasks::{ExportIndexSettings as DbExportIndexSettings, Kind};
+use serde::Serialize;
+use time::OffsetDateTime;
+use tracing::debug;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use SchedulesError::*;
+
+use crate::analytics::{Aggregate, Analytics};
+use crate::extractors::authentication::policies::ActionPolicy;
+use crate::extractors::authentication::GuardedData;
+use crate::routes::export::{ByteWithDeserr, ExportIndexSettings};
+
+/// The maximum number of schedules that can be defined on an instance.
+const MAX_SCHEDULES: usize = 100;
+
+#[routes::routes(
+    routes(
+        "" => [get(get_schedules), post(post_schedule)],
+        "/{uid}" => [get(get_schedule), patch(patch_schedule), delete(delete_schedule)],
+    ),
+    tag = "Schedules",
+    tags((
+        name = "Schedules",
+        description = "The `/schedules` route allows you to enqueue dumps, snapshots, exports, index compactions and task deletions automatically, following a cron expression.",
+    )),
+)]
+pub struct SchedulesApi;
+
+/// Configuration of a schedule
+#[routes::request(deny_unknown_fields = deny_immutable_fields_schedule)]
+#[derive(Debug)]
+pub(super) struct ScheduleSettings {
+    /// Cron expression telling when the task must be enqueued, evaluated in UTC.
+    ///
+    /// The five usual fields are supported: minute, hour, day of month, month and day of week,
+    /// as well as the `@yearly`, `@monthly`, `@weekly`, `@daily` and `@hourly` shorthands.
+    #[request(default, error = DeserrJsonError<InvalidScheduleCron>, schema_type = Option<String>, example = "0 3 * * *")]
+    cron: Setting<String>,
+    /// The task to enqueue.
+    #[request(default, error = DeserrJsonError<InvalidScheduleTask>, schema_type = Option<ScheduledTaskSettings>, example = json!({ "type": "dumpCreation" }))]
+    task: Setting<ScheduledTaskSettings>,
+    /// Whether the schedule enqueues tasks. Defaults to `true`.
+    #[request(default, error = DeserrJsonError<InvalidScheduleEnabled>, schema_type = Option<bool>, example = json!(true))]
+    enabled: Setting<bool>,
+}
+
+fn deny_immutable_fields_schedule(
+    field: &str,
+    accepted: &[&str],
+    location: ValuePointerRef,
+) -> DeserrJsonError {
+    match field {
+        "uid" => immutable_field_error(field, accepted, Code::ImmutableScheduleUid),
+        _ => deserr::take_cf_content(DeserrJsonError::<BadRequest>::error::<Infallible>(
+            None,
+            deserr::ErrorKind::UnknownKey { key: field, accepted },
+            location,
+        )),
+    }
+}
+
+/// Template of the task enqueued by a schedule
+#[routes::request(override_error = DeserrJsonError<InvalidScheduleTask>)]
+#[derive(Debug)]
+pub(super) struct ScheduledTaskSettings {
+    /// Type of the task.
+    #[request(required, rename = "type", error = DeserrJsonError<InvalidScheduleTask>)]
+    kind: ScheduledTaskType,
+    /// Index to compact. Required for `indexCompaction`.
+    #[request(default, error = DeserrJsonError<InvalidScheduleTask>, schema_type = Option<String>, example = json!("movies"))]
+    index_uid: Option<IndexUid>,
+    /// URL of the destination Meilisearch instance. Required for `export`.
+    #[request(default, error = DeserrJsonError<InvalidScheduleTask>, example = json!("https://ms-1234.heaven.meilisearch.com"))]
+    url: Option<String>,
+    /// API key for authenticating with the destination instance. Only for `export`.
+    #[request(default, error = DeserrJsonError<InvalidScheduleTask>, example = json!("1234abcd"))]
+    api_key: Option<String>,
+    /// Maximum payload size per request. Only for `export`.
+    #[request(default, error = DeserrJsonError<InvalidScheduleTask>, example = json!("24MiB"), schema_type = Option<String>)]
+    payload_size: Option<ByteWithDeserr>,
+    /// Index patterns to export with their settings. Only for `export`, all the indexes are exported when omitted.
+    #[request(default, error = DeserrJsonError, example = json!({ "*": { "filter": null } }), schema_type = Option<BTreeMap<String, ExportIndexSettings>>)]
+    indexes: Option<BTreeMap<IndexUidPattern, ExportIndexSettings>>,
+    /// The finished tasks that finished more than this number of days before the run are deleted. Required for `taskDeletion`.
+    #[request(default, error = DeserrJsonError<InvalidScheduleTask>, example = json!(30))]
+    older_than_days: Option<u32>,
+}
+
+#[routes::request(override_error = DeserrJsonError<InvalidScheduleTask>)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum ScheduledTaskType {
+    /// Creates a dump of the instance.
+    DumpCreation,
+    /// Creates a snapshot of the instance.
+    SnapshotCreation,
+    /// Exports documents and settings to a remote Meilisearch.
+    ///
+    /// - Requires `url` to be present
+    /// - Accepts `apiKey`, `payloadSize` and `indexes`
+    Export,
+    /// Compacts an index.
+    ///
+    /// - Requires `indexUid` to be present
+    IndexCompaction,
+    /// Deletes the old finished tasks.
+    ///
+    /// - Requires `olderThanDays` to be present
+    TaskDeletion,
+}
+
+impl fmt::Display for ScheduledTaskType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduledTaskType::DumpCreation => f.write_str("dumpCreation"),
+            ScheduledTaskType::SnapshotCreation => f.write_str("snapshotCreation"),
+            ScheduledTaskType::Export => f.write_str("export"),
+            ScheduledTaskType::IndexCompaction => f.write_str("indexCompaction"),
+            ScheduledTaskType::TaskDeletion => f.write_str("taskDeletion"),
+        }
+    }
+}
+
+impl ScheduledTaskSettings {
+    fn into_scheduled_task(self) -> Result<ScheduledTask, SchedulesError> {
+        let ScheduledTaskSettings {
+            kind,
+            index_uid,
+            url,
+            api_key,
+            payload_size,
+            indexes,
+            older_than_days,
+        } = self;
+
+        let accepted: &[&str] = match kind {
+            ScheduledTaskType::DumpCreation | ScheduledTaskType::SnapshotCreation => &[],
+            ScheduledTaskType::Export => &["url", "apiKey", "payloadSize", "indexes"],
+            ScheduledTaskType::IndexCompaction => &["indexUid"],
+            ScheduledTaskType::TaskDeletion => &["olderThanDays"],
+        };
+        let present = [
+            ("indexUid", index_uid.is_some()),
+            ("url", url.is_some()),
+            ("apiKey", api_key.is_some()),
+            ("payloadSize", payload_size.is_some()),
+            ("indexes", indexes.is_some()),
+            ("olderThanDays", older_than_days.is_some()),
+        ];
+        if let Some((field, _)) =
+            present.into_iter().find(|(field, present)| *present && !accepted.contains(field))
+        {
+            return Err(UnexpectedTaskField { kind, field });
+        }
+
+        Ok(match kind {
+            ScheduledTaskType::DumpCreation => ScheduledTask::DumpCreation,
+            ScheduledTaskType::SnapshotCreation => ScheduledTask::SnapshotCreation,
+            ScheduledTaskType::Export => ScheduledTask::Export {
+                url: url.ok_or(MissingTaskField { kind, field: "url" })?,
+                api_key,
+                payload_size: payload_size.map(|ByteWithDeserr(bytes)| bytes),
+                indexes: match indexes {
+                    Some(indexes) => indexes
+                        .into_iter()
+                        .map(|(pattern, ExportIndexSettings { filter, override_settings })| {
+                            (pattern, DbExportIndexSettings { filter, override_settings })
+                        })
+                        .collect(),
+                    None => BTreeMap::from([(
+                        IndexUidPattern::new_unchecked("*"),
+                        DbExportIndexSettings::default(),
+                    )]),
+                },
+            },
+            ScheduledTaskType::IndexCompaction => ScheduledTask::IndexCompaction {
+                index_uid: index_uid
+                    .ok_or(MissingTaskField { kind, field: "indexUid" })?
+                    .into_inner(),
+            },
+            ScheduledTaskType::TaskDeletion => ScheduledTask::TaskDeletion {
+                older_than_days: older_than_days
+                    .ok_or(MissingTaskField { kind, field: "olderThanDays" })?,
+            },
+        })
+    }
+}
+
+/// Response containing a list of all the schedules.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ScheduleResults {
+    /// All the schedules of the instance, sorted by creation date. The API keys of the exports are redacted.
+    results: Vec<Schedule>,
+}
+
+fn redacted(mut schedule: Schedule) -> Schedule {
+    schedule.task.redact_api_key();
+    schedule
+}
+
+#[derive(Debug, thiserror::Error)]
+enum SchedulesError {
+    #[error("The cron expression of the schedule is missing.")]
+    MissingCron,
+    #[error("{0}")]
+    InvalidCron(ParseCronError),
+    #[error("The cron expression `{0}` never matches.")]
+    CronNeverMatches(String),
+    #[error("The task of the schedule is missing.")]
+    MissingTask,
+    #[error("`{field}` is required for the `{kind}` task.")]
+    MissingTaskField { kind: ScheduledTaskType, field: &'static str },
+    #[error("`{field}` cannot be used with the `{kind}` task.")]
+    UnexpectedTaskField { kind: ScheduledTaskType, field: &'static str },
+    #[error("Defining too many schedules would crush the server. Please limit the number of schedules to {MAX_SCHEDULES}.")]
+    TooManySchedules,
+    #[error("Invalid UUID: {0}")]
+    InvalidUuid(uuid::Error),
+    #[error("The API key is not allowed to schedule the `{kind}` task{}. It requires the `{action}` action.", index.as_ref().map(|index| format!(" on `{index}`")).unwrap_or_default())]
+    UnauthorizedTask { kind: Kind, action: &'static str, index: Option<String> },
+}
+
+impl ErrorCode for SchedulesError {
+    fn error_code(&self) -> Code {
+        match self {
+            MissingCron | InvalidCron(_) | CronNeverMatches(_) => Code::InvalidScheduleCron,
+            MissingTask | MissingTaskField { .. } | UnexpectedTaskField { .. } => {
+                Code::InvalidScheduleTask
+            }
+            TooManySchedules => Code::InvalidSchedules,
+            InvalidUuid(_) => Code::InvalidScheduleUid,
+            UnauthorizedTask { .. } => Code::InvalidApiKey,
+        }
+    }
+}
+
+/// Parses the cron expression and checks that it matches at least once.
+fn parse_cron(cron: &str, now: OffsetDateTime) -> Result<CronSchedule, SchedulesError> {
+    let cron: CronSchedule = cron.parse().map_err(InvalidCron)?;
+    if cron.next_after(now).is_none() {
+        return Err(CronNeverMatches(cron.to_string()));
+    }
+    Ok(cron)
+}
+
+/// Checks that the API key could enqueue the task of the schedule by itself,
+/// so that a schedule can't be used to run a task the key is not allowed to.
+fn check_task_authorized(
+    auth_controller: &AuthController,
+    filters: &AuthFilter,
+    task: &ScheduledTask,
+) -> Result<(), ResponseError> {
+    let (action, action_name, indexes): (_, _, Vec<&str>) = match task {
+        ScheduledTask::DumpCreation => (Action::DumpsCreate, "dumps.create", Vec::new()),
+        ScheduledTask::SnapshotCreation => {
+            (Action::SnapshotsCreate, "snapshots.create", Vec::new())
+        }
+        ScheduledTask::Export { indexes, .. } => {
+            (Action::Export, "export", indexes.keys().map(|pattern| &**pattern).collect())
+        }
+        ScheduledTask::IndexCompaction { index_uid } => {
+            (Action::IndexesCompact, "indexes.compact", vec![index_uid.as_str()])
+        }
+        // the deleted tasks belong to all the indexes
+        ScheduledTask::TaskDeletion { .. } => (Action::TasksDelete, "tasks.delete", vec!["*"]),
+    };
+    let unauthorized = |index: Option<&str>| UnauthorizedTask {
+        kind: task.kind(),
+        action: action_name,
+        index: index.map(String::from),
+    };
+
+    if indexes.is_empty() && !auth_controller.is_filter_authorized(filters, action, None)? {
+        return Err(unauthorized(None).into());
+    }
+    for index in indexes {
+        if !filters.is_index_authorized(index)
+            || !auth_controller.is_filter_authorized(filters, action, Some(index))?
+        {
+            return Err(unauthorized(Some(index)).into());
+        }
+    }
+    Ok(())
+}
+
+#[derive(Serialize, Default)]
+pub struct PostSchedulesAnalytics;
+
+impl Aggregate for PostSchedulesAnalytics {
+    fn event_name(&self) -> &'static str {
+        "Schedules Created"
+    }
+
+    fn aggregate(self: Box<Self>, _new: Box<Self>) -> Box<Self> {
+        self
+    }
+
+    fn into_event(self: Box<Self>) -> serde_json::Value {
+        serde_json::to_value(*self).unwrap_or_default()
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct PatchSchedulesAnalytics;
+
+impl Aggregate for PatchSchedulesAnalytics {
+    fn event_name(&self) -> &'static str {
+        "Schedules Updated"
+    }
+
+    fn aggregate(self: Box<Self>, _new: Box<Self>) -> Box<Self> {
+        self
+    }
+
+    fn into_event(self: Box<Self>) -> serde_json::Value {
+        serde_json::to_value(*self).unwrap_or_default()
+    }
+}
+
+/// List schedules
+///
+/// Return all the schedules of the instance, with the date of their next run and the last task they enqueued.
+#[routes::path(
+    security(("Bearer" = ["schedules.get", "schedules.*", "*.get", "*"])),
+    responses(
+        (status = OK, description = "Schedules are returned.", body = ScheduleResults, content_type = "application/json", example = json!({
+            "results": [
+                {
+                    "uid": "550e8400-e29b-41d4-a716-446655440000",
+                    "cron": "0 3 * * *",
+                    "task": { "type": "dumpCreation" },
+                    "enabled": true,
+                    "createdAt": "2024-08-08T16:37:09.971Z",
+                    "nextRunAt": "2024-08-09T03:00:00Z",
+                    "lastRunAt": null,
+                    "lastTaskUid": null
+                }
+            ]
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    )
+)]
+async fn get_schedules(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SCHEDULES_GET }>, Data<IndexScheduler>>,
+) -> Result<HttpResponse, ResponseError> {
+    let results = index_scheduler.schedules()?.into_iter().map(redacted).collect();
+    let results = ScheduleResults { results };
+
+    debug!(returns = ?results, "Get schedules");
+    Ok(HttpResponse::Ok().json(results))
+}
+
+/// Get schedule
+///
+/// Retrieve a single schedule by its UID.
+#[routes::path(
+    security(("Bearer" = ["schedules.get", "schedules.*", "*.get", "*"])),
+    responses(
+        (status = 200, description = "Schedule found.", body = Schedule, content_type = "application/json", example = json!({
+            "uid": "550e8400-e29b-41d4-a716-446655440000",
+            "cron": "0 3 * * *",
+            "task": { "type": "taskDeletion", "olderThanDays": 30 },
+            "enabled": true,
+            "createdAt": "2024-08-08T16:37:09.971Z",
+            "nextRunAt": "2024-08-10T03:00:00Z",
+            "lastRunAt": "2024-08-09T03:00:00Z",
+            "lastTaskUid": 12
+        })),
+        (status = 404, description = "Schedule not found.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "Schedule `550e8400-e29b-41d4-a716-446655440000` not found.",
+            "code": "schedule_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#schedule_not_found"
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    ),
+    params(
+        ("uid" = Uuid, Path, description = "Universally unique identifier of the schedule.")
+    )
+)]
+async fn get_schedule(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SCHEDULES_GET }>, Data<IndexScheduler>>,
+    uid: Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let uid = Uuid::from_str(&uid.into_inner()).map_err(InvalidUuid)?;
+    let schedule = redacted(index_scheduler.schedule(uid)?);
+
+    debug!(returns = ?schedule, "Get schedule");
+    Ok(HttpResponse::Ok().json(schedule))
+}
+
+/// Create schedule
+///
+/// Register a task to be enqueued automatically each time the cron expression matches.
+/// Each task enqueued by a schedule contains the `scheduleUid` of the schedule.
+#[routes::path(
+    request_body = ScheduleSettings,
+    security(("Bearer" = ["schedules.create", "schedules.*", "*"])),
+    responses(
+        (status = 201, description = "Schedule created successfully.", body = Schedule, content_type = "application/json", example = json!({
+            "uid": "550e8400-e29b-41d4-a716-446655440000",
+            "cron": "0 3 * * *",
+            "task": { "type": "dumpCreation" },
+            "enabled": true,
+            "createdAt": "2024-08-08T16:37:09.971Z",
+            "nextRunAt": "2024-08-09T03:00:00Z",
+            "lastRunAt": null,
+            "lastTaskUid": null
+        })),
+        (status = 400, description = "Bad request.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "Invalid cron expression `0 25 * * *`: `25` is not a valid hour, expected a value between 0 and 23",
+            "code": "invalid_schedule_cron",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#invalid_schedule_cron"
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    )
+)]
+async fn post_schedule(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SCHEDULES_CREATE }>, Data<IndexScheduler>>,
+    auth_controller: Data<AuthController>,
+    schedule_settings: AwebJson<ScheduleSettings, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: Data<Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let schedule_settings = schedule_settings.into_inner();
+    debug!(parameters = ?schedule_settings, "Post schedule");
+
+    if index_scheduler.schedules()?.len() >= MAX_SCHEDULES {
+        return Err(TooManySchedules.into());
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let ScheduleSettings { cron, task, enabled } = schedule_settings;
+    let mut schedule = Schedule {
+        uid: Uuid::new_v4(),
+        cron: parse_cron(&cron.set().ok_or(MissingCron)?, now)?,
+        task: task.set().ok_or(MissingTask)?.into_scheduled_task()?,
+        enabled: enabled.set().unwrap_or(true),
+        created_at: now,
+        next_run_at: None,
+        last_run_at: None,
+        last_task_uid: None,
+    };
+    check_task_authorized(&auth_controller, index_scheduler.filters(), &schedule.task)?;
+    schedule.plan_next_run(now);
+    index_scheduler.put_schedule(&schedule)?;
+
+    analytics.publish(PostSchedulesAnalytics, &req);
+
+    let response = redacted(schedule);
+    debug!(returns = ?response, "Post schedule");
+    Ok(HttpResponse::Created().json(response))
+}
+
+/// Update schedule
+///
+/// Update the cron expression, the task or the status of an existing schedule.
+/// The task is replaced as a whole. The next run is computed again when the cron expression or the status change.
+#[routes::path(
+    request_body = ScheduleSettings,
+    security(("Bearer" = ["schedules.update", "schedules.*", "*"])),
+    responses(
+        (status = 200, description = "Schedule updated successfully.", body = Schedule, content_type = "application/json", example = json!({
+            "uid": "550e8400-e29b-41d4-a716-446655440000",
+            "cron": "0 3 * * *",
+            "task": { "type": "dumpCreation" },
+            "enabled": false,
+            "createdAt": "2024-08-08T16:37:09.971Z",
+            "nextRunAt": null,
+            "lastRunAt": null,
+            "lastTaskUid": null
+        })),
+        (status = 400, description = "Bad request.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "`indexUid` is required for the `indexCompaction` task.",
+            "code": "invalid_schedule_task",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#invalid_schedule_task"
+        })),
+        (status = 404, description = "Schedule not found.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "Schedule `550e8400-e29b-41d4-a716-446655440000` not found.",
+            "code": "schedule_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#schedule_not_found"
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    ),
+    params(
+        ("uid" = Uuid, Path, description = "Universally unique identifier of the schedule.")
+    )
+)]
+async fn patch_schedule(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SCHEDULES_UPDATE }>, Data<IndexScheduler>>,
+    auth_controller: Data<AuthController>,
+    uid: Path<String>,
+    schedule_settings: AwebJson<ScheduleSettings, DeserrJsonError>,
+    req: HttpRequest,
+    analytics: Data<Analytics>,
+) -> Result<HttpResponse, ResponseError> {
+    let uid = Uuid::from_str(&uid.into_inner()).map_err(InvalidUuid)?;
+    let schedule_settings = schedule_settings.into_inner();
+    debug!(parameters = ?(uid, &schedule_settings), "Patch schedule");
+
+    let now = OffsetDateTime::now_utc();
+    let mut schedule = index_scheduler.schedule(uid)?;
+    let ScheduleSettings { cron, task, enabled } = schedule_settings;
+    let mut plan_next_run = false;
+
+    match cron {
+        Setting::Set(cron) => {
+            schedule.cron = parse_cron(&cron, now)?;
+            plan_next_run = true;
+        }
+        Setting::Reset => return Err(MissingCron.into()),
+        Setting::NotSet => (),
+    }
+    match task {
+        Setting::Set(task) => {
+            let task = task.into_scheduled_task()?;
+            check_task_authorized(&auth_controller, index_scheduler.filters(), &task)?;
+            schedule.task = task;
+        }
+        Setting::Reset => return Err(MissingTask.into()),
+        Setting::NotSet => (),
+    }
+    match enabled {
+        Setting::Set(enabled) => {
+            plan_next_run |= schedule.enabled != enabled;
+            schedule.enabled = enabled;
+        }
+        Setting::Reset => {
+            plan_next_run |= !schedule.enabled;
+            schedule.enabled = true;
+        }
+        Setting::NotSet => (),
+    }
+
+    if plan_next_run {
+        schedule.plan_next_run(now);
+    }
+    index_scheduler.put_schedule(&schedule)?;
+
+    analytics.publish(PatchSchedulesAnalytics, &req);
+
+    let response = redacted(schedule);
+    debug!(returns = ?response, "Patch schedule");
+    Ok(HttpResponse::Ok().json(response))
+}
+
+/// Delete schedule
+///
+/// Permanently remove a schedule by its UID. The tasks it already enqueued are left untouched.
+#[routes::path(
+    security(("Bearer" = ["schedules.delete", "schedules.*", "*"])),
+    responses(
+        (status = 204, description = "Schedule deleted successfully."),
+        (status = 404, description = "Schedule not found.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "Schedule `550e8400-e29b-41d4-a716-446655440000` not found.",
+            "code": "schedule_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#schedule_not_found"
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    ),
+    params(
+        ("uid" = Uuid, Path, description = "Universally unique identifier of the schedule.")
+    )
+)]
+async fn delete_schedule(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::SCHEDULES_DELETE }>, Data<IndexScheduler>>,
+    uid: Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let uid = Uuid::from_str(&uid.into_inner()).map_err(InvalidUuid)?;
+    debug!(parameters = ?uid, "Delete schedule");
+
+    index_scheduler.delete_schedule(uid)?;
+
+    debug!(returns = "No Content", "Delete schedule");
+    Ok(HttpResponse::NoContent().finish())
+}
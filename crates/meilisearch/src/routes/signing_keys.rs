@@ -0,0 +1,220 @@
+use actix_web::web::{self, Data};
+use actix_web::HttpResponse;
+use deserr::actix_web::AwebJson;
+use meilisearch_auth::AuthController;
+use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::keys::{CreateSigningKey, SigningKey};
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use utoipa::ToSchema;
+
+use crate::extractors::authentication::policies::*;
+use crate::extractors::authentication::GuardedData;
+
+#[routes::routes(
+    routes(
+        "" => [post(create_signing_key), get(list_signing_keys)],
+        "/{kid}" => [get(get_signing_key), delete(delete_signing_key)],
+    ),
+    tag = "Signing keys",
+    tags((
+        name = "Signing keys",
+        description = "Register the public keys of your identity provider. Tenant tokens signed with the matching private keys
+are verified by Meilisearch without sharing any secret: the `kid` header of the token designates the signing key to verify it with.",
+    )),
+)]
+pub struct SigningKeysApi;
+
+/// Response containing a list of all registered signing keys.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SigningKeyResults {
+    /// All the signing keys registered on the instance, the most recent first.
+    results: Vec<SigningKey>,
+}
+
+/// Create signing key
+///
+/// Register the public key of an identity provider with a key id (`kid`), the algorithm it signs tenant tokens
+/// with, and the actions and indexes the tokens signed with it may access.
+///
+/// The public key is given either PEM-encoded in `publicKey` or as a JSON Web Key in `jwk`.
+/// Only the `RS256`, `ES256` and `EdDSA` algorithms are supported.
+#[routes::path(
+    security(("Bearer" = ["keys.create", "keys.*", "*"])),
+    request_body = CreateSigningKey,
+    responses(
+        (status = 201, description = "Signing key has been registered.", body = SigningKey, content_type = "application/json", example = json!(
+            {
+                "kid": "my-idp-2024",
+                "algorithm": "EdDSA",
+                "publicKey": "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAdilE7LnzschuKNTNvSvCXGqcunMo88p7EKPeV1AwlIw=\n-----END PUBLIC KEY-----",
+                "actions": ["search"],
+                "indexes": ["products"],
+                "expiresAt": null,
+                "createdAt": "2021-11-12T10:00:00Z"
+            }
+        )),
+        (status = 409, description = "A signing key with this `kid` already exists.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "`kid` field value `my-idp-2024` is already an existing signing key.",
+                "code": "signing_key_already_exists",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#signing_key_already_exists"
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+pub async fn create_signing_key(
+    auth_controller: GuardedData<ActionPolicy<{ actions::KEYS_CREATE }>, Data<AuthController>>,
+    body: AwebJson<CreateSigningKey, DeserrJsonError>,
+) -> Result<HttpResponse, ResponseError> {
+    let create_key = body.into_inner();
+    debug!(parameters = ?create_key, "Create signing key");
+
+    if let Err(e) = signing_key_decoding_key(
+        create_key.algorithm,
+        create_key.public_key.as_deref(),
+        create_key.jwk.as_ref(),
+    ) {
+        return Err(ResponseError::from_msg(
+            format!("The public key is invalid or does not match the algorithm: {e}."),
+            Code::InvalidSigningKeyPublicKey,
+        ));
+    }
+
+    let key = tokio::task::spawn_blocking(move || auth_controller.create_signing_key(create_key))
+        .await
+        .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    debug!(returns = ?key, "Create signing key");
+    Ok(HttpResponse::Created().json(key))
+}
+
+/// List signing keys
+///
+/// Return all the signing keys registered on the instance, the most recent first.
+#[routes::path(
+    security(("Bearer" = ["keys.get", "keys.*", "*"])),
+    responses(
+        (status = 200, description = "List of signing keys.", body = SigningKeyResults, content_type = "application/json", example = json!(
+            {
+                "results": [
+                    {
+                        "kid": "my-idp-2024",
+                        "algorithm": "EdDSA",
+                        "publicKey": "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAdilE7LnzschuKNTNvSvCXGqcunMo88p7EKPeV1AwlIw=\n-----END PUBLIC KEY-----",
+                        "actions": ["search"],
+                        "indexes": ["products"],
+                        "expiresAt": null,
+                        "createdAt": "2021-11-12T10:00:00Z"
+                    }
+                ]
+            }
+        )),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "The Authorization header is missing. It must use the bearer authorization method.",
+                "code": "missing_authorization_header",
+                "type": "auth",
+                "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+            }
+        )),
+    )
+)]
+pub async fn list_signing_keys(
+    auth_controller: GuardedData<ActionPolicy<{ actions::KEYS_GET }>, Data<AuthController>>,
+) -> Result<HttpResponse, ResponseError> {
+    let results = tokio::task::spawn_blocking(move || auth_controller.list_signing_keys())
+        .await
+        .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    let results = SigningKeyResults { results };
+    debug!(returns = ?results, "List signing keys");
+    Ok(HttpResponse::Ok().json(results))
+}
+
+#[derive(Deserialize)]
+pub struct SigningKeyParam {
+    kid: String,
+}
+
+/// Get a signing key
+///
+/// Get a signing key by its key id.
+#[routes::path(
+    security(("Bearer" = ["keys.get", "keys.*", "*"])),
+    params(("kid" = String, Path, example = "my-idp-2024", description = "The key id of the signing key.", nullable = false)),
+    responses(
+        (status = 200, description = "The signing key is returned.", body = SigningKey, content_type = "application/json", example = json!(
+            {
+                "kid": "my-idp-2024",
+                "algorithm": "EdDSA",
+                "publicKey": "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAdilE7LnzschuKNTNvSvCXGqcunMo88p7EKPeV1AwlIw=\n-----END PUBLIC KEY-----",
+                "actions": ["search"],
+                "indexes": ["products"],
+                "expiresAt": null,
+                "createdAt": "2021-11-12T10:00:00Z"
+            }
+        )),
+        (status = 404, description = "Signing key not found.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "Signing key `my-idp-2024` not found.",
+                "code": "signing_key_not_found",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#signing_key_not_found"
+            }
+        )),
+    )
+)]
+pub async fn get_signing_key(
+    auth_controller: GuardedData<ActionPolicy<{ actions::KEYS_GET }>, Data<AuthController>>,
+    path: web::Path<SigningKeyParam>,
+) -> Result<HttpResponse, ResponseError> {
+    let kid = path.into_inner().kid;
+    let key = tokio::task::spawn_blocking(move || auth_controller.get_signing_key(&kid))
+        .await
+        .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    debug!(returns = ?key, "Get signing key");
+    Ok(HttpResponse::Ok().json(key))
+}
+
+/// Delete a signing key
+///
+/// Delete a signing key by its key id. The tenant tokens signed with it are immediately rejected.
+#[routes::path(
+    security(("Bearer" = ["keys.delete", "keys.*", "*"])),
+    params(("kid" = String, Path, example = "my-idp-2024", description = "The key id of the signing key.", nullable = false)),
+    responses(
+        (status = 204, description = "The signing key has been removed."),
+        (status = 404, description = "Signing key not found.", body = ResponseError, content_type = "application/json", example = json!(
+            {
+                "message": "Signing key `my-idp-2024` not found.",
+                "code": "signing_key_not_found",
+                "type": "invalid_request",
+                "link": "https://docs.meilisearch.com/errors#signing_key_not_found"
+            }
+        )),
+    )
+)]
+pub async fn delete_signing_key(
+    auth_controller: GuardedData<ActionPolicy<{ actions::KEYS_DELETE }>, Data<AuthController>>,
+    path: web::Path<SigningKeyParam>,
+) -> Result<HttpResponse, ResponseError> {
+    let kid = path.into_inner().kid;
+    tokio::task::spawn_blocking(move || auth_controller.delete_signing_key(&kid))
+        .await
+        .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))??;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -1,15 +1,15 @@
 use actix_web::web::Data;
 use actix_web::{web, HttpRequest, HttpResponse};
-use deserr::actix_web::AwebJson;
+use deserr::actix_web::{AwebJson, AwebQueryParameter};
 use index_scheduler::IndexScheduler;
-use meilisearch_types::deserr::DeserrJsonError;
+use meilisearch_types::deserr::{DeserrJsonError, DeserrQueryParamError};
 use meilisearch_types::error::deserr_codes::{InvalidSwapIndexes, InvalidSwapRename};
 use meilisearch_types::error::ResponseError;
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::tasks::{IndexSwap, KindWithContent};
 use serde::Serialize;
 
-use super::SummarizedTaskView;
+use super::{check_task_priority, SummarizedTaskView, TaskPriorityQuery};
 use crate::analytics::{Aggregate, Analytics};
 use crate::error::MeilisearchHttpError;
 use crate::extractors::authentication::policies::*;
@@ -68,6 +68,7 @@ impl Aggregate for IndexSwappedAnalytics {
 /// Enqueued tasks are left unmodified.
 #[routes::path(
     security(("Bearer" = ["indexes.swap", "*"])),
+    params(TaskPriorityQuery),
     request_body(content = Vec<SwapIndexesPayload>),
     responses(
         (status = 202, description = "Task successfully enqueued.", body = SummarizedTaskView, content_type = "application/json", example = json!(
@@ -91,11 +92,13 @@ impl Aggregate for IndexSwappedAnalytics {
 )]
 pub async fn swap_indexes(
     index_scheduler: GuardedData<ActionPolicy<{ actions::INDEXES_SWAP }>, Data<IndexScheduler>>,
+    query: AwebQueryParameter<TaskPriorityQuery, DeserrQueryParamError>,
     params: AwebJson<Vec<SwapIndexesPayload>, DeserrJsonError>,
     req: HttpRequest,
     analytics: web::Data<Analytics>,
 ) -> Result<HttpResponse, ResponseError> {
     let params = params.into_inner();
+    let priority = check_task_priority(index_scheduler.filters(), query.into_inner().priority)?;
 
     let network = index_scheduler.network();
     let task_network = task_network_and_check_leader_and_version(&req, &network)?;
@@ -129,7 +132,7 @@ pub async fn swap_indexes(
     let task = KindWithContent::IndexSwap { swaps };
     let scheduler = index_scheduler.clone();
     let mut task = tokio::task::spawn_blocking(move || {
-        scheduler.register_with_custom_metadata(task, None, task_network)
+        scheduler.register_with_priority(task, None, priority, task_network)
     })
     .await??;
 
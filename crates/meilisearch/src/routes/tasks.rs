@@ -16,7 +16,7 @@ use meilisearch_types::error::{InvalidTaskDateError, ResponseError};
 use meilisearch_types::index_uid::IndexUid;
 use meilisearch_types::star_or::{OptionStarOr, OptionStarOrList};
 use meilisearch_types::task_view::TaskView;
-use meilisearch_types::tasks::{Kind, KindWithContent, Status};
+use meilisearch_types::tasks::{Kind, KindWithContent, Status, TaskPriority};
 use serde::{Deserialize, Serialize};
 use time::format_description::well_known::Rfc3339;
 use time::macros::format_description;
@@ -107,6 +107,13 @@ pub struct TasksFilterQuery {
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexUid>)]
     #[param(required = false, value_type = Option<Vec<String>>, example = json!(["movies", "theater"]))]
     pub index_uids: OptionStarOrList<IndexUid>,
+    /// Permits to filter tasks by their priority. By default, when
+    /// `priorities` query parameter is not set, tasks of all priorities are
+    /// returned. It's possible to specify several priorities by separating
+    /// them with the `,` character.
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskPriorities>)]
+    #[param(required = false, value_type = Option<Vec<TaskPriority>>, example = json!([TaskPriority::High]))]
+    pub priorities: OptionStarOrList<TaskPriority>,
 
     /// Permits to filter tasks based on their enqueuedAt time. Matches tasks
     /// enqueued after the given date. Supports RFC 3339 date format.
@@ -150,6 +157,7 @@ impl TasksFilterQuery {
             statuses: self.statuses.merge_star_and_none(),
             types: self.types.merge_star_and_none(),
             index_uids: self.index_uids.map(|x| x.to_string()).merge_star_and_none(),
+            priorities: self.priorities.merge_star_and_none(),
             uids: self.uids.merge_star_and_none(),
             canceled_by: self.canceled_by.merge_star_and_none(),
             before_enqueued_at: self.before_enqueued_at.merge_star_and_none(),
@@ -173,6 +181,7 @@ impl TaskDeletionOrCancelationQuery {
                 types: OptionStarOrList::None,
                 statuses: OptionStarOrList::None,
                 index_uids: OptionStarOrList::None,
+                priorities: OptionStarOrList::None,
                 after_enqueued_at: OptionStarOr::None,
                 before_enqueued_at: OptionStarOr::None,
                 after_started_at: OptionStarOr::None,
@@ -224,6 +233,13 @@ pub struct TaskDeletionOrCancelationQuery {
     #[deserr(default, error = DeserrQueryParamError<InvalidIndexUid>)]
     #[param(required = false, value_type = Option<Vec<String>>, example = json!(["movies", "theater"]))]
     pub index_uids: OptionStarOrList<IndexUid>,
+    /// Permits to filter tasks by their priority. By default, when
+    /// `priorities` query parameter is not set, tasks of all priorities are
+    /// returned. It's possible to specify several priorities by separating
+    /// them with the `,` character.
+    #[deserr(default, error = DeserrQueryParamError<InvalidTaskPriorities>)]
+    #[param(required = false, value_type = Option<Vec<TaskPriority>>, example = json!([TaskPriority::High]))]
+    pub priorities: OptionStarOrList<TaskPriority>,
 
     /// Permits to filter tasks based on their enqueuedAt time. Matches tasks
     /// enqueued after the given date. Supports RFC 3339 date format.
@@ -267,6 +283,7 @@ impl TaskDeletionOrCancelationQuery {
             statuses: self.statuses.merge_star_and_none(),
             types: self.types.merge_star_and_none(),
             index_uids: self.index_uids.map(|x| x.to_string()).merge_star_and_none(),
+            priorities: self.priorities.merge_star_and_none(),
             uids: self.uids.merge_star_and_none(),
             canceled_by: self.canceled_by.merge_star_and_none(),
             before_enqueued_at: self.before_enqueued_at.merge_star_and_none(),
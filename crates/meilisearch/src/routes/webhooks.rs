@@ -1,5 +1,5 @@
 use core::convert::Infallible;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::str::FromStr;
 
 use actix_http::header::{
@@ -13,12 +13,15 @@ use deserr::{DeserializeError, ValuePointerRef};
 use index_scheduler::IndexScheduler;
 use meilisearch_types::deserr::{immutable_field_error, DeserrJsonError};
 use meilisearch_types::error::deserr_codes::{
-    BadRequest, InvalidWebhookHeaders, InvalidWebhookUrl,
+    BadRequest, InvalidWebhookDeliveryUids, InvalidWebhookHeaders, InvalidWebhookIndexUids,
+    InvalidWebhookSecret, InvalidWebhookStatuses, InvalidWebhookTypes, InvalidWebhookUrl,
 };
 use meilisearch_types::error::{Code, ErrorCode, ResponseError};
+use meilisearch_types::index_uid_pattern::IndexUidPattern;
 use meilisearch_types::keys::actions;
 use meilisearch_types::milli::update::Setting;
-use meilisearch_types::webhooks::Webhook;
+use meilisearch_types::tasks::{Kind, Status};
+use meilisearch_types::webhooks::{DeliveryId, Webhook, WebhookDelivery};
 use serde::Serialize;
 use tracing::debug;
 use url::Url;
@@ -34,6 +37,7 @@ use crate::extractors::authentication::GuardedData;
     routes(
         "" => [get(get_webhooks), post(post_webhook)],
         "/{uuid}" => [get(get_webhook), patch(patch_webhook), delete(delete_webhook)],
+        "/{uuid}/deliveries" => [get(get_webhook_deliveries), post(redeliver_webhook_deliveries)],
     ),
     tag = "Webhooks",
     tags((
@@ -53,6 +57,21 @@ pub(super) struct WebhookSettings {
     /// HTTP headers to include in webhook requests.
     #[request(default, error = DeserrJsonError<InvalidWebhookHeaders>, schema_type = Option<BTreeMap<String, String>>, example = json!({"Authorization":"Bearer a-secret-token"}))]
     headers: Setting<BTreeMap<String, Setting<String>>>,
+    /// Only send the tasks of these types. All types are sent when omitted or `null`.
+    #[request(default, error = DeserrJsonError<InvalidWebhookTypes>, schema_type = Option<Vec<Kind>>, example = json!(["documentAdditionOrUpdate", "settingsUpdate"]))]
+    types: Setting<Vec<Kind>>,
+    /// Only send the tasks with these statuses. All statuses are sent when omitted or `null`.
+    #[request(default, error = DeserrJsonError<InvalidWebhookStatuses>, schema_type = Option<Vec<Status>>, example = json!(["failed"]))]
+    statuses: Setting<Vec<Status>>,
+    /// Only send the tasks about an index matching one of these patterns. All tasks are sent when omitted or `null`.
+    #[request(default, error = DeserrJsonError<InvalidWebhookIndexUids>, schema_type = Option<Vec<String>>, example = json!(["products", "movies_*"]))]
+    index_uids: Setting<Vec<IndexUidPattern>>,
+    /// Shared secret used to sign the payloads with HMAC-SHA256.
+    ///
+    /// When set, each request contains a `Meili-Webhook-Timestamp` header and a `Meili-Webhook-Signature` header
+    /// holding `sha256=` followed by the hex-encoded HMAC of `{timestamp}.{body}`, where `body` is the uncompressed NDJSON payload.
+    #[request(default, error = DeserrJsonError<InvalidWebhookSecret>, schema_type = Option<String>, example = "a-shared-secret")]
+    secret: Setting<String>,
 }
 
 fn deny_immutable_fields_webhook(
@@ -80,7 +99,7 @@ pub(super) struct WebhookWithMetadataRedactedAuthorization {
     uuid: Uuid,
     /// Whether the webhook can be edited.
     is_editable: bool,
-    /// URL and headers. Authorization header values are redacted in the response.
+    /// URL, headers, filters and secret. Authorization header values and the secret are redacted in the response.
     #[schema(value_type = WebhookSettings)]
     #[serde(flatten)]
     webhook: Webhook,
@@ -89,6 +108,7 @@ pub(super) struct WebhookWithMetadataRedactedAuthorization {
 impl WebhookWithMetadataRedactedAuthorization {
     pub fn from(uuid: Uuid, mut webhook: Webhook) -> Self {
         webhook.redact_authorization_header();
+        webhook.redact_secret();
         Self { uuid, is_editable: uuid != Uuid::nil(), webhook }
     }
 }
@@ -203,6 +223,8 @@ enum WebhooksError {
     InvalidUrl(String, url::ParseError),
     #[error("Invalid UUID: {0}")]
     InvalidUuid(uuid::Error),
+    #[error("The secret of the webhook `{0}` cannot be empty. Hint: To stop signing the payloads set the secret to `null`")]
+    EmptySecret(Uuid),
 }
 
 impl ErrorCode for WebhooksError {
@@ -217,6 +239,7 @@ impl ErrorCode for WebhooksError {
             InvalidHeaderValue(_, _) => meilisearch_types::error::Code::InvalidWebhookHeaders,
             InvalidUrl(_, _) => meilisearch_types::error::Code::InvalidWebhookUrl,
             InvalidUuid(_) => meilisearch_types::error::Code::InvalidWebhookUuid,
+            EmptySecret(_) => meilisearch_types::error::Code::InvalidWebhookSecret,
         }
     }
 }
@@ -226,7 +249,7 @@ fn patch_webhook_inner(
     old_webhook: Webhook,
     new_webhook: WebhookSettings,
 ) -> Result<Webhook, WebhooksError> {
-    let Webhook { url: old_url, mut headers } = old_webhook;
+    let Webhook { url: old_url, mut headers, types, statuses, index_uids, secret } = old_webhook;
 
     let url = match new_webhook.url {
         Setting::Set(url) => url,
@@ -257,7 +280,25 @@ fn patch_webhook_inner(
         return Err(TooManyHeaders(uuid.to_owned()));
     }
 
-    Ok(Webhook { url, headers })
+    let types = patch_filter(types, new_webhook.types);
+    let statuses = patch_filter(statuses, new_webhook.statuses);
+    let index_uids = patch_filter(index_uids, new_webhook.index_uids);
+    let secret = match new_webhook.secret {
+        Setting::Set(secret) => Some(secret),
+        Setting::Reset => None,
+        Setting::NotSet => secret,
+    };
+
+    Ok(Webhook { url, headers, types, statuses, index_uids, secret })
+}
+
+/// Replaces the whole filter when set, removes it when reset.
+fn patch_filter<T: Ord>(old: Option<BTreeSet<T>>, new: Setting<Vec<T>>) -> Option<BTreeSet<T>> {
+    match new {
+        Setting::Set(values) => Some(values.into_iter().collect()),
+        Setting::Reset => None,
+        Setting::NotSet => old,
+    }
 }
 
 fn check_changed(uuid: Uuid, webhook: &Webhook) -> Result<(), WebhooksError> {
@@ -273,6 +314,10 @@ fn check_changed(uuid: Uuid, webhook: &Webhook) -> Result<(), WebhooksError> {
         return Err(TooManyHeaders(uuid));
     }
 
+    if webhook.secret.as_ref().is_some_and(|secret| secret.is_empty()) {
+        return Err(EmptySecret(uuid));
+    }
+
     for (header, value) in &webhook.headers {
         HeaderName::from_bytes(header.as_bytes())
             .map_err(|e| InvalidHeaderName(header.to_owned(), e))?;
@@ -379,13 +424,17 @@ async fn post_webhook(
         return Err(TooManyWebhooks.into());
     }
 
+    let WebhookSettings { url, headers, types, statuses, index_uids, secret } = webhook_settings;
     let webhook = Webhook {
-        url: webhook_settings.url.set().ok_or(MissingUrl(uuid))?,
-        headers: webhook_settings
-            .headers
+        url: url.set().ok_or(MissingUrl(uuid))?,
+        headers: headers
             .set()
             .map(|h| h.into_iter().map(|(k, v)| (k, v.set().unwrap_or_default())).collect())
             .unwrap_or_default(),
+        types: types.set().map(|types| types.into_iter().collect()),
+        statuses: statuses.set().map(|statuses| statuses.into_iter().collect()),
+        index_uids: index_uids.set().map(|index_uids| index_uids.into_iter().collect()),
+        secret: secret.set(),
     };
 
     check_changed(uuid, &webhook)?;
@@ -509,3 +558,141 @@ async fn delete_webhook(
     debug!(returns = "No Content", "Delete webhook");
     Ok(HttpResponse::NoContent().finish())
 }
+
+/// Response containing the deliveries of a webhook.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct WebhookDeliveryResults {
+    /// The deliveries of the webhook, most recent first.
+    results: Vec<WebhookDelivery>,
+}
+
+/// List webhook deliveries
+///
+/// Return the notifications that could not be delivered to the webhook on the first attempt, along with all their delivery attempts.
+/// Failed notifications are retried automatically with an exponential backoff, up to 10 attempts.
+#[routes::path(
+    security(("Bearer" = ["webhooks.get", "webhooks.*", "*.get", "*"])),
+    responses(
+        (status = 200, description = "Deliveries are returned.", body = WebhookDeliveryResults, content_type = "application/json", example = json!({
+            "results": [
+                {
+                    "uid": 0,
+                    "webhookUuid": "550e8400-e29b-41d4-a716-446655440000",
+                    "taskUids": [12, 13],
+                    "status": "pending",
+                    "attempts": [
+                        {
+                            "attemptedAt": "2024-08-08T16:37:09.971Z",
+                            "statusCode": 500,
+                            "error": "The webhook answered with the status code 500."
+                        }
+                    ],
+                    "nextAttemptAt": "2024-08-08T16:37:19.971Z"
+                }
+            ]
+        })),
+        (status = 404, description = "Webhook not found.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The webhook was not found.",
+            "code": "webhook_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#webhook_not_found"
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    ),
+    params(
+        ("uuid" = Uuid, Path, description = "Universally unique identifier of the webhook.")
+    )
+)]
+async fn get_webhook_deliveries(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::WEBHOOKS_GET }>, Data<IndexScheduler>>,
+    uuid: Path<String>,
+) -> Result<HttpResponse, ResponseError> {
+    let uuid = Uuid::from_str(&uuid.into_inner()).map_err(InvalidUuid)?;
+    let webhooks = index_scheduler.webhooks_view();
+    if !webhooks.webhooks.contains_key(&uuid) {
+        return Err(WebhookNotFound(uuid).into());
+    }
+
+    let results = WebhookDeliveryResults { results: index_scheduler.webhook_deliveries(uuid)? };
+
+    debug!(returns = ?results, "Get webhook deliveries");
+    Ok(HttpResponse::Ok().json(results))
+}
+
+/// Deliveries of a webhook to send again
+#[routes::request]
+#[derive(Debug)]
+pub(super) struct RedeliverWebhookDeliveries {
+    /// Uids of the deliveries to send again. When omitted or `null`, all the deliveries that exhausted their automatic retries are sent again.
+    #[request(default, error = DeserrJsonError<InvalidWebhookDeliveryUids>, example = json!([0, 3]))]
+    delivery_uids: Option<Vec<DeliveryId>>,
+}
+
+/// Redeliver webhook deliveries
+///
+/// Send the given deliveries to the webhook again, right away. The deliveries are sent asynchronously, their outcome is visible in the list of deliveries.
+#[routes::path(
+    request_body = RedeliverWebhookDeliveries,
+    security(("Bearer" = ["webhooks.update", "webhooks.*", "*"])),
+    responses(
+        (status = 202, description = "The deliveries will be sent again.", body = WebhookDeliveryResults, content_type = "application/json", example = json!({
+            "results": [
+                {
+                    "uid": 0,
+                    "webhookUuid": "550e8400-e29b-41d4-a716-446655440000",
+                    "taskUids": [12, 13],
+                    "status": "pending",
+                    "attempts": [
+                        {
+                            "attemptedAt": "2024-08-08T16:37:09.971Z",
+                            "statusCode": 500,
+                            "error": "The webhook answered with the status code 500."
+                        }
+                    ],
+                    "nextAttemptAt": "2024-08-08T16:40:00.000Z"
+                }
+            ]
+        })),
+        (status = 404, description = "Webhook or delivery not found.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "Delivery `3` of the webhook `550e8400-e29b-41d4-a716-446655440000` not found.",
+            "code": "webhook_delivery_not_found",
+            "type": "invalid_request",
+            "link": "https://docs.meilisearch.com/errors#webhook_delivery_not_found"
+        })),
+        (status = 401, description = "The authorization header is missing.", body = ResponseError, content_type = "application/json", example = json!({
+            "message": "The Authorization header is missing. It must use the bearer authorization method.",
+            "code": "missing_authorization_header",
+            "type": "auth",
+            "link": "https://docs.meilisearch.com/errors#missing_authorization_header"
+        })),
+    ),
+    params(
+        ("uuid" = Uuid, Path, description = "Universally unique identifier of the webhook.")
+    )
+)]
+async fn redeliver_webhook_deliveries(
+    index_scheduler: GuardedData<ActionPolicy<{ actions::WEBHOOKS_UPDATE }>, Data<IndexScheduler>>,
+    uuid: Path<String>,
+    params: AwebJson<RedeliverWebhookDeliveries, DeserrJsonError>,
+) -> Result<HttpResponse, ResponseError> {
+    let uuid = Uuid::from_str(&uuid.into_inner()).map_err(InvalidUuid)?;
+    let params = params.into_inner();
+    debug!(parameters = ?(uuid, &params), "Redeliver webhook deliveries");
+
+    let webhooks = index_scheduler.webhooks_view();
+    if !webhooks.webhooks.contains_key(&uuid) {
+        return Err(WebhookNotFound(uuid).into());
+    }
+
+    let results = index_scheduler.redeliver_webhook_deliveries(uuid, params.delivery_uids)?;
+    let results = WebhookDeliveryResults { results };
+
+    debug!(returns = ?results, "Redeliver webhook deliveries");
+    Ok(HttpResponse::Accepted().json(results))
+}
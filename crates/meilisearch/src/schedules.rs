@@ -0,0 +1,105 @@
+//! Enqueues the tasks of the schedules stored in the index-scheduler when they are due.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+
+use index_scheduler::{IndexScheduler, Query};
+use meilisearch_auth::{AuthController, AuthFilter};
+use meilisearch_types::error::{Code, ResponseError};
+use meilisearch_types::schedules::ScheduledTask;
+use meilisearch_types::tasks::KindWithContent;
+use time::format_description::well_known::Rfc3339;
+use time::{Duration, OffsetDateTime};
+use tracing::error;
+
+use crate::analytics::find_user_id;
+
+/// Spawns the thread that checks the schedules at the start of every minute and enqueues the due tasks.
+pub fn spawn_schedules_thread(
+    index_scheduler: Arc<IndexScheduler>,
+    auth_controller: Arc<AuthController>,
+    db_path: PathBuf,
+) {
+    thread::Builder::new()
+        .name(String::from("register-scheduled-tasks"))
+        .spawn(move || loop {
+            let now = OffsetDateTime::now_utc();
+            let next_minute = now.replace_second(0).unwrap().replace_nanosecond(0).unwrap()
+                + Duration::minutes(1);
+            thread::sleep((next_minute - now).unsigned_abs());
+
+            let now = OffsetDateTime::now_utc();
+            let schedules = match index_scheduler.due_schedules(now) {
+                Ok(schedules) => schedules,
+                Err(e) => {
+                    error!("Error while retrieving the schedules: {e}");
+                    continue;
+                }
+            };
+            for schedule in schedules {
+                let result = scheduled_task_kind(
+                    &schedule.task,
+                    now,
+                    &index_scheduler,
+                    &auth_controller,
+                    &db_path,
+                )
+                .and_then(|kind| {
+                    Ok(index_scheduler.register_scheduled_task(schedule.uid, kind, now)?)
+                });
+                if let Err(e) = result {
+                    error!(
+                        "Error while registering the task of the schedule `{}`: {e}",
+                        schedule.uid
+                    );
+                    // skip this run, otherwise the schedule stays due and fails every minute
+                    if let Err(e) = index_scheduler.skip_scheduled_run(schedule.uid, now) {
+                        error!(
+                            "Error while planning the next run of the schedule `{}`: {e}",
+                            schedule.uid
+                        );
+                    }
+                }
+            }
+        })
+        .unwrap();
+}
+
+/// Builds the task to enqueue for a run of a schedule happening at `now`.
+pub fn scheduled_task_kind(
+    task: &ScheduledTask,
+    now: OffsetDateTime,
+    index_scheduler: &IndexScheduler,
+    auth_controller: &AuthController,
+    db_path: &std::path::Path,
+) -> Result<KindWithContent, ResponseError> {
+    Ok(match task.clone() {
+        ScheduledTask::DumpCreation => KindWithContent::DumpCreation {
+            keys: auth_controller.list_keys()?,
+            signing_keys: auth_controller.list_signing_keys()?,
+            instance_uid: find_user_id(db_path),
+            selection: Default::default(),
+        },
+        ScheduledTask::SnapshotCreation => KindWithContent::SnapshotCreation,
+        ScheduledTask::Export { url, api_key, payload_size, indexes } => {
+            KindWithContent::Export { url, api_key, payload_size, indexes }
+        }
+        ScheduledTask::IndexCompaction { index_uid } => {
+            KindWithContent::IndexCompaction { index_uid }
+        }
+        ScheduledTask::TaskDeletion { older_than_days } => {
+            let before_finished_at = now - Duration::days(older_than_days.into());
+            let query = Query { before_finished_at: Some(before_finished_at), ..Query::default() };
+            let (tasks, _) = index_scheduler
+                .get_task_ids_from_authorized_indexes(&query, &AuthFilter::default())?;
+            let before_finished_at = before_finished_at
+                .format(&Rfc3339)
+                .map_err(|e| ResponseError::from_msg(e.to_string(), Code::Internal))?;
+            KindWithContent::TaskDeletion {
+                query: format!("?beforeFinishedAt={before_finished_at}"),
+                tasks,
+            }
+        }
+    })
+}
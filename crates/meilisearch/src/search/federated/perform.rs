@@ -42,7 +42,7 @@ use super::weighted_scores;
 use crate::documents_retrieval::{FederatedHydrationFormatter, HydrationContext};
 use crate::documents_retrieval::{RemoteErrors, WithIndex};
 use crate::error::MeilisearchHttpError;
-use crate::personalization::PersonalizationService;
+use crate::personalization::{PersonalizationService, RerankerService};
 use crate::routes::indexes::search::search_kind;
 use crate::search::federated::types::{
     PreprocessedQuery, FEDERATION_EXTRA_DOCUMENT, INDEX_UID, PINNED_PRECEDENCE, QUERIES_POSITION,
@@ -187,6 +187,7 @@ pub async fn perform_federated_search(
         mut query_vectors,
         previous_query_data: _,
         facet_order,
+        mut applied_dynamic_search_rules,
     } = search_by_index;
 
     progress.update_progress(FederatingResultsStep::WaitForRemoteResults);
@@ -220,6 +221,15 @@ pub async fn perform_federated_search(
     let (mut hit_number, degraded, used_negative_operator, facets, max_remote_duration) =
         merge_metadata(&mut results_by_index, &remote_results);
 
+    // dynamic search rules are replicated on all remotes, so the same rule can be applied by several hosts
+    for remote_result in &remote_results {
+        for rule in remote_result.applied_dynamic_search_rules.iter().flatten() {
+            if !applied_dynamic_search_rules.contains(rule) {
+                applied_dynamic_search_rules.push(rule.clone());
+            }
+        }
+    }
+
     let (skip, take) = match (federation.page, federation.hits_per_page) {
         // no pagination
         (None, None) => (federation.offset, federation.limit),
@@ -328,11 +338,18 @@ pub async fn perform_federated_search(
             // Merge queries into a single string to pass to the personalization service
             let query =
                 query_metadata.iter().filter_map(|metadata| metadata.query.as_ref()).join(", ");
+            // Use the reranker of the first queried index that configures one
+            let index_reranker = RerankerService::from_indexes(
+                &index_scheduler,
+                query_metadata.iter().map(|metadata| metadata.index_uid.as_str()),
+            )
+            .without_index()?;
             merged_hits = personalization_service
                 .rerank_search_results(
                     std::mem::take(&mut merged_hits),
                     personalize,
                     Some(&query),
+                    index_reranker.as_ref(),
                     &deadline,
                     progress,
                 )
@@ -451,6 +468,8 @@ pub async fn perform_federated_search(
             remote_errors,
             request_uid: Some(request_uid),
             metadata: include_metadata.then_some(query_metadata),
+            applied_dynamic_search_rules: (!applied_dynamic_search_rules.is_empty())
+                .then_some(applied_dynamic_search_rules),
             performance_details,
         },
         deadline,
@@ -1001,6 +1020,7 @@ fn merge_metadata(
         remote_errors: _,
         metadata: _,
         request_uid: _,
+        applied_dynamic_search_rules: _,
         performance_details: _,
     } in remote_results
     {
@@ -1058,6 +1078,10 @@ impl PartitionedQueries {
             .into());
         }
 
+        if federated_query.query.has_facet_aggregations() {
+            return Err(MeilisearchHttpError::FacetAggregationsInFederatedQuery.into());
+        }
+
         if federated_query.query.has_personalize() {
             return Err(MeilisearchHttpError::PersonalizationInFederatedQuery.into());
         }
@@ -1070,6 +1094,10 @@ impl PartitionedQueries {
             return Err(MeilisearchHttpError::ShowPerformanceDetailsInFederatedQuery.into());
         }
 
+        if federated_query.query.has_suggestions() {
+            return Err(MeilisearchHttpError::SuggestionsInFederatedQuery.into());
+        }
+
         if federated_query.query.has_distinct() && federation.distinct.is_some() {
             return Err(MeilisearchHttpError::DistinctInFederatedQueryAndFederation.into());
         }
@@ -1308,6 +1336,8 @@ struct SearchByIndex {
     // remember the order and name of first index for each facet when merging with index settings
     // to detect if the order is inconsistent for a facet.
     facet_order: FacetOrder,
+    // uids of the dynamic search rules applied to any of the queries, in order of first application
+    applied_dynamic_search_rules: Vec<String>,
 }
 
 impl SearchByIndex {
@@ -1329,6 +1359,7 @@ impl SearchByIndex {
             results_by_index: Vec::with_capacity(index_count),
             query_vectors: BTreeMap::new(),
             previous_query_data: None,
+            applied_dynamic_search_rules: Vec::new(),
         }
     }
 
@@ -1422,9 +1453,10 @@ impl SearchByIndex {
             }
         }
 
-        for QueryByIndex { query, weight, query_index } in queries {
+        for QueryByIndex { mut query, weight, query_index } in queries {
             // use an immediately invoked lambda to capture the result without returning from the function
             let res: Result<(), ResponseError> = (|| {
+                query.restrict_attributes_to_search_on(&index, &rtxn)?;
                 let search_kind =
                     search_kind(&query, &params.index_scheduler, index_uid.to_string(), &index)?;
 
@@ -1567,8 +1599,17 @@ impl SearchByIndex {
                     degraded: query_degraded,
                     used_negative_operator: query_used_negative_operator,
                     query_vector,
+                    applied_rules,
+                    // suggestions are not allowed in federated queries
+                    suggestions: _,
                 } = result;
 
+                for rule in applied_rules {
+                    if !self.applied_dynamic_search_rules.contains(&rule) {
+                        self.applied_dynamic_search_rules.push(rule);
+                    }
+                }
+
                 if query.retrieve_vectors {
                     if let Some(query_vector) = query_vector {
                         debug_assert!(
@@ -4,6 +4,7 @@ use std::fmt;
 use std::vec::Vec;
 
 use indexmap::IndexMap;
+use meilisearch_auth::IndexSearchRules;
 use meilisearch_types::deserr::DeserrJsonError;
 use meilisearch_types::error::deserr_codes::{
     InvalidMultiSearchFacetsByIndex, InvalidMultiSearchMaxValuesPerFacet,
@@ -151,6 +152,18 @@ impl Federation {
     pub fn is_exhaustive(&self) -> bool {
         self.page.is_some() || self.hits_per_page.is_some()
     }
+
+    /// Incorporate the search rules of a tenant token for one of the federated indexes.
+    pub fn add_search_rules(&mut self, index_uid: &str, rules: &IndexSearchRules) {
+        self.limit = rules.restrict_limit(self.limit);
+        self.hits_per_page =
+            self.hits_per_page.map(|hits_per_page| rules.restrict_limit(hits_per_page));
+        for (facets_index_uid, facets) in &mut self.facets_by_index {
+            if facets_index_uid.as_str() == index_uid {
+                *facets = rules.restrict_facets(facets.take());
+            }
+        }
+    }
 }
 
 /// Options for merging facets from multiple indexes in federated search.
@@ -229,6 +242,10 @@ pub struct FederatedSearchResult {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub semantic_hit_count: Option<u32>,
 
+    /// Uids of the dynamic search rules applied to at least one of the queries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_dynamic_search_rules: Option<Vec<String>>,
+
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[schema(value_type = Option<Value>)]
     pub performance_details: Option<IndexMap<String, String>>,
@@ -254,6 +271,7 @@ impl FederatedSearchResult {
             metadata,
             remote_errors,
             semantic_hit_count,
+            applied_dynamic_search_rules,
             degraded,
             used_negative_operator,
             performance_details,
@@ -276,10 +294,13 @@ impl FederatedSearchResult {
             hits_info,
             facet_distribution,
             facet_stats,
+            facet_aggregations: None,
             request_uid,
             metadata,
             remote_errors,
             semantic_hit_count,
+            applied_dynamic_search_rules,
+            suggestions: None,
             degraded,
             used_negative_operator,
             performance_details,
@@ -303,6 +324,7 @@ impl fmt::Debug for FederatedSearchResult {
             remote_errors,
             request_uid,
             metadata,
+            applied_dynamic_search_rules,
             performance_details: _, // not part of the debug output because it's an Option and is always displayed in a dedicated log.
         } = self;
 
@@ -342,6 +364,9 @@ impl fmt::Debug for FederatedSearchResult {
         if let Some(metadata) = metadata {
             debug.field("metadata", &metadata);
         }
+        if let Some(applied_dynamic_search_rules) = applied_dynamic_search_rules {
+            debug.field("applied_dynamic_search_rules", &applied_dynamic_search_rules);
+        }
 
         debug.finish()
     }
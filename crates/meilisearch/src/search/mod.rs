@@ -1,12 +1,13 @@
 use core::fmt;
 use std::cmp::min;
 use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::convert::Infallible;
 use std::ops::Not as _;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
-use deserr::Deserr;
+use deserr::{DeserializeError, Deserr, ValuePointerRef};
 pub use federated::ProxyQuery;
 use index_scheduler::filter::parse_local_index_filter;
 use index_scheduler::{IndexScheduler, RoFeatures};
@@ -33,7 +34,7 @@ use meilisearch_types::{milli, Document};
 use milli::tokenizer::{Language, TokenizerBuilder};
 use milli::{
     AscDesc, FieldId, FieldsIdsMap, FormatOptions, Index, LocalizedAttributesRule, MatchBounds,
-    MatcherBuilder, SortError, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
+    MatcherBuilder, Member, SortError, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
 };
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -64,6 +65,9 @@ pub const DEFAULT_CROP_MARKER: fn() -> String = || "…".to_string();
 pub const DEFAULT_HIGHLIGHT_PRE_TAG: fn() -> String = || "<em>".to_string();
 pub const DEFAULT_HIGHLIGHT_POST_TAG: fn() -> String = || "</em>".to_string();
 pub const DEFAULT_SEMANTIC_RATIO: fn() -> SemanticRatio = || SemanticRatio(0.5);
+/// Maximum number of spelling suggestions returned by a search.
+pub const MAX_SEARCH_SUGGESTIONS: usize = 10;
+pub const DEFAULT_AUTOCOMPLETE_LIMIT: fn() -> usize = || milli::DEFAULT_AUTOCOMPLETE_LIMIT;
 pub const INCLUDE_METADATA_HEADER: &str = "Meili-Include-Metadata";
 
 /// Configuration for [personalized search](https://www.meilisearch.com/docs/learn/personalization/making_personalized_search_queries) results.
@@ -235,6 +239,13 @@ pub struct SearchQuery {
     /// More info: [faceting](https://www.meilisearch.com/docs/learn/filtering_and_sorting/search_with_facet_filters).
     #[request(default, error = DeserrJsonError<InvalidSearchFacets>)]
     pub facets: Option<AttributePatterns>,
+    /// Count the matching documents in numeric buckets for the listed attributes.
+    ///
+    /// Each attribute maps either to `ranges`, a list of `[from, to]` pairs where `from` is inclusive, `to` is exclusive and `null` leaves the range open on that side, or to an `interval`, the width of contiguous histogram buckets spanning from the lowest to the highest value among matching documents.
+    ///
+    /// The response includes `facetAggregations`. The attributes must be filterable with comparison enabled.
+    #[request(default, error = DeserrJsonError<InvalidSearchFacetAggregations>)]
+    pub facet_aggregations: Option<BTreeMap<String, FacetAggregationQuery>>,
     /// How to match query terms when there are not enough results to satisfy `limit`.
     ///
     /// **`last`**: Returns documents containing all query terms first. If there are not enough such results, Meilisearch removes one query term at a time, starting from the end of the query (e.g. for "big fat cat", then "big fat", then "big").
@@ -246,6 +257,22 @@ pub struct SearchQuery {
     /// Default: `last`.
     #[request(default, error = DeserrJsonError<InvalidSearchMatchingStrategy>)]
     pub matching_strategy: MatchingStrategy,
+    /// When true, the query supports the following operators:
+    ///
+    /// **`attribute:word`** or **`attribute:"a phrase"`**: only matches documents containing the term in this searchable attribute (e.g. `title:"red shoes"`).
+    ///
+    /// **`(word OR word)`**: matches documents containing any of the alternatives (e.g. `(nike OR adidas) running`). An alternative of several words is matched as a phrase.
+    ///
+    /// An attribute that is not searched on is considered as regular text. Default: `false`.
+    #[request(default, error = DeserrJsonError<InvalidSearchAdvancedQuerySyntax>)]
+    pub advanced_query_syntax: bool,
+    /// Return up to this number of alternative queries where the typos of `q` are corrected, in the `suggestions` field of the response.
+    ///
+    /// Each suggestion replaces words of the query with words of the index, within the limits of the [typo tolerance](https://www.meilisearch.com/docs/learn/relevancy/typo_tolerance_settings) settings, and comes with the number of documents containing all its words.
+    ///
+    /// At most 10 suggestions are returned. Default: `0`, no suggestions are computed.
+    #[request(default, error = DeserrJsonError<InvalidSearchSuggestions>)]
+    pub suggestions: usize,
     /// Restrict the search to the listed attributes only.
     ///
     /// Each attribute must be in the index [searchable attributes](https://www.meilisearch.com/docs/learn/relevancy/displayed_searchable_attributes) list.
@@ -343,6 +370,16 @@ pub struct SearchQuery {
     /// When true, the response includes a `performanceDetails` object with a timing breakdown of the query processing.
     #[request(default, error = DeserrJsonError<InvalidSearchShowPerformanceDetails>)]
     pub show_performance_details: bool,
+    /// Custom claims of the tenant token used for this search, if any.
+    ///
+    /// Never read from the request: set from the authentication so that the conditions of dynamic search rules can be evaluated.
+    #[request(skip)]
+    pub tenant_token_claims: Option<serde_json::Map<String, Value>>,
+    /// Attributes that the tenant token used for this search allows to retrieve, if it restricts them.
+    ///
+    /// Never read from the request: set from the authentication so that the search doesn't match the hidden attributes when `attributesToSearchOn` is omitted.
+    #[request(skip)]
+    pub tenant_token_attributes: Option<Vec<String>>,
 }
 
 /// Helper trait for queries that can be networked.
@@ -440,7 +477,10 @@ impl From<SearchParameters> for SearchQuery {
             sort,
             distinct,
             facets: None,
+            facet_aggregations: None,
             matching_strategy: matching_strategy.map(MatchingStrategy::from).unwrap_or_default(),
+            advanced_query_syntax: false,
+            suggestions: 0,
             attributes_to_search_on,
             ranking_score_threshold: ranking_score_threshold.map(RankingScoreThreshold::from),
             locales: None,
@@ -459,6 +499,8 @@ impl From<SearchParameters> for SearchQuery {
             show_ranking_score: false,
             show_ranking_score_details: false,
             show_performance_details: false,
+            tenant_token_claims: None,
+            tenant_token_attributes: None,
         }
     }
 }
@@ -530,7 +572,10 @@ impl fmt::Debug for SearchQuery {
             sort,
             distinct,
             facets,
+            facet_aggregations,
             matching_strategy,
+            advanced_query_syntax,
+            suggestions,
             attributes_to_search_on,
             ranking_score_threshold,
             locales,
@@ -543,6 +588,8 @@ impl fmt::Debug for SearchQuery {
             show_ranking_score,
             show_ranking_score_details,
             show_performance_details,
+            tenant_token_claims: _,
+            tenant_token_attributes: _,
         } = self;
 
         let mut debug = f.debug_struct("SearchQuery");
@@ -594,7 +641,16 @@ impl fmt::Debug for SearchQuery {
         if let Some(facets) = facets {
             debug.field("facets", &facets);
         }
+        if let Some(facet_aggregations) = facet_aggregations {
+            debug.field("facet_aggregations", &facet_aggregations);
+        }
         debug.field("matching_strategy", &matching_strategy);
+        if *advanced_query_syntax {
+            debug.field("advanced_query_syntax", advanced_query_syntax);
+        }
+        if *suggestions > 0 {
+            debug.field("suggestions", suggestions);
+        }
 
         // Then everything related to the formatting
         debug.field("crop_length", &crop_length);
@@ -660,6 +716,118 @@ pub struct HybridQuery {
     pub embedder: String,
 }
 
+/// Numeric buckets in which to count the matching documents for a facet.
+///
+/// Exactly one of `ranges` or `interval` must be provided.
+#[routes::request(
+    proxied,
+    validate = validate_facet_aggregation -> DeserrJsonError<InvalidSearchFacetAggregations>,
+    override_error = DeserrJsonError<InvalidSearchFacetAggregations>
+)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacetAggregationQuery {
+    /// List of `[from, to]` ranges. `from` is inclusive, `to` is exclusive, and `null` leaves the range open on that side.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub ranges: Option<Vec<Vec<Option<f64>>>>,
+    /// Width of the histogram buckets. Must be strictly positive.
+    #[request(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<f64>,
+}
+
+fn validate_facet_aggregation<E: DeserializeError>(
+    aggregation: FacetAggregationQuery,
+    location: ValuePointerRef,
+) -> Result<FacetAggregationQuery, E> {
+    match (&aggregation.ranges, aggregation.interval) {
+        (Some(ranges), None) => {
+            let location = location.push_key("ranges");
+            if ranges.is_empty() {
+                return Err(facet_aggregation_error(
+                    "`ranges` must contain at least one range.".to_string(),
+                    location,
+                ));
+            }
+            if ranges.len() > milli::MAX_BUCKETS_PER_FACET_AGGREGATION {
+                return Err(facet_aggregation_error(
+                    format!(
+                        "`ranges` contains {} ranges, but at most {} are allowed.",
+                        ranges.len(),
+                        milli::MAX_BUCKETS_PER_FACET_AGGREGATION
+                    ),
+                    location,
+                ));
+            }
+            for (i, range) in ranges.iter().enumerate() {
+                match range.as_slice() {
+                    [Some(from), Some(to)] if from > to => {
+                        return Err(facet_aggregation_error(
+                            format!("the start of a range must be lower than or equal to its end, but `{from}` is greater than `{to}`."),
+                            location.push_index(i),
+                        ))
+                    }
+                    [_, _] => (),
+                    _ => {
+                        return Err(facet_aggregation_error(
+                            format!(
+                                "a range must be a `[from, to]` pair, but found {} values.",
+                                range.len()
+                            ),
+                            location.push_index(i),
+                        ))
+                    }
+                }
+            }
+        }
+        (None, Some(interval)) => {
+            if !interval.is_finite() || interval <= 0.0 {
+                return Err(facet_aggregation_error(
+                    format!("`interval` must be strictly positive, but found `{interval}`."),
+                    location.push_key("interval"),
+                ));
+            }
+        }
+        (Some(_), Some(_)) => {
+            return Err(facet_aggregation_error(
+                "either `ranges` or `interval` can be used, not both at once.".to_string(),
+                location,
+            ))
+        }
+        (None, None) => {
+            return Err(facet_aggregation_error(
+                "one of `ranges` or `interval` must be provided.".to_string(),
+                location,
+            ))
+        }
+    }
+
+    Ok(aggregation)
+}
+
+fn facet_aggregation_error<E: DeserializeError>(msg: String, location: ValuePointerRef) -> E {
+    deserr::take_cf_content(E::error::<Infallible>(
+        None,
+        deserr::ErrorKind::Unexpected { msg },
+        location,
+    ))
+}
+
+impl From<FacetAggregationQuery> for milli::FacetAggregation {
+    fn from(FacetAggregationQuery { ranges, interval }: FacetAggregationQuery) -> Self {
+        match (ranges, interval) {
+            (_, Some(interval)) => milli::FacetAggregation::Interval(interval),
+            (ranges, None) => milli::FacetAggregation::Ranges(
+                ranges
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|range| {
+                        (range.first().copied().flatten(), range.get(1).copied().flatten())
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum SearchKind {
     KeywordOnly,
@@ -846,9 +1014,18 @@ pub struct SearchQueryWithIndex {
     /// Display the count of matches per facet
     #[request(default, error = DeserrJsonError<InvalidSearchFacets>)]
     pub facets: Option<AttributePatterns>,
+    /// Count the matching documents in numeric buckets for the listed attributes
+    #[request(default, error = DeserrJsonError<InvalidSearchFacetAggregations>)]
+    pub facet_aggregations: Option<BTreeMap<String, FacetAggregationQuery>>,
     /// Strategy used to match query terms within documents
     #[request(default, error = DeserrJsonError<InvalidSearchMatchingStrategy>)]
     pub matching_strategy: MatchingStrategy,
+    /// Parse the `attribute:term` and `(term OR term)` operators of the query
+    #[request(default, error = DeserrJsonError<InvalidSearchAdvancedQuerySyntax>)]
+    pub advanced_query_syntax: bool,
+    /// Number of alternative queries with corrected typos to return
+    #[request(default, error = DeserrJsonError<InvalidSearchSuggestions>)]
+    pub suggestions: usize,
     /// Restrict search to the specified attributes
     #[request(default, error = DeserrJsonError<InvalidSearchAttributesToSearchOn>)]
     pub attributes_to_search_on: Option<Vec<String>>,
@@ -900,6 +1077,12 @@ pub struct SearchQueryWithIndex {
     /// Adds a detailed performance details field
     #[request(default, error = DeserrJsonError<InvalidSearchShowPerformanceDetails>)]
     pub show_performance_details: Option<bool>,
+    /// Custom claims of the tenant token used for this search, if any
+    #[request(skip)]
+    pub tenant_token_claims: Option<serde_json::Map<String, Value>>,
+    /// Attributes that the tenant token used for this search allows to retrieve, if restricted
+    #[request(skip)]
+    pub tenant_token_attributes: Option<Vec<String>>,
     /// Federation options for multi-index search
     #[request(default)]
     pub federation_options: Option<FederationOptions>,
@@ -924,6 +1107,10 @@ impl SearchQueryWithIndex {
         self.facets.as_ref().filter(|v| !v.is_empty())
     }
 
+    pub fn has_facet_aggregations(&self) -> bool {
+        self.facet_aggregations.as_ref().is_some_and(|v| !v.is_empty())
+    }
+
     pub fn has_personalize(&self) -> bool {
         self.personalize.is_some()
     }
@@ -936,6 +1123,10 @@ impl SearchQueryWithIndex {
         self.show_performance_details.is_some()
     }
 
+    pub fn has_suggestions(&self) -> bool {
+        self.suggestions > 0
+    }
+
     fn has_distinct(&self) -> bool {
         self.distinct.is_some()
     }
@@ -963,7 +1154,10 @@ impl SearchQueryWithIndex {
             sort,
             distinct,
             facets,
+            facet_aggregations,
             matching_strategy,
+            advanced_query_syntax,
+            suggestions,
             attributes_to_search_on,
             ranking_score_threshold,
             locales,
@@ -976,6 +1170,8 @@ impl SearchQueryWithIndex {
             show_ranking_score,
             show_ranking_score_details,
             show_performance_details,
+            tenant_token_claims,
+            tenant_token_attributes,
         } = query;
 
         SearchQueryWithIndex {
@@ -997,7 +1193,10 @@ impl SearchQueryWithIndex {
             sort,
             distinct,
             facets,
+            facet_aggregations,
             matching_strategy,
+            advanced_query_syntax,
+            suggestions,
             attributes_to_search_on,
             ranking_score_threshold,
             locales,
@@ -1010,6 +1209,8 @@ impl SearchQueryWithIndex {
             show_ranking_score,
             show_ranking_score_details,
             show_performance_details: show_performance_details.then_some(true),
+            tenant_token_claims,
+            tenant_token_attributes,
             federation_options,
         }
     }
@@ -1034,7 +1235,10 @@ impl SearchQueryWithIndex {
             sort,
             distinct,
             facets,
+            facet_aggregations,
             matching_strategy,
+            advanced_query_syntax,
+            suggestions,
             attributes_to_search_on,
             ranking_score_threshold,
             locales,
@@ -1047,6 +1251,8 @@ impl SearchQueryWithIndex {
             show_ranking_score,
             show_ranking_score_details,
             show_performance_details,
+            tenant_token_claims,
+            tenant_token_attributes,
             federation_options,
         } = self;
         (
@@ -1069,7 +1275,10 @@ impl SearchQueryWithIndex {
                 sort,
                 distinct,
                 facets,
+                facet_aggregations,
                 matching_strategy,
+                advanced_query_syntax,
+                suggestions,
                 attributes_to_search_on,
                 ranking_score_threshold,
                 locales,
@@ -1082,6 +1291,8 @@ impl SearchQueryWithIndex {
                 show_ranking_score,
                 show_ranking_score_details,
                 show_performance_details: show_performance_details.unwrap_or_default(),
+                tenant_token_claims,
+                tenant_token_attributes,
                 // do not use ..Default::default() here,
                 // rather add any missing field from `SearchQuery` to `SearchQueryWithIndex`
             },
@@ -1129,6 +1340,28 @@ pub struct SimilarQuery {
     pub ranking_score_threshold: Option<RankingScoreThresholdSimilar>,
 }
 
+/// Request body for completing the last word of a query
+#[routes::request]
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutocompleteQuery {
+    /// Query whose last word is completed. The previous words of the query restrict the
+    /// completions to the documents containing them.
+    #[request(default, error = DeserrJsonError<InvalidSearchQ>)]
+    pub q: Option<String>,
+    /// Maximum number of completions returned, capped by the `maxTotalHits` of the index
+    #[request(default = DEFAULT_AUTOCOMPLETE_LIMIT(), schema_default = DEFAULT_AUTOCOMPLETE_LIMIT, error = DeserrJsonError<InvalidSearchLimit>)]
+    pub limit: usize,
+    /// Filter queries by an attribute's value
+    #[request(default, error = DeserrJsonError<InvalidSearchFilter>)]
+    pub filter: Option<Value>,
+    /// Only complete the words of these attributes
+    #[request(default, error = DeserrJsonError<InvalidSearchAttributesToSearchOn>)]
+    pub attributes_to_search_on: Option<Vec<String>>,
+    /// Languages to use for query processing
+    #[request(default, error = DeserrJsonError<InvalidSearchLocales>)]
+    pub locales: Option<Vec<Locale>>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ExternalDocumentId(String);
 
@@ -1393,6 +1626,11 @@ pub struct SearchResult {
     /// Present for numeric facets when `facets` was set.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub facet_stats: Option<BTreeMap<String, FacetStats>>,
+    /// Count of matching documents per numeric bucket for each requested facet aggregation.
+    ///
+    /// Present when `facetAggregations` was set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_aggregations: Option<BTreeMap<String, Vec<FacetBucket>>>,
     /// UUID v7 identifying this search request.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_uid: Option<Uuid>,
@@ -1418,6 +1656,18 @@ pub struct SearchResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub semantic_hit_count: Option<u32>,
 
+    /// Uids of the dynamic search rules applied to this search, by ascending precedence.
+    ///
+    /// Present when at least one rule was applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_dynamic_search_rules: Option<Vec<String>>,
+
+    /// Alternative queries where the typos of `q` are corrected, best first.
+    ///
+    /// Present when `suggestions` was set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suggestions: Option<Vec<SearchSuggestion>>,
+
     // These fields are only used for analytics purposes
     #[serde(skip)]
     pub degraded: bool,
@@ -1435,12 +1685,15 @@ impl fmt::Debug for SearchResult {
             hits_info,
             facet_distribution,
             facet_stats,
+            facet_aggregations,
             request_uid,
             metadata,
             semantic_hit_count,
             degraded,
             used_negative_operator,
             remote_errors,
+            applied_dynamic_search_rules,
+            suggestions,
             performance_details: _, // not part of the debug output because it's an Option and is always displayed in a dedicated log.
         } = self;
 
@@ -1465,6 +1718,9 @@ impl fmt::Debug for SearchResult {
         if let Some(facet_stats) = facet_stats {
             debug.field("facet_stats", &facet_stats);
         }
+        if let Some(facet_aggregations) = facet_aggregations {
+            debug.field("facet_aggregations", &facet_aggregations);
+        }
         if let Some(semantic_hit_count) = semantic_hit_count {
             debug.field("semantic_hit_count", &semantic_hit_count);
         }
@@ -1477,11 +1733,35 @@ impl fmt::Debug for SearchResult {
         if let Some(remote_errors) = remote_errors {
             debug.field("remote_errors", &remote_errors);
         }
+        if let Some(applied_dynamic_search_rules) = applied_dynamic_search_rules {
+            debug.field("applied_dynamic_search_rules", &applied_dynamic_search_rules);
+        }
+        if let Some(suggestions) = suggestions {
+            debug.field("suggestions", &suggestions);
+        }
 
         debug.finish()
     }
 }
 
+/// An alternative query where the typos of the search query are corrected.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+pub struct SearchSuggestion {
+    /// The corrected query, made of the normalized words of `q` and of their corrections.
+    pub query: String,
+    /// Number of documents matching the filter that contain all the words of the corrected query.
+    pub estimated_hits: u64,
+}
+
+impl From<milli::QuerySuggestion> for SearchSuggestion {
+    fn from(suggestion: milli::QuerySuggestion) -> Self {
+        let milli::QuerySuggestion { query, estimated_hits, typos: _ } = suggestion;
+        Self { query, estimated_hits }
+    }
+}
+
 /// Response containing similar documents
 #[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -1501,6 +1781,36 @@ pub struct SimilarResult {
     pub performance_details: Option<IndexMap<String, String>>,
 }
 
+/// Response containing the completions of the last word of a query
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(rename_all = "camelCase")]
+pub struct AutocompleteResult {
+    /// Words completing the last word of the query, sorted by descending number of documents.
+    pub completions: Vec<AutocompleteHit>,
+    /// The original `q` from the request. `null` if no query was provided.
+    pub query: Option<String>,
+    /// Time in milliseconds Meilisearch took to process the request.
+    pub processing_time_ms: u128,
+}
+
+/// A word completing the last word of a query
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AutocompleteHit {
+    /// The completed word, normalized as in the index
+    pub value: String,
+    /// The number of documents containing the word and the previous words of the query
+    pub count: u64,
+}
+
+impl From<milli::Completion> for AutocompleteHit {
+    fn from(completion: milli::Completion) -> Self {
+        let milli::Completion { value, count } = completion;
+        Self { value, count }
+    }
+}
+
 /// Search result with index identifier for multi-search responses
 #[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -1560,6 +1870,23 @@ pub struct FacetStats {
     pub max: f64,
 }
 
+/// Number of matching documents whose value falls in a numeric bucket.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct FacetBucket {
+    /// Inclusive lower bound of the bucket, `null` if unbounded.
+    pub from: Option<f64>,
+    /// Exclusive upper bound of the bucket, `null` if unbounded.
+    pub to: Option<f64>,
+    /// Number of matching documents with a value in the bucket.
+    pub count: u64,
+}
+
+impl From<milli::FacetBucket> for FacetBucket {
+    fn from(milli::FacetBucket { from, to, count }: milli::FacetBucket) -> Self {
+        FacetBucket { from, to, count }
+    }
+}
+
 /// Schema representation of a facet value hit (for OpenAPI documentation only).
 #[derive(ToSchema)]
 #[schema(rename_all = "camelCase")]
@@ -1594,6 +1921,170 @@ pub fn add_search_rules(filter: &mut Option<Value>, rules: IndexSearchRules) {
     *filter = fuse_filters(filter.take(), rules.filter);
 }
 
+/// Returns an error if the query filters, sorts or searches on an attribute that the search rules
+/// of a tenant token don't allow to retrieve, as the results would reveal its values.
+pub fn check_attributes_authorized(
+    rules: &IndexSearchRules,
+    filter: Option<&Value>,
+    sort: Option<&[String]>,
+    attributes_to_search_on: Option<&[String]>,
+) -> Result<(), ResponseError> {
+    if !rules.restricts_attributes() {
+        return Ok(());
+    }
+    let unauthorized = |attribute: &str, parameter: &str, code: Code| {
+        Err(ResponseError::from_msg(
+            format!("The tenant token does not allow using the `{attribute}` attribute in `{parameter}`."),
+            code,
+        ))
+    };
+
+    // invalid filters and sorts are reported when the query is performed
+    if let Some(Ok(Some(filter))) = filter.map(milli::Filter::from_json) {
+        if let Some(attribute) = filter.attributes().find(|a| !rules.is_attribute_authorized(a)) {
+            return unauthorized(attribute, "filter", Code::InvalidSearchFilter);
+        }
+    }
+    for sort in sort.into_iter().flatten() {
+        let attribute = match sort.parse::<AscDesc>().map(|sort| sort.member().clone()) {
+            Ok(Member::Field(field)) => field,
+            Ok(Member::Geo(_)) => milli::constants::RESERVED_GEO_FIELD_NAME.to_string(),
+            Err(_) => continue,
+        };
+        if !rules.is_attribute_authorized(&attribute) {
+            return unauthorized(&attribute, "sort", Code::InvalidSearchSort);
+        }
+    }
+    for attribute in attributes_to_search_on.into_iter().flatten() {
+        if !rules.is_attribute_authorized(attribute) {
+            return unauthorized(
+                attribute,
+                "attributesToSearchOn",
+                Code::InvalidSearchAttributesToSearchOn,
+            );
+        }
+    }
+    Ok(())
+}
+
+impl SearchQuery {
+    /// Incorporate the search rules of a tenant token in the search query.
+    pub fn add_search_rules(&mut self, rules: IndexSearchRules) -> Result<(), ResponseError> {
+        check_attributes_authorized(
+            &rules,
+            self.filter.as_ref(),
+            self.sort.as_deref(),
+            self.attributes_to_search_on.as_deref(),
+        )?;
+        self.limit = rules.restrict_limit(self.limit);
+        self.hits_per_page =
+            self.hits_per_page.map(|hits_per_page| rules.restrict_limit(hits_per_page));
+        self.attributes_to_retrieve =
+            rules.restrict_attributes_to_retrieve(self.attributes_to_retrieve.take());
+        self.attributes_to_highlight =
+            rules.restrict_attributes_to_highlight(self.attributes_to_highlight.take());
+        self.attributes_to_crop = rules.restrict_attributes_to_crop(self.attributes_to_crop.take());
+        // the matches positions are computed on all the displayed attributes
+        self.show_matches_position &= !rules.restricts_attributes();
+        self.facets = rules.restrict_facets(self.facets.take());
+        if let Some(facet_aggregations) = &mut self.facet_aggregations {
+            facet_aggregations.retain(|facet, _| rules.is_facet_authorized(facet));
+        }
+        self.tenant_token_attributes = rules.attributes_to_retrieve.clone();
+        add_search_rules(&mut self.filter, rules);
+        Ok(())
+    }
+
+    /// Search on the searchable attributes that the tenant token allows to retrieve
+    /// when `attributesToSearchOn` is omitted, so that the hidden attributes are not matched.
+    pub fn restrict_attributes_to_search_on(
+        &mut self,
+        index: &Index,
+        rtxn: &RoTxn,
+    ) -> Result<(), ResponseError> {
+        let (None, Some(allowed)) = (&self.attributes_to_search_on, &self.tenant_token_attributes)
+        else {
+            return Ok(());
+        };
+        let fields_ids_map = index.fields_ids_map(rtxn)?;
+        let searchable = index.searchable_fields_and_weights(rtxn, &fields_ids_map)?;
+        self.attributes_to_search_on = Some(
+            searchable
+                .into_iter()
+                .map(|(name, _, _)| name.into_owned())
+                .filter(|name| meilisearch_auth::is_attribute_allowed(allowed, name))
+                .collect(),
+        );
+        Ok(())
+    }
+}
+
+impl SearchQueryWithIndex {
+    /// Incorporate the search rules of a tenant token in the search query.
+    ///
+    /// The pagination of federated queries is handled by the federation,
+    /// see [`Federation::add_search_rules`].
+    pub fn add_search_rules(
+        &mut self,
+        rules: IndexSearchRules,
+        federated: bool,
+    ) -> Result<(), ResponseError> {
+        check_attributes_authorized(
+            &rules,
+            self.filter.as_ref(),
+            self.sort.as_deref(),
+            self.attributes_to_search_on.as_deref(),
+        )?;
+        if !federated {
+            self.limit =
+                Some(rules.restrict_limit(self.limit.unwrap_or_else(DEFAULT_SEARCH_LIMIT)));
+            self.hits_per_page =
+                self.hits_per_page.map(|hits_per_page| rules.restrict_limit(hits_per_page));
+        }
+        self.attributes_to_retrieve =
+            rules.restrict_attributes_to_retrieve(self.attributes_to_retrieve.take());
+        self.attributes_to_highlight =
+            rules.restrict_attributes_to_highlight(self.attributes_to_highlight.take());
+        self.attributes_to_crop = rules.restrict_attributes_to_crop(self.attributes_to_crop.take());
+        // the matches positions are computed on all the displayed attributes
+        self.show_matches_position &= !rules.restricts_attributes();
+        self.facets = rules.restrict_facets(self.facets.take());
+        if let Some(facet_aggregations) = &mut self.facet_aggregations {
+            facet_aggregations.retain(|facet, _| rules.is_facet_authorized(facet));
+        }
+        self.tenant_token_attributes = rules.attributes_to_retrieve.clone();
+        add_search_rules(&mut self.filter, rules);
+        Ok(())
+    }
+}
+
+impl SimilarQuery {
+    /// Incorporate the search rules of a tenant token in the similar query.
+    pub fn add_search_rules(&mut self, rules: IndexSearchRules) -> Result<(), ResponseError> {
+        check_attributes_authorized(&rules, self.filter.as_ref(), None, None)?;
+        self.limit = rules.restrict_limit(self.limit);
+        self.attributes_to_retrieve =
+            rules.restrict_attributes_to_retrieve(self.attributes_to_retrieve.take());
+        add_search_rules(&mut self.filter, rules);
+        Ok(())
+    }
+}
+
+impl AutocompleteQuery {
+    /// Incorporate the search rules of a tenant token in the autocomplete query.
+    pub fn add_search_rules(&mut self, rules: IndexSearchRules) -> Result<(), ResponseError> {
+        check_attributes_authorized(
+            &rules,
+            self.filter.as_ref(),
+            None,
+            self.attributes_to_search_on.as_deref(),
+        )?;
+        self.limit = rules.restrict_limit(self.limit);
+        add_search_rules(&mut self.filter, rules);
+        Ok(())
+    }
+}
+
 pub fn fuse_filters(left: Option<Value>, right: Option<Value>) -> Option<Value> {
     match (left, right) {
         (None, right) => right,
@@ -1719,6 +2210,8 @@ pub fn prepare_search<'t>(
 
     let is_finite_pagination = query.is_finite_pagination();
     search.terms_matching_strategy(query.matching_strategy.into());
+    search.advanced_query_syntax(query.advanced_query_syntax);
+    search.suggestions(query.suggestions.min(MAX_SEARCH_SUGGESTIONS));
 
     let max_total_hits = index
         .pagination_max_total_hits(rtxn)
@@ -1776,6 +2269,10 @@ pub fn prepare_search<'t>(
         search.locales(locales.iter().copied().map(Into::into).collect());
     }
 
+    if let Some(ref claims) = query.tenant_token_claims {
+        search.tenant_token_claims(claims);
+    }
+
     Ok((search, is_finite_pagination, max_total_hits, offset))
 }
 
@@ -1821,6 +2318,7 @@ pub fn perform_search(
                 .transpose()
         })
         .transpose()?;
+    query.restrict_attributes_to_search_on(index, &rtxn)?;
 
     let (mut search, is_finite_pagination, max_total_hits, offset) = prepare_search(
         index,
@@ -1856,6 +2354,8 @@ pub fn perform_search(
             degraded,
             used_negative_operator,
             query_vector,
+            applied_rules,
+            suggestions,
         },
         semantic_hit_count,
     ) = search_from_kind(search_kind, search)?;
@@ -1892,7 +2392,10 @@ pub fn perform_search(
         sort,
         distinct: _,
         facets,
+        facet_aggregations,
         matching_strategy: _,
+        advanced_query_syntax: _,
+        suggestions: requested_suggestions,
         attributes_to_search_on: _,
         ranking_score_threshold: _,
         locales,
@@ -1905,6 +2408,8 @@ pub fn perform_search(
         show_ranking_score,
         show_ranking_score_details,
         show_performance_details: _,
+        tenant_token_claims: _,
+        tenant_token_attributes: _,
     } = query;
 
     let format = AttributesFormat {
@@ -1958,6 +2463,19 @@ pub fn perform_search(
         HitsInfo::OffsetLimit { limit, offset, estimated_total_hits: number_of_hits }
     };
 
+    let facet_aggregations = facet_aggregations
+        .map(|aggregations| {
+            let _step = progress.update_progress_scoped(SearchStep::FacetAggregation);
+            compute_facet_aggregations(
+                aggregations,
+                index,
+                &rtxn,
+                &fields_ids_map,
+                candidates.clone(),
+            )
+        })
+        .transpose()?;
+
     let (facet_distribution, facet_stats) = facets
         .map(move |facets| {
             let _step = progress.update_progress_scoped(SearchStep::FacetDistribution);
@@ -1975,12 +2493,16 @@ pub fn perform_search(
         processing_time_ms: elapsed(before_search).as_millis(),
         facet_distribution,
         facet_stats,
+        facet_aggregations,
         degraded,
         used_negative_operator,
         semantic_hit_count,
         request_uid: Some(request_uid),
         metadata,
         remote_errors: None,
+        applied_dynamic_search_rules: (!applied_rules.is_empty()).then_some(applied_rules),
+        suggestions: (requested_suggestions > 0)
+            .then(|| suggestions.into_iter().map(SearchSuggestion::from).collect()),
         performance_details: None,
     };
     Ok((result, deadline))
@@ -2139,6 +2661,27 @@ fn compute_facet_distribution_stats(
     Ok(ComputedFacets { distribution, stats })
 }
 
+fn compute_facet_aggregations(
+    aggregations: BTreeMap<String, FacetAggregationQuery>,
+    index: &Index,
+    rtxn: &RoTxn,
+    fields_ids_map: &FieldsIdsMap,
+    candidates: roaring::RoaringBitmap,
+) -> Result<BTreeMap<String, Vec<FacetBucket>>, ResponseError> {
+    let aggregations = index
+        .facets_aggregations(rtxn, fields_ids_map)
+        .aggregations(
+            aggregations.into_iter().map(|(name, aggregation)| (name, aggregation.into())),
+        )
+        .candidates(candidates)
+        .execute()?;
+
+    Ok(aggregations
+        .into_iter()
+        .map(|(name, buckets)| (name, buckets.into_iter().map(FacetBucket::from).collect()))
+        .collect())
+}
+
 pub fn search_from_kind(
     search_kind: SearchKind,
     search: milli::Search<'_>,
@@ -2589,6 +3132,18 @@ pub fn perform_similar(
     progress: &Progress,
     search_rules: Option<IndexSearchRules>,
 ) -> Result<SimilarResult, ResponseError> {
+    perform_similar_with_documents_ids(index_scheduler, index_uid, query, progress, search_rules)
+        .map(|(result, _)| result)
+}
+
+/// Like [`perform_similar`], but also returns the internal ids of the hits.
+pub fn perform_similar_with_documents_ids(
+    index_scheduler: &IndexScheduler,
+    index_uid: IndexUid,
+    query: SimilarQuery,
+    progress: &Progress,
+    search_rules: Option<IndexSearchRules>,
+) -> Result<(SimilarResult, Vec<DocumentId>), ResponseError> {
     let before_search = Instant::now();
     let features = index_scheduler.features();
     let index = index_scheduler.user_index(&index_uid)?;
@@ -2680,6 +3235,8 @@ pub fn perform_similar(
         degraded: _,
         used_negative_operator: _,
         query_vector: _,
+        applied_rules: _,
+        suggestions: _,
     } = similar.execute().map_err(|err| match err {
         milli::Error::UserError(milli::UserError::InvalidFilter(_)) => {
             ResponseError::from_msg(err.to_string(), Code::InvalidSimilarFilter)
@@ -2732,7 +3289,70 @@ pub fn perform_similar(
         processing_time_ms: before_search.elapsed().as_millis(),
         performance_details,
     };
-    Ok(result)
+    Ok((result, documents_ids))
+}
+
+pub fn perform_autocomplete(
+    index_scheduler: &IndexScheduler,
+    index_uid: IndexUid,
+    query: AutocompleteQuery,
+    progress: &Progress,
+) -> Result<AutocompleteResult, ResponseError> {
+    let before_search = Instant::now();
+    let features = index_scheduler.features();
+    let index = index_scheduler.user_index(&index_uid)?;
+    let rtxn = index.read_txn()?;
+    let fields_ids_map = index.fields_ids_map(&rtxn)?;
+
+    let AutocompleteQuery { q, limit, filter, attributes_to_search_on, locales } = query;
+
+    // the completions are capped like the hits of a search
+    let max_total_hits = index
+        .pagination_max_total_hits(&rtxn)
+        .map_err(milli::Error::from)?
+        .map(|x| x as usize)
+        .unwrap_or(DEFAULT_PAGINATION_MAX_TOTAL_HITS);
+    let deadline = index.search_deadline(&rtxn)?;
+
+    let filter = filter
+        .and_then(|filter| {
+            parse_local_index_filter(&filter, Some(&index_uid), features, Code::InvalidSearchFilter)
+                .transpose()
+        })
+        .transpose()?;
+
+    let mut autocomplete = milli::Autocomplete::new(
+        q.clone().unwrap_or_default(),
+        &index,
+        &rtxn,
+        &fields_ids_map,
+        index_uid.as_str(),
+        progress,
+    );
+    autocomplete.limit(min(limit, max_total_hits));
+    autocomplete.deadline(deadline);
+
+    if let Some(filter) = filter {
+        autocomplete.filter(filter);
+    }
+
+    if let Some(attributes_to_search_on) = &attributes_to_search_on {
+        autocomplete.searchable_attributes(attributes_to_search_on);
+    }
+
+    if let Some(locales) = locales {
+        autocomplete.locales(locales.into_iter().map(Into::into).collect());
+    }
+
+    let completions = autocomplete
+        .execute()
+        .map_err(|err| MeilisearchHttpError::from_milli(err, Some(index_uid.to_string())))?;
+
+    Ok(AutocompleteResult {
+        completions: completions.into_iter().map(AutocompleteHit::from).collect(),
+        query: q,
+        processing_time_ms: before_search.elapsed().as_millis(),
+    })
 }
 
 fn extract_filters(
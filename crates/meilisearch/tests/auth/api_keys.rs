@@ -440,7 +440,7 @@ async fn error_add_api_key_invalid_parameters_actions() {
     meili_snap::snapshot!(code, @"400 Bad Request");
     meili_snap::snapshot!(meili_snap::json_string!(response, { ".createdAt" => "[ignored]", ".updatedAt" => "[ignored]" }), @r#"
     {
-      "message": "Unknown value `doc.add` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`, `export`, `network.get`, `network.update`, `chatCompletions`, `chats.*`, `chats.get`, `chats.delete`, `chatsSettings.*`, `chatsSettings.get`, `chatsSettings.update`, `*.get`, `webhooks.get`, `webhooks.update`, `webhooks.delete`, `webhooks.create`, `webhooks.*`, `indexes.compact`, `fields.post`, `tasks.compact`, `dynamicSearchRules.get`, `dynamicSearchRules.create`, `dynamicSearchRules.update`, `dynamicSearchRules.delete`, `dynamicSearchRules.*`",
+      "message": "Unknown value `doc.add` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`, `export`, `network.get`, `network.update`, `chatCompletions`, `chats.*`, `chats.get`, `chats.delete`, `chatsSettings.*`, `chatsSettings.get`, `chatsSettings.update`, `*.get`, `webhooks.get`, `webhooks.update`, `webhooks.delete`, `webhooks.create`, `webhooks.*`, `indexes.compact`, `fields.post`, `tasks.compact`, `dynamicSearchRules.get`, `dynamicSearchRules.create`, `dynamicSearchRules.update`, `dynamicSearchRules.delete`, `dynamicSearchRules.*`, `schedules.get`, `schedules.create`, `schedules.update`, `schedules.delete`, `schedules.*`, `dumps.import`, `tasks.prioritize`",
       "code": "invalid_api_key_actions",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_api_key_actions"
@@ -652,6 +652,11 @@ async fn get_api_key() {
         "products"
       ],
       "expiresAt": "2050-11-13T00:00:00Z",
+      "usage": {
+        "requests": 0,
+        "rateLimitedRequests": 0,
+        "concurrentSearches": 0
+      },
       "createdAt": "[ignored]",
       "updatedAt": "[ignored]"
     }
@@ -683,6 +688,11 @@ async fn get_api_key() {
         "products"
       ],
       "expiresAt": "2050-11-13T00:00:00Z",
+      "usage": {
+        "requests": 0,
+        "rateLimitedRequests": 0,
+        "concurrentSearches": 0
+      },
       "createdAt": "[ignored]",
       "updatedAt": "[ignored]"
     }
@@ -1805,3 +1815,118 @@ async fn error_access_api_key_routes_no_master_key_set() {
     "###);
     meili_snap::snapshot!(code, @"401 Unauthorized");
 }
+
+#[actix_rt::test]
+async fn rate_limited_api_key() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+    let (_, code) = server.set_features(json!({ "metrics": true })).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    let (task, _) = server.index("products").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = server
+        .add_api_key(json!({
+            "uid": "6a1c8a8f-1d3b-4e0c-9d0a-7c2f1b4e5d6a",
+            "actions": ["search"],
+            "indexes": ["products"],
+            "expiresAt": null,
+            "rateLimits": { "requestsPerSecond": 1 },
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"201 Created");
+    meili_snap::snapshot!(meili_snap::json_string!(response["rateLimits"]), @r###"
+    {
+      "requestsPerSecond": 1
+    }
+    "###);
+    let key = response["key"].as_str().unwrap().to_string();
+
+    let app = server.init_web_app().await;
+    let search = || {
+        actix_web::test::TestRequest::post()
+            .uri("/indexes/products/search")
+            .insert_header(("Authorization", format!("Bearer {key}")))
+            .set_json(serde_json::json!({}))
+            .to_request()
+    };
+    let res = actix_web::test::call_service(&app, search()).await;
+    meili_snap::snapshot!(res.status(), @"200 OK");
+    let res = actix_web::test::call_service(&app, search()).await;
+    meili_snap::snapshot!(res.status(), @"429 Too Many Requests");
+    meili_snap::snapshot!(res.headers().get("retry-after").unwrap().to_str().unwrap(), @"1");
+    let response: Value =
+        serde_json::from_slice(&actix_web::test::read_body(res).await).unwrap_or_default();
+    meili_snap::snapshot!(response, @r###"
+    {
+      "message": "The API key exceeded its limit of 1 requests per second. Retry after 1 seconds.",
+      "code": "api_key_rate_limit_exceeded",
+      "type": "auth",
+      "link": "https://docs.meilisearch.com/errors#api_key_rate_limit_exceeded"
+    }
+    "###);
+
+    let (response, code) = server.get_api_key("6a1c8a8f-1d3b-4e0c-9d0a-7c2f1b4e5d6a").await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["usage"]), @r###"
+    {
+      "requests": 2,
+      "rateLimitedRequests": 1,
+      "concurrentSearches": 0
+    }
+    "###);
+
+    let (metrics, code) =
+        server.service.request_text(actix_web::test::TestRequest::get().uri("/metrics")).await;
+    meili_snap::snapshot!(code, @"200 OK");
+    assert!(
+        metrics.contains(r#"meilisearch_api_key_requests_total{key_uid="6a1c8a8f-1d3b-4e0c-9d0a-7c2f1b4e5d6a",outcome="rate_limited"} 1"#),
+        "{metrics}"
+    );
+
+    // removing the limits lifts them immediately
+    let (response, code) = server
+        .patch_api_key("6a1c8a8f-1d3b-4e0c-9d0a-7c2f1b4e5d6a", json!({ "rateLimits": null }))
+        .await;
+    meili_snap::snapshot!(code, @"200 OK");
+    assert!(response.get("rateLimits").is_none(), "{response}");
+    let res = actix_web::test::call_service(&app, search()).await;
+    meili_snap::snapshot!(res.status(), @"200 OK");
+}
+
+#[actix_rt::test]
+async fn route_requiring_two_actions_counts_the_request_once() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+
+    let (response, code) = server
+        .add_api_key(json!({
+            "uid": "0b5b3e5e-45b8-4c52-a3a2-8d5d1f6b2a7c",
+            "actions": ["settings.get", "documents.get"],
+            "indexes": ["*"],
+            "expiresAt": null,
+            "rateLimits": { "requestsPerSecond": 1 },
+        }))
+        .await;
+    meili_snap::snapshot!(code, @"201 Created");
+    let key = response["key"].as_str().unwrap().to_string();
+
+    // the render route requires both the `settings.get` and `documents.get` actions
+    server.use_api_key(&key);
+    let (response, code) = server
+        .render_template(json!({ "template": { "kind": "documentTemplate", "indexUid": "test", "embedder": "rest" } }))
+        .await;
+    meili_snap::snapshot!(code, @"400 Bad Request");
+    meili_snap::snapshot!(response["code"], @r###""feature_not_enabled""###);
+
+    server.use_api_key("MASTER_KEY");
+    let (response, code) = server.get_api_key("0b5b3e5e-45b8-4c52-a3a2-8d5d1f6b2a7c").await;
+    meili_snap::snapshot!(code, @"200 OK");
+    meili_snap::snapshot!(meili_snap::json_string!(response["usage"]), @r###"
+    {
+      "requests": 1,
+      "rateLimitedRequests": 0,
+      "concurrentSearches": 0
+    }
+    "###);
+}
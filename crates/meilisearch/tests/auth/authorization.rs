@@ -101,6 +101,7 @@ pub static AUTHORIZATIONS: Lazy<AuthorizationMap> = Lazy::new(|| {
         ("GET",     "/indexes/products/stats", Allow) =>                          str_hashmap!{&["stats.get"] => Allow, &["stats.*"] => Allow, &["*"] => Allow},
         ("GET",     "/stats", Allow) =>                                           str_hashmap!{&["stats.get"] => Allow, &["stats.*"] => Allow, &["*"] => Allow},
         ("POST",    "/dumps", Deny) =>                                           str_hashmap!{&["dumps.create"] => Deny, &["dumps.*"] => Deny, &["*"] => Allow},
+        ("POST",    "/dumps/import", Deny) =>                                    str_hashmap!{&["dumps.import"] => Deny, &["dumps.*"] => Deny, &["*"] => Allow},
         ("POST",    "/snapshots", Deny) =>                                       str_hashmap!{&["snapshots.create"] => Deny, &["snapshots.*"] => Deny, &["*"] => Allow},
         ("GET",     "/version", Deny) =>                                         str_hashmap!{&["version"] => Deny, &["*"] => Allow},
         ("GET",     "/metrics", Deny) =>                                         str_hashmap!{&["metrics.get"] => Deny, &["metrics.*"] => Deny, &["*"] => Allow},
@@ -1004,6 +1005,52 @@ async fn error_creating_index_without_action() {
     assert_eq!(response["error"], expected_error.clone());
 }
 
+#[actix_rt::test]
+async fn error_prioritizing_task_without_action() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key(MASTER_KEY);
+
+    let content = json!({
+        "indexes": ["*"],
+        "actions": ["documents.add", "indexes.create", "tasks.get"],
+        "expiresAt": "2050-11-13T00:00:00Z"
+    });
+    let (response, code) = server.add_api_key(content).await;
+    assert_eq!(201, code, "{:?}", &response);
+    let key = response["key"].as_str().unwrap();
+    server.use_api_key(key);
+
+    let documents = json!([{ "id": 1, "content": "foo" }]);
+
+    // the default priority doesn't require any additional action.
+    let (response, code) =
+        server.service.post("/indexes/test/documents?priority=normal", documents.clone()).await;
+    assert_eq!(202, code, "{:?}", &response);
+
+    let (response, code) =
+        server.service.post("/indexes/test/documents?priority=high", documents.clone()).await;
+    assert_eq!(403, code, "{:?}", &response);
+    assert_eq!(response["code"], "task_priority_not_allowed");
+    assert_eq!(response["type"], "auth");
+
+    // `tasks.*` includes `tasks.prioritize`.
+    server.use_api_key(MASTER_KEY);
+    let content = json!({
+        "indexes": ["*"],
+        "actions": ["documents.add", "indexes.create", "tasks.*"],
+        "expiresAt": "2050-11-13T00:00:00Z"
+    });
+    let (response, code) = server.add_api_key(content).await;
+    assert_eq!(201, code, "{:?}", &response);
+    let key = response["key"].as_str().unwrap();
+    server.use_api_key(key);
+
+    let (response, code) =
+        server.service.post("/indexes/test/documents?priority=low", documents).await;
+    assert_eq!(202, code, "{:?}", &response);
+    assert_eq!(response["priority"], "low");
+}
+
 #[actix_rt::test]
 async fn lazy_create_index() {
     let mut server = Server::new_auth().await;
@@ -93,7 +93,7 @@ async fn create_api_key_bad_actions() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r#"
     {
-      "message": "Unknown value `doggo` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`, `export`, `network.get`, `network.update`, `chatCompletions`, `chats.*`, `chats.get`, `chats.delete`, `chatsSettings.*`, `chatsSettings.get`, `chatsSettings.update`, `*.get`, `webhooks.get`, `webhooks.update`, `webhooks.delete`, `webhooks.create`, `webhooks.*`, `indexes.compact`, `fields.post`, `tasks.compact`, `dynamicSearchRules.get`, `dynamicSearchRules.create`, `dynamicSearchRules.update`, `dynamicSearchRules.delete`, `dynamicSearchRules.*`",
+      "message": "Unknown value `doggo` at `.actions[0]`: expected one of `*`, `search`, `documents.*`, `documents.add`, `documents.get`, `documents.delete`, `indexes.*`, `indexes.create`, `indexes.get`, `indexes.update`, `indexes.delete`, `indexes.swap`, `tasks.*`, `tasks.cancel`, `tasks.delete`, `tasks.get`, `settings.*`, `settings.get`, `settings.update`, `stats.*`, `stats.get`, `metrics.*`, `metrics.get`, `dumps.*`, `dumps.create`, `snapshots.*`, `snapshots.create`, `version`, `keys.create`, `keys.get`, `keys.update`, `keys.delete`, `experimental.get`, `experimental.update`, `export`, `network.get`, `network.update`, `chatCompletions`, `chats.*`, `chats.get`, `chats.delete`, `chatsSettings.*`, `chatsSettings.get`, `chatsSettings.update`, `*.get`, `webhooks.get`, `webhooks.update`, `webhooks.delete`, `webhooks.create`, `webhooks.*`, `indexes.compact`, `fields.post`, `tasks.compact`, `dynamicSearchRules.get`, `dynamicSearchRules.create`, `dynamicSearchRules.update`, `dynamicSearchRules.delete`, `dynamicSearchRules.*`, `schedules.get`, `schedules.create`, `schedules.update`, `schedules.delete`, `schedules.*`, `dumps.import`, `tasks.prioritize`",
       "code": "invalid_api_key_actions",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_api_key_actions"
@@ -141,7 +141,7 @@ async fn create_api_key_bad_expires_at() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Unknown field `expires_at`: did you mean `expiresAt`? expected one of `description`, `name`, `uid`, `actions`, `indexes`, `expiresAt`",
+      "message": "Unknown field `expires_at`: did you mean `expiresAt`? expected one of `description`, `name`, `uid`, `actions`, `indexes`, `expiresAt`, `rateLimits`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -153,7 +153,7 @@ async fn create_api_key_bad_expires_at() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Unknown field `expires_at`: did you mean `expiresAt`? expected one of `description`, `name`, `uid`, `actions`, `indexes`, `expiresAt`",
+      "message": "Unknown field `expires_at`: did you mean `expiresAt`? expected one of `description`, `name`, `uid`, `actions`, `indexes`, `expiresAt`, `rateLimits`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -228,7 +228,7 @@ async fn create_api_key_unexpected_field() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Unknown field `doggo`: expected one of `description`, `name`, `uid`, `actions`, `indexes`, `expiresAt`",
+      "message": "Unknown field `doggo`: expected one of `description`, `name`, `uid`, `actions`, `indexes`, `expiresAt`, `rateLimits`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -330,7 +330,7 @@ async fn patch_api_keys_immutable_uid() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Immutable field `uid`: expected one of `description`, `name`",
+      "message": "Immutable field `uid`: expected one of `description`, `name`, `rateLimits`",
       "code": "immutable_api_key_uid",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_uid"
@@ -347,7 +347,7 @@ async fn patch_api_keys_immutable_actions() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Immutable field `actions`: expected one of `description`, `name`",
+      "message": "Immutable field `actions`: expected one of `description`, `name`, `rateLimits`",
       "code": "immutable_api_key_actions",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_actions"
@@ -364,7 +364,7 @@ async fn patch_api_keys_immutable_indexes() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Immutable field `indexes`: expected one of `description`, `name`",
+      "message": "Immutable field `indexes`: expected one of `description`, `name`, `rateLimits`",
       "code": "immutable_api_key_indexes",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_indexes"
@@ -381,7 +381,7 @@ async fn patch_api_keys_immutable_expires_at() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Immutable field `expiresAt`: expected one of `description`, `name`",
+      "message": "Immutable field `expiresAt`: expected one of `description`, `name`, `rateLimits`",
       "code": "immutable_api_key_expires_at",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_expires_at"
@@ -398,7 +398,7 @@ async fn patch_api_keys_immutable_created_at() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Immutable field `createdAt`: expected one of `description`, `name`",
+      "message": "Immutable field `createdAt`: expected one of `description`, `name`, `rateLimits`",
       "code": "immutable_api_key_created_at",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_created_at"
@@ -415,7 +415,7 @@ async fn patch_api_keys_immutable_updated_at() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Immutable field `updatedAt`: expected one of `description`, `name`",
+      "message": "Immutable field `updatedAt`: expected one of `description`, `name`, `rateLimits`",
       "code": "immutable_api_key_updated_at",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_api_key_updated_at"
@@ -432,7 +432,7 @@ async fn patch_api_keys_unknown_field() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(response), @r###"
     {
-      "message": "Unknown field `doggo`: expected one of `description`, `name`",
+      "message": "Unknown field `doggo`: expected one of `description`, `name`, `rateLimits`",
       "code": "bad_request",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#bad_request"
@@ -2,6 +2,7 @@ mod api_keys;
 mod authorization;
 mod errors;
 mod payload;
+mod signing_keys;
 mod tenant_token;
 
 mod tenant_token_multi_search;
@@ -568,3 +568,154 @@ async fn error_access_modified_token() {
     assert_eq!(response, INVALID_RESPONSE.clone());
     assert_eq!(code, 403);
 }
+
+#[actix_rt::test]
+async fn search_restricted_by_tenant_token() {
+    let mut server = Server::new_auth().await;
+    server.use_admin_key("MASTER_KEY").await;
+    let index = server.index("sales");
+    let documents = json!([
+        { "id": 1, "name": "Vintage lamp", "brand": "Lumen", "costPrice": 12 },
+        { "id": 2, "name": "Modern lamp", "brand": "Lumen", "costPrice": 30 },
+        { "id": 3, "name": "Desk lamp", "brand": "Brightly", "costPrice": 8 },
+    ]);
+    let (task, _status_code) = index.add_documents(documents, None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _status_code) =
+        index.update_settings(json!({"filterableAttributes": ["brand", "costPrice"]})).await;
+    server.wait_task(task.uid()).await.succeeded();
+    drop(index);
+
+    let (response, code) = server.add_api_key(ACCEPTED_KEYS[1].clone()).await;
+    assert_eq!(code, 201);
+    let key = response["key"].as_str().unwrap();
+    let uid = response["uid"].as_str().unwrap();
+
+    let tenant_token = hashmap! {
+        "searchRules" => json!({
+            "sales": {
+                "attributesToRetrieve": ["id", "name"],
+                "attributesToHighlight": ["name"],
+                "facets": ["brand"],
+                "limit": 2
+            }
+        }),
+        "exp" => json!((OffsetDateTime::now_utc() + Duration::hours(1)).unix_timestamp())
+    };
+    let web_token = generate_tenant_token(uid, key, tenant_token);
+    server.use_api_key(&web_token);
+    let index = server.index("sales");
+
+    let keys = |value: &serde_json::Value| {
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort_unstable();
+        keys
+    };
+
+    let (response, code) = index
+        .search_post(json!({
+            "q": "lamp",
+            "attributesToRetrieve": ["*"],
+            "attributesToHighlight": ["*"],
+            "attributesToCrop": ["costPrice:2"],
+            "showMatchesPosition": true,
+            "facets": ["*"],
+            "limit": 10
+        }))
+        .await;
+    assert_eq!(code, 200, "{response}");
+    let hits = response["hits"].as_array().unwrap();
+    assert_eq!(hits.len(), 2, "{response}");
+    for hit in hits {
+        assert_eq!(keys(hit), ["_formatted", "id", "name"], "{response}");
+        assert_eq!(keys(&hit["_formatted"]), ["id", "name"], "{response}");
+    }
+    assert_eq!(keys(&response["facetDistribution"]), ["brand"], "{response}");
+
+    let (response, code) = index.search_post(json!({ "hitsPerPage": 10 })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"].as_array().unwrap().len(), 2, "{response}");
+    assert_eq!(response["hitsPerPage"], json!(2), "{response}");
+
+    let (response, code) = index.facet_search(json!({ "facetName": "brand" })).await;
+    assert_eq!(code, 200, "{response}");
+    let (response, code) = index.facet_search(json!({ "facetName": "costPrice" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], json!("invalid_facet_search_facet_name"), "{response}");
+
+    let (response, code) = server
+        .multi_search(json!({
+            "federation": { "limit": 10, "facetsByIndex": { "sales": ["brand", "costPrice"] } },
+            "queries": [{ "indexUid": "sales", "q": "lamp" }]
+        }))
+        .await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"].as_array().unwrap().len(), 2, "{response}");
+    assert_eq!(keys(&response["facetsByIndex"]["sales"]["distribution"]), ["brand"], "{response}");
+
+    // the hidden attributes can't be used to filter, sort or search either
+    let (response, code) = index.search_post(json!({ "filter": "costPrice > 10" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], json!("invalid_search_filter"), "{response}");
+    assert_eq!(
+        response["message"],
+        json!("The tenant token does not allow using the `costPrice` attribute in `filter`."),
+        "{response}"
+    );
+    let (response, code) = index.search_post(json!({ "sort": ["costPrice:asc"] })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], json!("invalid_search_sort"), "{response}");
+    let (response, code) =
+        index.search_post(json!({ "q": "lamp", "attributesToSearchOn": ["brand"] })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], json!("invalid_search_attributes_to_search_on"), "{response}");
+    let (response, code) =
+        index.search_post(json!({ "q": "lamp", "attributesToSearchOn": ["name"] })).await;
+    assert_eq!(code, 200, "{response}");
+
+    let (response, code) = server
+        .multi_search(json!({
+            "queries": [{ "indexUid": "sales", "q": "lamp", "filter": ["costPrice > 10"] }]
+        }))
+        .await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(
+        response["message"],
+        json!("Inside `.queries[0]`: The tenant token does not allow using the `costPrice` attribute in `filter`."),
+        "{response}"
+    );
+
+    let (response, code) =
+        index.facet_search(json!({ "facetName": "brand", "filter": "costPrice > 10" })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], json!("invalid_search_filter"), "{response}");
+    let (response, code) = index
+        .facet_search(
+            json!({ "facetName": "brand", "q": "lamp", "attributesToSearchOn": ["brand"] }),
+        )
+        .await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], json!("invalid_search_attributes_to_search_on"), "{response}");
+
+    // without `attributesToSearchOn`, only the attributes allowed by the token are searched
+    let (response, code) = index.search_post(json!({ "q": "30" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([]), "{response}");
+    let (response, code) = index.search_post(json!({ "q": "lumen" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([]), "{response}");
+    let (response, code) = index.search_post(json!({ "q": "modern" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([{ "id": 2, "name": "Modern lamp" }]), "{response}");
+    let (response, code) = server
+        .multi_search(json!({
+            "federation": {},
+            "queries": [{ "indexUid": "sales", "q": "30" }]
+        }))
+        .await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["hits"], json!([]), "{response}");
+    let (response, code) = index.facet_search(json!({ "facetName": "brand", "q": "30" })).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["facetHits"], json!([]), "{response}");
+}
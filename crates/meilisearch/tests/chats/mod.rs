@@ -0,0 +1,335 @@
+use actix_web::test;
+use meili_snap::{json_string, snapshot};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+use crate::common::{Server, Value};
+use crate::json;
+
+/// Mocks an OpenAI-compatible LLM that first calls the given tool
+/// and then answers with the result of the tool call.
+async fn llm_mock_server(tool_name: &str, arguments: Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    let tool_name = tool_name.to_string();
+    let arguments = arguments.0.to_string();
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(move |req: &Request| {
+            let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+            let last_message = body["messages"].as_array().unwrap().last().unwrap();
+            let choices = if last_message["role"] == "tool" {
+                vec![
+                    serde_json::json!({ "index": 0, "delta": { "role": "assistant", "content": last_message["content"] }, "finish_reason": null }),
+                    serde_json::json!({ "index": 0, "delta": {}, "finish_reason": "stop" }),
+                ]
+            } else {
+                vec![
+                    serde_json::json!({ "index": 0, "delta": { "role": "assistant", "tool_calls": [{
+                        "index": 0,
+                        "id": "call_0",
+                        "type": "function",
+                        "function": { "name": tool_name, "arguments": arguments },
+                    }] }, "finish_reason": null }),
+                    serde_json::json!({ "index": 0, "delta": {}, "finish_reason": "tool_calls" }),
+                ]
+            };
+
+            let mut sse = String::new();
+            for choice in choices {
+                let chunk = serde_json::json!({
+                    "id": "chatcmpl-0",
+                    "object": "chat.completion.chunk",
+                    "created": 0,
+                    "model": "gpt-4o",
+                    "choices": [choice],
+                });
+                sse.push_str(&format!("data: {chunk}\n\n"));
+            }
+            sse.push_str("data: [DONE]\n\n");
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_string(sse)
+        })
+        .mount(&mock_server)
+        .await;
+
+    mock_server
+}
+
+/// Sets up a `products` index and a `my-workspace` chat using the LLM mock.
+async fn setup_chat(server: &Server, mock_server: &MockServer) {
+    let (_, code) = server.set_features(json!({ "chatCompletions": true })).await;
+    snapshot!(code, @"200 OK");
+    let (_, code) = server
+        .service
+        .patch(
+            "/chats/my-workspace/settings",
+            json!({ "source": "openAi", "baseUrl": mock_server.uri(), "apiKey": "sk-test" }),
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+
+    let index = server.index("products");
+    let (task, _) = index
+        .update_settings(json!({
+            "filterableAttributes": ["brand"],
+            "embedders": { "manual": { "source": "userProvided", "dimensions": 3 } },
+        }))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _) = index
+        .add_documents(
+            json!([
+                { "id": 1, "name": "iPhone", "brand": "Apple", "_vectors": { "manual": [1.0, 0.0, 0.0] } },
+                { "id": 2, "name": "iPad", "brand": "Apple", "_vectors": { "manual": [0.9, 0.1, 0.0] } },
+                { "id": 3, "name": "Galaxy", "brand": "Samsung", "_vectors": { "manual": [0.8, 0.2, 0.0] } },
+            ]),
+            None,
+        )
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+}
+
+/// Returns the streamed answer to a question, asked with the API key of the server.
+async fn ask(server: &Server) -> String {
+    let req = test::TestRequest::post().uri("/chats/my-workspace/chat/completions").set_json(
+        serde_json::json!({
+            "model": "gpt-4o",
+            "stream": true,
+            "messages": [{ "role": "user", "content": "Tell me about your products" }],
+        }),
+    );
+    let (response, code) = server.service.request_text(req).await;
+    snapshot!(code, @"200 OK");
+    response
+}
+
+/// Sets up a `products` index and a `my-workspace` chat using the LLM mock,
+/// then returns the streamed answer to a question.
+async fn chat_with_tool(tool_name: &str, arguments: Value) -> String {
+    let mock_server = llm_mock_server(tool_name, arguments).await;
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+    setup_chat(&server, &mock_server).await;
+    ask(&server).await
+}
+
+/// Same as [`chat_with_tool`], but asks the question with a tenant token
+/// only giving access to the Apple products.
+async fn chat_with_tool_as_tenant(tool_name: &str, arguments: Value) -> String {
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    let mock_server = llm_mock_server(tool_name, arguments).await;
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+    setup_chat(&server, &mock_server).await;
+
+    let (key, code) = server
+        .add_api_key(json!({
+            "indexes": ["*"],
+            "actions": ["search", "chatCompletions"],
+            "expiresAt": null,
+        }))
+        .await;
+    snapshot!(code, @"201 Created");
+    let claims = serde_json::json!({
+        "apiKeyUid": key["uid"],
+        "searchRules": { "products": { "filter": "brand = Apple" } },
+    });
+    let token = encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(key["key"].as_str().unwrap().as_bytes()),
+    )
+    .unwrap();
+
+    server.use_api_key(token);
+    ask(&server).await
+}
+
+#[actix_rt::test]
+async fn conversations_settings() {
+    let server = Server::new().await;
+    let (_, code) = server.set_features(json!({ "chatCompletions": true })).await;
+    snapshot!(code, @"200 OK");
+
+    let (response, code) = server.service.patch("/chats/my-workspace/settings", json!({})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["conversations"]), @r#"
+    {
+      "ttlSeconds": 604800,
+      "maxMessages": 100
+    }
+    "#);
+
+    let (response, code) = server
+        .service
+        .patch("/chats/my-workspace/settings", json!({ "conversations": { "maxMessages": 10 } }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["conversations"]), @r#"
+    {
+      "ttlSeconds": 604800,
+      "maxMessages": 10
+    }
+    "#);
+
+    let (response, code) = server
+        .service
+        .patch("/chats/my-workspace/settings", json!({ "conversations": { "maxMessages": 0 } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r#"
+    {
+      "message": "`conversations.maxMessages` must be greater than 0",
+      "code": "invalid_chat_completion_conversations_max_messages",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_chat_completion_conversations_max_messages"
+    }
+    "#);
+
+    let (response, code) = server
+        .service
+        .patch("/chats/my-workspace/settings", json!({ "conversations": { "ttlSeconds": -1 } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r#""invalid_chat_completion_conversations_ttl_seconds""#);
+
+    let (response, code) = server
+        .service
+        .patch("/chats/my-workspace/settings", json!({ "conversations": null }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["conversations"]), @r#"
+    {
+      "ttlSeconds": 604800,
+      "maxMessages": 100
+    }
+    "#);
+}
+
+#[actix_rt::test]
+async fn list_get_and_delete_conversations() {
+    let server = Server::new().await;
+    let (_, code) = server.set_features(json!({ "chatCompletions": true })).await;
+    snapshot!(code, @"200 OK");
+
+    let (response, code) = server.service.get("/chats/my-workspace/conversations").await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r#""chat_not_found""#);
+
+    let (_, code) = server.service.patch("/chats/my-workspace/settings", json!({})).await;
+    snapshot!(code, @"200 OK");
+
+    let (response, code) = server.service.get("/chats/my-workspace/conversations").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(response, @r#"
+    {
+      "results": [],
+      "offset": 0,
+      "limit": 20,
+      "total": 0
+    }
+    "#);
+
+    let (response, code) =
+        server.service.get("/chats/my-workspace/conversations/my-conversation").await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response, @r#"
+    {
+      "message": "Conversation `my-conversation` not found in chat `my-workspace`",
+      "code": "chat_conversation_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#chat_conversation_not_found"
+    }
+    "#);
+
+    let (response, code) = server
+        .service
+        .delete("/chats/my-workspace/conversations/my-conversation", Default::default())
+        .await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r#""chat_conversation_not_found""#);
+}
+
+#[actix_rt::test]
+async fn get_document_tool() {
+    let response =
+        chat_with_tool("_meiliGetDocument", json!({ "index_uid": "products", "document_id": "3" }))
+            .await;
+    assert!(response.contains("Galaxy"), "{response}");
+    assert!(!response.contains("iPhone"), "{response}");
+
+    let response = chat_with_tool(
+        "_meiliGetDocument",
+        json!({ "index_uid": "products", "document_id": "42" }),
+    )
+    .await;
+    assert!(response.contains("Document `42` not found in the `products` index."), "{response}");
+}
+
+#[actix_rt::test]
+async fn facet_distribution_tool() {
+    let response = chat_with_tool(
+        "_meiliFacetDistribution",
+        json!({ "index_uid": "products", "facet_name": "brand", "filter": "" }),
+    )
+    .await;
+    assert!(response.contains("Apple: 2"), "{response}");
+    assert!(response.contains("Samsung: 1"), "{response}");
+
+    let response = chat_with_tool(
+        "_meiliFacetDistribution",
+        json!({ "index_uid": "products", "facet_name": "brand", "filter": "id > 1" }),
+    )
+    .await;
+    assert!(response.contains("the _meiliFacetDistribution tool call failed"), "{response}");
+}
+
+#[actix_rt::test]
+async fn similar_documents_tool() {
+    let response = chat_with_tool(
+        "_meiliSimilarDocuments",
+        json!({ "index_uid": "products", "document_id": "1", "embedder": "manual" }),
+    )
+    .await;
+    assert!(response.contains("iPad"), "{response}");
+    assert!(response.contains("Galaxy"), "{response}");
+    assert!(!response.contains("iPhone"), "{response}");
+}
+
+#[actix_rt::test]
+async fn tools_enforce_tenant_token_filter() {
+    let response = chat_with_tool_as_tenant(
+        "_meiliSearchInIndex",
+        json!({ "index_uid": "products", "q": "", "filter": null }),
+    )
+    .await;
+    assert!(response.contains("iPhone"), "{response}");
+    assert!(!response.contains("Galaxy"), "{response}");
+
+    let response = chat_with_tool_as_tenant(
+        "_meiliGetDocument",
+        json!({ "index_uid": "products", "document_id": "3" }),
+    )
+    .await;
+    assert!(response.contains("Document `3` not found in the `products` index."), "{response}");
+    assert!(!response.contains("Galaxy"), "{response}");
+
+    let response = chat_with_tool_as_tenant(
+        "_meiliFacetDistribution",
+        json!({ "index_uid": "products", "facet_name": "brand", "filter": "" }),
+    )
+    .await;
+    assert!(response.contains("Apple: 2"), "{response}");
+    assert!(!response.contains("Samsung"), "{response}");
+
+    let response = chat_with_tool_as_tenant(
+        "_meiliSimilarDocuments",
+        json!({ "index_uid": "products", "document_id": "1", "embedder": "manual" }),
+    )
+    .await;
+    assert!(response.contains("iPad"), "{response}");
+    assert!(!response.contains("Galaxy"), "{response}");
+}
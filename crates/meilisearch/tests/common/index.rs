@@ -138,6 +138,16 @@ impl<'a> Index<'a, Owned> {
         self.service.put_encoded(url, documents, self.encoder).await
     }
 
+    pub async fn raw_add_documents_bytes(
+        &self,
+        payload: Vec<u8>,
+        content_type: &str,
+        query_parameter: &str,
+    ) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/documents{}", urlencode(self.uid.as_ref()), query_parameter);
+        self.service.post_raw(url, payload, vec![("Content-Type", content_type)]).await
+    }
+
     pub async fn raw_update_documents(
         &self,
         payload: &str,
@@ -274,6 +284,11 @@ impl<'a> Index<'a, Owned> {
         self.service.patch_encoded(url, settings, self.encoder).await
     }
 
+    pub async fn update_settings_reranker(&self, settings: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/settings/reranker", urlencode(self.uid.as_ref()));
+        self.service.patch_encoded(url, settings, self.encoder).await
+    }
+
     pub async fn delete_settings(&self) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/settings", urlencode(self.uid.as_ref()));
         self.service.delete(url, Default::default()).await
@@ -508,6 +523,31 @@ impl<State> Index<'_, State> {
         self.service.post(url, payload).await
     }
 
+    /// Fetches the documents with the given `Accept` header and returns the raw body.
+    pub async fn fetch_documents_with_accept(
+        &self,
+        payload: Value,
+        accept: &str,
+    ) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/documents/fetch", urlencode(self.uid.as_ref()));
+        let req = actix_web::test::TestRequest::post()
+            .uri(&url)
+            .set_json(payload)
+            .insert_header(("Accept", accept));
+        self.service.request_text(req).await
+    }
+
+    /// Gets the documents with the given `Accept` header and returns the raw body.
+    pub async fn get_all_documents_with_accept(
+        &self,
+        options: &str,
+        accept: &str,
+    ) -> (String, StatusCode) {
+        let url = format!("/indexes/{}/documents{}", urlencode(self.uid.as_ref()), options);
+        let req = actix_web::test::TestRequest::get().uri(&url).insert_header(("Accept", accept));
+        self.service.request_text(req).await
+    }
+
     pub async fn get_all_documents_raw(&self, options: &str) -> (Value, StatusCode) {
         let url = format!("/indexes/{}/documents{}", urlencode(self.uid.as_ref()), options);
         self.service.get(url).await
@@ -624,6 +664,11 @@ impl<State> Index<'_, State> {
         self.service.post_encoded(url, query, self.encoder).await
     }
 
+    pub async fn autocomplete(&self, query: Value) -> (Value, StatusCode) {
+        let url = format!("/indexes/{}/autocomplete", urlencode(self.uid.as_ref()));
+        self.service.post_encoded(url, query, self.encoder).await
+    }
+
     pub async fn get_distinct_attribute(&self) -> (Value, StatusCode) {
         let url =
             format!("/indexes/{}/settings/{}", urlencode(self.uid.as_ref()), "distinct-attribute");
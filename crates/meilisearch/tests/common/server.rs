@@ -205,6 +205,43 @@ impl Server<Owned> {
         self.service.patch(url, value).await
     }
 
+    pub async fn get_webhook_deliveries(&self, uuid: impl AsRef<str>) -> (Value, StatusCode) {
+        let url = format!("/webhooks/{}/deliveries", uuid.as_ref());
+        self.service.get(url).await
+    }
+
+    pub async fn redeliver_webhook_deliveries(
+        &self,
+        uuid: impl AsRef<str>,
+        value: Value,
+    ) -> (Value, StatusCode) {
+        let url = format!("/webhooks/{}/deliveries", uuid.as_ref());
+        self.service.post(url, value).await
+    }
+
+    pub async fn create_schedule(&self, value: Value) -> (Value, StatusCode) {
+        self.service.post("/schedules", value).await
+    }
+
+    pub async fn get_schedules(&self) -> (Value, StatusCode) {
+        self.service.get("/schedules").await
+    }
+
+    pub async fn get_schedule(&self, uid: impl AsRef<str>) -> (Value, StatusCode) {
+        let url = format!("/schedules/{}", uid.as_ref());
+        self.service.get(url).await
+    }
+
+    pub async fn patch_schedule(&self, uid: impl AsRef<str>, value: Value) -> (Value, StatusCode) {
+        let url = format!("/schedules/{}", uid.as_ref());
+        self.service.patch(url, value).await
+    }
+
+    pub async fn delete_schedule(&self, uid: impl AsRef<str>) -> (Value, StatusCode) {
+        let url = format!("/schedules/{}", uid.as_ref());
+        self.service.delete(url, Default::default()).await
+    }
+
     pub async fn create_dynamic_search_rule(
         &self,
         uid: impl AsRef<str>,
@@ -462,6 +499,14 @@ impl<State> Server<State> {
         self.service.post("/dumps", json!(null)).await
     }
 
+    pub async fn create_dump_with(&self, body: Value) -> (Value, StatusCode) {
+        self.service.post("/dumps", body).await
+    }
+
+    pub async fn import_dump(&self, body: Value) -> (Value, StatusCode) {
+        self.service.post("/dumps/import", body).await
+    }
+
     pub async fn create_snapshot(&self) -> (Value, StatusCode) {
         self.service.post("/snapshots", json!(null)).await
     }
@@ -8,7 +8,6 @@ use actix_web::http::StatusCode;
 use actix_web::test;
 use actix_web::test::TestRequest;
 use actix_web::web::Data;
-use http_client::policy::IpPolicy;
 use index_scheduler::IndexScheduler;
 use meilisearch::analytics::Analytics;
 use meilisearch::personalization::PersonalizationService;
@@ -178,13 +177,9 @@ impl Service {
             .options
             .experimental_personalization_api_key
             .clone()
-            .map(|api_key| {
-                PersonalizationService::cohere(
-                    api_key,
-                    // NO DANGER: test
-                    IpPolicy::danger_always_allow(),
-                )
-            })
+            .map(|api_key| PersonalizationService::cohere(api_key, &self.index_scheduler))
+            .transpose()
+            .unwrap()
             .unwrap_or_else(PersonalizationService::disabled);
 
         actix_web::test::init_service(create_app(
@@ -218,6 +213,20 @@ impl Service {
         (response, status_code)
     }
 
+    /// Send a test request and returns its body as text, for the routes not answering in JSON.
+    pub async fn request_text(&self, mut req: test::TestRequest) -> (String, StatusCode) {
+        let app = self.init_web_app().await;
+
+        if let Some(api_key) = &self.api_key {
+            req = req.insert_header(("Authorization", ["Bearer ", api_key].concat()));
+        }
+        let res = test::call_service(&app, req.to_request()).await;
+        let status_code = res.status();
+
+        let body = test::read_body(res).await;
+        (String::from_utf8(body.to_vec()).unwrap(), status_code)
+    }
+
     fn encode(&self, req: TestRequest, body: Value, encoder: Encoder) -> TestRequest {
         let bytes = serde_json::to_string(&body).expect("Failed to serialize test data to json");
         let encoded_body = encoder.encode(bytes);
@@ -540,7 +540,7 @@ async fn error_add_documents_test_bad_content_types() {
     snapshot!(json_string!(response),
         @r###"
     {
-      "message": "The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`",
+      "message": "The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`",
       "code": "invalid_content_type",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_content_type"
@@ -561,7 +561,7 @@ async fn error_add_documents_test_bad_content_types() {
     snapshot!(json_string!(response),
         @r###"
     {
-      "message": "The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`",
+      "message": "The Content-Type `text/plain` is invalid. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`",
       "code": "invalid_content_type",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_content_type"
@@ -596,7 +596,7 @@ async fn error_add_documents_test_no_content_type() {
     snapshot!(json_string!(response),
         @r###"
     {
-      "message": "A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`",
+      "message": "A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`",
       "code": "missing_content_type",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#missing_content_type"
@@ -616,7 +616,7 @@ async fn error_add_documents_test_no_content_type() {
     snapshot!(json_string!(response),
         @r###"
     {
-      "message": "A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`",
+      "message": "A Content-Type header is missing. Accepted values for the Content-Type header are: `application/json`, `application/x-ndjson`, `text/csv`, `application/vnd.apache.parquet`, `application/vnd.apache.arrow.stream`",
       "code": "missing_content_type",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#missing_content_type"
@@ -2955,3 +2955,151 @@ async fn batch_several_documents_addition() {
     assert_eq!(code, 200, "failed with `{}`", response);
     assert_eq!(response["results"].as_array().unwrap().len(), 120);
 }
+
+fn columnar_documents() -> arrow_array::RecordBatch {
+    use std::sync::Arc;
+
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, Int32Array, ListArray, StringArray, StructArray, UInt64Array};
+    use arrow_schema::{DataType, Field};
+
+    let address = StructArray::from(vec![
+        (
+            Arc::new(Field::new("city", DataType::Utf8, true)),
+            Arc::new(StringArray::from(vec![Some("Bern"), None])) as ArrayRef,
+        ),
+        (
+            Arc::new(Field::new("zip", DataType::Int32, false)),
+            Arc::new(Int32Array::from(vec![3000, 75001])) as ArrayRef,
+        ),
+    ]);
+    let scores = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+        Some(vec![Some(1), Some(2)]),
+        Some(vec![]),
+    ]);
+
+    arrow_array::RecordBatch::try_from_iter(vec![
+        ("id", Arc::new(UInt64Array::from(vec![0, 1])) as ArrayRef),
+        ("name", Arc::new(StringArray::from(vec!["jean", "jorts"])) as ArrayRef),
+        ("address", Arc::new(address) as ArrayRef),
+        ("scores", Arc::new(scores) as ArrayRef),
+    ])
+    .unwrap()
+}
+
+#[actix_rt::test]
+async fn add_parquet_and_arrow_stream_documents() {
+    let server = Server::new_shared();
+    let batch = columnar_documents();
+
+    let mut parquet = Vec::new();
+    let mut writer =
+        parquet::arrow::ArrowWriter::try_new(&mut parquet, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let mut arrow = Vec::new();
+    let mut writer = arrow_ipc::writer::StreamWriter::try_new(&mut arrow, &batch.schema()).unwrap();
+    writer.write(&batch).unwrap();
+    writer.finish().unwrap();
+    drop(writer);
+
+    for (payload, content_type) in [
+        (parquet, "application/vnd.apache.parquet"),
+        (arrow, "application/vnd.apache.arrow.stream"),
+    ] {
+        let index = server.unique_index();
+        let (response, code) = index.raw_add_documents_bytes(payload, content_type, "").await;
+        snapshot!(code, @"202 Accepted");
+        let response = server.wait_task(response.uid()).await.succeeded();
+        snapshot!(response["details"], @r###"
+        {
+          "receivedDocuments": 2,
+          "indexedDocuments": 2
+        }
+        "###);
+
+        let (documents, code) = index.get_all_documents(GetAllDocumentsOptions::default()).await;
+        snapshot!(code, @"200 OK");
+        snapshot!(json_string!(documents["results"]), @r###"
+        [
+          {
+            "id": 0,
+            "name": "jean",
+            "address.city": "Bern",
+            "address.zip": 3000,
+            "scores": [
+              1,
+              2
+            ]
+          },
+          {
+            "id": 1,
+            "name": "jorts",
+            "address.city": null,
+            "address.zip": 75001,
+            "scores": []
+          }
+        ]
+        "###);
+    }
+}
+
+#[actix_rt::test]
+async fn error_add_malformed_columnar_documents() {
+    use std::sync::Arc;
+
+    use arrow_array::{ArrayRef, Float64Array, UInt64Array};
+
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let batch = arrow_array::RecordBatch::try_from_iter(vec![
+        ("id", Arc::new(UInt64Array::from(vec![0, 1])) as ArrayRef),
+        ("price", Arc::new(Float64Array::from(vec![12.5, f64::NAN])) as ArrayRef),
+    ])
+    .unwrap();
+    let mut arrow = Vec::new();
+    let mut writer = arrow_ipc::writer::StreamWriter::try_new(&mut arrow, &batch.schema()).unwrap();
+    writer.write(&batch).unwrap();
+    writer.finish().unwrap();
+    drop(writer);
+
+    let (response, code) =
+        index.raw_add_documents_bytes(arrow, "application/vnd.apache.arrow.stream", "").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The `arrow` payload provided is malformed: `Invalid value at row 2 of column `price`: `NaN` cannot be represented in JSON`.",
+      "code": "malformed_payload",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#malformed_payload"
+    }
+    "###);
+
+    let (response, code) = index
+        .raw_add_documents_bytes(b"id,name\n0,jean".to_vec(), "application/vnd.apache.parquet", "")
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response, { ".message" => "[message]" }), @r###"
+    {
+      "message": "[message]",
+      "code": "malformed_payload",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#malformed_payload"
+    }
+    "###);
+
+    let (response, code) = index
+        .raw_add_documents_bytes(Vec::new(), "application/vnd.apache.parquet", "?csvDelimiter=a")
+        .await;
+    snapshot!(code, @"415 Unsupported Media Type");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "The Content-Type `application/vnd.apache.parquet` does not support the use of a csv delimiter. The csv delimiter can only be used with the Content-Type `text/csv`.",
+      "code": "invalid_content_type",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_content_type"
+    }
+    "###);
+}
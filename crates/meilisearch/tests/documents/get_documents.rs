@@ -1556,3 +1556,84 @@ async fn get_document_sort_desc_includes_docs_without_field() {
     }
     "###);
 }
+
+#[actix_rt::test]
+async fn stream_documents_with_accept_header() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, _code) = index
+        .add_documents(
+            json!([
+                { "id": 1, "title": "Carol", "genres": ["Romance", "Drama"] },
+                { "id": 2, "title": "Wonder, Woman" },
+                { "id": 3, "title": "Life of Us" },
+            ]),
+            Some("id"),
+        )
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    // the offset and limit are ignored, all the documents are streamed
+    let (body, code) = index
+        .get_all_documents_with_accept("?limit=1&fields=id,title", "application/x-ndjson")
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body, @r#"
+    {"id":1,"title":"Carol"}
+    {"id":2,"title":"Wonder, Woman"}
+    {"id":3,"title":"Life of Us"}
+    "#);
+
+    let (body, code) = index.get_all_documents_with_accept("", "text/csv").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body, @r#"
+    id,title,genres
+    1,Carol,"[""Romance"",""Drama""]"
+    2,"Wonder, Woman",
+    3,Life of Us,
+    "#);
+
+    let (body, code) = index
+        .fetch_documents_with_accept(json!({ "ids": [3, 1], "fields": ["title"] }), "text/csv")
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body, @r#"
+    title
+    Carol
+    Life of Us
+    "#);
+
+    // JSON stays the default when it's preferred or as acceptable as the streaming formats
+    let (body, code) = index
+        .fetch_documents_with_accept(
+            json!({ "limit": 1, "fields": ["id"] }),
+            "application/json, application/x-ndjson",
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body, @r#"{"results":[{"id":1}],"offset":0,"limit":1,"total":3}"#);
+
+    let (body, code) = index
+        .fetch_documents_with_accept(
+            json!({ "limit": 1, "fields": ["id"] }),
+            "application/json;q=0.5, application/x-ndjson",
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(body, @r#"
+    {"id":1}
+    {"id":2}
+    {"id":3}
+    "#);
+}
+
+#[actix_rt::test]
+async fn stream_documents_errors() {
+    let index = shared_index_with_test_set().await;
+
+    let (body, code) = index
+        .fetch_documents_with_accept(json!({ "sort": ["name:asc"] }), "application/x-ndjson")
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(body, @r#"{"message":"Attribute `name` is not sortable. This index does not have configured sortable attributes.","code":"invalid_document_sort","type":"invalid_request","link":"https://docs.meilisearch.com/errors#invalid_document_sort"}"#);
+}
@@ -107,7 +107,8 @@ async fn import_dump_v1_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -285,7 +286,8 @@ async fn import_dump_v1_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -462,7 +464,8 @@ async fn import_dump_v1_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -625,7 +628,8 @@ async fn import_dump_v2_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -800,7 +804,8 @@ async fn import_dump_v2_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -974,7 +979,8 @@ async fn import_dump_v2_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -1137,7 +1143,8 @@ async fn import_dump_v3_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -1312,7 +1319,8 @@ async fn import_dump_v3_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -1486,7 +1494,8 @@ async fn import_dump_v3_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -1649,7 +1658,8 @@ async fn import_dump_v4_movie_raw() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -1824,7 +1834,8 @@ async fn import_dump_v4_movie_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -1998,7 +2009,8 @@ async fn import_dump_v4_rubygems_with_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###
     );
@@ -2305,7 +2317,8 @@ async fn import_dump_v6_containing_experimental_features() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###);
 
@@ -2566,7 +2579,8 @@ async fn generate_and_import_dump_containing_vectors() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###);
 
@@ -2630,3 +2644,267 @@ async fn generate_and_import_dump_containing_vectors() {
         })
         .await;
 }
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn generate_and_import_selective_dump() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut opt = default_settings(temp.path());
+    let server = Server::new_with_options(opt.clone()).await.unwrap();
+
+    for uid in ["doggos", "doggos_puppies", "cattos"] {
+        let (response, code) =
+            server.index(uid).add_documents(json!([{ "id": 0, "name": uid }]), None).await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(response.uid()).await.succeeded();
+    }
+
+    let (response, code) = server
+        .create_dump_with(json!({ "indexes": ["doggos*"], "skipTasks": true, "skipKeys": true }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+    let dump_uid = response["details"]["dumpUid"].as_str().unwrap().to_string();
+
+    let (response, code) =
+        server.create_dump_with(json!({ "indexes": ["doggos*"], "skipTasks": false })).await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+    let dump_with_tasks_uid = response["details"]["dumpUid"].as_str().unwrap().to_string();
+
+    // ========= We made the dumps, now we should clear the DB and try to import our dumps
+    drop(server);
+    tokio::fs::remove_dir_all(&opt.db_path).await.unwrap();
+    let dump_path = opt.dump_dir.join(format!("{dump_uid}.dump"));
+    assert!(dump_path.exists(), "path: `{}`", dump_path.display());
+    let dump_with_tasks_path = opt.dump_dir.join(format!("{dump_with_tasks_uid}.dump"));
+    assert!(dump_with_tasks_path.exists(), "path: `{}`", dump_with_tasks_path.display());
+
+    opt.import_dump = Some(dump_path);
+    opt.db_path = temp.path().join("data.ms");
+
+    let server = Server::new_with_options(opt.clone()).await.unwrap();
+
+    let (indexes, code) = server.list_indexes(None, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(indexes["results"].as_array().unwrap().iter().map(|index| &index["uid"]).collect::<Vec<_>>()), @r###"
+    [
+      "doggos",
+      "doggos_puppies"
+    ]
+    "###);
+
+    let (response, code) =
+        server.index("doggos").get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["results"]), @r###"
+    [
+      {
+        "id": 0,
+        "name": "doggos"
+      }
+    ]
+    "###);
+
+    // the task queue was left out of the dump
+    let (tasks, code) = server.tasks().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(tasks["results"].as_array().unwrap().len(), @"0");
+
+    // ========= The tasks of the indexes left out of the dump are not imported either
+    drop(server);
+    opt.import_dump = Some(dump_with_tasks_path);
+    opt.db_path = temp.path().join("data_with_tasks.ms");
+
+    let server = Server::new_with_options(opt).await.unwrap();
+
+    let (indexes, code) = server.list_indexes(None, None).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(indexes["results"].as_array().unwrap().iter().map(|index| &index["uid"]).collect::<Vec<_>>()), @r###"
+    [
+      "doggos",
+      "doggos_puppies"
+    ]
+    "###);
+
+    let (tasks, code) = server.tasks().await;
+    snapshot!(code, @"200 OK");
+    let mut task_indexes: Vec<_> =
+        tasks["results"].as_array().unwrap().iter().map(|task| task["indexUid"].clone()).collect();
+    task_indexes.sort_by_key(|index| index.to_string());
+    snapshot!(json_string!(task_indexes), @r###"
+    [
+      "doggos",
+      "doggos_puppies",
+      null,
+      null
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn generate_and_import_dump_with_task_priorities() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut opt = default_settings(temp.path());
+    let server = Server::new_with_options(opt.clone()).await.unwrap();
+
+    let index = server.index("doggos");
+    for (id, priority) in [(0, "high"), (1, "normal"), (2, "low")] {
+        let (response, code) = index
+            .service
+            .post(
+                format!("/indexes/doggos/documents?priority={priority}"),
+                json!([{ "id": id, "name": "doggo" }]),
+            )
+            .await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(response.uid()).await.succeeded();
+    }
+
+    let (response, code) = server.create_dump().await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await;
+    snapshot!(response["status"], @r###""succeeded""###);
+
+    // ========= We made a dump, now we should clear the DB and try to import our dump
+    drop(index);
+    drop(server);
+    tokio::fs::remove_dir_all(&opt.db_path).await.unwrap();
+    let dump_name = format!("{}.dump", response["details"]["dumpUid"].as_str().unwrap());
+    let dump_path = opt.dump_dir.join(dump_name);
+    assert!(dump_path.exists(), "path: `{}`", dump_path.display());
+
+    opt.import_dump = Some(dump_path);
+    opt.db_path = temp.path().join("data.ms");
+
+    let server = Server::new_with_options(opt).await.unwrap();
+
+    let uids = |tasks: &serde_json::Value| -> Vec<u64> {
+        tasks["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|task| task["uid"].as_u64().unwrap())
+            .collect()
+    };
+    let (tasks, code) = server.tasks_filter("priorities=high").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(format!("{:?}", uids(&tasks)), @"[0]");
+    let (tasks, code) = server.tasks_filter("priorities=low").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(format!("{:?}", uids(&tasks)), @"[2]");
+    let (tasks, code) = server.tasks_filter("priorities=normal").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(format!("{:?}", uids(&tasks)), @"[3, 1]");
+}
+
+#[actix_rt::test]
+async fn create_dump_bad_parameters() {
+    let server = Server::new_shared();
+
+    let (response, code) = server.create_dump_with(json!({ "indexes": ["doggo?"] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_indexes""###);
+
+    let (response, code) = server.create_dump_with(json!({ "skipTasks": "doggo" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_skip_tasks""###);
+
+    let (response, code) = server.create_dump_with(json!({ "skipBatches": 1 })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_skip_batches""###);
+
+    let (response, code) = server.create_dump_with(json!({ "skipKeys": null })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_skip_keys""###);
+}
+
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn import_dump_in_running_instance() {
+    let server = Server::new().await;
+
+    for uid in ["doggos", "cattos"] {
+        let (response, code) =
+            server.index(uid).add_documents(json!([{ "id": 0, "name": uid }]), None).await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(response.uid()).await.succeeded();
+    }
+
+    let (response, code) = server.create_dump().await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await.succeeded();
+    let dump_uid = response["details"]["dumpUid"].as_str().unwrap().to_string();
+
+    // every index of the dump already exists
+    let (response, code) = server.import_dump(json!({ "dumpUid": dump_uid })).await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await.failed();
+    snapshot!(response["error"]["code"], @r###""index_already_exists""###);
+
+    let (response, code) = server
+        .import_dump(json!({
+            "dumpUid": dump_uid,
+            "indexRenames": { "doggos": "doggos_v2" },
+            "skipExistingIndexes": true,
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await.succeeded();
+    snapshot!(response["type"], @r###""dumpImport""###);
+    snapshot!(json_string!(response["details"], { ".dumpUid" => "[dump_uid]" }), @r###"
+    {
+      "dumpUid": "[dump_uid]",
+      "importedIndexes": [
+        "doggos_v2"
+      ],
+      "skippedIndexes": [
+        "cattos"
+      ]
+    }
+    "###);
+
+    let (response, code) =
+        server.index("doggos_v2").get_all_documents(GetAllDocumentsOptions::default()).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["results"]), @r###"
+    [
+      {
+        "id": 0,
+        "name": "doggos"
+      }
+    ]
+    "###);
+
+    let (response, code) = server.import_dump(json!({ "dumpUid": "20200101-000000000" })).await;
+    snapshot!(code, @"202 Accepted");
+    let response = server.wait_task(response.uid()).await.failed();
+    snapshot!(response["error"]["code"], @r###""dump_not_found""###);
+}
+
+#[actix_rt::test]
+async fn import_dump_bad_parameters() {
+    let server = Server::new_shared();
+
+    let (response, code) = server.import_dump(json!({})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""missing_dump_import_dump_uid""###);
+
+    let (response, code) = server.import_dump(json!({ "dumpUid": "../data.ms" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_import_dump_uid""###);
+
+    let (response, code) = server
+        .import_dump(json!({ "dumpUid": "doggo", "indexRenames": { "doggos": "doggos?" } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_import_index_renames""###);
+
+    let (response, code) =
+        server.import_dump(json!({ "dumpUid": "doggo", "skipExistingIndexes": "yes" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_dump_import_skip_existing_indexes""###);
+}
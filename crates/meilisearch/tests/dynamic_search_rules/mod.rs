@@ -1566,7 +1566,10 @@ async fn search_counts_pins_that_miss_query() {
       "limit": 20,
       "offset": 0,
       "estimatedTotalHits": 2,
-      "requestUid": "[uuid]"
+      "requestUid": "[uuid]",
+      "appliedDynamicSearchRules": [
+        "pin-query-miss-but-filtered"
+      ]
     }
     "#);
 
@@ -1594,7 +1597,10 @@ async fn search_counts_pins_that_miss_query() {
       "page": 2,
       "totalPages": 2,
       "totalHits": 2,
-      "requestUid": "[uuid]"
+      "requestUid": "[uuid]",
+      "appliedDynamicSearchRules": [
+        "pin-query-miss-but-filtered"
+      ]
     }
     "#);
 }
@@ -1660,7 +1666,10 @@ async fn search_distinct_deduplicates_pinned_documents() {
       "limit": 20,
       "offset": 0,
       "estimatedTotalHits": 2,
-      "requestUid": "[uuid]"
+      "requestUid": "[uuid]",
+      "appliedDynamicSearchRules": [
+        "pin-duplicate-series"
+      ]
     }
     "#);
 }
@@ -2917,7 +2926,10 @@ async fn multi_search_deduplicates_pins() {
       "limit": 20,
       "offset": 0,
       "estimatedTotalHits": 3,
-      "requestUid": "[uuid]"
+      "requestUid": "[uuid]",
+      "appliedDynamicSearchRules": [
+        "pin-invoked-twice-in-multi-search"
+      ]
     }
     "###);
 }
@@ -3102,7 +3114,452 @@ async fn multi_search_lower_precedence_pin_wins() {
       "limit": 20,
       "offset": 0,
       "estimatedTotalHits": 3,
-      "requestUid": "[uuid]"
+      "requestUid": "[uuid]",
+      "appliedDynamicSearchRules": [
+        "pin-for-query-0",
+        "pin-for-query-1"
+      ]
+    }
+    "###);
+}
+
+#[actix_web::test]
+async fn create_rejects_selectors_not_matching_the_action() {
+    let server = dynamic_search_rules_server().await;
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "pin-with-filter",
+            json!({
+                "actions": [
+                    {
+                        "selector": { "filter": "kind = keep" },
+                        "action": { "type": "pin", "position": 0 }
+                    }
+                ]
+            }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.actions[0].selector`: the `pin` action requires a selector with an `id`",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "inject-id",
+            json!({
+                "actions": [
+                    {
+                        "selector": { "id": "42" },
+                        "action": { "type": "injectFilter" }
+                    }
+                ]
+            }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.actions[0].selector`: the `injectFilter` action requires a selector with a `filter`",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "boost-both",
+            json!({
+                "actions": [
+                    {
+                        "selector": { "id": "42", "filter": "kind = keep" },
+                        "action": { "type": "boost" }
+                    }
+                ]
+            }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.actions[0].selector`: either `id` or `filter` can be used, not both at once",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+}
+
+#[actix_web::test]
+async fn search_applies_hide_bury_boost_and_inject_filter_actions() {
+    let server = dynamic_search_rules_server().await;
+    let index = server.index("products");
+
+    let (task, code) = index.update_settings(json!({ "filterableAttributes": ["kind"] })).await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = index
+        .add_documents(
+            json!([
+                { "id": "1", "kind": "old" },
+                { "id": "2", "kind": "regular" },
+                { "id": "3", "kind": "promoted" },
+                { "id": "4", "kind": "hidden" },
+                { "id": "5", "kind": "adult" }
+            ]),
+            None,
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "merchandising",
+            json!({
+                "active": true,
+                "actions": [
+                    {
+                        "selector": { "filter": "kind = promoted" },
+                        "action": { "type": "boost" }
+                    },
+                    {
+                        "selector": { "filter": "kind = old" },
+                        "action": { "type": "bury" }
+                    },
+                    {
+                        "selector": { "id": "4" },
+                        "action": { "type": "hide" }
+                    },
+                    {
+                        "selector": { "filter": "kind != adult" },
+                        "action": { "type": "injectFilter" }
+                    }
+                ]
+            }),
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (value, code) = index.search_post(json!({ "showRankingScoreDetails": true })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["hits"]), @r###"
+    [
+      {
+        "id": "3",
+        "kind": "promoted",
+        "_rankingScoreDetails": {
+          "boost": {
+            "order": 0,
+            "rule": "merchandising",
+            "precedence": null,
+            "score": 1.0
+          }
+        }
+      },
+      {
+        "id": "2",
+        "kind": "regular",
+        "_rankingScoreDetails": {}
+      },
+      {
+        "id": "1",
+        "kind": "old",
+        "_rankingScoreDetails": {
+          "bury": {
+            "order": 0,
+            "rule": "merchandising",
+            "precedence": null,
+            "score": 0.3333333333333333
+          }
+        }
+      }
+    ]
+    "###);
+    snapshot!(value["estimatedTotalHits"], @"3");
+}
+
+#[actix_web::test]
+async fn create_rejects_invalid_locale_query_and_tenant_token_conditions() {
+    let server = dynamic_search_rules_server().await;
+
+    let pin = json!([
+        {
+            "selector": { "id": "42" },
+            "action": { "type": "pin", "position": 0 }
+        }
+    ]);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "empty-locales",
+            json!({ "conditions": { "locales": [] }, "actions": pin }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.conditions.locales`: `locales` must contain at least one locale",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
     }
     "###);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "empty-prefix",
+            json!({ "conditions": { "query": { "prefix": " " } }, "actions": pin }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.conditions.query.prefix`: `prefix` cannot be empty",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "empty-query-with-prefix",
+            json!({ "conditions": { "query": { "isEmpty": true, "prefix": "bat" } }, "actions": pin }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.conditions.query`: `isEmpty: true` cannot be used with `prefix` or `regex`",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "invalid-regex",
+            json!({ "conditions": { "query": { "regex": "(" } }, "actions": pin }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.conditions.query.regex`: invalid regular expression: regex parse error:\n    (\n    ^\nerror: unclosed group",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "object-claim",
+            json!({
+                "conditions": { "tenantToken": { "claims": { "plan": { "name": "pro" } } } },
+                "actions": pin
+            }),
+        )
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(task), @r###"
+    {
+      "message": "Invalid value at `.conditions.tenantToken.claims.plan`: the value of the `plan` claim must be a string, a number, a boolean, or a non-empty array of them",
+      "code": "bad_request",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#bad_request"
+    }
+    "###);
+}
+
+#[actix_web::test]
+async fn search_applies_rules_matching_locales_and_query_prefix_and_regex() {
+    let server = dynamic_search_rules_server().await;
+    let index = server.index("movies");
+
+    let (task, code) = index
+        .add_documents(
+            json!([
+                { "id": "1", "title": "Batman" },
+                { "id": "2", "title": "Le Fabuleux Destin d'Amélie Poulain" },
+                { "id": "3", "title": "1984" }
+            ]),
+            None,
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    for (precedence, (uid, conditions, doc_id)) in [
+        ("french-queries", json!({ "locales": ["fra"] }), "2"),
+        ("queries-starting-with-bat", json!({ "query": { "prefix": "Bat" } }), "1"),
+        ("numeric-queries", json!({ "query": { "regex": "^[0-9]+$" } }), "3"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let (task, code) = server
+            .create_dynamic_search_rule(
+                uid,
+                json!({
+                    "precedence": precedence,
+                    "active": true,
+                    "conditions": conditions,
+                    "actions": [
+                        {
+                            "selector": { "id": doc_id },
+                            "action": { "type": "pin", "position": 0 }
+                        }
+                    ]
+                }),
+            )
+            .await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(task.uid()).await.succeeded();
+    }
+
+    let (value, code) = server.get_dynamic_search_rule("queries-starting-with-bat").await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["conditions"]), @r###"
+    {
+      "query": {
+        "prefix": "bat"
+      }
+    }
+    "###);
+
+    let (value, code) = index.search_post(json!({ "q": "batman", "locales": ["fra"] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @r###"
+    [
+      "french-queries",
+      "queries-starting-with-bat"
+    ]
+    "###);
+
+    let (value, code) = index.search_post(json!({ "q": "Batman" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @r###"
+    [
+      "queries-starting-with-bat"
+    ]
+    "###);
+
+    let (value, code) = index.search_post(json!({ "q": "1984" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["hits"]), @r###"
+    [
+      {
+        "id": "3",
+        "title": "1984"
+      }
+    ]
+    "###);
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @r###"
+    [
+      "numeric-queries"
+    ]
+    "###);
+
+    let (value, code) = index.search_post(json!({ "q": "amélie" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @"null");
+}
+
+#[actix_web::test]
+async fn search_applies_rules_matching_tenant_token_claims() {
+    use jsonwebtoken::{encode, EncodingKey, Header};
+
+    let mut server = Server::new_auth().await;
+    server.use_admin_key("MASTER_KEY").await;
+    let (value, code) = server.set_features(json!({ "dynamicSearchRules": true })).await;
+    assert_eq!(code, 200, "{value}");
+
+    let index = server.index("movies");
+    let (task, code) = index
+        .add_documents(
+            json!([
+                { "id": "1", "title": "Batman" },
+                { "id": "2", "title": "Batman Returns" }
+            ]),
+            None,
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+    drop(index);
+
+    let (task, code) = server
+        .create_dynamic_search_rule(
+            "premium-users",
+            json!({
+                "active": true,
+                "conditions": { "tenantToken": { "claims": { "plan": ["pro", "enterprise"] } } },
+                "actions": [
+                    {
+                        "selector": { "id": "2" },
+                        "action": { "type": "pin", "position": 0 }
+                    }
+                ]
+            }),
+        )
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    server.use_api_key("MASTER_KEY");
+    let (response, code) = server
+        .add_api_key(json!({ "indexes": ["movies"], "actions": ["search"], "expiresAt": null }))
+        .await;
+    assert_eq!(code, 201, "{response}");
+    let key = response["key"].as_str().unwrap().to_string();
+    let uid = response["uid"].as_str().unwrap().to_string();
+
+    let tenant_token = |plan: &str| {
+        let claims = json!({ "searchRules": ["movies"], "apiKeyUid": uid, "plan": plan });
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(key.as_bytes())).unwrap()
+    };
+
+    server.use_api_key(tenant_token("pro"));
+    let (value, code) = server.index("movies").search_post(json!({ "q": "batman" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["hits"]), @r###"
+    [
+      {
+        "id": "2",
+        "title": "Batman Returns"
+      },
+      {
+        "id": "1",
+        "title": "Batman"
+      }
+    ]
+    "###);
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @r###"
+    [
+      "premium-users"
+    ]
+    "###);
+
+    server.use_api_key(tenant_token("free"));
+    let (value, code) = server.index("movies").search_post(json!({ "q": "batman" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @"null");
+
+    // the claims conditions only apply to tenant tokens
+    server.use_api_key(&key);
+    let (value, code) = server.index("movies").search_post(json!({ "q": "batman" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value["appliedDynamicSearchRules"]), @"null");
 }
@@ -1,5 +1,6 @@
 mod auth;
 mod batches;
+mod chats;
 mod common;
 mod dashboard;
 mod documents;
@@ -9,6 +10,7 @@ mod features;
 mod index;
 mod logs;
 mod network;
+mod schedules;
 mod search;
 mod settings;
 mod similar;
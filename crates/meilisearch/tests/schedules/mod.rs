@@ -0,0 +1,240 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn create_get_update_delete_schedule() {
+    let server = Server::new().await;
+
+    let (schedule, code) = server
+        .create_schedule(json!({
+            "cron": "0 3 * * *",
+            "task": { "type": "taskDeletion", "olderThanDays": 30 }
+        }))
+        .await;
+    snapshot!(code, @"201 Created");
+    snapshot!(json_string!(schedule, { ".uid" => "[uid]", ".createdAt" => "[date]", ".nextRunAt" => "[date]" }), @r#"
+    {
+      "uid": "[uid]",
+      "cron": "0 3 * * *",
+      "task": {
+        "type": "taskDeletion",
+        "olderThanDays": 30
+      },
+      "enabled": true,
+      "createdAt": "[date]",
+      "nextRunAt": "[date]",
+      "lastRunAt": null,
+      "lastTaskUid": null
+    }
+    "#);
+    let uid = schedule["uid"].as_str().unwrap().to_string();
+
+    let (schedules, code) = server.get_schedules().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(schedules, { ".results[].uid" => "[uid]", ".results[].createdAt" => "[date]", ".results[].nextRunAt" => "[date]" }), @r#"
+    {
+      "results": [
+        {
+          "uid": "[uid]",
+          "cron": "0 3 * * *",
+          "task": {
+            "type": "taskDeletion",
+            "olderThanDays": 30
+          },
+          "enabled": true,
+          "createdAt": "[date]",
+          "nextRunAt": "[date]",
+          "lastRunAt": null,
+          "lastTaskUid": null
+        }
+      ]
+    }
+    "#);
+
+    // disabling a schedule removes its next run
+    let (schedule, code) = server
+        .patch_schedule(
+            &uid,
+            json!({
+                "enabled": false,
+                "task": {
+                    "type": "export",
+                    "url": "http://localhost:7701",
+                    "apiKey": "my-super-secret-api-key"
+                }
+            }),
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(schedule, { ".uid" => "[uid]", ".createdAt" => "[date]" }), @r#"
+    {
+      "uid": "[uid]",
+      "cron": "0 3 * * *",
+      "task": {
+        "type": "export",
+        "url": "http://localhost:7701",
+        "apiKey": "my-XXXXX...",
+        "indexes": {
+          "*": {
+            "filter": null,
+            "overrideSettings": false
+          }
+        }
+      },
+      "enabled": false,
+      "createdAt": "[date]",
+      "nextRunAt": null,
+      "lastRunAt": null,
+      "lastTaskUid": null
+    }
+    "#);
+
+    let (schedule, code) = server.get_schedule(&uid).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(schedule["task"]["apiKey"]), @r#""my-XXXXX...""#);
+
+    let (_, code) = server.delete_schedule(&uid).await;
+    snapshot!(code, @"204 No Content");
+
+    let (response, code) = server.get_schedule(&uid).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(response, { ".message" => "[message]" }), @r#"
+    {
+      "message": "[message]",
+      "code": "schedule_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#schedule_not_found"
+    }
+    "#);
+}
+
+#[actix_rt::test]
+async fn invalid_schedule() {
+    let server = Server::new().await;
+
+    let (response, code) = server
+        .create_schedule(json!({ "cron": "0 25 * * *", "task": { "type": "dumpCreation" } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r#"
+    {
+      "message": "Invalid cron expression `0 25 * * *`: `25` is not a valid hour, expected a value between 0 and 23",
+      "code": "invalid_schedule_cron",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_schedule_cron"
+    }
+    "#);
+
+    let (response, code) =
+        server.create_schedule(json!({ "task": { "type": "dumpCreation" } })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r#"
+    {
+      "message": "The cron expression of the schedule is missing.",
+      "code": "invalid_schedule_cron",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_schedule_cron"
+    }
+    "#);
+
+    let (response, code) = server
+        .create_schedule(json!({ "cron": "@daily", "task": { "type": "indexCompaction" } }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r#"
+    {
+      "message": "`indexUid` is required for the `indexCompaction` task.",
+      "code": "invalid_schedule_task",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_schedule_task"
+    }
+    "#);
+
+    let (response, code) = server
+        .create_schedule(json!({
+            "cron": "@daily",
+            "task": { "type": "snapshotCreation", "olderThanDays": 3 }
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r#"
+    {
+      "message": "`olderThanDays` cannot be used with the `snapshotCreation` task.",
+      "code": "invalid_schedule_task",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_schedule_task"
+    }
+    "#);
+
+    let (response, code) = server
+        .create_schedule(json!({
+            "uid": "550e8400-e29b-41d4-a716-446655440000",
+            "cron": "@daily",
+            "task": { "type": "dumpCreation" }
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r#"
+    {
+      "message": "Immutable field `uid`: expected one of `cron`, `task`, `enabled`",
+      "code": "immutable_schedule_uid",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#immutable_schedule_uid"
+    }
+    "#);
+
+    let (response, code) = server.get_schedule("not-a-uuid").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r#""invalid_schedule_uid""#);
+}
+
+#[actix_rt::test]
+async fn schedule_requires_the_action_of_its_task() {
+    let mut server = Server::new_auth().await;
+    server.use_api_key("MASTER_KEY");
+
+    let (response, code) = server
+        .add_api_key(json!({
+            "indexes": ["*"],
+            "actions": ["schedules.*", "dumps.create"],
+            "expiresAt": null
+        }))
+        .await;
+    assert_eq!(201, code, "{response}");
+    let key = response["key"].as_str().unwrap().to_string();
+    server.use_api_key(&key);
+
+    let (response, code) = server
+        .create_schedule(json!({
+            "cron": "0 3 * * *",
+            "task": { "type": "export", "url": "http://example.com" }
+        }))
+        .await;
+    snapshot!(code, @"403 Forbidden");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "The API key is not allowed to schedule the `export` task on `*`. It requires the `export` action.",
+      "code": "invalid_api_key",
+      "type": "auth",
+      "link": "https://docs.meilisearch.com/errors#invalid_api_key"
+    }
+    "#);
+
+    let (schedule, code) = server
+        .create_schedule(json!({
+            "cron": "0 3 * * *",
+            "task": { "type": "dumpCreation" }
+        }))
+        .await;
+    snapshot!(code, @"201 Created");
+    let uid = schedule["uid"].as_str().unwrap().to_string();
+
+    // the task of an existing schedule can't be replaced by a task the key can't enqueue either
+    let (response, code) = server
+        .patch_schedule(&uid, json!({ "task": { "type": "taskDeletion", "olderThanDays": 30 } }))
+        .await;
+    snapshot!(code, @"403 Forbidden");
+    snapshot!(response["message"], @r#""The API key is not allowed to schedule the `taskDeletion` task on `*`. It requires the `tasks.delete` action.""#);
+}
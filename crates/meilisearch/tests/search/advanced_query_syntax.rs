@@ -0,0 +1,152 @@
+use meili_snap::snapshot;
+use once_cell::sync::Lazy;
+
+use crate::common::index::Index;
+use crate::common::{Server, Shared, Value};
+use crate::json;
+
+static DOCUMENTS: Lazy<Value> = Lazy::new(|| {
+    json!([
+        { "id": 1, "title": "The Lord of the Rings", "overview": "A hobbit carries a ring to Mordor" },
+        { "id": 2, "title": "The Hobbit", "overview": "A journey with dwarves and a dragon" },
+        { "id": 3, "title": "Dragonheart", "overview": "The last dragon befriends a knight" },
+        { "id": 4, "title": "Ring", "overview": "A cursed video tape" },
+    ])
+});
+
+async fn index_with_documents(server: &Server<Shared>) -> Index<'_> {
+    let index = server.unique_index();
+    let (task, _status_code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    index
+}
+
+fn sorted_ids(response: &Value) -> Vec<u64> {
+    let mut ids: Vec<_> = response["hits"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hit| hit["id"].as_u64().unwrap())
+        .collect();
+    ids.sort_unstable();
+    ids
+}
+
+#[actix_rt::test]
+async fn field_scoped_terms() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    index
+        .search(json!({ "q": "title:hobbit", "advancedQuerySyntax": true }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(format!("{:?}", sorted_ids(&response)), @"[2]");
+        })
+        .await;
+
+    index
+        .search(
+            json!({ "q": "overview:\"cursed video\"", "advancedQuerySyntax": true }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(format!("{:?}", sorted_ids(&response)), @"[4]");
+            },
+        )
+        .await;
+
+    // unknown attributes are searched as plain words
+    index
+        .search(
+            json!({ "q": "hobbit genre:tale", "advancedQuerySyntax": true }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(format!("{:?}", sorted_ids(&response)), @"[1, 2]");
+            },
+        )
+        .await;
+}
+
+#[actix_rt::test]
+async fn or_groups() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    index
+        .search(
+            json!({ "q": "(tape OR dwarves)", "matchingStrategy": "all", "advancedQuerySyntax": true }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(format!("{:?}", sorted_ids(&response)), @"[2, 4]");
+            },
+        )
+        .await;
+
+    index
+        .search(
+            json!({ "q": "title:(hobbit OR ring)", "advancedQuerySyntax": true }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(format!("{:?}", sorted_ids(&response)), @"[2, 4]");
+            },
+        )
+        .await;
+
+    // without the advanced syntax the operators are searched as plain words
+    index
+        .search(json!({ "q": "(tape OR dwarves)", "matchingStrategy": "all" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(format!("{:?}", sorted_ids(&response)), @"[]");
+        })
+        .await;
+}
+
+fn sorted_formatted(response: &Value, attribute: &str) -> Vec<(u64, String)> {
+    let mut formatted: Vec<_> = response["hits"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hit| {
+            let value = hit["_formatted"][attribute].as_str().unwrap().to_string();
+            (hit["id"].as_u64().unwrap(), value)
+        })
+        .collect();
+    formatted.sort_unstable();
+    formatted
+}
+
+#[actix_rt::test]
+async fn highlighting() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    // every alternative of an OR group is highlighted
+    index
+        .search(
+            json!({
+                "q": "(tape OR dwarves)",
+                "advancedQuerySyntax": true,
+                "attributesToHighlight": ["overview"],
+            }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(format!("{:?}", sorted_formatted(&response, "overview")), @r###"[(2, "A journey with <em>dwarves</em> and a dragon"), (4, "A cursed video <em>tape</em>")]"###);
+            },
+        )
+        .await;
+
+    // the scoped term is highlighted like a regular term
+    index
+        .search(
+            json!({
+                "q": "title:hobbit",
+                "advancedQuerySyntax": true,
+                "attributesToHighlight": ["title", "overview"],
+            }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(format!("{:?}", sorted_formatted(&response, "title")), @r###"[(2, "The <em>Hobbit</em>")]"###);
+                snapshot!(format!("{:?}", sorted_formatted(&response, "overview")), @r###"[(2, "A journey with dwarves and a dragon")]"###);
+            },
+        )
+        .await;
+}
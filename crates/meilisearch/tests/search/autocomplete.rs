@@ -0,0 +1,194 @@
+use meili_snap::{json_string, snapshot};
+use once_cell::sync::Lazy;
+
+use crate::common::index::Index;
+use crate::common::{Server, Shared, Value};
+use crate::json;
+
+static DOCUMENTS: Lazy<Value> = Lazy::new(|| {
+    json!([
+        { "id": 0, "title": "the dragon reborn", "description": "a dark tale", "genre": "fantasy" },
+        { "id": 1, "title": "dragonheart", "description": "a knight and a dragon", "genre": "fantasy" },
+        { "id": 2, "title": "the dragon riders", "description": "dramatic battles", "genre": "fantasy" },
+        { "id": 3, "title": "drama queen", "description": "a dragon story", "genre": "comedy" },
+        { "id": 4, "title": "dreams", "description": "a dramatic dream", "genre": "drama" },
+    ])
+});
+
+async fn index_with_documents(server: &Server<Shared>) -> Index<'_> {
+    let index = server.unique_index();
+    let (task, _status_code) = index
+        .update_settings(json!({
+            "searchableAttributes": ["title", "description"],
+            "filterableAttributes": ["genre"],
+        }))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _status_code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    index
+}
+
+#[actix_rt::test]
+async fn autocomplete_last_word() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    let (response, code) = index.autocomplete(json!({ "q": "dra" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response, { ".processingTimeMs" => "[duration]" }), @r###"
+    {
+      "completions": [
+        {
+          "value": "dragon",
+          "count": 4
+        },
+        {
+          "value": "dramatic",
+          "count": 2
+        },
+        {
+          "value": "dragonheart",
+          "count": 1
+        },
+        {
+          "value": "drama",
+          "count": 1
+        }
+      ],
+      "query": "dra",
+      "processingTimeMs": "[duration]"
+    }
+    "###);
+
+    // the previous words restrict the documents
+    let (response, code) = index.autocomplete(json!({ "q": "the dra" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["completions"]), @r###"
+    [
+      {
+        "value": "dragon",
+        "count": 2
+      },
+      {
+        "value": "dramatic",
+        "count": 1
+      }
+    ]
+    "###);
+
+    let (response, code) = index.autocomplete(json!({ "q": "dr", "limit": 2 })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["completions"]), @r###"
+    [
+      {
+        "value": "dragon",
+        "count": 4
+      },
+      {
+        "value": "dramatic",
+        "count": 2
+      }
+    ]
+    "###);
+
+    let (response, code) = index.autocomplete(json!({})).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["completions"]), @"[]");
+}
+
+#[actix_rt::test]
+async fn autocomplete_limit_is_capped_by_max_total_hits() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    let (task, _status_code) =
+        index.update_settings(json!({ "pagination": { "maxTotalHits": 2 } })).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.autocomplete(json!({ "q": "dra", "limit": 1000000 })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["completions"]), @r###"
+    [
+      {
+        "value": "dragon",
+        "count": 4
+      },
+      {
+        "value": "dramatic",
+        "count": 2
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn autocomplete_with_filter_and_attributes_to_search_on() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    let (response, code) =
+        index.autocomplete(json!({ "q": "dra", "filter": "genre = fantasy" })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["completions"]), @r###"
+    [
+      {
+        "value": "dragon",
+        "count": 3
+      },
+      {
+        "value": "dragonheart",
+        "count": 1
+      },
+      {
+        "value": "dramatic",
+        "count": 1
+      }
+    ]
+    "###);
+
+    let (response, code) =
+        index.autocomplete(json!({ "q": "dra", "attributesToSearchOn": ["title"] })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["completions"]), @r###"
+    [
+      {
+        "value": "dragon",
+        "count": 2
+      },
+      {
+        "value": "dragonheart",
+        "count": 1
+      },
+      {
+        "value": "drama",
+        "count": 1
+      }
+    ]
+    "###);
+}
+
+#[actix_rt::test]
+async fn autocomplete_bad_parameters() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    let (response, code) = index.autocomplete(json!({ "q": "dra", "limit": "doggo" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_limit""###);
+
+    let (response, code) =
+        index.autocomplete(json!({ "q": "dra", "filter": "title = dragon" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_filter""###);
+
+    let (response, code) =
+        index.autocomplete(json!({ "q": "dra", "attributesToSearchOn": ["genre"] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""invalid_search_attributes_to_search_on""###);
+
+    let missing_index = server.unique_index();
+    let (response, code) = missing_index.autocomplete(json!({ "q": "dra" })).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(response["code"], @r###""index_not_found""###);
+}
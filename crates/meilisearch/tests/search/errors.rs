@@ -454,6 +454,106 @@ async fn search_non_filterable_facets() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_bad_facet_aggregations() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (response, _code) = index
+        .update_settings(json!({"filterableAttributes": [
+            "title",
+            {
+                "attributePatterns": ["price"],
+                "features": { "facetSearch": false, "filter": { "equality": true, "comparison": false } }
+            }
+        ]}))
+        .await;
+    server.wait_task(response.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({"facetAggregations": ["doggo"]})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid value type at `.facetAggregations`: expected an object, but found an array: `[\"doggo\"]`",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+
+    let (response, code) = index
+        .search_post(json!({"facetAggregations": {"title": {"ranges": [[0, 10]], "interval": 5}}}))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid value at `.facetAggregations.title`: either `ranges` or `interval` can be used, not both at once.",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+
+    let (response, code) =
+        index.search_post(json!({"facetAggregations": {"title": {"ranges": [[10, 0]]}}})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid value at `.facetAggregations.title.ranges[0]`: the start of a range must be lower than or equal to its end, but `10` is greater than `0`.",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+
+    let (response, code) =
+        index.search_post(json!({"facetAggregations": {"title": {"ranges": [[0, 10, 20]]}}})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid value at `.facetAggregations.title.ranges[0]`: a range must be a `[from, to]` pair, but found 3 values.",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+
+    let (response, code) =
+        index.search_post(json!({"facetAggregations": {"title": {"interval": 0}}})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid value at `.facetAggregations.title.interval`: `interval` must be strictly positive, but found `0`.",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+
+    let (response, code) =
+        index.search_post(json!({"facetAggregations": {"doggo": {"interval": 10}}})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid facet aggregation on attribute `doggo`: the attribute is not filterable. Available filterable attributes patterns with comparison enabled are: `title`.",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+
+    let (response, code) =
+        index.search_post(json!({"facetAggregations": {"price": {"interval": 10}}})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Invalid facet aggregation on attribute `price`: the attribute matched rule #1 in `filterableAttributes`, but this rule does not enable comparison.\n  - Hint: enable comparison in rule #1 by modifying the features.filter object",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "#);
+}
+
 #[actix_rt::test]
 async fn search_non_filterable_facets_multiple_filterable() {
     let server = Server::new_shared();
@@ -639,6 +739,62 @@ async fn search_bad_matching_strategy() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_bad_advanced_query_syntax() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) = index.search_post(json!({"advancedQuerySyntax": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.advancedQuerySyntax`: expected a boolean, but found a string: `\"doggo\"`",
+      "code": "invalid_search_advanced_query_syntax",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_advanced_query_syntax"
+    }
+    "###);
+
+    let (response, code) = index.search_get("?advancedQuerySyntax=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `advancedQuerySyntax`: could not parse `doggo` as a boolean, expected either `true` or `false`",
+      "code": "invalid_search_advanced_query_syntax",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_advanced_query_syntax"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn search_bad_suggestions() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) = index.search_post(json!({"suggestions": "doggo"})).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value type at `.suggestions`: expected a positive integer, but found a string: `\"doggo\"`",
+      "code": "invalid_search_suggestions",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_suggestions"
+    }
+    "###);
+
+    let (response, code) = index.search_get("?suggestions=doggo").await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `suggestions`: could not parse `doggo` as a positive integer",
+      "code": "invalid_search_suggestions",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_suggestions"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn filter_invalid_syntax_object() {
     test_settings_documents_indexing_swapping_and_search(
@@ -648,7 +804,7 @@ async fn filter_invalid_syntax_object() {
         |response, code| {
             snapshot!(response, @r#"
             {
-              "message": "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, `_geoBoundingBox` or `_geoPolygon` at `title & Glass`.\n1:14 title & Glass",
+              "message": "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, `_geoBoundingBox` or `_geoPolygon` at `title & Glass`.\n1:14 title & Glass",
               "code": "invalid_search_filter",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#invalid_search_filter"
@@ -669,7 +825,7 @@ async fn filter_invalid_syntax_array() {
         |response, code| {
             snapshot!(response, @r#"
             {
-              "message": "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `_geoRadius`, `_geoBoundingBox` or `_geoPolygon` at `title & Glass`.\n1:14 title & Glass",
+              "message": "Was expecting an operation `=`, `!=`, `>=`, `>`, `<=`, `<`, `IN`, `NOT IN`, `TO`, `EXISTS`, `NOT EXISTS`, `IS NULL`, `IS NOT NULL`, `IS EMPTY`, `IS NOT EMPTY`, `CONTAINS`, `NOT CONTAINS`, `STARTS WITH`, `NOT STARTS WITH`, `ENDS WITH`, `NOT ENDS WITH`, `MATCHES`, `NOT MATCHES`, `_geoRadius`, `_geoBoundingBox` or `_geoPolygon` at `title & Glass`.\n1:14 title & Glass",
               "code": "invalid_search_filter",
               "type": "invalid_request",
               "link": "https://docs.meilisearch.com/errors#invalid_search_filter"
@@ -1284,6 +1440,32 @@ async fn search_with_contains_without_enabling_the_feature() {
             "#);
         })
         .await;
+    index
+        .search(json!({ "filter": "doggo ENDS WITH fir" }), |response, code| {
+            snapshot!(code, @"400 Bad Request");
+            snapshot!(json_string!(response), @r#"
+            {
+              "message": "Using `ENDS WITH` in a filter requires enabling the `contains filter` experimental feature. See https://github.com/orgs/meilisearch/discussions/763\n7:16 doggo ENDS WITH fir",
+              "code": "feature_not_enabled",
+              "type": "invalid_request",
+              "link": "https://docs.meilisearch.com/errors#feature_not_enabled"
+            }
+            "#);
+        })
+        .await;
+    index
+        .search(json!({ "filter": "doggo NOT MATCHES '^ke.*'" }), |response, code| {
+            snapshot!(code, @"400 Bad Request");
+            snapshot!(json_string!(response), @r#"
+            {
+              "message": "Using `MATCHES` in a filter requires enabling the `contains filter` experimental feature. See https://github.com/orgs/meilisearch/discussions/763\n11:18 doggo NOT MATCHES '^ke.*'",
+              "code": "feature_not_enabled",
+              "type": "invalid_request",
+              "link": "https://docs.meilisearch.com/errors#feature_not_enabled"
+            }
+            "#);
+        })
+        .await;
     index
         .search(json!({ "filter": "doggo != echo AND doggo CONTAINS kefir" }), |response, code| {
             snapshot!(code, @"400 Bad Request");
@@ -102,6 +102,100 @@ async fn search_with_contains_filter() {
     assert_eq!(response["hits"].as_array().unwrap().len(), 2);
 }
 
+#[actix_rt::test]
+async fn search_with_ends_with_and_matches_filters() {
+    let temp = TempDir::new().unwrap();
+    let server = Server::new_with_options(Opt {
+        experimental_contains_filter: true,
+        ..default_settings(temp.path())
+    })
+    .await
+    .unwrap();
+    let index = server.index("movies");
+
+    let (task, _code) = index.update_settings(json!({"filterableAttributes": ["title"]})).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let documents = DOCUMENTS.clone();
+    let (request, _code) = index.add_documents(documents, None).await;
+    server.wait_task(request.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({ "filter": "title ENDS WITH ROOM", "attributesToRetrieve": ["title"] }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r#"
+    [
+      {
+        "title": "Escape Room"
+      }
+    ]
+    "#);
+
+    let (response, code) = index
+        .search_post(json!({
+            "filter": "title MATCHES '^(captain|escape) '",
+            "attributesToRetrieve": ["title"],
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response["hits"].as_array().unwrap().len(), 2, "{response}");
+
+    let (response, code) = index
+        .search_post(
+            json!({ "filter": "title NOT MATCHES 'a'", "attributesToRetrieve": ["title"] }),
+        )
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r#"
+    [
+      {
+        "title": "Gläss"
+      }
+    ]
+    "#);
+
+    let (response, code) = index.search_post(json!({ "filter": "title MATCHES 'ca(p'" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "The pattern `ca(p` is not a valid regular expression: unclosed group.\n16:20 title MATCHES 'ca(p'",
+      "code": "invalid_search_filter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_filter"
+    }
+    "#);
+
+    // the pattern operators must be enabled in the filterable attributes rules
+    let (task, _code) = index
+        .update_settings(json!({"filterableAttributes": [{"attributePatterns": ["title"]}]}))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "filter": "title ENDS WITH room" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Index `movies`: Filter operator `ENDS WITH` is not allowed for the attribute `title`.\n  - Note: allowed operators: OR, AND, NOT, =, !=, IN, IS EMPTY, IS NULL, EXISTS.\n  - Note: field `title` matched rule #0 in `filterableAttributes`\n  - Hint: enable patternMatching in rule #0 by modifying the features.filter object\n  - Hint: prepend another rule matching `title` with appropriate filter features before rule #0",
+      "code": "invalid_search_filter",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_filter"
+    }
+    "#);
+
+    let (task, _code) = index
+        .update_settings(json!({"filterableAttributes": [{
+            "attributePatterns": ["title"],
+            "features": { "filter": { "equality": false, "patternMatching": true } }
+        }]}))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.search_post(json!({ "filter": "title MATCHES 'room$'" })).await;
+    snapshot!(code, @"200 OK");
+    assert_eq!(response["hits"].as_array().unwrap().len(), 1, "{response}");
+}
+
 #[actix_rt::test]
 async fn search_with_pattern_filter_settings() {
     // Check if the Equality filter works with patterns
@@ -1,6 +1,8 @@
 // This module contains all the test concerning search. Each particular feature of the search
 // should be tested in its own module to isolate tests and keep the tests readable.
 
+mod advanced_query_syntax;
+mod autocomplete;
 mod distinct;
 mod document_join;
 mod errors;
@@ -16,8 +18,10 @@ mod metadata;
 mod multi;
 mod pagination;
 mod performance_details;
+mod personalization;
 mod restrict_searchable;
 mod search_queue;
+mod suggestions;
 
 use meili_snap::{json_string, snapshot};
 
@@ -580,6 +584,111 @@ async fn search_facet_distribution() {
         .await;
 }
 
+#[actix_rt::test]
+async fn search_facet_aggregations() {
+    let index = shared_index_with_nested_documents().await;
+
+    index
+        .search(
+            json!({
+                "facetAggregations": {
+                    "doggos.age": { "ranges": [[null, 5], [5, null], [3, 7]] }
+                }
+            }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(json_string!(response["facetAggregations"]), @r#"
+                {
+                  "doggos.age": [
+                    {
+                      "from": null,
+                      "to": 5.0,
+                      "count": 1
+                    },
+                    {
+                      "from": 5.0,
+                      "to": null,
+                      "count": 2
+                    },
+                    {
+                      "from": 3.0,
+                      "to": 7.0,
+                      "count": 2
+                    }
+                  ]
+                }
+                "#);
+            },
+        )
+        .await;
+
+    index
+        .search(
+            json!({
+                "filter": "father != pierre",
+                "facetAggregations": { "doggos.age": { "interval": 3 } }
+            }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(json_string!(response["facetAggregations"]), @r#"
+                {
+                  "doggos.age": [
+                    {
+                      "from": 0.0,
+                      "to": 3.0,
+                      "count": 1
+                    },
+                    {
+                      "from": 3.0,
+                      "to": 6.0,
+                      "count": 2
+                    },
+                    {
+                      "from": 6.0,
+                      "to": 9.0,
+                      "count": 1
+                    }
+                  ]
+                }
+                "#);
+            },
+        )
+        .await;
+
+    // attributes without numeric values in the candidates return no buckets
+    index
+        .search(
+            json!({ "facetAggregations": { "father": { "interval": 10 } } }),
+            |response, code| {
+                snapshot!(code, @"200 OK");
+                snapshot!(json_string!(response["facetAggregations"]), @r#"
+                {
+                  "father": []
+                }
+                "#);
+            },
+        )
+        .await;
+
+    // the number of buckets is checked before building them
+    index
+        .search(
+            json!({ "facetAggregations": { "doggos.age": { "interval": 0.001 } } }),
+            |response, code| {
+                snapshot!(code, @"400 Bad Request");
+                snapshot!(json_string!(response), @r#"
+                {
+                  "message": "Invalid facet aggregation on attribute `doggos.age`: an `interval` of `0.001` produces more than 1000 buckets between `2` and `8`.",
+                  "code": "invalid_search_facet_aggregations",
+                  "type": "invalid_request",
+                  "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+                }
+                "#);
+            },
+        )
+        .await;
+}
+
 #[actix_rt::test]
 async fn displayed_attributes() {
     let server = Server::new_shared();
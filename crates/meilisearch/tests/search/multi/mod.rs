@@ -925,6 +925,29 @@ async fn search_one_query_error() {
     "###);
 }
 
+#[actix_rt::test]
+async fn search_facet_aggregations_error() {
+    let server = Server::new_shared();
+    let index = shared_index_with_documents().await;
+    let nested_index = shared_index_with_nested_documents().await;
+
+    let (response, code) = server
+        .multi_search(json!({"queries": [
+        {"indexUid" : index.uid, "q": "glass"},
+        {"indexUid": nested_index.uid, "facetAggregations": { "doggos.age": { "interval": 3 } }},
+        ]}))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Inside `.queries[1]`: Using `.facetAggregations` is only allowed when searching a single index without the network.\n - Hint: remove `facetAggregations` from the query\n - Hint: search the index with the `/indexes/SHARED_NESTED_DOCUMENTS/search` route and `useNetwork: false`",
+      "code": "invalid_search_facet_aggregations",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_search_facet_aggregations"
+    }
+    "###);
+}
+
 #[actix_rt::test]
 async fn federation_one_query_error() {
     let server = Server::new_shared();
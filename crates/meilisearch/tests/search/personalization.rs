@@ -0,0 +1,154 @@
+use meili_snap::{json_string, snapshot};
+use once_cell::sync::Lazy;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::common::{Server, Value};
+use crate::json;
+
+static DOCUMENTS: Lazy<Value> = Lazy::new(|| {
+    json!([
+        {"id": 1, "title": "The Dark Knight", "genre": "Action"},
+        {"id": 2, "title": "Inception", "genre": "Sci-Fi"},
+        {"id": 3, "title": "The Matrix", "genre": "Sci-Fi"}
+    ])
+});
+
+async fn create_mock(response: serde_json::Value) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/rerank"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .mount(&mock_server)
+        .await;
+    mock_server
+}
+
+#[actix_rt::test]
+async fn rerank_with_index_reranker() {
+    let mock_server = create_mock(serde_json::json!([
+        { "index": 2, "score": 0.9 },
+        { "index": 1, "score": 0.8 },
+        { "index": 0, "score": 0.1 }
+    ]))
+    .await;
+
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, code) = index
+        .update_settings_reranker(json!({
+            "url": format!("{}/rerank", mock_server.uri()),
+            "request": { "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] },
+            "response": [{ "index": "{{index}}" }, "{{..}}"],
+            "documentFields": ["title"]
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({
+            "personalize": { "userContext": "I love science fiction movies" }
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["hits"]), @r#"
+    [
+      {
+        "id": 3,
+        "title": "The Matrix",
+        "genre": "Sci-Fi"
+      },
+      {
+        "id": 2,
+        "title": "Inception",
+        "genre": "Sci-Fi"
+      },
+      {
+        "id": 1,
+        "title": "The Dark Knight",
+        "genre": "Action"
+      }
+    ]
+    "#);
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let body: serde_json::Value = requests[0].body_json().unwrap();
+    snapshot!(json_string!(body), @r#"
+    {
+      "query": "User Context: I love science fiction movies",
+      "texts": [
+        "{\"title\":\"The Dark Knight\"}",
+        "{\"title\":\"Inception\"}",
+        "{\"title\":\"The Matrix\"}"
+      ]
+    }
+    "#);
+}
+
+#[actix_rt::test]
+async fn rerank_with_scores() {
+    let mock_server = create_mock(serde_json::json!({ "scores": [0.1, 0.9, 0.5] })).await;
+
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index
+        .update_settings_reranker(json!({
+            "url": format!("{}/rerank", mock_server.uri()),
+            "request": { "query": "{{query}}", "documents": ["{{document}}", "{{..}}"] },
+            "response": { "scores": ["{{score}}", "{{..}}"] }
+        }))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({
+            "personalize": { "userContext": "I love science fiction movies" }
+        }))
+        .await;
+    snapshot!(code, @"200 OK");
+    let ids: Vec<_> = response["hits"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|hit| hit["id"].as_u64().unwrap())
+        .collect();
+    snapshot!(format!("{ids:?}"), @"[2, 3, 1]");
+}
+
+#[actix_rt::test]
+async fn rerank_with_bad_response() {
+    let mock_server = create_mock(serde_json::json!([{ "index": 5 }])).await;
+
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, _code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _code) = index
+        .update_settings_reranker(json!({
+            "url": format!("{}/rerank", mock_server.uri()),
+            "request": { "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] },
+            "response": [{ "index": "{{index}}" }, "{{..}}"]
+        }))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index
+        .search_post(json!({
+            "personalize": { "userContext": "I love science fiction movies" }
+        }))
+        .await;
+    snapshot!(code, @"502 Bad Gateway");
+    snapshot!(json_string!(response), @r#"
+    {
+      "message": "Personalization service: Failed to parse response: in `response`: the reranker returned the position 5, but only 3 documents were sent",
+      "code": "remote_bad_response",
+      "type": "system",
+      "link": "https://docs.meilisearch.com/errors#remote_bad_response"
+    }
+    "#);
+}
@@ -0,0 +1,110 @@
+use meili_snap::{json_string, snapshot};
+use once_cell::sync::Lazy;
+
+use crate::common::index::Index;
+use crate::common::{Server, Shared, Value};
+use crate::json;
+
+static DOCUMENTS: Lazy<Value> = Lazy::new(|| {
+    json!([
+        { "id": 1, "title": "The Lord of the Rings", "overview": "A hobbit carries a ring to Mordor" },
+        { "id": 2, "title": "The Hobbit", "overview": "A journey with dwarves and a dragon" },
+        { "id": 3, "title": "Dragonheart", "overview": "The last dragon befriends a knight" },
+        { "id": 4, "title": "Ring", "overview": "A cursed video tape" },
+    ])
+});
+
+async fn index_with_documents(server: &Server<Shared>) -> Index<'_> {
+    let index = server.unique_index();
+    let (task, _status_code) = index.add_documents(DOCUMENTS.clone(), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    index
+}
+
+#[actix_rt::test]
+async fn suggestions_correct_typos() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    index
+        .search(json!({ "q": "hobit dragn", "suggestions": 3 }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["suggestions"]), @r###"
+            [
+              {
+                "query": "hobbit dragon",
+                "estimatedHits": 1
+              }
+            ]
+            "###);
+        })
+        .await;
+
+    // a query without typos has no suggestions
+    index
+        .search(json!({ "q": "hobbit dragon", "suggestions": 3 }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["suggestions"]), @"[]");
+        })
+        .await;
+
+    // the suggestions are only returned when requested
+    index
+        .search(json!({ "q": "hobit dragn" }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(response.get("suggestions").is_none(), @"true");
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn suggestions_respect_typo_tolerance() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    let (task, _status_code) = index
+        .update_settings_typo_tolerance(
+            json!({ "minWordSizeForTypos": { "oneTypo": 6, "twoTypos": 9 } }),
+        )
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(json!({ "q": "hobit dragn", "suggestions": 3 }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["suggestions"]), @"[]");
+        })
+        .await;
+
+    let (task, _status_code) =
+        index.update_settings_typo_tolerance(json!({ "enabled": false })).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    index
+        .search(json!({ "q": "dragn", "suggestions": 3 }), |response, code| {
+            snapshot!(code, @"200 OK");
+            snapshot!(json_string!(response["suggestions"]), @"[]");
+        })
+        .await;
+}
+
+#[actix_rt::test]
+async fn suggestions_in_federated_search() {
+    let server = Server::new_shared();
+    let index = index_with_documents(server).await;
+
+    let (response, code) = server
+        .multi_search(json!({ "federation": {}, "queries": [
+            { "indexUid": index.uid, "q": "hobit", "suggestions": 3 },
+        ]}))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Inside `.queries[0]`: Using `.suggestions` is not allowed in federated queries.\n - Hint: remove `suggestions` from the query or remove `federation` from the request",
+      "code": "invalid_multi_search_query_suggestions",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_multi_search_query_suggestions"
+    }
+    "###);
+}
@@ -199,6 +199,11 @@ test_setting_routes!(
         update_verb: put,
         default_value: []
     },
+    {
+        setting: reranker,
+        update_verb: patch,
+        default_value: null
+    },
 );
 
 #[actix_rt::test]
@@ -218,7 +223,7 @@ async fn get_settings() {
     let (response, code) = index.settings().await;
     assert_eq!(code, 200);
     let settings = response.as_object().unwrap();
-    assert_eq!(settings.keys().len(), 20);
+    assert_eq!(settings.keys().len(), 21);
     assert_eq!(settings["displayedAttributes"], json!(["*"]));
     assert_eq!(settings["searchableAttributes"], json!(["*"]));
     assert_eq!(settings["filterableAttributes"], json!([]));
@@ -251,6 +256,7 @@ async fn get_settings() {
     assert_eq!(settings["searchCutoffMs"], json!(null));
     assert_eq!(settings["prefixSearch"], json!("indexingTime"));
     assert_eq!(settings["facetSearch"], json!(true));
+    assert_eq!(settings["reranker"], json!(null));
     assert_eq!(settings["embedders"], json!({}));
     assert_eq!(settings["synonyms"], json!({}));
     assert_eq!(
@@ -369,7 +375,8 @@ async fn secrets_are_hidden_in_settings() {
       "searchCutoffMs": null,
       "localizedAttributes": null,
       "facetSearch": true,
-      "prefixSearch": "indexingTime"
+      "prefixSearch": "indexingTime",
+      "reranker": null
     }
     "###);
 
@@ -607,7 +614,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": true,
           "filter": {
             "equality": true,
-            "comparison": false
+            "comparison": false,
+            "patternMatching": false
           }
         }
       },
@@ -619,7 +627,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": false,
           "filter": {
             "equality": true,
-            "comparison": true
+            "comparison": true,
+            "patternMatching": false
           }
         }
       },
@@ -631,7 +640,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": false,
           "filter": {
             "equality": true,
-            "comparison": false
+            "comparison": false,
+            "patternMatching": false
           }
         }
       },
@@ -643,7 +653,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": true,
           "filter": {
             "equality": true,
-            "comparison": false
+            "comparison": false,
+            "patternMatching": false
           }
         }
       },
@@ -655,7 +666,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": true,
           "filter": {
             "equality": true,
-            "comparison": true
+            "comparison": true,
+            "patternMatching": false
           }
         }
       },
@@ -667,7 +679,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": true,
           "filter": {
             "equality": true,
-            "comparison": false
+            "comparison": false,
+            "patternMatching": false
           }
         }
       },
@@ -679,7 +692,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": true,
           "filter": {
             "equality": true,
-            "comparison": false
+            "comparison": false,
+            "patternMatching": false
           }
         }
       },
@@ -691,7 +705,8 @@ async fn granular_filterable_attributes() {
           "facetSearch": false,
           "filter": {
             "equality": true,
-            "comparison": true
+            "comparison": true,
+            "patternMatching": false
           }
         }
       }
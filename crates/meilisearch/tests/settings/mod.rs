@@ -5,5 +5,6 @@ mod get_settings;
 mod parent_seachable_fields;
 mod prefix_search_settings;
 mod proximity_settings;
+mod reranker;
 mod tokenizer_customization;
 mod vectors;
@@ -0,0 +1,144 @@
+use meili_snap::{json_string, snapshot};
+
+use crate::common::Server;
+use crate::json;
+
+#[actix_rt::test]
+async fn set_update_reset_reranker() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, code) = index
+        .update_settings_reranker(json!({
+            "url": "http://localhost:8080/rerank",
+            "apiKey": "my-super-secret-api-key",
+            "request": { "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] },
+            "response": [{ "index": "{{index}}" }, "{{..}}"],
+            "documentFields": ["title"]
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) = index.settings().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(response["reranker"]), @r#"
+    {
+      "url": "http://localhost:8080/rerank",
+      "apiKey": "my-XXXXX...",
+      "request": {
+        "query": "{{query}}",
+        "texts": [
+          "{{document}}",
+          "{{..}}"
+        ]
+      },
+      "response": [
+        {
+          "index": "{{index}}"
+        },
+        "{{..}}"
+      ],
+      "headers": {},
+      "timeoutMs": 30000,
+      "documentFields": [
+        "title"
+      ]
+    }
+    "#);
+
+    // a partial update keeps the other fields
+    let (task, _code) = index
+        .update_settings_reranker(json!({
+            "timeoutMs": 5000,
+            "documentFields": null
+        }))
+        .await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["reranker"]), @r#"
+    {
+      "url": "http://localhost:8080/rerank",
+      "apiKey": "my-XXXXX...",
+      "request": {
+        "query": "{{query}}",
+        "texts": [
+          "{{document}}",
+          "{{..}}"
+        ]
+      },
+      "response": [
+        {
+          "index": "{{index}}"
+        },
+        "{{..}}"
+      ],
+      "headers": {},
+      "timeoutMs": 5000,
+      "documentFields": null
+    }
+    "#);
+
+    let (task, _code) = index.update_settings(json!({ "reranker": null })).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, _code) = index.settings().await;
+    snapshot!(json_string!(response["reranker"]), @"null");
+}
+
+#[actix_rt::test]
+async fn invalid_reranker() {
+    let server = Server::new().await;
+    let index = server.index("test");
+
+    let (task, code) = index
+        .update_settings_reranker(json!({
+            "url": "http://localhost:8080/rerank",
+        }))
+        .await;
+    snapshot!(code, @"202 Accepted");
+    let task = server.wait_task(task.uid()).await.failed();
+    snapshot!(json_string!(task["error"]), @r#"
+    {
+      "message": "Index `test`: `.reranker`: missing field `request`",
+      "code": "invalid_settings_reranker",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_reranker"
+    }
+    "#);
+
+    let (task, _code) = index
+        .update_settings_reranker(json!({
+            "url": "http://localhost:8080/rerank",
+            "request": { "texts": ["{{document}}", "{{..}}"] },
+            "response": [{ "index": "{{index}}" }, "{{..}}"],
+        }))
+        .await;
+    let task = server.wait_task(task.uid()).await.failed();
+    snapshot!(json_string!(task["error"]), @r#"
+    {
+      "message": "Index `test`: `.reranker`: in `request`: missing the \"{{query}}\" placeholder",
+      "code": "invalid_settings_reranker",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_reranker"
+    }
+    "#);
+
+    let (task, _code) = index
+        .update_settings_reranker(json!({
+            "url": "http://localhost:8080/rerank",
+            "request": { "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] },
+            "response": { "results": "{{ranking}}" },
+        }))
+        .await;
+    let task = server.wait_task(task.uid()).await.failed();
+    snapshot!(json_string!(task["error"]), @r#"
+    {
+      "message": "Index `test`: `.reranker`: in `response`: missing either the \"{{index}}\" or the \"{{score}}\" placeholder",
+      "code": "invalid_settings_reranker",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_reranker"
+    }
+    "#);
+}
@@ -1,3 +1,4 @@
+use std::num::NonZeroU64;
 use std::time::Duration;
 
 use meili_snap::{json_string, snapshot};
@@ -200,6 +201,130 @@ async fn perform_on_demand_snapshot() {
     );
 }
 
+#[actix_rt::test]
+#[cfg_attr(target_os = "windows", ignore)]
+async fn snapshot_retention_count() {
+    let temp = tempfile::tempdir().unwrap();
+    let snapshot_dir = tempfile::tempdir().unwrap();
+
+    let options = Opt {
+        snapshot_dir: snapshot_dir.path().to_owned(),
+        experimental_snapshot_retention_count: NonZeroU64::new(2),
+        ..default_settings(temp.path())
+    };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let (task, _status_code) = server.index("doggo").create(Some("bone")).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let mut snapshots = Vec::new();
+    for _ in 0..3 {
+        let (task, code) = server.create_snapshot().await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(task.uid()).await.succeeded();
+
+        let mut names: Vec<String> = std::fs::read_dir(&snapshot_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_str().unwrap().to_string())
+            .collect();
+        names.sort();
+        snapshots.push(names);
+    }
+
+    // the snapshots are timestamped and only the last two are kept
+    for names in &snapshots {
+        assert!(names.iter().all(|name| name.starts_with("db-") && name.ends_with(".snapshot")));
+    }
+    let lengths: Vec<_> = snapshots.iter().map(Vec::len).collect();
+    snapshot!(format!("{lengths:?}"), @"[1, 2, 2]");
+    assert!(!snapshots[2].contains(&snapshots[0][0]));
+    assert!(snapshots[2].contains(&snapshots[1][1]));
+
+    // the latest snapshot can be imported
+    let temp = tempfile::tempdir().unwrap();
+    let snapshot_path = snapshot_dir.path().join(&snapshots[2][1]);
+    let options = Opt { import_snapshot: Some(snapshot_path), ..default_settings(temp.path()) };
+    let snapshot_server = Server::new_with_options(options).await.unwrap();
+    let (index, code) = snapshot_server.index("doggo").get().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(index["primaryKey"], @r###""bone""###);
+}
+
+#[actix_rt::test]
+#[cfg(unix)]
+async fn incremental_snapshot() {
+    use std::os::unix::fs::MetadataExt;
+
+    let temp = tempfile::tempdir().unwrap();
+    let snapshot_dir = tempfile::tempdir().unwrap();
+
+    let options = Opt {
+        snapshot_dir: snapshot_dir.path().to_owned(),
+        experimental_snapshot_retention_count: NonZeroU64::new(2),
+        experimental_incremental_snapshots: true,
+        ..default_settings(temp.path())
+    };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let (task, _status_code) = server.index("catto").create(Some("id")).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _status_code) = server.index("doggo").create(Some("bone")).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = server.create_snapshot().await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    // only the doggo index changes between the two snapshots
+    let (task, _code) = server.index("doggo").add_documents(json!([{ "bone": 1 }]), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = server.create_snapshot().await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let mut snapshots: Vec<_> = std::fs::read_dir(&snapshot_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.is_dir())
+        .collect();
+    snapshots.sort();
+    snapshot!(snapshots.len(), @"2");
+
+    let manifest = std::fs::read_to_string(snapshots[1].join("manifest.json")).unwrap();
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    let indexes = manifest["indexes"].as_object().unwrap();
+    let mut reused: Vec<_> = indexes
+        .iter()
+        .map(|(uuid, index)| {
+            (index["uid"].as_str().unwrap(), index["reused"].as_bool().unwrap(), uuid)
+        })
+        .collect();
+    reused.sort();
+    let reused_by_uid: Vec<_> = reused.iter().map(|(uid, reused, _)| (uid, reused)).collect();
+    snapshot!(format!("{reused_by_uid:?}"), @r###"[("catto", true), ("doggo", false)]"###);
+
+    // the unchanged index file is shared with the previous snapshot
+    let index_file = |snapshot: &std::path::Path, uuid: &str| {
+        std::fs::metadata(snapshot.join("indexes").join(uuid).join("data.mdb")).unwrap().ino()
+    };
+    let (_, _, catto_uuid) = reused[0];
+    let (_, _, doggo_uuid) = reused[1];
+    assert_eq!(index_file(&snapshots[0], catto_uuid), index_file(&snapshots[1], catto_uuid));
+    assert_ne!(index_file(&snapshots[0], doggo_uuid), index_file(&snapshots[1], doggo_uuid));
+
+    // the incremental snapshot is imported like a full one
+    let temp = tempfile::tempdir().unwrap();
+    let options =
+        Opt { import_snapshot: Some(snapshots[1].clone()), ..default_settings(temp.path()) };
+    let snapshot_server = Server::new_with_options(options).await.unwrap();
+
+    verify_snapshot!(server, snapshot_server, |server| =>
+        server.list_indexes(None, None),
+        server.index("catto").settings(),
+        server.index("doggo").get_all_documents(GetAllDocumentsOptions::default()),
+        server.index("doggo").settings(),
+    );
+}
+
 #[actix_rt::test]
 #[cfg_attr(target_os = "windows", ignore)]
 async fn snapshotception_issue_4653() {
@@ -266,3 +391,328 @@ async fn snapshotception_issue_4653() {
     }
     "#);
 }
+
+/// An in-memory stand-in of an S3 bucket that supports the uploads, the server-side copies,
+/// the listings, the downloads and the deletions of objects used by the S3 snapshots.
+#[cfg(all(unix, feature = "enterprise"))]
+#[derive(Clone, Default)]
+struct S3StandIn {
+    state: std::sync::Arc<std::sync::Mutex<S3StandInState>>,
+}
+
+#[cfg(all(unix, feature = "enterprise"))]
+#[derive(Default)]
+struct S3StandInState {
+    /// The objects by key with their content and their last modification date.
+    objects: std::collections::BTreeMap<String, (Vec<u8>, String)>,
+    /// The parts of the ongoing multipart uploads by upload id.
+    uploads: std::collections::BTreeMap<String, std::collections::BTreeMap<u16, Vec<u8>>>,
+    /// The server-side copies of objects, by source and destination keys.
+    copies: Vec<(String, String)>,
+    /// Used to generate the upload ids and increasing modification dates.
+    counter: u64,
+}
+
+#[cfg(all(unix, feature = "enterprise"))]
+impl S3StandInState {
+    fn next_modification_date(&mut self) -> String {
+        self.counter += 1;
+        let counter = self.counter;
+        format!(
+            "2025-01-01T{:02}:{:02}:{:02}.000Z",
+            counter / 3600,
+            counter / 60 % 60,
+            counter % 60
+        )
+    }
+}
+
+#[cfg(all(unix, feature = "enterprise"))]
+impl S3StandIn {
+    const BUCKET: &'static str = "test-bucket";
+
+    fn object_names(&self) -> Vec<String> {
+        self.state.lock().unwrap().objects.keys().cloned().collect()
+    }
+
+    fn object(&self, key: &str) -> Option<Vec<u8>> {
+        self.state.lock().unwrap().objects.get(key).map(|(content, _)| content.clone())
+    }
+
+    fn copies(&self) -> Vec<(String, String)> {
+        self.state.lock().unwrap().copies.clone()
+    }
+}
+
+#[cfg(all(unix, feature = "enterprise"))]
+impl wiremock::Respond for S3StandIn {
+    fn respond(&self, request: &wiremock::Request) -> wiremock::ResponseTemplate {
+        use wiremock::ResponseTemplate;
+
+        let mut state = self.state.lock().unwrap();
+        let query: std::collections::HashMap<String, String> =
+            request.url.query_pairs().into_owned().collect();
+        let bucket_path = format!("/{}", S3StandIn::BUCKET);
+        let Some(key) = request.url.path().strip_prefix(&bucket_path) else {
+            return ResponseTemplate::new(404);
+        };
+        let key = key.trim_start_matches('/').to_string();
+
+        match (request.method.as_str(), query.get("uploadId")) {
+            ("POST", None) if query.contains_key("uploads") => {
+                state.counter += 1;
+                let upload_id = format!("upload-{}", state.counter);
+                state.uploads.insert(upload_id.clone(), Default::default());
+                ResponseTemplate::new(200).set_body_string(format!(
+                    "<InitiateMultipartUploadResult><Bucket>{}</Bucket><Key>{key}</Key><UploadId>{upload_id}</UploadId></InitiateMultipartUploadResult>",
+                    S3StandIn::BUCKET
+                ))
+            }
+            ("PUT", Some(upload_id)) => {
+                let part_number: u16 = query["partNumber"].parse().unwrap();
+                let Some(parts) = state.uploads.get_mut(upload_id) else {
+                    return ResponseTemplate::new(404);
+                };
+                parts.insert(part_number, request.body.clone());
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", format!("\"{upload_id}-{part_number}\"").as_str())
+            }
+            ("POST", Some(upload_id)) => {
+                let Some(parts) = state.uploads.remove(upload_id) else {
+                    return ResponseTemplate::new(404);
+                };
+                let last_modified = state.next_modification_date();
+                state
+                    .objects
+                    .insert(key.clone(), (parts.into_values().flatten().collect(), last_modified));
+                ResponseTemplate::new(200).set_body_string(format!(
+                    "<CompleteMultipartUploadResult><Bucket>{}</Bucket><Key>{key}</Key><ETag>\"{upload_id}\"</ETag></CompleteMultipartUploadResult>",
+                    S3StandIn::BUCKET
+                ))
+            }
+            ("GET", None) if query.get("list-type").map(String::as_str) == Some("2") => {
+                let prefix = query.get("prefix").map(String::as_str).unwrap_or_default();
+                let contents: String = state
+                    .objects
+                    .iter()
+                    .filter(|(key, _)| key.starts_with(prefix))
+                    .map(|(key, (content, last_modified))| {
+                        format!(
+                            "<Contents><Key>{key}</Key><LastModified>{last_modified}</LastModified><ETag>\"etag\"</ETag><Size>{}</Size><StorageClass>STANDARD</StorageClass></Contents>",
+                            content.len()
+                        )
+                    })
+                    .collect();
+                ResponseTemplate::new(200).set_body_string(format!(
+                    "<ListBucketResult><Name>{}</Name><Prefix>{prefix}</Prefix><MaxKeys>1000</MaxKeys><IsTruncated>false</IsTruncated>{contents}</ListBucketResult>",
+                    S3StandIn::BUCKET
+                ))
+            }
+            ("GET", None) => match state.objects.get(&key) {
+                Some((content, _)) => ResponseTemplate::new(200).set_body_bytes(content.clone()),
+                None => ResponseTemplate::new(404),
+            },
+            ("DELETE", None) => {
+                state.objects.remove(&key);
+                ResponseTemplate::new(204)
+            }
+            ("PUT", None) => {
+                let content = match request.headers.get("x-amz-copy-source") {
+                    Some(source) => {
+                        let source = source.to_str().unwrap();
+                        let source = source.strip_prefix(&bucket_path).unwrap();
+                        let source = source.trim_start_matches('/').to_string();
+                        let Some((content, _)) = state.objects.get(&source) else {
+                            return ResponseTemplate::new(404);
+                        };
+                        let content = content.clone();
+                        state.copies.push((source, key.clone()));
+                        content
+                    }
+                    None => request.body.clone(),
+                };
+                let last_modified = state.next_modification_date();
+                state.objects.insert(key, (content, last_modified));
+                ResponseTemplate::new(200)
+            }
+            _ => ResponseTemplate::new(400),
+        }
+    }
+}
+
+#[actix_rt::test]
+#[cfg(all(unix, feature = "enterprise"))]
+async fn s3_snapshot_retention_and_import() {
+    use meilisearch::option::S3SnapshotOpts;
+    use wiremock::matchers::AnyMatcher;
+    use wiremock::{Mock, MockServer};
+
+    let mock_server = MockServer::start().await;
+    let bucket = S3StandIn::default();
+    Mock::given(AnyMatcher).respond_with(bucket.clone()).mount(&mock_server).await;
+
+    let s3_snapshot_options: S3SnapshotOpts = serde_json::from_value(json!({
+        "s3_bucket_url": mock_server.uri(),
+        "s3_bucket_region": "us-east-1",
+        "s3_bucket_name": S3StandIn::BUCKET,
+        "s3_snapshot_prefix": "snapshots",
+        "s3_access_key": "access-key",
+        "s3_secret_key": "secret-key",
+    }))
+    .unwrap();
+
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        s3_snapshot_options: Some(s3_snapshot_options.clone()),
+        experimental_snapshot_retention_count: NonZeroU64::new(1),
+        ..default_settings(temp.path())
+    };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let (task, _status_code) = server.index("doggo").create(Some("bone")).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let mut snapshots = Vec::new();
+    for _ in 0..2 {
+        let (task, code) = server.create_snapshot().await;
+        snapshot!(code, @"202 Accepted");
+        server.wait_task(task.uid()).await.succeeded();
+        snapshots.push(bucket.object_names());
+    }
+
+    // only the latest snapshot is kept in the bucket
+    let lengths: Vec<_> = snapshots.iter().map(Vec::len).collect();
+    snapshot!(format!("{lengths:?}"), @"[1, 1]");
+    assert!(snapshots[1][0].starts_with("snapshots/db-"));
+    assert!(snapshots[1][0].ends_with(".snapshot"));
+    assert_ne!(snapshots[0], snapshots[1]);
+
+    // the latest snapshot is imported from the bucket
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        import_snapshot_from_s3: Some(String::from("latest")),
+        s3_snapshot_options: Some(s3_snapshot_options.clone()),
+        ..default_settings(temp.path())
+    };
+    let snapshot_server = Server::new_with_options(options).await.unwrap();
+    let (index, code) = snapshot_server.index("doggo").get().await;
+    snapshot!(code, @"200 OK");
+    snapshot!(index["primaryKey"], @r###""bone""###);
+
+    // the snapshot is also imported by name, without the extension
+    let name = snapshots[1][0].strip_prefix("snapshots/").unwrap().strip_suffix(".snapshot");
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        import_snapshot_from_s3: name.map(String::from),
+        s3_snapshot_options: Some(s3_snapshot_options.clone()),
+        ..default_settings(temp.path())
+    };
+    let snapshot_server = Server::new_with_options(options).await.unwrap();
+    let (_index, code) = snapshot_server.index("doggo").get().await;
+    snapshot!(code, @"200 OK");
+
+    // the expired snapshot cannot be imported anymore
+    let name = snapshots[0][0].strip_prefix("snapshots/").unwrap();
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        import_snapshot_from_s3: Some(name.to_string()),
+        s3_snapshot_options: Some(s3_snapshot_options),
+        ..default_settings(temp.path())
+    };
+    let error = Server::new_with_options(options).await.err().unwrap();
+    assert_eq!(error.to_string(), format!("snapshot `{name}` doesn't exist in the S3 bucket"));
+}
+
+#[actix_rt::test]
+#[cfg(all(unix, feature = "enterprise"))]
+async fn s3_incremental_snapshot() {
+    use meilisearch::option::S3SnapshotOpts;
+    use wiremock::matchers::AnyMatcher;
+    use wiremock::{Mock, MockServer};
+
+    let mock_server = MockServer::start().await;
+    let bucket = S3StandIn::default();
+    Mock::given(AnyMatcher).respond_with(bucket.clone()).mount(&mock_server).await;
+
+    let s3_snapshot_options: S3SnapshotOpts = serde_json::from_value(json!({
+        "s3_bucket_url": mock_server.uri(),
+        "s3_bucket_region": "us-east-1",
+        "s3_bucket_name": S3StandIn::BUCKET,
+        "s3_snapshot_prefix": "snapshots",
+        "s3_access_key": "access-key",
+        "s3_secret_key": "secret-key",
+    }))
+    .unwrap();
+
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        s3_snapshot_options: Some(s3_snapshot_options.clone()),
+        experimental_snapshot_retention_count: NonZeroU64::new(1),
+        experimental_incremental_snapshots: true,
+        ..default_settings(temp.path())
+    };
+    let server = Server::new_with_options(options).await.unwrap();
+
+    let (task, _status_code) = server.index("catto").create(Some("id")).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _status_code) = server.index("doggo").create(Some("bone")).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = server.create_snapshot().await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    // only the doggo index changes between the two snapshots
+    let (task, _code) = server.index("doggo").add_documents(json!([{ "bone": 1 }]), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = server.create_snapshot().await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    // the previous snapshot is removed by the retention policy
+    let snapshots: Vec<_> = bucket
+        .object_names()
+        .into_iter()
+        .filter_map(|key| key.strip_suffix("/manifest.json").map(String::from))
+        .collect();
+    snapshot!(snapshots.len(), @"1");
+    let snapshot = &snapshots[0];
+    assert!(bucket.object_names().iter().all(|key| key.starts_with(&format!("{snapshot}/"))));
+
+    let manifest = bucket.object(&format!("{snapshot}/manifest.json")).unwrap();
+    let manifest: serde_json::Value = serde_json::from_slice(&manifest).unwrap();
+    let indexes = manifest["indexes"].as_object().unwrap();
+    let mut reused: Vec<_> = indexes
+        .iter()
+        .map(|(uuid, index)| {
+            (index["uid"].as_str().unwrap(), index["reused"].as_bool().unwrap(), uuid)
+        })
+        .collect();
+    reused.sort();
+    let reused_by_uid: Vec<_> = reused.iter().map(|(uid, reused, _)| (uid, reused)).collect();
+    snapshot!(format!("{reused_by_uid:?}"), @r###"[("catto", true), ("doggo", false)]"###);
+
+    // the unchanged index file is copied server-side from the previous snapshot
+    let (_, _, catto_uuid) = reused[0];
+    let copies = bucket.copies();
+    snapshot!(copies.len(), @"1");
+    let (src, dst) = &copies[0];
+    assert_ne!(src, dst);
+    assert!(src.ends_with(&format!("/indexes/{catto_uuid}/data.mdb")));
+    assert_eq!(dst, &format!("{snapshot}/indexes/{catto_uuid}/data.mdb"));
+
+    // the incremental snapshot is imported from the bucket like a full one
+    let temp = tempfile::tempdir().unwrap();
+    let options = Opt {
+        import_snapshot_from_s3: Some(String::from("latest")),
+        s3_snapshot_options: Some(s3_snapshot_options),
+        ..default_settings(temp.path())
+    };
+    let snapshot_server = Server::new_with_options(options).await.unwrap();
+
+    verify_snapshot!(server, snapshot_server, |server| =>
+        server.list_indexes(None, None),
+        server.index("catto").settings(),
+        server.index("doggo").get_all_documents(GetAllDocumentsOptions::default()),
+        server.index("doggo").settings(),
+    );
+}
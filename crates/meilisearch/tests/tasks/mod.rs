@@ -237,6 +237,138 @@ async fn list_tasks_status_and_type_filtered() {
     assert_eq!(response["results"].as_array().unwrap().len(), 2);
 }
 
+#[actix_rt::test]
+async fn list_tasks_priority_filtered() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let (task, _status_code) = index.create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = index
+        .service
+        .post(
+            format!("/indexes/{}/documents?priority=high", index.uid),
+            json!([{ "id": 1, "content": "doggo" }]),
+        )
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "high");
+    let task = server.wait_task(task.uid()).await.succeeded();
+    assert_eq!(task["priority"], "high");
+
+    let (task, code) = index
+        .service
+        .post(
+            format!("/indexes/{}/documents?priority=normal", index.uid),
+            json!([{ "id": 2, "content": "catto" }]),
+        )
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert!(task.get("priority").is_none(), "{task}");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        server.tasks_filter(&format!("indexUids={}&priorities=high", index.uid)).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"].as_array().unwrap().len(), 1);
+
+    let (response, code) =
+        server.tasks_filter(&format!("indexUids={}&priorities=normal", index.uid)).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"].as_array().unwrap().len(), 2);
+
+    let (response, code) =
+        server.tasks_filter(&format!("indexUids={}&priorities=low,high", index.uid)).await;
+    assert_eq!(code, 200, "{response}");
+    assert_eq!(response["results"].as_array().unwrap().len(), 1);
+
+    let (response, code) = server.tasks_filter("priorities=urgent").await;
+    assert_eq!(code, 400, "{response}");
+    snapshot!(json_string!(response), @r###"
+    {
+      "message": "Invalid value in parameter `priorities`: `urgent` is not a valid task priority. Available priorities are `low`, `normal`, `high`.",
+      "code": "invalid_task_priorities",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_task_priorities"
+    }
+    "###);
+}
+
+#[actix_rt::test]
+async fn settings_update_with_priority() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (task, code) = index
+        .service
+        .patch(
+            format!("/indexes/{}/settings?priority=high", index.uid),
+            json!({ "filterableAttributes": ["id"] }),
+        )
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "high");
+    let task = server.wait_task(task.uid()).await.succeeded();
+    assert_eq!(task["priority"], "high");
+
+    let (task, code) = index
+        .service
+        .delete(
+            format!("/indexes/{}/settings/filterable-attributes?priority=low", index.uid),
+            vec![],
+        )
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "low");
+    server.wait_task(task.uid()).await.succeeded();
+}
+
+#[actix_rt::test]
+async fn index_operations_with_priority() {
+    let server = Server::new_shared();
+    let index = server.unique_index();
+    let other = server.unique_index();
+
+    let (task, code) = index
+        .service
+        .post("/indexes?priority=high", json!({ "uid": index.uid, "primaryKey": "id" }))
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "high");
+    let task = server.wait_task(task.uid()).await.succeeded();
+    assert_eq!(task["priority"], "high");
+
+    let (task, code) = index
+        .service
+        .patch(format!("/indexes/{}?priority=low", index.uid), json!({ "primaryKey": "id" }))
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "low");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, _code) = other.create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) = index
+        .service
+        .post("/swap-indexes?priority=high", json!([{ "indexes": [index.uid, other.uid] }]))
+        .await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "high");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (task, code) =
+        index.service.delete(format!("/indexes/{}?priority=high", index.uid), vec![]).await;
+    assert_eq!(code, 202, "{task}");
+    assert_eq!(task["priority"], "high");
+    server.wait_task(task.uid()).await.succeeded();
+
+    let (response, code) =
+        index.service.post("/indexes?priority=urgent", json!({ "uid": other.uid })).await;
+    assert_eq!(code, 400, "{response}");
+    assert_eq!(response["code"], "invalid_task_priority", "{response}");
+}
+
 macro_rules! assert_valid_summarized_task {
     ($response:expr, $task_type:literal, $index:tt) => {{
         assert_eq!($response.as_object().unwrap().len(), 5);
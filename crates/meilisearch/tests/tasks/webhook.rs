@@ -3,7 +3,9 @@
 //! received requests into a channel for you to handle.
 
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use actix_http::body::MessageBody;
 use actix_web::dev::{ServiceFactory, ServiceResponse};
@@ -35,6 +37,43 @@ async fn forward_body(
     HttpResponse::Ok().into()
 }
 
+const WEBHOOK_SECRET: &str = "a-shared-secret-for-tests";
+
+#[post("/signed")]
+async fn forward_signed_body(
+    req: HttpRequest,
+    sender: Data<mpsc::UnboundedSender<Vec<u8>>>,
+    body: Bytes,
+) -> HttpResponse {
+    let headers = req.headers();
+    let timestamp: i64 =
+        headers.get("meili-webhook-timestamp").unwrap().to_str().unwrap().parse().unwrap();
+    let signature = headers.get("meili-webhook-signature").unwrap().to_str().unwrap();
+    assert_eq!(signature, index_scheduler::sign_webhook_payload(WEBHOOK_SECRET, timestamp, &body));
+
+    let body = body.to_vec();
+    sender.send(body).unwrap();
+    HttpResponse::Ok().into()
+}
+
+/// Number of requests received on the `/flaky` route.
+static FLAKY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// Fails on the first request it receives, and forwards the body of the next ones.
+#[post("/flaky")]
+async fn forward_body_after_failure(
+    sender: Data<mpsc::UnboundedSender<Vec<u8>>>,
+    body: Bytes,
+) -> HttpResponse {
+    if FLAKY_CALLS.fetch_add(1, Ordering::SeqCst) == 0 {
+        return HttpResponse::InternalServerError().into();
+    }
+
+    let body = body.to_vec();
+    sender.send(body).unwrap();
+    HttpResponse::Ok().into()
+}
+
 fn create_app(
     sender: Arc<mpsc::UnboundedSender<Vec<u8>>>,
 ) -> actix_web::App<
@@ -46,7 +85,11 @@ fn create_app(
         InitError = (),
     >,
 > {
-    App::new().service(forward_body).app_data(Data::from(sender))
+    App::new()
+        .service(forward_body)
+        .service(forward_signed_body)
+        .service(forward_body_after_failure)
+        .app_data(Data::from(sender))
 }
 
 struct WebhookHandle {
@@ -591,7 +634,7 @@ async fn forbidden_fields() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(value, @r#"
     {
-      "message": "Immutable field `uuid`: expected one of `url`, `headers`",
+      "message": "Immutable field `uuid`: expected one of `url`, `headers`, `types`, `statuses`, `indexUids`, `secret`",
       "code": "immutable_webhook_uuid",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_webhook_uuid"
@@ -609,7 +652,7 @@ async fn forbidden_fields() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(value, @r#"
     {
-      "message": "Immutable field `isEditable`: expected one of `url`, `headers`",
+      "message": "Immutable field `isEditable`: expected one of `url`, `headers`, `types`, `statuses`, `indexUids`, `secret`",
       "code": "immutable_webhook_is_editable",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_webhook_is_editable"
@@ -629,7 +672,7 @@ async fn forbidden_fields() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(value, @r#"
     {
-      "message": "Immutable field `uuid`: expected one of `url`, `headers`",
+      "message": "Immutable field `uuid`: expected one of `url`, `headers`, `types`, `statuses`, `indexUids`, `secret`",
       "code": "immutable_webhook_uuid",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_webhook_uuid"
@@ -649,7 +692,7 @@ async fn forbidden_fields() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(json_string!(value, { ".uuid" => "[uuid]" }), @r#"
     {
-      "message": "Immutable field `isEditable`: expected one of `url`, `headers`",
+      "message": "Immutable field `isEditable`: expected one of `url`, `headers`, `types`, `statuses`, `indexUids`, `secret`",
       "code": "immutable_webhook_is_editable",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#immutable_webhook_is_editable"
@@ -772,3 +815,282 @@ fn check_metadata(msg: Vec<u8>) {
         "###);
     }
 }
+
+#[actix_web::test]
+async fn filtered_and_signed() {
+    let WebhookHandle { server_handle, url, mut receiver } = create_webhook_server().await;
+
+    let server = Server::new().await;
+
+    let (value, code) = server
+        .create_webhook(json!({
+            "url": format!("{url}signed"),
+            "types": ["indexCreation"],
+            "statuses": ["succeeded"],
+            "indexUids": ["tamo*"],
+            "secret": WEBHOOK_SECRET,
+        }))
+        .await;
+    snapshot!(code, @"201 Created");
+    snapshot!(json_string!(value, { ".uuid" => "[uuid]", ".url" => "[ignored]" }), @r#"
+    {
+      "uuid": "[uuid]",
+      "isEditable": true,
+      "url": "[ignored]",
+      "headers": {},
+      "types": [
+        "indexCreation"
+      ],
+      "statuses": [
+        "succeeded"
+      ],
+      "indexUids": [
+        "tamo*"
+      ],
+      "secret": "a-sXXXXX..."
+    }
+    "#);
+
+    let (task, _) = server.index("tamo").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    // filtered out by the index pattern
+    let (task, _) = server.index("kefir").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    // filtered out by the status
+    let (task, _) = server.index("tamo").create(None).await;
+    server.wait_task(task.uid()).await.failed();
+    // filtered out by the type
+    let (task, _) = server.index("tamo").add_documents(json!({ "id": 1 }), None).await;
+    server.wait_task(task.uid()).await.succeeded();
+    let (task, _) = server.index("tamo-last").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    let mut notified = Vec::new();
+    'receive: while let Some(payload) = receiver.recv().await {
+        let payload = String::from_utf8(payload).unwrap();
+        for json in payload.split('\n').filter(|json| !json.is_empty()) {
+            let task: serde_json::Value = serde_json::from_str(json).unwrap();
+            assert_eq!(task["type"], "indexCreation", "{task}");
+            assert_eq!(task["status"], "succeeded", "{task}");
+            let index_uid = task["indexUid"].as_str().unwrap().to_string();
+            let is_last = index_uid == "tamo-last";
+            notified.push(index_uid);
+            if is_last {
+                break 'receive;
+            }
+        }
+    }
+    snapshot!(format!("{notified:?}"), @r###"["tamo", "tamo-last"]"###);
+
+    server_handle.abort();
+}
+
+#[actix_web::test]
+async fn invalid_filters_and_secret() {
+    let server = Server::new().await;
+
+    let (value, code) = server
+        .create_webhook(json!({ "url": "http://localhost:6666/hook", "types": ["doggo"] }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(value, @r#"
+    {
+      "message": "Unknown value `doggo` at `.types[0]`: expected one of `documentAdditionOrUpdate`, `documentEdition`, `documentDeletion`, `settingsUpdate`, `indexCreation`, `indexDeletion`, `indexUpdate`, `indexSwap`, `taskCancelation`, `taskDeletion`, `dumpCreation`, `snapshotCreation`, `export`, `upgradeDatabase`, `indexCompaction`, `networkTopologyChange`, `dsrUpdate`, `dsrClear`, `dumpImport`",
+      "code": "invalid_webhook_types",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhook_types"
+    }
+    "#);
+
+    let (value, code) = server
+        .create_webhook(json!({ "url": "http://localhost:6666/hook", "statuses": ["done"] }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(value, @r#"
+    {
+      "message": "Unknown value `done` at `.statuses[0]`: expected one of `enqueued`, `processing`, `succeeded`, `failed`, `canceled`",
+      "code": "invalid_webhook_statuses",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhook_statuses"
+    }
+    "#);
+
+    let (value, code) = server
+        .create_webhook(json!({ "url": "http://localhost:6666/hook", "indexUids": ["tamo kefir"] }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(value, @r#"
+    {
+      "message": "Invalid value at `.indexUids[0]`: `tamo kefir` is not a valid index uid pattern. Index uid patterns can be an integer or a string containing only alphanumeric characters, hyphens (-), underscores (_), and optionally end with a star (*).",
+      "code": "invalid_webhook_index_uids",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhook_index_uids"
+    }
+    "#);
+
+    let (value, code) =
+        server.create_webhook(json!({ "url": "http://localhost:6666/hook", "secret": "" })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(json_string!(value, { ".message" => "[ignored]" }), @r#"
+    {
+      "message": "[ignored]",
+      "code": "invalid_webhook_secret",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhook_secret"
+    }
+    "#);
+
+    // filters and secret can be removed with `null`
+    let (value, code) = server
+        .create_webhook(json!({
+            "url": "http://localhost:6666/hook",
+            "statuses": ["failed"],
+            "secret": WEBHOOK_SECRET,
+        }))
+        .await;
+    snapshot!(code, @"201 Created");
+    let uuid = value["uuid"].as_str().unwrap().to_string();
+    let (value, code) =
+        server.patch_webhook(&uuid, json!({ "statuses": null, "secret": null })).await;
+    snapshot!(code, @"200 OK");
+    snapshot!(json_string!(value, { ".uuid" => "[uuid]" }), @r#"
+    {
+      "uuid": "[uuid]",
+      "isEditable": true,
+      "url": "http://localhost:6666/hook",
+      "headers": {}
+    }
+    "#);
+}
+
+#[actix_web::test]
+async fn failed_deliveries_are_listed_and_redelivered() {
+    let WebhookHandle { server_handle, url, mut receiver } = create_webhook_server().await;
+
+    let server = Server::new().await;
+
+    let (value, code) = server.create_webhook(json!({ "url": format!("{url}flaky") })).await;
+    snapshot!(code, @"201 Created");
+    let uuid = value["uuid"].as_str().unwrap().to_string();
+
+    let (task, _) = server.index("tamo").create(None).await;
+    server.wait_task(task.uid()).await.succeeded();
+
+    // the failure is registered asynchronously after the notification was sent
+    let mut deliveries = json!(null);
+    for _ in 0..100 {
+        let (value, code) = server.get_webhook_deliveries(&uuid).await;
+        snapshot!(code, @"200 OK");
+        if !value["results"].as_array().unwrap().is_empty() {
+            deliveries = value;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    snapshot!(json_string!(deliveries, {
+        ".results[].webhookUuid" => "[uuid]",
+        ".results[].attempts[].attemptedAt" => "[date]",
+        ".results[].nextAttemptAt" => "[date]",
+    }), @r#"
+    {
+      "results": [
+        {
+          "uid": 0,
+          "webhookUuid": "[uuid]",
+          "taskUids": [
+            0
+          ],
+          "status": "pending",
+          "attempts": [
+            {
+              "attemptedAt": "[date]",
+              "statusCode": 500,
+              "error": "The webhook answered with the status code 500."
+            }
+          ],
+          "nextAttemptAt": "[date]"
+        }
+      ]
+    }
+    "#);
+
+    let (value, code) =
+        server.redeliver_webhook_deliveries(&uuid, json!({ "deliveryUids": [1] })).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(json_string!(value, { ".message" => "[message]" }), @r#"
+    {
+      "message": "[message]",
+      "code": "webhook_delivery_not_found",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#webhook_delivery_not_found"
+    }
+    "#);
+
+    let (value, code) =
+        server.redeliver_webhook_deliveries(&uuid, json!({ "deliveryUids": ["0"] })).await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(value, @r#"
+    {
+      "message": "Invalid value type at `.deliveryUids[0]`: expected a positive integer, but found a string: `\"0\"`",
+      "code": "invalid_webhook_delivery_uids",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_webhook_delivery_uids"
+    }
+    "#);
+
+    let (value, code) =
+        server.redeliver_webhook_deliveries(&uuid, json!({ "deliveryUids": [0] })).await;
+    snapshot!(code, @"202 Accepted");
+    snapshot!(value["results"][0]["status"], @r#""pending""#);
+
+    let payload = receiver.recv().await.unwrap();
+    let payload = String::from_utf8(payload).unwrap();
+    let task: serde_json::Value = serde_json::from_str(payload.trim_end()).unwrap();
+    snapshot!(task["indexUid"], @r#""tamo""#);
+
+    let mut deliveries = json!(null);
+    for _ in 0..100 {
+        let (value, _) = server.get_webhook_deliveries(&uuid).await;
+        if value["results"][0]["status"] == "succeeded" {
+            deliveries = value;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    snapshot!(json_string!(deliveries, {
+        ".results[].webhookUuid" => "[uuid]",
+        ".results[].attempts[].attemptedAt" => "[date]",
+    }), @r#"
+    {
+      "results": [
+        {
+          "uid": 0,
+          "webhookUuid": "[uuid]",
+          "taskUids": [
+            0
+          ],
+          "status": "succeeded",
+          "attempts": [
+            {
+              "attemptedAt": "[date]",
+              "statusCode": 500,
+              "error": "The webhook answered with the status code 500."
+            },
+            {
+              "attemptedAt": "[date]",
+              "statusCode": 200,
+              "error": null
+            }
+          ],
+          "nextAttemptAt": null
+        }
+      ]
+    }
+    "#);
+
+    let (value, code) = server.get_webhook_deliveries(Uuid::new_v4().to_string()).await;
+    snapshot!(code, @"404 Not Found");
+    snapshot!(value["code"], @r#""webhook_not_found""#);
+
+    server_handle.abort();
+}
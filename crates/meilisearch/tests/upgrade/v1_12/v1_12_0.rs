@@ -59,6 +59,11 @@ async fn check_the_keys(server: &Server) {
         "kefir"
       ],
       "expiresAt": null,
+      "usage": {
+        "requests": 0,
+        "rateLimitedRequests": 0,
+        "concurrentSearches": 0
+      },
       "createdAt": "2025-01-16T14:43:20.863318893Z",
       "updatedAt": "[date]"
     }
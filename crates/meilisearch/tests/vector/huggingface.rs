@@ -41,3 +41,90 @@ async fn hf_bge_m3_force_cls_settings() {
         .unwrap_or(false);
     snapshot!(has_vectors, @"true");
 }
+
+#[actix_rt::test]
+async fn hf_local_model_path_checks() {
+    let models = tempfile::tempdir().unwrap();
+    let models_dir = models.path().canonicalize().unwrap();
+    unsafe { std::env::set_var("MEILI_EXPERIMENTAL_HUGGING_FACE_MODELS_DIR", &models_dir) };
+
+    let server = Server::new_shared();
+    let index = server.unique_index();
+
+    let (response, code) = index
+        .update_settings(json!({
+          "embedders": { "default": { "source": "huggingFace", "modelPath": "models/bge-small" } }
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""vector_embedding_error""###);
+    assert!(
+        response["message"].as_str().unwrap().contains("must be an absolute path"),
+        "{response}"
+    );
+
+    // existing or not, the directories outside of the models directory are all reported the same way
+    let outside = tempfile::tempdir().unwrap();
+    for model_path in [
+        outside.path().to_path_buf(),
+        outside.path().join("does-not-exist"),
+        models_dir.join("does-not-exist"),
+        models_dir.join(".."),
+    ] {
+        let (response, code) = index
+            .update_settings(json!({
+              "embedders": { "default": { "source": "huggingFace", "modelPath": model_path } }
+            }))
+            .await;
+        snapshot!(code, @"400 Bad Request");
+        snapshot!(response["code"], @r###""vector_embedding_error""###);
+        let expected = format!(
+            "could not find a model directory at `{}` inside of the models directory.",
+            model_path.display()
+        );
+        assert!(response["message"].as_str().unwrap().contains(&expected), "{response}");
+    }
+
+    let dir = tempfile::tempdir_in(&models_dir).unwrap();
+    let (response, code) = index
+        .update_settings(json!({
+          "embedders": { "default": { "source": "huggingFace", "modelPath": dir.path() } }
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""vector_embedding_error""###);
+    assert!(
+        response["message"].as_str().unwrap().contains("does not contain `config.json`"),
+        "{response}"
+    );
+
+    let (response, code) = index
+        .update_settings(json!({
+          "embedders": { "default": { "source": "huggingFace", "modelPath": dir.path(), "revision": "main" } }
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response["code"], @r###""vector_embedding_error""###);
+    assert!(
+        response["message"]
+            .as_str()
+            .unwrap()
+            .contains("`revision` cannot be used with `modelPath`"),
+        "{response}"
+    );
+
+    let (response, code) = index
+        .update_settings(json!({
+          "embedders": { "default": { "source": "openAi", "modelPath": "/models/bge-small" } }
+        }))
+        .await;
+    snapshot!(code, @"400 Bad Request");
+    snapshot!(response, @r###"
+    {
+      "message": "`.embedders.default`: Field `modelPath` unavailable for source `openAi`.\n  - note: `modelPath` is available for sources: `huggingFace`\n  - note: available fields for source `openAi`: `source`, `model`, `apiKey`, `dimensions`, `documentTemplate`, `documentTemplateMaxBytes`, `url`, `distribution`, `binaryQuantized`",
+      "code": "invalid_settings_embedders",
+      "type": "invalid_request",
+      "link": "https://docs.meilisearch.com/errors#invalid_settings_embedders"
+    }
+    "###);
+}
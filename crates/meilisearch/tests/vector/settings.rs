@@ -563,7 +563,7 @@ async fn composite_checks() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(response, @r###"
     {
-      "message": "`.embedders.test.searchEmbedder`: Field `distribution` unavailable for source `huggingFace` for the search embedder.\n  - note: available fields for source `huggingFace` for the search embedder: `source`, `model`, `revision`, `pooling`\n  - note: `distribution` is available when source `huggingFace` is not for the search embedder",
+      "message": "`.embedders.test.searchEmbedder`: Field `distribution` unavailable for source `huggingFace` for the search embedder.\n  - note: available fields for source `huggingFace` for the search embedder: `source`, `model`, `revision`, `pooling`, `modelPath`\n  - note: `distribution` is available when source `huggingFace` is not for the search embedder",
       "code": "invalid_settings_embedders",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_settings_embedders"
@@ -820,7 +820,7 @@ async fn composite_checks() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(response, @r###"
     {
-      "message": "`.embedders.test.searchEmbedder`: Field `binaryQuantized` unavailable for source `huggingFace` for the search embedder.\n  - note: available fields for source `huggingFace` for the search embedder: `source`, `model`, `revision`, `pooling`\n  - note: `binaryQuantized` is available when source `huggingFace` is not for the search embedder",
+      "message": "`.embedders.test.searchEmbedder`: Field `binaryQuantized` unavailable for source `huggingFace` for the search embedder.\n  - note: available fields for source `huggingFace` for the search embedder: `source`, `model`, `revision`, `pooling`, `modelPath`\n  - note: `binaryQuantized` is available when source `huggingFace` is not for the search embedder",
       "code": "invalid_settings_embedders",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_settings_embedders"
@@ -859,7 +859,7 @@ async fn composite_checks() {
     snapshot!(code, @"400 Bad Request");
     snapshot!(response, @r###"
     {
-      "message": "`.embedders.test.searchEmbedder`: Field `documentTemplate` unavailable for source `huggingFace` for the search embedder.\n  - note: available fields for source `huggingFace` for the search embedder: `source`, `model`, `revision`, `pooling`\n  - note: `documentTemplate` is available when source `huggingFace` is not for the search embedder",
+      "message": "`.embedders.test.searchEmbedder`: Field `documentTemplate` unavailable for source `huggingFace` for the search embedder.\n  - note: available fields for source `huggingFace` for the search embedder: `source`, `model`, `revision`, `pooling`, `modelPath`\n  - note: `documentTemplate` is available when source `huggingFace` is not for the search embedder",
       "code": "invalid_settings_embedders",
       "type": "invalid_request",
       "link": "https://docs.meilisearch.com/errors#invalid_settings_embedders"
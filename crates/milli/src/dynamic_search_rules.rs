@@ -1,6 +1,7 @@
 use std::num::Saturating;
 use std::ops::{Bound, ControlFlow};
 
+use charabia::Language;
 use filter_parser::{
     ConstraintCondition, ConstraintConditionKind, ConstraintTarget, FilterConstraintFuel,
     FilterConstraints,
@@ -20,8 +21,8 @@ use crate::search::new::LocatedQueryTerm;
 use crate::search::{Pin, Precedence};
 use crate::update::new::document::DocumentFromDb;
 use crate::{
-    AscDesc, DocumentId, FieldId, FieldsIdsMap, Index, IndexFilter, PinDoc, Result, SearchContext,
-    SearchResult, UserError, MAX_COUNTED_WORDS,
+    AscDesc, DocumentId, FieldId, FieldsIdsMap, Filter, Index, IndexFilter, PinDoc, Result,
+    SearchContext, SearchResult, UserError, MAX_COUNTED_WORDS, MAX_FACET_VALUE_LENGTH,
 };
 
 pub type RuleId = u32;
@@ -70,28 +71,76 @@ impl<'a> DynamicSearchRulesView<'a> {
         Ok(Some(doc))
     }
 
-    pub fn resolve_pins(
+    /// Resolve the actions of the rules that are active for this search.
+    ///
+    /// The `universe` is modified in place to remove the pinned and hidden documents, and to apply the injected filters.
+    pub fn resolve_effects(
         &self,
-        query_terms: &[LocatedQueryTerm],
-        filter: Option<&IndexFilter>,
+        rule_query: RuleQuery<'_>,
         universe: &mut RoaringBitmap,
         search_context: &SearchContext,
         fuel: DsrFuel,
-    ) -> Result<Vec<PinDoc>> {
-        let active_rules =
-            self.active_rules_for_query(query_terms, filter, search_context, fuel)?;
+    ) -> Result<RuleEffects> {
+        let active_rules = self.active_rules_for_query(rule_query, search_context, fuel)?;
+
+        let mut applied_rules: Vec<String> = Vec::new();
+        let mut pins = Vec::new();
+        let mut hidden = RoaringBitmap::new();
+        let mut injected: Option<RoaringBitmap> = None;
+        let mut boosted = Vec::new();
+        let mut buried = Vec::new();
+
+        for action in self.find_actions(self.rule_ids_sorted_by_precedence(active_rules)?, fuel) {
+            let ActiveAction { rule, precedence, action } = action?;
+            if applied_rules.last() != Some(&rule) {
+                applied_rules.push(rule.clone());
+            }
+            match action.action {
+                DynamicSearchRuleAction::Pin { position } => {
+                    if let Some(id) = action.active_document(search_context)? {
+                        pins.push(PinDoc { position, precedence, id });
+                    }
+                }
+                DynamicSearchRuleAction::Hide => {
+                    hidden |= action.active_documents(search_context)?;
+                }
+                DynamicSearchRuleAction::InjectFilter => {
+                    // an injected filter that doesn't apply to the searched index must not empty its results
+                    let Some(selected) = action.selected_documents(search_context)? else {
+                        continue;
+                    };
+                    match &mut injected {
+                        Some(injected) => *injected &= selected,
+                        None => injected = Some(selected),
+                    }
+                }
+                DynamicSearchRuleAction::Boost => {
+                    let docids = action.active_documents(search_context)?;
+                    boosted.push(TierDocs { rule, precedence, docids });
+                }
+                DynamicSearchRuleAction::Bury => {
+                    let docids = action.active_documents(search_context)?;
+                    buried.push(TierDocs { rule, precedence, docids });
+                }
+            }
+        }
+
+        *universe -= hidden;
+        if let Some(injected) = injected {
+            *universe &= injected;
+        }
 
-        let pins: Result<Vec<PinDoc>> = self
-            .find_pins(self.rule_ids_sorted_by_precedence(active_rules)?, search_context, fuel)
-            .filter(|pin| if let Ok(pin) = pin.as_ref() { universe.remove(pin.id) } else { true })
+        let mut pins: Vec<PinDoc> = pins
+            .into_iter()
+            .filter(|pin| universe.remove(pin.id))
             .take(fuel.max_pin_actions())
             .collect();
 
-        let mut pins = pins?;
-
         Pin::dedup_and_sort(&mut pins);
 
-        Ok(pins)
+        let tiers = RuleTiers::new(boosted, buried, universe);
+
+        Ok(RuleEffects { pins, tiers, applied_rules })
     }
 
     pub fn rules_from_rule_ids<I>(
@@ -169,12 +218,11 @@ impl<'a> DynamicSearchRulesView<'a> {
         search.execute()
     }
 
-    fn find_pins(
+    fn find_actions(
         self,
         sorted_active_rules: impl IntoIterator<Item = Result<RuleId>> + 'a,
-        search_context: &'a SearchContext,
         fuel: DsrFuel,
-    ) -> impl Iterator<Item = Result<PinDoc>> + 'a {
+    ) -> impl Iterator<Item = Result<ActiveAction>> + 'a {
         sorted_active_rules
             .into_iter()
             .take(fuel.max_active_rules())
@@ -193,13 +241,28 @@ impl<'a> DynamicSearchRulesView<'a> {
                     return Ok(None);
                 };
 
+                let uid: Result<String, _> = match rule.field(fields::UID)? {
+                    Some(uid) => serde_json::from_str(uid.get()),
+                    None => Ok(String::new()),
+                };
+
+                let uid = match uid {
+                    Ok(uid) => uid,
+                    Err(err) => {
+                        tracing::warn!(
+                            "could not deserialize uid of rule with internal id `{rule_id}`: {err}"
+                        );
+                        return Ok(None);
+                    }
+                };
+
                 let precedence: Result<Option<u64>, _> = match rule.field(fields::PRECEDENCE)? {
                     Some(precedence) => serde_json::from_str(precedence.get()),
                     None => Ok(None),
                 };
 
                 let precedence = match precedence {
-                    Ok(precedence) => precedence,
+                    Ok(precedence) => Precedence(precedence),
                     Err(err) => {
                         tracing::warn!(
                         "could not deserialize actions of rule with internal id `{rule_id}`: {err}"
@@ -211,7 +274,11 @@ impl<'a> DynamicSearchRulesView<'a> {
                 let actions: Result<Vec<RuleAction>, serde_json::Error> =
                     serde_json::from_str(actions.get());
                 match actions {
-                    Ok(actions) => Ok(Some(actions.into_iter().zip(std::iter::repeat(precedence)))),
+                    Ok(actions) => Ok(Some(actions.into_iter().map(move |action| ActiveAction {
+                        rule: uid.clone(),
+                        precedence,
+                        action,
+                    }))),
                     Err(err) => {
                         tracing::warn!(
                         "could not deserialize actions of rule with internal id `{rule_id}`: {err}"
@@ -222,35 +289,26 @@ impl<'a> DynamicSearchRulesView<'a> {
             })
             .filter_map(|x| x.transpose())
             .flatten_ok()
-            .filter_map_ok(|(action, precedence)| {
-                let precedence = Precedence(precedence);
-                let doc_id = action.active_document(search_context).transpose()?;
-
-                let doc_id = match doc_id {
-                    Ok(doc_id) => doc_id,
-                    Err(err) => return Some(Err(err)),
-                };
-                match action.action {
-                    DynamicSearchRuleAction::Pin { position } => {
-                        Some(Ok(PinDoc { position, precedence, id: doc_id }))
-                    }
-                }
-            })
-            .map(|x| x.flatten())
     }
 
     fn active_rules_for_query(
         &self,
-        query_terms: &[LocatedQueryTerm],
-        filter: Option<&IndexFilter>,
+        rule_query: RuleQuery<'_>,
         search_context: &SearchContext,
         fuel: DsrFuel,
     ) -> Result<RoaringBitmap> {
+        let RuleQuery { query, query_terms, locales, tenant_token_claims, filter } = rule_query;
+
         let mut active_rules = self.active_rule_ids(true)?;
         let target_time = search_context.before_search.format(&Rfc3339).unwrap();
         self.apply_time_conditions(&mut active_rules, target_time.as_str())?;
         self.apply_query_conditions(&mut active_rules, query_terms, search_context, fuel)?;
+        self.apply_query_prefix_conditions(&mut active_rules, query)?;
+        self.apply_locale_conditions(&mut active_rules, locales)?;
+        self.apply_tenant_token_conditions(&mut active_rules, tenant_token_claims)?;
         self.apply_filter_conditions(&mut active_rules, filter, fuel)?;
+        // evaluating regexes requires reading the rules, so we do it on as few rules as possible
+        self.apply_query_regex_conditions(&mut active_rules, query, fuel)?;
 
         Ok(active_rules)
     }
@@ -412,6 +470,186 @@ impl<'a> DynamicSearchRulesView<'a> {
         Ok(())
     }
 
+    fn apply_query_prefix_conditions(
+        &self,
+        active_rules: &mut RoaringBitmap,
+        query: Option<&str>,
+    ) -> Result<(), crate::Error> {
+        let Some(prefix_fid) = self.db_fields_ids_map.id(fields::CONDITIONS_QUERY_PREFIX) else {
+            return Ok(());
+        };
+
+        // rules with a prefix condition that the query doesn't start with
+        let mut unverified =
+            self.index.facet_id_exists_docids.get(self.rtxn, &prefix_fid)?.unwrap_or_default();
+        unverified &= &*active_rules;
+
+        // prefixes are stored normalized, so we look up all the prefixes of the normalized query
+        let query = crate::normalize_facet(query.unwrap_or_default());
+        for (index, c) in query.char_indices() {
+            let end = index + c.len_utf8();
+            if unverified.is_empty() || end > MAX_FACET_VALUE_LENGTH {
+                break;
+            }
+
+            let key = FacetGroupKey { field_id: prefix_fid, level: 0, left_bound: &query[..end] };
+            if let Some(FacetGroupValue { size: _, bitmap: verified }) =
+                self.index.facet_id_string_docids.get(self.rtxn, &key)?
+            {
+                unverified -= verified;
+            }
+        }
+
+        *active_rules -= unverified;
+        Ok(())
+    }
+
+    fn apply_query_regex_conditions(
+        &self,
+        active_rules: &mut RoaringBitmap,
+        query: Option<&str>,
+        fuel: DsrFuel,
+    ) -> Result<(), crate::Error> {
+        let Some(regex_fid) = self.db_fields_ids_map.id(fields::CONDITIONS_QUERY_REGEX) else {
+            return Ok(());
+        };
+
+        let mut regex_rules =
+            self.index.facet_id_exists_docids.get(self.rtxn, &regex_fid)?.unwrap_or_default();
+        regex_rules &= &*active_rules;
+
+        let query = query.unwrap_or_default();
+        let mut evaluated = 0;
+        for rule_id in regex_rules {
+            // no more fuel, the remaining rules are considered as not matching
+            if evaluated >= fuel.max_active_rules() {
+                active_rules.remove(rule_id);
+                continue;
+            }
+            evaluated += 1;
+
+            let Some(rule) =
+                DocumentFromDb::new(rule_id, self.rtxn, self.index, self.db_fields_ids_map)?
+            else {
+                active_rules.remove(rule_id);
+                continue;
+            };
+
+            let conditions: Option<StoredConditions> = match rule.field(fields::CONDITIONS)? {
+                Some(conditions) => serde_json::from_str(conditions.get()).ok(),
+                None => None,
+            };
+            let regex = conditions.and_then(|conditions| conditions.query?.regex);
+
+            let is_match = match regex.as_deref().map(compile_query_regex) {
+                Some(Ok(regex)) => regex.is_match(query),
+                Some(Err(err)) => {
+                    tracing::warn!(
+                        "could not compile the query regex of rule with internal id `{rule_id}`: {err}"
+                    );
+                    false
+                }
+                None => false,
+            };
+
+            if !is_match {
+                active_rules.remove(rule_id);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn apply_locale_conditions(
+        &self,
+        active_rules: &mut RoaringBitmap,
+        locales: Option<&[Language]>,
+    ) -> Result<(), crate::Error> {
+        let Some(locales_fid) = self.db_fields_ids_map.id(fields::CONDITIONS_LOCALES) else {
+            return Ok(());
+        };
+
+        // rules with a locales condition that none of the search locales verify
+        let mut unverified =
+            self.index.facet_id_exists_docids.get(self.rtxn, &locales_fid)?.unwrap_or_default();
+        unverified &= &*active_rules;
+
+        for locale in locales.unwrap_or_default() {
+            if unverified.is_empty() {
+                break;
+            }
+            // locales are stored in their ISO-639-3 form
+            let key = FacetGroupKey { field_id: locales_fid, level: 0, left_bound: locale.code() };
+            if let Some(FacetGroupValue { size: _, bitmap: verified }) =
+                self.index.facet_id_string_docids.get(self.rtxn, &key)?
+            {
+                unverified -= verified;
+            }
+        }
+
+        *active_rules -= unverified;
+        Ok(())
+    }
+
+    fn apply_tenant_token_conditions(
+        &self,
+        active_rules: &mut RoaringBitmap,
+        claims: Option<&serde_json::Map<String, serde_json::Value>>,
+    ) -> Result<(), crate::Error> {
+        let claims_prefix = format!("{}.", fields::CONDITIONS_TENANT_TOKEN_CLAIMS);
+
+        for (claim_fid, field_name) in self.db_fields_ids_map.iter() {
+            if active_rules.is_empty() {
+                break;
+            }
+            let Some(claim) = field_name.strip_prefix(&claims_prefix) else {
+                continue;
+            };
+
+            // rules with a condition on this claim that the tenant token doesn't verify
+            let mut unverified =
+                self.index.facet_id_exists_docids.get(self.rtxn, &claim_fid)?.unwrap_or_default();
+            unverified &= &*active_rules;
+            if unverified.is_empty() {
+                continue;
+            }
+
+            let values = match claims.and_then(|claims| claims.get(claim)) {
+                Some(serde_json::Value::Array(values)) => values.as_slice(),
+                Some(value) => std::slice::from_ref(value),
+                None => &[],
+            };
+
+            for value in values {
+                let (normalized, number) = match value {
+                    serde_json::Value::String(s) => {
+                        (crate::normalize_facet(s), s.parse().ok().filter(|n: &f64| n.is_finite()))
+                    }
+                    serde_json::Value::Number(n) => {
+                        (crate::normalize_facet(&n.to_string()), n.as_f64())
+                    }
+                    serde_json::Value::Bool(b) => (b.to_string(), None),
+                    serde_json::Value::Null
+                    | serde_json::Value::Array(_)
+                    | serde_json::Value::Object(_) => continue,
+                };
+
+                unverified -= evaluate_equal(
+                    self.rtxn,
+                    claim_fid,
+                    self.index.facet_id_f64_docids,
+                    self.index.facet_id_string_docids,
+                    normalized,
+                    number,
+                )?;
+            }
+
+            *active_rules -= unverified;
+        }
+
+        Ok(())
+    }
+
     fn apply_filter_conditions(
         &self,
         active_rules: &mut RoaringBitmap,
@@ -632,9 +870,9 @@ impl<'a> DynamicSearchRulesView<'a> {
                             )?
                         }
                         ValueBounds::Contains { normalized: _ }
-                        | ValueBounds::StartsWith { normalized: _ } => {
-                            return Ok(Default::default())
-                        }
+                        | ValueBounds::StartsWith { normalized: _ }
+                        | ValueBounds::EndsWith { normalized: _ }
+                        | ValueBounds::Matches { pattern: _ } => return Ok(Default::default()),
                     }
                 }
                 // always unsupported, considered unsatisfiable
@@ -689,15 +927,14 @@ impl DynamicSearchRules {
         self.as_view().get_from_internal_id(rule_id)
     }
 
-    pub fn resolve_pins(
+    pub fn resolve_effects(
         &self,
-        query_terms: &[LocatedQueryTerm],
-        filter: Option<&IndexFilter>,
+        rule_query: RuleQuery<'_>,
         universe: &mut RoaringBitmap,
         search_context: &SearchContext,
         fuel: DsrFuel,
-    ) -> Result<Vec<PinDoc>> {
-        self.as_view().resolve_pins(query_terms, filter, universe, search_context, fuel)
+    ) -> Result<RuleEffects> {
+        self.as_view().resolve_effects(rule_query, universe, search_context, fuel)
     }
 
     pub fn rules_from_rule_ids<'t, I>(
@@ -733,6 +970,91 @@ impl DynamicSearchRules {
     }
 }
 
+/// What the conditions of the dynamic search rules are matched against.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuleQuery<'a> {
+    /// The raw search query.
+    pub query: Option<&'a str>,
+    /// The terms of the search query.
+    pub query_terms: &'a [LocatedQueryTerm],
+    /// The locales requested by the search.
+    pub locales: Option<&'a [Language]>,
+    /// The claims of the tenant token used to perform the search.
+    pub tenant_token_claims: Option<&'a serde_json::Map<String, serde_json::Value>>,
+    /// The filter of the search.
+    pub filter: Option<&'a IndexFilter>,
+}
+
+/// The effects of the active dynamic search rules on a search.
+#[derive(Debug, Default)]
+pub struct RuleEffects {
+    /// Documents placed at a fixed position of the results.
+    pub pins: Vec<PinDoc>,
+    /// Documents moved before or after the other results.
+    pub tiers: RuleTiers,
+    /// Uids of the rules whose actions were applied, by ascending precedence.
+    pub applied_rules: Vec<String>,
+}
+
+/// Documents moved by the boost and bury actions of the active rules.
+///
+/// A document belongs to at most one tier: the one of the rule with the lowest precedence number.
+#[derive(Debug, Default)]
+pub struct RuleTiers {
+    /// Boosted documents, by ascending precedence of their rule.
+    pub boosted: Vec<TierDocs>,
+    /// Buried documents, by ascending precedence of their rule.
+    pub buried: Vec<TierDocs>,
+}
+
+impl RuleTiers {
+    fn new(boosted: Vec<TierDocs>, buried: Vec<TierDocs>, universe: &RoaringBitmap) -> Self {
+        let mut claimed = RoaringBitmap::new();
+        let mut tiers = Self::default();
+
+        // rules are visited by ascending precedence, so the first rule to claim a document wins
+        let boosted = boosted.into_iter().map(|docs| (true, docs));
+        let buried = buried.into_iter().map(|docs| (false, docs));
+        for (is_boost, mut docs) in
+            boosted.merge_by(buried, |(_, left), (_, right)| left.precedence <= right.precedence)
+        {
+            docs.docids &= universe;
+            docs.docids -= &claimed;
+            if docs.docids.is_empty() {
+                continue;
+            }
+            claimed |= &docs.docids;
+            if is_boost {
+                tiers.boosted.push(docs);
+            } else {
+                tiers.buried.push(docs);
+            }
+        }
+
+        tiers
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boosted.is_empty() && self.buried.is_empty()
+    }
+}
+
+/// Documents boosted or buried by a single rule.
+#[derive(Debug)]
+pub struct TierDocs {
+    /// The uid of the rule.
+    pub rule: String,
+    pub precedence: Precedence,
+    pub docids: RoaringBitmap,
+}
+
+/// An action of an active rule, with the information needed to report it.
+struct ActiveAction {
+    rule: String,
+    precedence: Precedence,
+    action: RuleAction,
+}
+
 #[derive(Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct RuleAction {
@@ -745,17 +1067,73 @@ struct RuleAction {
 }
 
 impl RuleAction {
+    fn targets_index(&self, search_context: &SearchContext<'_>) -> bool {
+        self.selector.index_uid.as_ref().is_none_or(|selector_index_uid| {
+            selector_index_uid.as_str() == search_context.index_uid
+        })
+    }
+
     fn active_document(&self, search_context: &SearchContext<'_>) -> Result<Option<DocumentId>> {
-        if self.selector.index_uid.as_ref().is_some_and(|selector_index_uid| {
-            selector_index_uid.as_str() != search_context.index_uid
-        }) {
+        if !self.targets_index(search_context) {
             return Ok(None);
         }
 
-        Ok(search_context
-            .index
-            .external_documents_ids()
-            .get(search_context.txn, &self.selector.id)?)
+        let Some(id) = &self.selector.id else {
+            return Ok(None);
+        };
+
+        Ok(search_context.index.external_documents_ids().get(search_context.txn, id)?)
+    }
+
+    /// Returns the documents selected either by id or by filter.
+    ///
+    /// A filter that cannot be evaluated on the searched index selects no documents.
+    fn active_documents(&self, search_context: &SearchContext<'_>) -> Result<RoaringBitmap> {
+        Ok(self.selected_documents(search_context)?.unwrap_or_default())
+    }
+
+    /// Returns the documents selected either by id or by filter, or `None` when the action
+    /// doesn't apply to the searched index: it targets another index, it has no selector,
+    /// or its filter cannot be evaluated on this index.
+    fn selected_documents(
+        &self,
+        search_context: &SearchContext<'_>,
+    ) -> Result<Option<RoaringBitmap>> {
+        if !self.targets_index(search_context) {
+            return Ok(None);
+        }
+
+        if let Some(id) = self.active_document(search_context)? {
+            return Ok(Some(RoaringBitmap::from_iter([id])));
+        }
+
+        let Some(filter) = &self.selector.filter else {
+            // a missing document selects nothing, but an action without selector doesn't apply
+            return Ok(self.selector.id.is_some().then(RoaringBitmap::new));
+        };
+
+        let selected = Filter::from_json(filter)
+            .and_then(|filter| filter.map(Filter::into_local_index_filter).transpose())
+            .and_then(|filter| {
+                filter
+                    .map(|filter| {
+                        filter.evaluate(
+                            search_context.txn,
+                            search_context.index,
+                            search_context.fields_ids_map,
+                        )
+                    })
+                    .transpose()
+            });
+
+        match selected {
+            Ok(selected) => Ok(selected),
+            Err(crate::Error::UserError(err)) => {
+                tracing::warn!("could not evaluate the filter selector of a rule: {err}");
+                Ok(None)
+            }
+            Err(err) => Err(err),
+        }
     }
 }
 
@@ -764,13 +1142,44 @@ impl RuleAction {
 struct Selector {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub index_uid: Option<String>,
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
 }
 
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(tag = "type", rename_all = "camelCase", deny_unknown_fields)]
 enum DynamicSearchRuleAction {
     Pin { position: u32 },
+    Hide,
+    Bury,
+    Boost,
+    InjectFilter,
+}
+
+/// The part of the stored conditions that can only be evaluated by reading the rule.
+#[derive(Deserialize)]
+struct StoredConditions {
+    #[serde(default)]
+    query: Option<StoredQueryCondition>,
+}
+
+#[derive(Deserialize)]
+struct StoredQueryCondition {
+    #[serde(default)]
+    regex: Option<String>,
+}
+
+/// Maximum size in bytes of a compiled query regex, so that evaluating a rule stays cheap.
+const QUERY_REGEX_SIZE_LIMIT: usize = 1 << 16;
+
+/// Compiles the regex of a query condition, with the limits applied when evaluating rules.
+pub fn compile_query_regex(regex: &str) -> Result<regex::Regex, regex::Error> {
+    regex::RegexBuilder::new(regex)
+        .size_limit(QUERY_REGEX_SIZE_LIMIT)
+        .dfa_size_limit(QUERY_REGEX_SIZE_LIMIT)
+        .build()
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -850,6 +1259,10 @@ pub mod fields {
     pub const CONDITIONS_TIME_END: &str = "conditions.time.end";
     pub const CONDITIONS_QUERY_IS_EMPTY: &str = "conditions.query.isEmpty";
     pub const CONDITIONS_QUERY_WORDS: &str = "conditions.query.words";
+    pub const CONDITIONS_QUERY_PREFIX: &str = "conditions.query.prefix";
+    pub const CONDITIONS_QUERY_REGEX: &str = "conditions.query.regex";
+    pub const CONDITIONS_LOCALES: &str = "conditions.locales";
+    pub const CONDITIONS_TENANT_TOKEN_CLAIMS: &str = "conditions.tenantToken.claims";
     pub const CONDITIONS_FILTER_NB_CONSTRAINTS: &str = "conditions.filter.nbConstraints";
     pub const CONDITIONS_FILTER_VALUES: &str = "conditions.filter.values";
 }
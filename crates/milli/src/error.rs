@@ -166,6 +166,8 @@ and can not be more than 511 bytes.", .document_id.to_string()
         valid_patterns: BTreeSet<String>,
         matching_rule_index: Option<usize>,
     },
+    #[error("Invalid facet aggregation on attribute `{field}`: {reason}")]
+    InvalidFacetAggregation { field: String, reason: String },
     #[error(transparent)]
     InvalidGeoField(#[from] Box<GeoError>),
     #[error(transparent)]
@@ -192,6 +194,7 @@ and can not be more than 511 bytes.", .document_id.to_string()
         allowed_operators.join(", "),
         if operator == "=" || operator == "!=" || operator == "IN" {"equality"}
         else if operator == "<" || operator == ">" || operator == "<=" || operator == ">=" || operator == "TO" {"comparison"}
+        else if operator == "ENDS WITH" || operator == "MATCHES" {"patternMatching"}
         else {"the appropriate filter operators"}
     )]
     FilterOperatorNotAllowed {
@@ -429,6 +432,8 @@ and can not be more than 511 bytes.", .document_id.to_string()
     InvalidChatSettingsDocumentTemplate(crate::prompt::error::NewPromptError),
     #[error("`.chat.documentTemplateMaxBytes`: `documentTemplateMaxBytes` cannot be zero")]
     InvalidChatSettingsDocumentTemplateMaxBytes,
+    #[error("`.reranker`: {0}")]
+    InvalidSettingsReranker(String),
     #[error("{0}")]
     DocumentEmbeddingError(String),
     #[error("Mixed usage of the attribute, attributeRank, and wordPosition ranking rules. \
@@ -179,6 +179,11 @@ impl FilterableAttributesFeatures {
         self.filter.is_filterable_comparison()
     }
 
+    /// Check if `ENDS WITH` and `MATCHES` are allowed
+    pub fn is_filterable_pattern_matching(&self) -> bool {
+        self.filter.is_filterable_pattern_matching()
+    }
+
     /// Check if the facet search is allowed
     pub fn is_facet_searchable(&self) -> bool {
         self.facet_search
@@ -223,6 +228,13 @@ pub struct FilterFeatures {
     #[serde(default)]
     #[deserr(default)]
     pub comparison: bool,
+    /// When `true`, enables the string pattern operators: `ENDS WITH` and
+    /// `MATCHES` (regular expression). These operators go through all the
+    /// values of the attribute and require the `containsFilter`
+    /// experimental feature. Defaults to `false`.
+    #[serde(default)]
+    #[deserr(default)]
+    pub pattern_matching: bool,
 }
 
 fn default_true() -> bool {
@@ -243,6 +255,9 @@ impl FilterFeatures {
         if self.is_filterable_comparison() {
             operators.extend_from_slice(&["<", ">", "<=", ">=", "TO"]);
         }
+        if self.is_filterable_pattern_matching() {
+            operators.extend_from_slice(&["ENDS WITH", "MATCHES"]);
+        }
         if self.is_filterable_empty() {
             operators.push("IS EMPTY");
         }
@@ -257,7 +272,7 @@ impl FilterFeatures {
     }
 
     pub fn is_filterable(&self) -> bool {
-        self.equality || self.comparison
+        self.equality || self.comparison || self.pattern_matching
     }
 
     pub fn is_filterable_equality(&self) -> bool {
@@ -269,6 +284,11 @@ impl FilterFeatures {
         self.comparison
     }
 
+    /// Check if `ENDS WITH` and `MATCHES` are allowed
+    pub fn is_filterable_pattern_matching(&self) -> bool {
+        self.pattern_matching
+    }
+
     /// Check if `IS EMPTY` is allowed
     pub fn is_filterable_empty(&self) -> bool {
         self.is_filterable()
@@ -287,20 +307,20 @@ impl FilterFeatures {
     /// Create a new `FilterFeatures` with the legacy default features.
     ///
     /// This is the default behavior for `FilterableAttributesRule::Field`.
-    /// This will set the equality and comparison to true.
+    /// This will set the equality, comparison and pattern matching to true.
     pub fn legacy_default() -> Self {
-        Self { equality: true, comparison: true }
+        Self { equality: true, comparison: true, pattern_matching: true }
     }
 
     /// Create a new `FilterFeatures` with no features.
     pub fn no_features() -> Self {
-        Self { equality: false, comparison: false }
+        Self { equality: false, comparison: false, pattern_matching: false }
     }
 }
 
 impl Default for FilterFeatures {
     fn default() -> Self {
-        Self { equality: true, comparison: false }
+        Self { equality: true, comparison: false, pattern_matching: false }
     }
 }
 
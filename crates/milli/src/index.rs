@@ -37,10 +37,10 @@ use crate::vector::db::IndexEmbeddingConfigs;
 use crate::vector::{Embedding, VectorStore, VectorStoreBackend, VectorStoreStats};
 use crate::{
     default_criteria, CboRoaringBitmapCodec, Criterion, Deadline, DocumentId, ExternalDocumentsIds,
-    FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry, FieldIdWordCountCodec,
-    FieldidsWeightsMap, FilterableAttributesRule, GeoPoint, LocalizedAttributesRule, ObkvCodec,
-    Result, RoaringBitmapCodec, RoaringBitmapLenCodec, Search, U8StrStrCodec, Weight, BEU16, BEU32,
-    BEU64,
+    FacetAggregations, FacetDistribution, FieldDistribution, FieldId, FieldIdMapMissingEntry,
+    FieldIdWordCountCodec, FieldidsWeightsMap, FilterableAttributesRule, GeoPoint,
+    LocalizedAttributesRule, ObkvCodec, Result, RoaringBitmapCodec, RoaringBitmapLenCodec, Search,
+    U8StrStrCodec, Weight, BEU16, BEU32, BEU64,
 };
 
 pub const DEFAULT_MIN_WORD_LEN_ONE_TYPO: u8 = 5;
@@ -90,6 +90,7 @@ pub mod main_key {
     pub const DOCUMENTS_STATS: &str = "documents_stats";
     pub const DISABLED_TYPOS_TERMS: &str = "disabled_typos_terms";
     pub const CHAT: &str = "chat";
+    pub const RERANKER: &str = "reranker";
     pub const VECTOR_STORE_BACKEND: &str = "vector_store_backend";
 }
 
@@ -1501,6 +1502,14 @@ impl Index {
         FacetDistribution::new(rtxn, self, fields_ids_map)
     }
 
+    pub fn facets_aggregations<'a>(
+        &'a self,
+        rtxn: &'a RoTxn<'a>,
+        fields_ids_map: &'a FieldsIdsMap,
+    ) -> FacetAggregations<'a> {
+        FacetAggregations::new(rtxn, self, fields_ids_map)
+    }
+
     pub fn search<'a>(
         &'a self,
         rtxn: &'a RoTxn<'a>,
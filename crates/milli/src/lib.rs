@@ -28,6 +28,7 @@ pub mod must_stop_processing;
 pub mod order_by_map;
 pub mod prompt;
 pub mod proximity;
+pub mod reranker;
 pub mod score_details;
 mod search;
 pub mod sharding;
@@ -93,14 +94,17 @@ pub use self::heed_codec::{
 };
 pub use self::index::{CreateOrOpen, Index};
 pub use self::localized_attributes_rules::LocalizedAttributesRule;
-pub use self::search::facet::{FacetValueHit, SearchForFacetValues, SHARD_FIELD};
+pub use self::search::autocomplete::{Autocomplete, Completion, DEFAULT_AUTOCOMPLETE_LIMIT};
+pub use self::search::facet::{
+    FacetValueHit, SearchForFacetValues, MAX_BUCKETS_PER_FACET_AGGREGATION, SHARD_FIELD,
+};
 pub use self::search::similar::Similar;
 pub use self::search::steps::{FederatingResultsStep, SearchStep, TotalProcessingTimeStep};
 pub use self::search::{
-    merge_positioned_hits_into_page, serialize_index_filter_to_filter_string, FacetDistribution,
-    Filter, FormatOptions, IndexFilter, MatchBounds, MatcherBuilder, MatchingWords, OrderBy, Pin,
-    PinDoc, Precedence, Search, SearchResult, SemanticSearch, TermsMatchingStrategy,
-    DEFAULT_VALUES_PER_FACET,
+    merge_positioned_hits_into_page, serialize_index_filter_to_filter_string, FacetAggregation,
+    FacetAggregations, FacetBucket, FacetDistribution, Filter, FormatOptions, IndexFilter,
+    MatchBounds, MatcherBuilder, MatchingWords, OrderBy, Pin, PinDoc, Precedence, QuerySuggestion,
+    Search, SearchResult, SemanticSearch, TermsMatchingStrategy, DEFAULT_VALUES_PER_FACET,
 };
 pub use self::update::{
     ChannelCongestion, FragmentDiff, InnerIndexSettings, InnerIndexSettingsDiff, SettingsDelta,
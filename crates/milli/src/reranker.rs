@@ -0,0 +1,283 @@
+use std::collections::BTreeMap;
+
+use heed::types::{SerdeJson, Str};
+use heed::{RoTxn, RwTxn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::index::main_key;
+use crate::vector::json_template::InjectableValue;
+use crate::Index;
+
+pub const QUERY_PLACEHOLDER: &str = "{{query}}";
+pub const DOCUMENT_PLACEHOLDER: &str = "{{document}}";
+pub const INDEX_PLACEHOLDER: &str = "{{index}}";
+pub const SCORE_PLACEHOLDER: &str = "{{score}}";
+pub const REPEAT_PLACEHOLDER: &str = "{{..}}";
+
+/// The default duration after which a request to the reranker is abandoned.
+pub const DEFAULT_RERANKER_TIMEOUT_MS: u64 = 30_000;
+
+/// Configuration of the remote reranker used to personalize the search results of an index.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RerankerConfig {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    pub request: Value,
+    pub response: Value,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_fields: Option<Vec<String>>,
+}
+
+impl RerankerConfig {
+    pub fn timeout_ms(&self) -> u64 {
+        self.timeout_ms.unwrap_or(DEFAULT_RERANKER_TIMEOUT_MS)
+    }
+}
+
+impl Index {
+    /* reranker */
+    pub fn reranker_config(&self, rtxn: &RoTxn<'_>) -> heed::Result<Option<RerankerConfig>> {
+        self.main.remap_types::<Str, SerdeJson<_>>().get(rtxn, main_key::RERANKER)
+    }
+
+    pub(crate) fn put_reranker_config(
+        &self,
+        wtxn: &mut RwTxn<'_>,
+        config: &RerankerConfig,
+    ) -> heed::Result<()> {
+        self.main.remap_types::<Str, SerdeJson<_>>().put(wtxn, main_key::RERANKER, config)
+    }
+
+    pub(crate) fn delete_reranker_config(&self, wtxn: &mut RwTxn<'_>) -> heed::Result<bool> {
+        self.main.remap_key_type::<Str>().delete(wtxn, main_key::RERANKER)
+    }
+}
+
+/// How the ranking is read from the response of the reranker.
+#[derive(Debug)]
+enum RankingKind {
+    /// The response lists the positions of the documents in the request, best first.
+    Indices(InjectableValue),
+    /// The response lists the score of each document, in the order of the request.
+    Scores(InjectableValue),
+}
+
+/// The parsed request and response templates of a [`RerankerConfig`].
+#[derive(Debug)]
+pub struct RerankerTemplates {
+    request: InjectableValue,
+    ranking: RankingKind,
+}
+
+impl RerankerTemplates {
+    /// Parses the templates of the configuration.
+    ///
+    /// The request must contain the `"{{query}}"` placeholder and a repeated `"{{document}}"` placeholder.
+    /// The response must contain either a repeated `"{{index}}"` placeholder or a repeated `"{{score}}"` placeholder.
+    pub fn new(config: &RerankerConfig) -> Result<Self, String> {
+        if !contains_string(&config.request, QUERY_PLACEHOLDER) {
+            return Err(format!("in `request`: missing the {QUERY_PLACEHOLDER:?} placeholder"));
+        }
+
+        let request =
+            InjectableValue::new(config.request.clone(), DOCUMENT_PLACEHOLDER, REPEAT_PLACEHOLDER)
+                .map_err(|error| {
+                    error.error_message("request", DOCUMENT_PLACEHOLDER, REPEAT_PLACEHOLDER)
+                })?;
+        if !request.has_array_value() {
+            return Err(format!(
+                "in `request`: {DOCUMENT_PLACEHOLDER:?} must be repeated with {REPEAT_PLACEHOLDER:?} so that all the documents are sent in a single request"
+            ));
+        }
+
+        let ranking = if contains_string(&config.response, INDEX_PLACEHOLDER) {
+            RankingKind::Indices(Self::response(&config.response, INDEX_PLACEHOLDER)?)
+        } else if contains_string(&config.response, SCORE_PLACEHOLDER) {
+            RankingKind::Scores(Self::response(&config.response, SCORE_PLACEHOLDER)?)
+        } else {
+            return Err(format!(
+                "in `response`: missing either the {INDEX_PLACEHOLDER:?} or the {SCORE_PLACEHOLDER:?} placeholder"
+            ));
+        };
+
+        Ok(Self { request, ranking })
+    }
+
+    fn response(template: &Value, placeholder: &str) -> Result<InjectableValue, String> {
+        let response = InjectableValue::new(template.clone(), placeholder, REPEAT_PLACEHOLDER)
+            .map_err(|error| error.error_message("response", placeholder, REPEAT_PLACEHOLDER))?;
+        if !response.has_array_value() {
+            return Err(format!(
+                "in `response`: {placeholder:?} must be repeated with {REPEAT_PLACEHOLDER:?}"
+            ));
+        }
+        Ok(response)
+    }
+
+    /// Renders the body of the request sent to the reranker.
+    ///
+    /// Returns `None` if there is no document to rerank.
+    pub fn render_request(&self, query: &str, documents: Vec<String>) -> Option<Value> {
+        let mut request = self.request.inject(documents.into_iter().map(Value::String)).ok()?;
+        replace_string(&mut request, QUERY_PLACEHOLDER, query);
+        Some(request)
+    }
+
+    /// Extracts the ranking from the response of the reranker.
+    ///
+    /// Returns the positions of the `document_count` documents of the request, from the most to the least relevant one.
+    pub fn extract_ranking(
+        &self,
+        response: Value,
+        document_count: usize,
+    ) -> Result<Vec<usize>, String> {
+        match &self.ranking {
+            RankingKind::Indices(template) => {
+                let indices: Vec<usize> = template.extract(response).map_err(|error| {
+                    error.error_message("response", INDEX_PLACEHOLDER, "positive integer")
+                })?;
+                if let Some(index) = indices.iter().find(|&&index| index >= document_count) {
+                    return Err(format!(
+                        "in `response`: the reranker returned the position {index}, but only {document_count} documents were sent"
+                    ));
+                }
+                Ok(indices)
+            }
+            RankingKind::Scores(template) => {
+                let scores: Vec<f64> = template.extract(response).map_err(|error| {
+                    error.error_message("response", SCORE_PLACEHOLDER, "number")
+                })?;
+                if scores.len() != document_count {
+                    return Err(format!(
+                        "in `response`: the reranker returned {} scores, but {document_count} documents were sent",
+                        scores.len()
+                    ));
+                }
+                let mut indices: Vec<usize> = (0..scores.len()).collect();
+                // stable sort, so that documents with the same score keep their original order
+                indices.sort_by(|&left, &right| scores[right].total_cmp(&scores[left]));
+                Ok(indices)
+            }
+        }
+    }
+}
+
+fn contains_string(value: &Value, needle: &str) -> bool {
+    match value {
+        Value::String(s) => s == needle,
+        Value::Array(values) => values.iter().any(|value| contains_string(value, needle)),
+        Value::Object(map) => map.values().any(|value| contains_string(value, needle)),
+        Value::Null | Value::Bool(_) | Value::Number(_) => false,
+    }
+}
+
+fn replace_string(value: &mut Value, needle: &str, replacement: &str) {
+    match value {
+        Value::String(s) if s == needle => *s = replacement.to_string(),
+        Value::Array(values) => {
+            values.iter_mut().for_each(|value| replace_string(value, needle, replacement))
+        }
+        Value::Object(map) => {
+            map.values_mut().for_each(|value| replace_string(value, needle, replacement))
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn config(request: Value, response: Value) -> RerankerConfig {
+        RerankerConfig {
+            url: "http://localhost:8080/rerank".to_string(),
+            request,
+            response,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn render_request() {
+        let templates = RerankerTemplates::new(&config(
+            json!({ "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] }),
+            json!([{ "index": "{{index}}" }, "{{..}}"]),
+        ))
+        .unwrap();
+
+        let request =
+            templates.render_request("kefir", vec!["a dog".to_string(), "a cat".to_string()]);
+        assert_eq!(request, Some(json!({ "query": "kefir", "texts": ["a dog", "a cat"] })));
+        assert_eq!(templates.render_request("kefir", Vec::new()), None);
+    }
+
+    #[test]
+    fn extract_ranking() {
+        let by_index = RerankerTemplates::new(&config(
+            json!({ "query": "{{query}}", "documents": ["{{document}}", "{{..}}"] }),
+            json!({ "results": [{ "index": "{{index}}" }, "{{..}}"] }),
+        ))
+        .unwrap();
+        let ranking = by_index
+            .extract_ranking(
+                json!({ "results": [{ "index": 2, "score": 0.9 }, { "index": 0 }, { "index": 1 }] }),
+                3,
+            )
+            .unwrap();
+        assert_eq!(ranking, vec![2, 0, 1]);
+        let error =
+            by_index.extract_ranking(json!({ "results": [{ "index": 3 }] }), 3).unwrap_err();
+        assert_eq!(
+            error,
+            "in `response`: the reranker returned the position 3, but only 3 documents were sent"
+        );
+
+        let by_score = RerankerTemplates::new(&config(
+            json!({ "query": "{{query}}", "documents": ["{{document}}", "{{..}}"] }),
+            json!({ "scores": ["{{score}}", "{{..}}"] }),
+        ))
+        .unwrap();
+        let ranking =
+            by_score.extract_ranking(json!({ "scores": [0.1, 0.8, 0.1, 0.5] }), 4).unwrap();
+        assert_eq!(ranking, vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn invalid_templates() {
+        let error = RerankerTemplates::new(&config(
+            json!({ "documents": ["{{document}}", "{{..}}"] }),
+            json!([{ "index": "{{index}}" }, "{{..}}"]),
+        ))
+        .unwrap_err();
+        assert_eq!(error, r#"in `request`: missing the "{{query}}" placeholder"#);
+
+        let error = RerankerTemplates::new(&config(
+            json!({ "query": "{{query}}", "document": "{{document}}" }),
+            json!([{ "index": "{{index}}" }, "{{..}}"]),
+        ))
+        .unwrap_err();
+        assert_eq!(
+            error,
+            r#"in `request`: "{{document}}" must be repeated with "{{..}}" so that all the documents are sent in a single request"#
+        );
+
+        let error = RerankerTemplates::new(&config(
+            json!({ "query": "{{query}}", "documents": ["{{document}}", "{{..}}"] }),
+            json!({ "results": "{{ranking}}" }),
+        ))
+        .unwrap_err();
+        assert_eq!(
+            error,
+            r#"in `response`: missing either the "{{index}}" or the "{{score}}" placeholder"#
+        );
+    }
+}
@@ -31,6 +31,16 @@ pub enum ScoreDetails {
         position: u32,
         precedence: Option<u64>,
     },
+
+    /// Returned by the ranking rule that applies the boost and bury actions of dynamic search rules.
+    ///
+    /// Boosted documents rank before all the other documents, and buried documents after them.
+    /// Contrary to [`ScoreDetails::Pin`], this variant participates in score-based comparisons,
+    /// so that federated merge and hybrid search keep the boosted and buried documents in place.
+    Rule {
+        tier: RuleTier,
+        rank: Rank,
+    },
 }
 
 #[derive(Clone, Copy)]
@@ -123,6 +133,7 @@ impl ScoreDetails {
             ScoreDetails::Vector(_) => None,
             ScoreDetails::Skipped => Some(Rank { rank: 0, max_rank: 1 }),
             ScoreDetails::Pin { .. } => None,
+            ScoreDetails::Rule { tier: _, rank } => Some(*rank),
         }
     }
 
@@ -136,23 +147,33 @@ impl ScoreDetails {
         // Filter out only the ranking scores (Rank values) and exclude sort/geo sort.
         // Pin is a placement directive, not a score — it is filtered out upfront.
         let mut semantic_score = None;
-        let ranking_ranks =
-            details.filter_map(ScoreDetails::rank_or_value).filter_map(|rank_or_value| {
-                match rank_or_value {
-                    RankOrValue::Rank(rank) => Some(rank),
-                    RankOrValue::Score(score) => {
-                        semantic_score = Some(score);
-                        None
-                    }
-                    RankOrValue::Sort(_) => None,
-                    RankOrValue::GeoSort(_) => None,
+        let mut rule_rank = None;
+        let ranking_ranks = details
+            .inspect(|details| {
+                if let ScoreDetails::Rule { tier: _, rank } = details {
+                    rule_rank = Some(*rank);
                 }
+            })
+            .filter_map(ScoreDetails::rank_or_value)
+            .filter_map(|rank_or_value| match rank_or_value {
+                RankOrValue::Rank(rank) => Some(rank),
+                RankOrValue::Score(score) => {
+                    semantic_score = Some(score);
+                    None
+                }
+                RankOrValue::Sort(_) => None,
+                RankOrValue::GeoSort(_) => None,
             });
 
         let ranking_score = Rank::global_score(ranking_ranks);
 
-        // If we have semantic score, use it, otherwise use ranking score
-        semantic_score.unwrap_or(ranking_score)
+        // If we have semantic score, use it, otherwise use ranking score.
+        // The rank of the dynamic search rules always comes first, so it still applies to the semantic score.
+        match (semantic_score, rule_rank) {
+            (Some(semantic_score), Some(rule_rank)) => rule_rank.merge_score(semantic_score),
+            (Some(semantic_score), None) => semantic_score,
+            (None, _) => ranking_score,
+        }
     }
 
     pub fn score_values<'a>(
@@ -218,6 +239,7 @@ impl ScoreDetails {
             // Pin is filtered out before reaching rank_or_value() — see global_score(),
             // score_values(), and weighted_score_values().
             ScoreDetails::Pin { .. } => None,
+            ScoreDetails::Rule { tier: _, rank } => Some(RankOrValue::Rank(*rank)),
         }
     }
 
@@ -416,6 +438,22 @@ impl ScoreDetails {
                     details_map.insert("pin".into(), pin_details);
                     order += 1;
                 }
+                ScoreDetails::Rule { tier, rank } => {
+                    let (key, rule, precedence) = match tier {
+                        RuleTier::Boosted { rule, precedence } => ("boost", rule, precedence),
+                        RuleTier::Buried { rule, precedence } => ("bury", rule, precedence),
+                        // documents that no rule moved don't need to explain anything
+                        RuleTier::Organic => continue,
+                    };
+                    let rule_details = serde_json::json!({
+                        "order": order,
+                        "rule": rule,
+                        "precedence": precedence,
+                        "score": rank.local_score(),
+                    });
+                    details_map.insert(key.into(), rule_details);
+                    order += 1;
+                }
             }
         }
         details_map
@@ -537,6 +575,11 @@ impl Rank {
         rank.local_score()
     }
 
+    /// Merge a score in `[0, 1]` as if it was the local score of a ranking rule following this one.
+    pub fn merge_score(self, inner: f64) -> f64 {
+        (self.rank.saturating_sub(1) as f64 + inner) / self.max_rank as f64
+    }
+
     pub fn merge(mut outer: Rank, inner: Rank) -> Rank {
         outer.rank = outer.rank.saturating_sub(1);
 
@@ -549,6 +592,17 @@ impl Rank {
     }
 }
 
+/// The tier in which the boost and bury actions of dynamic search rules put a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleTier {
+    /// The document was boosted by the rule with the given uid.
+    Boosted { rule: String, precedence: Option<u64> },
+    /// No rule boosted or buried the document.
+    Organic,
+    /// The document was buried by the rule with the given uid.
+    Buried { rule: String, precedence: Option<u64> },
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ExactAttribute {
@@ -0,0 +1,112 @@
+use charabia::Language;
+use time::OffsetDateTime;
+
+use crate::progress::Progress;
+use crate::search::facet::IndexFilter;
+pub use crate::search::new::Completion;
+use crate::search::new::{compute_completions, extract_tokens, ExtractedTokens};
+use crate::{filtered_universe, Deadline, FieldsIdsMap, Index, Result, SearchContext, SearchStep};
+
+/// The default number of completions returned by the autocomplete route.
+pub const DEFAULT_AUTOCOMPLETE_LIMIT: usize = 10;
+
+/// Completes the last word of a query with the words of the index.
+///
+/// Contrary to a [`Search`](crate::Search), the documents are not ranked: the completions are
+/// directly read from the words databases and ordered by number of matching documents.
+pub struct Autocomplete<'a> {
+    query: String,
+    limit: usize,
+    // this should be linked to the String in the query
+    filter: Option<IndexFilter>,
+    searchable_attributes: Option<&'a [String]>,
+    locales: Option<Vec<Language>>,
+    deadline: Deadline,
+    rtxn: &'a heed::RoTxn<'a>,
+    fields_ids_map: &'a FieldsIdsMap,
+    index: &'a Index,
+    index_uid: &'a str,
+    progress: &'a Progress,
+}
+
+impl<'a> Autocomplete<'a> {
+    pub fn new(
+        query: String,
+        index: &'a Index,
+        rtxn: &'a heed::RoTxn<'a>,
+        fields_ids_map: &'a FieldsIdsMap,
+        index_uid: &'a str,
+        progress: &'a Progress,
+    ) -> Self {
+        Self {
+            query,
+            limit: DEFAULT_AUTOCOMPLETE_LIMIT,
+            filter: None,
+            searchable_attributes: None,
+            locales: None,
+            deadline: Deadline::never(),
+            rtxn,
+            fields_ids_map,
+            index,
+            index_uid,
+            progress,
+        }
+    }
+
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn filter(&mut self, filter: IndexFilter) -> &mut Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn searchable_attributes(&mut self, searchable: &'a [String]) -> &mut Self {
+        self.searchable_attributes = Some(searchable);
+        self
+    }
+
+    pub fn locales(&mut self, locales: Vec<Language>) -> &mut Self {
+        self.locales = Some(locales);
+        self
+    }
+
+    pub fn deadline(&mut self, deadline: Deadline) -> &mut Self {
+        self.deadline = deadline;
+        self
+    }
+
+    pub fn execute(&self) -> Result<Vec<Completion>> {
+        let mut ctx = SearchContext::new(
+            self.index,
+            self.rtxn,
+            self.fields_ids_map,
+            self.index_uid,
+            OffsetDateTime::now_utc(),
+        )?;
+
+        if let Some(searchable_attributes) = self.searchable_attributes {
+            ctx.attributes_to_search_on(searchable_attributes)?;
+        }
+
+        let universe = filtered_universe(
+            self.index,
+            self.rtxn,
+            self.fields_ids_map,
+            &self.filter,
+            None,
+            self.progress,
+        )?;
+
+        let step = self.progress.update_progress_scoped(SearchStep::TokenizeQuery);
+        // the last word must never be dropped by the words limit as it is the one we complete
+        let ExtractedTokens { query_terms, .. } =
+            extract_tokens(&mut ctx, &self.query, None, self.locales.as_ref(), false)?;
+        drop(step);
+
+        let _step = self.progress.update_progress_scoped(SearchStep::ComputeCompletions);
+        compute_completions(&mut ctx, &query_terms, &universe, self.limit, &self.deadline)
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::Bound;
+
+use roaring::RoaringBitmap;
+
+use crate::filterable_attributes_rules::{filtered_matching_patterns, matching_features};
+use crate::heed_codec::facet::OrderedF64Codec;
+use crate::search::facet::facet_range_search::find_docids_of_facet_within_bounds;
+use crate::search::facet::{facet_max_value, facet_min_value};
+use crate::{Error, FieldsIdsMap, FilterableAttributesRule, Index, Result, UserError};
+
+/// The maximum number of buckets that can be computed for a single facet.
+pub const MAX_BUCKETS_PER_FACET_AGGREGATION: usize = 1000;
+
+/// How should the numeric values of a facet be bucketed?
+#[derive(Debug, Clone, PartialEq)]
+pub enum FacetAggregation {
+    /// Explicit `[from, to)` ranges, `None` meaning the range is unbounded on that side.
+    Ranges(Vec<(Option<f64>, Option<f64>)>),
+    /// Contiguous buckets of the given width, spanning from the smallest to the largest value.
+    Interval(f64),
+}
+
+/// The number of candidates with a value in `[from, to)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FacetBucket {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+    pub count: u64,
+}
+
+pub struct FacetAggregations<'a> {
+    aggregations: BTreeMap<String, FacetAggregation>,
+    candidates: Option<RoaringBitmap>,
+    rtxn: &'a heed::RoTxn<'a>,
+    index: &'a Index,
+    fields_ids_map: &'a FieldsIdsMap,
+}
+
+impl<'a> FacetAggregations<'a> {
+    pub fn new(
+        rtxn: &'a heed::RoTxn<'a>,
+        index: &'a Index,
+        fields_ids_map: &'a FieldsIdsMap,
+    ) -> FacetAggregations<'a> {
+        FacetAggregations {
+            aggregations: BTreeMap::new(),
+            candidates: None,
+            rtxn,
+            index,
+            fields_ids_map,
+        }
+    }
+
+    pub fn aggregations<I: IntoIterator<Item = (A, FacetAggregation)>, A: AsRef<str>>(
+        &mut self,
+        aggregations: I,
+    ) -> &mut Self {
+        self.aggregations = aggregations
+            .into_iter()
+            .map(|(name, aggregation)| (name.as_ref().to_string(), aggregation))
+            .collect();
+        self
+    }
+
+    pub fn candidates(&mut self, candidates: RoaringBitmap) -> &mut Self {
+        self.candidates = Some(candidates);
+        self
+    }
+
+    pub fn execute(&self) -> Result<BTreeMap<String, Vec<FacetBucket>>> {
+        let filterable_attributes_rules = self.index.filterable_attributes_rules(self.rtxn)?;
+        self.check_aggregated_fields(&filterable_attributes_rules)?;
+
+        let candidates = match &self.candidates {
+            Some(candidates) => candidates.clone(),
+            None => self.index.documents_ids(self.rtxn)?,
+        };
+
+        let mut result = BTreeMap::new();
+        for (name, aggregation) in &self.aggregations {
+            let field_id = self.fields_ids_map.id(name);
+            let buckets = match aggregation {
+                FacetAggregation::Ranges(ranges) => ranges
+                    .iter()
+                    .map(|&(from, to)| self.bucket(field_id, from, to, &candidates))
+                    .collect::<Result<_>>()?,
+                FacetAggregation::Interval(interval) => {
+                    self.histogram(name, field_id, *interval, &candidates)?
+                }
+            };
+            result.insert(name.clone(), buckets);
+        }
+
+        Ok(result)
+    }
+
+    /// Builds the contiguous buckets of an `interval` aggregation.
+    ///
+    /// The first bucket starts at the largest multiple of `interval` lower than or equal
+    /// to the smallest value of the candidates, and the last one contains the largest value.
+    fn histogram(
+        &self,
+        name: &str,
+        field_id: Option<u16>,
+        interval: f64,
+        candidates: &RoaringBitmap,
+    ) -> Result<Vec<FacetBucket>> {
+        let Some(field_id) = field_id else { return Ok(Vec::new()) };
+        let min = facet_min_value(self.index, self.rtxn, field_id, candidates.clone())?;
+        let max = facet_max_value(self.index, self.rtxn, field_id, candidates.clone())?;
+        let (Some(min), Some(max)) = (min, max) else { return Ok(Vec::new()) };
+
+        // count the buckets with floats: a tiny interval would overflow the integer types
+        // and the bucket count must be checked before allocating anything.
+        let first = (min / interval).floor();
+        let bucket_count = (max / interval).floor() - first + 1.0;
+        if !bucket_count.is_finite() || bucket_count > MAX_BUCKETS_PER_FACET_AGGREGATION as f64 {
+            return Err(Error::UserError(UserError::InvalidFacetAggregation {
+                field: name.to_string(),
+                reason: format!(
+                    "an `interval` of `{interval}` produces more than {MAX_BUCKETS_PER_FACET_AGGREGATION} buckets between `{min}` and `{max}`."
+                ),
+            }));
+        }
+        let bucket_count = bucket_count as usize;
+
+        (0..bucket_count)
+            .map(|i| {
+                // compute the bounds from the bucket index rather than by accumulating the
+                // interval, so that the bounds stay exact multiples of the interval.
+                let from = (first + i as f64) * interval;
+                let to = (first + i as f64 + 1.0) * interval;
+                self.bucket(Some(field_id), Some(from), Some(to), candidates)
+            })
+            .collect()
+    }
+
+    fn bucket(
+        &self,
+        field_id: Option<u16>,
+        from: Option<f64>,
+        to: Option<f64>,
+        candidates: &RoaringBitmap,
+    ) -> Result<FacetBucket> {
+        let empty = FacetBucket { from, to, count: 0 };
+        let Some(field_id) = field_id else { return Ok(empty) };
+        if let (Some(from), Some(to)) = (from, to) {
+            if from >= to {
+                return Ok(empty);
+            }
+        }
+
+        let left = from.map_or(Bound::Unbounded, Bound::Included);
+        let right = to.map_or(Bound::Unbounded, Bound::Excluded);
+        let mut docids = RoaringBitmap::new();
+        find_docids_of_facet_within_bounds::<OrderedF64Codec>(
+            self.rtxn,
+            self.index.facet_id_f64_docids,
+            field_id,
+            &left,
+            &right,
+            Some(candidates),
+            &mut docids,
+        )?;
+
+        Ok(FacetBucket { from, to, count: docids.intersection_len(candidates) })
+    }
+
+    /// Check that the aggregated fields support comparison operators.
+    fn check_aggregated_fields(
+        &self,
+        filterable_attributes_rules: &[FilterableAttributesRule],
+    ) -> Result<()> {
+        for field in self.aggregations.keys() {
+            let reason = match matching_features(field, filterable_attributes_rules) {
+                Some((_, features)) if features.is_filterable_comparison() => continue,
+                Some((rule_index, _)) => format!(
+                    "the attribute matched rule #{rule_index} in `filterableAttributes`, but this rule does not enable comparison.\n  - Hint: enable comparison in rule #{rule_index} by modifying the features.filter object"
+                ),
+                None => {
+                    let valid_patterns: BTreeSet<_> =
+                        filtered_matching_patterns(filterable_attributes_rules, &|features| {
+                            features.is_filterable_comparison()
+                        });
+                    if valid_patterns.is_empty() {
+                        "the attribute is not filterable. This index does not have configured filterable attributes with comparison enabled.".to_string()
+                    } else {
+                        format!(
+                            "the attribute is not filterable. Available filterable attributes patterns with comparison enabled are: `{}`.",
+                            valid_patterns.into_iter().collect::<Vec<_>>().join(", ")
+                        )
+                    }
+                }
+            };
+
+            return Err(Error::UserError(UserError::InvalidFacetAggregation {
+                field: field.to_string(),
+                reason,
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FacetAggregations<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let FacetAggregations { aggregations, candidates, rtxn: _, index: _, fields_ids_map: _ } =
+            self;
+
+        f.debug_struct("FacetAggregations")
+            .field("aggregations", aggregations)
+            .field("candidates", candidates)
+            .finish()
+    }
+}
@@ -137,6 +137,13 @@ impl IndexFilter {
                     rtxn, index, field_id, operator, features, rule_index,
                 ));
             }
+            Condition::EndsWith { .. } | Condition::Matches { .. }
+                if !features.is_filterable_pattern_matching() =>
+            {
+                return Err(generate_filter_error(
+                    rtxn, index, field_id, operator, features, rule_index,
+                ));
+            }
             _ => (),
         };
 
@@ -180,37 +187,26 @@ impl IndexFilter {
             }
             ValueBounds::Contains { normalized } => {
                 let finder = Finder::new(&normalized);
-                let base = FacetGroupKey { field_id, level: 0, left_bound: "" };
-                let docids = strings_db
-                    .prefix_iter(rtxn, &base)?
-                    .remap_data_type::<LazyDecode<FacetGroupValueCodec>>()
-                    .filter_map(|result| -> Option<Result<RoaringBitmap>> {
-                        match result {
-                            Ok((FacetGroupKey { left_bound, .. }, lazy_group_value)) => {
-                                if finder.find(left_bound.as_bytes()).is_some() {
-                                    Some(lazy_group_value.decode().map(|gv| gv.bitmap).map_err(
-                                        |_| {
-                                            InternalError::from(SerializationError::Decoding {
-                                                db_name: Some(FACET_ID_STRING_DOCIDS),
-                                            })
-                                            .into()
-                                        },
-                                    ))
-                                } else {
-                                    None
-                                }
-                            }
-                            Err(_e) => {
-                                Some(Err(InternalError::from(SerializationError::Decoding {
-                                    db_name: Some(FACET_ID_STRING_DOCIDS),
-                                })
-                                .into()))
-                            }
-                        }
-                    })
-                    .union()?;
-
-                docids
+                Self::facet_strings_docids(rtxn, strings_db, field_id, |value| {
+                    finder.find(value.as_bytes()).is_some()
+                })?
+            }
+            ValueBounds::EndsWith { normalized } => {
+                Self::facet_strings_docids(rtxn, strings_db, field_id, |value| {
+                    value.ends_with(normalized.as_str())
+                })?
+            }
+            ValueBounds::Matches { pattern } => {
+                let regex = match operator {
+                    Condition::Matches { pattern: token, .. } => {
+                        filter_parser::build_regex(&pattern)
+                            .map_err(|error| token.to_external_error(error))?
+                    }
+                    _ => unreachable!("only the `MATCHES` operator produces a regular expression"),
+                };
+                Self::facet_strings_docids(rtxn, strings_db, field_id, |value| {
+                    regex.is_match(value)
+                })?
             }
             ValueBounds::StartsWith { normalized } => {
                 // The idea here is that "STARTS WITH baba" is the same as "baba <= value < babb".
@@ -267,6 +263,45 @@ impl IndexFilter {
         })
     }
 
+    /// Returns the documents having a string facet value, for this field, that is accepted by the predicate.
+    ///
+    /// All the normalized string values of the field are visited, from the level 0 of the facet database.
+    fn facet_strings_docids(
+        rtxn: &heed::RoTxn<'_>,
+        strings_db: heed::Database<
+            FacetGroupKeyCodec<crate::heed_codec::StrRefCodec>,
+            FacetGroupValueCodec,
+        >,
+        field_id: FieldId,
+        predicate: impl Fn(&str) -> bool,
+    ) -> Result<RoaringBitmap> {
+        let base = FacetGroupKey { field_id, level: 0, left_bound: "" };
+        strings_db
+            .prefix_iter(rtxn, &base)?
+            .remap_data_type::<LazyDecode<FacetGroupValueCodec>>()
+            .filter_map(|result| -> Option<Result<RoaringBitmap>> {
+                match result {
+                    Ok((FacetGroupKey { left_bound, .. }, lazy_group_value)) => {
+                        if predicate(left_bound) {
+                            Some(lazy_group_value.decode().map(|gv| gv.bitmap).map_err(|_| {
+                                InternalError::from(SerializationError::Decoding {
+                                    db_name: Some(FACET_ID_STRING_DOCIDS),
+                                })
+                                .into()
+                            }))
+                        } else {
+                            None
+                        }
+                    }
+                    Err(_e) => Some(Err(InternalError::from(SerializationError::Decoding {
+                        db_name: Some(FACET_ID_STRING_DOCIDS),
+                    })
+                    .into())),
+                }
+            })
+            .union()
+    }
+
     fn evaluate_shard_operator(
         rtxn: &heed::RoTxn<'_>,
         index: &Index,
@@ -903,5 +938,11 @@ fn serialize_condition(f: &mut impl FmtWrite, condition: &Condition) -> std::fmt
         Condition::StartsWith { word, keyword: _ } => {
             write!(f, "STARTS WITH {}", word.escaped_fragment())
         }
+        Condition::EndsWith { word, keyword: _ } => {
+            write!(f, "ENDS WITH {}", word.escaped_fragment())
+        }
+        Condition::Matches { pattern, keyword: _ } => {
+            write!(f, "MATCHES {}", pattern.escaped_fragment())
+        }
     }
 }
@@ -1,4 +1,5 @@
 use either::Either;
+use filter_parser::IndexFilterCondition;
 pub use filter_parser::{FilterCondition, Token};
 use serde_json::Value;
 
@@ -6,7 +7,7 @@ use crate::error::{Error, UserError};
 use crate::search::facet::filter::FilterError;
 use crate::Result;
 
-use crate::{search::facet::filter::MAX_FILTER_DEPTH, Filter, SHARD_FIELD};
+use crate::{search::facet::filter::MAX_FILTER_DEPTH, Filter, IndexFilter, SHARD_FIELD};
 
 impl Filter {
     pub fn from_json(facets: &Value) -> Result<Option<Self>> {
@@ -130,4 +131,48 @@ impl Filter {
     pub fn use_foreign_filter(&self) -> Option<&Token> {
         self.condition.use_foreign_operator()
     }
+
+    /// Returns the attributes on which this filter applies a condition.
+    pub fn attributes(&self) -> impl Iterator<Item = &str> {
+        self.condition.fids(MAX_FILTER_DEPTH).map(|fid| fid.fragment())
+    }
+
+    /// Converts this filter to an [`IndexFilter`] that only applies to the filtered index.
+    ///
+    /// Returns an error if the filter uses the `_foreign` operator.
+    pub fn into_local_index_filter(self) -> Result<IndexFilter> {
+        local_condition(self.condition).map(|condition| IndexFilter { condition })
+    }
+}
+
+fn local_condition(filter: FilterCondition) -> Result<IndexFilterCondition> {
+    Ok(match filter {
+        FilterCondition::Not(filter) => {
+            IndexFilterCondition::Not(Box::new(local_condition(*filter)?))
+        }
+        FilterCondition::Condition { fid, op } => IndexFilterCondition::Condition { fid, op },
+        FilterCondition::In { fid, els } => IndexFilterCondition::In { fid, els },
+        FilterCondition::Or(filters) => IndexFilterCondition::Or(
+            filters.into_iter().map(local_condition).collect::<Result<_>>()?,
+        ),
+        FilterCondition::And(filters) => IndexFilterCondition::And(
+            filters.into_iter().map(local_condition).collect::<Result<_>>()?,
+        ),
+        FilterCondition::VectorExists { fid, embedder, filter } => {
+            IndexFilterCondition::VectorExists { fid, embedder, filter }
+        }
+        FilterCondition::GeoLowerThan { point, radius, resolution } => {
+            IndexFilterCondition::GeoLowerThan { point, radius, resolution }
+        }
+        FilterCondition::GeoBoundingBox { top_right_point, bottom_left_point } => {
+            IndexFilterCondition::GeoBoundingBox { top_right_point, bottom_left_point }
+        }
+        FilterCondition::GeoPolygon { points } => IndexFilterCondition::GeoPolygon { points },
+        FilterCondition::Foreign { fid, op: _ } => {
+            let error = Error::UserError(UserError::InvalidFilter(
+                "Filter condition `_foreign` is not supported here.".to_string(),
+            ));
+            return Err(fid.to_external_error(error).into());
+        }
+    })
 }
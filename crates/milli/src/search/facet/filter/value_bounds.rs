@@ -15,6 +15,8 @@ pub enum ValueBounds {
     NotEqual { normalized: String, number: Option<f64> },
     Contains { normalized: String },
     StartsWith { normalized: String },
+    EndsWith { normalized: String },
+    Matches { pattern: String },
 }
 
 impl ValueBounds {
@@ -82,6 +84,14 @@ impl ValueBounds {
                 let normalized = crate::normalize_facet(word.fragment());
                 ValueBounds::StartsWith { normalized }
             }
+            Condition::EndsWith { keyword: _, word } => {
+                let normalized = crate::normalize_facet(word.fragment());
+                ValueBounds::EndsWith { normalized }
+            }
+            // the pattern is matched against the normalized facet values, case insensitively
+            Condition::Matches { keyword: _, pattern } => {
+                ValueBounds::Matches { pattern: pattern.fragment().to_string() }
+            }
         }
     }
 }
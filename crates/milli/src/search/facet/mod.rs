@@ -4,6 +4,9 @@ use heed::types::{Bytes, DecodeIgnore};
 use heed::{BytesDecode, RoTxn};
 use roaring::RoaringBitmap;
 
+pub use self::facet_aggregation::{
+    FacetAggregation, FacetAggregations, FacetBucket, MAX_BUCKETS_PER_FACET_AGGREGATION,
+};
 pub use self::facet_distribution::{FacetDistribution, OrderBy, DEFAULT_VALUES_PER_FACET};
 pub use self::filter::{
     serialize_index_filter_to_filter_string, value_bounds, BadGeoError, Filter, IndexFilter,
@@ -14,6 +17,7 @@ use crate::heed_codec::facet::{FacetGroupKeyCodec, OrderedF64Codec};
 use crate::heed_codec::BytesRefCodec;
 use crate::{Index, Result};
 
+mod facet_aggregation;
 mod facet_distribution;
 mod facet_distribution_iter;
 pub(crate) mod facet_range_search;
@@ -11,7 +11,7 @@ use crate::search::SemanticSearch;
 use crate::vector::{Embedding, SearchQuery};
 use crate::{
     merge_positioned_hits_into_page, FieldsIdsMap, Index, MatchingWords, PinDoc, Precedence,
-    Result, Search, SearchResult,
+    QuerySuggestion, Result, Search, SearchResult,
 };
 
 struct ScoreWithRatioResult {
@@ -21,6 +21,8 @@ struct ScoreWithRatioResult {
     degraded: bool,
     used_negative_operator: bool,
     query_vector: Option<Embedding>,
+    applied_rules: Vec<String>,
+    suggestions: Vec<QuerySuggestion>,
 }
 
 type ScoreWithRatio = (Vec<ScoreDetails>, f32);
@@ -94,6 +96,8 @@ impl ScoreWithRatioResult {
             degraded: results.degraded,
             used_negative_operator: results.used_negative_operator,
             query_vector: results.query_vector,
+            applied_rules: results.applied_rules,
+            suggestions: results.suggestions,
         }
     }
 
@@ -233,6 +237,10 @@ impl ScoreWithRatioResult {
                 used_negative_operator: vector_results.used_negative_operator
                     | keyword_results.used_negative_operator,
                 query_vector: vector_results.query_vector,
+                // both searches match the rules against the same query
+                applied_rules: keyword_results.applied_rules,
+                // only the keyword search computes suggestions
+                suggestions: keyword_results.suggestions,
             },
             semantic_hit_count,
         ))
@@ -286,6 +294,8 @@ impl Search<'_> {
             terms_matching_strategy: self.terms_matching_strategy,
             scoring_strategy: ScoringStrategy::Detailed,
             words_limit: self.words_limit,
+            advanced_query_syntax: self.advanced_query_syntax,
+            suggestions: self.suggestions,
             retrieve_vectors: self.retrieve_vectors,
             exhaustive_number_hits: self.exhaustive_number_hits,
             max_total_hits: self.max_total_hits,
@@ -300,6 +310,7 @@ impl Search<'_> {
             locales: self.locales.clone(),
             progress: self.progress,
             dynamic_search_rules: self.dynamic_search_rules,
+            tenant_token_claims: self.tenant_token_claims,
             candidates: self.candidates,
         };
 
@@ -407,6 +418,8 @@ fn return_keyword_results(
         degraded,
         used_negative_operator,
         query_vector,
+        applied_rules,
+        suggestions,
     }: SearchResult,
 ) -> (SearchResult, Option<u32>) {
     let (documents_ids, document_scores) = if offset >= documents_ids.len() ||
@@ -434,6 +447,8 @@ fn return_keyword_results(
             degraded,
             used_negative_operator,
             query_vector,
+            applied_rules,
+            suggestions,
         },
         Some(0),
     )
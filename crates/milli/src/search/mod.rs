@@ -8,19 +8,21 @@ use roaring::bitmap::RoaringBitmap;
 use time::OffsetDateTime;
 
 pub use self::facet::{
-    serialize_index_filter_to_filter_string, FacetDistribution, Filter, IndexFilter, OrderBy,
-    DEFAULT_VALUES_PER_FACET,
+    serialize_index_filter_to_filter_string, FacetAggregation, FacetAggregations, FacetBucket,
+    FacetDistribution, Filter, IndexFilter, OrderBy, DEFAULT_VALUES_PER_FACET,
 };
 pub use self::new::matches::{FormatOptions, MatchBounds, MatcherBuilder, MatchingWords};
+pub use self::new::QuerySuggestion;
 use self::new::{execute_vector_search, PartialSearchResult, VectorStoreStats};
 use crate::documents::GeoSortParameter;
-use crate::dynamic_search_rules::{DsrFuel, DynamicSearchRules};
+use crate::dynamic_search_rules::{DsrFuel, DynamicSearchRules, RuleEffects, RuleQuery};
 use crate::filterable_attributes_rules::{filtered_matching_patterns, matching_features};
 use crate::index::MatchingStrategy;
 use crate::progress::Progress;
 use crate::score_details::{ScoreDetails, ScoringStrategy};
 use crate::search::new::{
-    extract_tokens, resolve_negative_phrases, resolve_negative_words, ExtractedTokens, QueryGraph,
+    compute_suggestions, extract_tokens, resolve_field_scopes, resolve_negative_phrases,
+    resolve_negative_words, ExtractedTokens, QueryGraph,
 };
 use crate::vector::{Embedder, Embedding};
 use crate::{
@@ -33,6 +35,7 @@ static LEVDIST0: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(0, true));
 static LEVDIST1: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(1, true));
 static LEVDIST2: Lazy<LevBuilder> = Lazy::new(|| LevBuilder::new(2, true));
 
+pub mod autocomplete;
 pub mod facet;
 mod fst_utils;
 pub mod hybrid;
@@ -142,6 +145,8 @@ pub struct Search<'a> {
     terms_matching_strategy: TermsMatchingStrategy,
     scoring_strategy: ScoringStrategy,
     words_limit: usize,
+    advanced_query_syntax: bool,
+    suggestions: usize,
     retrieve_vectors: bool,
     exhaustive_number_hits: bool,
     max_total_hits: Option<usize>,
@@ -156,6 +161,7 @@ pub struct Search<'a> {
     locales: Option<Vec<Language>>,
     progress: &'a Progress,
     dynamic_search_rules: Option<(&'a DynamicSearchRules, DsrFuel)>,
+    tenant_token_claims: Option<&'a serde_json::Map<String, serde_json::Value>>,
     candidates: Option<&'a RoaringBitmap>,
 }
 
@@ -183,6 +189,8 @@ impl<'a> Search<'a> {
             exhaustive_number_hits: false,
             max_total_hits: None,
             words_limit: 10,
+            advanced_query_syntax: false,
+            suggestions: 0,
             rtxn,
             index,
             fields_ids_map,
@@ -194,6 +202,7 @@ impl<'a> Search<'a> {
             ranking_score_threshold: None,
             progress,
             dynamic_search_rules: None,
+            tenant_token_claims: None,
             candidates: None,
         }
     }
@@ -255,6 +264,18 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// Parses the `attribute:term` and `(term OR term)` operators of the query.
+    pub fn advanced_query_syntax(&mut self, value: bool) -> &mut Search<'a> {
+        self.advanced_query_syntax = value;
+        self
+    }
+
+    /// Computes up to `limit` alternative queries where the typos of the query are corrected.
+    pub fn suggestions(&mut self, limit: usize) -> &mut Search<'a> {
+        self.suggestions = limit;
+        self
+    }
+
     pub fn filter(&mut self, condition: Option<IndexFilter>) -> &mut Search<'a> {
         self.filter = condition;
         self
@@ -313,6 +334,15 @@ impl<'a> Search<'a> {
         self
     }
 
+    /// The claims of the tenant token used to perform the search, matched by the conditions of the dynamic search rules.
+    pub fn tenant_token_claims(
+        &mut self,
+        claims: &'a serde_json::Map<String, serde_json::Value>,
+    ) -> &mut Search<'a> {
+        self.tenant_token_claims = Some(claims);
+        self
+    }
+
     /// Limit the results to **at most** candidates.
     ///
     /// If there is a specified filter, it is applied on top of the candidates.
@@ -401,10 +431,12 @@ impl<'a> Search<'a> {
             self.progress,
         )?;
 
-        let (query_terms, pins, used_negative_operator) =
+        let (query_terms, mut rule_effects, used_negative_operator) =
             self.build_located_query_terms(&mut ctx, self.filter.as_ref(), &mut universe)?;
+        let applied_rules = std::mem::take(&mut rule_effects.applied_rules);
 
         let mut query_vector = None;
+        let mut suggestions = Vec::new();
         let PartialSearchResult {
             located_query_terms,
             candidates,
@@ -440,29 +472,44 @@ impl<'a> Search<'a> {
                     self.deadline.clone(),
                     self.ranking_score_threshold,
                     self.progress,
-                    pins,
+                    rule_effects,
+                )?
+            }
+            _ => {
+                if let Some((_, located_query_terms)) = &query_terms {
+                    if self.suggestions > 0 {
+                        let _step =
+                            self.progress.update_progress_scoped(SearchStep::ComputeSuggestions);
+                        suggestions = compute_suggestions(
+                            &mut ctx,
+                            located_query_terms,
+                            &universe,
+                            self.suggestions,
+                        )?;
+                    }
+                }
+
+                execute_search(
+                    &mut ctx,
+                    query_terms,
+                    self.terms_matching_strategy,
+                    self.scoring_strategy,
+                    self.exhaustive_number_hits,
+                    self.max_total_hits,
+                    universe,
+                    &self.sort_criteria,
+                    &self.distinct,
+                    self.geo_param,
+                    self.offset,
+                    self.limit,
+                    &mut DefaultSearchLogger,
+                    &mut DefaultSearchLogger,
+                    self.deadline.clone(),
+                    self.ranking_score_threshold,
+                    self.progress,
+                    rule_effects,
                 )?
             }
-            _ => execute_search(
-                &mut ctx,
-                query_terms,
-                self.terms_matching_strategy,
-                self.scoring_strategy,
-                self.exhaustive_number_hits,
-                self.max_total_hits,
-                universe,
-                &self.sort_criteria,
-                &self.distinct,
-                self.geo_param,
-                self.offset,
-                self.limit,
-                &mut DefaultSearchLogger,
-                &mut DefaultSearchLogger,
-                self.deadline.clone(),
-                self.ranking_score_threshold,
-                self.progress,
-                pins,
-            )?,
         };
 
         if let Some(VectorStoreStats { total_time, total_queries, total_results }) =
@@ -485,6 +532,8 @@ impl<'a> Search<'a> {
             degraded,
             used_negative_operator,
             query_vector,
+            applied_rules,
+            suggestions,
         })
     }
 
@@ -493,7 +542,7 @@ impl<'a> Search<'a> {
         ctx: &mut SearchContext<'_>,
         filter: Option<&IndexFilter>,
         universe: &mut RoaringBitmap,
-    ) -> Result<(Option<(QueryGraph, Vec<new::LocatedQueryTerm>)>, Vec<PinDoc>, bool), Error> {
+    ) -> Result<(Option<(QueryGraph, Vec<new::LocatedQueryTerm>)>, RuleEffects, bool), Error> {
         let mut used_negative_operator = false;
 
         let mut ignored = RoaringBitmap::new();
@@ -502,13 +551,27 @@ impl<'a> Search<'a> {
             if let Some(query) = self.query.as_deref().filter(|q| !q.trim().is_empty()) {
                 let _step = self.progress.update_progress_scoped(SearchStep::TokenizeQuery);
 
-                let ExtractedTokens { query_terms, graph, negative_words, negative_phrases } =
-                    extract_tokens(ctx, query, Some(self.words_limit), self.locales.as_ref())?;
+                let ExtractedTokens {
+                    query_terms,
+                    graph,
+                    negative_words,
+                    negative_phrases,
+                    field_scopes,
+                } = extract_tokens(
+                    ctx,
+                    query,
+                    Some(self.words_limit),
+                    self.locales.as_ref(),
+                    self.advanced_query_syntax,
+                )?;
 
                 used_negative_operator = !negative_words.is_empty() || !negative_phrases.is_empty();
 
                 ignored |= resolve_negative_words(ctx, Some(&*universe), &negative_words)?;
                 ignored |= resolve_negative_phrases(ctx, &negative_phrases)?;
+                if !field_scopes.is_empty() {
+                    *universe = resolve_field_scopes(ctx, universe, &field_scopes)?;
+                }
 
                 if query_terms.is_empty() {
                     // Do a placeholder search instead
@@ -520,23 +583,27 @@ impl<'a> Search<'a> {
                 None
             };
 
-        let pins = self
+        let rule_effects = self
             .dynamic_search_rules
             .map(|(dsrs, fuel)| {
-                dsrs.resolve_pins(
-                    query_graph_terms.as_ref().map(|(_, terms)| terms.as_slice()).unwrap_or(&[]),
+                let rule_query = RuleQuery {
+                    query: self.query.as_deref(),
+                    query_terms: query_graph_terms
+                        .as_ref()
+                        .map(|(_, terms)| terms.as_slice())
+                        .unwrap_or(&[]),
+                    locales: self.locales.as_deref(),
+                    tenant_token_claims: self.tenant_token_claims,
                     filter,
-                    universe,
-                    ctx,
-                    fuel,
-                )
+                };
+                dsrs.resolve_effects(rule_query, universe, ctx, fuel)
             })
             .transpose()?
             .unwrap_or_default();
 
         *universe -= ignored;
 
-        Ok((query_graph_terms, pins, used_negative_operator))
+        Ok((query_graph_terms, rule_effects, used_negative_operator))
     }
 }
 
@@ -554,6 +621,8 @@ impl fmt::Debug for Search<'_> {
             terms_matching_strategy,
             scoring_strategy,
             words_limit,
+            advanced_query_syntax,
+            suggestions,
             retrieve_vectors,
             exhaustive_number_hits,
             max_total_hits,
@@ -569,6 +638,8 @@ impl fmt::Debug for Search<'_> {
             candidates,
             progress: _,
             dynamic_search_rules: _,
+            // claims are not displayed as they might contain sensitive information
+            tenant_token_claims: _,
         } = self;
         f.debug_struct("Search")
             .field("query", query)
@@ -585,6 +656,8 @@ impl fmt::Debug for Search<'_> {
             .field("exhaustive_number_hits", exhaustive_number_hits)
             .field("max_total_hits", max_total_hits)
             .field("words_limit", words_limit)
+            .field("advanced_query_syntax", advanced_query_syntax)
+            .field("suggestions", suggestions)
             .field(
                 "semantic.embedder_name",
                 &semantic.as_ref().map(|semantic| &semantic.embedder_name),
@@ -606,6 +679,10 @@ pub struct SearchResult {
     pub degraded: bool,
     pub used_negative_operator: bool,
     pub query_vector: Option<Embedding>,
+    /// Uids of the dynamic search rules applied to this search.
+    pub applied_rules: Vec<String>,
+    /// Alternative queries with corrected typos, when requested with [`Search::suggestions`].
+    pub suggestions: Vec<QuerySuggestion>,
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,129 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use fst::automaton::{Automaton, Str};
+use fst::{IntoStreamer, Streamer};
+use roaring::RoaringBitmap;
+
+use super::query_term::{LocatedQueryTerm, QueryTermSubset};
+use super::resolve_query_graph::compute_query_term_subset_docids;
+use super::{SearchContext, Word};
+use crate::{CboRoaringBitmapLenCodec, Deadline, Result};
+
+/// A word of the index completing the last word of the query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Completion {
+    /// The completed word, as normalized in the index.
+    pub value: String,
+    /// The number of documents containing the completed word and all the previous words of the query.
+    pub count: u64,
+}
+
+/// Computes the words of the index starting with the last term of the query.
+///
+/// Only the documents of the universe containing all the previous terms of the query are considered.
+/// The completions are ordered by descending number of documents, then lexicographically.
+/// When the deadline is exceeded, the best completions among the words already visited are returned.
+pub fn compute_completions(
+    ctx: &mut SearchContext<'_>,
+    located_query_terms: &[LocatedQueryTerm],
+    universe: &RoaringBitmap,
+    limit: usize,
+    deadline: &Deadline,
+) -> Result<Vec<Completion>> {
+    let Some((last_term, previous_terms)) = located_query_terms.split_last() else {
+        return Ok(Vec::new());
+    };
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut universe = universe.clone();
+    for located_term in previous_terms {
+        located_term.value.compute_fully_if_needed(ctx)?;
+        let subset = QueryTermSubset::full(located_term.value);
+        universe = compute_query_term_subset_docids(ctx, Some(&universe), &subset)?;
+        if universe.is_empty() {
+            return Ok(Vec::new());
+        }
+    }
+
+    let term = ctx.term_interner.get(last_term.value);
+    // a phrase is already complete
+    if term.original_phrase().is_some() {
+        return Ok(Vec::new());
+    }
+    let prefix = term.original_word(ctx);
+
+    // the prefix database only contains the most frequent prefixes,
+    // but when it does it tells us if any document can be completed at all
+    let interned_prefix = ctx.word_interner.insert(prefix.clone());
+    if let Some(docids) =
+        ctx.word_prefix_docids(Some(&universe), Word::Original(interned_prefix))?
+    {
+        if docids.is_empty() {
+            return Ok(Vec::new());
+        }
+    }
+
+    // a min-heap of the best completions, the worst one being at the top
+    let mut completions = BinaryHeap::new();
+    let words_fst = ctx.get_words_fst()?;
+    let mut stream = words_fst.search(Str::new(&prefix).starts_with()).into_stream();
+    while let Some(word) = stream.next() {
+        if deadline.exceeded() {
+            break;
+        }
+        let word = std::str::from_utf8(word)?;
+
+        // the words are streamed in lexicographic order, so a word can only
+        // replace the worst completion by appearing in strictly more documents
+        if completions.len() == limit {
+            if let Some(Reverse((worst_count, _))) = completions.peek() {
+                if word_documents_count(ctx, word)? <= *worst_count {
+                    continue;
+                }
+            }
+        }
+
+        let interned = ctx.word_interner.insert(word.to_owned());
+        let count = match ctx.word_docids(Some(&universe), Word::Original(interned))? {
+            Some(docids) => docids.len(),
+            None => continue,
+        };
+        if count == 0 {
+            continue;
+        }
+
+        completions.push(Reverse((count, Reverse(word.to_owned()))));
+        if completions.len() > limit {
+            completions.pop();
+        }
+    }
+
+    Ok(completions
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((count, Reverse(value)))| Completion { value, count })
+        .collect())
+}
+
+/// Returns the number of documents of the index containing the word, in any attribute.
+///
+/// This is an upper bound of the number of documents of the universe containing it,
+/// that is cheap to compute as the bitmaps are not decoded.
+fn word_documents_count(ctx: &SearchContext<'_>, word: &str) -> Result<u64> {
+    let tolerant = ctx
+        .index
+        .word_docids
+        .remap_data_type::<CboRoaringBitmapLenCodec>()
+        .get(ctx.txn, word)?
+        .unwrap_or_default();
+    let exact = ctx
+        .index
+        .exact_word_docids
+        .remap_data_type::<CboRoaringBitmapLenCodec>()
+        .get(ctx.txn, word)?
+        .unwrap_or_default();
+    Ok(tolerant + exact)
+}
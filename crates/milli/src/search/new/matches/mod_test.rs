@@ -54,7 +54,7 @@ impl<'a> MatcherBuilder<'a> {
             Deadline::never(),
             None,
             &progress,
-            vec![],
+            Default::default(),
         )
         .unwrap();
 
@@ -1,3 +1,4 @@
+mod autocomplete;
 mod bucket_sort;
 mod db_cache;
 mod distinct;
@@ -13,8 +14,10 @@ mod ranking_rule_graph;
 mod ranking_rules;
 mod resolve_query_graph;
 mod small_bitmap;
+mod suggestions;
 
 mod exact_attribute;
+mod rule_tiers;
 mod sort;
 mod vector_sort;
 
@@ -25,6 +28,7 @@ use std::collections::{HashMap, HashSet};
 use std::ops::AddAssign;
 use std::time::Duration;
 
+pub use autocomplete::{compute_completions, Completion};
 use bucket_sort::{bucket_sort, BucketSortOutput};
 use charabia::{Language, TokenizerBuilder};
 use db_cache::DatabaseCache;
@@ -36,14 +40,18 @@ use interner::{DedupInterner, Interner};
 pub use logger::visual::VisualSearchLogger;
 pub use logger::{DefaultSearchLogger, SearchLogger};
 pub use query_graph::{QueryGraph, QueryNode};
-use query_term::{located_query_terms_from_tokens, Phrase, QueryTerm};
-pub use query_term::{ExtractedTokens, LocatedQueryTerm};
+use query_term::{located_query_terms_from_tokens, Phrase, QueryTerm, QueryTermSubset};
+pub use query_term::{ExtractedTokens, FieldScope, LocatedQueryTerm};
 use ranking_rules::{
     BoxRankingRule, PlaceholderQuery, RankingRule, RankingRuleOutput, RankingRuleQueryTrait,
 };
-use resolve_query_graph::{compute_query_graph_docids, PhraseDocIdsCache};
+use resolve_query_graph::{
+    compute_query_graph_docids, compute_query_term_subset_docids_within_field_id, PhraseDocIdsCache,
+};
 use roaring::RoaringBitmap;
+use rule_tiers::DynamicSearchRulesTiers;
 use sort::Sort;
+pub use suggestions::{compute_suggestions, QuerySuggestion};
 use time::OffsetDateTime;
 
 pub(crate) use self::distinct::{facet_string_values, facet_values_prefix_key};
@@ -54,6 +62,7 @@ use self::vector_sort::VectorSort;
 use crate::attribute_patterns::{match_pattern, PatternMatch};
 use crate::constants::RESERVED_GEO_FIELD_NAME;
 use crate::documents::GeoSortParameter;
+use crate::dynamic_search_rules::{RuleEffects, RuleTiers};
 use crate::index::PrefixSearch;
 use crate::localized_attributes_rules::LocalizedFieldIds;
 use crate::progress::Progress;
@@ -63,7 +72,7 @@ use crate::search::new::distinct::apply_distinct_rule;
 use crate::search::steps::SearchStep;
 use crate::vector::Embedder;
 use crate::{
-    AscDesc, Deadline, DocumentId, FieldId, FieldsIdsMap, Index, Member, PinDoc, Result,
+    AscDesc, Deadline, DocumentId, FieldId, FieldsIdsMap, Index, Member, Result,
     TermsMatchingStrategy, UserError, Weight,
 };
 
@@ -349,6 +358,42 @@ pub(in crate::search) fn resolve_negative_phrases(
     Ok(negative_bitmap)
 }
 
+/// Returns the documents of the universe in which every scoped term appears in its attribute.
+#[tracing::instrument(level = "trace", skip_all, target = "search::query")]
+pub(in crate::search) fn resolve_field_scopes(
+    ctx: &mut SearchContext<'_>,
+    universe: &RoaringBitmap,
+    field_scopes: &[FieldScope],
+) -> Result<RoaringBitmap> {
+    let mut docids = universe.clone();
+    for FieldScope { fid, terms } in field_scopes {
+        let mut scope_docids = RoaringBitmap::new();
+        for term in terms {
+            let term_subset = QueryTermSubset::full(term.value);
+            scope_docids |= compute_query_term_subset_docids_within_field_id(
+                ctx,
+                Some(&docids),
+                &term_subset,
+                *fid,
+            )?;
+        }
+        docids &= scope_docids;
+    }
+    Ok(docids)
+}
+
+/// Put the ranking rule applying the boost and bury actions of the dynamic search rules in front of
+/// the other ranking rules, if any of these actions applies.
+fn with_rule_tiers<'ctx, Q: RankingRuleQueryTrait>(
+    mut ranking_rules: Vec<BoxRankingRule<'ctx, Q>>,
+    tiers: RuleTiers,
+) -> Vec<BoxRankingRule<'ctx, Q>> {
+    if !tiers.is_empty() {
+        ranking_rules.insert(0, Box::new(DynamicSearchRulesTiers::new(tiers)));
+    }
+    ranking_rules
+}
+
 /// Return the list of initialised ranking rules to be used for a placeholder search.
 fn get_ranking_rules_for_placeholder_search<'ctx>(
     ctx: &SearchContext<'ctx>,
@@ -759,10 +804,12 @@ pub fn execute_vector_search(
     deadline: Deadline,
     ranking_score_threshold: Option<f64>,
     progress: &Progress,
-    pins: Vec<PinDoc>,
+    rule_effects: RuleEffects,
 ) -> Result<PartialSearchResult> {
     check_sort_criteria(ctx, sort_criteria.as_ref())?;
 
+    let RuleEffects { pins, tiers, applied_rules: _ } = rule_effects;
+
     // FIXME: input universe = universe & documents_with_vectors
     // for now if we're computing embeddings for ALL documents, we can assume that this is just universe
     let ranking_rules = get_ranking_rules_for_vector(
@@ -775,6 +822,7 @@ pub fn execute_vector_search(
         embedder,
         quantized,
     )?;
+    let ranking_rules = with_rule_tiers(ranking_rules, tiers);
 
     let mut placeholder_search_logger = logger::DefaultSearchLogger;
     let placeholder_search_logger: &mut dyn SearchLogger<PlaceholderQuery> =
@@ -827,10 +875,11 @@ pub fn execute_search(
     deadline: Deadline,
     ranking_score_threshold: Option<f64>,
     progress: &Progress,
-    pins: Vec<PinDoc>,
+    rule_effects: RuleEffects,
 ) -> Result<PartialSearchResult> {
     check_sort_criteria(ctx, sort_criteria.as_ref())?;
 
+    let RuleEffects { pins, tiers, applied_rules: _ } = rule_effects;
     let (query_graph, located_query_terms) = query_graph_terms.unzip();
 
     let bucket_sort_output = if let Some(query_graph) = query_graph {
@@ -840,6 +889,7 @@ pub fn execute_search(
             geo_param,
             terms_matching_strategy,
         )?;
+        let ranking_rules = with_rule_tiers(ranking_rules, tiers);
 
         universe &= resolve_universe(
             ctx,
@@ -870,6 +920,7 @@ pub fn execute_search(
     } else {
         let ranking_rules =
             get_ranking_rules_for_placeholder_search(ctx, sort_criteria, geo_param)?;
+        let ranking_rules = with_rule_tiers(ranking_rules, tiers);
         let _step = progress.update_progress_scoped(SearchStep::PlaceholderRanking);
         bucket_sort(
             ctx,
@@ -920,6 +971,7 @@ pub fn extract_tokens(
     query: &str,
     words_limit: Option<usize>,
     locales: Option<&Vec<Language>>,
+    advanced_syntax: bool,
 ) -> Result<ExtractedTokens> {
     let span = tracing::trace_span!(target: "search::tokens", "tokenizer_builder");
     let entered = span.enter();
@@ -992,7 +1044,8 @@ pub fn extract_tokens(
     let tokens = tokenizer.tokenize(query);
     drop(entered);
 
-    located_query_terms_from_tokens(ctx, &tokenizer, tokens, words_limit)
+    let advanced_syntax = advanced_syntax.then_some(query);
+    located_query_terms_from_tokens(ctx, &tokenizer, tokens, words_limit, advanced_syntax)
 }
 
 pub(crate) fn check_sort_criteria(
@@ -111,27 +111,32 @@ impl QueryGraph {
         let (mut prev2, mut prev1, mut prev0): (Vec<u16>, Vec<u16>, Vec<u16>) =
             (vec![], vec![], vec![root_node]);
 
-        let original_terms_len = terms.len();
-        for term_idx in 0..original_terms_len {
+        // Consecutive terms sharing the same positions are the alternatives of an OR group,
+        // they get the same term id so that they are parallel nodes of the graph.
+        let groups: Vec<&[LocatedQueryTerm]> =
+            terms.chunk_by(|left, right| left.positions == right.positions).collect();
+
+        for (term_idx, group) in groups.iter().enumerate() {
             let mut new_nodes = vec![];
 
-            let new_node_idx = add_node(
-                &mut nodes_data,
-                QueryNodeData::Term(LocatedQueryTermSubset {
-                    term_subset: QueryTermSubset::full(terms[term_idx].value),
-                    positions: terms[term_idx].positions.clone(),
-                    term_ids: term_idx as u8..=term_idx as u8,
-                }),
-            );
-            new_nodes.push(new_node_idx);
+            for term in group.iter() {
+                let new_node_idx = add_node(
+                    &mut nodes_data,
+                    QueryNodeData::Term(LocatedQueryTermSubset {
+                        term_subset: QueryTermSubset::full(term.value),
+                        positions: term.positions.clone(),
+                        term_ids: term_idx as u8..=term_idx as u8,
+                    }),
+                );
+                new_nodes.push(new_node_idx);
+            }
 
             if !prev1.is_empty() {
-                if let Some(ngram) = query_term::make_ngram(
-                    ctx,
-                    tokenizer,
-                    &terms[term_idx - 1..=term_idx],
-                    &nbr_typos,
-                )? {
+                let ngram = match single_terms(&groups[term_idx - 1..=term_idx]) {
+                    Some(terms) => query_term::make_ngram(ctx, tokenizer, &terms, &nbr_typos)?,
+                    None => None,
+                };
+                if let Some(ngram) = ngram {
                     new_located_query_terms.push(ngram.clone());
                     let ngram_idx = add_node(
                         &mut nodes_data,
@@ -145,12 +150,11 @@ impl QueryGraph {
                 }
             }
             if !prev2.is_empty() {
-                if let Some(ngram) = query_term::make_ngram(
-                    ctx,
-                    tokenizer,
-                    &terms[term_idx - 2..=term_idx],
-                    &nbr_typos,
-                )? {
+                let ngram = match single_terms(&groups[term_idx - 2..=term_idx]) {
+                    Some(terms) => query_term::make_ngram(ctx, tokenizer, &terms, &nbr_typos)?,
+                    None => None,
+                };
+                if let Some(ngram) = ngram {
                     new_located_query_terms.push(ngram.clone());
                     let ngram_idx = add_node(
                         &mut nodes_data,
@@ -422,6 +426,17 @@ impl QueryGraph {
     }
 }
 
+/// Returns the terms of the groups if none of them is an OR group, ngrams are not made across OR groups.
+fn single_terms(groups: &[&[LocatedQueryTerm]]) -> Option<Vec<LocatedQueryTerm>> {
+    groups
+        .iter()
+        .map(|group| match group {
+            [term] => Some(term.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
 fn add_node(nodes_data: &mut Vec<QueryNodeData>, node_data: QueryNodeData) -> u16 {
     let new_node_idx = nodes_data.len() as u16;
     nodes_data.push(node_data);
@@ -11,6 +11,7 @@ use either::Either;
 pub use ntypo_subset::NTypoTermSubset;
 pub use parse_query::{
     located_query_terms_from_tokens, make_ngram, number_of_typos_allowed, ExtractedTokens,
+    FieldScope,
 };
 pub use phrase::Phrase;
 
@@ -507,4 +508,23 @@ impl QueryTerm {
 
         (words.into_iter().collect(), phrases.into_iter().collect())
     }
+
+    /// Return the words one and two typos away from the original word with their number of typos,
+    /// and the original word split into two words, if any.
+    ///
+    /// Only the derivations that were already computed are returned.
+    pub fn typo_derivations(&self) -> (Vec<(Interned<String>, u8)>, Option<Interned<Phrase>>) {
+        let mut words = Vec::new();
+        let mut split_words = None;
+
+        if let Lazy::Init(one_typo) = &self.one_typo {
+            words.extend(one_typo.one_typo.iter().map(|word| (*word, 1)));
+            split_words = one_typo.split_words;
+        }
+        if let Lazy::Init(TwoTypoTerm { two_typos }) = &self.two_typo {
+            words.extend(two_typos.iter().map(|word| (*word, 2)));
+        }
+
+        (words, split_words)
+    }
 }
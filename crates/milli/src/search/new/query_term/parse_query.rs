@@ -1,14 +1,14 @@
 use std::collections::BTreeSet;
 
 use charabia::normalizer::NormalizedTokenIter;
-use charabia::{SeparatorKind, TokenKind, Tokenizer};
+use charabia::{SeparatorKind, Token, TokenKind, Tokenizer};
 
 use super::compute_derivations::partially_initialized_term_from_word;
 use super::{LocatedQueryTerm, ZeroTypoTerm};
 use crate::search::new::query_graph::QueryGraph;
 use crate::search::new::query_term::{Lazy, Phrase, QueryTerm};
 use crate::search::new::Word;
-use crate::{Result, SearchContext, MAX_WORD_LENGTH};
+use crate::{FieldId, Result, SearchContext, MAX_WORD_LENGTH};
 
 #[derive(Clone)]
 /// Extraction of the content of a query.
@@ -21,15 +21,31 @@ pub struct ExtractedTokens {
     pub negative_words: Vec<Word>,
     /// The phrases that must not appear in the results.
     pub negative_phrases: Vec<LocatedQueryTerm>,
+    /// The terms that must appear in a given attribute of the results.
+    ///
+    /// Always empty when the advanced query syntax is disabled.
+    pub field_scopes: Vec<FieldScope>,
+}
+
+/// Query terms restricted to an attribute with the `attribute:term` syntax.
+#[derive(Clone)]
+pub struct FieldScope {
+    pub fid: FieldId,
+    /// The alternatives of the scoped term, any of them must appear in the attribute.
+    pub terms: Vec<LocatedQueryTerm>,
 }
 
 /// Convert the tokenised search query into a list of located query terms.
+///
+/// When `advanced_syntax` contains the original query, the `attribute:term` and
+/// `(term OR term)` operators are also parsed.
 #[tracing::instrument(level = "trace", skip_all, target = "search::query")]
 pub fn located_query_terms_from_tokens(
     ctx: &mut SearchContext<'_>,
     tokenizer: &Tokenizer<'_>,
     query: NormalizedTokenIter<'_, '_, '_, '_>,
     words_limit: Option<usize>,
+    advanced_syntax: Option<&str>,
 ) -> Result<ExtractedTokens> {
     let nbr_typos = number_of_typos_allowed(ctx)?;
     let allow_prefix_search = ctx.is_prefix_search_allowed();
@@ -42,6 +58,8 @@ pub fn located_query_terms_from_tokens(
     let mut negative_next_token = false;
     let mut negative_words = Vec::new();
     let mut negative_phrases = Vec::new();
+    let mut advanced_syntax =
+        advanced_syntax.map(|query| AdvancedSyntax::new(ctx, query)).transpose()?;
 
     let parts_limit = words_limit.unwrap_or(usize::MAX);
 
@@ -57,11 +75,34 @@ pub fn located_query_terms_from_tokens(
         // early return if word limit is exceeded
         if query_terms.len() >= parts_limit {
             let (graph, query_terms) = QueryGraph::from_query(ctx, tokenizer, &query_terms)?;
-
-            return Ok(ExtractedTokens { query_terms, graph, negative_words, negative_phrases });
+            let field_scopes =
+                advanced_syntax.map(AdvancedSyntax::into_field_scopes).unwrap_or_default();
+
+            return Ok(ExtractedTokens {
+                query_terms,
+                graph,
+                negative_words,
+                negative_phrases,
+                field_scopes,
+            });
         }
 
+        let in_or_group = advanced_syntax.as_ref().is_some_and(|syntax| syntax.group.is_some());
+
         match token.kind {
+            TokenKind::Word | TokenKind::StopWord
+                if advanced_syntax.as_ref().is_some_and(|syntax| syntax.is_field_name(&token)) => {}
+            TokenKind::Word | TokenKind::StopWord if in_or_group => {
+                // Per the guard above, we are in an OR group
+                let syntax = advanced_syntax.as_mut().unwrap();
+                let group = syntax.group.as_mut().unwrap();
+                if syntax.query.get(token.byte_start..token.byte_end) == Some("OR") {
+                    group.alternatives.push(PhraseBuilder::empty());
+                } else {
+                    group.push_word(ctx, &token);
+                }
+                negative_next_token = false;
+            }
             TokenKind::Word | TokenKind::StopWord => {
                 // On first loop, goes from u16::MAX to 0, then normal increment.
                 position = position.wrapping_add(1);
@@ -112,6 +153,11 @@ pub fn located_query_terms_from_tokens(
                     };
                     query_terms.push(located_term);
                 }
+
+                // A word outside of a phrase consumes the attribute it is scoped to, if any.
+                if let Some(syntax) = advanced_syntax.as_mut().filter(|_| phrase.is_none()) {
+                    syntax.close_scope(&query_terms);
+                }
             }
             TokenKind::Separator(separator_kind) => {
                 // add penalty for hard separators
@@ -119,6 +165,42 @@ pub fn located_query_terms_from_tokens(
                     position = position.wrapping_add(7);
                 }
 
+                // Parentheses inside a phrase are part of the phrase, they are parsed
+                // after the closing quote when the phrase ends in this separator.
+                let phrase_was_open = phrase.is_some();
+                let mut negated_scope = false;
+                if let Some(syntax) = &mut advanced_syntax {
+                    if let Some(fid) = syntax.field_prefix_colon(&token) {
+                        // `-attribute:word` excludes the word from every attribute
+                        negated_scope = negative_next_token;
+                        if !negated_scope {
+                            syntax.open_scope(fid, query_terms.len());
+                        }
+                    }
+                    if !phrase_was_open {
+                        syntax.parse_parentheses(
+                            ctx,
+                            tokenizer,
+                            &token,
+                            &nbr_typos,
+                            &mut position,
+                            &mut query_terms,
+                        )?;
+                        // Quotes inside an OR group are ignored as every
+                        // alternative of several words is already a phrase.
+                        if in_or_group || syntax.group.is_some() {
+                            negative_next_token = false;
+                            encountered_whitespace = token
+                                .lemma()
+                                .chars()
+                                .last()
+                                .filter(|c| c.is_whitespace())
+                                .is_some();
+                            continue;
+                        }
+                    }
+                }
+
                 phrase = 'phrase: {
                     let phrase = phrase.take();
 
@@ -163,6 +245,9 @@ pub fn located_query_terms_from_tokens(
                                 query_terms.push(located_query_term);
                             }
                         }
+                        if let Some(syntax) = &mut advanced_syntax {
+                            syntax.close_scope(&query_terms);
+                        }
                     }
 
                     // Start new phrase if the token ends with an opening quote
@@ -174,8 +259,21 @@ pub fn located_query_terms_from_tokens(
                     }
                 };
 
-                negative_next_token =
-                    phrase.is_none() && token.lemma() == "-" && encountered_whitespace;
+                if let Some(syntax) = advanced_syntax.as_mut().filter(|_| phrase_was_open) {
+                    if phrase.is_none() {
+                        syntax.parse_parentheses(
+                            ctx,
+                            tokenizer,
+                            &token,
+                            &nbr_typos,
+                            &mut position,
+                            &mut query_terms,
+                        )?;
+                    }
+                }
+
+                negative_next_token = phrase.is_none()
+                    && (negated_scope || (token.lemma() == "-" && encountered_whitespace));
             }
             _ => (),
         }
@@ -196,9 +294,21 @@ pub fn located_query_terms_from_tokens(
         }
     }
 
+    // If a parenthesis is never closed, we consider all of the end of the query as the OR group.
+    let field_scopes = match advanced_syntax {
+        Some(mut syntax) => {
+            if let Some(group) = syntax.group.take() {
+                query_terms.extend(group.build(ctx, tokenizer, &nbr_typos)?);
+            }
+            syntax.close_scope(&query_terms);
+            syntax.into_field_scopes()
+        }
+        None => Vec::new(),
+    };
+
     let (graph, query_terms) = QueryGraph::from_query(ctx, tokenizer, &query_terms)?;
 
-    Ok(ExtractedTokens { query_terms, graph, negative_words, negative_phrases })
+    Ok(ExtractedTokens { query_terms, graph, negative_words, negative_phrases, field_scopes })
 }
 
 pub fn number_of_typos_allowed<'ctx>(
@@ -363,6 +473,217 @@ impl PhraseBuilder {
     }
 }
 
+/// The state of the advanced query syntax while parsing a query.
+struct AdvancedSyntax<'q> {
+    /// The original query, to read the operators before normalization.
+    query: &'q str,
+    /// The `attribute:` prefixes of the query.
+    field_prefixes: Vec<FieldPrefix>,
+    /// The attribute the next term is scoped to, with the number of query terms before that term.
+    pending_scope: Option<(FieldId, usize)>,
+    field_scopes: Vec<FieldScope>,
+    /// The OR group being parsed, if any.
+    group: Option<OrGroupBuilder>,
+}
+
+/// An `attribute:` prefix of the query, from the first byte of the attribute to the colon.
+struct FieldPrefix {
+    start: usize,
+    colon: usize,
+    fid: FieldId,
+}
+
+impl<'q> AdvancedSyntax<'q> {
+    fn new(ctx: &SearchContext<'_>, query: &'q str) -> Result<Self> {
+        // Only the attributes that are searched on can scope a term,
+        // any other `word:word` is searched as regular text.
+        let searchable = ctx.index.searchable_fields_and_weights(ctx.txn, ctx.fields_ids_map)?;
+        let fid_of = |name: &str| {
+            searchable
+                .iter()
+                .find(|(searchable, _, _)| searchable == name)
+                .map(|(_, fid, _)| *fid)
+                .filter(|fid| ctx.restricted_fids.as_ref().is_none_or(|fids| fids.contains(fid)))
+        };
+
+        let mut field_prefixes = Vec::new();
+        let mut in_phrase = false;
+        let mut name_start = None;
+        for (index, c) in query.char_indices() {
+            match c {
+                '"' => {
+                    in_phrase = !in_phrase;
+                    name_start = None;
+                }
+                ':' => {
+                    let followed_by_term =
+                        query[index + 1..].starts_with(|c: char| !c.is_whitespace());
+                    let start = name_start.take().filter(|_| !in_phrase && followed_by_term);
+                    let Some(start) = start else { continue };
+                    let name = &query[start..index];
+                    let prefix = match fid_of(name) {
+                        Some(fid) => Some(FieldPrefix { start, colon: index, fid }),
+                        // `-attribute:word` negates the word, the attribute is then ignored
+                        None => name.strip_prefix('-').and_then(fid_of).map(|fid| FieldPrefix {
+                            start: start + 1,
+                            colon: index,
+                            fid,
+                        }),
+                    };
+                    field_prefixes.extend(prefix);
+                }
+                '(' | ')' => name_start = None,
+                c if c.is_whitespace() => name_start = None,
+                _ => {
+                    name_start.get_or_insert(index);
+                }
+            }
+        }
+
+        Ok(Self {
+            query,
+            field_prefixes,
+            pending_scope: None,
+            field_scopes: Vec::new(),
+            group: None,
+        })
+    }
+
+    /// Returns `true` if the token is a part of the attribute of an `attribute:` prefix.
+    fn is_field_name(&self, token: &Token<'_>) -> bool {
+        self.field_prefixes
+            .iter()
+            .any(|prefix| prefix.start <= token.byte_start && token.byte_end <= prefix.colon)
+    }
+
+    /// Returns the attribute of the `attribute:` prefix ending in the separator, if any.
+    fn field_prefix_colon(&self, token: &Token<'_>) -> Option<FieldId> {
+        let bytes = token.byte_start..token.byte_end;
+        self.field_prefixes
+            .iter()
+            .find(|prefix| bytes.contains(&prefix.colon))
+            .map(|prefix| prefix.fid)
+    }
+
+    /// Scopes the next term to the attribute.
+    fn open_scope(&mut self, fid: FieldId, query_terms_len: usize) {
+        // the terms of an OR group cannot be scoped individually
+        if self.group.is_none() {
+            self.pending_scope = Some((fid, query_terms_len));
+        }
+    }
+
+    /// Attaches the query terms pushed since the `attribute:` prefix to the attribute.
+    ///
+    /// The scope is dropped when no term was pushed, e.g. for a stop word or a negative word.
+    fn close_scope(&mut self, query_terms: &[LocatedQueryTerm]) {
+        if let Some((fid, start)) = self.pending_scope.take() {
+            if let Some(terms) = query_terms.get(start..).filter(|terms| !terms.is_empty()) {
+                self.field_scopes.push(FieldScope { fid, terms: terms.to_vec() });
+            }
+        }
+    }
+
+    /// Opens and closes OR groups according to the parentheses of the separator,
+    /// the alternatives of a closed OR group are pushed to the query terms.
+    fn parse_parentheses(
+        &mut self,
+        ctx: &mut SearchContext<'_>,
+        tokenizer: &Tokenizer<'_>,
+        token: &Token<'_>,
+        number_of_typos_allowed: &impl Fn(&str) -> u8,
+        position: &mut u16,
+        query_terms: &mut Vec<LocatedQueryTerm>,
+    ) -> Result<()> {
+        let separator = self.query.get(token.byte_start..token.byte_end).unwrap_or_default();
+        for c in separator.chars() {
+            match c {
+                // nested OR groups are not supported
+                '(' if self.group.is_none() => {
+                    self.group = Some(OrGroupBuilder::new(position.wrapping_add(1)))
+                }
+                ')' => {
+                    let Some(group) = self.group.take() else { continue };
+                    let alternatives = group.build(ctx, tokenizer, number_of_typos_allowed)?;
+                    if let Some(alternative) = alternatives.first() {
+                        *position = *alternative.positions.end();
+                        query_terms.extend(alternatives);
+                        self.close_scope(query_terms);
+                    }
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+
+    fn into_field_scopes(self) -> Vec<FieldScope> {
+        self.field_scopes
+    }
+}
+
+/// An OR group being parsed, made of alternatives separated by `OR`.
+struct OrGroupBuilder {
+    /// The position of the first word of every alternative.
+    start: u16,
+    alternatives: Vec<PhraseBuilder>,
+}
+
+impl OrGroupBuilder {
+    fn new(start: u16) -> Self {
+        Self { start, alternatives: vec![PhraseBuilder::empty()] }
+    }
+
+    // precondition: token has kind Word or StopWord
+    fn push_word(&mut self, ctx: &mut SearchContext<'_>, token: &Token<'_>) {
+        // Per the constructor, there is always at least one alternative
+        let alternative = self.alternatives.last_mut().unwrap();
+        let position = self.start.wrapping_add(alternative.words.len() as u16);
+        alternative.push_word(ctx, token, position);
+    }
+
+    /// Builds the alternatives of the group, that all share the positions of the group
+    /// so that they end up as parallel nodes of the query graph.
+    ///
+    /// An alternative made of a single word is typo tolerant like any other word,
+    /// an alternative made of several words is a phrase.
+    fn build(
+        self,
+        ctx: &mut SearchContext<'_>,
+        tokenizer: &Tokenizer<'_>,
+        number_of_typos_allowed: &impl Fn(&str) -> u8,
+    ) -> Result<Vec<LocatedQueryTerm>> {
+        let alternatives: Vec<_> =
+            self.alternatives.into_iter().filter(|alternative| !alternative.is_empty()).collect();
+        let Some(len) = alternatives.iter().map(|alternative| alternative.words.len()).max() else {
+            return Ok(Vec::new());
+        };
+        let positions = self.start..=self.start.saturating_add(len as u16 - 1);
+
+        let mut terms = Vec::with_capacity(alternatives.len());
+        for alternative in alternatives {
+            let value = match alternative.words.as_slice() {
+                [Some(word)] => {
+                    let word = ctx.word_interner.get(*word).clone();
+                    let term = partially_initialized_term_from_word(
+                        ctx,
+                        tokenizer,
+                        &word,
+                        number_of_typos_allowed(&word),
+                        false,
+                        false,
+                    )?;
+                    ctx.term_interner.push(term)
+                }
+                // Per the filter above, the alternative is not empty
+                _ => alternative.build(ctx).unwrap().value,
+            };
+            terms.push(LocatedQueryTerm { value, positions: positions.clone() });
+        }
+        Ok(terms)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use charabia::TokenizerBuilder;
@@ -399,7 +720,7 @@ mod tests {
         )?;
         // panics with `attempt to add with overflow` before <https://github.com/meilisearch/meilisearch/issues/3785>
         let ExtractedTokens { query_terms, .. } =
-            located_query_terms_from_tokens(&mut ctx, &tokenizer, tokens, None)?;
+            located_query_terms_from_tokens(&mut ctx, &tokenizer, tokens, None, None)?;
         assert!(query_terms.is_empty());
 
         Ok(())
@@ -105,6 +105,7 @@ pub enum RankingRuleId {
     Sort,
     GeoSort,
     VectorSort,
+    DynamicSearchRules,
     Asc(String),
     Desc(String),
 }
@@ -121,6 +122,7 @@ impl std::fmt::Display for RankingRuleId {
             RankingRuleId::Sort => write!(f, "sort"),
             RankingRuleId::GeoSort => write!(f, "geo_sort"),
             RankingRuleId::VectorSort => write!(f, "vector_sort"),
+            RankingRuleId::DynamicSearchRules => write!(f, "dynamic_search_rules"),
             RankingRuleId::Asc(field_name) => write!(f, "asc:{}", field_name),
             RankingRuleId::Desc(field_name) => write!(f, "desc:{}", field_name),
         }
@@ -0,0 +1,129 @@
+use std::task::Poll;
+
+use roaring::RoaringBitmap;
+
+use super::ranking_rules::{RankingRule, RankingRuleOutput, RankingRuleQueryTrait};
+use crate::dynamic_search_rules::RuleTiers;
+use crate::score_details::{Rank, RuleTier, ScoreDetails};
+use crate::search::new::ranking_rules::RankingRuleId;
+use crate::{Deadline, Result, SearchContext, SearchLogger};
+
+/// A ranking rule that applies the boost and bury actions of the dynamic search rules.
+///
+/// It produces the following disjoint buckets, in order:
+///
+/// 1. One bucket per boosting rule, by ascending precedence.
+/// 2. The documents from the universe that no rule boosted or buried.
+/// 3. One bucket per burying rule, by descending precedence.
+///
+/// This ranking rule is only added in front of the other ranking rules when a boost or bury action applies.
+pub struct DynamicSearchRulesTiers<Q: RankingRuleQueryTrait> {
+    buckets: Vec<(RuleTier, Option<RoaringBitmap>)>,
+    state: Option<(Q, usize)>,
+}
+
+impl<Q: RankingRuleQueryTrait> DynamicSearchRulesTiers<Q> {
+    pub fn new(tiers: RuleTiers) -> Self {
+        let RuleTiers { boosted, buried } = tiers;
+
+        let boosted = boosted.into_iter().map(|docs| {
+            (
+                RuleTier::Boosted { rule: docs.rule, precedence: docs.precedence.0 },
+                Some(docs.docids),
+            )
+        });
+        // the rule with the lowest precedence number buries its documents the deepest
+        let buried = buried.into_iter().rev().map(|docs| {
+            (RuleTier::Buried { rule: docs.rule, precedence: docs.precedence.0 }, Some(docs.docids))
+        });
+
+        let buckets =
+            boosted.chain(std::iter::once((RuleTier::Organic, None))).chain(buried).collect();
+
+        Self { buckets, state: None }
+    }
+
+    fn bucket(&mut self, universe: &RoaringBitmap) -> Option<RankingRuleOutput<Q>> {
+        let (query, next_bucket) = self.state.as_mut()?;
+        let max_rank = self.buckets.len() as u32;
+
+        let (tier, rank, candidates) = loop {
+            let (tier, docids) = self.buckets.get(*next_bucket)?;
+            let rank = max_rank - *next_bucket as u32;
+            *next_bucket += 1;
+
+            let candidates = match docids {
+                Some(docids) => docids & universe,
+                // the organic bucket contains everything that is not in the remaining tiers
+                None => {
+                    let mut candidates = universe.clone();
+                    for (_, docids) in &self.buckets[*next_bucket..] {
+                        if let Some(docids) = docids {
+                            candidates -= docids;
+                        }
+                    }
+                    candidates
+                }
+            };
+
+            if !candidates.is_empty() {
+                break (tier.clone(), rank, candidates);
+            }
+        };
+
+        Some(RankingRuleOutput {
+            query: query.clone(),
+            candidates,
+            score: ScoreDetails::Rule { tier, rank: Rank { rank, max_rank } },
+        })
+    }
+}
+
+impl<'ctx, Q: RankingRuleQueryTrait> RankingRule<'ctx, Q> for DynamicSearchRulesTiers<Q> {
+    fn id(&self) -> RankingRuleId {
+        RankingRuleId::DynamicSearchRules
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::dynamic_search_rules")]
+    fn start_iteration(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Q>,
+        _universe: &RoaringBitmap,
+        query: &Q,
+        _deadline: &Deadline,
+    ) -> Result<()> {
+        self.state = Some((query.clone(), 0));
+        Ok(())
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::dynamic_search_rules")]
+    fn next_bucket(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Q>,
+        universe: &RoaringBitmap,
+        _deadline: &Deadline,
+    ) -> Result<Option<RankingRuleOutput<Q>>> {
+        Ok(self.bucket(universe))
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::dynamic_search_rules")]
+    fn non_blocking_next_bucket(
+        &mut self,
+        _ctx: &mut SearchContext<'ctx>,
+        _logger: &mut dyn SearchLogger<Q>,
+        universe: &RoaringBitmap,
+    ) -> Result<Poll<RankingRuleOutput<Q>>> {
+        // computing the buckets only intersects bitmaps, so this never blocks
+        Ok(match self.bucket(universe) {
+            Some(bucket) => Poll::Ready(bucket),
+            None => Poll::Pending,
+        })
+    }
+
+    #[tracing::instrument(level = "trace", skip_all, target = "search::dynamic_search_rules")]
+    fn end_iteration(&mut self, _ctx: &mut SearchContext<'ctx>, _logger: &mut dyn SearchLogger<Q>) {
+        self.state = None;
+    }
+}
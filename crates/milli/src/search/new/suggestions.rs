@@ -0,0 +1,145 @@
+use roaring::RoaringBitmap;
+
+use super::query_term::{LocatedQueryTerm, QueryTermSubset};
+use super::resolve_query_graph::compute_query_term_subset_docids;
+use super::{SearchContext, Word};
+use crate::Result;
+
+/// Maximum number of alternatives kept for a single term of the query.
+const MAX_ALTERNATIVES_PER_TERM: usize = 5;
+
+/// Maximum number of partial queries kept while combining the alternatives of the terms.
+const MAX_PARTIAL_QUERIES: usize = 20;
+
+/// An alternative to the search query, where some words were replaced by their typo derivations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuerySuggestion {
+    /// The suggested query, made of the normalized words of the original query and of their corrections.
+    pub query: String,
+    /// The number of documents containing all the words of the suggested query.
+    pub estimated_hits: u64,
+    /// The total number of typos corrected from the original query.
+    pub typos: u8,
+}
+
+struct Alternative {
+    text: String,
+    typos: u8,
+    docids: RoaringBitmap,
+}
+
+/// Orders the alternatives by ascending number of typos, then by descending number of documents.
+fn sort_alternatives(alternatives: &mut [Alternative]) {
+    alternatives.sort_by(|left, right| {
+        left.typos.cmp(&right.typos).then_with(|| right.docids.len().cmp(&left.docids.len()))
+    });
+}
+
+/// Computes the queries closest to the original one that only contain words of the index.
+///
+/// Each term is replaced by its derivations with one or two typos, as allowed by the typo tolerance
+/// settings, or by its split words. The suggestions are ordered by number of corrected typos, then by
+/// number of documents matching all their words within the universe.
+pub fn compute_suggestions(
+    ctx: &mut SearchContext<'_>,
+    located_query_terms: &[LocatedQueryTerm],
+    universe: &RoaringBitmap,
+    limit: usize,
+) -> Result<Vec<QuerySuggestion>> {
+    if limit == 0 || located_query_terms.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut partial_queries =
+        vec![Alternative { text: String::new(), typos: 0, docids: universe.clone() }];
+
+    for located_term in located_query_terms {
+        let alternatives = term_alternatives(ctx, located_term, universe)?;
+
+        let mut next_partial_queries = Vec::new();
+        for partial_query in &partial_queries {
+            for alternative in &alternatives {
+                let docids = &partial_query.docids & &alternative.docids;
+                if docids.is_empty() {
+                    continue;
+                }
+                let text = if partial_query.text.is_empty() {
+                    alternative.text.clone()
+                } else {
+                    format!("{} {}", partial_query.text, alternative.text)
+                };
+                let typos = partial_query.typos.saturating_add(alternative.typos);
+                next_partial_queries.push(Alternative { text, typos, docids });
+            }
+        }
+
+        sort_alternatives(&mut next_partial_queries);
+        next_partial_queries.truncate(MAX_PARTIAL_QUERIES);
+        partial_queries = next_partial_queries;
+
+        if partial_queries.is_empty() {
+            break;
+        }
+    }
+
+    Ok(partial_queries
+        .into_iter()
+        // a query without typos is the original query
+        .filter(|query| query.typos > 0)
+        .take(limit)
+        .map(|Alternative { text, typos, docids }| QuerySuggestion {
+            query: text,
+            estimated_hits: docids.len(),
+            typos,
+        })
+        .collect())
+}
+
+/// Returns the best alternatives to a term: the term itself, its typo derivations and its split words.
+fn term_alternatives(
+    ctx: &mut SearchContext<'_>,
+    located_term: &LocatedQueryTerm,
+    universe: &RoaringBitmap,
+) -> Result<Vec<Alternative>> {
+    located_term.value.compute_fully_if_needed(ctx)?;
+
+    let mut alternatives = Vec::new();
+
+    // the term as written by the user, including its prefix derivations and its synonyms
+    let mut zero_typo_subset = QueryTermSubset::full(located_term.value);
+    zero_typo_subset.clear_one_typo_subset();
+    zero_typo_subset.clear_two_typo_subset();
+    let docids = compute_query_term_subset_docids(ctx, Some(universe), &zero_typo_subset)?;
+    let term = ctx.term_interner.get(located_term.value);
+    let text = match term.original_phrase() {
+        Some(phrase) => format!("\"{}\"", phrase.description(ctx)),
+        None => term.original_word(ctx),
+    };
+    if !docids.is_empty() {
+        alternatives.push(Alternative { text, typos: 0, docids });
+    }
+
+    let (words, split_words) = term.typo_derivations();
+    for (word, typos) in words {
+        let Some(docids) = ctx.word_docids(Some(universe), Word::Derived(word))? else {
+            continue;
+        };
+        if docids.is_empty() {
+            continue;
+        }
+        let text = ctx.word_interner.get(word).clone();
+        alternatives.push(Alternative { text, typos, docids });
+    }
+
+    if let Some(split_words) = split_words {
+        let docids = ctx.get_phrase_docids(split_words)? & universe;
+        if !docids.is_empty() {
+            let text = split_words.description(ctx);
+            alternatives.push(Alternative { text, typos: 1, docids });
+        }
+    }
+
+    sort_alternatives(&mut alternatives);
+    alternatives.truncate(MAX_ALTERNATIVES_PER_TERM);
+    Ok(alternatives)
+}
@@ -0,0 +1,160 @@
+/*!
+This module tests the advanced query syntax:
+
+1. `attribute:word` only matches the documents containing the word in the attribute
+2. `attribute:"a phrase"` only matches the documents containing the phrase in the attribute
+3. `(word OR word)` matches the documents containing any of the alternatives
+4. `attribute:(word OR word)` scopes all the alternatives to the attribute
+5. `-attribute:word` excludes the word from every attribute
+6. An unknown attribute is searched as regular text
+7. The operators are regular text when the advanced syntax is disabled
+*/
+
+use crate::index::tests::TempIndex;
+use crate::search::new::tests::collect_field_values;
+use crate::{Criterion, SearchResult, TermsMatchingStrategy};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "description".to_owned()]);
+            s.set_criteria(vec![Criterion::Words]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            {
+                "id": 0,
+                "title": "red shoes",
+                "description": "comfortable running shoes"
+            },
+            {
+                "id": 1,
+                "title": "running shoes",
+                "description": "red and blue"
+            },
+            {
+                "id": 2,
+                "title": "nike running shoes",
+                "description": "lightweight"
+            },
+            {
+                "id": 3,
+                "title": "adidas sneakers",
+                "description": "made for running"
+            },
+            {
+                "id": 4,
+                "title": "puma boots",
+                "description": "nike inspired"
+            }
+        ]))
+        .unwrap();
+    index
+}
+
+fn search(index: &TempIndex, query: &str, advanced_query_syntax: bool) -> Vec<u32> {
+    let txn = index.read_txn().unwrap();
+    let fields_ids_map = index.fields_ids_map(&txn).unwrap();
+    let mut s = index.search(&txn, &fields_ids_map);
+    s.terms_matching_strategy(TermsMatchingStrategy::All);
+    s.advanced_query_syntax(advanced_query_syntax);
+    s.query(query);
+    let SearchResult { documents_ids, .. } = s.execute().unwrap();
+    documents_ids
+}
+
+#[test]
+fn field_scoped_word() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let documents_ids = search(&index, "title:red", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+    let texts = collect_field_values(&index, &txn, "title", &documents_ids);
+    insta::assert_debug_snapshot!(texts, @r###"
+    [
+        "\"red shoes\"",
+    ]
+    "###);
+
+    let documents_ids = search(&index, "description:red shoes", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[1]");
+}
+
+#[test]
+fn field_scoped_phrase() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "title:\"red shoes\"", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+
+    let documents_ids = search(&index, "description:\"running shoes\"", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0]");
+}
+
+#[test]
+fn or_group() {
+    let index = create_index();
+    let txn = index.read_txn().unwrap();
+
+    let documents_ids = search(&index, "(nike OR adidas) running", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 3]");
+    let texts = collect_field_values(&index, &txn, "title", &documents_ids);
+    insta::assert_debug_snapshot!(texts, @r###"
+    [
+        "\"nike running shoes\"",
+        "\"adidas sneakers\"",
+    ]
+    "###);
+
+    // an alternative of several words is a phrase
+    let documents_ids = search(&index, "(red shoes OR made for) running", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 3]");
+}
+
+#[test]
+fn field_scoped_or_group() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "title:(nike OR puma)", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 4]");
+
+    let documents_ids = search(&index, "description:(nike OR lightweight)", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 4]");
+
+    let documents_ids = search(&index, "title:(nike OR adidas)", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[2, 3]");
+}
+
+#[test]
+fn negative_field_scoped_word() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "-title:nike shoes", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[0, 1]");
+}
+
+#[test]
+fn unknown_attribute() {
+    let index = create_index();
+
+    // `brand` is not a searchable attribute, so it is a regular word of the query
+    let documents_ids = search(&index, "brand:nike", true);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+}
+
+#[test]
+fn advanced_syntax_disabled() {
+    let index = create_index();
+
+    let documents_ids = search(&index, "title:red", false);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+
+    let documents_ids = search(&index, "(nike OR adidas) running", false);
+    insta::assert_snapshot!(format!("{documents_ids:?}"), @"[]");
+}
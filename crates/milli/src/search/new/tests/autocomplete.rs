@@ -0,0 +1,173 @@
+/*!
+This module tests the completions of the last word of a query:
+
+1. The words starting with the last word are ordered by number of documents
+2. The previous words of the query restrict the documents considered
+3. The filter restricts the documents considered
+4. The searchable attributes restrict the documents considered
+5. The number of completions is limited
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Autocomplete, Completion, Filter, FilterableAttributesRule, IndexFilter};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned(), "description".to_owned()]);
+            s.set_filterable_fields(vec![FilterableAttributesRule::Field("genre".to_owned())]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "the dragon reborn", "description": "a dark tale", "genre": "fantasy" },
+            { "id": 1, "title": "dragonheart", "description": "a knight and a dragon", "genre": "fantasy" },
+            { "id": 2, "title": "the dragon riders", "description": "dramatic battles", "genre": "fantasy" },
+            { "id": 3, "title": "drama queen", "description": "a dragon story", "genre": "comedy" },
+            { "id": 4, "title": "dreams", "description": "a dramatic dream", "genre": "drama" }
+        ]))
+        .unwrap();
+    index
+}
+
+fn complete(
+    index: &TempIndex,
+    query: &str,
+    limit: usize,
+    filter: Option<&str>,
+    searchable_attributes: Option<&[String]>,
+) -> Vec<(String, u64)> {
+    let txn = index.read_txn().unwrap();
+    let fields_ids_map = index.fields_ids_map(&txn).unwrap();
+    let mut autocomplete =
+        Autocomplete::new(query.to_owned(), index, &txn, &fields_ids_map, "test", &index.progress);
+    autocomplete.limit(limit);
+    if let Some(filter) = filter {
+        autocomplete.filter(IndexFilter::from(Filter::from_str(filter).unwrap().unwrap()));
+    }
+    if let Some(searchable_attributes) = searchable_attributes {
+        autocomplete.searchable_attributes(searchable_attributes);
+    }
+    autocomplete
+        .execute()
+        .unwrap()
+        .into_iter()
+        .map(|Completion { value, count }| (value, count))
+        .collect()
+}
+
+#[test]
+fn completions_are_ordered_by_count() {
+    let index = create_index();
+
+    let completions = complete(&index, "dra", 10, None, None);
+    insta::assert_debug_snapshot!(completions, @r###"
+    [
+        (
+            "dragon",
+            4,
+        ),
+        (
+            "dramatic",
+            2,
+        ),
+        (
+            "dragonheart",
+            1,
+        ),
+        (
+            "drama",
+            1,
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn previous_words_restrict_the_documents() {
+    let index = create_index();
+
+    let completions = complete(&index, "the dra", 10, None, None);
+    insta::assert_debug_snapshot!(completions, @r###"
+    [
+        (
+            "dragon",
+            2,
+        ),
+        (
+            "dramatic",
+            1,
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn filter_restricts_the_documents() {
+    let index = create_index();
+
+    let completions = complete(&index, "dra", 10, Some("genre = fantasy"), None);
+    insta::assert_debug_snapshot!(completions, @r###"
+    [
+        (
+            "dragon",
+            3,
+        ),
+        (
+            "dragonheart",
+            1,
+        ),
+        (
+            "dramatic",
+            1,
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn searchable_attributes_restrict_the_documents() {
+    let index = create_index();
+
+    let title = vec!["title".to_owned()];
+    let completions = complete(&index, "dra", 10, None, Some(&title));
+    insta::assert_debug_snapshot!(completions, @r###"
+    [
+        (
+            "dragon",
+            2,
+        ),
+        (
+            "dragonheart",
+            1,
+        ),
+        (
+            "drama",
+            1,
+        ),
+    ]
+    "###);
+}
+
+#[test]
+fn completions_are_limited() {
+    let index = create_index();
+
+    let completions = complete(&index, "dr", 2, None, None);
+    insta::assert_debug_snapshot!(completions, @r###"
+    [
+        (
+            "dragon",
+            4,
+        ),
+        (
+            "dramatic",
+            2,
+        ),
+    ]
+    "###);
+}
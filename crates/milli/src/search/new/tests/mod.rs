@@ -1,4 +1,6 @@
+pub mod advanced_query_syntax;
 pub mod attribute_fid;
+pub mod autocomplete;
 pub mod cutoff;
 pub mod distinct;
 pub mod exactness;
@@ -12,6 +14,7 @@ pub mod proximity;
 pub mod proximity_typo;
 pub mod sort;
 pub mod stop_words;
+pub mod suggestions;
 pub mod typo;
 pub mod typo_proximity;
 pub mod word_position;
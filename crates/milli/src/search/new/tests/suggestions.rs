@@ -0,0 +1,112 @@
+/*!
+This module tests the computation of the query suggestions:
+
+1. A misspelled word is replaced by its typo derivations that exist in the index
+2. The typos of all the corrected words are summed
+3. A query without typos has no suggestions
+4. No suggestions are computed when the typo tolerance is disabled
+*/
+
+use crate::index::tests::TempIndex;
+use crate::{Criterion, QuerySuggestion, SearchResult};
+
+fn create_index() -> TempIndex {
+    let index = TempIndex::new();
+
+    index
+        .update_settings(|s| {
+            s.set_primary_key("id".to_owned());
+            s.set_searchable_fields(vec!["title".to_owned()]);
+            s.set_criteria(vec![Criterion::Words, Criterion::Typo]);
+        })
+        .unwrap();
+
+    index
+        .add_documents(documents!([
+            { "id": 0, "title": "the hobbit and the dragon" },
+            { "id": 1, "title": "the dragon reborn" },
+            { "id": 2, "title": "a dragon in the hobbit" },
+            { "id": 3, "title": "the wagon of the hobbit" },
+            { "id": 4, "title": "the lord of the rings" }
+        ]))
+        .unwrap();
+    index
+}
+
+fn get_suggestions(index: &TempIndex, query: &str, limit: usize) -> Vec<QuerySuggestion> {
+    let txn = index.read_txn().unwrap();
+    let fields_ids_map = index.fields_ids_map(&txn).unwrap();
+    let mut s = index.search(&txn, &fields_ids_map);
+    s.query(query);
+    s.suggestions(limit);
+    let SearchResult { suggestions, .. } = s.execute().unwrap();
+    suggestions
+}
+
+#[test]
+fn misspelled_words_are_corrected() {
+    let index = create_index();
+
+    let suggestions = get_suggestions(&index, "hobit dragn", 3);
+    insta::assert_debug_snapshot!(suggestions, @r###"
+    [
+        QuerySuggestion {
+            query: "hobbit dragon",
+            estimated_hits: 2,
+            typos: 2,
+        },
+    ]
+    "###);
+}
+
+#[test]
+fn typos_are_counted_per_word() {
+    let index = create_index();
+
+    let suggestions = get_suggestions(&index, "dragun", 3);
+    insta::assert_debug_snapshot!(suggestions, @r###"
+    [
+        QuerySuggestion {
+            query: "dragon",
+            estimated_hits: 3,
+            typos: 1,
+        },
+    ]
+    "###);
+
+    let suggestions = get_suggestions(&index, "hobit dragun", 3);
+    insta::assert_debug_snapshot!(suggestions, @r###"
+    [
+        QuerySuggestion {
+            query: "hobbit dragon",
+            estimated_hits: 2,
+            typos: 2,
+        },
+    ]
+    "###);
+}
+
+#[test]
+fn correct_query_has_no_suggestions() {
+    let index = create_index();
+
+    let suggestions = get_suggestions(&index, "hobbit dragon", 3);
+    insta::assert_debug_snapshot!(suggestions, @"[]");
+
+    // the suggestions are only computed when requested
+    let suggestions = get_suggestions(&index, "hobit dragn", 0);
+    insta::assert_debug_snapshot!(suggestions, @"[]");
+}
+
+#[test]
+fn no_suggestions_without_typo_tolerance() {
+    let index = create_index();
+    index
+        .update_settings(|s| {
+            s.set_authorize_typos(false);
+        })
+        .unwrap();
+
+    let suggestions = get_suggestions(&index, "hobit dragn", 3);
+    insta::assert_debug_snapshot!(suggestions, @"[]");
+}
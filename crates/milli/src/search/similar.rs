@@ -149,6 +149,8 @@ impl<'a> Similar<'a> {
             degraded: false,
             used_negative_operator: false,
             query_vector: None,
+            applied_rules: Vec::new(),
+            suggestions: Vec::new(),
         })
     }
 }
@@ -12,7 +12,10 @@ make_enum_progress! {
         SemanticRanking,
         Format,
         FacetDistribution,
+        FacetAggregation,
         Personalization,
+        ComputeSuggestions,
+        ComputeCompletions,
     }
 }
 
@@ -34,5 +37,6 @@ make_enum_progress! {
         WaitInQueue,
         Search,
         Similar,
+        Autocomplete,
     }
 }
@@ -2080,6 +2080,7 @@ fn test_multiple_vectors() {
                     model: Setting::NotSet,
                     revision: Setting::NotSet,
                     pooling: Setting::NotSet,
+                    model_path: Setting::NotSet,
                     api_key: Setting::NotSet,
                     dimensions: Setting::Set(3),
                     document_template: Setting::NotSet,
@@ -7,6 +7,7 @@ pub use self::facet::incremental::FacetsUpdateIncrementalInner;
 pub use self::index_documents::{request_threads, *};
 pub use self::indexer_config::{default_thread_pool_and_threads, IndexerConfig, S3SnapshotOptions};
 pub use self::new::ChannelCongestion;
+pub use self::reranker::RerankerSettings;
 pub use self::settings::{
     validate_embedding_settings, FragmentDiff, InnerIndexSettings, InnerIndexSettingsDiff, Setting,
     Settings, SettingsDelta,
@@ -25,6 +26,7 @@ pub(crate) mod facet;
 mod index_documents;
 mod indexer_config;
 pub mod new;
+mod reranker;
 pub(crate) mod settings;
 mod update_step;
 pub mod upgrade;
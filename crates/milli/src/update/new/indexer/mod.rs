@@ -776,12 +776,14 @@ where
         }
 
         // Check if the field still needs facet level databases
-        let FilterFeatures { equality: _, comparison: old_comparison } = old_filter;
+        let FilterFeatures { equality: _, comparison: old_comparison, pattern_matching: _ } =
+            old_filter;
         let old_asc_desc = old_metadata.is_asc_desc() == PatternMatch::Match;
         let old_sortable = old_metadata.is_sortable() == PatternMatch::Match;
         let is_old_comparison = old_sortable || old_asc_desc || old_comparison;
 
-        let FilterFeatures { equality: _, comparison: new_comparison } = new_filter;
+        let FilterFeatures { equality: _, comparison: new_comparison, pattern_matching: _ } =
+            new_filter;
         let new_asc_desc = new_metadata.is_asc_desc() == PatternMatch::Match;
         let new_sortable = new_metadata.is_sortable() == PatternMatch::Match;
         let is_new_comparison = new_sortable || new_asc_desc || new_comparison;
@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+
+use deserr::errors::JsonError;
+
+use crate::reranker::{RerankerConfig, DEFAULT_RERANKER_TIMEOUT_MS};
+use crate::update::Setting;
+
+/// Remote reranker used to [personalize](https://github.com/orgs/meilisearch/discussions/866) the search results of this index.
+///
+/// Any HTTP reranker can be used by describing the shape of its request and response.
+#[routes::request(setting, override_error = JsonError)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RerankerSettings {
+    /// URL the reranking requests are sent to with the `POST` method.
+    ///
+    /// If targetting URL resolving to a non-global IP (such as `localhost`), make sure that
+    /// `--experimental-allowed-ip-networks` allows it.
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<String>, example = json!("http://localhost:8080/rerank")
+    )]
+    pub url: Setting<String>,
+
+    /// Bearer token sent in the `Authorization` header. This setting is partially hidden when returned by the settings.
+    #[request(default, skip_serializing_if = "Setting::is_not_set", schema_type = Option<String>)]
+    pub api_key: Setting<String>,
+
+    /// Template of the request body.
+    ///
+    /// - Use `"{{query}}"` for the query and the user context.
+    /// - Use `["{{document}}", "{{..}}"]` for the list of documents to rerank.
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<serde_json::Value>,
+        example = json!({ "query": "{{query}}", "texts": ["{{document}}", "{{..}}"] })
+    )]
+    pub request: Setting<serde_json::Value>,
+
+    /// Template of the response body.
+    ///
+    /// - Use `[{ "index": "{{index}}" }, "{{..}}"]` when the reranker returns the positions of the documents, most relevant first.
+    /// - Use `["{{score}}", "{{..}}"]` when the reranker returns one score per document, in the order of the request.
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<serde_json::Value>,
+        example = json!([{ "index": "{{index}}" }, "{{..}}"])
+    )]
+    pub response: Setting<serde_json::Value>,
+
+    /// Additional headers sent with each request.
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<BTreeMap<String, String>>
+    )]
+    pub headers: Setting<BTreeMap<String, String>>,
+
+    /// Duration in milliseconds after which a request to the reranker is abandoned. Defaults to 30000.
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<u64>,
+        example = json!(5000)
+    )]
+    pub timeout_ms: Setting<u64>,
+
+    /// Fields of the documents sent to the reranker. When null, all the fields of the documents are sent.
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<Vec<String>>,
+        example = json!(["title", "overview"])
+    )]
+    pub document_fields: Setting<Vec<String>>,
+}
+
+impl From<RerankerConfig> for RerankerSettings {
+    fn from(config: RerankerConfig) -> Self {
+        let RerankerConfig {
+            url,
+            api_key,
+            request,
+            response,
+            headers,
+            timeout_ms,
+            document_fields,
+        } = config;
+        RerankerSettings {
+            url: Setting::Set(url),
+            api_key: Setting::some_or_not_set(api_key),
+            request: Setting::Set(request),
+            response: Setting::Set(response),
+            headers: Setting::Set(headers),
+            timeout_ms: Setting::Set(timeout_ms.unwrap_or(DEFAULT_RERANKER_TIMEOUT_MS)),
+            document_fields: match document_fields {
+                Some(fields) => Setting::Set(fields),
+                None => Setting::Reset,
+            },
+        }
+    }
+}
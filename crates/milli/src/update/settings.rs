@@ -13,7 +13,7 @@ use time::OffsetDateTime;
 use super::chat::ChatSearchParams;
 use super::del_add::{DelAdd, DelAddOperation};
 use super::index_documents::{IndexDocumentsConfig, Transform};
-use super::{ChatSettings, IndexerConfig};
+use super::{ChatSettings, IndexerConfig, RerankerSettings};
 use crate::attribute_patterns::{match_field_legacy, PatternMatch};
 use crate::constants::{
     RESERVED_GEOJSON_FIELD_NAME, RESERVED_GEO_FIELD_NAME, RESERVED_GEO_LAT_FIELD_NAME,
@@ -32,12 +32,13 @@ use crate::order_by_map::OrderByMap;
 use crate::progress::{EmbedderStats, Progress};
 use crate::prompt::{default_max_bytes, default_template_text, Prompt, PromptData};
 use crate::proximity::ProximityPrecision;
+use crate::reranker::{RerankerConfig, RerankerTemplates};
 use crate::update::index_documents::IndexDocumentsMethod;
 use crate::update::new::indexer::{reindex, update_index};
 use crate::update::new::steps::SettingsIndexerStep;
 use crate::update::{IndexDocuments, UpdateIndexingStep};
 use crate::vector::db::{FragmentConfigs, IndexEmbeddingConfig};
-use crate::vector::embedder::{openai, rest};
+use crate::vector::embedder::{hf, openai, rest};
 use crate::vector::json_template::JsonTemplate;
 use crate::vector::settings::{
     EmbedderAction, EmbedderSource, EmbeddingSettings, EmbeddingValidationContext, NestingContext,
@@ -200,6 +201,7 @@ pub struct Settings<'a, 't, 'i> {
     prefix_search: Setting<PrefixSearch>,
     facet_search: Setting<bool>,
     chat: Setting<ChatSettings>,
+    reranker: Setting<RerankerSettings>,
 }
 
 impl<'a, 't, 'i> Settings<'a, 't, 'i> {
@@ -240,6 +242,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
             prefix_search: Setting::NotSet,
             facet_search: Setting::NotSet,
             chat: Setting::NotSet,
+            reranker: Setting::NotSet,
             indexer_config,
         }
     }
@@ -486,6 +489,14 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.chat = Setting::Reset;
     }
 
+    pub fn set_reranker(&mut self, value: RerankerSettings) {
+        self.reranker = Setting::Set(value);
+    }
+
+    pub fn reset_reranker(&mut self) {
+        self.reranker = Setting::Reset;
+    }
+
     #[tracing::instrument(
         level = "trace"
         skip(self, progress_callback, should_abort, settings_diff, embedder_stats),
@@ -1457,6 +1468,79 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         }
     }
 
+    fn update_reranker_config(&mut self) -> Result<bool> {
+        match &self.reranker {
+            Setting::Set(RerankerSettings {
+                url: new_url,
+                api_key: new_api_key,
+                request: new_request,
+                response: new_response,
+                headers: new_headers,
+                timeout_ms: new_timeout_ms,
+                document_fields: new_document_fields,
+            }) => {
+                let old = self.index.reranker_config(self.wtxn)?;
+                let RerankerConfig {
+                    url,
+                    api_key,
+                    request,
+                    response,
+                    headers,
+                    timeout_ms,
+                    document_fields,
+                } = old.clone().unwrap_or_default();
+
+                let config = RerankerConfig {
+                    url: match new_url {
+                        Setting::Set(url) => url.clone(),
+                        Setting::Reset => String::new(),
+                        Setting::NotSet => url,
+                    },
+                    api_key: match new_api_key {
+                        Setting::Set(api_key) => Some(api_key.clone()),
+                        Setting::Reset => None,
+                        Setting::NotSet => api_key,
+                    },
+                    request: match new_request {
+                        Setting::Set(request) => request.clone(),
+                        Setting::Reset => serde_json::Value::Null,
+                        Setting::NotSet => request,
+                    },
+                    response: match new_response {
+                        Setting::Set(response) => response.clone(),
+                        Setting::Reset => serde_json::Value::Null,
+                        Setting::NotSet => response,
+                    },
+                    headers: match new_headers {
+                        Setting::Set(headers) => headers.clone(),
+                        Setting::Reset => BTreeMap::new(),
+                        Setting::NotSet => headers,
+                    },
+                    timeout_ms: match new_timeout_ms {
+                        Setting::Set(timeout_ms) => Some(*timeout_ms),
+                        Setting::Reset => None,
+                        Setting::NotSet => timeout_ms,
+                    },
+                    document_fields: match new_document_fields {
+                        Setting::Set(fields) => Some(fields.clone()),
+                        Setting::Reset => None,
+                        Setting::NotSet => document_fields,
+                    },
+                };
+
+                validate_reranker_config(&config).map_err(UserError::InvalidSettingsReranker)?;
+
+                if old.as_ref() == Some(&config) {
+                    return Ok(false);
+                }
+                self.index.put_reranker_config(self.wtxn, &config)?;
+                Ok(true)
+            }
+            Setting::Reset => self.index.delete_reranker_config(self.wtxn).map_err(Into::into),
+            Setting::NotSet => Ok(false),
+        }
+    }
+
     fn legacy_execute<FP>(
         mut self,
         progress_callback: FP,
@@ -1502,6 +1586,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.update_localized_attributes_rules()?;
         self.update_disabled_typos_terms()?;
         self.update_chat_config()?;
+        self.update_reranker_config()?;
 
         let embedding_config_updates = self.update_embedding_configs()?;
 
@@ -1584,6 +1669,7 @@ impl<'a, 't, 'i> Settings<'a, 't, 'i> {
         self.update_pagination_max_total_hits()?;
         self.update_search_cutoff()?;
         self.update_chat_config()?;
+        self.update_reranker_config()?;
         self.update_facet_search()?;
         self.update_prefix_search()?;
         self.update_exact_words()?;
@@ -2164,6 +2250,27 @@ fn embedders(
     res.map(RuntimeEmbedders::new)
 }
 
+/// Checks that a reranker configuration can be used to send requests to the reranker.
+fn validate_reranker_config(config: &RerankerConfig) -> StdResult<(), String> {
+    if config.url.is_empty() {
+        return Err("missing field `url`".to_string());
+    }
+    if let Err(error) = url::Url::parse(&config.url) {
+        return Err(format!("could not parse `{}`: {error}", config.url));
+    }
+    if config.request.is_null() {
+        return Err("missing field `request`".to_string());
+    }
+    if config.response.is_null() {
+        return Err("missing field `response`".to_string());
+    }
+    if config.timeout_ms == Some(0) {
+        return Err("`timeoutMs` cannot be zero".to_string());
+    }
+    RerankerTemplates::new(config)?;
+    Ok(())
+}
+
 fn validate_prompt(
     name: &str,
     new_prompt: Setting<String>,
@@ -2206,6 +2313,7 @@ pub fn validate_embedding_settings(
         model,
         revision,
         pooling,
+        model_path,
         api_key,
         dimensions,
         document_template,
@@ -2342,6 +2450,7 @@ pub fn validate_embedding_settings(
             model,
             revision,
             pooling,
+            model_path,
             api_key,
             dimensions,
             document_template,
@@ -2365,6 +2474,7 @@ pub fn validate_embedding_settings(
         &model,
         &revision,
         &pooling,
+        &model_path,
         &dimensions,
         &api_key,
         &url,
@@ -2413,10 +2523,19 @@ pub fn validate_embedding_settings(
                 }
             }
         }
-        EmbedderSource::Ollama
-        | EmbedderSource::HuggingFace
-        | EmbedderSource::UserProvided
-        | EmbedderSource::Rest => {}
+        EmbedderSource::HuggingFace => {
+            if let Setting::Set(model_path) = &model_path {
+                if let Setting::Set(_) = revision {
+                    return Err(crate::UserError::VectorEmbeddingError(
+                        crate::vector::error::NewEmbedderError::local_model_with_revision().into(),
+                    )
+                    .into());
+                }
+                hf::validate_model_path(model_path)
+                    .map_err(|error| crate::UserError::VectorEmbeddingError(error.into()))?;
+            }
+        }
+        EmbedderSource::Ollama | EmbedderSource::UserProvided | EmbedderSource::Rest => {}
         EmbedderSource::Composite => {
             if let Setting::Set(embedder) = &search_embedder {
                 if let Some(source) = embedder.source.set() {
@@ -2446,6 +2565,7 @@ pub fn validate_embedding_settings(
                         &embedder.model,
                         &embedder.revision,
                         &embedder.pooling,
+                        &embedder.model_path,
                         &embedder.dimensions,
                         &embedder.api_key,
                         &embedder.url,
@@ -2503,6 +2623,7 @@ pub fn validate_embedding_settings(
                         &embedder.model,
                         &embedder.revision,
                         &embedder.pooling,
+                        &embedder.model_path,
                         &embedder.dimensions,
                         &embedder.api_key,
                         &embedder.url,
@@ -2535,6 +2656,7 @@ pub fn validate_embedding_settings(
         model,
         revision,
         pooling,
+        model_path,
         api_key,
         dimensions,
         document_template,
@@ -918,6 +918,7 @@ fn test_correct_settings_init() {
                 facet_search,
                 disable_on_numbers,
                 chat,
+                reranker,
             } = settings;
             assert!(matches!(searchable_fields, Setting::NotSet));
             assert!(matches!(displayed_fields, Setting::NotSet));
@@ -948,6 +949,7 @@ fn test_correct_settings_init() {
             assert!(matches!(facet_search, Setting::NotSet));
             assert!(matches!(disable_on_numbers, Setting::NotSet));
             assert!(matches!(chat, Setting::NotSet));
+            assert!(matches!(reranker, Setting::NotSet));
         })
         .unwrap();
 }
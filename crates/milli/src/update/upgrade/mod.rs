@@ -99,5 +99,6 @@ pub fn upgrade(
 
 /// Indicates the range of version where the DSR index must be upgraded
 pub fn must_upgrade_dsr(initial_version: (u32, u32, u32)) -> Result<bool> {
-    Ok(((1, 50, 0)..(1, 51, 0)).contains(&initial_version))
+    // v1.52 made the query prefix and regex, locales and tenant token conditions filterable
+    Ok(((1, 50, 0)..(1, 52, 0)).contains(&initial_version))
 }
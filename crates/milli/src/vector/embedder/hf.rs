@@ -1,3 +1,5 @@
+use std::path::{Path, PathBuf};
+
 use candle_core::Tensor;
 use candle_nn::VarBuilder;
 use candle_transformers::models::bert::{BertModel, Config as BertConfig, DTYPE};
@@ -46,6 +48,9 @@ pub struct EmbedderOptions {
     pub distribution: Option<DistributionShift>,
     #[serde(default)]
     pub pooling: OverridePooling,
+    /// Absolute path to a local directory containing the model, used instead of fetching it from the hub.
+    #[serde(default)]
+    pub model_path: Option<String>,
 }
 
 #[routes::request(no_error, setting)]
@@ -64,6 +69,7 @@ impl EmbedderOptions {
             revision: Some("617ca489d9e86b49b8167676d8220688b99db36e".into()),
             distribution: None,
             pooling: OverridePooling::UseModel,
+            model_path: None,
         }
     }
 }
@@ -204,6 +210,105 @@ impl From<PoolingConfig> for Pooling {
     }
 }
 
+/// The files of a model, either downloaded from the hub or found in a local directory.
+struct ModelFiles {
+    config: PathBuf,
+    tokenizer: PathBuf,
+    weights: PathBuf,
+    weight_source: WeightSource,
+    /// The pooling configuration is optional, mean pooling is used when missing.
+    pooling: Option<PathBuf>,
+}
+
+impl ModelFiles {
+    fn from_hub(options: &EmbedderOptions) -> Result<Self, NewEmbedderError> {
+        let repo = match options.revision.clone() {
+            Some(revision) => Repo::with_revision(options.model.clone(), RepoType::Model, revision),
+            None => Repo::model(options.model.clone()),
+        };
+        let api = Api::new().map_err(NewEmbedderError::new_api_fail)?;
+        let api = api.repo(repo);
+        let config = api.get("config.json").map_err(NewEmbedderError::api_get)?;
+        let tokenizer = api.get("tokenizer.json").map_err(NewEmbedderError::api_get)?;
+        let (weights, weight_source) = {
+            api.get("model.safetensors")
+                .map(|filename| (filename, WeightSource::Safetensors))
+                .or_else(|_| {
+                    api.get("pytorch_model.bin").map(|filename| (filename, WeightSource::Pytorch))
+                })
+                .map_err(NewEmbedderError::api_get)?
+        };
+
+        let pooling = match api.get("1_Pooling/config.json") {
+            Ok(pooling) => Some(pooling),
+            Err(hf_hub::api::sync::ApiError::RequestError(error))
+                if matches!(*error, ureq::Error::StatusCode(404)) =>
+            {
+                // ignore the error if the file simply doesn't exist
+                None
+            }
+            Err(error) => return Err(NewEmbedderError::api_get(error)),
+        };
+
+        Ok(Self { config, tokenizer, weights, weight_source, pooling })
+    }
+
+    /// Finds the files of the model in the local directory, without any network access.
+    ///
+    /// The directory must be inside of the models directory set by the operator, and
+    /// is expected to follow the layout of a hub repository.
+    fn from_local_dir(model_path: &Path) -> Result<Self, NewEmbedderError> {
+        let path = local_model_dir(model_path)?;
+        let file = |name: &str| Some(path.join(name)).filter(|file| file.is_file());
+        let missing =
+            |name| NewEmbedderError::local_model_missing_file(model_path.to_path_buf(), name);
+
+        let config = file("config.json").ok_or_else(|| missing("`config.json`"))?;
+        let tokenizer = file("tokenizer.json").ok_or_else(|| missing("`tokenizer.json`"))?;
+        let (weights, weight_source) = file("model.safetensors")
+            .map(|weights| (weights, WeightSource::Safetensors))
+            .or_else(|| file("pytorch_model.bin").map(|weights| (weights, WeightSource::Pytorch)))
+            .ok_or_else(|| missing("`model.safetensors` or `pytorch_model.bin`"))?;
+        let pooling = file("1_Pooling/config.json");
+
+        Ok(Self { config, tokenizer, weights, weight_source, pooling })
+    }
+}
+
+/// The environment variable containing the directory the local models must be in.
+pub const MODELS_DIR_ENV_VAR: &str = "MEILI_EXPERIMENTAL_HUGGING_FACE_MODELS_DIR";
+
+/// Resolves the model path to a directory inside of the models directory.
+///
+/// The same error is returned whether the path doesn't exist or is outside of the models
+/// directory, so that the `modelPath` setting can't be used to probe the file system.
+fn local_model_dir(model_path: &Path) -> Result<PathBuf, NewEmbedderError> {
+    let Some(models_dir) = std::env::var_os(MODELS_DIR_ENV_VAR) else {
+        return Err(NewEmbedderError::local_models_dir_not_set());
+    };
+    if !model_path.is_absolute() {
+        return Err(NewEmbedderError::local_model_relative_path(model_path.to_path_buf()));
+    }
+
+    let not_found = || NewEmbedderError::local_model_not_found(model_path.to_path_buf());
+    let models_dir = Path::new(&models_dir);
+    if !model_path.starts_with(models_dir) {
+        return Err(not_found());
+    }
+    // resolve the `..` and the symbolic links before checking again that we are in the models directory
+    let models_dir = models_dir.canonicalize().map_err(|_| not_found())?;
+    let path = model_path.canonicalize().map_err(|_| not_found())?;
+    if !path.starts_with(&models_dir) || !path.is_dir() {
+        return Err(not_found());
+    }
+    Ok(path)
+}
+
+/// Checks that the local directory is inside of the models directory and contains the files of a model.
+pub fn validate_model_path(model_path: &str) -> Result<(), NewEmbedderError> {
+    ModelFiles::from_local_dir(Path::new(model_path)).map(|_| ())
+}
+
 impl Embedder {
     pub fn new(
         options: EmbedderOptions,
@@ -216,59 +321,37 @@ impl Embedder {
                 candle_core::Device::Cpu
             }
         };
-        let repo = match options.revision.clone() {
-            Some(revision) => Repo::with_revision(options.model.clone(), RepoType::Model, revision),
-            None => Repo::model(options.model.clone()),
+        let ModelFiles {
+            config: config_filename,
+            tokenizer: tokenizer_filename,
+            weights: weights_filename,
+            weight_source,
+            pooling,
+        } = match &options.model_path {
+            Some(model_path) => ModelFiles::from_local_dir(Path::new(model_path))?,
+            None => ModelFiles::from_hub(&options)?,
         };
-        let (config_filename, tokenizer_filename, weights_filename, weight_source, pooling) = {
-            let api = Api::new().map_err(NewEmbedderError::new_api_fail)?;
-            let api = api.repo(repo);
-            let config = api.get("config.json").map_err(NewEmbedderError::api_get)?;
-            let tokenizer = api.get("tokenizer.json").map_err(NewEmbedderError::api_get)?;
-            let (weights, source) = {
-                api.get("model.safetensors")
-                    .map(|filename| (filename, WeightSource::Safetensors))
-                    .or_else(|_| {
-                        api.get("pytorch_model.bin")
-                            .map(|filename| (filename, WeightSource::Pytorch))
-                    })
-                    .map_err(NewEmbedderError::api_get)?
-            };
-
-            let pooling = match api.get("1_Pooling/config.json") {
-                Ok(pooling) => Some(pooling),
-                Err(hf_hub::api::sync::ApiError::RequestError(error))
-                    if matches!(*error, ureq::Error::StatusCode(404)) =>
-                {
-                    // ignore the error if the file simply doesn't exist
-                    None
-                }
-                Err(error) => return Err(NewEmbedderError::api_get(error)),
-            };
-            let mut pooling: Pooling = match pooling {
-                Some(pooling_filename) => {
-                    let pooling = std::fs::read_to_string(&pooling_filename).map_err(|inner| {
-                        NewEmbedderError::open_pooling_config(pooling_filename.clone(), inner)
-                    })?;
-
-                    let pooling: PoolingConfig =
-                        serde_json::from_str(&pooling).map_err(|inner| {
-                            NewEmbedderError::deserialize_pooling_config(
-                                options.model.clone(),
-                                pooling_filename,
-                                inner,
-                            )
-                        })?;
-                    pooling.into()
-                }
-                None => Pooling::default(),
-            };
-
-            pooling.override_with(options.pooling);
 
-            (config, tokenizer, weights, source, pooling)
+        let mut pooling: Pooling = match pooling {
+            Some(pooling_filename) => {
+                let pooling = std::fs::read_to_string(&pooling_filename).map_err(|inner| {
+                    NewEmbedderError::open_pooling_config(pooling_filename.clone(), inner)
+                })?;
+
+                let pooling: PoolingConfig = serde_json::from_str(&pooling).map_err(|inner| {
+                    NewEmbedderError::deserialize_pooling_config(
+                        options.model.clone(),
+                        pooling_filename,
+                        inner,
+                    )
+                })?;
+                pooling.into()
+            }
+            None => Pooling::default(),
         };
 
+        pooling.override_with(options.pooling);
+
         let config_str = std::fs::read_to_string(&config_filename)
             .map_err(|inner| NewEmbedderError::open_config(config_filename.clone(), inner))?;
 
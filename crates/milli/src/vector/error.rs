@@ -372,6 +372,29 @@ impl NewEmbedderError {
         Self { kind: NewEmbedderErrorKind::ApiGet(inner), fault: FaultSource::Undecided }
     }
 
+    pub fn local_model_relative_path(path: PathBuf) -> Self {
+        Self { kind: NewEmbedderErrorKind::LocalModelRelativePath(path), fault: FaultSource::User }
+    }
+
+    pub fn local_models_dir_not_set() -> Self {
+        Self { kind: NewEmbedderErrorKind::LocalModelsDirNotSet, fault: FaultSource::User }
+    }
+
+    pub fn local_model_not_found(path: PathBuf) -> Self {
+        Self { kind: NewEmbedderErrorKind::LocalModelNotFound(path), fault: FaultSource::User }
+    }
+
+    pub fn local_model_missing_file(path: PathBuf, file: &'static str) -> Self {
+        Self {
+            kind: NewEmbedderErrorKind::LocalModelMissingFile { path, file },
+            fault: FaultSource::User,
+        }
+    }
+
+    pub(crate) fn local_model_with_revision() -> Self {
+        Self { kind: NewEmbedderErrorKind::LocalModelWithRevision, fault: FaultSource::User }
+    }
+
     pub fn pytorch_weight(inner: candle_core::Error) -> Self {
         Self { kind: NewEmbedderErrorKind::PytorchWeight(inner), fault: FaultSource::Runtime }
     }
@@ -591,6 +614,16 @@ pub enum NewEmbedderErrorKind {
     NewApiFail(ApiError),
     #[error("fetching file from HG_HUB failed:\n  - {0}")]
     ApiGet(ApiError),
+    #[error("`modelPath` must be an absolute path.\n  - Got `{}`", .0.display())]
+    LocalModelRelativePath(PathBuf),
+    #[error("`modelPath` cannot be used as no models directory is set.\n  - Hint: launch Meilisearch with `--experimental-hugging-face-models-dir` to set the directory the local models are in.")]
+    LocalModelsDirNotSet,
+    #[error("could not find a model directory at `{}` inside of the models directory.", .0.display())]
+    LocalModelNotFound(PathBuf),
+    #[error("the local model directory `{}` does not contain {file}.\n  - Hint: the directory must contain `config.json`, `tokenizer.json`, and the weights in `model.safetensors` or `pytorch_model.bin`.", path.display())]
+    LocalModelMissingFile { path: PathBuf, file: &'static str },
+    #[error("`revision` cannot be used with `modelPath`.\n  - Hint: the model is loaded as-is from the local directory, remove the `revision`.")]
+    LocalModelWithRevision,
     #[error("could not determine model dimensions:\n  - test embedding failed with {0}")]
     CouldNotDetermineDimension(EmbedError),
     #[error("loading model failed:\n  - {0}")]
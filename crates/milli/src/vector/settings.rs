@@ -76,6 +76,19 @@ pub struct EmbeddingSettings {
     )]
     pub pooling: Setting<OverridePooling>,
 
+    /// Absolute path to a local directory containing the model, for deployments without network access.
+    ///
+    /// - Only available for `huggingFace`.
+    /// - The directory must contain `config.json`, `tokenizer.json`, the weights in `model.safetensors` or `pytorch_model.bin`, and optionally `1_Pooling/config.json`.
+    /// - When set, the model is never fetched from Hugging Face and `revision` cannot be used.
+    /// - 🏗️ Changing the value of this parameter always regenerates embeddings
+    #[request(
+        default,
+        skip_serializing_if = "Setting::is_not_set",
+        schema_type = Option<String>, example = json!("/models/bge-base-en-v1.5")
+    )]
+    pub model_path: Setting<String>,
+
     /// API key sent to the embedder.
     ///
     /// - If not set for source `openAi`, the key is read from the `OPENAI_API_KEY` then `MEILI_OPENAI_API_KEY` environment variables.
@@ -309,6 +322,17 @@ pub struct SubEmbeddingSettings {
     #[schema(value_type = Option<OverridePooling>, default = json!("useModel"), example = json!("useModel"))]
     pub pooling: Setting<OverridePooling>,
 
+    /// Absolute path to a local directory containing the model, for deployments without network access.
+    ///
+    /// - Only available for `huggingFace`.
+    /// - The directory must contain `config.json`, `tokenizer.json`, the weights in `model.safetensors` or `pytorch_model.bin`, and optionally `1_Pooling/config.json`.
+    /// - When set, the model is never fetched from Hugging Face and `revision` cannot be used.
+    /// - 🏗️ Changing the value of this parameter always regenerates embeddings
+    #[serde(default, skip_serializing_if = "Setting::is_not_set")]
+    #[deserr(default)]
+    #[schema(value_type = Option<String>, example = json!("/models/bge-base-en-v1.5"))]
+    pub model_path: Setting<String>,
+
     /// API key sent to the embedder.
     ///
     /// - If not set for source `openAi`, the key is read from the `OPENAI_API_KEY` then `MEILI_OPENAI_API_KEY` environment variables.
@@ -561,6 +585,7 @@ impl SettingsDiff {
                     mut model,
                     mut revision,
                     mut pooling,
+                    mut model_path,
                     mut api_key,
                     mut dimensions,
                     mut document_template,
@@ -582,6 +607,7 @@ impl SettingsDiff {
                     model: new_model,
                     revision: new_revision,
                     pooling: new_pooling,
+                    model_path: new_model_path,
                     api_key: new_api_key,
                     dimensions: new_dimensions,
                     document_template: new_document_template,
@@ -614,6 +640,7 @@ impl SettingsDiff {
                     &mut model,
                     &mut revision,
                     &mut pooling,
+                    &mut model_path,
                     &mut api_key,
                     &mut dimensions,
                     &mut document_template,
@@ -628,6 +655,7 @@ impl SettingsDiff {
                     new_model,
                     new_revision,
                     new_pooling,
+                    new_model_path,
                     new_api_key,
                     new_dimensions,
                     new_document_template,
@@ -657,6 +685,7 @@ impl SettingsDiff {
                     model,
                     revision,
                     pooling,
+                    model_path,
                     api_key,
                     dimensions,
                     document_template,
@@ -705,6 +734,7 @@ impl SettingsDiff {
                     mut model,
                     mut revision,
                     mut pooling,
+                    mut model_path,
                     mut api_key,
                     mut dimensions,
                     mut document_template,
@@ -732,6 +762,7 @@ impl SettingsDiff {
                     model: new_model,
                     revision: new_revision,
                     pooling: new_pooling,
+                    model_path: new_model_path,
                     api_key: new_api_key,
                     dimensions: new_dimensions,
                     document_template: new_document_template,
@@ -754,6 +785,7 @@ impl SettingsDiff {
                     &mut model,
                     &mut revision,
                     &mut pooling,
+                    &mut model_path,
                     &mut api_key,
                     &mut dimensions,
                     &mut document_template,
@@ -768,6 +800,7 @@ impl SettingsDiff {
                     new_model,
                     new_revision,
                     new_pooling,
+                    new_model_path,
                     new_api_key,
                     new_dimensions,
                     new_document_template,
@@ -791,6 +824,7 @@ impl SettingsDiff {
                     model,
                     revision,
                     pooling,
+                    model_path,
                     api_key,
                     dimensions,
                     document_template,
@@ -821,6 +855,7 @@ impl SettingsDiff {
         model: &mut Setting<String>,
         revision: &mut Setting<String>,
         pooling: &mut Setting<OverridePooling>,
+        model_path: &mut Setting<String>,
         api_key: &mut Setting<String>,
         dimensions: &mut Setting<usize>,
         document_template: &mut Setting<String>,
@@ -835,6 +870,7 @@ impl SettingsDiff {
         new_model: Setting<String>,
         new_revision: Setting<String>,
         new_pooling: Setting<OverridePooling>,
+        new_model_path: Setting<String>,
         new_api_key: Setting<String>,
         new_dimensions: Setting<usize>,
         new_document_template: Setting<String>,
@@ -855,6 +891,7 @@ impl SettingsDiff {
                 model,
                 revision,
                 pooling,
+                model_path,
                 dimensions,
                 url,
                 indexing_fragments,
@@ -878,6 +915,9 @@ impl SettingsDiff {
         if pooling.apply(new_pooling) {
             ReindexAction::push_action(reindex_action, ReindexAction::FullReindex);
         }
+        if model_path.apply(new_model_path) {
+            ReindexAction::push_action(reindex_action, ReindexAction::FullReindex);
+        }
         if dimensions.apply(new_dimensions) {
             match *source {
                 // regenerate on dimensions change in OpenAI since truncation is supported
@@ -1048,6 +1088,7 @@ fn apply_default_for_source(
     model: &mut Setting<String>,
     revision: &mut Setting<String>,
     pooling: &mut Setting<OverridePooling>,
+    model_path: &mut Setting<String>,
     dimensions: &mut Setting<usize>,
     url: &mut Setting<String>,
     indexing_fragments: &mut Setting<BTreeMap<String, Option<Fragment>>>,
@@ -1065,6 +1106,7 @@ fn apply_default_for_source(
             *model = Setting::Reset;
             *revision = Setting::Reset;
             *pooling = Setting::Reset;
+            *model_path = Setting::Reset;
             *dimensions = Setting::NotSet;
             *url = Setting::NotSet;
             *indexing_fragments = Setting::NotSet;
@@ -1079,6 +1121,7 @@ fn apply_default_for_source(
             *model = Setting::Reset;
             *revision = Setting::NotSet;
             *pooling = Setting::NotSet;
+            *model_path = Setting::NotSet;
             *dimensions = Setting::Reset;
             *url = Setting::NotSet;
             *indexing_fragments = Setting::NotSet;
@@ -1093,6 +1136,7 @@ fn apply_default_for_source(
             *model = Setting::Reset;
             *revision = Setting::NotSet;
             *pooling = Setting::NotSet;
+            *model_path = Setting::NotSet;
             *dimensions = Setting::NotSet;
             *url = Setting::Reset;
             *indexing_fragments = Setting::NotSet;
@@ -1107,6 +1151,7 @@ fn apply_default_for_source(
             *model = Setting::NotSet;
             *revision = Setting::NotSet;
             *pooling = Setting::NotSet;
+            *model_path = Setting::NotSet;
             *dimensions = Setting::Reset;
             *url = Setting::Reset;
             *indexing_fragments = Setting::Reset;
@@ -1121,6 +1166,7 @@ fn apply_default_for_source(
             *model = Setting::NotSet;
             *revision = Setting::NotSet;
             *pooling = Setting::NotSet;
+            *model_path = Setting::NotSet;
             *dimensions = Setting::Reset;
             *url = Setting::NotSet;
             *indexing_fragments = Setting::NotSet;
@@ -1137,6 +1183,7 @@ fn apply_default_for_source(
             *model = Setting::NotSet;
             *revision = Setting::NotSet;
             *pooling = Setting::NotSet;
+            *model_path = Setting::NotSet;
             *dimensions = Setting::NotSet;
             *url = Setting::NotSet;
             *indexing_fragments = Setting::NotSet;
@@ -1202,6 +1249,7 @@ pub enum MetaEmbeddingSetting {
     Model,
     Revision,
     Pooling,
+    ModelPath,
     ApiKey,
     Dimensions,
     DocumentTemplate,
@@ -1226,6 +1274,7 @@ impl MetaEmbeddingSetting {
             Model => "model",
             Revision => "revision",
             Pooling => "pooling",
+            ModelPath => "modelPath",
             ApiKey => "apiKey",
             Dimensions => "dimensions",
             DocumentTemplate => "documentTemplate",
@@ -1253,6 +1302,7 @@ impl EmbeddingSettings {
         model: &Setting<String>,
         revision: &Setting<String>,
         pooling: &Setting<OverridePooling>,
+        model_path: &Setting<String>,
         dimensions: &Setting<usize>,
         api_key: &Setting<String>,
         url: &Setting<String>,
@@ -1283,6 +1333,13 @@ impl EmbeddingSettings {
             context,
             pooling,
         )?;
+        Self::check_setting(
+            embedder_name,
+            source,
+            MetaEmbeddingSetting::ModelPath,
+            context,
+            model_path,
+        )?;
         Self::check_setting(
             embedder_name,
             source,
@@ -1444,13 +1501,19 @@ impl EmbeddingSettings {
             ) => FieldStatus::Allowed,
             (
                 OpenAi,
-                Revision | Pooling | IndexingFragments | SearchFragments | Request | Response
-                | Headers | SearchEmbedder | IndexingEmbedder,
+                Revision | Pooling | ModelPath | IndexingFragments | SearchFragments | Request
+                | Response | Headers | SearchEmbedder | IndexingEmbedder,
                 _,
             ) => FieldStatus::Disallowed,
             (
                 HuggingFace,
-                Source | Model | Revision | Pooling | DocumentTemplate | DocumentTemplateMaxBytes,
+                Source
+                | Model
+                | Revision
+                | Pooling
+                | ModelPath
+                | DocumentTemplate
+                | DocumentTemplateMaxBytes,
                 _,
             ) => FieldStatus::Allowed,
             (
@@ -1467,8 +1530,8 @@ impl EmbeddingSettings {
             ) => FieldStatus::Allowed,
             (
                 Ollama,
-                Revision | Pooling | IndexingFragments | SearchFragments | Request | Response
-                | Headers | SearchEmbedder | IndexingEmbedder,
+                Revision | Pooling | ModelPath | IndexingFragments | SearchFragments | Request
+                | Response | Headers | SearchEmbedder | IndexingEmbedder,
                 _,
             ) => FieldStatus::Disallowed,
             (UserProvided, Dimensions, _) => FieldStatus::Mandatory,
@@ -1478,6 +1541,7 @@ impl EmbeddingSettings {
                 Model
                 | Revision
                 | Pooling
+                | ModelPath
                 | ApiKey
                 | DocumentTemplate
                 | DocumentTemplateMaxBytes
@@ -1506,9 +1570,11 @@ impl EmbeddingSettings {
             (Rest, IndexingFragments, Search) => FieldStatus::Disallowed,
             (Rest, SearchFragments, NotNested | Search) => FieldStatus::Allowed,
             (Rest, SearchFragments, Indexing) => FieldStatus::Disallowed,
-            (Rest, Model | Revision | Pooling | SearchEmbedder | IndexingEmbedder, _) => {
-                FieldStatus::Disallowed
-            }
+            (
+                Rest,
+                Model | Revision | Pooling | ModelPath | SearchEmbedder | IndexingEmbedder,
+                _,
+            ) => FieldStatus::Disallowed,
             (Composite, SearchEmbedder | IndexingEmbedder, _) => FieldStatus::Mandatory,
             (Composite, Source, _) => FieldStatus::Allowed,
             (
@@ -1516,6 +1582,7 @@ impl EmbeddingSettings {
                 Model
                 | Revision
                 | Pooling
+                | ModelPath
                 | ApiKey
                 | Dimensions
                 | DocumentTemplate
@@ -1610,16 +1677,22 @@ pub struct Fragment {
 
 impl EmbeddingSettings {
     fn from_hugging_face(
-        hf::EmbedderOptions { model, revision, distribution, pooling }: hf::EmbedderOptions,
+        hf::EmbedderOptions { model, revision, distribution, pooling, model_path }: hf::EmbedderOptions,
         document_template: Setting<String>,
         document_template_max_bytes: Setting<usize>,
         quantized: Option<bool>,
     ) -> Self {
         Self {
             source: Setting::Set(EmbedderSource::HuggingFace),
-            model: Setting::Set(model),
+            // a local model named after its path was not given a name
+            model: if model_path.as_ref() == Some(&model) {
+                Setting::NotSet
+            } else {
+                Setting::Set(model)
+            },
             revision: Setting::some_or_not_set(revision),
             pooling: Setting::Set(pooling),
+            model_path: Setting::some_or_not_set(model_path),
             api_key: Setting::NotSet,
             dimensions: Setting::NotSet,
             document_template,
@@ -1654,6 +1727,7 @@ impl EmbeddingSettings {
             model: Setting::Set(embedding_model.name().to_owned()),
             revision: Setting::NotSet,
             pooling: Setting::NotSet,
+            model_path: Setting::NotSet,
             api_key: Setting::some_or_not_set(api_key),
             dimensions: Setting::some_or_not_set(dimensions),
             document_template,
@@ -1688,6 +1762,7 @@ impl EmbeddingSettings {
             model: Setting::Set(embedding_model),
             revision: Setting::NotSet,
             pooling: Setting::NotSet,
+            model_path: Setting::NotSet,
             api_key: Setting::some_or_not_set(api_key),
             dimensions: Setting::some_or_not_set(dimensions),
             document_template,
@@ -1714,6 +1789,7 @@ impl EmbeddingSettings {
             model: Setting::NotSet,
             revision: Setting::NotSet,
             pooling: Setting::NotSet,
+            model_path: Setting::NotSet,
             api_key: Setting::NotSet,
             dimensions: Setting::Set(dimensions),
             document_template: Setting::NotSet,
@@ -1752,6 +1828,7 @@ impl EmbeddingSettings {
             model: Setting::NotSet,
             revision: Setting::NotSet,
             pooling: Setting::NotSet,
+            model_path: Setting::NotSet,
             api_key: Setting::some_or_not_set(api_key),
             dimensions: Setting::some_or_not_set(dimensions),
             document_template: if indexing_fragments.is_empty() && search_fragments.is_empty() {
@@ -1837,6 +1914,7 @@ impl From<EmbeddingConfig> for EmbeddingSettings {
                     model: Setting::NotSet,
                     revision: Setting::NotSet,
                     pooling: Setting::NotSet,
+                    model_path: Setting::NotSet,
                     api_key: Setting::NotSet,
                     dimensions: Setting::NotSet,
                     binary_quantized: Setting::some_or_not_set(quantized),
@@ -1913,6 +1991,7 @@ impl From<EmbeddingSettings> for SubEmbeddingSettings {
             model,
             revision,
             pooling,
+            model_path,
             api_key,
             dimensions,
             document_template,
@@ -1933,6 +2012,7 @@ impl From<EmbeddingSettings> for SubEmbeddingSettings {
             model,
             revision,
             pooling,
+            model_path,
             api_key,
             dimensions,
             document_template,
@@ -1959,6 +2039,7 @@ impl From<EmbeddingSettings> for EmbeddingConfig {
             model,
             revision,
             pooling,
+            model_path,
             api_key,
             dimensions,
             document_template,
@@ -2007,9 +2088,14 @@ impl From<EmbeddingSettings> for EmbeddingConfig {
                 EmbedderSource::Ollama => {
                     SubEmbedderOptions::ollama(model, url, api_key, dimensions, distribution).into()
                 }
-                EmbedderSource::HuggingFace => {
-                    SubEmbedderOptions::hugging_face(model, revision, pooling, distribution).into()
-                }
+                EmbedderSource::HuggingFace => SubEmbedderOptions::hugging_face(
+                    model,
+                    revision,
+                    pooling,
+                    model_path,
+                    distribution,
+                )
+                .into(),
                 EmbedderSource::UserProvided => {
                     SubEmbedderOptions::user_provided(dimensions.set().unwrap(), distribution)
                         .into()
@@ -2056,6 +2142,7 @@ impl SubEmbedderOptions {
             model,
             revision,
             pooling,
+            model_path,
             api_key,
             dimensions,
             // retrieved by the EmbeddingConfig
@@ -2077,7 +2164,7 @@ impl SubEmbedderOptions {
         match source.set().unwrap() {
             EmbedderSource::OpenAi => Self::openai(model, url, api_key, dimensions, distribution),
             EmbedderSource::HuggingFace => {
-                Self::hugging_face(model, revision, pooling, distribution)
+                Self::hugging_face(model, revision, pooling, model_path, distribution)
             }
             EmbedderSource::Ollama => Self::ollama(model, url, api_key, dimensions, distribution),
             EmbedderSource::UserProvided => {
@@ -2127,9 +2214,11 @@ impl SubEmbedderOptions {
         model: Setting<String>,
         revision: Setting<String>,
         pooling: Setting<OverridePooling>,
+        model_path: Setting<String>,
         distribution: Setting<DistributionShift>,
     ) -> Self {
         let mut options = hf::EmbedderOptions::default();
+        let model_is_set = matches!(model, Setting::Set(_));
         if let Some(model) = model.set() {
             options.model = model;
             // Reset the revision if we are setting the model.
@@ -2145,6 +2234,15 @@ impl SubEmbedderOptions {
         if let Some(pooling) = pooling.set() {
             options.pooling = pooling;
         }
+        if let Some(model_path) = model_path.set() {
+            // The local model is used as-is, there is no revision to pick.
+            // Unless a name is given, the model is named after its path rather than the default model.
+            if !model_is_set {
+                options.model = model_path.clone();
+            }
+            options.model_path = Some(model_path);
+            options.revision = None;
+        }
         options.distribution = distribution.set();
         SubEmbedderOptions::HuggingFace(options)
     }
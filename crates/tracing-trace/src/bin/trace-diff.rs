@@ -0,0 +1,107 @@
+//! Compares the spans of two traces and reports the ones that regressed.
+//!
+//! Usage: `trace-diff <BASELINE> <CANDIDATE> [THRESHOLD]`, where `THRESHOLD` is the percentage of
+//! additional time above which a span is considered regressed (10 by default).
+//!
+//! Exits with the status code 1 when at least one span regressed, so it can be used with `git bisect run`.
+
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::time::Duration;
+
+use tracing_trace::processor::span_stats::{diff_call_stats, to_call_stats, CallStats};
+
+/// Spans whose total time changed by less than this are ignored, as they are mostly noise.
+const MIN_TIME_DELTA: Duration = Duration::from_millis(1);
+
+fn main() {
+    let mut args = std::env::args_os().skip(1);
+    let baseline_file = args.next().expect("missing <BASELINE> file");
+    let candidate_file = args.next().expect("missing <CANDIDATE> file");
+    let threshold: f64 = args
+        .next()
+        .map(|threshold| {
+            threshold.to_str().and_then(|t| t.parse().ok()).expect("invalid [THRESHOLD] percentage")
+        })
+        .unwrap_or(10.0);
+
+    let baseline = call_stats(&baseline_file);
+    let candidate = call_stats(&candidate_file);
+
+    let mut diffs: Vec<_> = diff_call_stats(baseline, candidate)
+        .into_iter()
+        .filter(|diff| diff.time_delta().unsigned_abs() >= MIN_TIME_DELTA.as_nanos())
+        .collect();
+    diffs.sort_by_key(|diff| std::cmp::Reverse(diff.time_delta()));
+
+    let mut regressed = Vec::new();
+    let mut improved = Vec::new();
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    for diff in &diffs {
+        match (diff.baseline, diff.candidate, diff.time_ratio()) {
+            (None, Some(_), _) => added.push(diff),
+            (Some(_), None, _) => removed.push(diff),
+            (_, _, Some(ratio)) if ratio > 1.0 + threshold / 100.0 => regressed.push(diff),
+            (_, _, Some(ratio)) if ratio < 1.0 - threshold / 100.0 => improved.push(diff),
+            _ => (),
+        }
+    }
+    improved.reverse();
+
+    println!("Regressed spans (more than {threshold}% slower):");
+    for diff in &regressed {
+        let (baseline, candidate) = (diff.baseline.unwrap(), diff.candidate.unwrap());
+        println!(
+            "  {}: {} -> {} ({:+.1}%, self time {}, calls {} -> {})",
+            diff.name,
+            format_time(baseline.time),
+            format_time(candidate.time),
+            (diff.time_ratio().unwrap() - 1.0) * 100.0,
+            format_delta(diff.self_time_delta()),
+            baseline.call_count,
+            candidate.call_count,
+        );
+    }
+    println!("Improved spans (more than {threshold}% faster):");
+    for diff in &improved {
+        let (baseline, candidate) = (diff.baseline.unwrap(), diff.candidate.unwrap());
+        println!(
+            "  {}: {} -> {} ({:+.1}%)",
+            diff.name,
+            format_time(baseline.time),
+            format_time(candidate.time),
+            (diff.time_ratio().unwrap() - 1.0) * 100.0,
+        );
+    }
+    println!("Spans only in the candidate:");
+    for diff in &added {
+        println!("  {}: {}", diff.name, format_time(diff.candidate.unwrap().time));
+    }
+    println!("Spans only in the baseline:");
+    for diff in &removed {
+        println!("  {}: {}", diff.name, format_time(diff.baseline.unwrap().time));
+    }
+
+    if !regressed.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+fn call_stats(file: &OsStr) -> BTreeMap<String, CallStats> {
+    let input = std::io::BufReader::new(
+        std::fs::File::open(file)
+            .unwrap_or_else(|e| panic!("could not open {}: {e}", file.to_string_lossy())),
+    );
+    let trace = tracing_trace::TraceReader::new(input);
+    to_call_stats(trace).unwrap()
+}
+
+fn format_time(nanos: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos))
+}
+
+fn format_delta(nanos: i128) -> String {
+    let sign = if nanos < 0 { '-' } else { '+' };
+    format!("{sign}{:.2?}", Duration::from_nanos(nanos.unsigned_abs() as u64))
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::entry::{Entry, Event, MemoryStats, NewCallsite, SpanClose, SpanEnter, SpanExit};
+use crate::{Error, TraceReader};
+
+/// The process id of all the events, a trace only ever describes a single Meilisearch process.
+const PID: u32 = 1;
+
+/// A trace in the [Chrome Trace Event format], that can be opened in `chrome://tracing` or in the
+/// [Perfetto UI](https://ui.perfetto.dev).
+///
+/// [Chrome Trace Event format]: https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChromeTrace {
+    pub trace_events: Vec<TraceEvent>,
+    pub display_time_unit: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TraceEvent {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cat: Option<String>,
+    pub ph: Phase,
+    /// Timestamp of the event, in microseconds.
+    pub ts: f64,
+    /// Duration of a complete event, in microseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dur: Option<f64>,
+    pub pid: u32,
+    pub tid: usize,
+    /// Scope of an instant event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub s: Option<&'static str>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub args: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum Phase {
+    /// A span that was entered then exited.
+    #[serde(rename = "X")]
+    Complete,
+    /// A tracing event.
+    #[serde(rename = "i")]
+    Instant,
+    /// A sample of the memory counters.
+    #[serde(rename = "C")]
+    Counter,
+    /// The name of a process or a thread.
+    #[serde(rename = "M")]
+    Metadata,
+}
+
+pub fn to_chrome_trace<R: std::io::Read>(
+    trace: TraceReader<R>,
+    app: &str,
+) -> Result<ChromeTrace, Error> {
+    let mut trace_events = vec![TraceEvent {
+        name: "process_name".to_string(),
+        cat: None,
+        ph: Phase::Metadata,
+        ts: 0.0,
+        dur: None,
+        pid: PID,
+        tid: 0,
+        s: None,
+        args: args(json!({ "name": app })),
+    }];
+
+    let mut calls = HashMap::new();
+    let mut spans = HashMap::new();
+
+    for entry in trace {
+        let entry = entry?;
+        match entry {
+            Entry::NewCallsite(callsite) => {
+                calls.insert(callsite.call_id, callsite);
+            }
+            Entry::NewThread(thread) => {
+                let Some(name) = thread.name else { continue };
+                trace_events.push(TraceEvent {
+                    name: "thread_name".to_string(),
+                    cat: None,
+                    ph: Phase::Metadata,
+                    ts: 0.0,
+                    dur: None,
+                    pid: PID,
+                    tid: thread.thread_id.to_usize(),
+                    s: None,
+                    args: args(json!({ "name": name })),
+                });
+            }
+            Entry::NewSpan(span) => {
+                spans.insert(span.id, (span, SpanStatus::Outside));
+            }
+            Entry::SpanEnter(SpanEnter { id, time, memory }) => {
+                let (_span, status) = spans.get_mut(&id).unwrap();
+
+                let SpanStatus::Outside = status else {
+                    continue;
+                };
+
+                *status = SpanStatus::Inside { time, memory };
+
+                trace_events.extend(memory_counter(time, memory));
+            }
+            Entry::SpanExit(SpanExit { id, time, memory }) => {
+                let (span, status) = spans.get_mut(&id).unwrap();
+
+                let SpanStatus::Inside { time: begin, memory: begin_memory } = *status else {
+                    continue;
+                };
+
+                *status = SpanStatus::Outside;
+
+                let span = *span;
+                let callsite = calls.get(&span.call_id).unwrap();
+
+                let mut args = callsite_args(callsite);
+                args.insert("span_id".to_string(), json!(span.id));
+                if let Some(parent_id) = span.parent_id {
+                    args.insert("parent_span_id".to_string(), json!(parent_id));
+                }
+                let memory_delta =
+                    begin_memory.zip(memory).and_then(|(begin, end)| end.checked_sub(begin));
+                if let Some(MemoryStats { resident }) = memory_delta {
+                    args.insert("resident".to_string(), json!(resident));
+                }
+
+                trace_events.push(TraceEvent {
+                    name: callsite.name.to_string(),
+                    cat: Some(callsite.target.to_string()),
+                    ph: Phase::Complete,
+                    ts: to_micros(begin),
+                    dur: Some(to_micros(time - begin)),
+                    pid: PID,
+                    tid: span.thread_id.to_usize(),
+                    s: None,
+                    args,
+                });
+
+                trace_events.extend(memory_counter(time, memory));
+            }
+            Entry::Event(Event { call_id, thread_id, parent_id, time, memory }) => {
+                let callsite = calls.get(&call_id).unwrap();
+
+                let mut args = callsite_args(callsite);
+                if let Some(parent_id) = parent_id {
+                    args.insert("parent_span_id".to_string(), json!(parent_id));
+                }
+
+                trace_events.push(TraceEvent {
+                    name: callsite.name.to_string(),
+                    cat: Some(callsite.target.to_string()),
+                    ph: Phase::Instant,
+                    ts: to_micros(time),
+                    dur: None,
+                    pid: PID,
+                    tid: thread_id.to_usize(),
+                    s: Some("t"),
+                    args,
+                });
+
+                trace_events.extend(memory_counter(time, memory));
+            }
+            Entry::SpanClose(SpanClose { id, time: _ }) => {
+                spans.remove(&id);
+            }
+        }
+    }
+
+    Ok(ChromeTrace { trace_events, display_time_unit: "ns" })
+}
+
+/// A counter event with the resident memory, displayed as a track of the process.
+fn memory_counter(time: std::time::Duration, memory: Option<MemoryStats>) -> Option<TraceEvent> {
+    let MemoryStats { resident } = memory?;
+    Some(TraceEvent {
+        name: "memory".to_string(),
+        cat: None,
+        ph: Phase::Counter,
+        ts: to_micros(time),
+        dur: None,
+        pid: PID,
+        tid: 0,
+        s: None,
+        args: args(json!({ "resident": resident })),
+    })
+}
+
+fn callsite_args(callsite: &NewCallsite) -> Map<String, Value> {
+    args(json!({
+        "filename": callsite.file.as_deref(),
+        "line": callsite.line,
+        "module_path": callsite.module_path.as_deref(),
+    }))
+}
+
+fn args(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+fn to_micros(time: std::time::Duration) -> f64 {
+    time.as_nanos() as f64 / 1000.0
+}
+
+#[derive(Debug, Clone, Copy)]
+enum SpanStatus {
+    Outside,
+    Inside { time: std::time::Duration, memory: Option<MemoryStats> },
+}
@@ -1,3 +1,4 @@
+pub mod chrome_trace;
 pub mod firefox_profiler;
 pub mod fmt;
 pub mod span_stats;
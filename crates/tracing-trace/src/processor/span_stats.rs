@@ -13,7 +13,7 @@ enum SpanStatus {
     Inside(std::time::Duration),
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct CallStats {
     pub call_count: usize,
     pub time: u64,
@@ -158,3 +158,59 @@ fn calls_to_stats(calls: Vec<(Duration, Duration)>) -> CallStats {
     let self_sum: Duration = calls.iter().map(|(_, self_duration)| self_duration).sum();
     CallStats { call_count: nb, time: sum.as_nanos() as u64, self_time: self_sum.as_nanos() as u64 }
 }
+
+/// The stats of a span in two runs, to compare them.
+#[derive(Debug, Clone)]
+pub struct CallStatsDiff {
+    pub name: String,
+    /// The stats of the span in the first run, `None` if the span did not run.
+    pub baseline: Option<CallStats>,
+    /// The stats of the span in the second run, `None` if the span did not run.
+    pub candidate: Option<CallStats>,
+}
+
+impl CallStatsDiff {
+    /// The difference of the total time spent in the span, in nanoseconds.
+    pub fn time_delta(&self) -> i128 {
+        let baseline = self.baseline.map_or(0, |stats| stats.time);
+        let candidate = self.candidate.map_or(0, |stats| stats.time);
+        candidate as i128 - baseline as i128
+    }
+
+    /// The difference of the time spent in the span outside of its children, in nanoseconds.
+    pub fn self_time_delta(&self) -> i128 {
+        let baseline = self.baseline.map_or(0, |stats| stats.self_time);
+        let candidate = self.candidate.map_or(0, |stats| stats.self_time);
+        candidate as i128 - baseline as i128
+    }
+
+    /// The total time of the candidate relative to the one of the baseline,
+    /// `None` if the span did not run in both runs.
+    pub fn time_ratio(&self) -> Option<f64> {
+        let (baseline, candidate) = self.baseline.zip(self.candidate)?;
+        if baseline.time == 0 {
+            return None;
+        }
+        Some(candidate.time as f64 / baseline.time as f64)
+    }
+}
+
+/// Pairs the stats of the spans of two runs, as returned by [`to_call_stats`].
+pub fn diff_call_stats(
+    mut baseline: BTreeMap<String, CallStats>,
+    candidate: BTreeMap<String, CallStats>,
+) -> Vec<CallStatsDiff> {
+    let mut diffs: Vec<_> = candidate
+        .into_iter()
+        .map(|(name, candidate)| {
+            let baseline = baseline.remove(&name);
+            CallStatsDiff { name, baseline, candidate: Some(candidate) }
+        })
+        .collect();
+    diffs.extend(baseline.into_iter().map(|(name, baseline)| CallStatsDiff {
+        name,
+        baseline: Some(baseline),
+        candidate: None,
+    }));
+    diffs
+}